@@ -37,6 +37,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             user_id: Some("user-123".to_string()),
             tags: Some(vec!["example".to_string(), "tutorial".to_string()]),
             custom: None,
+            ..Default::default()
         },
         created_at: Utc::now(),
         updated_at: Utc::now(),
@@ -78,13 +79,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let metadata = MessageMetadata {
         model_id: Some("gpt-4".to_string()),
         provider: Some("openai".to_string()),
-        usage: Some(UsageStats {
-            prompt_tokens: 20,
-            completion_tokens: 50,
-            total_tokens: 70,
-        }),
+        usage: Some(UsageStats::new(20, 50)),
         finish_reason: Some("stop".to_string()),
         custom: None,
+        ..Default::default()
     };
 
     let assistant_message = AssistantMessage::new(
@@ -125,6 +123,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             user_id: Some("user-123".to_string()),
             tags: Some(vec!["async".to_string(), "rust".to_string()]),
             custom: None,
+            ..Default::default()
         },
         created_at: Utc::now(),
         updated_at: Utc::now(),
@@ -53,6 +53,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     usage: Some(UsageStats::new(100, 50)),
                     finish_reason: Some("stop".to_string()),
                     custom: None,
+                    ..Default::default()
                 });
         storage
             .store_assistant_message(&asst_msg, &[asst_part])
@@ -52,6 +52,7 @@ pub struct AnthropicClient {
     api_key: Option<String>,
     headers: HashMap<String, String>,
     name: Option<String>,
+    http_client: Option<reqwest::Client>,
 }
 
 impl AnthropicClient {
@@ -168,6 +169,30 @@ impl AnthropicClient {
         self
     }
 
+    /// Sets the HTTP client used for all API requests of the provider.
+    ///
+    /// Use this to share a connection pool or to configure proxies, timeouts,
+    /// TLS roots or a user agent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use llm_kit_anthropic::AnthropicClient;
+    /// use std::time::Duration;
+    ///
+    /// let http_client = reqwest::Client::builder()
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let client = AnthropicClient::new()
+    ///     .http_client(http_client);
+    /// ```
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
     /// Builds the `AnthropicProvider` with the configured settings.
     ///
     /// # Returns
@@ -206,6 +231,10 @@ impl AnthropicClient {
             settings = settings.with_headers(self.headers);
         }
 
+        if let Some(http_client) = self.http_client {
+            settings = settings.with_http_client(http_client);
+        }
+
         AnthropicProvider::new(settings)
     }
 }
@@ -230,7 +230,13 @@ impl LanguageModel for AnthropicMessagesLanguageModel {
         let transformed_body = self.transform_request_body(build_result.args);
 
         // Make HTTP POST request
-        let response_json = post_json(&url, headers, transformed_body.clone()).await?;
+        let response_json = post_json(
+            &self.config.http_client,
+            &url,
+            headers,
+            transformed_body.clone(),
+        )
+        .await?;
 
         // Parse response
         let response: AnthropicMessagesResponse = serde_json::from_value(response_json)?;
@@ -336,7 +342,13 @@ impl LanguageModel for AnthropicMessagesLanguageModel {
         let transformed_body = self.transform_request_body(build_result.args.clone());
 
         // Make HTTP POST request with streaming
-        let byte_stream = post_stream(url.as_str(), headers, transformed_body).await?;
+        let byte_stream = post_stream(
+            &self.config.http_client,
+            url.as_str(),
+            headers,
+            transformed_body,
+        )
+        .await?;
 
         // Parse SSE events and convert to SDK stream parts
         let stream = parse_sse_stream(
@@ -33,6 +33,9 @@ pub struct AnthropicMessagesConfig {
 
     /// Optional function to generate unique IDs
     pub generate_id: Option<GenerateIdFn>,
+
+    /// HTTP client used to send requests
+    pub http_client: reqwest::Client,
 }
 
 impl AnthropicMessagesConfig {
@@ -45,6 +48,7 @@ impl AnthropicMessagesConfig {
             build_request_url: None,
             transform_request_body: None,
             generate_id: None,
+            http_client: reqwest::Client::new(),
         }
     }
 
@@ -65,4 +69,10 @@ impl AnthropicMessagesConfig {
         self.generate_id = Some(generator);
         self
     }
+
+    /// Set the HTTP client used to send requests
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = http_client;
+        self
+    }
 }
@@ -3,11 +3,13 @@ use serde_json::Value;
 use std::collections::HashMap;
 
 use crate::error::parse_anthropic_error;
+use llm_kit_provider::error::ProviderError;
 
 /// Make an HTTP POST request to the Anthropic API
 ///
 /// # Arguments
 ///
+/// * `client` - The HTTP client used to send the request
 /// * `url` - The API endpoint URL
 /// * `headers` - HTTP headers to include in the request
 /// * `body` - Request body as JSON value
@@ -16,6 +18,7 @@ use crate::error::parse_anthropic_error;
 ///
 /// The response body as a JSON value, or an error
 pub async fn post_json(
+    client: &reqwest::Client,
     url: &str,
     headers: HashMap<String, String>,
     body: Value,
@@ -23,9 +26,6 @@ pub async fn post_json(
     // Serialize body to string for logging and sending
     let body_string = serde_json::to_string(&body)?;
 
-    // Create HTTP client
-    let client = reqwest::Client::new();
-
     // Build request
     let mut request = client.post(url).header("Content-Type", "application/json");
 
@@ -35,16 +35,22 @@ pub async fn post_json(
     }
 
     // Send request
-    let response = request.body(body_string).send().await?;
+    let response = request.body(body_string.clone()).send().await?;
 
-    // Get status and response body
+    // Get status, headers and response body
     let status = response.status();
+    let response_headers = response.headers().clone();
     let response_body = response.text().await?;
 
     // Handle error responses
     if !status.is_success() {
-        let provider_error = parse_anthropic_error(status.as_u16(), &response_body);
-        return Err(Box::new(provider_error));
+        return Err(Box::new(api_call_error(
+            url,
+            body_string,
+            status.as_u16(),
+            &response_headers,
+            response_body,
+        )));
     }
 
     // Parse successful response
@@ -57,6 +63,7 @@ pub async fn post_json(
 ///
 /// # Arguments
 ///
+/// * `client` - The HTTP client used to send the request
 /// * `url` - The API endpoint URL
 /// * `headers` - HTTP headers to include in the request
 /// * `body` - Request body as JSON value
@@ -65,6 +72,7 @@ pub async fn post_json(
 ///
 /// A byte stream from the response
 pub async fn post_stream(
+    client: &reqwest::Client,
     url: &str,
     headers: HashMap<String, String>,
     body: Value,
@@ -75,9 +83,6 @@ pub async fn post_stream(
     // Serialize body
     let body_string = serde_json::to_string(&body)?;
 
-    // Create HTTP client
-    let client = reqwest::Client::new();
-
     // Build request
     let mut request = client.post(url).header("Content-Type", "application/json");
 
@@ -87,22 +92,63 @@ pub async fn post_stream(
     }
 
     // Send request
-    let response = request.body(body_string).send().await?;
+    let response = request.body(body_string.clone()).send().await?;
 
     // Get status
     let status = response.status();
 
     // Handle error responses
     if !status.is_success() {
+        let response_headers = response.headers().clone();
         let response_body = response.text().await?;
-        let provider_error = parse_anthropic_error(status.as_u16(), &response_body);
-        return Err(Box::new(provider_error));
+        return Err(Box::new(api_call_error(
+            url,
+            body_string,
+            status.as_u16(),
+            &response_headers,
+            response_body,
+        )));
     }
 
     // Return byte stream
     Ok(response.bytes_stream())
 }
 
+/// Build an `APICallError` for a failed request.
+///
+/// The parsed Anthropic error is kept as the source, while the status code and
+/// response headers allow callers to decide whether and when to retry.
+fn api_call_error(
+    url: &str,
+    request_body: String,
+    status_code: u16,
+    response_headers: &reqwest::header::HeaderMap,
+    response_body: String,
+) -> ProviderError {
+    let provider_error = parse_anthropic_error(status_code, &response_body);
+    let headers = response_headers
+        .iter()
+        .filter_map(|(key, value)| {
+            value
+                .to_str()
+                .ok()
+                .map(|value| (key.as_str().to_string(), value.to_string()))
+        })
+        .collect();
+
+    ProviderError::api_call_error_with_details(
+        provider_error.to_string(),
+        url,
+        request_body,
+        Some(status_code),
+        Some(headers),
+        Some(response_body),
+        None,
+        None,
+        Some(Box::new(provider_error)),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -1,11 +1,14 @@
 use bytes::Bytes;
 use futures_util::stream::Stream;
 use llm_kit_provider::language_model::call_warning::LanguageModelCallWarning;
+use llm_kit_provider::language_model::content::LanguageModelContent;
 use llm_kit_provider::language_model::stream_part::LanguageModelStreamPart;
+use llm_kit_provider_utils::event_source::{ParseResult, parse_json_event_stream};
 use std::collections::HashMap;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
+use super::process_content::create_citation_sources;
 use super::stream_schema::AnthropicChunk;
 use crate::map_stop_reason::map_anthropic_stop_reason;
 
@@ -28,20 +31,21 @@ pub fn parse_sse_stream(
     include_raw_chunks: bool,
 ) -> impl Stream<Item = LanguageModelStreamPart> + Unpin + Send {
     Box::pin(AnthropicStreamParser::new(
-        Box::pin(byte_stream),
+        Box::pin(parse_json_event_stream(byte_stream)),
         uses_json_response_tool,
         warnings,
         include_raw_chunks,
     ))
 }
 
+/// Stream of parsed SSE chunks from the HTTP response
+type ChunkStream =
+    Pin<Box<dyn Stream<Item = Result<ParseResult<AnthropicChunk>, reqwest::Error>> + Send>>;
+
 /// State machine for parsing Anthropic streaming responses
 struct AnthropicStreamParser {
-    /// Underlying byte stream
-    byte_stream: Pin<Box<dyn Stream<Item = Result<Bytes, reqwest::Error>> + Send>>,
-
-    /// Buffer for incomplete SSE events
-    buffer: String,
+    /// Underlying stream of parsed SSE chunks
+    chunk_stream: ChunkStream,
 
     /// Pending output events
     pending_events: Vec<LanguageModelStreamPart>,
@@ -113,14 +117,13 @@ struct UsageState {
 
 impl AnthropicStreamParser {
     fn new(
-        byte_stream: Pin<Box<dyn Stream<Item = Result<Bytes, reqwest::Error>> + Send>>,
+        chunk_stream: ChunkStream,
         uses_json_response_tool: bool,
         warnings: Vec<LanguageModelCallWarning>,
         include_raw_chunks: bool,
     ) -> Self {
         Self {
-            byte_stream,
-            buffer: String::new(),
+            chunk_stream,
             pending_events: Vec::new(),
             content_blocks: HashMap::new(),
             mcp_tool_calls: HashMap::new(),
@@ -238,6 +241,10 @@ impl AnthropicStreamParser {
                     ),
                 ));
             }
+
+            AnthropicChunk::Unknown => {
+                // Ignore events of new types
+            }
         }
     }
 
@@ -486,6 +493,10 @@ impl AnthropicStreamParser {
                     Some("text_editor_code_execution_tool_result".to_string());
                 self.emit_text_editor_code_execution_result(tool_use_id, content);
             }
+
+            ContentBlockStart::Unknown => {
+                // Ignore blocks of new types; their deltas and stop are ignored too
+            }
         }
     }
 
@@ -568,6 +579,47 @@ impl AnthropicStreamParser {
                     *first_delta = false;
                 }
             }
+
+            ContentBlockDelta::SignatureDelta { signature } => {
+                // Signatures are only sent for thinking blocks
+                if !matches!(
+                    self.content_blocks.get(&index),
+                    Some(ContentBlockState::Reasoning)
+                ) {
+                    return;
+                }
+
+                let mut metadata = HashMap::new();
+                let mut anthropic_meta = HashMap::new();
+                anthropic_meta.insert("signature".to_string(), serde_json::json!(signature));
+                metadata.insert("anthropic".to_string(), anthropic_meta);
+
+                self.pending_events.push(LanguageModelStreamPart::ReasoningDelta(
+                    llm_kit_provider::language_model::stream_part::reasoning_delta::LanguageModelStreamReasoningDelta::with_metadata(
+                        index.to_string(),
+                        "",
+                        Some(metadata),
+                    ),
+                ));
+            }
+
+            ContentBlockDelta::CitationsDelta { citation } => {
+                // Citations of new types are skipped
+                let Ok(citation) = serde_json::from_value(citation) else {
+                    return;
+                };
+
+                for content in create_citation_sources(vec![citation]) {
+                    if let LanguageModelContent::Source(source) = content {
+                        self.pending_events
+                            .push(LanguageModelStreamPart::Source(source));
+                    }
+                }
+            }
+
+            ContentBlockDelta::Unknown => {
+                // Ignore deltas of new types
+            }
         }
     }
 
@@ -881,17 +933,18 @@ impl Stream for AnthropicStreamParser {
             return Poll::Ready(Some(self.pending_events.remove(0)));
         }
 
-        // Poll for more bytes
-        match self.byte_stream.as_mut().poll_next(cx) {
-            Poll::Ready(Some(Ok(bytes))) => {
-                // Add bytes to buffer
-                if let Ok(text) = std::str::from_utf8(&bytes) {
-                    self.buffer.push_str(text);
+        // Poll for the next chunk
+        match self.chunk_stream.as_mut().poll_next(cx) {
+            Poll::Ready(Some(Ok(chunk))) => {
+                match chunk {
+                    Ok(chunk) => self.process_chunk(chunk),
+                    Err(e) => self.pending_events.push(LanguageModelStreamPart::Error(
+                        llm_kit_provider::language_model::stream_part::error::LanguageModelStreamError::new(
+                            serde_json::json!(e.to_string()),
+                        ),
+                    )),
                 }
 
-                // Parse SSE events from buffer
-                self.parse_sse_events();
-
                 // Return next pending event or continue polling
                 if !self.pending_events.is_empty() {
                     Poll::Ready(Some(self.pending_events.remove(0)))
@@ -917,23 +970,3 @@ impl Stream for AnthropicStreamParser {
         }
     }
 }
-
-impl AnthropicStreamParser {
-    /// Parse SSE events from the buffer
-    fn parse_sse_events(&mut self) {
-        // SSE format: "data: {json}\n\n"
-        while let Some(pos) = self.buffer.find("\n\n") {
-            let event_str = self.buffer.drain(..pos + 2).collect::<String>();
-
-            // Extract data line
-            for line in event_str.lines() {
-                if let Some(data) = line.strip_prefix("data: ") {
-                    // Parse JSON
-                    if let Ok(chunk) = serde_json::from_str::<AnthropicChunk>(data) {
-                        self.process_chunk(chunk);
-                    }
-                }
-            }
-        }
-    }
-}
@@ -42,6 +42,10 @@ pub enum AnthropicChunk {
 
     /// Error event
     Error { error: ErrorData },
+
+    /// Event of a type this version doesn't know (ignored)
+    #[serde(other)]
+    Unknown,
 }
 
 /// Message start data
@@ -141,6 +145,10 @@ pub enum ContentBlockStart {
         tool_use_id: String,
         content: TextEditorCodeExecutionContent,
     },
+
+    /// Block of a type this version doesn't know (ignored)
+    #[serde(other)]
+    Unknown,
 }
 
 /// Content block delta data
@@ -155,6 +163,16 @@ pub enum ContentBlockDelta {
 
     /// Input JSON delta (for tool use)
     InputJsonDelta { partial_json: String },
+
+    /// Signature of a thinking block
+    SignatureDelta { signature: String },
+
+    /// Citation of a text block (kept as JSON, as new citation types are skipped)
+    CitationsDelta { citation: Value },
+
+    /// Delta of a type this version doesn't know (ignored)
+    #[serde(other)]
+    Unknown,
 }
 
 /// Message delta data
@@ -55,6 +55,7 @@ pub struct AnthropicProvider {
     base_url: String,
     provider_name: String,
     headers: Arc<dyn Fn() -> HashMap<String, String> + Send + Sync>,
+    http_client: reqwest::Client,
 }
 
 impl AnthropicProvider {
@@ -107,6 +108,7 @@ impl AnthropicProvider {
             .expect("ANTHROPIC_API_KEY must be set either in settings or environment variable");
 
         let custom_headers = settings.headers.unwrap_or_default();
+        let http_client = settings.http_client.unwrap_or_default();
 
         let headers_fn = Arc::new(move || {
             let mut headers = HashMap::new();
@@ -128,6 +130,7 @@ impl AnthropicProvider {
             base_url,
             provider_name,
             headers: headers_fn,
+            http_client,
         }
     }
 
@@ -169,7 +172,8 @@ impl AnthropicProvider {
             self.provider_name.clone(),
             self.base_url.clone(),
             self.headers.clone(),
-        );
+        )
+        .with_http_client(self.http_client.clone());
 
         AnthropicMessagesLanguageModel::new(model_id, config)
     }
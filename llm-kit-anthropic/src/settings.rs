@@ -10,6 +10,7 @@ use std::collections::HashMap;
 ///   It defaults to the `ANTHROPIC_API_KEY` environment variable.
 /// * `headers` - Custom headers to include in the requests.
 /// * `name` - Custom provider name. Defaults to 'anthropic.messages'.
+/// * `http_client` - HTTP client shared by all models of the provider.
 ///
 /// # Example
 ///
@@ -26,6 +27,7 @@ use std::collections::HashMap;
 ///         h
 ///     }),
 ///     name: Some("my-anthropic".to_string()),
+///     http_client: None,
 /// };
 /// ```
 #[derive(Debug, Clone, Default)]
@@ -44,6 +46,10 @@ pub struct AnthropicProviderSettings {
     /// Custom provider name.
     /// Defaults to 'anthropic.messages'.
     pub name: Option<String>,
+
+    /// Optional HTTP client shared by all models of the provider. Use it to configure
+    /// proxies, timeouts, TLS roots or a user agent. Defaults to a new `reqwest::Client`.
+    pub http_client: Option<reqwest::Client>,
 }
 
 impl AnthropicProviderSettings {
@@ -87,6 +93,12 @@ impl AnthropicProviderSettings {
         self.name = Some(name.into());
         self
     }
+
+    /// Sets the HTTP client used for API requests.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,99 @@
+use futures::StreamExt;
+use llm_kit_anthropic::{AnthropicProvider, AnthropicProviderSettings};
+use llm_kit_provider::LanguageModel;
+use llm_kit_provider::language_model::call_options::LanguageModelCallOptions;
+use llm_kit_provider::language_model::content::source::LanguageModelSource;
+use llm_kit_provider::language_model::prompt::LanguageModelMessage;
+use llm_kit_provider::language_model::stream_part::LanguageModelStreamPart;
+use llm_kit_provider_utils::testing::{MockResponse, MockServer, test_http_client};
+use serde_json::json;
+
+/// A response with a signed thinking block and a text block with a citation.
+fn thinking_and_cited_text() -> MockResponse {
+    MockResponse::event_stream(
+        [
+            json!({ "type": "message_start", "message": { "id": "msg_1", "model": "claude-sonnet-4-5", "usage": { "input_tokens": 10 } } }),
+            json!({ "type": "content_block_start", "index": 0, "content_block": { "type": "thinking", "thinking": "" } }),
+            json!({ "type": "content_block_delta", "index": 0, "delta": { "type": "thinking_delta", "thinking": "Let me check." } }),
+            json!({ "type": "content_block_delta", "index": 0, "delta": { "type": "signature_delta", "signature": "sig_1" } }),
+            json!({ "type": "content_block_stop", "index": 0 }),
+            json!({ "type": "content_block_start", "index": 1, "content_block": { "type": "text", "text": "" } }),
+            json!({ "type": "content_block_delta", "index": 1, "delta": { "type": "text_delta", "text": "It is sunny." } }),
+            json!({ "type": "content_block_delta", "index": 1, "delta": { "type": "citations_delta", "citation": { "type": "web_search_result_location", "cited_text": "Sunny", "url": "https://weather.example.com", "title": "Weather", "encrypted_index": "idx_1" } } }),
+            json!({ "type": "content_block_delta", "index": 1, "delta": { "type": "citations_delta", "citation": { "type": "future_location", "cited_text": "Sunny" } } }),
+            json!({ "type": "content_block_stop", "index": 1 }),
+            json!({ "type": "content_block_start", "index": 2, "content_block": { "type": "future_block" } }),
+            json!({ "type": "content_block_delta", "index": 2, "delta": { "type": "future_delta" } }),
+            json!({ "type": "content_block_stop", "index": 2 }),
+            json!({ "type": "future_event" }),
+            json!({ "type": "message_delta", "delta": { "stop_reason": "end_turn" }, "usage": { "output_tokens": 20 } }),
+            json!({ "type": "message_stop" }),
+        ],
+        false,
+    )
+}
+
+#[tokio::test]
+async fn test_stream_handles_signature_citation_and_unknown_events() {
+    let server = MockServer::start().await;
+    server.enqueue(thinking_and_cited_text());
+    let provider = AnthropicProvider::new(
+        AnthropicProviderSettings::new()
+            .with_base_url(server.url())
+            .with_api_key("test-key")
+            .with_http_client(test_http_client()),
+    );
+
+    let response = provider
+        .messages("claude-sonnet-4-5".to_string())
+        .do_stream(LanguageModelCallOptions::new(vec![
+            LanguageModelMessage::user_text("What is the weather?"),
+        ]))
+        .await
+        .unwrap();
+    let parts: Vec<LanguageModelStreamPart> = response.stream.collect().await;
+
+    assert!(
+        !parts
+            .iter()
+            .any(|part| matches!(part, LanguageModelStreamPart::Error(_))),
+        "unexpected error in {:?}",
+        parts
+    );
+
+    let signature = parts.iter().find_map(|part| match part {
+        LanguageModelStreamPart::ReasoningDelta(delta) => delta
+            .provider_metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get("anthropic"))
+            .and_then(|anthropic| anthropic.get("signature")),
+        _ => None,
+    });
+    assert_eq!(signature, Some(&json!("sig_1")));
+
+    let sources: Vec<&LanguageModelSource> = parts
+        .iter()
+        .filter_map(|part| match part {
+            LanguageModelStreamPart::Source(source) => Some(source),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(sources.len(), 1);
+    assert!(matches!(
+        sources[0],
+        LanguageModelSource::Url { url, .. } if url == "https://weather.example.com"
+    ));
+
+    let text: String = parts
+        .iter()
+        .filter_map(|part| match part {
+            LanguageModelStreamPart::TextDelta(delta) => Some(delta.delta.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(text, "It is sunny.");
+    assert!(matches!(
+        parts.last(),
+        Some(LanguageModelStreamPart::Finish(_))
+    ));
+}
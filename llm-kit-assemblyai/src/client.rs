@@ -37,6 +37,7 @@ pub struct AssemblyAIClient {
     headers: HashMap<String, String>,
     base_url: Option<String>,
     polling_interval_ms: Option<u64>,
+    http_client: Option<reqwest::Client>,
 }
 
 impl AssemblyAIClient {
@@ -100,6 +101,19 @@ impl AssemblyAIClient {
         self
     }
 
+    /// Sets the HTTP client used for all API requests of the provider.
+    ///
+    /// Use this to share a connection pool or to configure proxies, timeouts,
+    /// TLS roots or a user agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `http_client` - The `reqwest` client to use
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
     /// Builds the `AssemblyAIProvider` with the configured settings.
     ///
     /// # Returns
@@ -134,6 +148,10 @@ impl AssemblyAIClient {
             settings = settings.with_polling_interval_ms(polling_interval_ms);
         }
 
+        if let Some(http_client) = self.http_client {
+            settings = settings.with_http_client(http_client);
+        }
+
         AssemblyAIProvider::new(settings)
     }
 }
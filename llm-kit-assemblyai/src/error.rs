@@ -1,5 +1,6 @@
 use llm_kit_provider::error::ProviderError;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use thiserror::Error;
 
 /// AssemblyAI-specific error types.
@@ -96,6 +97,35 @@ pub fn parse_error_response(body: &str) -> Result<AssemblyAIError, serde_json::E
     })
 }
 
+/// Create an API call error from an unsuccessful AssemblyAI response.
+///
+/// The error is retryable depending on the status code.
+pub fn api_call_error(
+    context: &str,
+    url: &str,
+    request_body: String,
+    status_code: u16,
+    response_headers: HashMap<String, String>,
+    body: String,
+) -> ProviderError {
+    let message = match parse_error_response(&body) {
+        Ok(error) => format!("{}: {}", context, error),
+        Err(_) => format!("{}: HTTP {}: {}", context, status_code, body),
+    };
+
+    ProviderError::api_call_error_with_details(
+        message,
+        url,
+        request_body,
+        Some(status_code),
+        Some(response_headers),
+        Some(body),
+        None,
+        None,
+        None,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -124,4 +154,21 @@ mod tests {
         let provider_error: ProviderError = error.into();
         assert!(provider_error.to_string().contains("Test error"));
     }
+
+    #[test]
+    fn test_api_call_error() {
+        let body = r#"{"error": {"message": "Too many requests", "code": 429}}"#;
+        let error = api_call_error(
+            "Upload failed",
+            "https://api.assemblyai.com/v2/upload",
+            String::new(),
+            429,
+            HashMap::new(),
+            body.to_string(),
+        );
+
+        assert_eq!(error.status_code(), Some(429));
+        assert!(error.is_retryable());
+        assert!(error.to_string().contains("Too many requests"));
+    }
 }
@@ -57,6 +57,7 @@ impl AssemblyAIProvider {
             api_key: self.settings.api_key.clone(),
             headers: self.settings.headers.clone().unwrap_or_default(),
             polling_interval_ms: self.settings.polling_interval_ms,
+            http_client: self.settings.http_client.clone().unwrap_or_default(),
         }
     }
 
@@ -14,6 +14,10 @@ pub struct AssemblyAIProviderSettings {
 
     /// Polling interval in milliseconds for checking transcription status (default: 3000)
     pub polling_interval_ms: u64,
+
+    /// Optional HTTP client shared by all models of the provider. Use it to configure
+    /// proxies, timeouts, TLS roots or a user agent. Defaults to a new `reqwest::Client`.
+    pub http_client: Option<reqwest::Client>,
 }
 
 impl AssemblyAIProviderSettings {
@@ -24,6 +28,7 @@ impl AssemblyAIProviderSettings {
             headers: None,
             base_url: "https://api.assemblyai.com".to_string(),
             polling_interval_ms: 3000,
+            http_client: None,
         }
     }
 
@@ -60,6 +65,12 @@ impl AssemblyAIProviderSettings {
         self.polling_interval_ms = interval_ms;
         self
     }
+
+    /// Sets the HTTP client used for API requests.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
 }
 
 impl Default for AssemblyAIProviderSettings {
@@ -14,7 +14,7 @@ use super::api_types::{
     TranscriptionStatus,
 };
 use super::options::{AssemblyAITranscriptionModelId, AssemblyAITranscriptionOptions};
-use crate::error::{parse_error_response, AssemblyAIError};
+use crate::error::{api_call_error, AssemblyAIError};
 
 /// Configuration for the AssemblyAI transcription model.
 pub struct AssemblyAITranscriptionConfig {
@@ -23,13 +23,13 @@ pub struct AssemblyAITranscriptionConfig {
     pub api_key: Option<String>,
     pub headers: HashMap<String, String>,
     pub polling_interval_ms: u64,
+    pub http_client: reqwest::Client,
 }
 
 /// AssemblyAI transcription model implementation.
 pub struct AssemblyAITranscriptionModel {
     model_id: AssemblyAITranscriptionModelId,
     config: AssemblyAITranscriptionConfig,
-    client: reqwest::Client,
 }
 
 impl AssemblyAITranscriptionModel {
@@ -38,11 +38,7 @@ impl AssemblyAITranscriptionModel {
         model_id: AssemblyAITranscriptionModelId,
         config: AssemblyAITranscriptionConfig,
     ) -> Self {
-        Self {
-            model_id,
-            config,
-            client: reqwest::Client::new(),
-        }
+        Self { model_id, config }
     }
 
     /// Build headers for API requests.
@@ -87,7 +83,8 @@ impl AssemblyAITranscriptionModel {
         headers.insert(CONTENT_TYPE, "application/octet-stream".parse()?);
 
         let request = self
-            .client
+            .config
+            .http_client
             .post(&url)
             .headers(headers)
             .body(audio_data.to_vec());
@@ -100,12 +97,19 @@ impl AssemblyAITranscriptionModel {
         }
 
         let response = request.send().await?;
+        let status = response.status();
 
-        if !response.status().is_success() {
+        if !status.is_success() {
+            let response_headers = header_map_to_hash_map(response.headers());
             let body = response.text().await?;
-            let error =
-                parse_error_response(&body).unwrap_or_else(|_| AssemblyAIError::UploadFailed(body));
-            return Err(Box::new(error));
+            return Err(Box::new(api_call_error(
+                "Upload failed",
+                &url,
+                String::new(), // Audio data is not recorded
+                status.as_u16(),
+                response_headers,
+                body,
+            )));
         }
 
         let upload_response: AssemblyAIUploadResponse = response.json().await?;
@@ -148,22 +152,26 @@ impl AssemblyAITranscriptionModel {
         }
 
         let response = self
-            .client
+            .config
+            .http_client
             .post(&url)
             .headers(headers)
             .json(&body)
             .send()
             .await?;
 
-        if !response.status().is_success() {
+        let status = response.status();
+        if !status.is_success() {
+            let response_headers = header_map_to_hash_map(response.headers());
             let body_text = response.text().await?;
-            let error = parse_error_response(&body_text).unwrap_or_else(|_| {
-                AssemblyAIError::InvalidResponse(format!(
-                    "Failed to submit transcription: {}",
-                    body_text
-                ))
-            });
-            return Err(Box::new(error));
+            return Err(Box::new(api_call_error(
+                "Failed to submit transcription",
+                &url,
+                body.to_string(),
+                status.as_u16(),
+                response_headers,
+                body_text,
+            )));
         }
 
         let submit_response: AssemblyAISubmitResponse = response.json().await?;
@@ -189,17 +197,25 @@ impl AssemblyAITranscriptionModel {
             }
 
             let response = self
-                .client
+                .config
+                .http_client
                 .get(&url)
                 .headers(headers.clone())
                 .send()
                 .await?;
 
-            if !response.status().is_success() {
+            let status = response.status();
+            if !status.is_success() {
+                let response_headers = header_map_to_hash_map(response.headers());
                 let body = response.text().await?;
-                let error = parse_error_response(&body)
-                    .unwrap_or_else(|_| AssemblyAIError::InvalidResponse(body));
-                return Err(Box::new(error));
+                return Err(Box::new(api_call_error(
+                    "Failed to get transcription",
+                    &url,
+                    String::new(),
+                    status.as_u16(),
+                    response_headers,
+                    body,
+                )));
             }
 
             let transcript: AssemblyAITranscriptionResponse = response.json().await?;
@@ -245,6 +261,13 @@ impl AssemblyAITranscriptionModel {
     }
 }
 
+fn header_map_to_hash_map(headers: &HeaderMap) -> HashMap<String, String> {
+    headers
+        .iter()
+        .map(|(k, v)| (k.as_str().to_string(), v.to_str().unwrap_or("").to_string()))
+        .collect()
+}
+
 #[async_trait]
 impl TranscriptionModelTrait for AssemblyAITranscriptionModel {
     fn provider(&self) -> &str {
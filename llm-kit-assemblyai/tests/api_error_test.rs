@@ -0,0 +1,75 @@
+use llm_kit_assemblyai::{AssemblyAIProvider, AssemblyAIProviderSettings};
+use llm_kit_provider::error::ProviderError;
+use llm_kit_provider::transcription_model::call_options::TranscriptionModelCallOptions;
+use llm_kit_provider_utils::testing::{test_http_client, MockResponse, MockServer};
+use serde_json::json;
+
+fn provider(server: &MockServer) -> AssemblyAIProvider {
+    AssemblyAIProvider::new(
+        AssemblyAIProviderSettings::new()
+            .with_base_url(server.url())
+            .with_api_key("test-key")
+            .with_polling_interval_ms(1)
+            .with_http_client(test_http_client()),
+    )
+}
+
+fn error_response(status: u16, message: &str) -> MockResponse {
+    MockResponse::json(json!({ "error": { "message": message, "code": status } }))
+        .with_status(status)
+}
+
+fn upload_response() -> MockResponse {
+    MockResponse::json(json!({ "upload_url": "https://cdn.assemblyai.com/upload/1" }))
+}
+
+fn submit_response() -> MockResponse {
+    MockResponse::json(json!({ "id": "transcript_1", "status": "queued" }))
+}
+
+async fn transcription_error(server: &MockServer) -> (Option<u16>, bool, String) {
+    let model = provider(server).transcription_model("best");
+    let options = TranscriptionModelCallOptions::new(vec![1, 2, 3], "audio/wav");
+
+    let error = model.do_generate(options).await.unwrap_err();
+    let error = error.downcast_ref::<ProviderError>().unwrap();
+    (error.status_code(), error.is_retryable(), error.to_string())
+}
+
+#[tokio::test]
+async fn test_upload_errors_are_api_call_errors() {
+    let server = MockServer::start().await;
+    server.enqueue(error_response(429, "Too many requests"));
+
+    let (status, retryable, message) = transcription_error(&server).await;
+
+    assert_eq!(status, Some(429));
+    assert!(retryable);
+    assert!(message.contains("Too many requests"));
+}
+
+#[tokio::test]
+async fn test_submit_errors_are_api_call_errors() {
+    let server = MockServer::start().await;
+    server.enqueue(upload_response());
+    server.enqueue(error_response(401, "Invalid API key"));
+
+    let (status, retryable, _) = transcription_error(&server).await;
+
+    assert_eq!(status, Some(401));
+    assert!(!retryable);
+}
+
+#[tokio::test]
+async fn test_polling_errors_are_api_call_errors() {
+    let server = MockServer::start().await;
+    server.enqueue(upload_response());
+    server.enqueue(submit_response());
+    server.enqueue(MockResponse::json(json!({ "message": "Unavailable" })).with_status(503));
+
+    let (status, retryable, message) = transcription_error(&server).await;
+
+    assert_eq!(status, Some(503));
+    assert!(retryable);
+    assert!(message.contains("Unavailable"));
+}
@@ -71,6 +71,7 @@ pub struct AzureClient {
     headers: HashMap<String, String>,
     api_version: Option<String>,
     use_deployment_based_urls: bool,
+    http_client: Option<reqwest::Client>,
 }
 
 impl AzureClient {
@@ -244,6 +245,30 @@ impl AzureClient {
         self
     }
 
+    /// Sets the HTTP client used for all API requests of the provider.
+    ///
+    /// Use this to share a connection pool or to configure proxies, timeouts,
+    /// TLS roots or a user agent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use llm_kit_azure::AzureClient;
+    /// use std::time::Duration;
+    ///
+    /// let http_client = reqwest::Client::builder()
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let client = AzureClient::new()
+    ///     .http_client(http_client);
+    /// ```
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
     /// Builds the Azure OpenAI provider.
     ///
     /// This method constructs the provider settings from the builder configuration
@@ -300,6 +325,11 @@ impl AzureClient {
         // Set deployment-based URLs flag
         settings = settings.with_use_deployment_based_urls(self.use_deployment_based_urls);
 
+        // Set HTTP client if provided
+        if let Some(http_client) = self.http_client {
+            settings = settings.with_http_client(http_client);
+        }
+
         AzureOpenAIProvider::new(settings)
     }
 }
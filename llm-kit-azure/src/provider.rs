@@ -49,6 +49,7 @@ use crate::settings::AzureOpenAIProviderSettings;
 /// ```
 pub struct AzureOpenAIProvider {
     settings: AzureOpenAIProviderSettings,
+    http_client: reqwest::Client,
 }
 
 impl AzureOpenAIProvider {
@@ -62,7 +63,12 @@ impl AzureOpenAIProvider {
         if let Err(e) = settings.validate() {
             panic!("Invalid Azure OpenAI provider settings: {}", e);
         }
-        Self { settings }
+        let http_client = settings.http_client.clone().unwrap_or_default();
+
+        Self {
+            settings,
+            http_client,
+        }
     }
 
     /// Helper function to build URLs for Azure OpenAI API calls.
@@ -194,6 +200,7 @@ impl AzureOpenAIProvider {
             include_usage: true,
             supports_structured_outputs: false,
             supported_urls: None,
+            http_client: self.http_client.clone(),
         }
     }
 
@@ -233,6 +240,7 @@ impl AzureOpenAIProvider {
                 )
             }),
             include_usage: true,
+            http_client: self.http_client.clone(),
         }
     }
 
@@ -273,6 +281,7 @@ impl AzureOpenAIProvider {
             }),
             max_embeddings_per_call: None,
             supports_parallel_calls: None,
+            http_client: self.http_client.clone(),
         }
     }
 
@@ -311,6 +320,7 @@ impl AzureOpenAIProvider {
                     use_deployment_based,
                 )
             }),
+            http_client: self.http_client.clone(),
         }
     }
 
@@ -43,6 +43,10 @@ pub struct AzureOpenAIProviderSettings {
     /// Set to false (default) to use v1 API format:
     /// `{base_url}/v1{path}?api-version={api_version}`
     pub use_deployment_based_urls: bool,
+
+    /// Optional HTTP client shared by all models of the provider. Use it to configure
+    /// proxies, timeouts, TLS roots or a user agent. Defaults to a new `reqwest::Client`.
+    pub http_client: Option<reqwest::Client>,
 }
 
 impl Default for AzureOpenAIProviderSettings {
@@ -54,6 +58,7 @@ impl Default for AzureOpenAIProviderSettings {
             headers: None,
             api_version: "v1".to_string(),
             use_deployment_based_urls: false,
+            http_client: None,
         }
     }
 }
@@ -120,6 +125,12 @@ impl AzureOpenAIProviderSettings {
         self
     }
 
+    /// Sets the HTTP client used for API requests.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
     /// Gets the base URL prefix for API calls.
     ///
     /// Returns the custom `base_url` if set, otherwise constructs from `resource_name`.
@@ -49,6 +49,7 @@ pub struct BasetenClient {
     base_url: Option<String>,
     model_url: Option<String>,
     headers: HashMap<String, String>,
+    http_client: Option<reqwest::Client>,
 }
 
 impl BasetenClient {
@@ -115,6 +116,30 @@ impl BasetenClient {
         self
     }
 
+    /// Sets the HTTP client used for all API requests of the provider.
+    ///
+    /// Use this to share a connection pool or to configure proxies, timeouts,
+    /// TLS roots or a user agent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use llm_kit_baseten::BasetenClient;
+    /// use std::time::Duration;
+    ///
+    /// let http_client = reqwest::Client::builder()
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let client = BasetenClient::new()
+    ///     .http_client(http_client);
+    /// ```
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
     /// Builds the `BasetenProvider` with the configured settings.
     ///
     /// If no API key is explicitly set, attempts to load from the `BASETEN_API_KEY` environment variable.
@@ -155,6 +180,10 @@ impl BasetenClient {
             settings = settings.with_headers(self.headers);
         }
 
+        if let Some(http_client) = self.http_client {
+            settings = settings.with_http_client(http_client);
+        }
+
         BasetenProvider::new(settings)
     }
 }
@@ -13,12 +13,18 @@ use crate::settings::BasetenProviderSettings;
 /// Wraps the OpenAI-compatible provider with Baseten-specific configuration.
 pub struct BasetenProvider {
     settings: BasetenProviderSettings,
+    http_client: reqwest::Client,
 }
 
 impl BasetenProvider {
     /// Creates a new Baseten provider with the given settings.
     pub fn new(settings: BasetenProviderSettings) -> Self {
-        Self { settings }
+        let http_client = settings.http_client.clone().unwrap_or_default();
+
+        Self {
+            settings,
+            http_client,
+        }
     }
 
     /// Creates a language model (alias for chat_model).
@@ -123,6 +129,9 @@ impl BasetenProvider {
             compat_settings = compat_settings.with_headers(headers.clone());
         }
 
+        // Share the HTTP client across all models of the provider
+        compat_settings = compat_settings.with_http_client(self.http_client.clone());
+
         OpenAICompatibleProvider::new(compat_settings)
     }
 
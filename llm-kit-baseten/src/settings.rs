@@ -19,6 +19,10 @@ pub struct BasetenProviderSettings {
 
     /// Optional custom headers to include in requests.
     pub headers: Option<HashMap<String, String>>,
+
+    /// Optional HTTP client shared by all models of the provider. Use it to configure
+    /// proxies, timeouts, TLS roots or a user agent. Defaults to a new `reqwest::Client`.
+    pub http_client: Option<reqwest::Client>,
 }
 
 impl Default for BasetenProviderSettings {
@@ -28,6 +32,7 @@ impl Default for BasetenProviderSettings {
             api_key: None,
             model_url: None,
             headers: None,
+            http_client: None,
         }
     }
 }
@@ -69,6 +74,12 @@ impl BasetenProviderSettings {
         self.headers = Some(headers);
         self
     }
+
+    /// Sets the HTTP client used for API requests.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -51,6 +51,7 @@ pub struct CerebrasClient {
     base_url: Option<String>,
     api_key: Option<String>,
     headers: HashMap<String, String>,
+    http_client: Option<reqwest::Client>,
 }
 
 impl CerebrasClient {
@@ -155,6 +156,30 @@ impl CerebrasClient {
         self
     }
 
+    /// Sets the HTTP client used for all API requests of the provider.
+    ///
+    /// Use this to share a connection pool or to configure proxies, timeouts,
+    /// TLS roots or a user agent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use llm_kit_cerebras::CerebrasClient;
+    /// use std::time::Duration;
+    ///
+    /// let http_client = reqwest::Client::builder()
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let client = CerebrasClient::new()
+    ///     .http_client(http_client);
+    /// ```
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
     /// Builds the `CerebrasProvider` with the configured settings.
     ///
     /// If no API key is provided via the builder, the provider will attempt
@@ -193,6 +218,10 @@ impl CerebrasClient {
             settings = settings.with_headers(self.headers);
         }
 
+        if let Some(http_client) = self.http_client {
+            settings = settings.with_http_client(http_client);
+        }
+
         CerebrasProvider::new(settings)
     }
 }
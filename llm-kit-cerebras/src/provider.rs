@@ -26,6 +26,7 @@ use crate::settings::CerebrasProviderSettings;
 /// ```
 pub struct CerebrasProvider {
     settings: CerebrasProviderSettings,
+    http_client: reqwest::Client,
 }
 
 impl CerebrasProvider {
@@ -46,7 +47,12 @@ impl CerebrasProvider {
     /// let provider = CerebrasProvider::new(settings);
     /// ```
     pub fn new(settings: CerebrasProviderSettings) -> Self {
-        Self { settings }
+        let http_client = settings.http_client.clone().unwrap_or_default();
+
+        Self {
+            settings,
+            http_client,
+        }
     }
 
     /// Creates a language model with the given model ID.
@@ -127,6 +133,7 @@ impl CerebrasProvider {
             include_usage: false,
             supports_structured_outputs: true,
             supported_urls: None,
+            http_client: self.http_client.clone(),
         }
     }
 
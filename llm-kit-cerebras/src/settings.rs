@@ -14,6 +14,10 @@ pub struct CerebrasProviderSettings {
 
     /// Custom headers to include in all requests
     pub headers: Option<HashMap<String, String>>,
+
+    /// Optional HTTP client shared by all models of the provider. Use it to configure
+    /// proxies, timeouts, TLS roots or a user agent. Defaults to a new `reqwest::Client`.
+    pub http_client: Option<reqwest::Client>,
 }
 
 impl CerebrasProviderSettings {
@@ -35,6 +39,7 @@ impl CerebrasProviderSettings {
             base_url: base_url.into(),
             api_key: None,
             headers: None,
+            http_client: None,
         }
     }
 
@@ -104,6 +109,25 @@ impl CerebrasProviderSettings {
         }
         self
     }
+
+    /// Sets the HTTP client used for API requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `http_client` - The `reqwest` client to use
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use llm_kit_cerebras::CerebrasProviderSettings;
+    ///
+    /// let settings = CerebrasProviderSettings::new("https://api.cerebras.ai/v1")
+    ///     .with_http_client(reqwest::Client::new());
+    /// ```
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
 }
 
 impl Default for CerebrasProviderSettings {
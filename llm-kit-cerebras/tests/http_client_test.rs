@@ -0,0 +1,20 @@
+use llm_kit_cerebras::{CerebrasProvider, CerebrasProviderSettings};
+use llm_kit_provider_utils::testing::{
+    MockServer, assert_models_use_http_client, provider_models, test_http_client,
+};
+
+#[tokio::test]
+async fn test_http_client_is_used_by_every_model() {
+    let server = MockServer::start().await;
+    let provider = CerebrasProvider::new(
+        CerebrasProviderSettings::new(server.url())
+            .with_api_key("test-key")
+            .with_http_client(test_http_client()),
+    );
+
+    let models = provider_models(&provider, "test-model");
+    let kinds: Vec<&str> = models.iter().map(|model| model.kind()).collect();
+    assert_eq!(kinds, ["language"]);
+
+    assert_models_use_http_client(&server, &models).await;
+}
@@ -3,12 +3,15 @@ use crate::output::Output;
 use crate::prompt::call_settings::CallSettings;
 use crate::tool::{ToolCallRepairFunction, ToolSet};
 use llm_kit_provider::LanguageModel;
+use llm_kit_provider::language_model::call_options::LanguageModelResponseFormat;
 use llm_kit_provider::language_model::tool_choice::LanguageModelToolChoice;
 use llm_kit_provider::shared::provider_options::SharedProviderOptions;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+#[cfg(feature = "storage")]
+use crate::history::HistoryStrategy;
 #[cfg(feature = "storage")]
 use llm_kit_storage::Storage;
 
@@ -62,14 +65,31 @@ pub struct AgentSettings {
     /// changing the tool call and result types in the result.
     pub active_tools: Option<Vec<String>>,
 
-    /// Optional specification for generating structured outputs.
+    /// Optional specification of the output of each call.
+    ///
+    /// A text part requests a text response, and a tool call part forces a call
+    /// to that tool. Other parts are rejected when the agent is called. An
+    /// explicit `response_format` or `tool_choice` takes precedence.
     pub output: Option<Output>,
 
+    /// The response format (e.g. JSON with a schema) for structured outputs.
+    ///
+    /// Forwarded to each call. With a JSON format, the text of the last step is
+    /// parsed into the `output` of the result.
+    pub response_format: Option<LanguageModelResponseFormat>,
+
     /// Optional trait object that you can use to provide different settings for a step.
     pub prepare_step: Option<Arc<dyn PrepareStep>>,
 
     /// A function that attempts to repair a tool call that failed to parse.
-    pub experimental_repair_tool_call: Option<ToolCallRepairFunction>,
+    ///
+    /// Shared across all calls made by the agent.
+    pub experimental_repair_tool_call: Option<Arc<ToolCallRepairFunction>>,
+
+    /// Maximum number of tool calls of a step that run concurrently.
+    ///
+    /// Default: unlimited
+    pub max_parallel_tool_calls: Option<usize>,
 
     /// Callback that is called when each step (LLM call) is finished, including intermediate steps.
     pub on_step_finish: Option<AgentOnStepFinishCallback>,
@@ -103,6 +123,13 @@ pub struct AgentSettings {
     #[cfg(feature = "storage")]
     pub session_id: Option<String>,
 
+    /// Optional strategy that selects which part of the loaded history is sent.
+    ///
+    /// When not set, the whole conversation history is sent.
+    /// Requires the "storage" feature flag.
+    #[cfg(feature = "storage")]
+    pub history_strategy: Option<Arc<dyn HistoryStrategy>>,
+
     // Call settings (inherited from CallSettings)
     /// Maximum number of tokens to generate.
     pub max_output_tokens: Option<u32>,
@@ -144,8 +171,10 @@ impl AgentSettings {
             stop_when: None,
             active_tools: None,
             output: None,
+            response_format: None,
             prepare_step: None,
             experimental_repair_tool_call: None,
+            max_parallel_tool_calls: None,
             on_step_finish: None,
             on_finish: None,
             provider_options: None,
@@ -154,6 +183,8 @@ impl AgentSettings {
             storage: None,
             #[cfg(feature = "storage")]
             session_id: None,
+            #[cfg(feature = "storage")]
+            history_strategy: None,
             max_output_tokens: None,
             temperature: None,
             top_p: None,
@@ -208,6 +239,12 @@ impl AgentSettings {
         self
     }
 
+    /// Sets the response format for structured outputs.
+    pub fn with_response_format(mut self, response_format: LanguageModelResponseFormat) -> Self {
+        self.response_format = Some(response_format);
+        self
+    }
+
     /// Sets the prepare step trait object.
     pub fn with_prepare_step(mut self, prepare_step: Arc<dyn PrepareStep>) -> Self {
         self.prepare_step = Some(prepare_step);
@@ -216,7 +253,13 @@ impl AgentSettings {
 
     /// Sets the tool call repair function.
     pub fn with_experimental_repair_tool_call(mut self, repair_fn: ToolCallRepairFunction) -> Self {
-        self.experimental_repair_tool_call = Some(repair_fn);
+        self.experimental_repair_tool_call = Some(Arc::new(repair_fn));
+        self
+    }
+
+    /// Sets the maximum number of tool calls of a step that run concurrently.
+    pub fn with_max_parallel_tool_calls(mut self, max_parallel_tool_calls: usize) -> Self {
+        self.max_parallel_tool_calls = Some(max_parallel_tool_calls);
         self
     }
 
@@ -370,6 +413,19 @@ impl AgentSettings {
         self
     }
 
+    /// Sets the strategy that selects which part of the loaded history is sent.
+    ///
+    /// Use it to keep long sessions within the model's context window, for example
+    /// with [`LastMessages`](crate::history::LastMessages) or
+    /// [`TokenBudget`](crate::history::TokenBudget).
+    ///
+    /// Requires the "storage" feature flag.
+    #[cfg(feature = "storage")]
+    pub fn with_history_strategy(mut self, strategy: Arc<dyn HistoryStrategy>) -> Self {
+        self.history_strategy = Some(strategy);
+        self
+    }
+
     /// Converts the agent settings to call settings.
     pub fn to_call_settings(&self) -> CallSettings {
         CallSettings {
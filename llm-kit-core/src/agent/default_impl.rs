@@ -1,9 +1,12 @@
 use crate::error::AISDKError;
 use crate::generate_text::{GenerateText, step_count_is};
+use crate::output::Output;
 use crate::prompt::{Prompt, PromptContent};
 use crate::stream_text::StreamText;
 use crate::tool::ToolSet;
 use async_trait::async_trait;
+use llm_kit_provider::language_model::call_options::LanguageModelResponseFormat;
+use llm_kit_provider::language_model::tool_choice::LanguageModelToolChoice;
 use std::sync::Arc;
 
 use super::agent_settings::AgentSettings;
@@ -90,6 +93,42 @@ impl Agent {
 
         Ok(prompt)
     }
+
+    /// Gets the response format and tool choice of a call.
+    ///
+    /// The output specification is mapped onto them unless they are set explicitly.
+    fn output_call_settings(
+        &self,
+    ) -> Result<
+        (
+            Option<LanguageModelResponseFormat>,
+            Option<LanguageModelToolChoice>,
+        ),
+        AISDKError,
+    > {
+        let (output_format, output_tool_choice) = match &self.settings.output {
+            None => (None, None),
+            Some(Output::Text(_)) => (Some(LanguageModelResponseFormat::Text), None),
+            Some(Output::ToolCall(tool_call)) => (
+                None,
+                Some(LanguageModelToolChoice::Tool {
+                    name: tool_call.tool_name.clone(),
+                }),
+            ),
+            Some(output) => {
+                return Err(AISDKError::invalid_argument(
+                    "output",
+                    format!("{:?}", output),
+                    "Only text and tool call outputs can be requested",
+                ));
+            }
+        };
+
+        Ok((
+            self.settings.response_format.clone().or(output_format),
+            self.settings.tool_choice.clone().or(output_tool_choice),
+        ))
+    }
 }
 
 impl AgentInterface for Agent {
@@ -105,14 +144,20 @@ impl AgentInterface for Agent {
 
     fn generate(&self, params: AgentCallParameters) -> Result<GenerateText, AISDKError> {
         let prompt = self.build_prompt(&params)?;
+        let (response_format, tool_choice) = self.output_call_settings()?;
         let mut builder = GenerateText::new(self.settings.model.clone(), prompt);
 
+        // Answer the tool approval requests of a previous call
+        if !params.approvals.is_empty() {
+            builder = builder.resume_with_approvals(params.approvals);
+        }
+
         // Apply tools from agent settings
         if let Some(tools) = &self.settings.tools {
             builder = builder.tools(tools.clone());
         }
-        if let Some(tool_choice) = &self.settings.tool_choice {
-            builder = builder.tool_choice(tool_choice.clone());
+        if let Some(tool_choice) = tool_choice {
+            builder = builder.tool_choice(tool_choice);
         }
 
         // Apply stop condition (default to step_count_is(20) if not provided)
@@ -141,12 +186,26 @@ impl AgentInterface for Agent {
             if let Some(session_id) = &self.settings.session_id {
                 builder = builder.with_session_id(session_id.clone());
             }
+            if let Some(history_strategy) = &self.settings.history_strategy {
+                builder = builder.with_history_strategy(history_strategy.clone());
+            }
         }
 
         // Apply other settings
         if let Some(prepare_step) = &self.settings.prepare_step {
             builder = builder.prepare_step(Box::new(ArcPrepareStepWrapper(prepare_step.clone())));
         }
+        if let Some(repair_tool_call) = &self.settings.experimental_repair_tool_call {
+            let repair_tool_call = Arc::clone(repair_tool_call);
+            builder = builder
+                .experimental_repair_tool_call(Box::new(move |options| repair_tool_call(options)));
+        }
+        if let Some(max_parallel_tool_calls) = self.settings.max_parallel_tool_calls {
+            builder = builder.max_parallel_tool_calls(max_parallel_tool_calls);
+        }
+        if let Some(response_format) = response_format {
+            builder = builder.with_response_format(response_format);
+        }
         if let Some(provider_options) = &self.settings.provider_options {
             builder = builder.provider_options(provider_options.clone());
         }
@@ -182,15 +241,24 @@ impl AgentInterface for Agent {
     }
 
     fn stream(&self, params: AgentCallParameters) -> Result<StreamText, AISDKError> {
+        if !params.approvals.is_empty() {
+            return Err(AISDKError::invalid_argument(
+                "approvals",
+                params.approvals.len(),
+                "Tool approvals can only be answered with `generate`",
+            ));
+        }
+
         let prompt = self.build_prompt(&params)?;
+        let (response_format, tool_choice) = self.output_call_settings()?;
         let mut builder = StreamText::new(self.settings.model.clone(), prompt);
 
         // Apply tools from agent settings
         if let Some(tools) = &self.settings.tools {
             builder = builder.tools(tools.clone());
         }
-        if let Some(tool_choice) = &self.settings.tool_choice {
-            builder = builder.tool_choice(tool_choice.clone());
+        if let Some(tool_choice) = tool_choice {
+            builder = builder.tool_choice(tool_choice);
         }
 
         // Apply stop condition (default to step_count_is(20) if not provided)
@@ -219,12 +287,26 @@ impl AgentInterface for Agent {
             if let Some(session_id) = &self.settings.session_id {
                 builder = builder.with_session_id(session_id.clone());
             }
+            if let Some(history_strategy) = &self.settings.history_strategy {
+                builder = builder.with_history_strategy(history_strategy.clone());
+            }
         }
 
         // Apply other settings
         if let Some(prepare_step) = &self.settings.prepare_step {
             builder = builder.prepare_step(Box::new(ArcPrepareStepWrapper(prepare_step.clone())));
         }
+        if let Some(repair_tool_call) = &self.settings.experimental_repair_tool_call {
+            let repair_tool_call = Arc::clone(repair_tool_call);
+            builder = builder
+                .experimental_repair_tool_call(Box::new(move |options| repair_tool_call(options)));
+        }
+        if let Some(max_parallel_tool_calls) = self.settings.max_parallel_tool_calls {
+            builder = builder.max_parallel_tool_calls(max_parallel_tool_calls);
+        }
+        if let Some(response_format) = response_format {
+            builder = builder.with_response_format(response_format);
+        }
         if let Some(provider_options) = &self.settings.provider_options {
             builder = builder.provider_options(provider_options.clone());
         }
@@ -288,8 +370,350 @@ mod tests {
     // Note: Full integration tests will require actual LanguageModel implementations.
     // These are basic structural tests.
 
+    use super::*;
+    use crate::output::{ReasoningOutput, TextOutput};
+    use llm_kit_provider::LanguageModel;
+    use llm_kit_provider::language_model::call_options::{
+        LanguageModelCallOptions, LanguageModelResponseFormat,
+    };
+    use llm_kit_provider::language_model::content::LanguageModelContent;
+    use llm_kit_provider::language_model::content::text::LanguageModelText;
+    use llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason;
+    use llm_kit_provider::language_model::stream_part::LanguageModelStreamPart;
+    use llm_kit_provider::language_model::usage::LanguageModelUsage;
+    use llm_kit_provider::language_model::{
+        LanguageModelGenerateResponse, LanguageModelStreamResponse,
+    };
+    use llm_kit_provider_utils::tool::ToolCall;
+    use regex::Regex;
+    use serde_json::json;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
     #[test]
     fn test_agent_can_be_created() {
         // This test will be expanded when we have mock models available
     }
+
+    struct JsonMockLanguageModel {
+        text: &'static str,
+        call_options: Mutex<Option<LanguageModelCallOptions>>,
+    }
+
+    impl JsonMockLanguageModel {
+        fn new(text: &'static str) -> Self {
+            Self {
+                text,
+                call_options: Mutex::new(None),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl LanguageModel for JsonMockLanguageModel {
+        fn provider(&self) -> &str {
+            "test-provider"
+        }
+
+        fn model_id(&self) -> &str {
+            "test-model"
+        }
+
+        async fn supported_urls(&self) -> HashMap<String, Vec<Regex>> {
+            HashMap::new()
+        }
+
+        async fn do_generate(
+            &self,
+            options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelGenerateResponse, Box<dyn std::error::Error>> {
+            *self.call_options.lock().unwrap() = Some(options);
+
+            Ok(LanguageModelGenerateResponse {
+                content: vec![LanguageModelContent::Text(LanguageModelText::new(
+                    self.text,
+                ))],
+                finish_reason: LanguageModelFinishReason::Stop,
+                usage: LanguageModelUsage::new(10, 20),
+                provider_metadata: None,
+                request: None,
+                response: None,
+                warnings: Vec::new(),
+            })
+        }
+
+        async fn do_stream(
+            &self,
+            options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelStreamResponse, Box<dyn std::error::Error>> {
+            *self.call_options.lock().unwrap() = Some(options);
+
+            let parts = vec![
+                LanguageModelStreamPart::text_start("1"),
+                LanguageModelStreamPart::text_delta("1", self.text),
+                LanguageModelStreamPart::text_end("1"),
+                LanguageModelStreamPart::finish(
+                    LanguageModelUsage::new(10, 20),
+                    LanguageModelFinishReason::Stop,
+                ),
+            ];
+            Ok(LanguageModelStreamResponse {
+                stream: Box::new(futures_util::stream::iter(parts)),
+                request: None,
+                response: None,
+            })
+        }
+    }
+
+    fn json_format() -> LanguageModelResponseFormat {
+        LanguageModelResponseFormat::Json {
+            schema: Some(json!({ "type": "object" })),
+            name: None,
+            description: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_agent_generate_parses_structured_output() {
+        let model = Arc::new(JsonMockLanguageModel::new(r#"{"city": "Paris"}"#));
+        let agent =
+            Agent::new(AgentSettings::new(model.clone()).with_response_format(json_format()));
+
+        let result = agent
+            .generate(AgentCallParameters::from_text("Where is the Louvre?"))
+            .unwrap()
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.output().unwrap(), Some(json!({ "city": "Paris" })));
+        let options = model.call_options.lock().unwrap().take().unwrap();
+        assert!(matches!(
+            options.response_format,
+            Some(LanguageModelResponseFormat::Json { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_agent_generate_keeps_result_when_output_is_invalid() {
+        let model = Arc::new(JsonMockLanguageModel::new("The Louvre is in Paris."));
+        let agent = Agent::new(AgentSettings::new(model).with_response_format(json_format()));
+
+        let result = agent
+            .generate(AgentCallParameters::from_text("Where is the Louvre?"))
+            .unwrap()
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.text, "The Louvre is in Paris.");
+        assert!(matches!(
+            result.output(),
+            Err(AISDKError::NoObjectGenerated { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_agent_generate_applies_output() {
+        let model = Arc::new(JsonMockLanguageModel::new("Paris"));
+        let agent = Agent::new(
+            AgentSettings::new(model.clone()).with_output(Output::Text(TextOutput::new(""))),
+        );
+
+        agent
+            .generate(AgentCallParameters::from_text("Where is the Louvre?"))
+            .unwrap()
+            .execute()
+            .await
+            .unwrap();
+
+        let options = model.call_options.lock().unwrap().take().unwrap();
+        assert_eq!(
+            options.response_format,
+            Some(LanguageModelResponseFormat::Text)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_agent_stream_applies_output() {
+        let model = Arc::new(JsonMockLanguageModel::new("Paris"));
+        let agent = Agent::new(
+            AgentSettings::new(model.clone()).with_output(Output::Text(TextOutput::new(""))),
+        );
+
+        let result = agent
+            .stream(AgentCallParameters::from_text("Where is the Louvre?"))
+            .unwrap()
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.text().await.unwrap(), "Paris");
+        let options = model.call_options.lock().unwrap().take().unwrap();
+        assert_eq!(
+            options.response_format,
+            Some(LanguageModelResponseFormat::Text)
+        );
+    }
+
+    #[test]
+    fn test_agent_maps_tool_call_output_to_tool_choice() {
+        let model = Arc::new(JsonMockLanguageModel::new(""));
+        let output = Output::ToolCall(ToolCall::new("call_1", "weather", json!({})));
+        let agent = Agent::new(AgentSettings::new(model.clone()).with_output(output.clone()));
+
+        let (response_format, tool_choice) = agent.output_call_settings().unwrap();
+        assert_eq!(response_format, None);
+        assert_eq!(
+            tool_choice,
+            Some(LanguageModelToolChoice::Tool {
+                name: "weather".to_string()
+            })
+        );
+
+        // An explicit tool choice takes precedence
+        let agent = Agent::new(
+            AgentSettings::new(model)
+                .with_output(output)
+                .with_tool_choice(LanguageModelToolChoice::Auto),
+        );
+        let (_, tool_choice) = agent.output_call_settings().unwrap();
+        assert_eq!(tool_choice, Some(LanguageModelToolChoice::Auto));
+    }
+
+    #[test]
+    fn test_agent_rejects_unsupported_output() {
+        let model = Arc::new(JsonMockLanguageModel::new(""));
+        let output = Output::Reasoning(ReasoningOutput::new("Thinking"));
+        let agent = Agent::new(AgentSettings::new(model).with_output(output));
+
+        assert!(matches!(
+            agent.generate(AgentCallParameters::from_text("Hi")),
+            Err(AISDKError::InvalidArgument { .. })
+        ));
+        assert!(matches!(
+            agent.stream(AgentCallParameters::from_text("Hi")),
+            Err(AISDKError::InvalidArgument { .. })
+        ));
+    }
+
+    // Mock LanguageModel that calls `delete_file`, then answers once the tool output is sent
+    struct ApprovalMockLanguageModel;
+
+    #[async_trait]
+    impl LanguageModel for ApprovalMockLanguageModel {
+        fn provider(&self) -> &str {
+            "test-provider"
+        }
+
+        fn model_id(&self) -> &str {
+            "test-model"
+        }
+
+        async fn supported_urls(&self) -> HashMap<String, Vec<Regex>> {
+            HashMap::new()
+        }
+
+        async fn do_generate(
+            &self,
+            options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelGenerateResponse, Box<dyn std::error::Error>> {
+            use llm_kit_provider::language_model::content::tool_call::LanguageModelToolCall;
+            use llm_kit_provider::language_model::prompt::LanguageModelMessage;
+
+            let (content, finish_reason) = match options.prompt.last() {
+                Some(LanguageModelMessage::Tool(_)) => (
+                    vec![LanguageModelContent::Text(LanguageModelText::new("Done"))],
+                    LanguageModelFinishReason::Stop,
+                ),
+                _ => (
+                    vec![LanguageModelContent::ToolCall(LanguageModelToolCall::new(
+                        "call_1",
+                        "delete_file",
+                        r#"{"path": "a.txt"}"#,
+                    ))],
+                    LanguageModelFinishReason::ToolCalls,
+                ),
+            };
+
+            Ok(LanguageModelGenerateResponse {
+                content,
+                finish_reason,
+                usage: LanguageModelUsage::default(),
+                provider_metadata: None,
+                request: None,
+                response: None,
+                warnings: Vec::new(),
+            })
+        }
+
+        async fn do_stream(
+            &self,
+            _options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelStreamResponse, Box<dyn std::error::Error>> {
+            unimplemented!("Mock implementation")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_agent_tool_approval_round_trip() {
+        use llm_kit_provider_utils::message::{Message, UserMessage};
+        use llm_kit_provider_utils::tool::{Tool, ToolApprovalResponse, ToolExecutionOutput};
+
+        let deleted = Arc::new(Mutex::new(Vec::new()));
+        let tool_deleted = Arc::clone(&deleted);
+        let mut tools = ToolSet::new();
+        tools.insert(
+            "delete_file".to_string(),
+            Tool::function(json!({ "type": "object" }))
+                .with_needs_approval(true)
+                .with_execute(Arc::new(move |input: serde_json::Value, _options| {
+                    let deleted = Arc::clone(&tool_deleted);
+                    ToolExecutionOutput::Single(Box::pin(async move {
+                        deleted.lock().unwrap().push(input["path"].to_string());
+                        Ok(json!({ "deleted": true }))
+                    }))
+                })),
+        );
+        let agent =
+            Agent::new(AgentSettings::new(Arc::new(ApprovalMockLanguageModel)).with_tools(tools));
+        let mut messages = vec![Message::User(UserMessage::new("Delete a.txt"))];
+
+        let result = agent
+            .generate(AgentCallParameters::from_messages(messages.clone()))
+            .unwrap()
+            .execute()
+            .await
+            .unwrap();
+
+        // The call waits for approval instead of being executed
+        assert!(deleted.lock().unwrap().is_empty());
+        let approvals: Vec<_> = result
+            .pending_approvals()
+            .iter()
+            .map(|request| ToolApprovalResponse::granted(request.approval_id.clone()))
+            .collect();
+        assert_eq!(approvals.len(), 1);
+        messages.extend(result.response.messages.into_iter().map(Message::from));
+
+        // Streaming cannot answer approvals
+        assert!(matches!(
+            agent.stream(
+                AgentCallParameters::from_messages(messages.clone())
+                    .with_approvals(approvals.clone())
+            ),
+            Err(AISDKError::InvalidArgument { .. })
+        ));
+
+        let result = agent
+            .generate(AgentCallParameters::from_messages(messages).with_approvals(approvals))
+            .unwrap()
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(*deleted.lock().unwrap(), vec![r#""a.txt""#.to_string()]);
+        assert_eq!(result.text, "Done");
+        assert!(result.pending_approvals().is_empty());
+    }
 }
@@ -3,6 +3,7 @@ use crate::prompt::PromptContent;
 use crate::stream_text::StreamText;
 use crate::tool::ToolSet;
 use llm_kit_provider_utils::message::Message;
+use llm_kit_provider_utils::tool::ToolApprovalResponse;
 
 /// Parameters for calling an agent.
 ///
@@ -27,12 +28,20 @@ use llm_kit_provider_utils::message::Message;
 pub struct AgentCallParameters {
     /// The prompt content - either text or messages.
     pub prompt: PromptContent,
+
+    /// Answers to the tool approval requests of a previous call.
+    ///
+    /// See [`with_approvals`](Self::with_approvals).
+    pub approvals: Vec<ToolApprovalResponse>,
 }
 
 impl AgentCallParameters {
     /// Creates new agent call parameters with the given prompt content.
     pub fn new(prompt: PromptContent) -> Self {
-        Self { prompt }
+        Self {
+            prompt,
+            approvals: Vec::new(),
+        }
     }
 
     /// Creates parameters with a text prompt.
@@ -45,9 +54,7 @@ impl AgentCallParameters {
     /// let params = AgentCallParameters::from_text("What is the weather?");
     /// ```
     pub fn from_text(text: impl Into<String>) -> Self {
-        Self {
-            prompt: PromptContent::Text { text: text.into() },
-        }
+        Self::new(PromptContent::Text { text: text.into() })
     }
 
     /// Creates parameters with messages.
@@ -62,9 +69,48 @@ impl AgentCallParameters {
     /// let params = AgentCallParameters::from_messages(messages);
     /// ```
     pub fn from_messages(messages: Vec<Message>) -> Self {
-        Self {
-            prompt: PromptContent::Messages { messages },
-        }
+        Self::new(PromptContent::Messages { messages })
+    }
+
+    /// Sets the answers to the tool approval requests of a previous call.
+    ///
+    /// When a call stops with
+    /// [`pending_approvals`](crate::GenerateTextResult::pending_approvals), call the
+    /// agent again with the previous messages, the response messages and the answers.
+    /// Approved tool calls are executed and denied tool calls are reported to the model
+    /// before it is called again. Only supported by
+    /// [`generate`](AgentInterface::generate).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use llm_kit_core::agent::{AgentCallParameters, AgentInterface};
+    /// use llm_kit_provider_utils::message::Message;
+    /// use llm_kit_provider_utils::tool::ToolApprovalResponse;
+    /// # async fn example(agent: &impl AgentInterface, mut messages: Vec<Message>) -> Result<(), Box<dyn std::error::Error>> {
+    ///
+    /// let result = agent
+    ///     .generate(AgentCallParameters::from_messages(messages.clone()))?
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// let approvals = result
+    ///     .pending_approvals()
+    ///     .iter()
+    ///     .map(|request| ToolApprovalResponse::granted(request.approval_id.clone()))
+    ///     .collect();
+    ///
+    /// messages.extend(result.response.messages.into_iter().map(Message::from));
+    /// let result = agent
+    ///     .generate(AgentCallParameters::from_messages(messages).with_approvals(approvals))?
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_approvals(mut self, approvals: Vec<ToolApprovalResponse>) -> Self {
+        self.approvals = approvals;
+        self
     }
 }
 
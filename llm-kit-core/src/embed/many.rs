@@ -1,6 +1,6 @@
 use crate::embed::many_result::{EmbedManyResult, EmbedManyResultResponseData};
 use crate::error::AISDKError;
-use crate::generate_text::{RetryConfig, prepare_retries};
+use crate::generate_text::{RetryPolicy, prepare_retries};
 use llm_kit_provider::embedding_model::call_options::EmbeddingModelCallOptions;
 use llm_kit_provider::embedding_model::embedding::EmbeddingModelEmbedding;
 use llm_kit_provider::embedding_model::{EmbeddingModel, EmbeddingModelUsage};
@@ -47,6 +47,7 @@ where
     model: Arc<dyn EmbeddingModel<V>>,
     values: Vec<V>,
     max_retries: Option<u32>,
+    retry_policy: Option<Arc<dyn RetryPolicy>>,
     abort_signal: Option<CancellationToken>,
     headers: Option<SharedHeaders>,
     provider_options: Option<SharedProviderOptions>,
@@ -68,6 +69,7 @@ where
             model,
             values,
             max_retries: None,
+            retry_policy: None,
             abort_signal: None,
             headers: None,
             provider_options: None,
@@ -85,6 +87,16 @@ where
         self
     }
 
+    /// Set the policy that decides whether and when failed calls are retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `retry_policy` - The retry policy. Default: [`DefaultRetryPolicy`](crate::generate_text::DefaultRetryPolicy).
+    pub fn retry_policy(mut self, retry_policy: Arc<dyn RetryPolicy>) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
     /// Set an abort signal that can be used to cancel the call.
     ///
     /// # Arguments
@@ -132,7 +144,11 @@ where
     /// A result object that contains the embeddings, the values, and additional information.
     pub async fn execute(self) -> Result<EmbedManyResult<V>, AISDKError> {
         // Prepare retry configuration
-        let retry_config = prepare_retries(self.max_retries, self.abort_signal.clone())?;
+        let retry_config = prepare_retries(
+            self.max_retries,
+            self.abort_signal.clone(),
+            self.retry_policy.clone(),
+        )?;
 
         // Add user agent to headers
         let headers_with_user_agent =
@@ -204,17 +220,12 @@ where
 
             for chunk in parallel_chunk {
                 let model = self.model.clone();
-                let max_retries = retry_config.max_retries;
+                let retry_config = retry_config.clone();
                 let abort_signal_clone = self.abort_signal.clone();
                 let headers = headers_with_user_agent.clone();
                 let provider_options = self.provider_options.clone();
 
                 let future = async move {
-                    let retry_config = RetryConfig {
-                        max_retries,
-                        abort_signal: abort_signal_clone.clone(),
-                    };
-
                     retry_config
                         .execute_with_boxed_error(move || {
                             let model = model.clone();
@@ -1,6 +1,6 @@
 use crate::embed::result::{EmbedResult, EmbedResultResponseData};
 use crate::error::AISDKError;
-use crate::generate_text::prepare_retries;
+use crate::generate_text::{RetryPolicy, prepare_retries};
 use llm_kit_provider::embedding_model::call_options::EmbeddingModelCallOptions;
 use llm_kit_provider::embedding_model::{EmbeddingModel, EmbeddingModelUsage};
 use llm_kit_provider::shared::headers::SharedHeaders;
@@ -37,6 +37,7 @@ where
     model: Arc<dyn EmbeddingModel<V>>,
     value: V,
     max_retries: Option<u32>,
+    retry_policy: Option<Arc<dyn RetryPolicy>>,
     abort_signal: Option<CancellationToken>,
     headers: Option<SharedHeaders>,
     provider_options: Option<SharedProviderOptions>,
@@ -57,6 +58,7 @@ where
             model,
             value,
             max_retries: None,
+            retry_policy: None,
             abort_signal: None,
             headers: None,
             provider_options: None,
@@ -73,6 +75,16 @@ where
         self
     }
 
+    /// Set the policy that decides whether and when failed calls are retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `retry_policy` - The retry policy. Default: [`DefaultRetryPolicy`](crate::generate_text::DefaultRetryPolicy).
+    pub fn retry_policy(mut self, retry_policy: Arc<dyn RetryPolicy>) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
     /// Set an abort signal that can be used to cancel the call.
     ///
     /// # Arguments
@@ -110,7 +122,11 @@ where
     /// A result object that contains the embedding, the value, and additional information.
     pub async fn execute(self) -> Result<EmbedResult<V>, AISDKError> {
         // Prepare retry configuration
-        let retry_config = prepare_retries(self.max_retries, self.abort_signal.clone())?;
+        let retry_config = prepare_retries(
+            self.max_retries,
+            self.abort_signal.clone(),
+            self.retry_policy.clone(),
+        )?;
 
         // Add user agent to headers
         let headers_with_user_agent =
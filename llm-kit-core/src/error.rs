@@ -1,12 +1,14 @@
 use std::time::Duration;
 use thiserror::Error;
 
+mod download;
 mod invalid_argument;
 mod invalid_prompt;
 mod invalid_stream_part;
 mod invalid_tool_input;
 mod model_error;
 mod no_image_generated;
+mod no_object_generated;
 mod no_output_generated;
 mod no_speech_generated;
 mod no_such_tool;
@@ -15,12 +17,14 @@ mod retryable_error;
 mod unsupported_model_version;
 
 // Re-export builders for public API
+pub use download::DownloadErrorBuilder;
 pub use invalid_argument::InvalidArgumentErrorBuilder;
 pub use invalid_prompt::InvalidPromptErrorBuilder;
 pub use invalid_stream_part::InvalidStreamPartErrorBuilder;
 pub use invalid_tool_input::InvalidToolInputErrorBuilder;
 pub use model_error::ModelErrorBuilder;
 pub use no_image_generated::NoImageGeneratedErrorBuilder;
+pub use no_object_generated::NoObjectGeneratedErrorBuilder;
 pub use no_output_generated::NoOutputGeneratedErrorBuilder;
 pub use no_speech_generated::NoSpeechGeneratedErrorBuilder;
 pub use no_such_tool::NoSuchToolErrorBuilder;
@@ -165,6 +169,27 @@ pub enum AISDKError {
         responses: Option<Vec<crate::generate_image::ImageModelResponseMetadata>>,
     },
 
+    /// A no object generated error.
+    ///
+    /// This error occurs when object generation fails. This can have multiple causes:
+    /// - The model failed to generate a response.
+    /// - The model generated a response that could not be parsed.
+    /// - The model generated a response that does not match the schema.
+    #[error("No object generated: {message}")]
+    NoObjectGenerated {
+        /// The error message
+        message: String,
+        /// The raw text generated by the model (if any)
+        text: Option<String>,
+        /// Response metadata of the model call (if available)
+        response: Option<Box<crate::generate_text::StepResponseMetadata>>,
+        /// Token usage of the model call (if available)
+        usage: Option<llm_kit_provider::language_model::usage::LanguageModelUsage>,
+        /// Reason why the model finished generating (if available)
+        finish_reason:
+            Option<llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason>,
+    },
+
     /// A no output generated error.
     ///
     /// This error occurs when no LLM output was generated, e.g. because of errors.
@@ -211,4 +236,19 @@ pub enum AISDKError {
         /// The model identifier
         model_id: String,
     },
+
+    /// A download error.
+    ///
+    /// This error occurs when a URL in the prompt that the model doesn't support
+    /// could not be downloaded, e.g. because the request failed or the content
+    /// exceeded the size limit.
+    #[error("Failed to download {url}: {message}")]
+    Download {
+        /// The URL that could not be downloaded
+        url: String,
+        /// The HTTP status code of the response (if available)
+        status_code: Option<u16>,
+        /// The error message
+        message: String,
+    },
 }
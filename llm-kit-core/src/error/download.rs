@@ -0,0 +1,135 @@
+use crate::error::AISDKError;
+
+/// Builder for [`AISDKError::Download`].
+///
+/// # Examples
+///
+/// ```
+/// use llm_kit_core::error::{AISDKError, DownloadErrorBuilder};
+///
+/// let error = DownloadErrorBuilder::new("https://example.com/image.png")
+///     .status_code(404)
+///     .message("HTTP 404 Not Found")
+///     .build();
+///
+/// match error {
+///     AISDKError::Download { url, status_code, .. } => {
+///         assert_eq!(url, "https://example.com/image.png");
+///         assert_eq!(status_code, Some(404));
+///     }
+///     _ => panic!("Expected Download"),
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DownloadErrorBuilder {
+    url: String,
+    status_code: Option<u16>,
+    message: Option<String>,
+}
+
+impl DownloadErrorBuilder {
+    /// Creates a new builder for a download error of the given URL.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            status_code: None,
+            message: None,
+        }
+    }
+
+    /// Sets the HTTP status code of the response.
+    pub fn status_code(mut self, status_code: u16) -> Self {
+        self.status_code = Some(status_code);
+        self
+    }
+
+    /// Sets the error message.
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Builds the [`AISDKError::Download`] error.
+    ///
+    /// If no custom message is provided, the message is derived from the status code.
+    pub fn build(self) -> AISDKError {
+        let message = self.message.unwrap_or_else(|| match self.status_code {
+            Some(status_code) => format!("HTTP {}", status_code),
+            None => "Download failed".to_string(),
+        });
+
+        AISDKError::Download {
+            url: self.url,
+            status_code: self.status_code,
+            message,
+        }
+    }
+}
+
+impl AISDKError {
+    /// Creates a new download error.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL that could not be downloaded
+    /// * `message` - Description of why the download failed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use llm_kit_core::error::AISDKError;
+    ///
+    /// let error = AISDKError::download("https://example.com/doc.pdf", "connection refused");
+    ///
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "Failed to download https://example.com/doc.pdf: connection refused"
+    /// );
+    /// ```
+    pub fn download(url: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::Download {
+            url: url.into(),
+            status_code: None,
+            message: message.into(),
+        }
+    }
+
+    /// Creates a builder for a download error of the given URL.
+    pub fn download_builder(url: impl Into<String>) -> DownloadErrorBuilder {
+        DownloadErrorBuilder::new(url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_download_builder_default_message() {
+        let error = AISDKError::download_builder("https://example.com/a.png")
+            .status_code(403)
+            .build();
+
+        match error {
+            AISDKError::Download {
+                url,
+                status_code,
+                message,
+            } => {
+                assert_eq!(url, "https://example.com/a.png");
+                assert_eq!(status_code, Some(403));
+                assert_eq!(message, "HTTP 403");
+            }
+            _ => panic!("Expected Download"),
+        }
+    }
+
+    #[test]
+    fn test_download_display() {
+        let error = AISDKError::download("https://example.com/a.png", "too large");
+        assert_eq!(
+            error.to_string(),
+            "Failed to download https://example.com/a.png: too large"
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use crate::error::AISDKError;
+use crate::generate_text::StepResponseMetadata;
+use llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason;
+use llm_kit_provider::language_model::usage::LanguageModelUsage;
+
+/// Builder for [`AISDKError::NoObjectGenerated`].
+///
+/// # Examples
+///
+/// ```
+/// use llm_kit_core::error::{AISDKError, NoObjectGeneratedErrorBuilder};
+///
+/// let error = NoObjectGeneratedErrorBuilder::new()
+///     .message("Response did not match schema")
+///     .text(r#"{"name": 42}"#)
+///     .build();
+///
+/// match error {
+///     AISDKError::NoObjectGenerated { message, text, .. } => {
+///         assert_eq!(message, "Response did not match schema");
+///         assert_eq!(text.as_deref(), Some(r#"{"name": 42}"#));
+///     }
+///     _ => panic!("Expected NoObjectGenerated"),
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NoObjectGeneratedErrorBuilder {
+    message: Option<String>,
+    text: Option<String>,
+    response: Option<StepResponseMetadata>,
+    usage: Option<LanguageModelUsage>,
+    finish_reason: Option<LanguageModelFinishReason>,
+}
+
+impl NoObjectGeneratedErrorBuilder {
+    /// Creates a new builder for a no object generated error.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the error message.
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Sets the raw text generated by the model.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Sets the response metadata of the model call.
+    pub fn response(mut self, response: StepResponseMetadata) -> Self {
+        self.response = Some(response);
+        self
+    }
+
+    /// Sets the token usage of the model call.
+    pub fn usage(mut self, usage: LanguageModelUsage) -> Self {
+        self.usage = Some(usage);
+        self
+    }
+
+    /// Sets the reason why the model finished generating.
+    pub fn finish_reason(mut self, finish_reason: LanguageModelFinishReason) -> Self {
+        self.finish_reason = Some(finish_reason);
+        self
+    }
+
+    /// Builds the [`AISDKError::NoObjectGenerated`] error.
+    ///
+    /// If no custom message is provided, defaults to "No object generated."
+    pub fn build(self) -> AISDKError {
+        AISDKError::NoObjectGenerated {
+            message: self
+                .message
+                .unwrap_or_else(|| "No object generated.".to_string()),
+            text: self.text,
+            response: self.response.map(Box::new),
+            usage: self.usage,
+            finish_reason: self.finish_reason,
+        }
+    }
+}
+
+impl AISDKError {
+    /// Creates a new no object generated error with a custom message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use llm_kit_core::error::AISDKError;
+    ///
+    /// let error = AISDKError::no_object_generated("No JSON found in the response");
+    ///
+    /// match error {
+    ///     AISDKError::NoObjectGenerated { message, text, .. } => {
+    ///         assert_eq!(message, "No JSON found in the response");
+    ///         assert!(text.is_none());
+    ///     }
+    ///     _ => panic!("Expected NoObjectGenerated"),
+    /// }
+    /// ```
+    pub fn no_object_generated(message: impl Into<String>) -> Self {
+        NoObjectGeneratedErrorBuilder::new()
+            .message(message)
+            .build()
+    }
+
+    /// Creates a builder for a no object generated error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use llm_kit_core::error::AISDKError;
+    ///
+    /// let error = AISDKError::no_object_generated_builder()
+    ///     .message("Could not parse the response")
+    ///     .text("not json")
+    ///     .build();
+    /// ```
+    pub fn no_object_generated_builder() -> NoObjectGeneratedErrorBuilder {
+        NoObjectGeneratedErrorBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_object_generated_default_message() {
+        let error = NoObjectGeneratedErrorBuilder::new().build();
+
+        match error {
+            AISDKError::NoObjectGenerated {
+                message,
+                text,
+                response,
+                usage,
+                finish_reason,
+            } => {
+                assert_eq!(message, "No object generated.");
+                assert!(text.is_none());
+                assert!(response.is_none());
+                assert!(usage.is_none());
+                assert!(finish_reason.is_none());
+            }
+            _ => panic!("Expected NoObjectGenerated"),
+        }
+    }
+
+    #[test]
+    fn test_no_object_generated_builder_all_fields() {
+        let error = AISDKError::no_object_generated_builder()
+            .message("Response did not match schema")
+            .text("{}")
+            .response(StepResponseMetadata {
+                id: Some("resp_1".to_string()),
+                ..Default::default()
+            })
+            .usage(LanguageModelUsage::new(10, 20))
+            .finish_reason(LanguageModelFinishReason::Stop)
+            .build();
+
+        match error {
+            AISDKError::NoObjectGenerated {
+                message,
+                text,
+                response,
+                usage,
+                finish_reason,
+            } => {
+                assert_eq!(message, "Response did not match schema");
+                assert_eq!(text.as_deref(), Some("{}"));
+                assert_eq!(response.unwrap().id.as_deref(), Some("resp_1"));
+                assert_eq!(usage.unwrap().output_tokens, 20);
+                assert_eq!(finish_reason, Some(LanguageModelFinishReason::Stop));
+            }
+            _ => panic!("Expected NoObjectGenerated"),
+        }
+    }
+
+    #[test]
+    fn test_no_object_generated_display() {
+        let error = AISDKError::no_object_generated("Could not parse the response");
+        let display = format!("{}", error);
+        assert!(display.contains("No object generated"));
+        assert!(display.contains("Could not parse the response"));
+    }
+}
@@ -4,7 +4,8 @@ pub mod result;
 pub use result::{GenerateImageResult, ImageModelResponseMetadata};
 
 use crate::error::AISDKError;
-use crate::generate_text::{GeneratedFile, prepare_retries};
+use crate::generate_text::{GeneratedFile, RetryPolicy, prepare_retries};
+use crate::prompt::download::detect_media_type;
 use llm_kit_provider::image_model::call_options::{AspectRatio, ImageModelCallOptions, ImageSize};
 use llm_kit_provider::image_model::call_warning::ImageModelCallWarning;
 use llm_kit_provider::image_model::{ImageData, ImageModel, ImageModelProviderMetadata};
@@ -49,6 +50,7 @@ pub struct GenerateImage {
     seed: Option<u32>,
     provider_options: Option<SharedProviderOptions>,
     max_retries: Option<u32>,
+    retry_policy: Option<Arc<dyn RetryPolicy>>,
     abort_signal: Option<CancellationToken>,
     headers: Option<SharedHeaders>,
 }
@@ -71,6 +73,7 @@ impl GenerateImage {
             seed: None,
             provider_options: None,
             max_retries: None,
+            retry_policy: None,
             abort_signal: None,
             headers: None,
         }
@@ -146,6 +149,16 @@ impl GenerateImage {
         self
     }
 
+    /// Set the policy that decides whether and when failed calls are retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `retry_policy` - The retry policy. Default: [`DefaultRetryPolicy`](crate::generate_text::DefaultRetryPolicy).
+    pub fn retry_policy(mut self, retry_policy: Arc<dyn RetryPolicy>) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
     /// Set an abort signal that can be used to cancel the call.
     ///
     /// # Arguments
@@ -208,7 +221,11 @@ impl GenerateImage {
             add_user_agent_suffix(self.headers, format!("ai/{}", VERSION));
 
         // Prepare retry configuration
-        let retry_config = prepare_retries(self.max_retries, self.abort_signal.clone())?;
+        let retry_config = prepare_retries(
+            self.max_retries,
+            self.abort_signal.clone(),
+            self.retry_policy.clone(),
+        )?;
 
         // Get max images per call from model or use provided value
         let max_images_per_call_with_default = if let Some(max) = self.max_images_per_call {
@@ -247,15 +264,9 @@ impl GenerateImage {
             let prompt = self.prompt.clone();
             let headers = headers_with_user_agent.clone();
             let provider_options = self.provider_options.clone();
-            let max_retries = retry_config.max_retries;
-            let abort_signal_clone = self.abort_signal.clone();
+            let retry_config = retry_config.clone();
 
             let future = async move {
-                let retry_config = crate::generate_text::RetryConfig {
-                    max_retries,
-                    abort_signal: abort_signal_clone,
-                };
-
                 retry_config
                     .execute_with_boxed_error(move || {
                         let model = model.clone();
@@ -365,36 +376,13 @@ fn detect_image_media_type(image_data: &ImageData) -> String {
 }
 
 /// Detect media type from raw bytes based on magic bytes.
+///
+/// Defaults to PNG when the bytes are not a known image format.
 fn detect_media_type_from_bytes(bytes: &[u8]) -> String {
-    if bytes.len() < 4 {
-        return "image/png".to_string();
-    }
-
-    // Check PNG signature
-    if bytes.starts_with(&[0x89, 0x50, 0x4E, 0x47]) {
-        return "image/png".to_string();
-    }
-
-    // Check JPEG signature
-    if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
-        return "image/jpeg".to_string();
-    }
-
-    // Check WebP signature
-    if bytes.len() >= 12
-        && bytes[0..4] == [0x52, 0x49, 0x46, 0x46]
-        && bytes[8..12] == [0x57, 0x45, 0x42, 0x50]
-    {
-        return "image/webp".to_string();
-    }
-
-    // Check GIF signature
-    if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
-        return "image/gif".to_string();
-    }
-
-    // Default to PNG
-    "image/png".to_string()
+    detect_media_type(bytes)
+        .filter(|media_type| media_type.starts_with("image/"))
+        .unwrap_or("image/png")
+        .to_string()
 }
 
 /// Add a user agent suffix to headers.
@@ -0,0 +1,525 @@
+/// Result type for object generation.
+pub mod generate_object_result;
+/// Modes for requesting objects from a language model.
+pub mod object_generation_mode;
+/// JSON schema derivation and object validation.
+pub mod validate_object;
+
+pub use generate_object_result::GenerateObjectResult;
+pub use object_generation_mode::{JSON_TOOL_NAME, ObjectGenerationMode};
+pub use validate_object::object_schema;
+
+use crate::error::AISDKError;
+use crate::generate_text::{RequestMetadata, RetryPolicy, StepResponseMetadata, prepare_retries};
+use crate::prompt::{
+    Prompt,
+    call_settings::CallSettings,
+    call_settings::prepare_call_settings,
+    convert_to_language_model_prompt::convert_to_language_model_prompt,
+    download::{Downloader, PromptDownloads},
+    standardize::validate_and_standardize,
+};
+use llm_kit_provider::language_model::call_options::LanguageModelCallOptions;
+use llm_kit_provider::language_model::content::LanguageModelContent;
+use llm_kit_provider::language_model::{LanguageModel, LanguageModelGenerateResponse};
+use llm_kit_provider::shared::provider_options::SharedProviderOptions;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+use validate_object::parse_and_validate_object;
+
+/// Builder for generating a typed, schema-validated object with a language model.
+///
+/// The JSON schema is derived from `T` with `schemars`. Depending on the
+/// [`ObjectGenerationMode`], it is sent as a JSON response format or as the input
+/// schema of a forced tool call. The response is validated against the schema
+/// before it is deserialized into `T`.
+///
+/// # Example
+///
+/// ```no_run
+/// use llm_kit_core::GenerateObject;
+/// use llm_kit_core::prompt::Prompt;
+/// use schemars::JsonSchema;
+/// use serde::Deserialize;
+/// # use std::sync::Arc;
+/// # use llm_kit_provider::LanguageModel;
+/// # async fn example(model: Arc<dyn LanguageModel>) -> Result<(), Box<dyn std::error::Error>> {
+///
+/// #[derive(Debug, Deserialize, JsonSchema)]
+/// struct Recipe {
+///     name: String,
+///     ingredients: Vec<String>,
+/// }
+///
+/// let result = GenerateObject::<Recipe>::new(model, Prompt::text("Generate a lasagna recipe"))
+///     .schema_name("recipe")
+///     .temperature(0.3)
+///     .execute()
+///     .await?;
+///
+/// println!("{:?}", result.object);
+/// # Ok(())
+/// # }
+/// ```
+pub struct GenerateObject<T> {
+    model: Arc<dyn LanguageModel>,
+    prompt: Prompt,
+    settings: CallSettings,
+    retry_policy: Option<Arc<dyn RetryPolicy>>,
+    downloader: Option<Arc<dyn Downloader>>,
+    mode: ObjectGenerationMode,
+    schema_name: Option<String>,
+    schema_description: Option<String>,
+    provider_options: Option<SharedProviderOptions>,
+    _object: PhantomData<fn() -> T>,
+}
+
+impl<T> GenerateObject<T>
+where
+    T: JsonSchema + DeserializeOwned,
+{
+    /// Creates a new builder with the required model and prompt.
+    pub fn new(model: Arc<dyn LanguageModel>, prompt: Prompt) -> Self {
+        Self {
+            model,
+            prompt,
+            settings: CallSettings::default(),
+            retry_policy: None,
+            downloader: None,
+            mode: ObjectGenerationMode::default(),
+            schema_name: None,
+            schema_description: None,
+            provider_options: None,
+            _object: PhantomData,
+        }
+    }
+
+    /// Sets the complete call settings.
+    pub fn settings(mut self, settings: CallSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Sets the temperature for generation.
+    pub fn temperature(mut self, temperature: f64) -> Self {
+        self.settings = self.settings.with_temperature(temperature);
+        self
+    }
+
+    /// Sets the maximum number of output tokens.
+    pub fn max_output_tokens(mut self, max_tokens: u32) -> Self {
+        self.settings = self.settings.with_max_output_tokens(max_tokens);
+        self
+    }
+
+    /// Sets the top_p value for nucleus sampling.
+    pub fn top_p(mut self, top_p: f64) -> Self {
+        self.settings = self.settings.with_top_p(top_p);
+        self
+    }
+
+    /// Sets the top_k value for sampling.
+    pub fn top_k(mut self, top_k: u32) -> Self {
+        self.settings = self.settings.with_top_k(top_k);
+        self
+    }
+
+    /// Sets the seed for deterministic generation.
+    pub fn seed(mut self, seed: u32) -> Self {
+        self.settings = self.settings.with_seed(seed);
+        self
+    }
+
+    /// Sets the maximum number of retries.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.settings = self.settings.with_max_retries(max_retries);
+        self
+    }
+
+    /// Sets the policy that decides whether and when failed model calls are retried.
+    ///
+    /// Defaults to [`DefaultRetryPolicy`](crate::generate_text::DefaultRetryPolicy).
+    pub fn retry_policy(mut self, retry_policy: Arc<dyn RetryPolicy>) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Sets the downloader for prompt URLs the model doesn't support.
+    ///
+    /// Defaults to [`DefaultDownloader`](crate::prompt::download::DefaultDownloader).
+    pub fn downloader(mut self, downloader: Arc<dyn Downloader>) -> Self {
+        self.downloader = Some(downloader);
+        self
+    }
+
+    /// Sets custom HTTP headers.
+    pub fn headers(mut self, headers: std::collections::HashMap<String, String>) -> Self {
+        self.settings = self.settings.with_headers(headers);
+        self
+    }
+
+    /// Sets the abort signal for cancellation.
+    pub fn abort_signal(mut self, signal: CancellationToken) -> Self {
+        self.settings = self.settings.with_abort_signal(signal);
+        self
+    }
+
+    /// Sets how the object is requested from the model.
+    ///
+    /// Defaults to [`ObjectGenerationMode::Auto`].
+    pub fn mode(mut self, mode: ObjectGenerationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the name of the output that should be generated.
+    ///
+    /// Used by some providers for additional LLM guidance.
+    pub fn schema_name(mut self, name: impl Into<String>) -> Self {
+        self.schema_name = Some(name.into());
+        self
+    }
+
+    /// Sets the description of the output that should be generated.
+    ///
+    /// Used by some providers for additional LLM guidance.
+    pub fn schema_description(mut self, description: impl Into<String>) -> Self {
+        self.schema_description = Some(description.into());
+        self
+    }
+
+    /// Sets provider-specific options.
+    pub fn provider_options(mut self, options: SharedProviderOptions) -> Self {
+        self.provider_options = Some(options);
+        self
+    }
+
+    /// Executes the object generation with the configured settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AISDKError::NoObjectGenerated`] when the model does not return an
+    /// object, or when the object does not match the schema of `T`.
+    pub async fn execute(self) -> Result<GenerateObjectResult<T>, AISDKError> {
+        let retry_config = prepare_retries(
+            self.settings.max_retries,
+            self.settings.abort_signal.clone(),
+            self.retry_policy.clone(),
+        )?;
+
+        let schema = object_schema::<T>();
+        let mode = self.mode.resolve(self.model.as_ref());
+
+        let call_options = prepare_object_call_options(
+            self.model.as_ref(),
+            self.prompt,
+            &self.settings,
+            self.provider_options,
+            self.downloader,
+        )
+        .await?;
+        let call_options = mode.apply(
+            call_options,
+            &schema,
+            self.schema_name.as_ref(),
+            self.schema_description.as_ref(),
+        );
+
+        let response = retry_config
+            .execute_with_boxed_error(|| {
+                let call_options = call_options.clone();
+                let model = Arc::clone(&self.model);
+                async move { model.do_generate(call_options).await }
+            })
+            .await?;
+
+        let step_response: StepResponseMetadata = response
+            .response
+            .clone()
+            .map(|r| r.into())
+            .unwrap_or_default();
+
+        let text = extract_object_text(&response, mode).ok_or_else(|| {
+            AISDKError::no_object_generated_builder()
+                .message("The model did not return a response.")
+                .response(step_response.clone())
+                .usage(response.usage)
+                .finish_reason(response.finish_reason.clone())
+                .build()
+        })?;
+
+        let object = parse_and_validate_object::<T>(&text, &schema).map_err(|message| {
+            AISDKError::no_object_generated_builder()
+                .message(message)
+                .text(text.clone())
+                .response(step_response.clone())
+                .usage(response.usage)
+                .finish_reason(response.finish_reason.clone())
+                .build()
+        })?;
+
+        Ok(GenerateObjectResult {
+            object,
+            text,
+            finish_reason: response.finish_reason,
+            usage: response.usage,
+            warnings: if response.warnings.is_empty() {
+                None
+            } else {
+                Some(response.warnings)
+            },
+            request: RequestMetadata {
+                body: response.request.and_then(|r| r.body),
+            },
+            response: step_response,
+            provider_metadata: response.provider_metadata,
+        })
+    }
+}
+
+/// Builds the call options shared by object generation and object streaming.
+pub(crate) async fn prepare_object_call_options(
+    model: &dyn LanguageModel,
+    prompt: Prompt,
+    settings: &CallSettings,
+    provider_options: Option<SharedProviderOptions>,
+    downloader: Option<Arc<dyn Downloader>>,
+) -> Result<LanguageModelCallOptions, AISDKError> {
+    let prepared_settings = prepare_call_settings(settings)?;
+    let prompt = PromptDownloads::new(model, downloader)
+        .await
+        .inline(validate_and_standardize(prompt)?)
+        .await?;
+    let messages = convert_to_language_model_prompt(prompt)?;
+
+    let mut call_options = LanguageModelCallOptions::new(messages);
+    if let Some(max_tokens) = prepared_settings.max_output_tokens {
+        call_options = call_options.with_max_output_tokens(max_tokens);
+    }
+    if let Some(temp) = prepared_settings.temperature {
+        call_options = call_options.with_temperature(temp);
+    }
+    if let Some(top_p) = prepared_settings.top_p {
+        call_options = call_options.with_top_p(top_p);
+    }
+    if let Some(top_k) = prepared_settings.top_k {
+        call_options = call_options.with_top_k(top_k);
+    }
+    if let Some(penalty) = prepared_settings.presence_penalty {
+        call_options = call_options.with_presence_penalty(penalty);
+    }
+    if let Some(penalty) = prepared_settings.frequency_penalty {
+        call_options = call_options.with_frequency_penalty(penalty);
+    }
+    if let Some(sequences) = prepared_settings.stop_sequences {
+        call_options = call_options.with_stop_sequences(sequences);
+    }
+    if let Some(seed) = prepared_settings.seed {
+        call_options = call_options.with_seed(seed);
+    }
+    if let Some(ref headers) = settings.headers {
+        call_options = call_options.with_headers(headers.clone());
+    }
+    if let Some(signal) = settings.abort_signal.clone() {
+        call_options = call_options.with_abort_signal(signal);
+    }
+    if let Some(opts) = provider_options {
+        call_options = call_options.with_provider_options(opts);
+    }
+
+    Ok(call_options)
+}
+
+/// Extracts the JSON text of the object from the model response.
+///
+/// In tool mode this is the input of the JSON tool call, otherwise the generated text.
+fn extract_object_text(
+    response: &LanguageModelGenerateResponse,
+    mode: ObjectGenerationMode,
+) -> Option<String> {
+    match mode {
+        ObjectGenerationMode::Tool => response.content.iter().find_map(|part| match part {
+            LanguageModelContent::ToolCall(tool_call) if tool_call.tool_name == JSON_TOOL_NAME => {
+                Some(tool_call.input.clone())
+            }
+            _ => None,
+        }),
+        ObjectGenerationMode::Json | ObjectGenerationMode::Auto => {
+            let text: String = response
+                .content
+                .iter()
+                .filter_map(|part| match part {
+                    LanguageModelContent::Text(text) => Some(text.text.as_str()),
+                    _ => None,
+                })
+                .collect();
+
+            if text.is_empty() { None } else { Some(text) }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use llm_kit_provider::language_model::LanguageModelStreamResponse;
+    use llm_kit_provider::language_model::call_options::LanguageModelResponseFormat;
+    use llm_kit_provider::language_model::content::text::LanguageModelText;
+    use llm_kit_provider::language_model::content::tool_call::LanguageModelToolCall;
+    use llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason;
+    use llm_kit_provider::language_model::usage::LanguageModelUsage;
+    use regex::Regex;
+    use serde::Deserialize;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Debug, PartialEq, Deserialize, JsonSchema)]
+    struct Recipe {
+        name: String,
+        servings: u32,
+    }
+
+    struct ObjectMockLanguageModel {
+        structured_outputs: bool,
+        content: Vec<LanguageModelContent>,
+        call_options: Mutex<Option<LanguageModelCallOptions>>,
+    }
+
+    impl ObjectMockLanguageModel {
+        fn new(structured_outputs: bool, content: LanguageModelContent) -> Self {
+            Self {
+                structured_outputs,
+                content: vec![content],
+                call_options: Mutex::new(None),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl LanguageModel for ObjectMockLanguageModel {
+        fn provider(&self) -> &str {
+            "test-provider"
+        }
+
+        fn model_id(&self) -> &str {
+            "test-model"
+        }
+
+        async fn supported_urls(&self) -> HashMap<String, Vec<Regex>> {
+            HashMap::new()
+        }
+
+        fn supports_structured_outputs(&self) -> bool {
+            self.structured_outputs
+        }
+
+        async fn do_generate(
+            &self,
+            options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelGenerateResponse, Box<dyn std::error::Error>> {
+            *self.call_options.lock().unwrap() = Some(options);
+
+            Ok(LanguageModelGenerateResponse {
+                content: self.content.clone(),
+                finish_reason: LanguageModelFinishReason::Stop,
+                usage: LanguageModelUsage::new(10, 20),
+                provider_metadata: None,
+                request: None,
+                response: None,
+                warnings: Vec::new(),
+            })
+        }
+
+        async fn do_stream(
+            &self,
+            _options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelStreamResponse, Box<dyn std::error::Error>> {
+            unimplemented!("Mock implementation")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_generate_object_json_mode() {
+        let model = Arc::new(ObjectMockLanguageModel::new(
+            true,
+            LanguageModelContent::Text(LanguageModelText::new(
+                r#"{"name": "Lasagna", "servings": 4}"#,
+            )),
+        ));
+
+        let result = GenerateObject::<Recipe>::new(model.clone(), Prompt::text("Recipe"))
+            .schema_name("recipe")
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.object,
+            Recipe {
+                name: "Lasagna".to_string(),
+                servings: 4
+            }
+        );
+        assert_eq!(result.usage, LanguageModelUsage::new(10, 20));
+
+        let options = model.call_options.lock().unwrap().take().unwrap();
+        assert!(matches!(
+            options.response_format,
+            Some(LanguageModelResponseFormat::Json { .. })
+        ));
+        assert!(options.tools.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_generate_object_tool_mode_fallback() {
+        let model = Arc::new(ObjectMockLanguageModel::new(
+            false,
+            LanguageModelContent::ToolCall(LanguageModelToolCall::new(
+                "call_1",
+                JSON_TOOL_NAME,
+                r#"{"name": "Pancakes", "servings": 2}"#,
+            )),
+        ));
+
+        let result = GenerateObject::<Recipe>::new(model.clone(), Prompt::text("Recipe"))
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.object.name, "Pancakes");
+
+        let options = model.call_options.lock().unwrap().take().unwrap();
+        assert!(options.response_format.is_none());
+        assert_eq!(options.tools.map(|tools| tools.len()), Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_generate_object_schema_mismatch() {
+        let model = Arc::new(ObjectMockLanguageModel::new(
+            true,
+            LanguageModelContent::Text(LanguageModelText::new(r#"{"name": "Lasagna"}"#)),
+        ));
+
+        let result = GenerateObject::<Recipe>::new(model, Prompt::text("Recipe"))
+            .execute()
+            .await;
+
+        match result {
+            Err(AISDKError::NoObjectGenerated {
+                text,
+                usage,
+                finish_reason,
+                ..
+            }) => {
+                assert_eq!(text.as_deref(), Some(r#"{"name": "Lasagna"}"#));
+                assert_eq!(usage, Some(LanguageModelUsage::new(10, 20)));
+                assert_eq!(finish_reason, Some(LanguageModelFinishReason::Stop));
+            }
+            other => panic!("Expected NoObjectGenerated error, got {:?}", other.err()),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::generate_text::{RequestMetadata, StepResponseMetadata};
+use llm_kit_provider::language_model::call_warning::LanguageModelCallWarning;
+use llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason;
+use llm_kit_provider::language_model::usage::LanguageModelUsage;
+use llm_kit_provider::shared::provider_metadata::SharedProviderMetadata;
+
+/// The result of a `generate_object` call.
+///
+/// # Example
+///
+/// ```no_run
+/// use llm_kit_core::GenerateObject;
+/// # use llm_kit_core::prompt::Prompt;
+/// # use schemars::JsonSchema;
+/// # use serde::Deserialize;
+/// # use std::sync::Arc;
+/// # use llm_kit_provider::LanguageModel;
+/// # #[derive(Deserialize, JsonSchema)]
+/// # struct Recipe { name: String }
+/// # async fn example(model: Arc<dyn LanguageModel>) -> Result<(), Box<dyn std::error::Error>> {
+///
+/// let result = GenerateObject::<Recipe>::new(model, Prompt::text("Generate a recipe"))
+///     .execute()
+///     .await?;
+/// println!("Recipe: {}", result.object.name);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenerateObjectResult<T> {
+    /// The generated object, validated against the schema.
+    pub object: T,
+
+    /// The raw JSON text that the object was parsed from.
+    pub text: String,
+
+    /// The reason why the generation finished.
+    pub finish_reason: LanguageModelFinishReason,
+
+    /// The token usage of the generation.
+    pub usage: LanguageModelUsage,
+
+    /// Warnings from the model provider (e.g. unsupported settings).
+    pub warnings: Option<Vec<LanguageModelCallWarning>>,
+
+    /// Additional request information.
+    pub request: RequestMetadata,
+
+    /// Additional response information.
+    pub response: StepResponseMetadata,
+
+    /// Additional provider-specific metadata.
+    pub provider_metadata: Option<SharedProviderMetadata>,
+}
@@ -0,0 +1,129 @@
+use llm_kit_provider::LanguageModel;
+use llm_kit_provider::language_model::call_options::{
+    LanguageModelCallOptions, LanguageModelResponseFormat,
+};
+use llm_kit_provider::language_model::tool::LanguageModelTool;
+use llm_kit_provider::language_model::tool::function_tool::LanguageModelFunctionTool;
+use llm_kit_provider::language_model::tool_choice::LanguageModelToolChoice;
+use serde_json::Value;
+
+/// Name of the tool that is used to generate objects in [`ObjectGenerationMode::Tool`].
+pub const JSON_TOOL_NAME: &str = "json";
+
+/// Description of the tool that is used to generate objects in [`ObjectGenerationMode::Tool`].
+const JSON_TOOL_DESCRIPTION: &str = "Respond with a JSON object.";
+
+/// How an object is requested from the language model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ObjectGenerationMode {
+    /// Use [`Json`](Self::Json) when the model supports structured outputs and
+    /// fall back to [`Tool`](Self::Tool) otherwise.
+    #[default]
+    Auto,
+
+    /// Send the schema as a JSON response format.
+    Json,
+
+    /// Force a call to a tool whose input schema is the object schema.
+    ///
+    /// Works with any provider that supports tool calling.
+    Tool,
+}
+
+impl ObjectGenerationMode {
+    /// Resolves [`Auto`](Self::Auto) to a concrete mode for the given model.
+    pub fn resolve(self, model: &dyn LanguageModel) -> Self {
+        match self {
+            Self::Auto if model.supports_structured_outputs() => Self::Json,
+            Self::Auto => Self::Tool,
+            mode => mode,
+        }
+    }
+
+    /// Adds the response format or the JSON tool to the call options.
+    ///
+    /// Must be called on a resolved mode.
+    pub(crate) fn apply(
+        self,
+        call_options: LanguageModelCallOptions,
+        schema: &Value,
+        schema_name: Option<&String>,
+        schema_description: Option<&String>,
+    ) -> LanguageModelCallOptions {
+        match self {
+            Self::Tool => {
+                let description = schema_description
+                    .cloned()
+                    .unwrap_or_else(|| JSON_TOOL_DESCRIPTION.to_string());
+                let tool = LanguageModelFunctionTool::new(JSON_TOOL_NAME, schema.clone())
+                    .with_description(description);
+
+                call_options
+                    .with_tools(vec![LanguageModelTool::Function(tool)])
+                    .with_tool_choice(LanguageModelToolChoice::Tool {
+                        name: JSON_TOOL_NAME.to_string(),
+                    })
+            }
+            Self::Json | Self::Auto => {
+                call_options.with_response_format(LanguageModelResponseFormat::Json {
+                    schema: Some(schema.clone()),
+                    name: schema_name.cloned(),
+                    description: schema_description.cloned(),
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_apply_json_mode() {
+        let schema = json!({ "type": "object" });
+        let options = ObjectGenerationMode::Json.apply(
+            LanguageModelCallOptions::new(vec![]),
+            &schema,
+            Some(&"recipe".to_string()),
+            None,
+        );
+
+        assert!(options.tools.is_none());
+        match options.response_format {
+            Some(LanguageModelResponseFormat::Json { schema, name, .. }) => {
+                assert_eq!(schema, Some(json!({ "type": "object" })));
+                assert_eq!(name.as_deref(), Some("recipe"));
+            }
+            _ => panic!("Expected JSON response format"),
+        }
+    }
+
+    #[test]
+    fn test_apply_tool_mode() {
+        let schema = json!({ "type": "object" });
+        let options = ObjectGenerationMode::Tool.apply(
+            LanguageModelCallOptions::new(vec![]),
+            &schema,
+            None,
+            None,
+        );
+
+        assert!(options.response_format.is_none());
+        let tools = options.tools.unwrap();
+        match &tools[0] {
+            LanguageModelTool::Function(tool) => {
+                assert_eq!(tool.name, JSON_TOOL_NAME);
+                assert_eq!(tool.input_schema, schema);
+            }
+            _ => panic!("Expected function tool"),
+        }
+        assert_eq!(
+            options.tool_choice,
+            Some(LanguageModelToolChoice::Tool {
+                name: JSON_TOOL_NAME.to_string()
+            })
+        );
+    }
+}
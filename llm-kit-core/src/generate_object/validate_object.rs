@@ -0,0 +1,104 @@
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// Derives the JSON schema of `T`, including the definitions of nested types.
+pub fn object_schema<T: JsonSchema>() -> Value {
+    let mut schema = serde_json::to_value(schemars::schema_for!(T))
+        .expect("Failed to convert schema to JSON value");
+
+    // The meta-schema reference is not needed by providers and rejected by some of them.
+    if let Some(obj) = schema.as_object_mut() {
+        obj.remove("$schema");
+    }
+
+    schema
+}
+
+/// Parses text as JSON, validates it against the schema and deserializes it into `T`.
+///
+/// Returns a description of the failure on error.
+pub(crate) fn parse_and_validate_object<T: DeserializeOwned>(
+    text: &str,
+    schema: &Value,
+) -> Result<T, String> {
+    let value: Value =
+        serde_json::from_str(text).map_err(|e| format!("Could not parse the response: {}", e))?;
+
+    let validator =
+        jsonschema::validator_for(schema).map_err(|e| format!("Invalid object schema: {}", e))?;
+
+    if !validator.is_valid(&value) {
+        let error_messages: Vec<String> = validator
+            .iter_errors(&value)
+            .map(|e| format!("{} at {}", e, e.instance_path))
+            .collect();
+
+        return Err(format!(
+            "Response did not match schema: {}",
+            error_messages.join("; ")
+        ));
+    }
+
+    serde_json::from_value(value).map_err(|e| format!("Could not deserialize the response: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, JsonSchema, PartialEq)]
+    struct Recipe {
+        name: String,
+        ingredients: Vec<Ingredient>,
+    }
+
+    #[derive(Debug, Deserialize, JsonSchema, PartialEq)]
+    struct Ingredient {
+        name: String,
+        amount: u32,
+    }
+
+    #[test]
+    fn test_object_schema_includes_definitions() {
+        let schema = object_schema::<Recipe>();
+
+        assert!(schema.get("$schema").is_none());
+        assert_eq!(schema["type"], "object");
+        assert!(schema["definitions"]["Ingredient"].is_object());
+    }
+
+    #[test]
+    fn test_parse_and_validate_object() {
+        let schema = object_schema::<Recipe>();
+        let recipe: Recipe = parse_and_validate_object(
+            r#"{"name": "Soup", "ingredients": [{"name": "Water", "amount": 1}]}"#,
+            &schema,
+        )
+        .unwrap();
+
+        assert_eq!(recipe.name, "Soup");
+        assert_eq!(recipe.ingredients[0].amount, 1);
+    }
+
+    #[test]
+    fn test_parse_and_validate_object_invalid_json() {
+        let schema = object_schema::<Recipe>();
+        let error = parse_and_validate_object::<Recipe>("not json", &schema).unwrap_err();
+
+        assert!(error.contains("Could not parse the response"));
+    }
+
+    #[test]
+    fn test_parse_and_validate_object_schema_mismatch() {
+        let schema = object_schema::<Recipe>();
+        let error = parse_and_validate_object::<Recipe>(
+            r#"{"name": "Soup", "ingredients": [{"name": 1}]}"#,
+            &schema,
+        )
+        .unwrap_err();
+
+        assert!(error.contains("Response did not match schema"));
+    }
+}
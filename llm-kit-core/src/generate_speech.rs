@@ -7,7 +7,7 @@ pub use audio_file::{GeneratedAudioFile, GeneratedAudioFileWithType};
 pub use result::{GenerateSpeechResult, audio_data_to_generated_audio_file};
 
 use crate::error::AISDKError;
-use crate::generate_text::prepare_retries;
+use crate::generate_text::{RetryPolicy, prepare_retries};
 use llm_kit_provider::shared::headers::SharedHeaders;
 use llm_kit_provider::shared::provider_options::SharedProviderOptions;
 use llm_kit_provider::speech_model::SpeechModel;
@@ -50,6 +50,7 @@ pub struct GenerateSpeech {
     language: Option<String>,
     provider_options: Option<SharedProviderOptions>,
     max_retries: Option<u32>,
+    retry_policy: Option<Arc<dyn RetryPolicy>>,
     abort_signal: Option<CancellationToken>,
     headers: Option<SharedHeaders>,
 }
@@ -72,6 +73,7 @@ impl GenerateSpeech {
             language: None,
             provider_options: None,
             max_retries: None,
+            retry_policy: None,
             abort_signal: None,
             headers: None,
         }
@@ -147,6 +149,16 @@ impl GenerateSpeech {
         self
     }
 
+    /// Set the policy that decides whether and when failed calls are retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `retry_policy` - The retry policy. Default: [`DefaultRetryPolicy`](crate::generate_text::DefaultRetryPolicy).
+    pub fn retry_policy(mut self, retry_policy: Arc<dyn RetryPolicy>) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
     /// Set an abort signal that can be used to cancel the call.
     ///
     /// # Arguments
@@ -188,7 +200,11 @@ impl GenerateSpeech {
             add_user_agent_suffix(self.headers, format!("ai/{}", VERSION));
 
         // Prepare retry configuration
-        let retry_config = prepare_retries(self.max_retries, self.abort_signal.clone())?;
+        let retry_config = prepare_retries(
+            self.max_retries,
+            self.abort_signal.clone(),
+            self.retry_policy.clone(),
+        )?;
 
         // Execute the model call with retry logic
         let result = retry_config
@@ -23,7 +23,10 @@ pub use generate_text_result::{GenerateTextResult, ResponseMetadata};
 pub use generated_file::{GeneratedFile, GeneratedFileWithType};
 pub use prepare_step::{PrepareStep, PrepareStepOptions, PrepareStepResult};
 pub use response_message::ResponseMessage;
-pub use retries::{RetryConfig, prepare_retries};
+pub use retries::{
+    DefaultRetryPolicy, OnRetryCallback, RetryAttempt, RetryConfig, RetryPolicy,
+    is_retryable_error, prepare_retries, retry_after_delay,
+};
 pub use step_result::{RequestMetadata, StepResponseMetadata, StepResult};
 pub use stop_condition::{
     HasToolCall, StepCountIs, StopCondition, has_tool_call, is_stop_condition_met, step_count_is,
@@ -31,22 +34,34 @@ pub use stop_condition::{
 pub use to_response_messages::to_response_messages;
 
 use crate::error::AISDKError;
+#[cfg(feature = "storage")]
+use crate::history::HistoryStrategy;
 use crate::prompt::{
-    Prompt,
+    Prompt, PromptContent,
     call_settings::{CallSettings, prepare_call_settings},
     convert_to_language_model_prompt::convert_to_language_model_prompt,
+    download::{Downloader, PromptDownloads},
     standardize::{StandardizedPrompt, validate_and_standardize},
 };
-use crate::tool::{ToolSet, execute_tool_call, parse_tool_call, prepare_tools_and_tool_choice};
+use crate::telemetry::{TelemetrySettings, TelemetrySpan};
+use crate::tool::{
+    ToolCallRepairFunction, ToolSet, collect_tool_approvals, execute_tool_call, is_approval_needed,
+    parse_tool_call_with_repair, prepare_tools_and_tool_choice,
+};
 use llm_kit_provider::{
     language_model::tool_choice::LanguageModelToolChoice,
     language_model::{
-        LanguageModel, call_options::LanguageModelCallOptions, usage::LanguageModelUsage,
+        LanguageModel,
+        call_options::{LanguageModelCallOptions, LanguageModelResponseFormat},
+        usage::LanguageModelUsage,
     },
     shared::provider_options::SharedProviderOptions,
 };
-use llm_kit_provider_utils::message::Message;
-use llm_kit_provider_utils::tool::{ToolCall, ToolError, ToolOutput, ToolResult};
+use llm_kit_provider_utils::message::{Message, ToolContentPart, ToolMessage, UserMessage};
+use llm_kit_provider_utils::tool::{
+    ToolApprovalRequestOutput, ToolApprovalResponse, ToolCall, ToolError, ToolOutput,
+    ToolOutputDenied, ToolResult,
+};
 use std::sync::Arc;
 use tokio_util::sync::CancellationToken;
 
@@ -55,16 +70,23 @@ use tokio_util::sync::CancellationToken;
 /// This function takes a list of typed tool calls that need to be executed on the client side
 /// (not provider-executed), looks up each tool in the tool set, and executes them.
 ///
+/// Tool calls run concurrently, at most `max_parallel_tool_calls` at a time. Calls to tools
+/// marked as [`sequential`](llm_kit_provider_utils::tool::Tool::sequential) run on their own:
+/// they start after the preceding calls have finished, and the following calls start after them.
+///
 /// # Arguments
 ///
 /// * `tool_calls` - References to the typed tool calls to execute
 /// * `tools` - The tool set containing tool definitions
 /// * `messages` - The conversation messages for context (passed to each tool)
 /// * `abort_signal` - Optional cancellation token for aborting tool execution
+/// * `max_parallel_tool_calls` - Maximum number of concurrent tool calls. Default: unlimited.
+/// * `span` - The telemetry span of the step; each tool execution gets a child span
 ///
 /// # Returns
 ///
-/// A vector of tool outputs (results or errors). Tools that cannot be executed are skipped.
+/// A vector of tool outputs (results or errors) in the order of the tool calls.
+/// Tools that cannot be executed are skipped.
 ///
 /// # Example
 ///
@@ -76,33 +98,91 @@ use tokio_util::sync::CancellationToken;
 ///     &tool_set,
 ///     &messages,
 ///     Some(abort_signal),
+///     Some(4),
+///     &step_span,
 /// ).await;
 /// ```
-async fn execute_tools(
+pub(crate) async fn execute_tools(
     tool_calls: &[&ToolCall],
     tools: &ToolSet,
     messages: &[Message],
     abort_signal: Option<CancellationToken>,
+    max_parallel_tool_calls: Option<usize>,
+    span: &TelemetrySpan,
 ) -> Vec<ToolOutput> {
-    let mut outputs = Vec::new();
+    use futures_util::StreamExt;
+
+    execute_tools_stream(
+        tool_calls,
+        tools,
+        messages,
+        abort_signal,
+        max_parallel_tool_calls,
+        span,
+    )
+    .collect()
+    .await
+}
+
+/// Executes tool calls like [`execute_tools`] and yields each output as soon as it
+/// and the outputs of all preceding tool calls are available.
+pub(crate) fn execute_tools_stream<'a>(
+    tool_calls: &'a [&'a ToolCall],
+    tools: &'a ToolSet,
+    messages: &'a [Message],
+    abort_signal: Option<CancellationToken>,
+    max_parallel_tool_calls: Option<usize>,
+    span: &'a TelemetrySpan,
+) -> impl futures_util::Stream<Item = ToolOutput> + 'a {
+    use futures_util::StreamExt;
+
+    let max_parallel = max_parallel_tool_calls.unwrap_or(usize::MAX).max(1);
+    let is_sequential = move |tool_call: &ToolCall| {
+        tools
+            .get(&tool_call.tool_name)
+            .is_some_and(|tool| tool.sequential)
+    };
+
+    // Consecutive calls to concurrent tools form one batch, sequential tools get a batch of their own
+    let batches = tool_calls.chunk_by(move |a, b| !is_sequential(a) && !is_sequential(b));
 
-    for &tool_call in tool_calls {
-        // Execute the tool call with conversation context
-        if let Some(output) = execute_tool_call(
+    futures_util::stream::iter(batches)
+        .flat_map(move |batch| {
+            let batch_futures: Vec<_> = batch
+                .iter()
+                .map(|&tool_call| {
+                    execute_tool(tool_call, tools, messages, abort_signal.clone(), span)
+                })
+                .collect();
+
+            // `buffered` keeps the outputs in the order of the tool calls
+            futures_util::stream::iter(batch_futures).buffered(max_parallel)
+        })
+        .filter_map(futures_util::future::ready)
+}
+
+/// Executes a single tool call with conversation context inside its own telemetry span.
+async fn execute_tool(
+    tool_call: &ToolCall,
+    tools: &ToolSet,
+    messages: &[Message],
+    abort_signal: Option<CancellationToken>,
+    span: &TelemetrySpan,
+) -> Option<ToolOutput> {
+    let tool_span = span.tool_call(tool_call);
+    let output = tool_span
+        .instrument(execute_tool_call(
             tool_call.clone(),
             tools,
             messages.to_vec(),
-            abort_signal.clone(),
+            abort_signal,
             None,
             None,
-        )
-        .await
-        {
-            outputs.push(output);
-        }
-    }
+        ))
+        .await;
+    tool_span.record_tool_output(output.as_ref());
 
-    outputs
+    output
 }
 
 /// Converts language model content, tool calls, and tool outputs into a unified content array.
@@ -155,11 +235,19 @@ pub fn as_output(
         match part {
             // Convert provider Text to TextOutput
             LanguageModelContent::Text(text) => {
-                result.push(Output::Text(TextOutput::new(text.text)));
+                let mut output = TextOutput::new(text.text);
+                if let Some(metadata) = text.provider_metadata {
+                    output = output.with_provider_metadata(metadata);
+                }
+                result.push(Output::Text(output));
             }
             // Convert provider Reasoning to ReasoningOutput
             LanguageModelContent::Reasoning(reasoning) => {
-                result.push(Output::Reasoning(ReasoningOutput::new(reasoning.text)));
+                let mut output = ReasoningOutput::new(reasoning.text);
+                if let Some(metadata) = reasoning.provider_metadata {
+                    output = output.with_provider_metadata(metadata);
+                }
+                result.push(Output::Reasoning(output));
             }
             // Convert provider Source to SourceOutput
             LanguageModelContent::Source(source) => {
@@ -274,6 +362,8 @@ pub struct GenerateText {
     model: Arc<dyn LanguageModel>,
     prompt: Prompt,
     settings: CallSettings,
+    retry_policy: Option<Arc<dyn RetryPolicy>>,
+    downloader: Option<Arc<dyn Downloader>>,
     tools: Option<ToolSet>,
     tool_choice: Option<LanguageModelToolChoice>,
     response_format:
@@ -281,14 +371,19 @@ pub struct GenerateText {
     provider_options: Option<SharedProviderOptions>,
     stop_when: Option<Vec<Box<dyn StopCondition>>>,
     prepare_step: Option<Box<dyn PrepareStep>>,
+    repair_tool_call: Option<ToolCallRepairFunction>,
+    max_parallel_tool_calls: Option<usize>,
     on_step_finish: Option<Box<dyn OnStepFinish>>,
     on_finish: Option<Box<dyn OnFinish>>,
+    telemetry: TelemetrySettings,
     #[cfg(feature = "storage")]
     storage: Option<Arc<dyn llm_kit_storage::Storage>>,
     #[cfg(feature = "storage")]
     session_id: Option<String>,
     #[cfg(feature = "storage")]
     load_history: bool,
+    #[cfg(feature = "storage")]
+    history_strategy: Option<Arc<dyn HistoryStrategy>>,
 }
 
 impl GenerateText {
@@ -298,20 +393,27 @@ impl GenerateText {
             model,
             prompt,
             settings: CallSettings::default(),
+            retry_policy: None,
+            downloader: None,
             tools: None,
             tool_choice: None,
             response_format: None,
             provider_options: None,
             stop_when: None,
             prepare_step: None,
+            repair_tool_call: None,
+            max_parallel_tool_calls: None,
             on_step_finish: None,
             on_finish: None,
+            telemetry: TelemetrySettings::default(),
             #[cfg(feature = "storage")]
             storage: None,
             #[cfg(feature = "storage")]
             session_id: None,
             #[cfg(feature = "storage")]
             load_history: true, // Default to true for automatic history loading
+            #[cfg(feature = "storage")]
+            history_strategy: None,
         }
     }
 
@@ -375,6 +477,22 @@ impl GenerateText {
         self
     }
 
+    /// Sets the policy that decides whether and when failed model calls are retried.
+    ///
+    /// Defaults to [`DefaultRetryPolicy`](crate::generate_text::DefaultRetryPolicy).
+    pub fn retry_policy(mut self, retry_policy: Arc<dyn RetryPolicy>) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Sets the downloader for prompt URLs the model doesn't support.
+    ///
+    /// Defaults to [`DefaultDownloader`](crate::prompt::download::DefaultDownloader).
+    pub fn downloader(mut self, downloader: Arc<dyn Downloader>) -> Self {
+        self.downloader = Some(downloader);
+        self
+    }
+
     /// Sets custom headers for the request.
     pub fn headers(mut self, headers: std::collections::HashMap<String, String>) -> Self {
         self.settings = self.settings.with_headers(headers);
@@ -426,6 +544,76 @@ impl GenerateText {
         self
     }
 
+    /// Sets a function that attempts to repair tool calls that fail to parse.
+    ///
+    /// The repair function is called when a tool call references an unknown tool
+    /// or has input that does not match the tool's schema.
+    pub fn experimental_repair_tool_call(mut self, repair: ToolCallRepairFunction) -> Self {
+        self.repair_tool_call = Some(repair);
+        self
+    }
+
+    /// Sets the maximum number of tool calls of a step that run concurrently.
+    ///
+    /// Tool calls run concurrently by default. Their outputs keep the order of the calls.
+    /// Tools marked as [`sequential`](llm_kit_provider_utils::tool::Tool::sequential)
+    /// always run on their own.
+    pub fn max_parallel_tool_calls(mut self, max_parallel_tool_calls: usize) -> Self {
+        self.max_parallel_tool_calls = Some(max_parallel_tool_calls);
+        self
+    }
+
+    /// Answers the tool approval requests of a previous generation and continues it.
+    ///
+    /// Calls to tools that need approval are not executed. The generation stops with
+    /// the approval requests instead (see [`GenerateTextResult::pending_approvals`]).
+    /// To continue, create a new generation whose prompt contains the previous messages
+    /// and the response messages, and pass the answers here. Approved tool calls are
+    /// executed and denied tool calls are reported as [`ToolOutputDenied`] before the
+    /// model is called again.
+    ///
+    /// With storage, the previous messages are loaded from the session, so the prompt
+    /// can be empty.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let result = GenerateText::new(model.clone(), Prompt::messages(messages.clone()))
+    ///     .tools(tools.clone())
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// let approvals = result
+    ///     .pending_approvals()
+    ///     .iter()
+    ///     .map(|request| ToolApprovalResponse::granted(request.approval_id.clone()))
+    ///     .collect();
+    ///
+    /// messages.extend(result.response.messages.into_iter().map(Message::from));
+    /// let result = GenerateText::new(model, Prompt::messages(messages))
+    ///     .tools(tools)
+    ///     .resume_with_approvals(approvals)
+    ///     .execute()
+    ///     .await?;
+    /// ```
+    pub fn resume_with_approvals(mut self, approvals: Vec<ToolApprovalResponse>) -> Self {
+        if let PromptContent::Text { text } = &self.prompt.content {
+            self.prompt.content = PromptContent::Messages {
+                messages: vec![Message::User(UserMessage::new(text.clone()))],
+            };
+        }
+
+        if let Some(messages) = self.prompt.get_messages_mut() {
+            messages.push(Message::Tool(ToolMessage::new(
+                approvals
+                    .into_iter()
+                    .map(ToolContentPart::ApprovalResponse)
+                    .collect(),
+            )));
+        }
+        self
+    }
+
     /// Sets the on_step_finish callback.
     pub fn on_step_finish(mut self, callback: Box<dyn OnStepFinish>) -> Self {
         self.on_step_finish = Some(callback);
@@ -508,8 +696,54 @@ impl GenerateText {
         self
     }
 
+    /// Sets the strategy that selects which part of the loaded history is sent.
+    ///
+    /// By default the whole history is sent. Use a strategy such as
+    /// [`LastMessages`](crate::history::LastMessages) or
+    /// [`TokenBudget`](crate::history::TokenBudget) to keep long sessions within the
+    /// model's context window. Errors of the strategy fail the call.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let result = GenerateText::new(model, prompt)
+    ///     .with_storage(storage)
+    ///     .with_session_id(session_id)
+    ///     .with_history_strategy(Arc::new(TokenBudget::new(8_000)))
+    ///     .execute()
+    ///     .await?;
+    /// ```
+    #[cfg(feature = "storage")]
+    pub fn with_history_strategy(mut self, strategy: Arc<dyn HistoryStrategy>) -> Self {
+        self.history_strategy = Some(strategy);
+        self
+    }
+
+    /// Sets the telemetry settings for the call.
+    ///
+    /// Spans are only emitted when the `telemetry` feature is enabled and the
+    /// settings are enabled. Telemetry is disabled by default.
+    pub fn telemetry(mut self, telemetry: TelemetrySettings) -> Self {
+        self.telemetry = telemetry;
+        self
+    }
+
     /// Executes the text generation with the configured settings.
     pub async fn execute(self) -> Result<GenerateTextResult, AISDKError> {
+        let span = TelemetrySpan::generate_text(self.model.as_ref(), &self.telemetry);
+
+        let result = span.instrument(self.run(&span)).await;
+        match &result {
+            Ok(result) => {
+                span.record_finish(&result.finish_reason, &result.total_usage);
+                span.record_output_text(&result.text);
+            }
+            Err(error) => span.record_error(error),
+        }
+        result
+    }
+
+    async fn run(self, span: &TelemetrySpan) -> Result<GenerateTextResult, AISDKError> {
         // Prepare stop conditions - default to step_count_is(1)
         let stop_conditions = self
             .stop_when
@@ -519,6 +753,7 @@ impl GenerateText {
         let retry_config = prepare_retries(
             self.settings.max_retries,
             self.settings.abort_signal.clone(),
+            self.retry_policy.clone(),
         )?;
 
         // Prepare and validate call settings
@@ -529,54 +764,68 @@ impl GenerateText {
 
         // Store user message and load conversation history if storage is configured
         #[cfg(feature = "storage")]
-        let initial_prompt = if let (Some(storage), Some(session_id)) =
-            (&self.storage, &self.session_id)
-        {
-            use crate::storage_conversion::{load_conversation_history, user_message_to_storage};
+        let initial_prompt =
+            if let (Some(storage), Some(session_id)) = (&self.storage, &self.session_id) {
+                use crate::storage_conversion::{
+                    load_conversation_history, tool_message_to_storage, user_message_to_storage,
+                };
 
-            // Create session if it doesn't exist
-            if storage.get_session(session_id).await.is_err() {
-                let session = llm_kit_storage::Session::new(session_id.clone());
-                if let Err(e) = storage.store_session(&session).await {
-                    log::warn!("Failed to create session: {}", e);
+                // Create session if it doesn't exist
+                if storage.get_session(session_id).await.is_err() {
+                    let session = llm_kit_storage::Session::new(session_id.clone());
+                    if let Err(e) = storage.store_session(&session).await {
+                        log::warn!("Failed to create session: {}", e);
+                    }
                 }
-            }
 
-            // Store the new user message immediately
-            if let Some(user_msg) = initial_prompt.messages.iter().find(|m| m.is_user())
-                && let Message::User(user_message) = user_msg
-            {
-                let (storage_msg, parts) =
-                    user_message_to_storage(storage, session_id.clone(), user_message);
-                if let Err(e) = storage.store_user_message(&storage_msg, &parts).await {
-                    log::warn!("Failed to store user message: {}", e);
+                // Store the new user message immediately
+                if let Some(user_msg) = initial_prompt.messages.iter().find(|m| m.is_user())
+                    && let Message::User(user_message) = user_msg
+                {
+                    let (storage_msg, parts) =
+                        user_message_to_storage(storage, session_id.clone(), user_message);
+                    if let Err(e) = storage.store_user_message(&storage_msg, &parts).await {
+                        log::warn!("Failed to store user message: {}", e);
+                    }
                 }
-            }
 
-            // Load conversation history if enabled (includes the just-stored user message)
-            if self.load_history {
-                match load_conversation_history(storage, session_id).await {
-                    Ok(history) => {
-                        if !history.is_empty() {
-                            StandardizedPrompt {
-                                messages: history,
-                                system: initial_prompt.system.clone(),
+                // Store the tool approval responses when resuming with approvals
+                if let Some(Message::Tool(tool_message)) = initial_prompt.messages.last() {
+                    let (storage_msg, parts) =
+                        tool_message_to_storage(storage, session_id.clone(), tool_message);
+                    if let Err(e) = storage.store_assistant_message(&storage_msg, &parts).await {
+                        log::warn!("Failed to store tool approval responses: {}", e);
+                    }
+                }
+
+                // Load conversation history if enabled (includes the just-stored user message)
+                if self.load_history {
+                    match load_conversation_history(storage, session_id).await {
+                        Ok(history) => {
+                            let history = match &self.history_strategy {
+                                Some(strategy) => strategy.apply(history).await?,
+                                None => history,
+                            };
+                            if !history.is_empty() {
+                                StandardizedPrompt {
+                                    messages: history,
+                                    system: initial_prompt.system.clone(),
+                                }
+                            } else {
+                                initial_prompt
                             }
-                        } else {
+                        }
+                        Err(e) => {
+                            log::warn!("Failed to load conversation history: {}", e);
                             initial_prompt
                         }
                     }
-                    Err(e) => {
-                        log::warn!("Failed to load conversation history: {}", e);
-                        initial_prompt
-                    }
+                } else {
+                    initial_prompt
                 }
             } else {
                 initial_prompt
-            }
-        } else {
-            initial_prompt
-        };
+            };
 
         // Store the initial messages before the loop
         let initial_messages = initial_prompt.messages.clone();
@@ -591,18 +840,54 @@ impl GenerateText {
         let (provider_tools, prepared_tool_choice) =
             prepare_tools_and_tool_choice(self.tools.as_ref(), self.tool_choice);
 
+        // Downloads of prompt URLs the model doesn't support, shared by all steps
+        let mut downloads =
+            PromptDownloads::new(self.model.as_ref(), self.downloader.clone()).await;
+
+        // Execute the approved tool calls and deny the others before the first step
+        let tool_approvals = collect_tool_approvals(&initial_messages);
+        let mut approval_outputs: Vec<Output> = Vec::new();
+        if let Some(tool_set) = self.tools.as_ref() {
+            let approved_tool_calls: Vec<&ToolCall> = tool_approvals
+                .approved_tool_approvals
+                .iter()
+                .map(|approval| &approval.tool_call)
+                .collect();
+            let approved_tool_outputs = execute_tools(
+                &approved_tool_calls,
+                tool_set,
+                &initial_messages,
+                self.settings.abort_signal.clone(),
+                self.max_parallel_tool_calls,
+                span,
+            )
+            .await;
+            approval_outputs.extend(as_output(vec![], vec![], approved_tool_outputs));
+        }
+        for approval in &tool_approvals.denied_tool_approvals {
+            let mut denied = ToolOutputDenied::new(
+                approval.tool_call.tool_call_id.clone(),
+                approval.tool_call.tool_name.clone(),
+                approval.tool_call.input.clone(),
+            );
+            if let Some(ref reason) = approval.approval_response.reason {
+                denied = denied.with_reason(reason.clone());
+            }
+            approval_outputs.push(Output::ToolOutputDenied(denied));
+        }
+        response_messages.extend(to_response_messages(
+            approval_outputs.clone(),
+            self.tools.as_ref(),
+        ));
+
         // Do-while loop for multi-step generation
         loop {
+            let step_span = span.step(steps.len());
+
             // Step 5: Create step input messages by combining initial messages with accumulated response messages
             let mut step_input_messages = initial_messages.clone();
             // Convert response messages to model messages and append to step_input_messages
-            for response_msg in &response_messages {
-                let model_msg = match response_msg {
-                    ResponseMessage::Assistant(msg) => Message::Assistant(msg.clone()),
-                    ResponseMessage::Tool(msg) => Message::Tool(msg.clone()),
-                };
-                step_input_messages.push(model_msg);
-            }
+            step_input_messages.extend(response_messages.iter().cloned().map(Message::from));
 
             // Call prepare_step callback to allow step customization
             let prepare_step_result = if let Some(ref prepare_fn) = self.prepare_step {
@@ -637,11 +922,15 @@ impl GenerateText {
                 .as_ref()
                 .and_then(|r| r.active_tools.clone());
 
-            // Step 6: Convert to language model format (provider messages)
-            let messages = convert_to_language_model_prompt(StandardizedPrompt {
-                messages: step_messages,
-                system: step_system,
-            })?;
+            // Step 6: Convert to language model format (provider messages), inlining
+            // the URLs the model doesn't support
+            let step_prompt = downloads
+                .inline(StandardizedPrompt {
+                    messages: step_messages,
+                    system: step_system.clone(),
+                })
+                .await?;
+            let messages = convert_to_language_model_prompt(step_prompt)?;
 
             // Step 7: Build CallOptions
             let mut call_options = LanguageModelCallOptions::new(messages);
@@ -726,51 +1015,39 @@ impl GenerateText {
             }
 
             // Step 8: Call model.do_generate with retry logic
-            let response = retry_config
-                .execute(|| {
+            let model_span =
+                step_span.model_call("do_generate", self.model.as_ref(), &call_options);
+            let response = model_span
+                .instrument(retry_config.execute_with_boxed_error(|| {
                     let call_options_clone = call_options.clone();
                     let model_clone = Arc::clone(&self.model);
-                    async move {
-                        model_clone
-                            .do_generate(call_options_clone)
-                            .await
-                            .map_err(|e| {
-                                let error_string = e.to_string();
-                                // Check if error contains retry hint and create appropriate error type
-                                if let Some(retry_after) =
-                                    retries::extract_retry_delay_from_error(&error_string)
-                                {
-                                    AISDKError::retryable_error_with_delay(
-                                        error_string,
-                                        retry_after,
-                                    )
-                                } else {
-                                    AISDKError::model_error(error_string)
-                                }
-                            })
-                    }
-                })
-                .await?;
+                    async move { model_clone.do_generate(call_options_clone).await }
+                }))
+                .await
+                .inspect(|response| model_span.record_response(response))
+                .inspect_err(|error| model_span.record_error(error))?;
 
             // Step 9: Parse tool calls from the response
             use llm_kit_provider::language_model::content::LanguageModelContent;
 
             let step_tool_calls: Vec<ToolCall> = if let Some(tool_set) = self.tools.as_ref() {
-                response
-                    .content
-                    .iter()
-                    .filter_map(|part| {
-                        if let LanguageModelContent::ToolCall(tool_call) = part {
-                            Some(tool_call)
-                        } else {
-                            None
-                        }
-                    })
-                    .map(|tool_call| {
-                        // Parse each tool call against the tool set
-                        parse_tool_call(tool_call, tool_set)
-                    })
-                    .collect::<Result<Vec<_>, _>>()?
+                let mut tool_calls = Vec::new();
+                for part in &response.content {
+                    if let LanguageModelContent::ToolCall(tool_call) = part {
+                        // Parse each tool call against the tool set, repairing it if possible
+                        tool_calls.push(
+                            parse_tool_call_with_repair(
+                                tool_call,
+                                tool_set,
+                                self.repair_tool_call.as_ref(),
+                                step_system.as_deref(),
+                                &step_input_messages,
+                            )
+                            .await?,
+                        );
+                    }
+                }
+                tool_calls
             } else {
                 // No tools provided, so no tool calls to parse
                 Vec::new()
@@ -784,14 +1061,51 @@ impl GenerateText {
                 .iter()
                 .filter(|tool_call| tool_call.provider_executed != Some(true))
                 .collect();
+            step_span.record_tool_calls(&step_tool_calls);
+
+            // Calls to tools that need approval are not executed until they are approved
+            let mut executable_tool_calls: Vec<&ToolCall> = Vec::new();
+            let mut approval_requests: Vec<Output> = Vec::new();
+            for &tool_call in &client_tool_calls {
+                let needs_approval = match self
+                    .tools
+                    .as_ref()
+                    .and_then(|tool_set| tool_set.get(&tool_call.tool_name))
+                {
+                    Some(tool) => {
+                        is_approval_needed(
+                            tool,
+                            tool_call.tool_call_id.clone(),
+                            tool_call.input.clone(),
+                            step_input_messages.clone(),
+                            None,
+                        )
+                        .await
+                    }
+                    None => false,
+                };
+
+                if needs_approval {
+                    approval_requests.push(Output::ToolApprovalRequest(
+                        ToolApprovalRequestOutput::new(
+                            uuid::Uuid::new_v4().to_string(),
+                            tool_call.clone(),
+                        ),
+                    ));
+                } else {
+                    executable_tool_calls.push(tool_call);
+                }
+            }
 
             // Execute client tool calls and collect outputs
             let client_tool_outputs = if let Some(tool_set) = self.tools.as_ref() {
                 execute_tools(
-                    &client_tool_calls,
+                    &executable_tool_calls,
                     tool_set,
                     &step_input_messages,
                     abort_signal_for_tools,
+                    self.max_parallel_tool_calls,
+                    &step_span,
                 )
                 .await
             } else {
@@ -802,11 +1116,13 @@ impl GenerateText {
             let client_tool_outputs_count = client_tool_outputs.len();
 
             // Create step content using as_output(clone step_tool_calls since we borrowed it above)
-            let step_content = as_output(
+            let mut step_content = as_output(
                 response.content.clone(),
                 step_tool_calls.clone(),
                 client_tool_outputs,
             );
+            let has_approval_requests = !approval_requests.is_empty();
+            step_content.extend(approval_requests);
 
             // Append to messages for potential next step
             let step_response_messages =
@@ -815,6 +1131,11 @@ impl GenerateText {
                 response_messages.push(msg);
             }
 
+            // The outputs of approved and denied tool calls are reported with the first step
+            if steps.is_empty() && !approval_outputs.is_empty() {
+                step_content.splice(0..0, std::mem::take(&mut approval_outputs));
+            }
+
             // Create and push the current step result (using step_content, NOT response.content)
             let current_step_result = StepResult::new(
                 step_content.clone(), // ← Use Output, not provider Content
@@ -836,6 +1157,7 @@ impl GenerateText {
                 response.provider_metadata.clone(),
             );
 
+            step_span.record_finish(&response.finish_reason, &response.usage);
             steps.push(current_step_result.clone());
 
             // Call on_step_finish callback
@@ -846,9 +1168,11 @@ impl GenerateText {
             // Check loop termination conditions (do-while loop pattern)
             // Continue if:
             // - There are client tool calls AND
+            // - No tool call is waiting for approval AND
             // - All tool calls have outputs AND
             // - Stop conditions are not met
             let should_continue = !client_tool_calls.is_empty()
+                && !has_approval_requests
                 && client_tool_outputs_count == client_tool_calls.len()
                 && !is_stop_condition_met(&stop_conditions, &steps).await;
 
@@ -877,19 +1201,28 @@ impl GenerateText {
             });
 
         // Create the result
-        let result = GenerateTextResult::from_steps(steps, total_usage);
+        let mut result = GenerateTextResult::from_steps(steps, total_usage);
+        result.response.messages = response_messages.clone();
+
+        if matches!(
+            self.response_format,
+            Some(LanguageModelResponseFormat::Json { .. })
+        ) {
+            result = result.with_json_output();
+        }
 
         // Store all response messages if storage is configured (user message already stored)
         #[cfg(feature = "storage")]
         if let (Some(storage), Some(session_id)) = (&self.storage, &self.session_id) {
-            use crate::storage_conversion::response_messages_to_storage;
+            use crate::storage_conversion::response_messages_to_storage_with_model;
 
             // Convert all response messages (assistant + tool) to storage format
-            let storage_messages = response_messages_to_storage(
+            let storage_messages = response_messages_to_storage_with_model(
                 storage,
                 session_id.clone(),
                 &response_messages,
                 &result,
+                self.model.as_ref(),
             );
 
             // Store each message
@@ -963,6 +1296,115 @@ mod tests {
         }
     }
 
+    // Mock LanguageModel that returns a single tool call with a misspelled tool name
+    struct ToolCallMockLanguageModel;
+
+    #[async_trait]
+    impl LanguageModel for ToolCallMockLanguageModel {
+        fn provider(&self) -> &str {
+            "test-provider"
+        }
+
+        fn model_id(&self) -> &str {
+            "test-model"
+        }
+
+        async fn supported_urls(&self) -> HashMap<String, Vec<Regex>> {
+            HashMap::new()
+        }
+
+        async fn do_generate(
+            &self,
+            _options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelGenerateResponse, Box<dyn std::error::Error>> {
+            use llm_kit_provider::language_model::content::LanguageModelContent;
+            use llm_kit_provider::language_model::content::tool_call::LanguageModelToolCall;
+
+            Ok(LanguageModelGenerateResponse {
+                content: vec![LanguageModelContent::ToolCall(LanguageModelToolCall::new(
+                    "call_1",
+                    "getWeather",
+                    r#"{"city": "SF"}"#,
+                ))],
+                finish_reason:
+                    llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason::ToolCalls,
+                usage: LanguageModelUsage::default(),
+                provider_metadata: None,
+                request: None,
+                response: None,
+                warnings: Vec::new(),
+            })
+        }
+
+        async fn do_stream(
+            &self,
+            _options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelStreamResponse, Box<dyn std::error::Error>> {
+            unimplemented!("Mock implementation")
+        }
+    }
+
+    fn weather_tool_set() -> ToolSet {
+        let mut tools = ToolSet::new();
+        tools.insert(
+            "get_weather".to_string(),
+            llm_kit_provider_utils::tool::Tool::function(serde_json::json!({
+                "type": "object",
+                "properties": { "city": { "type": "string" } }
+            })),
+        );
+        tools
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_fails_on_unknown_tool_without_repair() {
+        let result = GenerateText::new(
+            Arc::new(ToolCallMockLanguageModel),
+            Prompt::text("What is the weather?"),
+        )
+        .tools(weather_tool_set())
+        .execute()
+        .await;
+
+        assert!(matches!(result, Err(AISDKError::NoSuchTool { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_repairs_tool_call() {
+        use crate::tool::ToolCallRepairOptions;
+        use llm_kit_provider::language_model::content::tool_call::LanguageModelToolCall;
+
+        let result = GenerateText::new(
+            Arc::new(ToolCallMockLanguageModel),
+            Prompt::text("What is the weather?").with_system("You are a helpful assistant"),
+        )
+        .tools(weather_tool_set())
+        .experimental_repair_tool_call(Box::new(|options: ToolCallRepairOptions| {
+            Box::pin(async move {
+                assert_eq!(
+                    options.system.as_deref(),
+                    Some("You are a helpful assistant")
+                );
+                assert_eq!(options.messages.len(), 1);
+                Some(LanguageModelToolCall::new(
+                    options.tool_call.tool_call_id,
+                    "get_weather",
+                    options.tool_call.input,
+                ))
+            })
+        }))
+        .execute()
+        .await
+        .unwrap();
+
+        assert_eq!(result.tool_calls.len(), 1);
+        assert_eq!(result.tool_calls[0].tool_name, "get_weather");
+        assert_eq!(
+            result.tool_calls[0].input,
+            serde_json::json!({"city": "SF"})
+        );
+    }
+
     #[tokio::test]
     async fn test_generate_text_basic() {
         let model = Arc::new(MockLanguageModel::new());
@@ -1177,4 +1619,340 @@ mod tests {
             _ => panic!("Expected Output::File variant"),
         }
     }
+
+    // Tool that records how many of its calls run at the same time
+    fn concurrency_tracking_tool(
+        running: Arc<std::sync::atomic::AtomicUsize>,
+        max_running: Arc<std::sync::atomic::AtomicUsize>,
+        sequential: bool,
+    ) -> llm_kit_provider_utils::tool::Tool {
+        use llm_kit_provider_utils::tool::{Tool, ToolExecutionOutput};
+        use std::sync::atomic::Ordering;
+
+        Tool::function(serde_json::json!({"type": "object"}))
+            .with_sequential(sequential)
+            .with_execute(Arc::new(move |input: Value, _options| {
+                let running = Arc::clone(&running);
+                let max_running = Arc::clone(&max_running);
+                ToolExecutionOutput::Single(Box::pin(async move {
+                    let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_running.fetch_max(now_running, Ordering::SeqCst);
+                    // Later calls finish first, so the output order must not follow completion order
+                    let delay = 40 - input["index"].as_u64().unwrap_or(0) * 5;
+                    tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+                    running.fetch_sub(1, Ordering::SeqCst);
+                    Ok(input)
+                }))
+            }))
+    }
+
+    async fn run_tool_calls(
+        tools: &ToolSet,
+        tool_calls: &[ToolCall],
+        max_parallel_tool_calls: Option<usize>,
+    ) -> Vec<String> {
+        let span = TelemetrySpan::generate_text(
+            &MockLanguageModel::new(),
+            &TelemetrySettings::new().with_enabled(false),
+        );
+        let tool_call_refs: Vec<&ToolCall> = tool_calls.iter().collect();
+
+        execute_tools(
+            &tool_call_refs,
+            tools,
+            &[],
+            None,
+            max_parallel_tool_calls,
+            &span,
+        )
+        .await
+        .into_iter()
+        .map(|output| match output {
+            ToolOutput::Result(result) => result.tool_call_id,
+            ToolOutput::Error(error) => panic!("Unexpected tool error: {:?}", error.error),
+        })
+        .collect()
+    }
+
+    fn indexed_tool_calls(tool_names: &[&str]) -> Vec<ToolCall> {
+        tool_names
+            .iter()
+            .enumerate()
+            .map(|(index, tool_name)| {
+                ToolCall::new(
+                    format!("call_{index}"),
+                    *tool_name,
+                    serde_json::json!({ "index": index }),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_execute_tools_runs_calls_concurrently_in_order() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let max_running = Arc::new(AtomicUsize::new(0));
+        let mut tools = ToolSet::new();
+        tools.insert(
+            "lookup".to_string(),
+            concurrency_tracking_tool(Arc::new(AtomicUsize::new(0)), max_running.clone(), false),
+        );
+
+        let tool_calls = indexed_tool_calls(&["lookup", "lookup", "lookup", "lookup"]);
+        let ids = run_tool_calls(&tools, &tool_calls, None).await;
+
+        assert_eq!(ids, vec!["call_0", "call_1", "call_2", "call_3"]);
+        assert_eq!(max_running.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_execute_tools_respects_max_parallel_tool_calls() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let max_running = Arc::new(AtomicUsize::new(0));
+        let mut tools = ToolSet::new();
+        tools.insert(
+            "lookup".to_string(),
+            concurrency_tracking_tool(Arc::new(AtomicUsize::new(0)), max_running.clone(), false),
+        );
+
+        let tool_calls = indexed_tool_calls(&["lookup", "lookup", "lookup", "lookup", "lookup"]);
+        let ids = run_tool_calls(&tools, &tool_calls, Some(2)).await;
+
+        assert_eq!(ids, vec!["call_0", "call_1", "call_2", "call_3", "call_4"]);
+        assert_eq!(max_running.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_execute_tools_runs_sequential_tools_alone() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // Both tools share the counters, so overlapping calls of either tool are detected
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+        let mut tools = ToolSet::new();
+        tools.insert(
+            "lookup".to_string(),
+            concurrency_tracking_tool(running.clone(), max_running.clone(), false),
+        );
+        tools.insert(
+            "write".to_string(),
+            concurrency_tracking_tool(running, max_running.clone(), true),
+        );
+
+        let tool_calls = indexed_tool_calls(&["lookup", "lookup", "write", "lookup", "lookup"]);
+        let ids = run_tool_calls(&tools, &tool_calls, None).await;
+
+        assert_eq!(ids, vec!["call_0", "call_1", "call_2", "call_3", "call_4"]);
+        assert_eq!(max_running.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_execute_tools_stream_yields_outputs_before_slower_calls_finish() {
+        use futures_util::StreamExt;
+        use llm_kit_provider_utils::tool::{Tool, ToolExecutionOutput};
+        use std::time::Duration;
+        use tokio::sync::Notify;
+
+        // The slow tool only finishes after the output of the fast tool was received
+        let release = Arc::new(Notify::new());
+        let slow_release = Arc::clone(&release);
+        let mut tools = ToolSet::new();
+        tools.insert(
+            "fast".to_string(),
+            Tool::function(serde_json::json!({"type": "object"})).with_execute(Arc::new(
+                |input: Value, _options| ToolExecutionOutput::Single(Box::pin(async { Ok(input) })),
+            )),
+        );
+        tools.insert(
+            "slow".to_string(),
+            Tool::function(serde_json::json!({"type": "object"})).with_execute(Arc::new(
+                move |input: Value, _options| {
+                    let release = Arc::clone(&slow_release);
+                    ToolExecutionOutput::Single(Box::pin(async move {
+                        release.notified().await;
+                        Ok(input)
+                    }))
+                },
+            )),
+        );
+
+        let span = TelemetrySpan::generate_text(
+            &MockLanguageModel::new(),
+            &TelemetrySettings::new().with_enabled(false),
+        );
+        let tool_calls = indexed_tool_calls(&["fast", "slow"]);
+        let tool_call_refs: Vec<&ToolCall> = tool_calls.iter().collect();
+        let mut outputs = std::pin::pin!(execute_tools_stream(
+            &tool_call_refs,
+            &tools,
+            &[],
+            None,
+            None,
+            &span,
+        ));
+
+        let first = tokio::time::timeout(Duration::from_secs(5), outputs.next())
+            .await
+            .expect("the fast tool output should not wait for the slow tool");
+        assert!(
+            matches!(first, Some(ToolOutput::Result(result)) if result.tool_call_id == "call_0")
+        );
+
+        release.notify_one();
+        let second = outputs.next().await;
+        assert!(
+            matches!(second, Some(ToolOutput::Result(result)) if result.tool_call_id == "call_1")
+        );
+        assert!(outputs.next().await.is_none());
+    }
+
+    // Mock LanguageModel that calls `delete_file` twice, then answers once the tool outputs are sent
+    struct ApprovalMockLanguageModel;
+
+    #[async_trait]
+    impl LanguageModel for ApprovalMockLanguageModel {
+        fn provider(&self) -> &str {
+            "test-provider"
+        }
+
+        fn model_id(&self) -> &str {
+            "test-model"
+        }
+
+        async fn supported_urls(&self) -> HashMap<String, Vec<Regex>> {
+            HashMap::new()
+        }
+
+        async fn do_generate(
+            &self,
+            options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelGenerateResponse, Box<dyn std::error::Error>> {
+            use llm_kit_provider::language_model::content::LanguageModelContent;
+            use llm_kit_provider::language_model::content::text::LanguageModelText;
+            use llm_kit_provider::language_model::content::tool_call::LanguageModelToolCall;
+            use llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason;
+            use llm_kit_provider::language_model::prompt::LanguageModelMessage;
+
+            let (content, finish_reason) = match options.prompt.last() {
+                Some(LanguageModelMessage::Tool(_)) => (
+                    vec![LanguageModelContent::Text(LanguageModelText::new("Done"))],
+                    LanguageModelFinishReason::Stop,
+                ),
+                _ => (
+                    vec![
+                        LanguageModelContent::ToolCall(LanguageModelToolCall::new(
+                            "call_1",
+                            "delete_file",
+                            r#"{"path": "a.txt"}"#,
+                        )),
+                        LanguageModelContent::ToolCall(LanguageModelToolCall::new(
+                            "call_2",
+                            "delete_file",
+                            r#"{"path": "b.txt"}"#,
+                        )),
+                    ],
+                    LanguageModelFinishReason::ToolCalls,
+                ),
+            };
+
+            Ok(LanguageModelGenerateResponse {
+                content,
+                finish_reason,
+                usage: LanguageModelUsage::default(),
+                provider_metadata: None,
+                request: None,
+                response: None,
+                warnings: Vec::new(),
+            })
+        }
+
+        async fn do_stream(
+            &self,
+            _options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelStreamResponse, Box<dyn std::error::Error>> {
+            unimplemented!("Mock implementation")
+        }
+    }
+
+    fn delete_file_tool_set(deleted: Arc<std::sync::Mutex<Vec<String>>>) -> ToolSet {
+        use llm_kit_provider_utils::tool::{Tool, ToolExecutionOutput};
+
+        let mut tools = ToolSet::new();
+        tools.insert(
+            "delete_file".to_string(),
+            Tool::function(serde_json::json!({
+                "type": "object",
+                "properties": { "path": { "type": "string" } }
+            }))
+            .with_needs_approval(true)
+            .with_execute(Arc::new(move |input: Value, _options| {
+                let deleted = Arc::clone(&deleted);
+                ToolExecutionOutput::Single(Box::pin(async move {
+                    let path = input["path"].as_str().unwrap_or_default().to_string();
+                    deleted.lock().unwrap().push(path);
+                    Ok(serde_json::json!({ "deleted": true }))
+                }))
+            })),
+        );
+        tools
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_tool_approval_round_trip() {
+        let deleted = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let model: Arc<dyn LanguageModel> = Arc::new(ApprovalMockLanguageModel);
+        let mut messages = vec![Message::User(UserMessage::new("Delete a.txt and b.txt"))];
+
+        let result = GenerateText::new(Arc::clone(&model), Prompt::messages(messages.clone()))
+            .tools(delete_file_tool_set(Arc::clone(&deleted)))
+            .stop_when(vec![Box::new(step_count_is(5))])
+            .execute()
+            .await
+            .unwrap();
+
+        // The calls wait for approval instead of being executed
+        assert_eq!(result.steps.len(), 1);
+        assert!(deleted.lock().unwrap().is_empty());
+        let pending = result.pending_approvals();
+        assert_eq!(pending.len(), 2);
+        assert_eq!(pending[0].tool_call.tool_call_id, "call_1");
+        assert_eq!(pending[1].tool_call.tool_call_id, "call_2");
+
+        let approvals = vec![
+            ToolApprovalResponse::granted(pending[0].approval_id.clone()),
+            ToolApprovalResponse::denied(pending[1].approval_id.clone()).with_reason("Keep b.txt"),
+        ];
+        messages.extend(result.response.messages.into_iter().map(Message::from));
+
+        let result = GenerateText::new(model, Prompt::messages(messages))
+            .tools(delete_file_tool_set(Arc::clone(&deleted)))
+            .resume_with_approvals(approvals)
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(*deleted.lock().unwrap(), vec!["a.txt".to_string()]);
+        assert_eq!(result.text, "Done");
+        assert!(result.pending_approvals().is_empty());
+        assert_eq!(result.tool_results.len(), 1);
+        assert_eq!(result.tool_results[0].tool_call_id, "call_1");
+        match result
+            .content
+            .iter()
+            .find(|part| part.is_tool_output_denied())
+        {
+            Some(Output::ToolOutputDenied(denied)) => {
+                assert_eq!(denied.tool_call_id, "call_2");
+                assert_eq!(denied.reason.as_deref(), Some("Keep b.txt"));
+            }
+            _ => panic!("Expected denied tool output"),
+        }
+
+        // The tool outputs are sent to the model in a tool message before its answer
+        assert_eq!(result.response.messages.len(), 2);
+        assert!(result.response.messages[0].is_tool());
+        assert!(result.response.messages[1].is_assistant());
+    }
 }
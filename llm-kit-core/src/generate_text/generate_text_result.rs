@@ -8,9 +8,10 @@ use serde_json::Value;
 use super::generated_file::GeneratedFile;
 use super::response_message::ResponseMessage;
 use super::step_result::{RequestMetadata, StepResponseMetadata, StepResult};
+use crate::error::{AISDKError, NoObjectGeneratedErrorBuilder};
 use crate::output::Output;
 use crate::output::reasoning::ReasoningOutput;
-use llm_kit_provider_utils::tool::{ToolCall, ToolResult};
+use llm_kit_provider_utils::tool::{ToolApprovalRequestOutput, ToolCall, ToolResult};
 
 /// Metadata for the response, including messages and optional body.
 #[derive(Debug, Clone, PartialEq)]
@@ -121,6 +122,9 @@ pub struct GenerateTextResult {
     /// You can use this to get information about intermediate steps,
     /// such as the tool calls or the response headers.
     pub steps: Vec<StepResult>,
+
+    /// Whether a JSON response format was requested, see [`output`](Self::output).
+    json_output: bool,
 }
 
 impl GenerateTextResult {
@@ -175,6 +179,7 @@ impl GenerateTextResult {
             response: ResponseMetadata::from_step_metadata(vec![], final_step.response.clone()),
             provider_metadata: final_step.provider_metadata.clone(),
             steps,
+            json_output: false,
         }
     }
 
@@ -215,7 +220,56 @@ impl GenerateTextResult {
             response,
             provider_metadata,
             steps,
+            json_output: false,
+        }
+    }
+
+    /// Gets the tool approval requests of the final step.
+    ///
+    /// Tool calls to tools that need approval are not executed. Instead, the generation
+    /// stops with an approval request for each of them. Answer the requests with
+    /// [`GenerateText::resume_with_approvals`](crate::GenerateText::resume_with_approvals)
+    /// to execute or deny the tool calls and continue the generation.
+    pub fn pending_approvals(&self) -> Vec<&ToolApprovalRequestOutput> {
+        self.content
+            .iter()
+            .filter_map(|part| {
+                if let Output::ToolApprovalRequest(approval_request) = part {
+                    Some(approval_request)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Gets the structured output parsed from the text of the last step.
+    ///
+    /// Returns `None` unless a JSON response format was requested, or when the
+    /// generation stopped with pending tool approvals.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NoObjectGenerated` error when the text is not valid JSON.
+    pub fn output(&self) -> Result<Option<Value>, AISDKError> {
+        if !self.json_output || !self.pending_approvals().is_empty() {
+            return Ok(None);
         }
+
+        serde_json::from_str(&self.text).map(Some).map_err(|error| {
+            NoObjectGeneratedErrorBuilder::new()
+                .message(format!("Could not parse the response: {}", error))
+                .text(self.text.clone())
+                .usage(self.usage)
+                .finish_reason(self.finish_reason.clone())
+                .build()
+        })
+    }
+
+    /// Parses the text of the last step as JSON in [`output`](Self::output).
+    pub(crate) fn with_json_output(mut self) -> Self {
+        self.json_output = true;
+        self
     }
 }
 
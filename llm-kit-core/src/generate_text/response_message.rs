@@ -101,6 +101,15 @@ impl From<Message> for ResponseMessage {
     }
 }
 
+impl From<ResponseMessage> for Message {
+    fn from(message: ResponseMessage) -> Self {
+        match message {
+            ResponseMessage::Assistant(msg) => Message::Assistant(msg),
+            ResponseMessage::Tool(msg) => Message::Tool(msg),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,24 +1,315 @@
 use crate::error::AISDKError;
+use llm_kit_provider::error::ProviderError;
+use rand::Rng;
 use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
 use tokio_util::sync::CancellationToken;
 
+/// Information about a failed attempt that is passed to a [`RetryPolicy`].
+#[derive(Clone, Copy)]
+pub struct RetryAttempt<'a> {
+    /// The error returned by the failed attempt.
+    pub error: &'a (dyn std::error::Error + 'static),
+    /// The number of the failed attempt, starting at 1.
+    pub attempt: u32,
+    /// The total time spent waiting between the previous attempts.
+    pub total_delay: Duration,
+}
+
+/// Decides whether and when a failed operation is retried.
+///
+/// The maximum number of retries is enforced by [`RetryConfig`]. The policy is only
+/// consulted while retries remain, and can stop retrying earlier by returning `None`.
+///
+/// # Example
+///
+/// ```
+/// use llm_kit_core::generate_text::{RetryAttempt, RetryPolicy, is_retryable_error};
+/// use std::time::Duration;
+///
+/// /// Retries retryable errors after a fixed delay of one second.
+/// struct FixedDelayPolicy;
+///
+/// impl RetryPolicy for FixedDelayPolicy {
+///     fn retry_delay(&self, attempt: &RetryAttempt<'_>) -> Option<Duration> {
+///         is_retryable_error(attempt.error).then_some(Duration::from_secs(1))
+///     }
+/// }
+/// ```
+pub trait RetryPolicy: Send + Sync {
+    /// Returns the delay before the next attempt, or `None` if the error must not be retried.
+    fn retry_delay(&self, attempt: &RetryAttempt<'_>) -> Option<Duration>;
+
+    /// Called before waiting for the next attempt.
+    ///
+    /// Defaults to doing nothing.
+    fn on_retry(&self, _attempt: &RetryAttempt<'_>, _delay: Duration) {}
+}
+
+/// Callback that is invoked before each retry with the failed attempt and the delay.
+pub type OnRetryCallback = Arc<dyn Fn(&RetryAttempt<'_>, Duration) + Send + Sync>;
+
+/// The default retry policy.
+///
+/// Retries errors that are classified as retryable by [`is_retryable_error`] with
+/// exponential backoff. When the provider requests a delay through the `retry-after-ms`
+/// or `retry-after` response headers, that delay is used instead of the backoff, capped
+/// at the maximum delay.
+///
+/// # Example
+///
+/// ```
+/// use llm_kit_core::generate_text::DefaultRetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = DefaultRetryPolicy::new()
+///     .with_initial_delay(Duration::from_millis(500))
+///     .with_max_delay(Duration::from_secs(10))
+///     .with_max_total_delay(Duration::from_secs(30))
+///     .with_jitter(0.2)
+///     .with_on_retry(|attempt, delay| {
+///         println!("Attempt {} failed, retrying in {:?}", attempt.attempt, delay);
+///     });
+/// ```
+#[derive(Clone)]
+pub struct DefaultRetryPolicy {
+    initial_delay: Duration,
+    backoff_factor: f64,
+    max_delay: Duration,
+    max_total_delay: Option<Duration>,
+    jitter: f64,
+    on_retry: Option<OnRetryCallback>,
+}
+
+impl DefaultRetryPolicy {
+    /// Creates a new policy with an initial delay of 100ms, a backoff factor of 2,
+    /// a maximum delay of 60s, no limit on the total delay and no jitter.
+    pub fn new() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(100),
+            backoff_factor: 2.0,
+            max_delay: Duration::from_secs(60),
+            max_total_delay: None,
+            jitter: 0.0,
+            on_retry: None,
+        }
+    }
+
+    /// Sets the delay before the first retry.
+    pub fn with_initial_delay(mut self, delay: Duration) -> Self {
+        self.initial_delay = delay;
+        self
+    }
+
+    /// Sets the factor by which the delay grows after each retry.
+    pub fn with_backoff_factor(mut self, factor: f64) -> Self {
+        self.backoff_factor = factor;
+        self
+    }
+
+    /// Sets the maximum delay before a single retry.
+    pub fn with_max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Sets the maximum total time spent waiting between attempts.
+    ///
+    /// A retry whose delay would exceed this budget is not performed.
+    pub fn with_max_total_delay(mut self, delay: Duration) -> Self {
+        self.max_total_delay = Some(delay);
+        self
+    }
+
+    /// Sets the jitter as a fraction between 0 and 1.
+    ///
+    /// Each backoff delay is randomly reduced by up to this fraction, which spreads out
+    /// retries of concurrent requests.
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets a callback that is invoked before each retry.
+    pub fn with_on_retry<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&RetryAttempt<'_>, Duration) + Send + Sync + 'static,
+    {
+        self.on_retry = Some(Arc::new(callback));
+        self
+    }
+
+    /// Returns the backoff delay for the given attempt, without jitter.
+    fn backoff_delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let seconds = self.initial_delay.as_secs_f64() * self.backoff_factor.powi(exponent);
+
+        Duration::try_from_secs_f64(seconds)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+
+    /// Randomly reduces the delay by up to the configured jitter.
+    fn apply_jitter(&self, delay: Duration) -> Duration {
+        if self.jitter == 0.0 {
+            return delay;
+        }
+
+        let reduction = rand::thread_rng().gen_range(0.0..=self.jitter);
+        delay.mul_f64(1.0 - reduction)
+    }
+}
+
+impl Default for DefaultRetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RetryPolicy for DefaultRetryPolicy {
+    fn retry_delay(&self, attempt: &RetryAttempt<'_>) -> Option<Duration> {
+        if !is_retryable_error(attempt.error) {
+            return None;
+        }
+
+        let delay = match retry_after_delay(attempt.error) {
+            Some(delay) => delay.min(self.max_delay),
+            None => self.apply_jitter(self.backoff_delay(attempt.attempt)),
+        };
+
+        if let Some(max_total_delay) = self.max_total_delay
+            && attempt.total_delay + delay > max_total_delay
+        {
+            return None;
+        }
+
+        Some(delay)
+    }
+
+    fn on_retry(&self, attempt: &RetryAttempt<'_>, delay: Duration) {
+        if let Some(callback) = &self.on_retry {
+            callback(attempt, delay);
+        }
+    }
+}
+
+/// Returns whether an error returned by a model call can be retried.
+///
+/// - [`ProviderError::APICallError`]s are classified by their `is_retryable` flag.
+///   Client errors (4xx) other than 408, 409 and 429 are never retried.
+/// - Other [`ProviderError`]s, such as invalid prompts or missing models, are not retried.
+/// - [`AISDKError::RetryableError`] and [`AISDKError::ModelError`] are retried, other
+///   [`AISDKError`]s are not.
+/// - `reqwest` errors are retried for timeouts, connection failures and retryable
+///   status codes.
+/// - Any other error carries no structured information and is not retried.
+pub fn is_retryable_error(error: &(dyn std::error::Error + 'static)) -> bool {
+    if let Some(error) = error.downcast_ref::<ProviderError>() {
+        return match error {
+            ProviderError::APICallError {
+                status_code: Some(code),
+                ..
+            } if (400..500).contains(code) && !is_retryable_status(*code) => false,
+            ProviderError::APICallError { is_retryable, .. } => *is_retryable,
+            _ => false,
+        };
+    }
+
+    if let Some(error) = error.downcast_ref::<AISDKError>() {
+        return matches!(
+            error,
+            AISDKError::RetryableError { .. } | AISDKError::ModelError { .. }
+        );
+    }
+
+    if let Some(error) = error.downcast_ref::<reqwest::Error>() {
+        return error.is_timeout()
+            || error.is_connect()
+            || error.is_request()
+            || error
+                .status()
+                .is_some_and(|status| is_retryable_status(status.as_u16()));
+    }
+
+    false
+}
+
+/// Returns the delay requested by the provider for the next attempt, if any.
+///
+/// The delay is read from [`AISDKError::RetryableError`] or from the `retry-after-ms`
+/// and `retry-after` response headers of a [`ProviderError::APICallError`].
+/// `retry-after` values in the HTTP-date format and delays that do not fit into a
+/// [`Duration`] are ignored.
+pub fn retry_after_delay(error: &(dyn std::error::Error + 'static)) -> Option<Duration> {
+    if let Some(AISDKError::RetryableError { retry_after, .. }) = error.downcast_ref::<AISDKError>()
+    {
+        return *retry_after;
+    }
+
+    let Some(ProviderError::APICallError {
+        response_headers: Some(headers),
+        ..
+    }) = error.downcast_ref::<ProviderError>()
+    else {
+        return None;
+    };
+
+    let header = |name: &str| {
+        headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .and_then(|(_, value)| value.trim().parse::<f64>().ok())
+            .filter(|value| value.is_finite() && *value >= 0.0)
+    };
+
+    header("retry-after-ms")
+        .and_then(|millis| Duration::try_from_secs_f64(millis / 1000.0).ok())
+        .or_else(|| header("retry-after").and_then(|secs| Duration::try_from_secs_f64(secs).ok()))
+}
+
+/// Returns whether an HTTP status code indicates a transient failure.
+fn is_retryable_status(code: u16) -> bool {
+    code == 408 || code == 409 || code == 429 || code >= 500
+}
+
 /// Configuration for retry behavior.
+#[derive(Clone)]
 pub struct RetryConfig {
     /// Maximum number of retry attempts.
     pub max_retries: u32,
     /// Optional cancellation token to abort retry attempts.
     pub abort_signal: Option<CancellationToken>,
+    /// Policy that decides whether and when a failed attempt is retried.
+    pub retry_policy: Arc<dyn RetryPolicy>,
 }
 
 impl RetryConfig {
+    /// Creates a new configuration with the [`DefaultRetryPolicy`].
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            abort_signal: None,
+            retry_policy: Arc::new(DefaultRetryPolicy::new()),
+        }
+    }
+
+    /// Sets the cancellation token to abort retry attempts.
+    pub fn with_abort_signal(mut self, abort_signal: CancellationToken) -> Self {
+        self.abort_signal = Some(abort_signal);
+        self
+    }
+
+    /// Sets the policy that decides whether and when a failed attempt is retried.
+    pub fn with_retry_policy(mut self, retry_policy: Arc<dyn RetryPolicy>) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
     /// Execute an async operation with retry logic.
     ///
-    /// This method will retry failed operations up to `max_retries` times with
-    /// exponential backoff, respecting retry hints from `AISDKError::RetryableError`
-    /// and abort signals.
-    ///
-    /// When an error is `AISDKError::RetryableError` with a `retry_after` hint,
-    /// that delay will be used instead of the default exponential backoff.
+    /// Failed operations are retried up to `max_retries` times. The retry policy
+    /// decides whether an error is retried and how long to wait before the next attempt.
     ///
     /// # Arguments
     ///
@@ -35,10 +326,7 @@ impl RetryConfig {
     /// use llm_kit_core::error::AISDKError;
     ///
     /// # async fn example() -> Result<(), AISDKError> {
-    /// let config = RetryConfig {
-    ///     max_retries: 3,
-    ///     abort_signal: None,
-    /// };
+    /// let config = RetryConfig::new(3);
     ///
     /// # let tool_call = ();
     /// # async fn execute_tool(_: ()) -> Result<String, AISDKError> { Ok("result".to_string()) }
@@ -54,107 +342,93 @@ impl RetryConfig {
         F: Fn() -> Fut,
         Fut: Future<Output = Result<T, AISDKError>>,
     {
-        let mut retries = 0;
-        let mut default_delay = std::time::Duration::from_millis(100); // Start with 100ms
+        let mut attempt = 0;
+        let mut total_delay = Duration::ZERO;
 
         loop {
-            // Check if we should abort
-            if let Some(ref token) = self.abort_signal
-                && token.is_cancelled()
-            {
-                return Err(AISDKError::model_error("Operation cancelled".to_string()));
-            }
-
-            // Try the operation
-            match operation().await {
-                Ok(result) => return Ok(result),
-                Err(e) => {
-                    if retries >= self.max_retries {
-                        return Err(e);
-                    }
-
-                    // Extract retry delay hint from RetryableError, or use exponential backoff
-                    let delay = match &e {
-                        AISDKError::RetryableError { retry_after, .. } => {
-                            retry_after.unwrap_or(default_delay)
-                        }
-                        _ => default_delay,
-                    };
+            self.check_aborted()?;
+            attempt += 1;
 
-                    // Wait with the appropriate delay
-                    tokio::time::sleep(delay).await;
-
-                    // Double the default delay for next retry (exponential backoff)
-                    default_delay *= 2;
-                    retries += 1;
-                }
-            }
+            let delay = match operation().await {
+                Ok(result) => return Ok(result),
+                Err(error) => match self.next_delay(&error, attempt, total_delay) {
+                    Some(delay) => delay,
+                    None => return Err(error),
+                },
+            };
+
+            tokio::time::sleep(delay).await;
+            total_delay += delay;
         }
     }
 
     /// Execute an async operation with retry logic, converting boxed errors to AISDKError.
     ///
-    /// This method will retry failed operations up to `max_retries` times with
-    /// exponential backoff, respecting retry headers and abort signals.
-    ///
-    /// When an error contains a retry delay hint (e.g., from a Retry-After header),
-    /// that delay will be used instead of the default exponential backoff.
-    ///
-    /// **Note**: This method is mainly for backward compatibility. For new code,
-    /// prefer using [`execute`](Self::execute) which works with `AISDKError` directly
-    /// and provides better type safety with structured retry hints.
-    ///
-    /// This variant is specifically designed for operations that return
-    /// `Result<T, Box<dyn std::error::Error>>`. It parses retry hints from error
-    /// strings, whereas `execute` can use structured `RetryableError` variants.
+    /// This variant is designed for model calls that return
+    /// `Result<T, Box<dyn std::error::Error>>`. The boxed error is passed to the retry
+    /// policy as is, so that structured provider errors such as
+    /// [`ProviderError::APICallError`] can be classified. The final error is converted
+    /// to [`AISDKError::ModelError`].
     ///
     /// # See Also
     ///
-    /// * [`execute`](Self::execute) - Recommended for new code using `Result<T, AISDKError>`
+    /// * [`execute`](Self::execute) - For operations that return `Result<T, AISDKError>`
     pub async fn execute_with_boxed_error<F, Fut, T>(&self, operation: F) -> Result<T, AISDKError>
     where
         F: Fn() -> Fut,
         Fut: Future<Output = Result<T, Box<dyn std::error::Error>>>,
     {
-        let mut retries = 0;
-        let mut default_delay = std::time::Duration::from_millis(100); // Start with 100ms
+        let mut attempt = 0;
+        let mut total_delay = Duration::ZERO;
 
         loop {
-            // Check if we should abort
-            if let Some(ref token) = self.abort_signal
-                && token.is_cancelled()
-            {
-                return Err(AISDKError::model_error("Operation cancelled".to_string()));
-            }
-
-            // Try the operation
-            match operation().await {
+            self.check_aborted()?;
+            attempt += 1;
+
+            let delay = match operation().await {
                 Ok(result) => return Ok(result),
-                Err(e) => {
-                    if retries >= self.max_retries {
-                        // Check if the error string contains a retry hint
-                        // This is a fallback for errors that don't use AISDKError::RetryableError
-                        return Err(AISDKError::model_error(e.to_string()));
-                    }
+                Err(error) => match self.next_delay(error.as_ref(), attempt, total_delay) {
+                    Some(delay) => delay,
+                    None => return Err(AISDKError::model_error(error.to_string())),
+                },
+            };
+
+            tokio::time::sleep(delay).await;
+            total_delay += delay;
+        }
+    }
 
-                    // Try to extract retry delay from error message
-                    // If error contains "retry after X seconds", parse it
-                    let delay =
-                        if let Some(retry_delay) = extract_retry_delay_from_error(&e.to_string()) {
-                            retry_delay
-                        } else {
-                            default_delay
-                        };
-
-                    // Wait with the appropriate delay
-                    tokio::time::sleep(delay).await;
-
-                    // Double the default delay for next retry (exponential backoff)
-                    default_delay *= 2;
-                    retries += 1;
-                }
-            }
+    /// Returns an error if the abort signal has been triggered.
+    fn check_aborted(&self) -> Result<(), AISDKError> {
+        if let Some(ref token) = self.abort_signal
+            && token.is_cancelled()
+        {
+            return Err(AISDKError::model_error("Operation cancelled".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the delay before the next attempt, or `None` if the error is final.
+    fn next_delay(
+        &self,
+        error: &(dyn std::error::Error + 'static),
+        attempt: u32,
+        total_delay: Duration,
+    ) -> Option<Duration> {
+        if attempt > self.max_retries {
+            return None;
         }
+
+        let retry_attempt = RetryAttempt {
+            error,
+            attempt,
+            total_delay,
+        };
+        let delay = self.retry_policy.retry_delay(&retry_attempt)?;
+        self.retry_policy.on_retry(&retry_attempt, delay);
+
+        Some(delay)
     }
 }
 
@@ -163,15 +437,17 @@ impl RetryConfig {
 /// # Arguments
 /// * `max_retries` - Optional maximum number of retries. Defaults to 2 if not specified.
 /// * `abort_signal` - Optional cancellation token to abort retry attempts.
+/// * `retry_policy` - Optional retry policy. Defaults to [`DefaultRetryPolicy`].
 ///
 /// # Returns
-/// Returns a `RetryConfig` with validated max_retries and abort signal.
+/// Returns a `RetryConfig` with validated max_retries, abort signal and retry policy.
 ///
 /// # Errors
 /// Returns an error if max_retries is negative.
 pub fn prepare_retries(
     max_retries: Option<u32>,
     abort_signal: Option<CancellationToken>,
+    retry_policy: Option<Arc<dyn RetryPolicy>>,
 ) -> Result<RetryConfig, AISDKError> {
     // Validate max_retries (Rust's u32 ensures it's non-negative and an integer)
     let max_retries_result = max_retries.unwrap_or(2);
@@ -179,101 +455,235 @@ pub fn prepare_retries(
     Ok(RetryConfig {
         max_retries: max_retries_result,
         abort_signal,
+        retry_policy: retry_policy.unwrap_or_else(|| Arc::new(DefaultRetryPolicy::new())),
     })
 }
 
-/// Extract retry delay from error message.
-///
-/// This function attempts to parse retry delay hints embedded in error messages.
-/// It looks for patterns like "retry after X seconds" or "retry-after: X".
-///
-/// # Arguments
-///
-/// * `error_message` - The error message string to parse
-///
-/// # Returns
-///
-/// `Some(Duration)` if a retry delay was found, `None` otherwise.
-pub(crate) fn extract_retry_delay_from_error(error_message: &str) -> Option<std::time::Duration> {
-    // Check for "retry after X seconds" pattern (case-insensitive)
-    let lower = error_message.to_lowercase();
-
-    // Try to find "retry after X" or "retry-after: X"
-    if let Some(idx) = lower.find("retry") {
-        let after_retry = &lower[idx..];
-
-        // Look for numbers after "retry after" or "retry-after"
-        for word in after_retry.split_whitespace() {
-            // Remove common punctuation
-            let cleaned = word.trim_matches(|c: char| !c.is_ascii_digit());
-            if let Ok(seconds) = cleaned.parse::<u64>() {
-                // Found a number that looks like retry delay in seconds
-                // Cap at reasonable maximum (1 hour)
-                let capped_seconds = seconds.min(3600);
-                return Some(std::time::Duration::from_secs(capped_seconds));
-            }
-        }
-    }
-
-    None
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_prepare_retries_default() {
-        let config = prepare_retries(None, None).unwrap();
+        let config = prepare_retries(None, None, None).unwrap();
         assert_eq!(config.max_retries, 2);
     }
 
     #[test]
     fn test_prepare_retries_custom() {
-        let config = prepare_retries(Some(5), None).unwrap();
+        let config = prepare_retries(Some(5), None, None).unwrap();
         assert_eq!(config.max_retries, 5);
     }
 
     #[test]
     fn test_prepare_retries_zero() {
-        let config = prepare_retries(Some(0), None).unwrap();
+        let config = prepare_retries(Some(0), None, None).unwrap();
         assert_eq!(config.max_retries, 0);
     }
 
+    fn api_call_error(
+        status_code: u16,
+        headers: Option<std::collections::HashMap<String, String>>,
+    ) -> ProviderError {
+        ProviderError::api_call_error_with_details(
+            "Request failed",
+            "https://api.example.com",
+            "{}",
+            Some(status_code),
+            headers,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
     #[test]
-    fn test_extract_retry_delay_simple() {
-        let delay = extract_retry_delay_from_error("Rate limit exceeded. Retry after 60 seconds");
-        assert_eq!(delay, Some(std::time::Duration::from_secs(60)));
+    fn test_is_retryable_error_classifies_api_call_errors() {
+        assert!(is_retryable_error(&api_call_error(429, None)));
+        assert!(is_retryable_error(&api_call_error(503, None)));
+        assert!(!is_retryable_error(&api_call_error(400, None)));
+        assert!(!is_retryable_error(&api_call_error(401, None)));
+
+        // Client errors are never retried, even when flagged as retryable
+        let error = ProviderError::api_call_error_with_details(
+            "Unauthorized",
+            "https://api.example.com",
+            "{}",
+            Some(401),
+            None,
+            None,
+            Some(true),
+            None,
+            None,
+        );
+        assert!(!is_retryable_error(&error));
     }
 
     #[test]
-    fn test_extract_retry_delay_with_punctuation() {
-        let delay = extract_retry_delay_from_error("Error: retry-after: 30");
-        assert_eq!(delay, Some(std::time::Duration::from_secs(30)));
+    fn test_is_retryable_error_other_errors() {
+        assert!(!is_retryable_error(&ProviderError::invalid_prompt(
+            "Bad prompt",
+            "prompt"
+        )));
+        assert!(is_retryable_error(&AISDKError::retryable_error("Retry")));
+        assert!(!is_retryable_error(&AISDKError::invalid_prompt(
+            "Bad prompt"
+        )));
+
+        // Errors without structured information, like a 401 reported as a string
+        let error: Box<dyn std::error::Error> = "API request failed with status 401".into();
+        assert!(!is_retryable_error(error.as_ref()));
     }
 
     #[test]
-    fn test_extract_retry_delay_case_insensitive() {
-        let delay = extract_retry_delay_from_error("RETRY AFTER 45 SECONDS");
-        assert_eq!(delay, Some(std::time::Duration::from_secs(45)));
+    fn test_retry_after_delay_from_headers() {
+        let headers = std::collections::HashMap::from([
+            ("Retry-After".to_string(), "3".to_string()),
+            ("retry-after-ms".to_string(), "1500".to_string()),
+        ]);
+        assert_eq!(
+            retry_after_delay(&api_call_error(429, Some(headers))),
+            Some(Duration::from_millis(1500))
+        );
+
+        let headers =
+            std::collections::HashMap::from([("retry-after".to_string(), "2".to_string())]);
+        assert_eq!(
+            retry_after_delay(&api_call_error(429, Some(headers))),
+            Some(Duration::from_secs(2))
+        );
+
+        let headers = std::collections::HashMap::from([(
+            "retry-after".to_string(),
+            "Wed, 21 Oct 2015 07:28:00 GMT".to_string(),
+        )]);
+        assert_eq!(retry_after_delay(&api_call_error(429, Some(headers))), None);
+        assert_eq!(retry_after_delay(&api_call_error(429, None)), None);
+
+        // Delays that do not fit into a Duration are ignored instead of panicking
+        let headers =
+            std::collections::HashMap::from([("retry-after".to_string(), "1e30".to_string())]);
+        assert_eq!(retry_after_delay(&api_call_error(429, Some(headers))), None);
+        let headers =
+            std::collections::HashMap::from([("retry-after-ms".to_string(), "1e30".to_string())]);
+        assert_eq!(retry_after_delay(&api_call_error(429, Some(headers))), None);
     }
 
     #[test]
-    fn test_extract_retry_delay_caps_at_max() {
-        let delay = extract_retry_delay_from_error("Retry after 7200 seconds");
-        assert_eq!(delay, Some(std::time::Duration::from_secs(3600))); // Capped at 1 hour
+    fn test_default_policy_backoff_and_max_delay() {
+        let policy = DefaultRetryPolicy::new()
+            .with_initial_delay(Duration::from_secs(1))
+            .with_max_delay(Duration::from_secs(3));
+        let error = api_call_error(500, None);
+        let delay = |attempt| {
+            policy.retry_delay(&RetryAttempt {
+                error: &error,
+                attempt,
+                total_delay: Duration::ZERO,
+            })
+        };
+
+        assert_eq!(delay(1), Some(Duration::from_secs(1)));
+        assert_eq!(delay(2), Some(Duration::from_secs(2)));
+        assert_eq!(delay(3), Some(Duration::from_secs(3)));
+        assert_eq!(delay(100), Some(Duration::from_secs(3)));
     }
 
     #[test]
-    fn test_extract_retry_delay_no_match() {
-        let delay = extract_retry_delay_from_error("Generic error message");
-        assert_eq!(delay, None);
+    fn test_default_policy_honours_retry_after_up_to_max_delay() {
+        let policy = DefaultRetryPolicy::new().with_max_delay(Duration::from_secs(10));
+
+        let headers =
+            std::collections::HashMap::from([("retry-after".to_string(), "5".to_string())]);
+        let error = api_call_error(429, Some(headers));
+        let attempt = RetryAttempt {
+            error: &error,
+            attempt: 1,
+            total_delay: Duration::ZERO,
+        };
+        assert_eq!(policy.retry_delay(&attempt), Some(Duration::from_secs(5)));
+
+        // A requested delay above the maximum is capped
+        let headers =
+            std::collections::HashMap::from([("retry-after".to_string(), "120".to_string())]);
+        let error = api_call_error(429, Some(headers));
+        let attempt = RetryAttempt {
+            error: &error,
+            attempt: 1,
+            total_delay: Duration::ZERO,
+        };
+        assert_eq!(policy.retry_delay(&attempt), Some(Duration::from_secs(10)));
     }
 
     #[test]
-    fn test_extract_retry_delay_no_number() {
-        let delay = extract_retry_delay_from_error("Retry after a while");
-        assert_eq!(delay, None);
+    fn test_default_policy_max_total_delay_and_jitter() {
+        let policy = DefaultRetryPolicy::new()
+            .with_initial_delay(Duration::from_secs(1))
+            .with_max_total_delay(Duration::from_secs(2))
+            .with_jitter(0.5);
+        let error = api_call_error(503, None);
+
+        for _ in 0..20 {
+            let delay = policy
+                .retry_delay(&RetryAttempt {
+                    error: &error,
+                    attempt: 1,
+                    total_delay: Duration::ZERO,
+                })
+                .unwrap();
+            assert!(delay >= Duration::from_millis(500));
+            assert!(delay <= Duration::from_secs(1));
+        }
+
+        let attempt = RetryAttempt {
+            error: &error,
+            attempt: 2,
+            total_delay: Duration::from_millis(1900),
+        };
+        assert_eq!(policy.retry_delay(&attempt), None);
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_boxed_error_does_not_retry_client_errors() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let config = RetryConfig::new(3);
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<(), AISDKError> = config
+            .execute_with_boxed_error(|| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                async { Err(Box::new(api_call_error(401, None)) as Box<dyn std::error::Error>) }
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_execute_calls_on_retry() {
+        use std::sync::Mutex;
+
+        let retries = Arc::new(Mutex::new(Vec::new()));
+        let retries_clone = Arc::clone(&retries);
+        let policy = DefaultRetryPolicy::new()
+            .with_initial_delay(Duration::from_millis(1))
+            .with_on_retry(move |attempt, delay| {
+                retries_clone.lock().unwrap().push((attempt.attempt, delay));
+            });
+        let config = RetryConfig::new(2).with_retry_policy(Arc::new(policy));
+
+        let result: Result<(), AISDKError> = config
+            .execute(|| async { Err(AISDKError::retryable_error("Overloaded")) })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            *retries.lock().unwrap(),
+            vec![(1, Duration::from_millis(1)), (2, Duration::from_millis(2))]
+        );
     }
 
     #[tokio::test]
@@ -281,10 +691,7 @@ mod tests {
         use std::sync::Arc;
         use std::sync::atomic::{AtomicU32, Ordering};
 
-        let config = RetryConfig {
-            max_retries: 3,
-            abort_signal: None,
-        };
+        let config = RetryConfig::new(3);
 
         let attempts = Arc::new(AtomicU32::new(0));
         let attempts_clone = attempts.clone();
@@ -309,10 +716,7 @@ mod tests {
         use std::sync::atomic::{AtomicU32, Ordering};
         use std::time::Duration;
 
-        let config = RetryConfig {
-            max_retries: 2,
-            abort_signal: None,
-        };
+        let config = RetryConfig::new(2);
 
         let attempts = Arc::new(AtomicU32::new(0));
         let attempts_clone = attempts.clone();
@@ -351,10 +755,7 @@ mod tests {
         use std::sync::Arc;
         use std::sync::atomic::{AtomicU32, Ordering};
 
-        let config = RetryConfig {
-            max_retries: 3,
-            abort_signal: None,
-        };
+        let config = RetryConfig::new(3);
 
         let attempts = Arc::new(AtomicU32::new(0));
         let attempts_clone = attempts.clone();
@@ -388,10 +789,7 @@ mod tests {
         use std::sync::Arc;
         use std::sync::atomic::{AtomicU32, Ordering};
 
-        let config = RetryConfig {
-            max_retries: 2,
-            abort_signal: None,
-        };
+        let config = RetryConfig::new(2);
 
         let attempts = Arc::new(AtomicU32::new(0));
         let attempts_clone = attempts.clone();
@@ -422,10 +820,7 @@ mod tests {
         use std::sync::atomic::{AtomicU32, Ordering};
 
         let token = CancellationToken::new();
-        let config = RetryConfig {
-            max_retries: 5,
-            abort_signal: Some(token.clone()),
-        };
+        let config = RetryConfig::new(5).with_abort_signal(token.clone());
 
         let token_clone = token.clone();
         let attempts = Arc::new(AtomicU32::new(0));
@@ -458,10 +853,7 @@ mod tests {
         use std::sync::Arc;
         use std::sync::atomic::{AtomicU32, Ordering};
 
-        let config = RetryConfig {
-            max_retries: 2,
-            abort_signal: None,
-        };
+        let config = RetryConfig::new(2);
 
         let attempts = Arc::new(AtomicU32::new(0));
         let attempts_clone = attempts.clone();
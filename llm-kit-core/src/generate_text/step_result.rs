@@ -269,6 +269,26 @@ impl StepResult {
             })
             .collect()
     }
+
+    /// Gets all tool approval requests from the content.
+    ///
+    /// # Returns
+    ///
+    /// A vector of references to tool approval requests.
+    pub fn tool_approval_requests(
+        &self,
+    ) -> Vec<&llm_kit_provider_utils::tool::ToolApprovalRequestOutput> {
+        self.content
+            .iter()
+            .filter_map(|part| {
+                if let Output::ToolApprovalRequest(approval_request) = part {
+                    Some(approval_request)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
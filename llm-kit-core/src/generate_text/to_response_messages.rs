@@ -3,9 +3,12 @@ use crate::output::Output;
 use crate::prompt::create_tool_model_output::{ErrorMode, create_tool_model_output};
 use crate::tool::ToolSet;
 use llm_kit_provider_utils::message::{
-    AssistantMessage, ToolMessage, assistant::AssistantContentPart,
-    content_parts::tool_result::ToolResultPart, tool::ToolContentPart,
+    AssistantMessage, ToolMessage,
+    assistant::AssistantContentPart,
+    content_parts::tool_result::{ToolResultOutput, ToolResultPart},
+    tool::ToolContentPart,
 };
+use llm_kit_provider_utils::tool::ToolApprovalRequest;
 
 /// Converts the result of a `generate_text` call to a list of response messages.
 ///
@@ -52,6 +55,10 @@ pub fn to_response_messages(content: Vec<Output>, tools: Option<&ToolSet>) -> Ve
                     // Include only if provider executed
                     error.provider_executed == Some(true)
                 }
+                Output::ToolOutputDenied(denied) => {
+                    // Include only if provider executed
+                    denied.provider_executed == Some(true)
+                }
                 _ => true,
             }
         })
@@ -63,25 +70,42 @@ pub fn to_response_messages(content: Vec<Output>, tools: Option<&ToolSet>) -> Ve
             }
         })
         .filter_map(|part| {
+            // Provider metadata is passed back as provider options so that providers can
+            // round-trip their own item identifiers (e.g. OpenAI reasoning items).
             match part {
-                Output::Text(text_output) => Some(AssistantContentPart::Text(
-                    llm_kit_provider_utils::message::content_parts::TextPart::new(
-                        text_output.text.clone(),
-                    ),
-                )),
-                Output::Reasoning(reasoning_output) => Some(AssistantContentPart::Reasoning(
-                    llm_kit_provider_utils::message::content_parts::ReasoningPart::new(
-                        reasoning_output.text.clone(),
-                    ),
-                )),
-                Output::ToolCall(tool_call) => Some(AssistantContentPart::ToolCall(
-                    llm_kit_provider_utils::message::content_parts::ToolCallPart::new(
-                        tool_call.tool_call_id.clone(),
-                        tool_call.tool_name.clone(),
-                        tool_call.input.clone(),
-                    )
-                    .with_provider_executed(tool_call.provider_executed.unwrap_or(false)),
-                )),
+                Output::Text(text_output) => {
+                    let mut text_part =
+                        llm_kit_provider_utils::message::content_parts::TextPart::new(
+                            text_output.text.clone(),
+                        );
+                    if let Some(ref metadata) = text_output.provider_metadata {
+                        text_part = text_part.with_provider_options(metadata.clone());
+                    }
+                    Some(AssistantContentPart::Text(text_part))
+                }
+                Output::Reasoning(reasoning_output) => {
+                    let mut reasoning_part =
+                        llm_kit_provider_utils::message::content_parts::ReasoningPart::new(
+                            reasoning_output.text.clone(),
+                        );
+                    if let Some(ref metadata) = reasoning_output.provider_metadata {
+                        reasoning_part = reasoning_part.with_provider_options(metadata.clone());
+                    }
+                    Some(AssistantContentPart::Reasoning(reasoning_part))
+                }
+                Output::ToolCall(tool_call) => {
+                    let mut tool_call_part =
+                        llm_kit_provider_utils::message::content_parts::ToolCallPart::new(
+                            tool_call.tool_call_id.clone(),
+                            tool_call.tool_name.clone(),
+                            tool_call.input.clone(),
+                        )
+                        .with_provider_executed(tool_call.provider_executed.unwrap_or(false));
+                    if let Some(ref metadata) = tool_call.provider_metadata {
+                        tool_call_part = tool_call_part.with_provider_options(metadata.clone());
+                    }
+                    Some(AssistantContentPart::ToolCall(tool_call_part))
+                }
                 Output::ToolResult(result) => {
                     // Get the tool from the toolset
                     let tool = tools.and_then(|ts| ts.get(&result.tool_name));
@@ -110,6 +134,19 @@ pub fn to_response_messages(content: Vec<Output>, tools: Option<&ToolSet>) -> Ve
                         provider_options: None,
                     }))
                 }
+                Output::ToolOutputDenied(denied) => {
+                    Some(AssistantContentPart::ToolResult(ToolResultPart::new(
+                        denied.tool_call_id.clone(),
+                        denied.tool_name.clone(),
+                        ToolResultOutput::execution_denied(denied.reason.clone()),
+                    )))
+                }
+                Output::ToolApprovalRequest(approval_request) => Some(
+                    AssistantContentPart::ToolApprovalRequest(ToolApprovalRequest::new(
+                        approval_request.approval_id.clone(),
+                        approval_request.tool_call.tool_call_id.clone(),
+                    )),
+                ),
                 _ => None,
             }
         })
@@ -125,12 +162,12 @@ pub fn to_response_messages(content: Vec<Output>, tools: Option<&ToolSet>) -> Ve
     // Filter and map content for tool message (client-executed tool results)
     let tool_content: Vec<ToolResultPart> = content
         .iter()
-        .filter(|part| matches!(part, Output::ToolResult(_) | Output::ToolError(_)))
         .filter(|part| {
             // Include only if NOT provider-executed
             match part {
                 Output::ToolResult(result) => result.provider_executed != Some(true),
                 Output::ToolError(error) => error.provider_executed != Some(true),
+                Output::ToolOutputDenied(denied) => denied.provider_executed != Some(true),
                 _ => false,
             }
         })
@@ -159,6 +196,11 @@ pub fn to_response_messages(content: Vec<Output>, tools: Option<&ToolSet>) -> Ve
                     provider_options: None,
                 }
             }
+            Output::ToolOutputDenied(denied) => ToolResultPart::new(
+                denied.tool_call_id.clone(),
+                denied.tool_name.clone(),
+                ToolResultOutput::execution_denied(denied.reason.clone()),
+            ),
             _ => unreachable!(),
         })
         .collect();
@@ -181,7 +223,10 @@ pub fn to_response_messages(content: Vec<Output>, tools: Option<&ToolSet>) -> Ve
 mod tests {
     use super::*;
     use crate::generate_text::{ReasoningOutput, TextOutput};
-    use llm_kit_provider_utils::tool::{ToolCall, ToolError, ToolResult};
+    use llm_kit_provider_utils::message::AssistantContent;
+    use llm_kit_provider_utils::tool::{
+        ToolApprovalRequestOutput, ToolCall, ToolError, ToolOutputDenied, ToolResult,
+    };
     use serde_json::json;
 
     #[test]
@@ -233,6 +278,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_to_response_messages_forwards_provider_metadata() {
+        let mut metadata = std::collections::HashMap::new();
+        metadata.insert(
+            "openai".to_string(),
+            [("itemId".to_string(), json!("rs_1"))]
+                .into_iter()
+                .collect(),
+        );
+        let content = vec![Output::Reasoning(
+            ReasoningOutput::new("").with_provider_metadata(metadata.clone()),
+        )];
+        let messages = to_response_messages(content, None);
+
+        match &messages[0] {
+            ResponseMessage::Assistant(msg) => match &msg.content {
+                llm_kit_provider_utils::message::AssistantContent::Parts(parts) => {
+                    match &parts[0] {
+                        AssistantContentPart::Reasoning(part) => {
+                            assert_eq!(part.provider_options, Some(metadata));
+                        }
+                        _ => panic!("Expected reasoning part"),
+                    }
+                }
+                _ => panic!("Expected content parts"),
+            },
+            _ => panic!("Expected assistant message"),
+        }
+    }
+
     #[test]
     fn test_to_response_messages_with_tool_call() {
         let tool_call = ToolCall::new("call_1", "test_tool", json!({"arg": "value"}));
@@ -344,4 +419,50 @@ mod tests {
             _ => panic!("Expected tool message second"),
         }
     }
+
+    #[test]
+    fn test_to_response_messages_tool_approval_and_denial() {
+        let tool_call = ToolCall::new("call_1", "delete_file", json!({"path": "a.txt"}));
+        let approval_request = ToolApprovalRequestOutput::new("approval_1", tool_call.clone());
+        let denied = ToolOutputDenied::new("call_2", "delete_file", json!({"path": "b.txt"}))
+            .with_reason("Not allowed");
+
+        let content = vec![
+            Output::ToolCall(tool_call),
+            Output::ToolApprovalRequest(approval_request),
+            Output::ToolOutputDenied(denied),
+        ];
+        let messages = to_response_messages(content, None);
+
+        assert_eq!(messages.len(), 2);
+        match &messages[0] {
+            ResponseMessage::Assistant(msg) => match &msg.content {
+                AssistantContent::Parts(parts) => {
+                    assert_eq!(parts.len(), 2);
+                    match &parts[1] {
+                        AssistantContentPart::ToolApprovalRequest(request) => {
+                            assert_eq!(request.approval_id, "approval_1");
+                            assert_eq!(request.tool_call_id, "call_1");
+                        }
+                        _ => panic!("Expected tool approval request"),
+                    }
+                }
+                _ => panic!("Expected assistant content parts"),
+            },
+            _ => panic!("Expected assistant message first"),
+        }
+        match &messages[1] {
+            ResponseMessage::Tool(msg) => match &msg.content[0] {
+                ToolContentPart::ToolResult(result) => {
+                    assert_eq!(result.tool_call_id, "call_2");
+                    assert_eq!(
+                        result.output,
+                        ToolResultOutput::execution_denied(Some("Not allowed".to_string()))
+                    );
+                }
+                _ => panic!("Expected tool result"),
+            },
+            _ => panic!("Expected tool message second"),
+        }
+    }
 }
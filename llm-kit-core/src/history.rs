@@ -0,0 +1,126 @@
+/// Strategy that keeps the last N messages.
+mod last_messages;
+/// Strategy that replaces older messages with a model-generated summary.
+mod summarize;
+/// Strategy that keeps the most recent messages within a token budget.
+mod token_budget;
+
+pub use last_messages::LastMessages;
+pub use summarize::SummarizeOlderMessages;
+pub use token_budget::{TokenBudget, TokenEstimator, estimate_tokens};
+
+use crate::error::AISDKError;
+use async_trait::async_trait;
+use llm_kit_provider_utils::message::Message;
+
+/// Decides which part of a conversation history is sent to the model.
+///
+/// History strategies are applied to the history loaded from storage before each call,
+/// so long sessions can be kept within the model's context window. Implementations must
+/// keep tool calls and their results together: the [`Message::Tool`] messages that follow
+/// an assistant message are either all kept with it or all dropped.
+///
+/// # Example
+///
+/// ```
+/// use llm_kit_core::error::AISDKError;
+/// use llm_kit_core::history::HistoryStrategy;
+/// use llm_kit_provider_utils::message::Message;
+/// use async_trait::async_trait;
+///
+/// /// Drops system messages from the stored history.
+/// struct WithoutSystemMessages;
+///
+/// #[async_trait]
+/// impl HistoryStrategy for WithoutSystemMessages {
+///     async fn apply(&self, messages: Vec<Message>) -> Result<Vec<Message>, AISDKError> {
+///         Ok(messages
+///             .into_iter()
+///             .filter(|message| !matches!(message, Message::System(_)))
+///             .collect())
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait HistoryStrategy: Send + Sync {
+    /// Returns the messages to send, given the full history in chronological order.
+    async fn apply(&self, messages: Vec<Message>) -> Result<Vec<Message>, AISDKError>;
+}
+
+/// Returns the start indices of the message groups that must be kept or dropped together.
+///
+/// A tool message belongs to the group of the message before it, so a group is an
+/// assistant message with its tool results, or a single other message.
+pub(crate) fn group_starts(messages: &[Message]) -> Vec<usize> {
+    messages
+        .iter()
+        .enumerate()
+        .filter(|(index, message)| *index == 0 || !matches!(message, Message::Tool(_)))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Moves a cut index back to the start of its group, so no tool result loses its call.
+pub(crate) fn align_to_group_start(messages: &[Message], index: usize) -> usize {
+    let mut index = index.min(messages.len());
+    while index > 0 && index < messages.len() && matches!(messages[index], Message::Tool(_)) {
+        index -= 1;
+    }
+    index
+}
+
+#[cfg(test)]
+pub(crate) mod test_messages {
+    use llm_kit_provider_utils::message::{
+        AssistantContentPart, AssistantMessage, Message, ToolCallPart, ToolContentPart,
+        ToolMessage, ToolResultOutput, ToolResultPart, UserMessage,
+    };
+
+    pub fn user(text: &str) -> Message {
+        Message::User(UserMessage::new(text))
+    }
+
+    pub fn assistant(text: &str) -> Message {
+        Message::Assistant(AssistantMessage::new(text))
+    }
+
+    pub fn tool_call(id: &str) -> Message {
+        Message::Assistant(AssistantMessage::with_parts(vec![
+            AssistantContentPart::ToolCall(ToolCallPart::new(id, "search", serde_json::json!({}))),
+        ]))
+    }
+
+    pub fn tool_result(id: &str) -> Message {
+        Message::Tool(ToolMessage::new(vec![ToolContentPart::ToolResult(
+            ToolResultPart::new(id, "search", ToolResultOutput::text("done")),
+        )]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_messages::*;
+    use super::*;
+
+    #[test]
+    fn test_group_starts_attach_tool_messages() {
+        let messages = vec![
+            user("hi"),
+            tool_call("1"),
+            tool_result("1"),
+            tool_result("1"),
+            assistant("done"),
+        ];
+
+        assert_eq!(group_starts(&messages), vec![0, 1, 4]);
+    }
+
+    #[test]
+    fn test_align_to_group_start() {
+        let messages = vec![user("hi"), tool_call("1"), tool_result("1"), user("next")];
+
+        assert_eq!(align_to_group_start(&messages, 2), 1);
+        assert_eq!(align_to_group_start(&messages, 3), 3);
+        assert_eq!(align_to_group_start(&messages, 10), 4);
+    }
+}
@@ -0,0 +1,69 @@
+use super::{HistoryStrategy, align_to_group_start};
+use crate::error::AISDKError;
+use async_trait::async_trait;
+use llm_kit_provider_utils::message::Message;
+
+/// Keeps the last N messages of the history.
+///
+/// When the cut would separate tool results from their tool call, the tool call is kept
+/// as well, so the result can contain slightly more than N messages.
+///
+/// # Example
+///
+/// ```
+/// use llm_kit_core::history::LastMessages;
+///
+/// let strategy = LastMessages::new(20);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LastMessages {
+    count: usize,
+}
+
+impl LastMessages {
+    /// Creates a strategy that keeps the last `count` messages.
+    pub fn new(count: usize) -> Self {
+        Self { count }
+    }
+}
+
+#[async_trait]
+impl HistoryStrategy for LastMessages {
+    async fn apply(&self, mut messages: Vec<Message>) -> Result<Vec<Message>, AISDKError> {
+        let start = align_to_group_start(&messages, messages.len().saturating_sub(self.count));
+        Ok(messages.split_off(start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::test_messages::*;
+
+    #[tokio::test]
+    async fn test_keeps_last_messages() {
+        let messages = vec![user("1"), assistant("2"), user("3"), assistant("4")];
+
+        let kept = LastMessages::new(2).apply(messages).await.unwrap();
+
+        assert_eq!(kept, vec![user("3"), assistant("4")]);
+    }
+
+    #[tokio::test]
+    async fn test_keeps_tool_call_with_its_results() {
+        let messages = vec![user("1"), tool_call("a"), tool_result("a"), assistant("2")];
+
+        let kept = LastMessages::new(2).apply(messages).await.unwrap();
+
+        assert_eq!(kept, vec![tool_call("a"), tool_result("a"), assistant("2")]);
+    }
+
+    #[tokio::test]
+    async fn test_short_history_is_unchanged() {
+        let messages = vec![user("1"), assistant("2")];
+
+        let kept = LastMessages::new(10).apply(messages.clone()).await.unwrap();
+
+        assert_eq!(kept, messages);
+    }
+}
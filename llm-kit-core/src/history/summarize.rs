@@ -0,0 +1,195 @@
+use super::{HistoryStrategy, align_to_group_start};
+use crate::error::AISDKError;
+use crate::generate_text::GenerateText;
+use crate::prompt::Prompt;
+use async_trait::async_trait;
+use llm_kit_provider::language_model::LanguageModel;
+use llm_kit_provider_utils::message::{Message, SystemMessage, UserMessage};
+use std::sync::Arc;
+
+/// Default instructions for summarizing older messages.
+const DEFAULT_INSTRUCTIONS: &str = "Summarize the conversation above. Keep all facts, decisions, \
+     open questions and tool results that may be needed to continue the conversation. \
+     Reply with the summary only.";
+
+/// Keeps the last N messages and replaces the older ones with a summary.
+///
+/// The older messages are summarized by the given model and the summary is inserted as a
+/// system message before the kept messages. The split never separates a tool call from its
+/// results. The summary is generated on every call that has more than N messages, so a
+/// small, fast model is usually a good choice.
+///
+/// # Example
+///
+/// ```no_run
+/// use llm_kit_core::history::SummarizeOlderMessages;
+/// # use llm_kit_provider::LanguageModel;
+/// # use std::sync::Arc;
+/// # fn example(summary_model: Arc<dyn LanguageModel>) {
+///
+/// let strategy = SummarizeOlderMessages::new(summary_model, 10)
+///     .with_instructions("Summarize the conversation above in at most five bullet points.");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SummarizeOlderMessages {
+    model: Arc<dyn LanguageModel>,
+    keep_last: usize,
+    instructions: String,
+}
+
+impl SummarizeOlderMessages {
+    /// Creates a strategy that keeps the last `keep_last` messages and summarizes the rest.
+    pub fn new(model: Arc<dyn LanguageModel>, keep_last: usize) -> Self {
+        Self {
+            model,
+            keep_last,
+            instructions: DEFAULT_INSTRUCTIONS.to_string(),
+        }
+    }
+
+    /// Sets the instructions that ask the model for the summary.
+    pub fn with_instructions(mut self, instructions: impl Into<String>) -> Self {
+        self.instructions = instructions.into();
+        self
+    }
+}
+
+#[async_trait]
+impl HistoryStrategy for SummarizeOlderMessages {
+    async fn apply(&self, mut messages: Vec<Message>) -> Result<Vec<Message>, AISDKError> {
+        let start = align_to_group_start(&messages, messages.len().saturating_sub(self.keep_last));
+        if start == 0 {
+            return Ok(messages);
+        }
+
+        let recent = messages.split_off(start);
+        let mut summary_prompt = messages;
+        summary_prompt.push(Message::User(UserMessage::new(self.instructions.clone())));
+
+        let summary = GenerateText::new(Arc::clone(&self.model), Prompt::messages(summary_prompt))
+            .execute()
+            .await?;
+
+        let mut result = Vec::with_capacity(recent.len() + 1);
+        result.push(Message::System(SystemMessage::new(format!(
+            "Summary of the earlier conversation:\n{}",
+            summary.text
+        ))));
+        result.extend(recent);
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::test_messages::*;
+    use llm_kit_provider::language_model::{
+        LanguageModelGenerateResponse, LanguageModelStreamResponse,
+        call_options::LanguageModelCallOptions,
+        content::{LanguageModelContent, text::LanguageModelText},
+        finish_reason::LanguageModelFinishReason,
+        usage::LanguageModelUsage,
+    };
+    use regex::Regex;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    // Mock LanguageModel that answers every call with a fixed summary
+    #[derive(Default)]
+    struct SummaryMockLanguageModel {
+        prompt_lengths: Mutex<Vec<usize>>,
+    }
+
+    #[async_trait]
+    impl LanguageModel for SummaryMockLanguageModel {
+        fn provider(&self) -> &str {
+            "test-provider"
+        }
+
+        fn model_id(&self) -> &str {
+            "summary-model"
+        }
+
+        async fn supported_urls(&self) -> HashMap<String, Vec<Regex>> {
+            HashMap::new()
+        }
+
+        async fn do_generate(
+            &self,
+            options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelGenerateResponse, Box<dyn std::error::Error>> {
+            self.prompt_lengths
+                .lock()
+                .unwrap()
+                .push(options.prompt.len());
+
+            Ok(LanguageModelGenerateResponse {
+                content: vec![LanguageModelContent::Text(LanguageModelText::new(
+                    "They talked.",
+                ))],
+                finish_reason: LanguageModelFinishReason::Stop,
+                usage: LanguageModelUsage::default(),
+                provider_metadata: None,
+                request: None,
+                response: None,
+                warnings: Vec::new(),
+            })
+        }
+
+        async fn do_stream(
+            &self,
+            _options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelStreamResponse, Box<dyn std::error::Error>> {
+            unimplemented!("Mock implementation")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_summarizes_older_messages() {
+        let model = Arc::new(SummaryMockLanguageModel::default());
+        let messages = vec![
+            user("1"),
+            tool_call("a"),
+            tool_result("a"),
+            user("2"),
+            assistant("3"),
+        ];
+
+        // Keeping 3 messages would split the tool call from its result
+        let kept = SummarizeOlderMessages::new(model.clone(), 3)
+            .apply(messages)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            kept,
+            vec![
+                Message::System(SystemMessage::new(
+                    "Summary of the earlier conversation:\nThey talked."
+                )),
+                tool_call("a"),
+                tool_result("a"),
+                user("2"),
+                assistant("3"),
+            ]
+        );
+        // The summarized message plus the instructions
+        assert_eq!(*model.prompt_lengths.lock().unwrap(), vec![2]);
+    }
+
+    #[tokio::test]
+    async fn test_short_history_is_not_summarized() {
+        let model = Arc::new(SummaryMockLanguageModel::default());
+        let messages = vec![user("1"), assistant("2")];
+
+        let kept = SummarizeOlderMessages::new(model.clone(), 2)
+            .apply(messages.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(kept, messages);
+        assert!(model.prompt_lengths.lock().unwrap().is_empty());
+    }
+}
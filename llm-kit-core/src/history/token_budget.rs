@@ -0,0 +1,142 @@
+use super::{HistoryStrategy, group_starts};
+use crate::error::AISDKError;
+use async_trait::async_trait;
+use llm_kit_provider_utils::message::Message;
+use std::sync::Arc;
+
+/// Function that estimates the number of tokens of a message.
+pub type TokenEstimator = Arc<dyn Fn(&Message) -> usize + Send + Sync>;
+
+/// Estimates the number of tokens of a message from its serialized size.
+///
+/// Uses the common approximation of four characters per token. This is only a rough
+/// estimate; use [`TokenBudget::with_estimator`] with the model's tokenizer for
+/// accurate budgets.
+pub fn estimate_tokens(message: &Message) -> usize {
+    serde_json::to_string(message)
+        .map(|json| json.len().div_ceil(4))
+        .unwrap_or(0)
+}
+
+/// Keeps the most recent messages that fit in a token budget.
+///
+/// Messages are added from the newest to the oldest until the next message would exceed
+/// the budget. A tool call and its results are added or dropped together, and the newest
+/// message is always kept, even if it alone exceeds the budget.
+///
+/// # Example
+///
+/// ```
+/// use llm_kit_core::history::TokenBudget;
+///
+/// let strategy = TokenBudget::new(8_000);
+/// ```
+#[derive(Clone)]
+pub struct TokenBudget {
+    max_tokens: usize,
+    estimator: TokenEstimator,
+}
+
+impl TokenBudget {
+    /// Creates a strategy with the given budget, using [`estimate_tokens`].
+    pub fn new(max_tokens: usize) -> Self {
+        Self {
+            max_tokens,
+            estimator: Arc::new(estimate_tokens),
+        }
+    }
+
+    /// Sets the function used to estimate the number of tokens of a message.
+    pub fn with_estimator<F>(mut self, estimator: F) -> Self
+    where
+        F: Fn(&Message) -> usize + Send + Sync + 'static,
+    {
+        self.estimator = Arc::new(estimator);
+        self
+    }
+}
+
+#[async_trait]
+impl HistoryStrategy for TokenBudget {
+    async fn apply(&self, mut messages: Vec<Message>) -> Result<Vec<Message>, AISDKError> {
+        let mut start = messages.len();
+        let mut used = 0;
+
+        for group_start in group_starts(&messages).into_iter().rev() {
+            let tokens: usize = messages[group_start..start]
+                .iter()
+                .map(|message| (self.estimator)(message))
+                .sum();
+
+            if used + tokens > self.max_tokens && start < messages.len() {
+                break;
+            }
+
+            used += tokens;
+            start = group_start;
+        }
+
+        Ok(messages.split_off(start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::test_messages::*;
+
+    fn one_token_per_message() -> TokenBudget {
+        TokenBudget::new(0).with_estimator(|_| 1)
+    }
+
+    #[tokio::test]
+    async fn test_keeps_messages_within_budget() {
+        let messages = vec![user("1"), assistant("2"), user("3"), assistant("4")];
+
+        let strategy = TokenBudget {
+            max_tokens: 3,
+            ..one_token_per_message()
+        };
+        let kept = strategy.apply(messages).await.unwrap();
+
+        assert_eq!(kept, vec![assistant("2"), user("3"), assistant("4")]);
+    }
+
+    #[tokio::test]
+    async fn test_drops_tool_call_with_its_results() {
+        let messages = vec![
+            user("1"),
+            tool_call("a"),
+            tool_result("a"),
+            user("2"),
+            assistant("3"),
+        ];
+
+        // The tool call group needs 2 tokens, but only 1 is left
+        let strategy = TokenBudget {
+            max_tokens: 3,
+            ..one_token_per_message()
+        };
+        let kept = strategy.apply(messages).await.unwrap();
+
+        assert_eq!(kept, vec![user("2"), assistant("3")]);
+    }
+
+    #[tokio::test]
+    async fn test_always_keeps_newest_message() {
+        let messages = vec![user("1"), user("2")];
+
+        let kept = one_token_per_message().apply(messages).await.unwrap();
+
+        assert_eq!(kept, vec![user("2")]);
+    }
+
+    #[test]
+    fn test_estimate_tokens() {
+        let message = user("a".repeat(400).as_str());
+
+        let tokens = estimate_tokens(&message);
+
+        assert!((100..120).contains(&tokens), "estimated {tokens} tokens");
+    }
+}
@@ -164,12 +164,16 @@
 //! - [`embed`]: Embedding generation (single and batch)
 //! - [`error`]: Error types for the SDK
 //! - [`generate_image`]: Image generation
+//! - [`generate_object`]: Typed object generation
 //! - [`generate_speech`]: Speech synthesis
 //! - [`generate_text`]: Text generation with tool calling
 //! - [`output`]: Unified output types (text, reasoning, sources)
 //! - [`prompt`]: Message types and prompt management
+//! - [`registry`]: Provider registry and custom providers
 //! - [`rerank`]: Document reranking
+//! - [`stream_object`]: Typed object streaming
 //! - [`stream_text`]: Text streaming with callbacks
+//! - [`telemetry`]: Telemetry settings and tracing instrumentation
 //! - [`tool`]: Tool system for function calling
 //! - [`transcribe`]: Audio transcription
 
@@ -183,25 +187,37 @@ pub mod embed;
 pub mod error;
 /// Image generation functionality.
 pub mod generate_image;
+/// Typed object generation with JSON schemas.
+pub mod generate_object;
 /// Speech synthesis functionality.
 pub mod generate_speech;
 /// Text generation with tool calling support.
 pub mod generate_text;
+/// Strategies that fit loaded conversation history into the model's context window.
+pub mod history;
 /// Unified output types for text, reasoning, and sources.
 pub mod output;
 /// Message types and prompt management.
 pub mod prompt;
+/// Provider registry with `"provider:model"` id resolution and custom providers.
+pub mod registry;
 /// Document reranking functionality.
 pub mod rerank;
 /// Storage conversion utilities (requires storage feature).
 #[cfg(feature = "storage")]
 pub mod storage_conversion;
+/// Typed object streaming with partial objects.
+pub mod stream_object;
 /// Text streaming with callbacks and transforms.
 pub mod stream_text;
+/// Telemetry settings and tracing instrumentation (spans require the telemetry feature).
+pub mod telemetry;
 /// Tool system for function calling (dynamic and type-safe).
 pub mod tool;
 /// Audio transcription functionality.
 pub mod transcribe;
+/// UI message stream protocol and streaming HTTP responses.
+pub mod ui_message_stream;
 
 pub use agent::{
     Agent, AgentCallParameters, AgentFinishEvent, AgentInterface, AgentOnFinishCallback,
@@ -214,27 +230,35 @@ pub use embed::{
 };
 pub use error::AISDKError;
 pub use generate_image::{GenerateImage, GenerateImageResult, ImageModelResponseMetadata};
+pub use generate_object::{GenerateObject, GenerateObjectResult, ObjectGenerationMode};
 pub use generate_speech::{
     GenerateSpeech, GenerateSpeechResult, GeneratedAudioFile, GeneratedAudioFileWithType,
 };
 pub use generate_text::{
-    FinishEvent, GenerateText, GenerateTextResult, GeneratedFile, HasToolCall, OnFinish,
-    OnStepFinish, PrepareStep, PrepareStepOptions, PrepareStepResult, RequestMetadata,
-    ResponseMessage, ResponseMetadata, StepCountIs, StepResponseMetadata, StepResult,
+    DefaultRetryPolicy, FinishEvent, GenerateText, GenerateTextResult, GeneratedFile, HasToolCall,
+    OnFinish, OnStepFinish, PrepareStep, PrepareStepOptions, PrepareStepResult, RequestMetadata,
+    ResponseMessage, ResponseMetadata, RetryPolicy, StepCountIs, StepResponseMetadata, StepResult,
     StopCondition, as_output, has_tool_call, is_stop_condition_met, step_count_is,
     to_response_messages,
 };
+pub use history::{HistoryStrategy, LastMessages, SummarizeOlderMessages, TokenBudget};
 pub use output::{Output, reasoning::ReasoningOutput, source::SourceOutput, text::TextOutput};
+pub use registry::{CustomProvider, CustomProviderOptions, ProviderRegistry, custom_provider};
 pub use rerank::{RankedDocumentWithValue, Rerank, RerankResponseMetadata, RerankResult};
+pub use stream_object::{ObjectStreamPart, StreamObject, StreamObjectResult};
+#[cfg(feature = "storage")]
+pub use stream_text::resume_stream;
 pub use stream_text::{
     AbortEvent, AsyncIterableStream, ChunkEvent, ChunkStreamPart, ConsumeStreamOptions, ErrorEvent,
     ErrorHandler, OnAbortCallback, OnChunkCallback, OnErrorCallback, OnFinishCallback,
     OnStepFinishCallback, StreamFinishEvent, StreamGeneratedFile, StreamText, StreamTextResult,
     TextStreamPart,
 };
+pub use telemetry::TelemetrySettings;
 pub use tool::{
     ToolCallRepairFunction, ToolCallRepairOptions, ToolSet, execute_tool_call, is_approval_needed,
     no_repair, parse_provider_executed_dynamic_tool_call, parse_tool_call,
     prepare_tools_and_tool_choice,
 };
 pub use transcribe::{AudioInput, Transcribe, TranscriptionResult};
+pub use ui_message_stream::{UIMessageChunk, UIMessageStreamOptions};
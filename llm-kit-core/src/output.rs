@@ -10,13 +10,16 @@ pub use source::SourceOutput;
 pub use text::TextOutput;
 
 use crate::generate_text::GeneratedFile;
-use llm_kit_provider_utils::tool::{ToolCall, ToolError, ToolResult};
+use llm_kit_provider_utils::tool::{
+    ToolApprovalRequestOutput, ToolCall, ToolError, ToolOutputDenied, ToolResult,
+};
 use serde::{Deserialize, Serialize};
 
 /// An output part that can appear in an assistant message.
 ///
 /// This represents all possible outputs that can be included in a response,
-/// including text, reasoning, sources, files, tool calls, tool results, and tool errors.
+/// including text, reasoning, sources, files, tool calls, tool results, tool errors,
+/// tool approval requests, and denied tool calls.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Output {
@@ -40,6 +43,12 @@ pub enum Output {
 
     /// A tool error.
     ToolError(ToolError),
+
+    /// A request to approve a tool call before it is executed.
+    ToolApprovalRequest(ToolApprovalRequestOutput),
+
+    /// A tool call whose execution was denied.
+    ToolOutputDenied(ToolOutputDenied),
 }
 
 impl Output {
@@ -77,6 +86,16 @@ impl Output {
     pub fn is_tool_error(&self) -> bool {
         matches!(self, Output::ToolError(_))
     }
+
+    /// Returns true if this is a tool approval request.
+    pub fn is_tool_approval_request(&self) -> bool {
+        matches!(self, Output::ToolApprovalRequest(_))
+    }
+
+    /// Returns true if this is a denied tool call.
+    pub fn is_tool_output_denied(&self) -> bool {
+        matches!(self, Output::ToolOutputDenied(_))
+    }
 }
 
 #[cfg(test)]
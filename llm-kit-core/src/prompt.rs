@@ -4,6 +4,8 @@ pub mod call_settings;
 pub mod convert_to_language_model_prompt;
 /// Creation of tool model output from responses.
 pub mod create_tool_model_output;
+/// Downloading of prompt URLs that models don't support.
+pub mod download;
 /// Standardization of prompts for language models.
 pub mod standardize;
 
@@ -326,7 +326,9 @@ fn convert_tool_result_output(
 
 /// Convert a core ToolResultContentPart to a provider ToolResultContentItem.
 ///
-/// Returns None for items that cannot be converted (like FileId which providers don't support).
+/// Returns None for items that cannot be converted. URL and file ID items are inlined
+/// before conversion by [`download`](crate::prompt::download), so any left here are
+/// dropped.
 fn convert_tool_result_content_part(
     part: llm_kit_provider_utils::message::content_parts::ToolResultContentPart,
 ) -> Option<llm_kit_provider::language_model::prompt::LanguageModelToolResultContentItem> {
@@ -358,16 +360,11 @@ fn convert_tool_result_content_part(
             Some(LanguageModelToolResultContentItem::Media { data, media_type })
         }
 
-        // For URL-based and ID-based items, we can't convert them directly
-        // The provider expects base64 data, not URLs
+        // The provider expects base64 data, not URLs; these are inlined before conversion
         ToolResultContentPart::FileUrl { .. }
         | ToolResultContentPart::FileId { .. }
         | ToolResultContentPart::ImageUrl { .. }
-        | ToolResultContentPart::ImageFileId { .. } => {
-            // TODO: In a real implementation, we might want to download these
-            // For now, skip them
-            None
-        }
+        | ToolResultContentPart::ImageFileId { .. } => None,
 
         ToolResultContentPart::Custom { .. } => {
             // Custom items aren't supported by provider
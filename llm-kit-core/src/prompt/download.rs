@@ -0,0 +1,747 @@
+use crate::error::AISDKError;
+use crate::prompt::standardize::StandardizedPrompt;
+use async_trait::async_trait;
+use base64::Engine;
+use llm_kit_provider::LanguageModel;
+use llm_kit_provider_utils::message::content_parts::{
+    FileId, FilePart, FileSource, ImagePart, ImageSource, ToolResultContentPart, ToolResultOutput,
+};
+use llm_kit_provider_utils::message::{
+    AssistantContent, AssistantContentPart, DataContent, Message, ToolContentPart, UserContent,
+    UserContentPart,
+};
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Default size limit of a download: 20 MiB.
+pub const DEFAULT_MAX_DOWNLOAD_BYTES: usize = 20 * 1024 * 1024;
+
+/// Content downloaded for a prompt.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DownloadedFile {
+    /// The raw content.
+    pub data: Vec<u8>,
+
+    /// The media type reported by the source, if any.
+    pub media_type: Option<String>,
+}
+
+/// Downloads the content of URLs and file IDs that a model can't fetch itself.
+///
+/// Prompt conversion inlines image and file URLs that are not in the model's
+/// [`supported_urls`](LanguageModel::supported_urls), and all URLs and file IDs in
+/// tool results, since providers only accept inline tool result content.
+///
+/// # Example
+///
+/// ```
+/// use async_trait::async_trait;
+/// use llm_kit_core::error::AISDKError;
+/// use llm_kit_core::prompt::download::{DownloadedFile, Downloader};
+///
+/// /// Serves URLs from an in-process asset cache.
+/// struct AssetDownloader;
+///
+/// #[async_trait]
+/// impl Downloader for AssetDownloader {
+///     async fn download(&self, url: &str) -> Result<DownloadedFile, AISDKError> {
+///         Err(AISDKError::download(url, "not in the asset cache"))
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait Downloader: Send + Sync {
+    /// Downloads the content of a URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AISDKError::Download` if the content can't be downloaded.
+    async fn download(&self, url: &str) -> Result<DownloadedFile, AISDKError>;
+
+    /// Downloads the content of a provider file ID.
+    ///
+    /// File IDs refer to files stored with a provider, so there is no generic way to
+    /// download them; the default implementation returns an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AISDKError::Download` if the content can't be downloaded.
+    async fn download_file_id(&self, file_id: &str) -> Result<DownloadedFile, AISDKError> {
+        Err(AISDKError::download(
+            file_id,
+            "file IDs can't be downloaded by this downloader",
+        ))
+    }
+}
+
+/// Downloads URLs over HTTP with a size limit.
+///
+/// # Example
+///
+/// ```
+/// use llm_kit_core::prompt::download::DefaultDownloader;
+///
+/// let downloader = DefaultDownloader::new().with_max_bytes(5 * 1024 * 1024);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DefaultDownloader {
+    client: reqwest::Client,
+    max_bytes: usize,
+}
+
+impl Default for DefaultDownloader {
+    fn default() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            max_bytes: DEFAULT_MAX_DOWNLOAD_BYTES,
+        }
+    }
+}
+
+impl DefaultDownloader {
+    /// Creates a downloader with the default size limit.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum size of a download in bytes.
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Sets the HTTP client used for downloads.
+    pub fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Returns the error for content that exceeds the size limit.
+    fn too_large(&self, url: &str) -> AISDKError {
+        AISDKError::download(
+            url,
+            format!("content exceeds the size limit of {} bytes", self.max_bytes),
+        )
+    }
+}
+
+#[async_trait]
+impl Downloader for DefaultDownloader {
+    async fn download(&self, url: &str) -> Result<DownloadedFile, AISDKError> {
+        let mut response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| AISDKError::download(url, e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(AISDKError::download_builder(url)
+                .status_code(status.as_u16())
+                .message(format!("HTTP {}", status))
+                .build());
+        }
+        if response
+            .content_length()
+            .is_some_and(|length| length > self.max_bytes as u64)
+        {
+            return Err(self.too_large(url));
+        }
+
+        let media_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(';').next())
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+
+        // Read in chunks so a missing or wrong content length can't exceed the limit
+        let mut data = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| AISDKError::download(url, e.to_string()))?
+        {
+            if data.len() + chunk.len() > self.max_bytes {
+                return Err(self.too_large(url));
+            }
+            data.extend_from_slice(&chunk);
+        }
+
+        Ok(DownloadedFile { data, media_type })
+    }
+}
+
+/// Inlines the URLs and file IDs of prompts for a model, across the steps of a call.
+///
+/// Downloads are cached by URL, so a URL in the conversation is downloaded once per
+/// call instead of once per step.
+pub(crate) struct PromptDownloads {
+    downloader: Arc<dyn Downloader>,
+    /// Provider name that file IDs are keyed by, e.g. `openai` for `openai.chat`
+    provider: String,
+    supported_urls: HashMap<String, Vec<Regex>>,
+    cache: HashMap<String, DownloadedFile>,
+}
+
+impl PromptDownloads {
+    /// Creates the downloads for a model, using the default downloader if none is given.
+    pub(crate) async fn new(
+        model: &dyn LanguageModel,
+        downloader: Option<Arc<dyn Downloader>>,
+    ) -> Self {
+        Self {
+            downloader: downloader.unwrap_or_else(|| Arc::new(DefaultDownloader::new())),
+            provider: model
+                .provider()
+                .split('.')
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+            supported_urls: model.supported_urls().await,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Inlines the URLs the model doesn't support and the tool result URLs and file IDs.
+    pub(crate) async fn inline(
+        &mut self,
+        mut prompt: StandardizedPrompt,
+    ) -> Result<StandardizedPrompt, AISDKError> {
+        for message in &mut prompt.messages {
+            match message {
+                Message::User(user_msg) => {
+                    if let UserContent::Parts(parts) = &mut user_msg.content {
+                        for part in parts {
+                            self.inline_user_part(part).await?;
+                        }
+                    }
+                }
+                Message::Assistant(asst_msg) => {
+                    if let AssistantContent::Parts(parts) = &mut asst_msg.content {
+                        for part in parts {
+                            if let AssistantContentPart::ToolResult(tool_result) = part {
+                                self.inline_tool_result_output(&mut tool_result.output)
+                                    .await?;
+                            }
+                        }
+                    }
+                }
+                Message::Tool(tool_msg) => {
+                    for part in &mut tool_msg.content {
+                        if let ToolContentPart::ToolResult(tool_result) = part {
+                            self.inline_tool_result_output(&mut tool_result.output)
+                                .await?;
+                        }
+                    }
+                }
+                Message::System(_) => {}
+            }
+        }
+
+        Ok(prompt)
+    }
+
+    /// Inlines an image or file URL of a user message if the model doesn't support it.
+    async fn inline_user_part(&mut self, part: &mut UserContentPart) -> Result<(), AISDKError> {
+        match part {
+            UserContentPart::Image(ImagePart {
+                image: image @ ImageSource::Url(_),
+                media_type,
+                ..
+            }) => {
+                let ImageSource::Url(url) = &*image else {
+                    return Ok(());
+                };
+                let part_media_type = media_type.as_deref().unwrap_or("image/*");
+                if self.is_supported(part_media_type, url.as_str()) {
+                    return Ok(());
+                }
+
+                let (data, downloaded_media_type) = self.fetch(url.as_str()).await?;
+                *image = ImageSource::Data(DataContent::Bytes(data));
+                if media_type.is_none() {
+                    *media_type = downloaded_media_type;
+                }
+            }
+            UserContentPart::File(FilePart {
+                data: data @ FileSource::Url(_),
+                media_type,
+                ..
+            }) => {
+                let FileSource::Url(url) = &*data else {
+                    return Ok(());
+                };
+                if self.is_supported(media_type, url.as_str()) {
+                    return Ok(());
+                }
+
+                let (bytes, _) = self.fetch(url.as_str()).await?;
+                *data = FileSource::Data(DataContent::Bytes(bytes));
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Inlines the URLs and file IDs of tool result content.
+    async fn inline_tool_result_output(
+        &mut self,
+        output: &mut ToolResultOutput,
+    ) -> Result<(), AISDKError> {
+        let ToolResultOutput::Content { value } = output else {
+            return Ok(());
+        };
+
+        for item in value {
+            let inlined = match item {
+                ToolResultContentPart::FileUrl {
+                    url,
+                    provider_options,
+                } => {
+                    let (data, media_type) = self.fetch(url).await?;
+                    ToolResultContentPart::FileData {
+                        data: base64::engine::general_purpose::STANDARD.encode(data),
+                        media_type: media_type
+                            .unwrap_or_else(|| "application/octet-stream".to_string()),
+                        filename: None,
+                        provider_options: provider_options.take(),
+                    }
+                }
+                ToolResultContentPart::ImageUrl {
+                    url,
+                    provider_options,
+                } => {
+                    let (data, media_type) = self.fetch(url).await?;
+                    ToolResultContentPart::ImageData {
+                        data: base64::engine::general_purpose::STANDARD.encode(data),
+                        media_type: image_media_type(media_type, url)?,
+                        provider_options: provider_options.take(),
+                    }
+                }
+                ToolResultContentPart::FileId {
+                    file_id,
+                    provider_options,
+                } => {
+                    let (data, media_type) = self.fetch_file_id(file_id).await?;
+                    ToolResultContentPart::FileData {
+                        data: base64::engine::general_purpose::STANDARD.encode(data),
+                        media_type: media_type
+                            .unwrap_or_else(|| "application/octet-stream".to_string()),
+                        filename: None,
+                        provider_options: provider_options.take(),
+                    }
+                }
+                ToolResultContentPart::ImageFileId {
+                    file_id,
+                    provider_options,
+                } => {
+                    let (data, media_type) = self.fetch_file_id(file_id).await?;
+                    ToolResultContentPart::ImageData {
+                        data: base64::engine::general_purpose::STANDARD.encode(data),
+                        media_type: image_media_type(media_type, self.provider_file_id(file_id)?)?,
+                        provider_options: provider_options.take(),
+                    }
+                }
+                _ => continue,
+            };
+            *item = inlined;
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether the model accepts a URL of the given media type.
+    fn is_supported(&self, media_type: &str, url: &str) -> bool {
+        self.supported_urls.iter().any(|(pattern, regexes)| {
+            media_type_matches(pattern, media_type) && regexes.iter().any(|re| re.is_match(url))
+        })
+    }
+
+    /// Downloads a URL, returning its content and detected media type.
+    ///
+    /// `data:` URLs are decoded directly.
+    async fn fetch(&mut self, url: &str) -> Result<(Vec<u8>, Option<String>), AISDKError> {
+        if !self.cache.contains_key(url) {
+            let file = match decode_data_url(url) {
+                Some(file) => file?,
+                None => self.downloader.download(url).await?,
+            };
+            self.cache.insert(url.to_string(), file);
+        }
+
+        Ok(resolve(&self.cache[url]))
+    }
+
+    /// Downloads a file ID, returning its content and detected media type.
+    async fn fetch_file_id(
+        &mut self,
+        file_id: &FileId,
+    ) -> Result<(Vec<u8>, Option<String>), AISDKError> {
+        let file_id = self.provider_file_id(file_id)?;
+        let file = self.downloader.download_file_id(file_id).await?;
+        Ok(resolve(&file))
+    }
+
+    /// Gets the file ID for the provider of the model.
+    fn provider_file_id<'f>(&self, file_id: &'f FileId) -> Result<&'f str, AISDKError> {
+        match file_id {
+            FileId::Single(file_id) => Ok(file_id),
+            FileId::Multiple(file_ids) => file_ids
+                .get(&self.provider)
+                .map(String::as_str)
+                .ok_or_else(|| {
+                    AISDKError::invalid_prompt(format!(
+                        "Tool result file ID has no ID for provider '{}'",
+                        self.provider
+                    ))
+                }),
+        }
+    }
+}
+
+/// Returns the content of a downloaded file and its media type.
+///
+/// A media type detected from the content takes precedence over the reported one,
+/// which servers often leave generic.
+fn resolve(file: &DownloadedFile) -> (Vec<u8>, Option<String>) {
+    let media_type = detect_media_type(&file.data)
+        .map(str::to_string)
+        .or_else(|| {
+            file.media_type
+                .clone()
+                .filter(|media_type| media_type != "application/octet-stream")
+        });
+    (file.data.clone(), media_type)
+}
+
+/// Returns the media type of a downloaded image.
+///
+/// Fails if the media type could neither be detected nor was reported, since providers
+/// need a concrete media type for inline image data.
+fn image_media_type(media_type: Option<String>, source: &str) -> Result<String, AISDKError> {
+    media_type.ok_or_else(|| {
+        AISDKError::download(source, "could not determine the media type of the image")
+    })
+}
+
+/// Checks whether a supported URL media type pattern (e.g. `image/*`) matches.
+fn media_type_matches(pattern: &str, media_type: &str) -> bool {
+    if matches!(pattern, "*" | "*/*") {
+        return true;
+    }
+    match pattern.strip_suffix('*') {
+        Some(prefix) => media_type.starts_with(prefix),
+        None => pattern == media_type,
+    }
+}
+
+/// Decodes a base64 `data:` URL; returns `None` for other URLs.
+fn decode_data_url(url: &str) -> Option<Result<DownloadedFile, AISDKError>> {
+    let rest = url.strip_prefix("data:")?;
+    let Some((header, payload)) = rest.split_once(',') else {
+        return Some(Err(AISDKError::download(url, "invalid data URL")));
+    };
+    let Some(media_type) = header.strip_suffix(";base64") else {
+        return Some(Err(AISDKError::download(
+            url,
+            "only base64 data URLs are supported",
+        )));
+    };
+
+    Some(
+        base64::engine::general_purpose::STANDARD
+            .decode(payload)
+            .map(|data| DownloadedFile {
+                data,
+                media_type: Some(media_type.to_string()).filter(|m| !m.is_empty()),
+            })
+            .map_err(|e| AISDKError::download(url, e.to_string())),
+    )
+}
+
+/// Detects the media type of common image and document formats by their signature.
+pub(crate) fn detect_media_type(data: &[u8]) -> Option<&'static str> {
+    if data.starts_with(&[0x89, 0x50, 0x4E, 0x47]) {
+        Some("image/png")
+    } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        Some("image/gif")
+    } else if data.len() >= 12 && data.starts_with(b"RIFF") && &data[8..12] == b"WEBP" {
+        Some("image/webp")
+    } else if data.starts_with(b"%PDF-") {
+        Some("application/pdf")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use llm_kit_provider::language_model::call_options::LanguageModelCallOptions;
+    use llm_kit_provider::language_model::{
+        LanguageModelGenerateResponse, LanguageModelStreamResponse,
+    };
+    use llm_kit_provider_utils::message::content_parts::ToolResultPart;
+    use llm_kit_provider_utils::message::{ToolMessage, UserMessage};
+    use std::sync::Mutex;
+
+    const PNG: &[u8] = &[0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A];
+
+    struct MockModel {
+        supported_urls: HashMap<String, Vec<Regex>>,
+    }
+
+    #[async_trait]
+    impl LanguageModel for MockModel {
+        fn provider(&self) -> &str {
+            "mock.chat"
+        }
+
+        fn model_id(&self) -> &str {
+            "mock-model"
+        }
+
+        async fn supported_urls(&self) -> HashMap<String, Vec<Regex>> {
+            self.supported_urls.clone()
+        }
+
+        async fn do_generate(
+            &self,
+            _options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelGenerateResponse, Box<dyn std::error::Error>> {
+            unimplemented!()
+        }
+
+        async fn do_stream(
+            &self,
+            _options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelStreamResponse, Box<dyn std::error::Error>> {
+            unimplemented!()
+        }
+    }
+
+    /// Serves a PNG for every URL and records the requested URLs.
+    #[derive(Default)]
+    struct MockDownloader {
+        requests: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl Downloader for MockDownloader {
+        async fn download(&self, url: &str) -> Result<DownloadedFile, AISDKError> {
+            self.requests.lock().unwrap().push(url.to_string());
+            if url.contains("missing") {
+                return Err(AISDKError::download_builder(url).status_code(404).build());
+            }
+            Ok(DownloadedFile {
+                data: PNG.to_vec(),
+                media_type: Some("application/octet-stream".to_string()),
+            })
+        }
+
+        async fn download_file_id(&self, file_id: &str) -> Result<DownloadedFile, AISDKError> {
+            self.download(&format!("file:{}", file_id)).await
+        }
+    }
+
+    async fn downloads(
+        supported_urls: HashMap<String, Vec<Regex>>,
+    ) -> (PromptDownloads, Arc<MockDownloader>) {
+        let downloader = Arc::new(MockDownloader::default());
+        let model = MockModel { supported_urls };
+        let downloads = PromptDownloads::new(&model, Some(downloader.clone())).await;
+        (downloads, downloader)
+    }
+
+    fn user_prompt(parts: Vec<UserContentPart>) -> StandardizedPrompt {
+        StandardizedPrompt {
+            system: None,
+            messages: vec![Message::User(UserMessage::with_parts(parts))],
+        }
+    }
+
+    fn image_url(url: &str) -> UserContentPart {
+        UserContentPart::Image(ImagePart::from_url(url.parse().unwrap()))
+    }
+
+    #[tokio::test]
+    async fn test_inlines_unsupported_user_urls_once() {
+        let supported_urls = HashMap::from([(
+            "image/*".to_string(),
+            vec![Regex::new(r"^https://cdn\.example\.com/").unwrap()],
+        )]);
+        let (mut downloads, downloader) = downloads(supported_urls).await;
+        let prompt = user_prompt(vec![
+            image_url("https://cdn.example.com/a.png"),
+            image_url("https://other.example.com/b.png"),
+        ]);
+
+        let inlined = downloads.inline(prompt.clone()).await.unwrap();
+        downloads.inline(prompt).await.unwrap();
+
+        let Message::User(user_msg) = &inlined.messages[0] else {
+            panic!("Expected user message");
+        };
+        let UserContent::Parts(parts) = &user_msg.content else {
+            panic!("Expected parts");
+        };
+        assert!(matches!(
+            &parts[0],
+            UserContentPart::Image(ImagePart {
+                image: ImageSource::Url(_),
+                ..
+            })
+        ));
+        match &parts[1] {
+            UserContentPart::Image(ImagePart {
+                image: ImageSource::Data(DataContent::Bytes(data)),
+                media_type,
+                ..
+            }) => {
+                assert_eq!(data, PNG);
+                assert_eq!(media_type.as_deref(), Some("image/png"));
+            }
+            other => panic!("Expected inlined image, got {:?}", other),
+        }
+        assert_eq!(
+            *downloader.requests.lock().unwrap(),
+            vec!["https://other.example.com/b.png".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_inlines_tool_result_urls() {
+        let (mut downloads, _) = downloads(HashMap::new()).await;
+        let prompt = StandardizedPrompt {
+            system: None,
+            messages: vec![Message::Tool(ToolMessage::new(vec![
+                ToolContentPart::ToolResult(ToolResultPart::new(
+                    "call_1",
+                    "screenshot",
+                    ToolResultOutput::Content {
+                        value: vec![ToolResultContentPart::ImageUrl {
+                            url: "https://example.com/shot.png".to_string(),
+                            provider_options: None,
+                        }],
+                    },
+                )),
+            ]))],
+        };
+
+        let inlined = downloads.inline(prompt).await.unwrap();
+
+        let Message::Tool(tool_msg) = &inlined.messages[0] else {
+            panic!("Expected tool message");
+        };
+        let ToolContentPart::ToolResult(tool_result) = &tool_msg.content[0] else {
+            panic!("Expected tool result");
+        };
+        assert_eq!(
+            tool_result.output,
+            ToolResultOutput::Content {
+                value: vec![ToolResultContentPart::ImageData {
+                    data: base64::engine::general_purpose::STANDARD.encode(PNG),
+                    media_type: "image/png".to_string(),
+                    provider_options: None,
+                }],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tool_result_image_without_media_type_fails() {
+        let (mut downloads, _) = downloads(HashMap::new()).await;
+        let mut output = ToolResultOutput::Content {
+            value: vec![ToolResultContentPart::ImageUrl {
+                url: "data:;base64,AAECAw==".to_string(),
+                provider_options: None,
+            }],
+        };
+
+        let error = downloads
+            .inline_tool_result_output(&mut output)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, AISDKError::Download { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_failed_download_and_file_id_errors() {
+        let (mut downloads, _) = downloads(HashMap::new()).await;
+
+        let error = downloads
+            .inline(user_prompt(vec![image_url(
+                "https://example.com/missing.png",
+            )]))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            AISDKError::Download {
+                status_code: Some(404),
+                ..
+            }
+        ));
+
+        let error = downloads
+            .fetch_file_id(&FileId::Single("file_missing".to_string()))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, AISDKError::Download { .. }));
+
+        let error = downloads
+            .fetch_file_id(&FileId::Multiple(HashMap::from([(
+                "other".to_string(),
+                "file_1".to_string(),
+            )])))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, AISDKError::InvalidPrompt { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_file_id_for_provider() {
+        let (mut downloads, downloader) = downloads(HashMap::new()).await;
+
+        // File IDs are keyed by the provider name, without the model type
+        let (data, media_type) = downloads
+            .fetch_file_id(&FileId::Multiple(HashMap::from([
+                ("other".to_string(), "file_2".to_string()),
+                ("mock".to_string(), "file_1".to_string()),
+            ])))
+            .await
+            .unwrap();
+
+        assert_eq!(data, PNG);
+        assert_eq!(media_type.as_deref(), Some("image/png"));
+        assert_eq!(*downloader.requests.lock().unwrap(), ["file:file_1"]);
+    }
+
+    #[test]
+    fn test_decode_data_url() {
+        let file = decode_data_url("data:text/plain;base64,aGk=")
+            .unwrap()
+            .unwrap();
+        assert_eq!(file.data, b"hi");
+        assert_eq!(file.media_type.as_deref(), Some("text/plain"));
+
+        assert!(decode_data_url("https://example.com").is_none());
+        assert!(decode_data_url("data:text/plain,hi").unwrap().is_err());
+    }
+
+    #[test]
+    fn test_media_type_matches() {
+        assert!(media_type_matches("image/*", "image/png"));
+        assert!(media_type_matches("*/*", "application/pdf"));
+        assert!(media_type_matches("application/pdf", "application/pdf"));
+        assert!(!media_type_matches("image/*", "application/pdf"));
+    }
+}
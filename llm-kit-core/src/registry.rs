@@ -0,0 +1,7 @@
+/// Provider assembled from explicit model maps with an optional fallback provider.
+pub mod custom_provider;
+/// Registry that resolves `"provider:model"` ids across multiple providers.
+pub mod provider_registry;
+
+pub use custom_provider::{CustomProvider, CustomProviderOptions, custom_provider};
+pub use provider_registry::ProviderRegistry;
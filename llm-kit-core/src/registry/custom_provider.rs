@@ -0,0 +1,308 @@
+use llm_kit_provider::embedding_model::EmbeddingModel;
+use llm_kit_provider::error::ProviderError;
+use llm_kit_provider::image_model::ImageModel;
+use llm_kit_provider::language_model::LanguageModel;
+use llm_kit_provider::provider::Provider;
+use llm_kit_provider::reranking_model::RerankingModel;
+use llm_kit_provider::speech_model::SpeechModel;
+use llm_kit_provider::transcription_model::TranscriptionModel;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Provider id reported when a custom provider cannot resolve a model.
+const CUSTOM_PROVIDER_ID: &str = "custom";
+
+/// Models and fallback provider of a [`CustomProvider`].
+///
+/// Models are looked up by id in the map of their kind. Ids that are not in the map
+/// are forwarded to the fallback provider, if any.
+#[derive(Clone, Default)]
+pub struct CustomProviderOptions {
+    /// Language models by id.
+    pub language_models: HashMap<String, Arc<dyn LanguageModel>>,
+
+    /// Text embedding models by id.
+    pub text_embedding_models: HashMap<String, Arc<dyn EmbeddingModel<String>>>,
+
+    /// Image models by id.
+    pub image_models: HashMap<String, Arc<dyn ImageModel>>,
+
+    /// Transcription models by id.
+    pub transcription_models: HashMap<String, Arc<dyn TranscriptionModel>>,
+
+    /// Speech models by id.
+    pub speech_models: HashMap<String, Arc<dyn SpeechModel>>,
+
+    /// Reranking models by id.
+    pub reranking_models: HashMap<String, Arc<dyn RerankingModel>>,
+
+    /// Provider used for ids that are not in the model maps.
+    pub fallback_provider: Option<Arc<dyn Provider>>,
+}
+
+impl CustomProviderOptions {
+    /// Creates empty options without a fallback provider.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a language model under the given id.
+    pub fn with_language_model(
+        mut self,
+        id: impl Into<String>,
+        model: Arc<dyn LanguageModel>,
+    ) -> Self {
+        self.language_models.insert(id.into(), model);
+        self
+    }
+
+    /// Adds a text embedding model under the given id.
+    pub fn with_text_embedding_model(
+        mut self,
+        id: impl Into<String>,
+        model: Arc<dyn EmbeddingModel<String>>,
+    ) -> Self {
+        self.text_embedding_models.insert(id.into(), model);
+        self
+    }
+
+    /// Adds an image model under the given id.
+    pub fn with_image_model(mut self, id: impl Into<String>, model: Arc<dyn ImageModel>) -> Self {
+        self.image_models.insert(id.into(), model);
+        self
+    }
+
+    /// Adds a transcription model under the given id.
+    pub fn with_transcription_model(
+        mut self,
+        id: impl Into<String>,
+        model: Arc<dyn TranscriptionModel>,
+    ) -> Self {
+        self.transcription_models.insert(id.into(), model);
+        self
+    }
+
+    /// Adds a speech model under the given id.
+    pub fn with_speech_model(mut self, id: impl Into<String>, model: Arc<dyn SpeechModel>) -> Self {
+        self.speech_models.insert(id.into(), model);
+        self
+    }
+
+    /// Adds a reranking model under the given id.
+    pub fn with_reranking_model(
+        mut self,
+        id: impl Into<String>,
+        model: Arc<dyn RerankingModel>,
+    ) -> Self {
+        self.reranking_models.insert(id.into(), model);
+        self
+    }
+
+    /// Sets the provider used for ids that are not in the model maps.
+    pub fn with_fallback_provider(mut self, provider: Arc<dyn Provider>) -> Self {
+        self.fallback_provider = Some(provider);
+        self
+    }
+}
+
+/// Provider built from explicit model maps, created with [`custom_provider`].
+pub struct CustomProvider {
+    options: CustomProviderOptions,
+}
+
+/// Creates a provider from explicit model maps with an optional fallback provider.
+///
+/// This is useful to give models stable aliases, to pre-configure models (for example
+/// with [`wrap_language_model`](llm_kit_provider::language_model::middleware::wrap_language_model)),
+/// or to limit which models of a provider are available.
+///
+/// # Example
+///
+/// ```no_run
+/// use llm_kit_core::{CustomProviderOptions, ProviderRegistry, custom_provider};
+/// use llm_kit_provider::{LanguageModel, Provider};
+/// use std::sync::Arc;
+/// # fn example(
+/// #     anthropic: Arc<dyn Provider>,
+/// #     fast_model: Arc<dyn LanguageModel>,
+/// # ) -> Result<(), llm_kit_provider::ProviderError> {
+///
+/// let provider = custom_provider(
+///     CustomProviderOptions::new()
+///         .with_language_model("fast", fast_model)
+///         .with_fallback_provider(anthropic),
+/// );
+///
+/// let registry = ProviderRegistry::new().with_provider("app", Arc::new(provider));
+/// let model = registry.language_model("app:fast")?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn custom_provider(options: CustomProviderOptions) -> CustomProvider {
+    CustomProvider { options }
+}
+
+impl CustomProvider {
+    /// Returns the model from the map, or resolves it with the fallback provider.
+    #[allow(clippy::result_large_err)]
+    fn resolve<T: ?Sized>(
+        &self,
+        models: &HashMap<String, Arc<T>>,
+        model_id: &str,
+        fallback: impl FnOnce(&dyn Provider) -> Result<Arc<T>, ProviderError>,
+    ) -> Result<Arc<T>, ProviderError> {
+        if let Some(model) = models.get(model_id) {
+            return Ok(Arc::clone(model));
+        }
+
+        match &self.options.fallback_provider {
+            Some(provider) => fallback(provider.as_ref()),
+            None => Err(ProviderError::no_such_model(model_id, CUSTOM_PROVIDER_ID)),
+        }
+    }
+}
+
+#[allow(clippy::result_large_err)]
+impl Provider for CustomProvider {
+    fn language_model(&self, model_id: &str) -> Result<Arc<dyn LanguageModel>, ProviderError> {
+        self.resolve(&self.options.language_models, model_id, |provider| {
+            provider.language_model(model_id)
+        })
+    }
+
+    fn text_embedding_model(
+        &self,
+        model_id: &str,
+    ) -> Result<Arc<dyn EmbeddingModel<String>>, ProviderError> {
+        self.resolve(&self.options.text_embedding_models, model_id, |provider| {
+            provider.text_embedding_model(model_id)
+        })
+    }
+
+    fn image_model(&self, model_id: &str) -> Result<Arc<dyn ImageModel>, ProviderError> {
+        self.resolve(&self.options.image_models, model_id, |provider| {
+            provider.image_model(model_id)
+        })
+    }
+
+    fn transcription_model(
+        &self,
+        model_id: &str,
+    ) -> Result<Arc<dyn TranscriptionModel>, ProviderError> {
+        self.resolve(&self.options.transcription_models, model_id, |provider| {
+            provider.transcription_model(model_id)
+        })
+    }
+
+    fn speech_model(&self, model_id: &str) -> Result<Arc<dyn SpeechModel>, ProviderError> {
+        self.resolve(&self.options.speech_models, model_id, |provider| {
+            provider.speech_model(model_id)
+        })
+    }
+
+    fn reranking_model(&self, model_id: &str) -> Result<Arc<dyn RerankingModel>, ProviderError> {
+        self.resolve(&self.options.reranking_models, model_id, |provider| {
+            provider.reranking_model(model_id)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use llm_kit_provider::language_model::{
+        LanguageModelGenerateResponse, LanguageModelStreamResponse,
+        call_options::LanguageModelCallOptions,
+    };
+    use regex::Regex;
+
+    // Mock LanguageModel for testing
+    struct MockLanguageModel {
+        model_name: String,
+    }
+
+    #[async_trait]
+    impl LanguageModel for MockLanguageModel {
+        fn provider(&self) -> &str {
+            "test-provider"
+        }
+
+        fn model_id(&self) -> &str {
+            &self.model_name
+        }
+
+        async fn supported_urls(&self) -> HashMap<String, Vec<Regex>> {
+            HashMap::new()
+        }
+
+        async fn do_generate(
+            &self,
+            _options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelGenerateResponse, Box<dyn std::error::Error>> {
+            unimplemented!("Mock implementation")
+        }
+
+        async fn do_stream(
+            &self,
+            _options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelStreamResponse, Box<dyn std::error::Error>> {
+            unimplemented!("Mock implementation")
+        }
+    }
+
+    fn mock_model(model_name: &str) -> Arc<dyn LanguageModel> {
+        Arc::new(MockLanguageModel {
+            model_name: model_name.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_returns_model_from_map() {
+        let provider = custom_provider(
+            CustomProviderOptions::new().with_language_model("fast", mock_model("llama-3.3-70b")),
+        );
+
+        let model = provider.language_model("fast").unwrap();
+        assert_eq!(model.model_id(), "llama-3.3-70b");
+    }
+
+    #[test]
+    fn test_unknown_model_without_fallback() {
+        let provider = custom_provider(CustomProviderOptions::new());
+
+        match provider.language_model("fast") {
+            Err(ProviderError::NoSuchModel {
+                model_id,
+                provider_id,
+                ..
+            }) => {
+                assert_eq!(model_id, "fast");
+                assert_eq!(provider_id, "custom");
+            }
+            Err(other) => panic!("Expected NoSuchModel error, got {other:?}"),
+            Ok(_) => panic!("Expected NoSuchModel error"),
+        }
+        assert!(provider.text_embedding_model("embed").is_err());
+        assert!(provider.image_model("image").is_err());
+    }
+
+    #[test]
+    fn test_unknown_model_uses_fallback_provider() {
+        let fallback = custom_provider(
+            CustomProviderOptions::new().with_language_model("smart", mock_model("claude")),
+        );
+        let provider = custom_provider(
+            CustomProviderOptions::new()
+                .with_language_model("fast", mock_model("llama"))
+                .with_fallback_provider(Arc::new(fallback)),
+        );
+
+        assert_eq!(provider.language_model("fast").unwrap().model_id(), "llama");
+        assert_eq!(
+            provider.language_model("smart").unwrap().model_id(),
+            "claude"
+        );
+        assert!(provider.language_model("other").is_err());
+    }
+}
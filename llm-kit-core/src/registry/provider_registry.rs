@@ -0,0 +1,301 @@
+use llm_kit_provider::embedding_model::EmbeddingModel;
+use llm_kit_provider::error::ProviderError;
+use llm_kit_provider::image_model::ImageModel;
+use llm_kit_provider::language_model::LanguageModel;
+use llm_kit_provider::provider::Provider;
+use llm_kit_provider::reranking_model::RerankingModel;
+use llm_kit_provider::speech_model::SpeechModel;
+use llm_kit_provider::transcription_model::TranscriptionModel;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Default separator between the provider prefix and the model id.
+const DEFAULT_SEPARATOR: &str = ":";
+
+/// Registry of providers that resolves model ids such as `"anthropic:claude-sonnet-4-5"`.
+///
+/// Each provider is registered under a prefix. Model ids are split at the first
+/// separator (`:` by default): the part before it selects the provider and the rest
+/// is passed to the provider as its model id. The registry implements [`Provider`],
+/// so every model kind can be resolved and registries can be nested.
+///
+/// # Example
+///
+/// ```no_run
+/// use llm_kit_core::ProviderRegistry;
+/// use llm_kit_provider::Provider;
+/// use std::sync::Arc;
+/// # fn example(
+/// #     anthropic: Arc<dyn Provider>,
+/// #     groq: Arc<dyn Provider>,
+/// # ) -> Result<(), llm_kit_provider::ProviderError> {
+///
+/// let registry = ProviderRegistry::new()
+///     .with_provider("anthropic", anthropic)
+///     .with_provider("groq", groq);
+///
+/// let model = registry.language_model("anthropic:claude-sonnet-4-5")?;
+/// let fast_model = registry.language_model("groq:llama-3.3-70b")?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ProviderRegistry {
+    providers: HashMap<String, Arc<dyn Provider>>,
+    separator: String,
+}
+
+impl ProviderRegistry {
+    /// Creates an empty registry that uses `:` as separator.
+    pub fn new() -> Self {
+        Self {
+            providers: HashMap::new(),
+            separator: DEFAULT_SEPARATOR.to_string(),
+        }
+    }
+
+    /// Registers a provider under the given prefix.
+    ///
+    /// A provider already registered under the same prefix is replaced.
+    pub fn with_provider(mut self, id: impl Into<String>, provider: Arc<dyn Provider>) -> Self {
+        self.register_provider(id, provider);
+        self
+    }
+
+    /// Sets the separator between the provider prefix and the model id.
+    pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Registers a provider under the given prefix.
+    ///
+    /// A provider already registered under the same prefix is replaced.
+    pub fn register_provider(&mut self, id: impl Into<String>, provider: Arc<dyn Provider>) {
+        self.providers.insert(id.into(), provider);
+    }
+
+    /// Returns the provider registered under the given prefix.
+    pub fn provider(&self, id: &str) -> Option<&Arc<dyn Provider>> {
+        self.providers.get(id)
+    }
+
+    /// Returns the registered provider prefixes.
+    pub fn provider_ids(&self) -> Vec<&str> {
+        self.providers.keys().map(String::as_str).collect()
+    }
+
+    /// Splits the id into the registered provider and the provider-specific model id.
+    #[allow(clippy::result_large_err)]
+    fn resolve<'a>(&self, id: &'a str) -> Result<(&Arc<dyn Provider>, &'a str), ProviderError> {
+        let Some((provider_id, model_id)) = id.split_once(self.separator.as_str()) else {
+            return Err(ProviderError::invalid_argument(
+                "id",
+                format!(
+                    "Invalid model id for registry: {} (must be in the format \"providerId{}modelId\")",
+                    id, self.separator
+                ),
+            ));
+        };
+
+        let provider = self
+            .providers
+            .get(provider_id)
+            .ok_or_else(|| ProviderError::no_such_model(model_id, provider_id))?;
+
+        Ok((provider, model_id))
+    }
+}
+
+impl Default for ProviderRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Provider for ProviderRegistry {
+    fn language_model(&self, id: &str) -> Result<Arc<dyn LanguageModel>, ProviderError> {
+        let (provider, model_id) = self.resolve(id)?;
+        provider.language_model(model_id)
+    }
+
+    fn text_embedding_model(
+        &self,
+        id: &str,
+    ) -> Result<Arc<dyn EmbeddingModel<String>>, ProviderError> {
+        let (provider, model_id) = self.resolve(id)?;
+        provider.text_embedding_model(model_id)
+    }
+
+    fn image_model(&self, id: &str) -> Result<Arc<dyn ImageModel>, ProviderError> {
+        let (provider, model_id) = self.resolve(id)?;
+        provider.image_model(model_id)
+    }
+
+    fn transcription_model(&self, id: &str) -> Result<Arc<dyn TranscriptionModel>, ProviderError> {
+        let (provider, model_id) = self.resolve(id)?;
+        provider.transcription_model(model_id)
+    }
+
+    fn speech_model(&self, id: &str) -> Result<Arc<dyn SpeechModel>, ProviderError> {
+        let (provider, model_id) = self.resolve(id)?;
+        provider.speech_model(model_id)
+    }
+
+    fn reranking_model(&self, id: &str) -> Result<Arc<dyn RerankingModel>, ProviderError> {
+        let (provider, model_id) = self.resolve(id)?;
+        provider.reranking_model(model_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Provider that rejects every model, echoing the ids it was asked for
+    struct EchoProvider {
+        provider_id: String,
+    }
+
+    fn echo_provider(provider_id: &str) -> Arc<dyn Provider> {
+        Arc::new(EchoProvider {
+            provider_id: provider_id.to_string(),
+        })
+    }
+
+    impl Provider for EchoProvider {
+        fn language_model(&self, model_id: &str) -> Result<Arc<dyn LanguageModel>, ProviderError> {
+            Err(ProviderError::no_such_model(model_id, &self.provider_id))
+        }
+
+        fn text_embedding_model(
+            &self,
+            model_id: &str,
+        ) -> Result<Arc<dyn EmbeddingModel<String>>, ProviderError> {
+            Err(ProviderError::no_such_model(model_id, &self.provider_id))
+        }
+
+        fn image_model(&self, model_id: &str) -> Result<Arc<dyn ImageModel>, ProviderError> {
+            Err(ProviderError::no_such_model(model_id, &self.provider_id))
+        }
+
+        fn transcription_model(
+            &self,
+            model_id: &str,
+        ) -> Result<Arc<dyn TranscriptionModel>, ProviderError> {
+            Err(ProviderError::no_such_model(model_id, &self.provider_id))
+        }
+
+        fn speech_model(&self, model_id: &str) -> Result<Arc<dyn SpeechModel>, ProviderError> {
+            Err(ProviderError::no_such_model(model_id, &self.provider_id))
+        }
+
+        fn reranking_model(
+            &self,
+            model_id: &str,
+        ) -> Result<Arc<dyn RerankingModel>, ProviderError> {
+            Err(ProviderError::no_such_model(model_id, &self.provider_id))
+        }
+    }
+
+    fn assert_resolved_to<T>(
+        result: Result<T, ProviderError>,
+        expected_provider: &str,
+        expected_model: &str,
+    ) {
+        match result {
+            Err(ProviderError::NoSuchModel {
+                model_id,
+                provider_id,
+                ..
+            }) => {
+                assert_eq!(provider_id, expected_provider);
+                assert_eq!(model_id, expected_model);
+            }
+            Err(other) => panic!("Expected NoSuchModel error, got {other:?}"),
+            Ok(_) => panic!("Expected NoSuchModel error"),
+        }
+    }
+
+    #[test]
+    fn test_resolves_every_model_kind() {
+        let registry = ProviderRegistry::new()
+            .with_provider("anthropic", echo_provider("anthropic-impl"))
+            .with_provider("groq", echo_provider("groq-impl"));
+
+        assert_resolved_to(
+            registry.language_model("anthropic:claude-sonnet-4-5"),
+            "anthropic-impl",
+            "claude-sonnet-4-5",
+        );
+        assert_resolved_to(
+            registry.text_embedding_model("groq:embed"),
+            "groq-impl",
+            "embed",
+        );
+        assert_resolved_to(registry.image_model("groq:image"), "groq-impl", "image");
+        assert_resolved_to(
+            registry.transcription_model("groq:whisper"),
+            "groq-impl",
+            "whisper",
+        );
+        assert_resolved_to(registry.speech_model("groq:tts"), "groq-impl", "tts");
+        assert_resolved_to(
+            registry.reranking_model("groq:rerank"),
+            "groq-impl",
+            "rerank",
+        );
+    }
+
+    #[test]
+    fn test_splits_at_first_separator() {
+        let registry =
+            ProviderRegistry::new().with_provider("openai", echo_provider("openai-impl"));
+
+        assert_resolved_to(
+            registry.language_model("openai:ft:gpt-4o:my-org"),
+            "openai-impl",
+            "ft:gpt-4o:my-org",
+        );
+    }
+
+    #[test]
+    fn test_custom_separator() {
+        let registry = ProviderRegistry::new()
+            .with_separator(" > ")
+            .with_provider("groq", echo_provider("groq-impl"));
+
+        assert_resolved_to(
+            registry.language_model("groq > llama-3.3-70b"),
+            "groq-impl",
+            "llama-3.3-70b",
+        );
+    }
+
+    #[test]
+    fn test_unknown_provider() {
+        let registry = ProviderRegistry::new();
+
+        assert_resolved_to(registry.language_model("mistral:large"), "mistral", "large");
+    }
+
+    #[test]
+    fn test_missing_separator() {
+        let registry = ProviderRegistry::new().with_provider("groq", echo_provider("groq-impl"));
+
+        match registry.language_model("llama-3.3-70b") {
+            Err(ProviderError::InvalidArgument { argument, .. }) => assert_eq!(argument, "id"),
+            Err(other) => panic!("Expected InvalidArgument error, got {other:?}"),
+            Ok(_) => panic!("Expected InvalidArgument error"),
+        }
+    }
+
+    #[test]
+    fn test_register_provider_replaces_existing() {
+        let mut registry = ProviderRegistry::new().with_provider("groq", echo_provider("first"));
+        registry.register_provider("groq", echo_provider("second"));
+
+        assert_eq!(registry.provider_ids(), vec!["groq"]);
+        assert!(registry.provider("groq").is_some());
+        assert_resolved_to(registry.language_model("groq:llama"), "second", "llama");
+    }
+}
@@ -4,7 +4,7 @@ pub mod result;
 pub use result::{RankedDocumentWithValue, RerankResponseMetadata, RerankResult};
 
 use crate::error::AISDKError;
-use crate::generate_text::prepare_retries;
+use crate::generate_text::{RetryPolicy, prepare_retries};
 use llm_kit_provider::reranking_model::RerankingModel;
 use llm_kit_provider::reranking_model::call_options::{
     RerankingDocuments, RerankingModelCallOptions,
@@ -57,6 +57,7 @@ where
     top_n: Option<usize>,
     provider_options: Option<SharedProviderOptions>,
     max_retries: Option<u32>,
+    retry_policy: Option<Arc<dyn RetryPolicy>>,
     abort_signal: Option<CancellationToken>,
     headers: Option<SharedHeaders>,
 }
@@ -80,6 +81,7 @@ where
             top_n: None,
             provider_options: None,
             max_retries: None,
+            retry_policy: None,
             abort_signal: None,
             headers: None,
         }
@@ -115,6 +117,16 @@ where
         self
     }
 
+    /// Set the policy that decides whether and when failed calls are retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `retry_policy` - The retry policy. Default: [`DefaultRetryPolicy`](crate::generate_text::DefaultRetryPolicy).
+    pub fn retry_policy(mut self, retry_policy: Arc<dyn RetryPolicy>) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
     /// Set an abort signal that can be used to cancel the call.
     ///
     /// # Arguments
@@ -173,7 +185,11 @@ where
         let documents_to_send = detect_document_type(&self.documents)?;
 
         // Prepare retry configuration
-        let retry_config = prepare_retries(self.max_retries, self.abort_signal.clone())?;
+        let retry_config = prepare_retries(
+            self.max_retries,
+            self.abort_signal.clone(),
+            self.retry_policy.clone(),
+        )?;
 
         // Clone model info for logging before moving into closure
         let provider_name = self.model.provider().to_string();
@@ -8,6 +8,7 @@
 //! - `user_message_to_storage`: Convert user messages from prompts to storage format
 //! - `assistant_output_to_storage`: Convert assistant outputs to storage format
 //! - `load_conversation_history`: Load and convert stored messages back to prompt format
+//! - `load_pending_approvals`: Load the tool approval requests still waiting for a response
 //!
 //! # Example
 //!
@@ -30,14 +31,18 @@
 //! let history = load_conversation_history(&storage, &session_id).await?;
 //! ```
 
-use crate::generate_text::GenerateTextResult;
+use crate::generate_text::{GenerateTextResult, StepResult};
 use crate::output::Output;
-use llm_kit_provider_utils::message::{AssistantMessage, Message, UserMessage};
+use llm_kit_provider::language_model::LanguageModel;
+use llm_kit_provider::language_model::usage::LanguageModelUsage;
+use llm_kit_provider_utils::message::{AssistantMessage, Message, ToolMessage, UserMessage};
+use llm_kit_provider_utils::tool::ToolApprovalRequestOutput;
 use llm_kit_storage::{
     AssistantMessage as StorageAssistantMessage, FileData as StorageFileData,
     FilePart as StorageFilePart, ImageData as StorageImageData, ImagePart as StorageImagePart,
     MessageMetadata, MessagePart, MessageRole, ReasoningPart, Storage, StorageError, TextPart,
-    ToolCallPart, UsageStats, UserMessage as StorageUserMessage,
+    ToolApprovalRequestPart, ToolApprovalResponsePart, ToolCallPart, UsageStats,
+    UserMessage as StorageUserMessage,
 };
 use std::sync::Arc;
 
@@ -129,7 +134,13 @@ pub fn user_message_to_storage(
 /// * `storage` - Storage instance for generating IDs
 /// * `session_id` - Session these messages belong to
 /// * `response_messages` - All response messages from the generation
-/// * `result` - The final generation result for metadata (model_id, usage, finish_reason)
+/// * `result` - The generation result whose steps provide the metadata of each assistant message
+///
+/// Each assistant message gets the metadata of the step that generated it: model ID,
+/// full token usage and finish reason. The usage of steps without an assistant message
+/// is added to the next stored assistant message, so summing the usage of the stored
+/// messages gives the total usage of the generation. Use
+/// [`response_messages_to_storage_with_model`] to also record the provider.
 ///
 /// # Returns
 ///
@@ -138,7 +149,8 @@ pub fn user_message_to_storage(
 /// # Example
 ///
 /// ```ignore
-/// let storage_messages = response_messages_to_storage(&storage, session_id, &response_messages, &result);
+/// let storage_messages =
+///     response_messages_to_storage(&storage, session_id, &response_messages, &result);
 /// for (msg, parts) in storage_messages {
 ///     storage.store_assistant_message(&msg, &parts).await?;
 /// }
@@ -148,11 +160,36 @@ pub fn response_messages_to_storage(
     session_id: String,
     response_messages: &[crate::ResponseMessage],
     result: &GenerateTextResult,
+) -> Vec<(StorageAssistantMessage, Vec<MessagePart>)> {
+    convert_response_messages(storage, session_id, response_messages, result, None)
+}
+
+/// Convert response messages to storage format, recording the model that generated them.
+///
+/// Like [`response_messages_to_storage`], but the assistant messages also record the
+/// provider of `model`, and its model ID when the response did not report one.
+pub fn response_messages_to_storage_with_model(
+    storage: &Arc<dyn Storage>,
+    session_id: String,
+    response_messages: &[crate::ResponseMessage],
+    result: &GenerateTextResult,
+    model: &dyn LanguageModel,
+) -> Vec<(StorageAssistantMessage, Vec<MessagePart>)> {
+    convert_response_messages(storage, session_id, response_messages, result, Some(model))
+}
+
+fn convert_response_messages(
+    storage: &Arc<dyn Storage>,
+    session_id: String,
+    response_messages: &[crate::ResponseMessage],
+    result: &GenerateTextResult,
+    model: Option<&dyn LanguageModel>,
 ) -> Vec<(StorageAssistantMessage, Vec<MessagePart>)> {
     use crate::ResponseMessage;
     use llm_kit_provider_utils::message::AssistantContent;
 
     let mut storage_messages = Vec::new();
+    let mut assistant_metadata = assistant_message_metadata(&result.steps, model).into_iter();
 
     for response_msg in response_messages {
         let message_id = storage.generate_message_id();
@@ -272,11 +309,12 @@ pub fn response_messages_to_storage(
                                     generated_file_to_storage_from_file_part(part_id.clone(), file)
                                 }
                                 llm_kit_provider_utils::message::AssistantContentPart::ToolApprovalRequest(
-                                    _,
-                                ) => {
-                                    // Skip tool approval requests - they're not persisted
-                                    continue;
-                                }
+                                    approval_request,
+                                ) => MessagePart::ToolApprovalRequest(ToolApprovalRequestPart::new(
+                                    part_id.clone(),
+                                    approval_request.approval_id.clone(),
+                                    approval_request.tool_call_id.clone(),
+                                )),
                             };
 
                             parts.push(part);
@@ -285,23 +323,7 @@ pub fn response_messages_to_storage(
                     }
                 }
 
-                // Build metadata only for the last message (final result)
-                let metadata = if response_msg == response_messages.last().unwrap()
-                    && matches!(response_msg, ResponseMessage::Assistant(_))
-                {
-                    MessageMetadata {
-                        model_id: result.response.model_id.clone(),
-                        provider: None,
-                        usage: Some(UsageStats::new(
-                            result.usage.input_tokens as u32,
-                            result.usage.output_tokens as u32,
-                        )),
-                        finish_reason: Some(format!("{:?}", result.finish_reason)),
-                        custom: None,
-                    }
-                } else {
-                    MessageMetadata::default()
-                };
+                let metadata = assistant_metadata.next().unwrap_or_default();
 
                 let storage_message =
                     StorageAssistantMessage::new(message_id, session_id.clone(), part_ids)
@@ -310,113 +332,139 @@ pub fn response_messages_to_storage(
                 storage_messages.push((storage_message, parts));
             }
             ResponseMessage::Tool(tool_msg) => {
-                // Convert tool message to assistant message with only tool result parts
-                for tool_content_part in &tool_msg.content {
-                    match tool_content_part {
-                        llm_kit_provider_utils::message::tool::ToolContentPart::ToolResult(
-                            tool_result,
-                        ) => {
-                            let part_id = storage.generate_part_id();
+                storage_messages.push(tool_message_to_storage(
+                    storage,
+                    session_id.clone(),
+                    tool_msg,
+                ));
+            }
+        }
+    }
 
-                            let part = {
-                                use llm_kit_provider_utils::message::content_parts::ToolResultOutput;
-                                match &tool_result.output {
-                                    ToolResultOutput::Text { value, .. } => {
-                                        MessagePart::ToolResult(
-                                            llm_kit_storage::ToolResultPart::new_success(
-                                                part_id.clone(),
-                                                tool_result.tool_call_id.clone(),
-                                                tool_result.tool_name.clone(),
-                                                serde_json::Value::String(value.clone()),
-                                            ),
-                                        )
-                                    }
-                                    ToolResultOutput::Json { value, .. } => {
-                                        MessagePart::ToolResult(
-                                            llm_kit_storage::ToolResultPart::new_success(
-                                                part_id.clone(),
-                                                tool_result.tool_call_id.clone(),
-                                                tool_result.tool_name.clone(),
-                                                value.clone(),
-                                            ),
-                                        )
-                                    }
-                                    ToolResultOutput::Content { value, .. } => {
-                                        MessagePart::ToolResult(
-                                            llm_kit_storage::ToolResultPart::new_success(
-                                                part_id.clone(),
-                                                tool_result.tool_call_id.clone(),
-                                                tool_result.tool_name.clone(),
-                                                serde_json::to_value(value).unwrap_or_default(),
-                                            ),
-                                        )
-                                    }
-                                    ToolResultOutput::ExecutionDenied { reason, .. } => {
-                                        let error_msg = reason
-                                            .clone()
-                                            .unwrap_or_else(|| "Execution denied".to_string());
-                                        MessagePart::ToolResult(
-                                            llm_kit_storage::ToolResultPart::new_error(
-                                                part_id.clone(),
-                                                tool_result.tool_call_id.clone(),
-                                                tool_result.tool_name.clone(),
-                                                error_msg,
-                                            ),
-                                        )
-                                    }
-                                    ToolResultOutput::ErrorText { value, .. } => {
-                                        MessagePart::ToolResult(
-                                            llm_kit_storage::ToolResultPart::new_error(
-                                                part_id.clone(),
-                                                tool_result.tool_call_id.clone(),
-                                                tool_result.tool_name.clone(),
-                                                value.clone(),
-                                            ),
-                                        )
-                                    }
-                                    ToolResultOutput::ErrorJson { value, .. } => {
-                                        MessagePart::ToolResult(
-                                            llm_kit_storage::ToolResultPart::new_error(
-                                                part_id.clone(),
-                                                tool_result.tool_call_id.clone(),
-                                                tool_result.tool_name.clone(),
-                                                value.to_string(),
-                                            ),
-                                        )
-                                    }
-                                }
-                            };
+    storage_messages
+}
 
-                            parts.push(part);
-                            part_ids.push(part_id);
+/// Convert a tool message to storage format.
+///
+/// Tool messages are stored as assistant messages containing only tool result and
+/// tool approval response parts. The `is_client_tool_message` metadata flag marks them
+/// so that `load_conversation_history` restores them as tool messages.
+///
+/// # Arguments
+///
+/// * `storage` - Storage instance for generating IDs
+/// * `session_id` - Session this message belongs to
+/// * `tool_message` - The tool message to convert
+///
+/// # Returns
+///
+/// A tuple of (StorageAssistantMessage, `Vec<MessagePart>`) ready to be stored
+pub fn tool_message_to_storage(
+    storage: &Arc<dyn Storage>,
+    session_id: String,
+    tool_message: &ToolMessage,
+) -> (StorageAssistantMessage, Vec<MessagePart>) {
+    let message_id = storage.generate_message_id();
+    let mut parts = Vec::new();
+    let mut part_ids = Vec::new();
+
+    for tool_content_part in &tool_message.content {
+        match tool_content_part {
+            llm_kit_provider_utils::message::tool::ToolContentPart::ToolResult(tool_result) => {
+                let part_id = storage.generate_part_id();
+
+                let part = {
+                    use llm_kit_provider_utils::message::content_parts::ToolResultOutput;
+                    match &tool_result.output {
+                        ToolResultOutput::Text { value, .. } => {
+                            MessagePart::ToolResult(llm_kit_storage::ToolResultPart::new_success(
+                                part_id.clone(),
+                                tool_result.tool_call_id.clone(),
+                                tool_result.tool_name.clone(),
+                                serde_json::Value::String(value.clone()),
+                            ))
+                        }
+                        ToolResultOutput::Json { value, .. } => {
+                            MessagePart::ToolResult(llm_kit_storage::ToolResultPart::new_success(
+                                part_id.clone(),
+                                tool_result.tool_call_id.clone(),
+                                tool_result.tool_name.clone(),
+                                value.clone(),
+                            ))
                         }
-                        llm_kit_provider_utils::message::tool::ToolContentPart::ApprovalResponse(
-                            _,
-                        ) => {
-                            // Skip approval responses - they're not persisted
-                            continue;
+                        ToolResultOutput::Content { value, .. } => {
+                            MessagePart::ToolResult(llm_kit_storage::ToolResultPart::new_success(
+                                part_id.clone(),
+                                tool_result.tool_call_id.clone(),
+                                tool_result.tool_name.clone(),
+                                serde_json::to_value(value).unwrap_or_default(),
+                            ))
+                        }
+                        ToolResultOutput::ExecutionDenied { reason, .. } => {
+                            let error_msg = reason
+                                .clone()
+                                .unwrap_or_else(|| "Execution denied".to_string());
+                            MessagePart::ToolResult(llm_kit_storage::ToolResultPart::new_error(
+                                part_id.clone(),
+                                tool_result.tool_call_id.clone(),
+                                tool_result.tool_name.clone(),
+                                error_msg,
+                            ))
+                        }
+                        ToolResultOutput::ErrorText { value, .. } => {
+                            MessagePart::ToolResult(llm_kit_storage::ToolResultPart::new_error(
+                                part_id.clone(),
+                                tool_result.tool_call_id.clone(),
+                                tool_result.tool_name.clone(),
+                                value.clone(),
+                            ))
+                        }
+                        ToolResultOutput::ErrorJson { value, .. } => {
+                            MessagePart::ToolResult(llm_kit_storage::ToolResultPart::new_error(
+                                part_id.clone(),
+                                tool_result.tool_call_id.clone(),
+                                tool_result.tool_name.clone(),
+                                value.to_string(),
+                            ))
                         }
                     }
-                }
-
-                // Store as assistant message with metadata flag indicating client-executed tools
-                let metadata = MessageMetadata {
-                    custom: Some(serde_json::json!({
-                        "is_client_tool_message": true
-                    })),
-                    ..Default::default()
                 };
 
-                let storage_message =
-                    StorageAssistantMessage::new(message_id, session_id.clone(), part_ids)
-                        .with_metadata(metadata);
+                parts.push(part);
+                part_ids.push(part_id);
+            }
+            llm_kit_provider_utils::message::tool::ToolContentPart::ApprovalResponse(
+                approval_response,
+            ) => {
+                let part_id = storage.generate_part_id();
 
-                storage_messages.push((storage_message, parts));
+                let mut part = ToolApprovalResponsePart::new(
+                    part_id.clone(),
+                    approval_response.approval_id.clone(),
+                    approval_response.approved,
+                );
+                if let Some(reason) = &approval_response.reason {
+                    part = part.with_reason(reason.clone());
+                }
+
+                parts.push(MessagePart::ToolApprovalResponse(part));
+                part_ids.push(part_id);
             }
         }
     }
 
-    storage_messages
+    // Store as assistant message with metadata flag indicating client-executed tools
+    let metadata = MessageMetadata {
+        custom: Some(serde_json::json!({
+            "is_client_tool_message": true
+        })),
+        ..Default::default()
+    };
+
+    let storage_message =
+        StorageAssistantMessage::new(message_id, session_id, part_ids).with_metadata(metadata);
+
+    (storage_message, parts)
 }
 
 /// Convert assistant output to storage format.
@@ -496,6 +544,24 @@ pub fn assistant_output_to_storage(
                 // Convert GeneratedFile to storage FilePart
                 generated_file_to_storage(part_id.clone(), file)
             }
+            Output::ToolApprovalRequest(approval_request) => {
+                MessagePart::ToolApprovalRequest(ToolApprovalRequestPart::new(
+                    part_id.clone(),
+                    approval_request.approval_id.clone(),
+                    approval_request.tool_call.tool_call_id.clone(),
+                ))
+            }
+            Output::ToolOutputDenied(denied) => {
+                MessagePart::ToolResult(llm_kit_storage::ToolResultPart::new_error(
+                    part_id.clone(),
+                    denied.tool_call_id.clone(),
+                    denied.tool_name.clone(),
+                    denied
+                        .reason
+                        .clone()
+                        .unwrap_or_else(|| "Execution denied".to_string()),
+                ))
+            }
         };
 
         parts.push(part);
@@ -506,12 +572,10 @@ pub fn assistant_output_to_storage(
     let metadata = MessageMetadata {
         model_id: result.response.model_id.clone(),
         provider: None, // Provider info not directly available in result
-        usage: Some(UsageStats::new(
-            result.usage.input_tokens as u32,
-            result.usage.output_tokens as u32,
-        )),
+        usage: Some(usage_to_storage(&result.usage)),
         finish_reason: Some(format!("{:?}", result.finish_reason)),
         custom: None,
+        ..Default::default()
     };
 
     let storage_message =
@@ -520,6 +584,88 @@ pub fn assistant_output_to_storage(
     (storage_message, parts)
 }
 
+/// Convert the token usage reported by a model to storage format.
+///
+/// Keeps the reasoning and cached input token counts. If the provider reported no
+/// total, it is computed from the input and output tokens.
+pub fn usage_to_storage(usage: &LanguageModelUsage) -> UsageStats {
+    let total_tokens = if usage.total_tokens == 0 {
+        usage.input_tokens + usage.output_tokens
+    } else {
+        usage.total_tokens
+    };
+
+    UsageStats {
+        prompt_tokens: usage.input_tokens,
+        completion_tokens: usage.output_tokens,
+        total_tokens,
+        reasoning_tokens: usage.reasoning_tokens,
+        cached_input_tokens: usage.cached_input_tokens,
+    }
+}
+
+/// Metadata of the assistant messages generated by the steps, in order.
+///
+/// The usage of steps without an assistant message is added to the next assistant
+/// message, or to the last one if no assistant message follows.
+fn assistant_message_metadata(
+    steps: &[StepResult],
+    model: Option<&dyn LanguageModel>,
+) -> Vec<MessageMetadata> {
+    use crate::ResponseMessage;
+    use crate::generate_text::to_response_messages;
+
+    // Steps with an assistant message and the usage to record for them
+    let mut assistant_steps: Vec<(&StepResult, LanguageModelUsage)> = Vec::new();
+    let mut pending_usage: Option<LanguageModelUsage> = None;
+
+    for step in steps {
+        let has_assistant_message = to_response_messages(step.content.clone(), None)
+            .iter()
+            .any(|message| matches!(message, ResponseMessage::Assistant(_)));
+        if has_assistant_message {
+            assistant_steps.push((step, add_usage(pending_usage.take(), &step.usage)));
+        } else {
+            pending_usage = Some(add_usage(pending_usage, &step.usage));
+        }
+    }
+
+    if let (Some(usage), Some((_, last_usage))) = (pending_usage, assistant_steps.last_mut()) {
+        *last_usage = add_usage(Some(*last_usage), &usage);
+    }
+
+    assistant_steps
+        .into_iter()
+        .map(|(step, usage)| MessageMetadata {
+            model_id: step
+                .response
+                .model_id
+                .clone()
+                .or_else(|| model.map(|model| model.model_id().to_string())),
+            provider: model.map(|model| model.provider().to_string()),
+            usage: Some(usage_to_storage(&usage)),
+            finish_reason: Some(format!("{:?}", step.finish_reason)),
+            custom: None,
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Adds the usage of a step to an optional usage sum.
+fn add_usage(sum: Option<LanguageModelUsage>, usage: &LanguageModelUsage) -> LanguageModelUsage {
+    let Some(sum) = sum else {
+        return *usage;
+    };
+
+    LanguageModelUsage {
+        input_tokens: sum.input_tokens + usage.input_tokens,
+        output_tokens: sum.output_tokens + usage.output_tokens,
+        total_tokens: sum.total() + usage.total(),
+        reasoning_tokens: sum.reasoning_tokens + usage.reasoning_tokens,
+        cached_input_tokens: sum.cached_input_tokens + usage.cached_input_tokens,
+    }
+}
+
 /// Load conversation history from storage and convert to prompt messages.
 ///
 /// Retrieves all messages in a session and converts them back to the prompt format
@@ -591,6 +737,36 @@ pub async fn load_conversation_history(
     Ok(messages)
 }
 
+/// Load the tool approval requests of a session that are still waiting for a response.
+///
+/// Use this to find out which tool calls a user still has to approve or deny after
+/// a generation with storage stopped on approval requests.
+///
+/// # Arguments
+///
+/// * `storage` - Storage instance to load from
+/// * `session_id` - Session ID to load approvals for
+///
+/// # Errors
+///
+/// Returns `StorageError` if loading fails or session doesn't exist
+///
+/// # Example
+///
+/// ```ignore
+/// let pending = load_pending_approvals(&storage, &session_id).await?;
+/// for request in pending {
+///     println!("Approve {}?", request.tool_call.tool_name);
+/// }
+/// ```
+pub async fn load_pending_approvals(
+    storage: &Arc<dyn Storage>,
+    session_id: &str,
+) -> Result<Vec<ToolApprovalRequestOutput>, StorageError> {
+    let history = load_conversation_history(storage, session_id).await?;
+    Ok(crate::tool::pending_tool_approvals(&history))
+}
+
 /// Convert storage parts to a UserMessage.
 ///
 /// Internal helper function that reconstructs a user message from stored parts.
@@ -637,17 +813,28 @@ fn parts_to_user_message(parts: &[MessagePart]) -> UserMessage {
 /// Convert storage parts to a ToolMessage.
 ///
 /// Internal helper function that reconstructs a tool message from stored parts.
-/// This is used when an assistant message contains ONLY tool results.
+/// This is used when an assistant message contains ONLY tool results and approval responses.
 fn parts_to_tool_message(parts: &[MessagePart]) -> llm_kit_provider_utils::message::ToolMessage {
     use llm_kit_provider_utils::message::tool::{ToolContentPart, ToolMessage};
+    use llm_kit_provider_utils::tool::ToolApprovalResponse;
 
     let mut tool_content: Vec<ToolContentPart> = Vec::new();
 
     for part in parts {
-        if let MessagePart::ToolResult(tool_result) = part
-            && let Some(result_part) = storage_tool_result_to_tool_content(tool_result)
-        {
-            tool_content.push(result_part);
+        match part {
+            MessagePart::ToolResult(tool_result) => {
+                if let Some(result_part) = storage_tool_result_to_tool_content(tool_result) {
+                    tool_content.push(result_part);
+                }
+            }
+            MessagePart::ToolApprovalResponse(approval_response) => {
+                tool_content.push(ToolContentPart::ApprovalResponse(ToolApprovalResponse {
+                    approval_id: approval_response.approval_id.clone(),
+                    approved: approval_response.approved,
+                    reason: approval_response.reason.clone(),
+                }));
+            }
+            _ => {}
         }
     }
 
@@ -704,6 +891,18 @@ fn parts_to_assistant_message(parts: &[MessagePart]) -> AssistantMessage {
                     content_parts.push(part);
                 }
             }
+            MessagePart::ToolApprovalRequest(approval_request) => {
+                content_parts.push(
+                    llm_kit_provider_utils::message::AssistantContentPart::ToolApprovalRequest(
+                        llm_kit_provider_utils::tool::ToolApprovalRequest::new(
+                            approval_request.approval_id.clone(),
+                            approval_request.tool_call_id.clone(),
+                        ),
+                    ),
+                );
+            }
+            // Approval responses belong to tool messages
+            MessagePart::ToolApprovalResponse(_) => {}
             // Skip source and image parts - sources are metadata only, images not in AssistantContentPart
             MessagePart::Source(_) | MessagePart::Image(_) => {}
         }
@@ -1009,19 +1208,15 @@ fn storage_tool_result_to_tool_content(
 mod tests {
     use super::*;
 
-    use llm_kit_storage_filesystem::FilesystemStorage;
-    use tempfile::TempDir;
+    use llm_kit_storage::InMemoryStorage;
 
-    async fn setup_storage() -> (Arc<dyn Storage>, TempDir) {
-        let temp_dir = TempDir::new().unwrap();
-        let storage = FilesystemStorage::new(temp_dir.path()).unwrap();
-        storage.initialize().await.unwrap();
-        (Arc::new(storage), temp_dir)
+    fn setup_storage() -> Arc<dyn Storage> {
+        Arc::new(InMemoryStorage::new())
     }
 
     #[tokio::test]
     async fn test_user_message_conversion() {
-        let (storage, _dir) = setup_storage().await;
+        let storage = setup_storage();
         let session_id = storage.generate_session_id();
 
         let user_msg = UserMessage::new("Hello, AI!");
@@ -1038,7 +1233,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_conversation_history_roundtrip() {
-        let (storage, _dir) = setup_storage().await;
+        let storage = setup_storage();
         let session_id = storage.generate_session_id();
 
         // Create session
@@ -1074,7 +1269,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_multi_turn_conversation_history() {
-        let (storage, _dir) = setup_storage().await;
+        let storage = setup_storage();
         let session_id = storage.generate_session_id();
 
         // Create session
@@ -1110,7 +1305,7 @@ mod tests {
     async fn test_image_part_conversion_roundtrip() {
         use llm_kit_provider_utils::message::{DataContent, ImagePart as PromptImagePart};
 
-        let (storage, _dir) = setup_storage().await;
+        let storage = setup_storage();
 
         // Test Base64 image conversion
         let image_data = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==".to_string();
@@ -1138,7 +1333,7 @@ mod tests {
     async fn test_file_part_conversion_roundtrip() {
         use llm_kit_provider_utils::message::{DataContent, FilePart as PromptFilePart};
 
-        let (storage, _dir) = setup_storage().await;
+        let storage = setup_storage();
 
         // Test file conversion with filename
         let file_data = vec![0x48, 0x65, 0x6c, 0x6c, 0x6f]; // "Hello" in bytes
@@ -1169,7 +1364,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_large_conversation_history() {
-        let (storage, _dir) = setup_storage().await;
+        let storage = setup_storage();
         let session_id = storage.generate_session_id();
 
         // Create session
@@ -1217,7 +1412,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_message_ordering_preservation() {
-        let (storage, _dir) = setup_storage().await;
+        let storage = setup_storage();
         let session_id = storage.generate_session_id();
 
         // Create session
@@ -1263,7 +1458,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_empty_session_history() {
-        let (storage, _dir) = setup_storage().await;
+        let storage = setup_storage();
         let session_id = storage.generate_session_id();
 
         // Create session but don't store any messages
@@ -1285,7 +1480,7 @@ mod tests {
             DataContent, ImagePart as PromptImagePart, UserContentPart,
         };
 
-        let (storage, _dir) = setup_storage().await;
+        let storage = setup_storage();
         let session_id = storage.generate_session_id();
 
         // Create user message with text and image
@@ -1316,4 +1511,264 @@ mod tests {
             .collect();
         assert_eq!(image_parts.len(), 1);
     }
+
+    #[test]
+    fn test_usage_to_storage_keeps_detailed_counts() {
+        let usage = LanguageModelUsage {
+            input_tokens: 100,
+            output_tokens: 40,
+            total_tokens: 0,
+            reasoning_tokens: 25,
+            cached_input_tokens: 60,
+        };
+
+        assert_eq!(
+            usage_to_storage(&usage),
+            UsageStats::new(100, 40)
+                .with_reasoning_tokens(25)
+                .with_cached_input_tokens(60)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_response_messages_get_step_metadata() {
+        use crate::generate_text::{RequestMetadata, StepResponseMetadata, to_response_messages};
+        use crate::output::TextOutput;
+        use llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason;
+        use llm_kit_provider::language_model::{
+            LanguageModelGenerateResponse, LanguageModelStreamResponse,
+            call_options::LanguageModelCallOptions,
+        };
+        use regex::Regex;
+        use std::collections::HashMap;
+
+        struct UsageMockLanguageModel;
+
+        #[async_trait::async_trait]
+        impl LanguageModel for UsageMockLanguageModel {
+            fn provider(&self) -> &str {
+                "test-provider"
+            }
+
+            fn model_id(&self) -> &str {
+                "test-model"
+            }
+
+            async fn supported_urls(&self) -> HashMap<String, Vec<Regex>> {
+                HashMap::new()
+            }
+
+            async fn do_generate(
+                &self,
+                _options: LanguageModelCallOptions,
+            ) -> Result<LanguageModelGenerateResponse, Box<dyn std::error::Error>> {
+                unimplemented!("Mock implementation")
+            }
+
+            async fn do_stream(
+                &self,
+                _options: LanguageModelCallOptions,
+            ) -> Result<LanguageModelStreamResponse, Box<dyn std::error::Error>> {
+                unimplemented!("Mock implementation")
+            }
+        }
+
+        let step = |content: Vec<Output>, input_tokens: u64, model_id: Option<&str>| {
+            StepResult::new(
+                content,
+                LanguageModelFinishReason::Stop,
+                LanguageModelUsage::new(input_tokens, 5),
+                None,
+                RequestMetadata::default(),
+                StepResponseMetadata {
+                    model_id: model_id.map(String::from),
+                    ..Default::default()
+                },
+                None,
+            )
+        };
+        // The empty step produces no message, so the steps don't line up with the messages
+        let steps = vec![
+            step(vec![Output::Text(TextOutput::new("First"))], 10, None),
+            step(Vec::new(), 20, None),
+            step(
+                vec![Output::Text(TextOutput::new("Second"))],
+                30,
+                Some("test-model-2024"),
+            ),
+        ];
+        let response_messages: Vec<_> = steps
+            .iter()
+            .flat_map(|step| to_response_messages(step.content.clone(), None))
+            .collect();
+        let result = GenerateTextResult::from_steps(steps, LanguageModelUsage::new(60, 15));
+
+        let storage = setup_storage();
+        let session_id = storage.generate_session_id();
+        let messages = response_messages_to_storage_with_model(
+            &storage,
+            session_id.clone(),
+            &response_messages,
+            &result,
+            &UsageMockLanguageModel,
+        );
+
+        let metadata: Vec<_> = messages
+            .iter()
+            .map(|(message, _)| message.metadata.clone())
+            .collect();
+        assert_eq!(
+            metadata,
+            vec![
+                MessageMetadata {
+                    model_id: Some("test-model".to_string()),
+                    provider: Some("test-provider".to_string()),
+                    usage: Some(UsageStats::new(10, 5)),
+                    finish_reason: Some("Stop".to_string()),
+                    custom: None,
+                    ..Default::default()
+                },
+                // The usage of the empty step is added to the next message
+                MessageMetadata {
+                    model_id: Some("test-model-2024".to_string()),
+                    provider: Some("test-provider".to_string()),
+                    usage: Some(UsageStats::new(50, 10)),
+                    finish_reason: Some("Stop".to_string()),
+                    custom: None,
+                    ..Default::default()
+                },
+            ]
+        );
+
+        for (message, parts) in &messages {
+            storage
+                .store_assistant_message(message, parts)
+                .await
+                .unwrap();
+        }
+        let usage = storage.session_usage(&session_id).await.unwrap();
+        assert_eq!(usage.total, UsageStats::new(60, 15));
+        assert_eq!(usage.steps, 2);
+        assert_eq!(usage.by_model.len(), 2);
+
+        // Without a model, only the model ID reported by the response is recorded
+        let messages =
+            response_messages_to_storage(&storage, session_id, &response_messages, &result);
+        let metadata: Vec<_> = messages
+            .iter()
+            .map(|(message, _)| {
+                (
+                    message.metadata.model_id.clone(),
+                    message.metadata.provider.clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            metadata,
+            vec![(None, None), (Some("test-model-2024".to_string()), None)]
+        );
+    }
+
+    #[test]
+    fn test_trailing_step_usage_is_added_to_last_message() {
+        use crate::generate_text::{RequestMetadata, StepResponseMetadata, to_response_messages};
+        use crate::output::TextOutput;
+        use llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason;
+
+        let step = |content: Vec<Output>, input_tokens: u64| {
+            StepResult::new(
+                content,
+                LanguageModelFinishReason::Stop,
+                LanguageModelUsage::new(input_tokens, 5),
+                None,
+                RequestMetadata::default(),
+                StepResponseMetadata::default(),
+                None,
+            )
+        };
+        let steps = vec![
+            step(vec![Output::Text(TextOutput::new("Answer"))], 10),
+            step(Vec::new(), 20),
+        ];
+        let response_messages: Vec<_> = steps
+            .iter()
+            .flat_map(|step| to_response_messages(step.content.clone(), None))
+            .collect();
+        let result = GenerateTextResult::from_steps(steps, LanguageModelUsage::new(30, 10));
+
+        let storage = setup_storage();
+        let messages = response_messages_to_storage(
+            &storage,
+            storage.generate_session_id(),
+            &response_messages,
+            &result,
+        );
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].0.metadata.usage, Some(UsageStats::new(30, 10)));
+    }
+
+    #[tokio::test]
+    async fn test_tool_approval_roundtrip() {
+        use llm_kit_provider_utils::message::tool::ToolContentPart;
+        use llm_kit_provider_utils::tool::ToolApprovalResponse;
+
+        let storage = setup_storage();
+        let session_id = storage.generate_session_id();
+        let session = llm_kit_storage::Session::new(session_id.clone());
+        storage.store_session(&session).await.unwrap();
+
+        // Assistant message that calls a tool and asks for approval
+        let tool_call_id = storage.generate_part_id();
+        let approval_request_id = storage.generate_part_id();
+        let message = StorageAssistantMessage::new(
+            storage.generate_message_id(),
+            session_id.clone(),
+            vec![tool_call_id.clone(), approval_request_id.clone()],
+        );
+        let parts = vec![
+            MessagePart::ToolCall(ToolCallPart::new(
+                tool_call_id,
+                "call_1".to_string(),
+                "delete_file".to_string(),
+                serde_json::json!({"path": "a.txt"}),
+            )),
+            MessagePart::ToolApprovalRequest(ToolApprovalRequestPart::new(
+                approval_request_id,
+                "approval_1".to_string(),
+                "call_1".to_string(),
+            )),
+        ];
+        storage
+            .store_assistant_message(&message, &parts)
+            .await
+            .unwrap();
+
+        let pending = load_pending_approvals(&storage, &session_id).await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].approval_id, "approval_1");
+        assert_eq!(pending[0].tool_call.tool_name, "delete_file");
+
+        // Answer the approval request
+        let tool_message = ToolMessage::new(vec![ToolContentPart::ApprovalResponse(
+            ToolApprovalResponse::denied("approval_1").with_reason("Keep it"),
+        )]);
+        let (message, parts) = tool_message_to_storage(&storage, session_id.clone(), &tool_message);
+        storage
+            .store_assistant_message(&message, &parts)
+            .await
+            .unwrap();
+
+        let history = load_conversation_history(&storage, &session_id)
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1], Message::Tool(tool_message));
+        assert!(
+            load_pending_approvals(&storage, &session_id)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
 }
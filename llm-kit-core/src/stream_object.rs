@@ -0,0 +1,484 @@
+/// Stream part types for object streaming.
+pub mod object_stream_part;
+/// Result type for object streaming.
+pub mod stream_object_result;
+
+pub use object_stream_part::ObjectStreamPart;
+pub use stream_object_result::StreamObjectResult;
+
+use crate::error::AISDKError;
+use crate::generate_object::{
+    JSON_TOOL_NAME, ObjectGenerationMode, object_schema, prepare_object_call_options,
+};
+use crate::generate_text::{RequestMetadata, RetryPolicy, StepResponseMetadata, prepare_retries};
+use crate::prompt::{Prompt, call_settings::CallSettings, download::Downloader};
+use crate::stream_text::AsyncIterableStream;
+use crate::stream_text::output::repair_partial_json;
+use futures_util::StreamExt;
+use llm_kit_provider::language_model::LanguageModel;
+use llm_kit_provider::language_model::stream_part::LanguageModelStreamPart;
+use llm_kit_provider::shared::provider_options::SharedProviderOptions;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+/// Builder for streaming a typed, schema-validated object from a language model.
+///
+/// Partial objects are emitted while the JSON text is generated. The final object
+/// is validated against the JSON schema of `T` when it is awaited through
+/// [`StreamObjectResult::object`].
+///
+/// # Example
+///
+/// ```no_run
+/// use llm_kit_core::StreamObject;
+/// use llm_kit_core::prompt::Prompt;
+/// use futures::StreamExt;
+/// use schemars::JsonSchema;
+/// use serde::Deserialize;
+/// # use std::sync::Arc;
+/// # use llm_kit_provider::LanguageModel;
+/// # async fn example(model: Arc<dyn LanguageModel>) -> Result<(), Box<dyn std::error::Error>> {
+///
+/// #[derive(Debug, Deserialize, JsonSchema)]
+/// struct Recipe {
+///     name: String,
+///     ingredients: Vec<String>,
+/// }
+///
+/// let result = StreamObject::<Recipe>::new(model, Prompt::text("Generate a lasagna recipe"))
+///     .execute()
+///     .await?;
+///
+/// let mut partial_stream = result.partial_object_stream();
+/// while let Some(partial) = partial_stream.next().await {
+///     println!("{}", partial);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct StreamObject<T> {
+    model: Arc<dyn LanguageModel>,
+    prompt: Prompt,
+    settings: CallSettings,
+    retry_policy: Option<Arc<dyn RetryPolicy>>,
+    downloader: Option<Arc<dyn Downloader>>,
+    mode: ObjectGenerationMode,
+    schema_name: Option<String>,
+    schema_description: Option<String>,
+    provider_options: Option<SharedProviderOptions>,
+    _object: PhantomData<fn() -> T>,
+}
+
+impl<T> StreamObject<T>
+where
+    T: JsonSchema + DeserializeOwned,
+{
+    /// Creates a new builder with the required model and prompt.
+    pub fn new(model: Arc<dyn LanguageModel>, prompt: Prompt) -> Self {
+        Self {
+            model,
+            prompt,
+            settings: CallSettings::default(),
+            retry_policy: None,
+            downloader: None,
+            mode: ObjectGenerationMode::default(),
+            schema_name: None,
+            schema_description: None,
+            provider_options: None,
+            _object: PhantomData,
+        }
+    }
+
+    /// Sets the complete call settings.
+    pub fn settings(mut self, settings: CallSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Sets the temperature for generation.
+    pub fn temperature(mut self, temperature: f64) -> Self {
+        self.settings = self.settings.with_temperature(temperature);
+        self
+    }
+
+    /// Sets the maximum number of output tokens.
+    pub fn max_output_tokens(mut self, max_tokens: u32) -> Self {
+        self.settings = self.settings.with_max_output_tokens(max_tokens);
+        self
+    }
+
+    /// Sets the top_p value for nucleus sampling.
+    pub fn top_p(mut self, top_p: f64) -> Self {
+        self.settings = self.settings.with_top_p(top_p);
+        self
+    }
+
+    /// Sets the top_k value for sampling.
+    pub fn top_k(mut self, top_k: u32) -> Self {
+        self.settings = self.settings.with_top_k(top_k);
+        self
+    }
+
+    /// Sets the seed for deterministic generation.
+    pub fn seed(mut self, seed: u32) -> Self {
+        self.settings = self.settings.with_seed(seed);
+        self
+    }
+
+    /// Sets the maximum number of retries.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.settings = self.settings.with_max_retries(max_retries);
+        self
+    }
+
+    /// Sets the policy that decides whether and when failed model calls are retried.
+    ///
+    /// Defaults to [`DefaultRetryPolicy`](crate::generate_text::DefaultRetryPolicy).
+    pub fn retry_policy(mut self, retry_policy: Arc<dyn RetryPolicy>) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Sets the downloader for prompt URLs the model doesn't support.
+    ///
+    /// Defaults to [`DefaultDownloader`](crate::prompt::download::DefaultDownloader).
+    pub fn downloader(mut self, downloader: Arc<dyn Downloader>) -> Self {
+        self.downloader = Some(downloader);
+        self
+    }
+
+    /// Sets custom HTTP headers.
+    pub fn headers(mut self, headers: std::collections::HashMap<String, String>) -> Self {
+        self.settings = self.settings.with_headers(headers);
+        self
+    }
+
+    /// Sets the abort signal for cancellation.
+    pub fn abort_signal(mut self, signal: CancellationToken) -> Self {
+        self.settings = self.settings.with_abort_signal(signal);
+        self
+    }
+
+    /// Sets how the object is requested from the model.
+    ///
+    /// Defaults to [`ObjectGenerationMode::Auto`].
+    pub fn mode(mut self, mode: ObjectGenerationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the name of the output that should be generated.
+    ///
+    /// Used by some providers for additional LLM guidance.
+    pub fn schema_name(mut self, name: impl Into<String>) -> Self {
+        self.schema_name = Some(name.into());
+        self
+    }
+
+    /// Sets the description of the output that should be generated.
+    ///
+    /// Used by some providers for additional LLM guidance.
+    pub fn schema_description(mut self, description: impl Into<String>) -> Self {
+        self.schema_description = Some(description.into());
+        self
+    }
+
+    /// Sets provider-specific options.
+    pub fn provider_options(mut self, options: SharedProviderOptions) -> Self {
+        self.provider_options = Some(options);
+        self
+    }
+
+    /// Starts streaming the object with the configured settings.
+    ///
+    /// Errors that occur after the stream has started are emitted as
+    /// [`ObjectStreamPart::Error`] parts.
+    pub async fn execute(self) -> Result<StreamObjectResult<T>, AISDKError> {
+        let retry_config = prepare_retries(
+            self.settings.max_retries,
+            self.settings.abort_signal.clone(),
+            self.retry_policy.clone(),
+        )?;
+
+        let schema = object_schema::<T>();
+        let mode = self.mode.resolve(self.model.as_ref());
+
+        let call_options = prepare_object_call_options(
+            self.model.as_ref(),
+            self.prompt,
+            &self.settings,
+            self.provider_options,
+            self.downloader,
+        )
+        .await?;
+        let call_options = mode.apply(
+            call_options,
+            &schema,
+            self.schema_name.as_ref(),
+            self.schema_description.as_ref(),
+        );
+
+        let stream_response = retry_config
+            .execute_with_boxed_error(|| {
+                let call_options = call_options.clone();
+                let model = Arc::clone(&self.model);
+                async move { model.do_stream(call_options).await }
+            })
+            .await?;
+
+        let request = RequestMetadata {
+            body: stream_response.request.and_then(|r| r.body),
+        };
+        let mut provider_stream = stream_response.stream;
+
+        let (tx, mut rx) = mpsc::unbounded_channel::<ObjectStreamPart>();
+
+        tokio::spawn(async move {
+            let mut accumulated = String::new();
+            let mut last_object: Option<Value> = None;
+            let mut json_tool_input_id: Option<String> = None;
+            let mut response = StepResponseMetadata::default();
+
+            while let Some(part) = provider_stream.next().await {
+                let delta = match part {
+                    LanguageModelStreamPart::StreamStart(ss) => {
+                        let _ = tx.send(ObjectStreamPart::Start {
+                            warnings: ss.warnings,
+                        });
+                        continue;
+                    }
+                    LanguageModelStreamPart::ResponseMetadata(metadata) => {
+                        response = metadata.into();
+                        continue;
+                    }
+                    LanguageModelStreamPart::TextDelta(td)
+                        if mode != ObjectGenerationMode::Tool =>
+                    {
+                        td.delta
+                    }
+                    LanguageModelStreamPart::ToolInputStart(tis)
+                        if mode == ObjectGenerationMode::Tool
+                            && tis.tool_name == JSON_TOOL_NAME =>
+                    {
+                        json_tool_input_id = Some(tis.id);
+                        continue;
+                    }
+                    LanguageModelStreamPart::ToolInputDelta(tid)
+                        if json_tool_input_id.as_deref() == Some(tid.id.as_str()) =>
+                    {
+                        tid.delta
+                    }
+                    // Providers that do not stream tool inputs only send the complete call
+                    LanguageModelStreamPart::ToolCall(tool_call)
+                        if mode == ObjectGenerationMode::Tool
+                            && tool_call.tool_name == JSON_TOOL_NAME
+                            && accumulated.is_empty() =>
+                    {
+                        tool_call.input
+                    }
+                    LanguageModelStreamPart::Finish(f) => {
+                        let _ = tx.send(ObjectStreamPart::Finish {
+                            finish_reason: f.finish_reason,
+                            usage: f.usage,
+                            response: response.clone(),
+                            provider_metadata: f.provider_metadata,
+                        });
+                        continue;
+                    }
+                    LanguageModelStreamPart::Error(e) => {
+                        let _ = tx.send(ObjectStreamPart::Error { error: e.error });
+                        break;
+                    }
+                    _ => continue,
+                };
+
+                if delta.is_empty() {
+                    continue;
+                }
+                accumulated.push_str(&delta);
+                let _ = tx.send(ObjectStreamPart::TextDelta { text_delta: delta });
+
+                // Emit a new partial object whenever the repaired JSON changes
+                if let Ok(object) =
+                    serde_json::from_str::<Value>(&repair_partial_json(&accumulated))
+                    && last_object.as_ref() != Some(&object)
+                {
+                    last_object = Some(object.clone());
+                    let _ = tx.send(ObjectStreamPart::Object { object });
+                }
+            }
+        });
+
+        let stream: AsyncIterableStream<ObjectStreamPart> = Box::pin(async_stream::stream! {
+            while let Some(part) = rx.recv().await {
+                yield part;
+            }
+        });
+
+        Ok(StreamObjectResult::new(stream, schema, request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use llm_kit_provider::language_model::call_options::LanguageModelCallOptions;
+    use llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason;
+    use llm_kit_provider::language_model::usage::LanguageModelUsage;
+    use llm_kit_provider::language_model::{
+        LanguageModelGenerateResponse, LanguageModelStreamResponse,
+    };
+    use regex::Regex;
+    use serde::Deserialize;
+    use std::collections::HashMap;
+
+    #[derive(Debug, PartialEq, Deserialize, JsonSchema)]
+    struct Recipe {
+        name: String,
+        servings: u32,
+    }
+
+    struct StreamMockLanguageModel {
+        structured_outputs: bool,
+        parts: Vec<LanguageModelStreamPart>,
+    }
+
+    #[async_trait]
+    impl LanguageModel for StreamMockLanguageModel {
+        fn provider(&self) -> &str {
+            "test-provider"
+        }
+
+        fn model_id(&self) -> &str {
+            "test-model"
+        }
+
+        async fn supported_urls(&self) -> HashMap<String, Vec<Regex>> {
+            HashMap::new()
+        }
+
+        fn supports_structured_outputs(&self) -> bool {
+            self.structured_outputs
+        }
+
+        async fn do_generate(
+            &self,
+            _options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelGenerateResponse, Box<dyn std::error::Error>> {
+            unimplemented!("Mock implementation")
+        }
+
+        async fn do_stream(
+            &self,
+            _options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelStreamResponse, Box<dyn std::error::Error>> {
+            Ok(LanguageModelStreamResponse {
+                stream: Box::new(futures_util::stream::iter(self.parts.clone())),
+                request: None,
+                response: None,
+            })
+        }
+    }
+
+    fn finish_part() -> LanguageModelStreamPart {
+        LanguageModelStreamPart::finish(
+            LanguageModelUsage::new(10, 20),
+            LanguageModelFinishReason::Stop,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_stream_object_partial_objects() {
+        let model = Arc::new(StreamMockLanguageModel {
+            structured_outputs: true,
+            parts: vec![
+                LanguageModelStreamPart::stream_start(Vec::new()),
+                LanguageModelStreamPart::text_start("1"),
+                LanguageModelStreamPart::text_delta("1", r#"{"name": "Las"#),
+                LanguageModelStreamPart::text_delta("1", r#"agna", "servings""#),
+                LanguageModelStreamPart::text_delta("1", r#": 4}"#),
+                LanguageModelStreamPart::text_end("1"),
+                finish_part(),
+            ],
+        });
+
+        let result = StreamObject::<Recipe>::new(model, Prompt::text("Recipe"))
+            .execute()
+            .await
+            .unwrap();
+
+        // The dangling "servings" key cannot be repaired, so no partial is emitted for it
+        let partials: Vec<Value> = result.clone().partial_object_stream().collect().await;
+        assert_eq!(
+            partials,
+            vec![
+                serde_json::json!({ "name": "Las" }),
+                serde_json::json!({ "name": "Lasagna", "servings": 4 }),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_object_tool_mode() {
+        let model = Arc::new(StreamMockLanguageModel {
+            structured_outputs: false,
+            parts: vec![
+                LanguageModelStreamPart::tool_input_start("call_1", JSON_TOOL_NAME),
+                LanguageModelStreamPart::tool_input_delta("call_1", r#"{"name": "Pancakes", "#),
+                LanguageModelStreamPart::tool_input_delta("call_1", r#""servings": 2}"#),
+                LanguageModelStreamPart::tool_input_end("call_1"),
+                finish_part(),
+            ],
+        });
+
+        let result = StreamObject::<Recipe>::new(model, Prompt::text("Recipe"))
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.object().await.unwrap(),
+            Recipe {
+                name: "Pancakes".to_string(),
+                servings: 2
+            }
+        );
+        assert_eq!(
+            result.usage().await.unwrap(),
+            LanguageModelUsage::new(10, 20)
+        );
+        assert_eq!(
+            result.finish_reason().await.unwrap(),
+            LanguageModelFinishReason::Stop
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_object_schema_mismatch() {
+        let model = Arc::new(StreamMockLanguageModel {
+            structured_outputs: true,
+            parts: vec![
+                LanguageModelStreamPart::text_delta("1", r#"{"name": "Lasagna"}"#),
+                finish_part(),
+            ],
+        });
+
+        let result = StreamObject::<Recipe>::new(model, Prompt::text("Recipe"))
+            .execute()
+            .await
+            .unwrap();
+
+        match result.object().await {
+            Err(AISDKError::NoObjectGenerated { text, .. }) => {
+                assert_eq!(text.as_deref(), Some(r#"{"name": "Lasagna"}"#));
+            }
+            other => panic!("Expected NoObjectGenerated error, got {:?}", other.err()),
+        }
+    }
+}
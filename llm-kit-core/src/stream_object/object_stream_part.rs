@@ -0,0 +1,49 @@
+use crate::generate_text::StepResponseMetadata;
+use llm_kit_provider::language_model::call_warning::LanguageModelCallWarning;
+use llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason;
+use llm_kit_provider::language_model::usage::LanguageModelUsage;
+use llm_kit_provider::shared::provider_metadata::SharedProviderMetadata;
+use serde_json::Value;
+
+/// A part of the stream returned by `stream_object`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ObjectStreamPart {
+    /// The stream has started.
+    Start {
+        /// Warnings from the model provider (e.g. unsupported settings).
+        warnings: Vec<LanguageModelCallWarning>,
+    },
+
+    /// A new partial object.
+    ///
+    /// Emitted whenever the repaired JSON text parses to a different value than
+    /// the previous partial object.
+    Object {
+        /// The partial object.
+        object: Value,
+    },
+
+    /// A delta of the raw JSON text.
+    TextDelta {
+        /// The text delta.
+        text_delta: String,
+    },
+
+    /// The model finished generating.
+    Finish {
+        /// The reason why the generation finished.
+        finish_reason: LanguageModelFinishReason,
+        /// The token usage of the generation.
+        usage: LanguageModelUsage,
+        /// Response metadata.
+        response: StepResponseMetadata,
+        /// Additional provider-specific metadata.
+        provider_metadata: Option<SharedProviderMetadata>,
+    },
+
+    /// An error occurred.
+    Error {
+        /// The error.
+        error: Value,
+    },
+}
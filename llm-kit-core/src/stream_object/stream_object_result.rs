@@ -0,0 +1,323 @@
+use crate::error::AISDKError;
+use crate::generate_object::validate_object::parse_and_validate_object;
+use crate::generate_text::{RequestMetadata, StepResponseMetadata};
+use crate::stream_object::ObjectStreamPart;
+use crate::stream_text::AsyncIterableStream;
+use futures_util::StreamExt;
+use llm_kit_provider::language_model::call_warning::LanguageModelCallWarning;
+use llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason;
+use llm_kit_provider::language_model::usage::LanguageModelUsage;
+use llm_kit_provider::shared::provider_metadata::SharedProviderMetadata;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::marker::PhantomData;
+use std::sync::{Arc, OnceLock};
+use tokio::sync::Mutex;
+
+/// Internal state shared between all accessors in a `StreamObjectResult`.
+#[derive(Clone)]
+struct StreamState {
+    text: String,
+    finish_reason: LanguageModelFinishReason,
+    usage: LanguageModelUsage,
+    warnings: Option<Vec<LanguageModelCallWarning>>,
+    response: StepResponseMetadata,
+    provider_metadata: Option<SharedProviderMetadata>,
+}
+
+impl Default for StreamState {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            finish_reason: LanguageModelFinishReason::Unknown,
+            usage: LanguageModelUsage::default(),
+            warnings: None,
+            response: StepResponseMetadata::default(),
+            provider_metadata: None,
+        }
+    }
+}
+
+/// The result of a `stream_object` call.
+///
+/// The stream can either be read incrementally through [`partial_object_stream`],
+/// [`text_stream`] or [`full_stream`], or awaited through the accessors such as
+/// [`object`], which consume the remaining stream.
+///
+/// [`partial_object_stream`]: StreamObjectResult::partial_object_stream
+/// [`text_stream`]: StreamObjectResult::text_stream
+/// [`full_stream`]: StreamObjectResult::full_stream
+/// [`object`]: StreamObjectResult::object
+///
+/// # Example
+///
+/// ```no_run
+/// use llm_kit_core::StreamObjectResult;
+/// use futures::StreamExt;
+/// # use serde::Deserialize;
+/// # #[derive(Debug, Deserialize)]
+/// # struct Recipe { name: String }
+///
+/// # async fn example(result: StreamObjectResult<Recipe>) -> Result<(), Box<dyn std::error::Error>> {
+/// let mut partial_stream = result.partial_object_stream();
+/// while let Some(partial) = partial_stream.next().await {
+///     println!("{}", partial);
+/// }
+///
+/// let recipe = result.object().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct StreamObjectResult<T> {
+    /// Shared state that holds the consumed stream data
+    state: Arc<OnceLock<StreamState>>,
+
+    /// The underlying full stream
+    full_stream: Arc<Mutex<Option<AsyncIterableStream<ObjectStreamPart>>>>,
+
+    /// Whether the stream has been consumed
+    consumed: Arc<Mutex<bool>>,
+
+    /// The JSON schema the final object is validated against
+    schema: Arc<Value>,
+
+    /// Request metadata of the model call
+    request: RequestMetadata,
+
+    _object: PhantomData<fn() -> T>,
+}
+
+impl<T> StreamObjectResult<T>
+where
+    T: DeserializeOwned,
+{
+    /// Creates a new `StreamObjectResult` from a stream of object stream parts.
+    ///
+    /// # Arguments
+    ///
+    /// * `full_stream` - The stream of object stream parts
+    /// * `schema` - The JSON schema the final object is validated against
+    /// * `request` - Request metadata of the model call
+    pub fn new(
+        full_stream: AsyncIterableStream<ObjectStreamPart>,
+        schema: Value,
+        request: RequestMetadata,
+    ) -> Self {
+        Self {
+            state: Arc::new(OnceLock::new()),
+            full_stream: Arc::new(Mutex::new(Some(full_stream))),
+            consumed: Arc::new(Mutex::new(false)),
+            schema: Arc::new(schema),
+            request,
+            _object: PhantomData,
+        }
+    }
+
+    /// Consumes the stream and populates the internal state.
+    ///
+    /// This method is called automatically by all awaiting accessors.
+    /// It ensures the stream is only consumed once.
+    async fn ensure_consumed(&self) -> Result<(), AISDKError> {
+        if self.state.get().is_some() {
+            return Ok(());
+        }
+
+        let mut consumed = self.consumed.lock().await;
+        if *consumed {
+            while self.state.get().is_none() {
+                tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+            }
+            return Ok(());
+        }
+
+        *consumed = true;
+        drop(consumed);
+
+        let mut stream = self
+            .full_stream
+            .lock()
+            .await
+            .take()
+            .ok_or_else(|| AISDKError::model_error("Stream already consumed"))?;
+
+        let mut state = StreamState::default();
+        while let Some(part) = stream.next().await {
+            match part {
+                ObjectStreamPart::Start { warnings } => {
+                    if !warnings.is_empty() {
+                        state.warnings = Some(warnings);
+                    }
+                }
+                ObjectStreamPart::TextDelta { text_delta } => {
+                    state.text.push_str(&text_delta);
+                }
+                ObjectStreamPart::Finish {
+                    finish_reason,
+                    usage,
+                    response,
+                    provider_metadata,
+                } => {
+                    state.finish_reason = finish_reason;
+                    state.usage = usage;
+                    state.response = response;
+                    state.provider_metadata = provider_metadata;
+                }
+                ObjectStreamPart::Error { error } => {
+                    return Err(AISDKError::model_error(format!("Stream error: {}", error)));
+                }
+                ObjectStreamPart::Object { .. } => {}
+            }
+        }
+
+        self.state
+            .set(state)
+            .map_err(|_| AISDKError::model_error("Failed to store stream state"))?;
+
+        Ok(())
+    }
+
+    fn state(&self) -> &StreamState {
+        self.state
+            .get()
+            .expect("stream state is set after consumption")
+    }
+
+    /// The generated object, validated against the schema and deserialized into `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AISDKError::NoObjectGenerated`] when the model did not return an
+    /// object, or when the object does not match the schema.
+    pub async fn object(&self) -> Result<T, AISDKError> {
+        self.ensure_consumed().await?;
+        let state = self.state();
+
+        if state.text.is_empty() {
+            return Err(AISDKError::no_object_generated_builder()
+                .message("The model did not return a response.")
+                .response(state.response.clone())
+                .usage(state.usage)
+                .finish_reason(state.finish_reason.clone())
+                .build());
+        }
+
+        parse_and_validate_object::<T>(&state.text, &self.schema).map_err(|message| {
+            AISDKError::no_object_generated_builder()
+                .message(message)
+                .text(state.text.clone())
+                .response(state.response.clone())
+                .usage(state.usage)
+                .finish_reason(state.finish_reason.clone())
+                .build()
+        })
+    }
+
+    /// The raw JSON text that was generated by the model.
+    pub async fn text(&self) -> Result<String, AISDKError> {
+        self.ensure_consumed().await?;
+        Ok(self.state().text.clone())
+    }
+
+    /// The reason why the generation finished.
+    pub async fn finish_reason(&self) -> Result<LanguageModelFinishReason, AISDKError> {
+        self.ensure_consumed().await?;
+        Ok(self.state().finish_reason.clone())
+    }
+
+    /// The token usage of the generation.
+    pub async fn usage(&self) -> Result<LanguageModelUsage, AISDKError> {
+        self.ensure_consumed().await?;
+        Ok(self.state().usage)
+    }
+
+    /// Warnings from the model provider (e.g. unsupported settings).
+    pub async fn warnings(&self) -> Result<Option<Vec<LanguageModelCallWarning>>, AISDKError> {
+        self.ensure_consumed().await?;
+        Ok(self.state().warnings.clone())
+    }
+
+    /// Response metadata.
+    pub async fn response(&self) -> Result<StepResponseMetadata, AISDKError> {
+        self.ensure_consumed().await?;
+        Ok(self.state().response.clone())
+    }
+
+    /// Additional provider-specific metadata.
+    pub async fn provider_metadata(&self) -> Result<Option<SharedProviderMetadata>, AISDKError> {
+        self.ensure_consumed().await?;
+        Ok(self.state().provider_metadata.clone())
+    }
+
+    /// Request metadata of the model call.
+    pub fn request(&self) -> &RequestMetadata {
+        &self.request
+    }
+
+    /// Gets a stream of partial objects.
+    ///
+    /// Partial objects are not validated against the schema; fields may be missing
+    /// or incomplete until the stream finishes.
+    ///
+    /// Note: This consumes the full stream, so it cannot be called after other
+    /// consuming operations.
+    pub fn partial_object_stream(&self) -> AsyncIterableStream<Value> {
+        let full_stream = self.full_stream.clone();
+
+        Box::pin(async_stream::stream! {
+            let mut stream = full_stream.lock().await;
+            if let Some(mut s) = stream.take() {
+                while let Some(part) = s.next().await {
+                    if let ObjectStreamPart::Object { object } = part {
+                        yield object;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Gets a stream of the raw JSON text deltas.
+    ///
+    /// Note: This consumes the full stream, so it cannot be called after other
+    /// consuming operations.
+    pub fn text_stream(&self) -> AsyncIterableStream<String> {
+        let full_stream = self.full_stream.clone();
+
+        Box::pin(async_stream::stream! {
+            let mut stream = full_stream.lock().await;
+            if let Some(mut s) = stream.take() {
+                while let Some(part) = s.next().await {
+                    if let ObjectStreamPart::TextDelta { text_delta } = part {
+                        yield text_delta;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Gets a stream with all events, including partial objects, text deltas and errors.
+    pub fn full_stream(&self) -> AsyncIterableStream<ObjectStreamPart> {
+        let full_stream = self.full_stream.clone();
+
+        Box::pin(async_stream::stream! {
+            let mut stream = full_stream.lock().await;
+            if let Some(mut s) = stream.take() {
+                while let Some(part) = s.next().await {
+                    yield part;
+                }
+            }
+        })
+    }
+}
+
+impl<T> Clone for StreamObjectResult<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: Arc::clone(&self.state),
+            full_stream: Arc::clone(&self.full_stream),
+            consumed: Arc::clone(&self.consumed),
+            schema: Arc::clone(&self.schema),
+            request: self.request.clone(),
+            _object: PhantomData,
+        }
+    }
+}
@@ -2,6 +2,9 @@
 pub mod callbacks;
 /// Output parsing for structured streaming.
 pub mod output;
+/// Resumable streams persisted in storage (requires storage feature).
+#[cfg(feature = "storage")]
+pub mod resume;
 /// Result type for streaming operations.
 pub mod stream_text_result;
 /// Stream part types for text streaming.
@@ -16,6 +19,8 @@ pub use callbacks::{
     StreamTextOnErrorCallback as OnErrorCallback, StreamTextOnFinishCallback as OnFinishCallback,
     StreamTextOnStepFinishCallback as OnStepFinishCallback,
 };
+#[cfg(feature = "storage")]
+pub use resume::resume_stream;
 pub use stream_text_result::{
     AsyncIterableStream, ConsumeStreamOptions, ErrorHandler, StreamTextResult,
 };
@@ -29,24 +34,34 @@ pub use transform::{
 use crate::ResponseMessage;
 use crate::error::AISDKError;
 use crate::generate_text::{
-    PrepareStep, PrepareStepOptions, StepResult, StopCondition, is_stop_condition_met,
-    to_response_messages,
+    PrepareStep, PrepareStepOptions, RetryConfig, RetryPolicy, StepResult, StopCondition,
+    execute_tools_stream, is_stop_condition_met, prepare_retries, to_response_messages,
 };
+#[cfg(feature = "storage")]
+use crate::history::HistoryStrategy;
 use crate::output::{Output, ReasoningOutput, SourceOutput, TextOutput};
 use crate::prompt::{
-    Prompt, call_settings::CallSettings, call_settings::prepare_call_settings,
+    Prompt,
+    call_settings::CallSettings,
+    call_settings::prepare_call_settings,
     convert_to_language_model_prompt::convert_to_language_model_prompt,
-    standardize::StandardizedPrompt, standardize::validate_and_standardize,
+    download::{Downloader, PromptDownloads},
+    standardize::StandardizedPrompt,
+    standardize::validate_and_standardize,
 };
+use crate::telemetry::{TelemetrySettings, TelemetrySpan};
 use crate::tool::{
-    ToolSet, execute_tool_call, parse_provider_executed_dynamic_tool_call, parse_tool_call,
-    prepare_tools_and_tool_choice,
+    ToolCallRepairFunction, ToolSet, parse_provider_executed_dynamic_tool_call,
+    parse_tool_call_with_repair, prepare_tools_and_tool_choice,
+};
+use llm_kit_provider::language_model::call_options::{
+    LanguageModelCallOptions, LanguageModelResponseFormat,
 };
-use llm_kit_provider::language_model::call_options::LanguageModelCallOptions;
 use llm_kit_provider::language_model::{
     LanguageModel, finish_reason::LanguageModelFinishReason, tool_choice::LanguageModelToolChoice,
     usage::LanguageModelUsage,
 };
+use llm_kit_provider::shared::provider_metadata::SharedProviderMetadata;
 use llm_kit_provider::shared::provider_options::SharedProviderOptions;
 use llm_kit_provider_utils::message::Message;
 use llm_kit_provider_utils::tool::ToolCall;
@@ -55,6 +70,64 @@ use std::pin::Pin;
 use std::sync::Arc;
 use tokio::sync::mpsc;
 
+/// Reasoning blocks that have started but not ended, in the order they started.
+///
+/// Blocks are tracked by their stream part ID because providers can interleave them
+/// (OpenAI Responses starts the next summary part before ending the previous one).
+/// The provider metadata of the start and end parts is kept on the output, so that
+/// reasoning without text (e.g. only encrypted content) still round-trips.
+#[derive(Default)]
+struct ActiveReasoning {
+    blocks: Vec<(String, ReasoningOutput)>,
+}
+
+impl ActiveReasoning {
+    fn start(&mut self, id: String, provider_metadata: Option<SharedProviderMetadata>) {
+        let mut reasoning = ReasoningOutput::new("");
+        reasoning.provider_metadata = provider_metadata;
+        self.blocks.push((id, reasoning));
+    }
+
+    fn delta(&mut self, id: &str, text: &str) {
+        match self.blocks.iter_mut().find(|(block_id, _)| block_id == id) {
+            Some((_, reasoning)) => reasoning.text.push_str(text),
+            // Providers that don't send a start part
+            None => self
+                .blocks
+                .push((id.to_string(), ReasoningOutput::new(text))),
+        }
+    }
+
+    /// Ends a block. Returns `None` for blocks without text or metadata.
+    fn end(
+        &mut self,
+        id: &str,
+        provider_metadata: Option<SharedProviderMetadata>,
+    ) -> Option<ReasoningOutput> {
+        let index = self
+            .blocks
+            .iter()
+            .position(|(block_id, _)| block_id == id)?;
+        let (_, mut reasoning) = self.blocks.remove(index);
+        if provider_metadata.is_some() {
+            reasoning.provider_metadata = provider_metadata;
+        }
+        Self::keep(reasoning)
+    }
+
+    /// Ends all open blocks.
+    fn finish(&mut self) -> Vec<ReasoningOutput> {
+        self.blocks
+            .drain(..)
+            .filter_map(|(_, reasoning)| Self::keep(reasoning))
+            .collect()
+    }
+
+    fn keep(reasoning: ReasoningOutput) -> Option<ReasoningOutput> {
+        (!reasoning.text.is_empty() || reasoning.provider_metadata.is_some()).then_some(reasoning)
+    }
+}
+
 /// Result of streaming a single step.
 ///
 /// This contains all the accumulated data from processing one streaming call to the model.
@@ -89,23 +162,34 @@ struct SingleStepStreamResult {
 /// This helper function handles one streaming call to the model and processes all the
 /// stream parts, accumulating tool calls and content. It emits stream parts to the channel
 /// as they arrive.
+#[allow(clippy::too_many_arguments)]
 async fn stream_single_step(
     model: Arc<dyn LanguageModel>,
+    retry_config: &RetryConfig,
     call_options: llm_kit_provider::language_model::call_options::LanguageModelCallOptions,
     tools: Option<&ToolSet>,
+    repair_tool_call: Option<&ToolCallRepairFunction>,
+    step_system: Option<&str>,
+    step_messages: &[Message],
     include_raw_chunks: bool,
     tx: &mpsc::UnboundedSender<TextStreamPart>,
     on_chunk: Option<&Arc<OnChunkCallback>>,
     on_error: Option<&Arc<OnErrorCallback>>,
+    span: &TelemetrySpan,
 ) -> Result<SingleStepStreamResult, AISDKError> {
     use futures_util::StreamExt;
     use llm_kit_provider::language_model::stream_part::LanguageModelStreamPart;
 
-    // Call model.do_stream
-    let stream_response = model
-        .do_stream(call_options)
+    // Call model.do_stream with retry logic
+    let model_span = span.model_call("do_stream", model.as_ref(), &call_options);
+    let stream_response = model_span
+        .instrument(retry_config.execute_with_boxed_error(|| {
+            let model = Arc::clone(&model);
+            let call_options = call_options.clone();
+            async move { model.do_stream(call_options).await }
+        }))
         .await
-        .map_err(|e| AISDKError::model_error(e.to_string()))?;
+        .inspect_err(|error| model_span.record_error(error))?;
 
     // Extract metadata before moving stream
     let request_body = stream_response
@@ -119,7 +203,7 @@ async fn stream_single_step(
     let mut step_content: Vec<Output> = Vec::new();
     let mut step_tool_calls: Vec<ToolCall> = Vec::new();
     let mut current_text = String::new();
-    let mut current_reasoning = String::new();
+    let mut active_reasoning = ActiveReasoning::default();
     let step_request = crate::generate_text::RequestMetadata {
         body: request_body.clone(),
     };
@@ -155,12 +239,15 @@ async fn stream_single_step(
                     provider_metadata: te.provider_metadata,
                 }
             }
-            LanguageModelStreamPart::ReasoningStart(rs) => TextStreamPart::ReasoningStart {
-                id: rs.id,
-                provider_metadata: rs.provider_metadata,
-            },
+            LanguageModelStreamPart::ReasoningStart(rs) => {
+                active_reasoning.start(rs.id.clone(), rs.provider_metadata.clone());
+                TextStreamPart::ReasoningStart {
+                    id: rs.id,
+                    provider_metadata: rs.provider_metadata,
+                }
+            }
             LanguageModelStreamPart::ReasoningDelta(rd) => {
-                current_reasoning.push_str(&rd.delta);
+                active_reasoning.delta(&rd.id, &rd.delta);
                 TextStreamPart::ReasoningDelta {
                     id: rd.id,
                     provider_metadata: rd.provider_metadata,
@@ -169,11 +256,9 @@ async fn stream_single_step(
             }
             LanguageModelStreamPart::ReasoningEnd(re) => {
                 // Add accumulated reasoning to content
-                if !current_reasoning.is_empty() {
-                    step_content.push(Output::Reasoning(ReasoningOutput::new(
-                        current_reasoning.clone(),
-                    )));
-                    current_reasoning.clear();
+                if let Some(reasoning) = active_reasoning.end(&re.id, re.provider_metadata.clone())
+                {
+                    step_content.push(Output::Reasoning(reasoning));
                 }
                 TextStreamPart::ReasoningEnd {
                     id: re.id,
@@ -243,12 +328,7 @@ async fn stream_single_step(
                     step_content.push(Output::Text(TextOutput::new(current_text.clone())));
                     current_text.clear();
                 }
-                if !current_reasoning.is_empty() {
-                    step_content.push(Output::Reasoning(ReasoningOutput::new(
-                        current_reasoning.clone(),
-                    )));
-                    current_reasoning.clear();
-                }
+                step_content.extend(active_reasoning.finish().into_iter().map(Output::Reasoning));
 
                 step_usage = f.usage;
                 step_finish_reason = f.finish_reason.clone();
@@ -284,10 +364,16 @@ async fn stream_single_step(
                 )));
             }
             LanguageModelStreamPart::ToolCall(provider_tool_call) => {
-                // Parse the tool call using parse_tool_call
-
+                // Parse the tool call, repairing it if possible
                 let typed_tool_call = if let Some(tool_set) = tools {
-                    parse_tool_call(&provider_tool_call, tool_set)
+                    parse_tool_call_with_repair(
+                        &provider_tool_call,
+                        tool_set,
+                        repair_tool_call,
+                        step_system,
+                        step_messages,
+                    )
+                    .await
                 } else {
                     // No tools provided, treat as dynamic
                     parse_provider_executed_dynamic_tool_call(&provider_tool_call)
@@ -371,6 +457,9 @@ async fn stream_single_step(
         }
     }
 
+    model_span.record_finish(&step_finish_reason, &step_usage);
+    model_span.record_output(&step_content);
+
     Ok(SingleStepStreamResult {
         content: step_content,
         tool_calls: step_tool_calls,
@@ -416,6 +505,8 @@ pub struct StreamText {
     model: Arc<dyn LanguageModel>,
     prompt: Prompt,
     settings: CallSettings,
+    retry_policy: Option<Arc<dyn RetryPolicy>>,
+    downloader: Option<Arc<dyn Downloader>>,
     tools: Option<ToolSet>,
     tool_choice: Option<LanguageModelToolChoice>,
     response_format:
@@ -423,18 +514,25 @@ pub struct StreamText {
     provider_options: Option<SharedProviderOptions>,
     stop_when: Option<Vec<Box<dyn StopCondition>>>,
     prepare_step: Option<Box<dyn PrepareStep>>,
+    repair_tool_call: Option<ToolCallRepairFunction>,
+    max_parallel_tool_calls: Option<usize>,
     include_raw_chunks: bool,
     transforms: Option<Vec<Box<dyn StreamTransform>>>,
     on_chunk: Option<OnChunkCallback>,
     on_error: Option<OnErrorCallback>,
     on_step_finish: Option<OnStepFinishCallback>,
     on_finish: Option<OnFinishCallback>,
+    telemetry: TelemetrySettings,
     #[cfg(feature = "storage")]
     storage: Option<Arc<dyn llm_kit_storage::Storage>>,
     #[cfg(feature = "storage")]
     session_id: Option<String>,
     #[cfg(feature = "storage")]
     load_history: bool,
+    #[cfg(feature = "storage")]
+    history_strategy: Option<Arc<dyn HistoryStrategy>>,
+    #[cfg(feature = "storage")]
+    stream_id: Option<String>,
 }
 
 impl StreamText {
@@ -444,24 +542,33 @@ impl StreamText {
             model,
             prompt,
             settings: CallSettings::default(),
+            retry_policy: None,
+            downloader: None,
             tools: None,
             tool_choice: None,
             response_format: None,
             provider_options: None,
             stop_when: None,
             prepare_step: None,
+            repair_tool_call: None,
+            max_parallel_tool_calls: None,
             include_raw_chunks: false,
             transforms: None,
             on_chunk: None,
             on_error: None,
             on_step_finish: None,
             on_finish: None,
+            telemetry: TelemetrySettings::default(),
             #[cfg(feature = "storage")]
             storage: None,
             #[cfg(feature = "storage")]
             session_id: None,
             #[cfg(feature = "storage")]
             load_history: true, // Default to true for automatic history loading
+            #[cfg(feature = "storage")]
+            history_strategy: None,
+            #[cfg(feature = "storage")]
+            stream_id: None,
         }
     }
 
@@ -525,6 +632,22 @@ impl StreamText {
         self
     }
 
+    /// Sets the policy that decides whether and when failed model calls are retried.
+    ///
+    /// Defaults to [`DefaultRetryPolicy`](crate::generate_text::DefaultRetryPolicy).
+    pub fn retry_policy(mut self, retry_policy: Arc<dyn RetryPolicy>) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Sets the downloader for prompt URLs the model doesn't support.
+    ///
+    /// Defaults to [`DefaultDownloader`](crate::prompt::download::DefaultDownloader).
+    pub fn downloader(mut self, downloader: Arc<dyn Downloader>) -> Self {
+        self.downloader = Some(downloader);
+        self
+    }
+
     /// Sets custom headers for the request.
     pub fn headers(mut self, headers: std::collections::HashMap<String, String>) -> Self {
         self.settings = self.settings.with_headers(headers);
@@ -576,6 +699,25 @@ impl StreamText {
         self
     }
 
+    /// Sets a function that attempts to repair tool calls that fail to parse.
+    ///
+    /// The repair function is called when a tool call references an unknown tool
+    /// or has input that does not match the tool's schema.
+    pub fn experimental_repair_tool_call(mut self, repair: ToolCallRepairFunction) -> Self {
+        self.repair_tool_call = Some(repair);
+        self
+    }
+
+    /// Sets the maximum number of tool calls of a step that run concurrently.
+    ///
+    /// Tool calls run concurrently by default. Their outputs keep the order of the calls.
+    /// Tools marked as [`sequential`](llm_kit_provider_utils::tool::Tool::sequential)
+    /// always run on their own.
+    pub fn max_parallel_tool_calls(mut self, max_parallel_tool_calls: usize) -> Self {
+        self.max_parallel_tool_calls = Some(max_parallel_tool_calls);
+        self
+    }
+
     /// Enables or disables inclusion of raw chunks from the provider.
     pub fn include_raw_chunks(mut self, include: bool) -> Self {
         self.include_raw_chunks = include;
@@ -612,6 +754,15 @@ impl StreamText {
         self
     }
 
+    /// Sets the telemetry settings for the call.
+    ///
+    /// Spans are only emitted when the `telemetry` feature is enabled and the
+    /// settings are enabled. Telemetry is disabled by default.
+    pub fn telemetry(mut self, telemetry: TelemetrySettings) -> Self {
+        self.telemetry = telemetry;
+        self
+    }
+
     /// Enable storage for conversation persistence.
     ///
     /// When storage is configured with a session ID, the system will:
@@ -682,14 +833,77 @@ impl StreamText {
         self
     }
 
+    /// Sets the strategy that selects which part of the loaded history is sent.
+    ///
+    /// By default the whole history is sent. Use a strategy such as
+    /// [`LastMessages`](crate::history::LastMessages) or
+    /// [`TokenBudget`](crate::history::TokenBudget) to keep long sessions within the
+    /// model's context window. Errors of the strategy fail the call.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let result = StreamText::new(model, prompt)
+    ///     .with_storage(storage)
+    ///     .with_session_id(session_id)
+    ///     .with_history_strategy(Arc::new(TokenBudget::new(8_000)))
+    ///     .execute()
+    ///     .await?;
+    /// ```
+    #[cfg(feature = "storage")]
+    pub fn with_history_strategy(mut self, strategy: Arc<dyn HistoryStrategy>) -> Self {
+        self.history_strategy = Some(strategy);
+        self
+    }
+
+    /// Persist the stream under the given ID, so clients can resume it.
+    ///
+    /// Requires storage and a session ID. Every stream part is stored as it is
+    /// produced, and [`resume_stream`] replays them for a reconnecting client. The
+    /// stream keeps running when the client disconnects. If it ends without
+    /// finishing, e.g. on an error, it is marked as interrupted and what it produced
+    /// is stored as an incomplete assistant message. The stored messages carry the
+    /// stream ID in their metadata.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let result = StreamText::new(model, prompt)
+    ///     .with_storage(storage.clone())
+    ///     .with_session_id(session_id)
+    ///     .with_stream_id("str_123")
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// // After a reconnect
+    /// let result = resume_stream(storage, "str_123").await?;
+    /// ```
+    #[cfg(feature = "storage")]
+    pub fn with_stream_id(mut self, stream_id: impl Into<String>) -> Self {
+        self.stream_id = Some(stream_id.into());
+        self
+    }
+
     /// Executes the text streaming with the configured settings.
     pub async fn execute(self) -> Result<StreamTextResult, AISDKError> {
+        let json_output = matches!(
+            self.response_format,
+            Some(LanguageModelResponseFormat::Json { .. })
+        );
+
         // Initialize stop conditions with default if not provided
         let stop_conditions = Arc::new(
             self.stop_when
                 .unwrap_or_else(|| vec![Box::new(crate::generate_text::step_count_is(1))]),
         );
 
+        // Prepare retries
+        let retry_config = prepare_retries(
+            self.settings.max_retries,
+            self.settings.abort_signal.clone(),
+            self.retry_policy,
+        )?;
+
         // Prepare and validate call settings
         let prepared_settings = prepare_call_settings(&self.settings)?;
 
@@ -726,6 +940,10 @@ impl StreamText {
             if self.load_history {
                 match load_conversation_history(storage, session_id).await {
                     Ok(history) => {
+                        let history = match &self.history_strategy {
+                            Some(strategy) => strategy.apply(history).await?,
+                            None => history,
+                        };
                         if !history.is_empty() {
                             crate::prompt::standardize::StandardizedPrompt {
                                 messages: history,
@@ -766,20 +984,38 @@ impl StreamText {
         let standardized_prompt_arc = Arc::new(standardized_prompt);
         let provider_options_arc = self.provider_options.map(Arc::new);
         let prepare_step_arc = self.prepare_step.map(Arc::new);
+        let repair_tool_call_arc = self.repair_tool_call.map(Arc::new);
         let model_arc = self.model; // model is already Arc<dyn LanguageModel>
+        let downloader = self.downloader;
         let stop_conditions_arc = stop_conditions;
         let include_raw_chunks = self.include_raw_chunks;
+        let max_parallel_tool_calls = self.max_parallel_tool_calls;
+        #[cfg(feature = "storage")]
+        let stream_storage = match (&self.storage, &self.session_id, self.stream_id) {
+            (Some(storage), Some(session_id), Some(stream_id)) => {
+                Some((Arc::clone(storage), session_id.clone(), stream_id))
+            }
+            _ => None,
+        };
+        #[cfg(feature = "storage")]
+        let stream_id_arc = stream_storage
+            .as_ref()
+            .map(|(_, _, stream_id)| stream_id.clone());
         #[cfg(feature = "storage")]
         let storage_arc = self.storage;
         #[cfg(feature = "storage")]
         let session_id_arc = self.session_id;
 
         // Spawn a task to handle the multi-step streaming
+        let span = TelemetrySpan::stream_text(model_arc.as_ref(), &self.telemetry);
         let tx_clone = tx.clone();
-        tokio::spawn(async move {
+        tokio::spawn(span.clone().instrument(async move {
             // Emit Start event
             let _ = tx_clone.send(TextStreamPart::Start);
 
+            // Downloads of prompt URLs the model doesn't support, shared by all steps
+            let mut downloads = PromptDownloads::new(model_arc.as_ref(), downloader).await;
+
             // Accumulate all steps
             let mut all_steps: Vec<StepResult> = Vec::new();
             let mut total_usage = LanguageModelUsage::default();
@@ -791,6 +1027,8 @@ impl StreamText {
 
             // Multi-step loop
             loop {
+                let step_span = span.step(all_steps.len());
+
                 for response_msg in &response_messages {
                     let model_msg = match response_msg {
                         ResponseMessage::Assistant(msg) => Message::Assistant(msg.clone()),
@@ -832,11 +1070,15 @@ impl StreamText {
                     .as_ref()
                     .and_then(|r| r.active_tools.clone());
 
-                // Convert current messages to language model format
-                let messages = match convert_to_language_model_prompt(StandardizedPrompt {
-                    messages: step_messages.clone(),
-                    system: step_system,
-                }) {
+                // Convert current messages to language model format, inlining the URLs
+                // the model doesn't support
+                let step_prompt = downloads
+                    .inline(StandardizedPrompt {
+                        messages: step_messages.clone(),
+                        system: step_system.clone(),
+                    })
+                    .await;
+                let messages = match step_prompt.and_then(convert_to_language_model_prompt) {
                     Ok(m) => m,
                     Err(e) => {
                         let _ = tx_clone.send(TextStreamPart::Error {
@@ -926,17 +1168,23 @@ impl StreamText {
                 // Stream this single step
                 let step_result = match stream_single_step(
                     model_arc.clone(),
+                    &retry_config,
                     call_options,
                     tools_for_task.as_ref().map(|arc| arc.as_ref()),
+                    repair_tool_call_arc.as_ref().map(|arc| arc.as_ref()),
+                    step_system.as_deref(),
+                    &step_input_messages,
                     include_raw_chunks,
                     &tx_clone,
                     on_chunk_arc.as_ref(),
                     on_error_arc.as_ref(),
+                    &step_span,
                 )
                 .await
                 {
                     Ok(r) => r,
                     Err(e) => {
+                        span.record_error(&e);
                         if let Some(callback) = on_error_arc.as_ref() {
                             let event = callbacks::StreamTextErrorEvent {
                                 error: serde_json::json!({ "message": e.to_string() }),
@@ -967,36 +1215,36 @@ impl StreamText {
                     .iter()
                     .filter(|tool_call| tool_call.provider_executed != Some(true))
                     .collect();
+                step_span.record_tool_calls(&step_result.tool_calls);
 
-                // Execute client tool calls
+                // Execute client tool calls and emit each output to the stream as soon as
+                // it is available, in the order of the tool calls
                 let mut client_tool_outputs = Vec::new();
                 if let Some(tool_set) = tools_for_task.as_ref() {
-                    for &tool_call in &client_tool_calls {
-                        if let Some(output) = execute_tool_call(
-                            tool_call.clone(),
-                            tool_set,
-                            step_messages.clone(),
-                            abort_signal_for_tools.clone(),
-                            None, // experimental_context
-                            None, // on_preliminary_tool_result
-                        )
-                        .await
-                        {
-                            // Emit tool result to the stream
-                            match &output {
-                                llm_kit_provider_utils::tool::ToolOutput::Result(result) => {
-                                    let _ = tx_clone.send(TextStreamPart::ToolResult {
-                                        tool_result: result.clone(),
-                                    });
-                                }
-                                llm_kit_provider_utils::tool::ToolOutput::Error(error) => {
-                                    let _ = tx_clone.send(TextStreamPart::ToolError {
-                                        tool_error: error.clone(),
-                                    });
-                                }
+                    use futures_util::StreamExt;
+
+                    let mut outputs = std::pin::pin!(execute_tools_stream(
+                        &client_tool_calls,
+                        tool_set,
+                        &step_messages,
+                        abort_signal_for_tools.clone(),
+                        max_parallel_tool_calls,
+                        &step_span,
+                    ));
+                    while let Some(output) = outputs.next().await {
+                        match &output {
+                            llm_kit_provider_utils::tool::ToolOutput::Result(result) => {
+                                let _ = tx_clone.send(TextStreamPart::ToolResult {
+                                    tool_result: result.clone(),
+                                });
+                            }
+                            llm_kit_provider_utils::tool::ToolOutput::Error(error) => {
+                                let _ = tx_clone.send(TextStreamPart::ToolError {
+                                    tool_error: error.clone(),
+                                });
                             }
-                            client_tool_outputs.push(output);
                         }
+                        client_tool_outputs.push(output);
                     }
                 }
 
@@ -1026,6 +1274,7 @@ impl StreamText {
                     step_result.provider_metadata.clone(),
                 );
 
+                step_span.record_finish(&step_result.finish_reason, &step_result.usage);
                 all_steps.push(current_step_result.clone());
 
                 // Call on_step_finish callback
@@ -1054,6 +1303,8 @@ impl StreamText {
 
             // Emit final Finish event
             if let Some(last_step) = all_steps.last() {
+                span.record_finish(&last_step.finish_reason, &total_usage);
+                span.record_output_text(&last_step.text());
                 let _ = tx_clone.send(TextStreamPart::Finish {
                     finish_reason: last_step.finish_reason.clone(),
                     total_usage,
@@ -1077,28 +1328,39 @@ impl StreamText {
             if let (Some(storage), Some(session_id)) = (&storage_arc, &session_id_arc)
                 && !all_steps.is_empty()
             {
-                use crate::storage_conversion::response_messages_to_storage;
+                use crate::storage_conversion::response_messages_to_storage_with_model;
 
                 // Build a result from the stream for metadata
                 let stream_result =
                     crate::generate_text::GenerateTextResult::from_steps(all_steps, total_usage);
 
                 // Convert all response messages (assistant + tool) to storage format
-                let storage_messages = response_messages_to_storage(
+                let storage_messages = response_messages_to_storage_with_model(
                     storage,
                     session_id.clone(),
                     &response_messages,
                     &stream_result,
+                    model_arc.as_ref(),
                 );
 
                 // Store each message
-                for (storage_msg, parts) in storage_messages {
+                for (mut storage_msg, parts) in storage_messages {
+                    storage_msg.metadata.stream_id = stream_id_arc.clone();
                     if let Err(e) = storage.store_assistant_message(&storage_msg, &parts).await {
                         log::warn!("Failed to store response message: {}", e);
                     }
                 }
             }
-        });
+        }));
+
+        // Persist the stream parts if a stream ID is configured
+        #[cfg(feature = "storage")]
+        let rx = match stream_storage {
+            Some((storage, session_id, stream_id)) => {
+                resume::persist_stream(storage, session_id, stream_id, rx).await
+            }
+            None => rx,
+        };
 
         // Step 7: Create an AsyncIterableStream from the receiver
         let mut stream: Pin<Box<dyn futures_util::Stream<Item = TextStreamPart> + Send>> =
@@ -1118,6 +1380,11 @@ impl StreamText {
         }
 
         // Step 9: Create and return StreamTextResult
-        Ok(StreamTextResult::new(stream))
+        let result = StreamTextResult::new(stream);
+        if json_output {
+            Ok(result.with_json_output())
+        } else {
+            Ok(result)
+        }
     }
 }
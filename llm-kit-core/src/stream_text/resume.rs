@@ -0,0 +1,583 @@
+use super::{StreamTextResult, TextStreamPart};
+use chrono::Utc;
+use llm_kit_storage::{
+    AssistantMessage, MessageMetadata, MessagePart, ReasoningPart, Storage, StorageError,
+    StoredStream, StreamStatus, TextPart,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::Duration;
+use tokio::sync::{Notify, mpsc};
+
+/// How long an active stream is considered live after its last heartbeat.
+const STREAM_LEASE: Duration = Duration::from_secs(30);
+
+/// How often the producer of a stream refreshes its heartbeat.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How often a stream produced by another process is polled for new chunks.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Streams produced by this process, with a notification for new chunks.
+static LIVE_STREAMS: LazyLock<Mutex<HashMap<String, Arc<Notify>>>> =
+    LazyLock::new(Default::default);
+
+/// Returns the notification of a stream if it is produced by this process.
+fn live_stream(stream_id: &str) -> Option<Arc<Notify>> {
+    LIVE_STREAMS
+        .lock()
+        .ok()
+        .and_then(|streams| streams.get(stream_id).cloned())
+}
+
+/// Whether the lease of an active stream expired, i.e. its producer is gone.
+fn lease_expired(stream: &StoredStream) -> bool {
+    // A heartbeat in the future (clock skew) counts as live
+    Utc::now()
+        .signed_duration_since(stream.updated_at)
+        .to_std()
+        .is_ok_and(|age| age > STREAM_LEASE)
+}
+
+/// Persists the parts of a stream under the stream ID while forwarding them.
+///
+/// Returns the receiver of the forwarded parts. While the stream is produced, its
+/// heartbeat is refreshed so that resumers in other processes know it is live. When
+/// the stream ends with a [`TextStreamPart::Finish`] it is marked as completed; the
+/// producer has stored its messages by then. Otherwise it is marked as interrupted
+/// and its partial output is stored as an incomplete message.
+pub(crate) async fn persist_stream(
+    storage: Arc<dyn Storage>,
+    session_id: String,
+    stream_id: String,
+    mut rx: mpsc::UnboundedReceiver<TextStreamPart>,
+) -> mpsc::UnboundedReceiver<TextStreamPart> {
+    let stream = StoredStream::new(stream_id.clone(), session_id);
+    if let Err(e) = storage.store_stream(&stream).await {
+        log::warn!("Failed to store stream: {}", e);
+        return rx;
+    }
+
+    let notify = Arc::new(Notify::new());
+    if let Ok(mut streams) = LIVE_STREAMS.lock() {
+        streams.insert(stream_id.clone(), Arc::clone(&notify));
+    }
+
+    let (tx, forwarded) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        let mut finished = false;
+        // Cleared if a resumer took the stream over after a missed heartbeat
+        let mut owned = true;
+        let mut heartbeat = tokio::time::interval(HEARTBEAT_INTERVAL);
+        heartbeat.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        heartbeat.tick().await;
+
+        loop {
+            let part = tokio::select! {
+                part = rx.recv() => part,
+                _ = heartbeat.tick(), if owned => {
+                    owned = refresh_stream(storage.as_ref(), &stream_id).await;
+                    continue;
+                }
+            };
+            let Some(part) = part else { break };
+
+            // Store the parts that are ready in one batch
+            let mut parts = vec![part];
+            while let Ok(part) = rx.try_recv() {
+                parts.push(part);
+            }
+            finished |= parts
+                .iter()
+                .any(|part| matches!(part, TextStreamPart::Finish { .. }));
+
+            if owned {
+                let chunks: Vec<serde_json::Value> = parts
+                    .iter()
+                    .filter_map(|part| serde_json::to_value(part).ok())
+                    .collect();
+                if let Err(e) = storage.append_stream_chunks(&stream_id, &chunks).await {
+                    log::warn!("Failed to store stream chunks: {}", e);
+                }
+                notify.notify_waiters();
+            }
+
+            // The client may have disconnected; keep producing for a resuming client
+            for part in parts {
+                let _ = tx.send(part);
+            }
+        }
+
+        if owned {
+            let result = if finished {
+                storage
+                    .update_stream_status(&stream_id, StreamStatus::Active, StreamStatus::Completed)
+                    .await
+                    .map(|_| ())
+            } else {
+                interrupt_stream(storage.as_ref(), &stream_id).await
+            };
+            if let Err(e) = result {
+                log::warn!("Failed to finish stream: {}", e);
+            }
+        }
+
+        if let Ok(mut streams) = LIVE_STREAMS.lock() {
+            streams.remove(&stream_id);
+        }
+        notify.notify_waiters();
+    });
+
+    forwarded
+}
+
+/// Refreshes the heartbeat of an active stream.
+///
+/// Returns `false` if the stream is no longer active, i.e. a resumer marked it as
+/// interrupted.
+async fn refresh_stream(storage: &dyn Storage, stream_id: &str) -> bool {
+    match storage
+        .update_stream_status(stream_id, StreamStatus::Active, StreamStatus::Active)
+        .await
+    {
+        Ok(true) => true,
+        Ok(false) => {
+            log::warn!("Stream {} was interrupted while it was produced", stream_id);
+            false
+        }
+        Err(e) => {
+            log::warn!("Failed to refresh stream: {}", e);
+            true
+        }
+    }
+}
+
+/// Resume a stream persisted by [`StreamText`](crate::StreamText) with a stream ID.
+///
+/// The returned stream replays every part the stream produced so far, then follows
+/// the stream until it ends: streams produced by this process are followed as their
+/// chunks are stored, streams produced by another process by polling the storage.
+///
+/// The producer of a stream refreshes its heartbeat while it runs. A stream that is
+/// still marked as active but whose heartbeat is older than 30 seconds, e.g.
+/// because the process that produced it crashed, is marked as interrupted: what it
+/// produced is stored as an assistant message with [`MessageMetadata::incomplete`]
+/// set. If several clients resume such a stream at once, only one of them stores
+/// the message. The replay of an interrupted stream ends with
+/// [`TextStreamPart::Abort`].
+///
+/// # Errors
+///
+/// Returns `StorageError::NotFound` if the stream doesn't exist.
+///
+/// # Example
+///
+/// ```ignore
+/// use llm_kit_core::stream_text::resume_stream;
+///
+/// // A client reconnects with the stream ID it was given
+/// let result = resume_stream(storage, "str_123").await?;
+/// let text = result.text().await?;
+/// ```
+pub async fn resume_stream(
+    storage: Arc<dyn Storage>,
+    stream_id: &str,
+) -> Result<StreamTextResult, StorageError> {
+    storage.get_stream(stream_id).await?;
+
+    let stream_id = stream_id.to_string();
+    let parts = async_stream::stream! {
+        let mut offset = 0;
+        let mut aborted = false;
+
+        loop {
+            // Listen before reading, so no notification between the reads is missed
+            let live = live_stream(&stream_id);
+            let mut notified = std::pin::pin!(live.as_ref().map(|notify| notify.notified()));
+            if let Some(notified) = notified.as_mut().as_pin_mut() {
+                notified.enable();
+            }
+
+            // All chunks are stored before the status changes
+            let stream = match storage.get_stream(&stream_id).await {
+                Ok(stream) => stream,
+                Err(e) => {
+                    yield TextStreamPart::Error {
+                        error: serde_json::json!({ "message": e.to_string() }),
+                    };
+                    break;
+                }
+            };
+            let chunks = match storage.get_stream_chunks(&stream_id, offset).await {
+                Ok(chunks) => chunks,
+                Err(e) => {
+                    yield TextStreamPart::Error {
+                        error: serde_json::json!({ "message": e.to_string() }),
+                    };
+                    break;
+                }
+            };
+            offset += chunks.len();
+            for chunk in chunks {
+                if let Ok(part) = serde_json::from_value::<TextStreamPart>(chunk) {
+                    aborted = matches!(part, TextStreamPart::Abort);
+                    yield part;
+                }
+            }
+
+            match stream.status {
+                StreamStatus::Completed => break,
+                StreamStatus::Interrupted => {
+                    if !aborted {
+                        yield TextStreamPart::Abort;
+                    }
+                    break;
+                }
+                StreamStatus::Active => {}
+            }
+            match notified.as_mut().as_pin_mut() {
+                Some(notified) => notified.await,
+                None if lease_expired(&stream) => {
+                    // The producer is gone without finishing the stream
+                    if let Err(e) = interrupt_stream(storage.as_ref(), &stream_id).await {
+                        log::warn!("Failed to interrupt stream: {}", e);
+                        break;
+                    }
+                }
+                None => tokio::time::sleep(POLL_INTERVAL).await,
+            }
+        }
+    };
+
+    Ok(StreamTextResult::new(Box::pin(parts)))
+}
+
+/// Marks an active stream as interrupted, storing its partial output as an
+/// incomplete message.
+///
+/// Does nothing if the stream is no longer active, so that only one caller stores
+/// the message.
+async fn interrupt_stream(storage: &dyn Storage, stream_id: &str) -> Result<(), StorageError> {
+    if !storage
+        .update_stream_status(stream_id, StreamStatus::Active, StreamStatus::Interrupted)
+        .await?
+    {
+        return Ok(());
+    }
+
+    let stream = storage.get_stream(stream_id).await?;
+    let parts: Vec<TextStreamPart> = storage
+        .get_stream_chunks(stream_id, 0)
+        .await?
+        .into_iter()
+        .filter_map(|chunk| serde_json::from_value(chunk).ok())
+        .collect();
+
+    let message_parts = partial_message_parts(storage, &parts);
+    if message_parts.is_empty() {
+        return Ok(());
+    }
+    let part_ids = message_parts
+        .iter()
+        .map(|part| part.id().to_string())
+        .collect();
+    let message = AssistantMessage::new(
+        storage.generate_message_id(),
+        stream.session_id.clone(),
+        part_ids,
+    )
+    .with_metadata(MessageMetadata {
+        stream_id: Some(stream.id.clone()),
+        incomplete: true,
+        ..Default::default()
+    });
+    storage
+        .store_assistant_message(&message, &message_parts)
+        .await
+}
+
+/// Collects the text and reasoning of stream parts into message parts, in order.
+fn partial_message_parts(storage: &dyn Storage, parts: &[TextStreamPart]) -> Vec<MessagePart> {
+    // (is reasoning, segment ID, content) of each text and reasoning segment
+    let mut segments: Vec<(bool, &str, String)> = Vec::new();
+
+    for part in parts {
+        // Providers may reuse segment IDs across steps, so a start opens a new segment
+        let (reasoning, id, text) = match part {
+            TextStreamPart::TextStart { id, .. } => {
+                segments.push((false, id, String::new()));
+                continue;
+            }
+            TextStreamPart::ReasoningStart { id, .. } => {
+                segments.push((true, id, String::new()));
+                continue;
+            }
+            TextStreamPart::TextDelta { id, text, .. } => (false, id, text),
+            TextStreamPart::ReasoningDelta { id, text, .. } => (true, id, text),
+            _ => continue,
+        };
+
+        match segments
+            .iter_mut()
+            .rev()
+            .find(|segment| segment.0 == reasoning && segment.1 == id)
+        {
+            Some(segment) => segment.2.push_str(text),
+            None => segments.push((reasoning, id, text.clone())),
+        }
+    }
+
+    segments
+        .into_iter()
+        .filter(|(_, _, content)| !content.is_empty())
+        .map(|(reasoning, _, content)| {
+            if reasoning {
+                MessagePart::Reasoning(ReasoningPart::new(storage.generate_part_id(), content))
+            } else {
+                MessagePart::Text(TextPart::new(storage.generate_part_id(), content))
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason;
+    use llm_kit_provider::language_model::usage::LanguageModelUsage;
+    use llm_kit_storage::{InMemoryStorage, Session};
+
+    fn text_start(id: &str) -> TextStreamPart {
+        TextStreamPart::TextStart {
+            id: id.to_string(),
+            provider_metadata: None,
+        }
+    }
+
+    fn text_delta(id: &str, text: &str) -> TextStreamPart {
+        TextStreamPart::TextDelta {
+            id: id.to_string(),
+            provider_metadata: None,
+            text: text.to_string(),
+        }
+    }
+
+    fn reasoning_delta(id: &str, text: &str) -> TextStreamPart {
+        TextStreamPart::ReasoningDelta {
+            id: id.to_string(),
+            provider_metadata: None,
+            text: text.to_string(),
+        }
+    }
+
+    /// Stores the stream `str_1` with the given parts, produced by another process
+    /// whose last heartbeat was `heartbeat_age` ago.
+    async fn setup_stream(
+        parts: &[TextStreamPart],
+        heartbeat_age: Duration,
+    ) -> (Arc<dyn Storage>, String) {
+        let storage: Arc<dyn Storage> = Arc::new(InMemoryStorage::new());
+        let session_id = storage.generate_session_id();
+        storage
+            .store_session(&Session::new(session_id.clone()))
+            .await
+            .unwrap();
+        let mut stream = StoredStream::new("str_1".to_string(), session_id.clone());
+        stream.updated_at -= chrono::Duration::from_std(heartbeat_age).unwrap();
+        storage.store_stream(&stream).await.unwrap();
+        let chunks: Vec<serde_json::Value> = parts
+            .iter()
+            .map(|part| serde_json::to_value(part).unwrap())
+            .collect();
+        storage
+            .append_stream_chunks("str_1", &chunks)
+            .await
+            .unwrap();
+        (storage, session_id)
+    }
+
+    #[test]
+    fn test_partial_message_parts_splits_segments_at_starts() {
+        let storage = InMemoryStorage::new();
+        let parts = vec![
+            reasoning_delta("r1", "Thinking"),
+            text_start("0"),
+            text_delta("0", "Hello"),
+            text_delta("0", " world"),
+            text_start("1"),
+            text_start("0"),
+            text_delta("0", "Again"),
+        ];
+
+        let message_parts = partial_message_parts(&storage, &parts);
+
+        assert_eq!(message_parts.len(), 3);
+        assert!(
+            matches!(&message_parts[0], MessagePart::Reasoning(part) if part.content == "Thinking")
+        );
+        assert!(matches!(&message_parts[1], MessagePart::Text(part) if part.text == "Hello world"));
+        assert!(matches!(&message_parts[2], MessagePart::Text(part) if part.text == "Again"));
+    }
+
+    #[tokio::test]
+    async fn test_resume_interrupts_orphaned_stream() {
+        let (storage, session_id) = setup_stream(
+            &[text_start("0"), text_delta("0", "Partial")],
+            STREAM_LEASE * 2,
+        )
+        .await;
+
+        // Clients resuming at once both see the interruption
+        let (first, second) = tokio::join!(
+            resume_stream(Arc::clone(&storage), "str_1"),
+            resume_stream(Arc::clone(&storage), "str_1"),
+        );
+        let (parts, other_parts): (Vec<TextStreamPart>, Vec<TextStreamPart>) = tokio::join!(
+            first.unwrap().full_stream().collect(),
+            second.unwrap().full_stream().collect(),
+        );
+
+        assert_eq!(parts.len(), 3);
+        assert!(matches!(parts[2], TextStreamPart::Abort));
+        assert_eq!(other_parts.len(), 3);
+        assert!(matches!(other_parts[2], TextStreamPart::Abort));
+        let stream = storage.get_stream("str_1").await.unwrap();
+        assert_eq!(stream.status, StreamStatus::Interrupted);
+
+        let message_ids = storage.list_messages(&session_id, None).await.unwrap();
+        assert_eq!(message_ids.len(), 1);
+        let (_, message_parts, metadata) = storage
+            .get_message_with_metadata(&session_id, &message_ids[0])
+            .await
+            .unwrap();
+        let metadata = metadata.unwrap();
+        assert!(metadata.incomplete);
+        assert_eq!(metadata.stream_id.as_deref(), Some("str_1"));
+        assert!(matches!(&message_parts[0], MessagePart::Text(part) if part.text == "Partial"));
+
+        // Only one of them stored the incomplete message, and resuming again
+        // replays the interrupted stream without storing another one
+        let result = resume_stream(Arc::clone(&storage), "str_1").await.unwrap();
+        assert_eq!(result.full_stream().count().await, 3);
+        assert_eq!(
+            storage
+                .list_messages(&session_id, None)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_persist_and_resume_completed_stream() {
+        let (storage, session_id) = setup_stream(&[], STREAM_LEASE * 2).await;
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut forwarded =
+            persist_stream(Arc::clone(&storage), session_id, "str_2".to_string(), rx).await;
+
+        tx.send(text_start("0")).unwrap();
+        tx.send(text_delta("0", "Done")).unwrap();
+        tx.send(TextStreamPart::Finish {
+            finish_reason: LanguageModelFinishReason::Stop,
+            total_usage: LanguageModelUsage::new(10, 5),
+        })
+        .unwrap();
+        drop(tx);
+
+        let mut count = 0;
+        while forwarded.recv().await.is_some() {
+            count += 1;
+        }
+        assert_eq!(count, 3);
+
+        let result = resume_stream(Arc::clone(&storage), "str_2").await.unwrap();
+        assert_eq!(result.text().await.unwrap(), "Done");
+        let stream = storage.get_stream("str_2").await.unwrap();
+        assert_eq!(stream.status, StreamStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_resume_follows_live_stream() {
+        let (storage, session_id) = setup_stream(&[], STREAM_LEASE * 2).await;
+        let (tx, rx) = mpsc::unbounded_channel();
+        let _forwarded = persist_stream(
+            Arc::clone(&storage),
+            session_id.clone(),
+            "str_3".to_string(),
+            rx,
+        )
+        .await;
+
+        tx.send(text_start("0")).unwrap();
+        tx.send(text_delta("0", "Hel")).unwrap();
+        let result = resume_stream(Arc::clone(&storage), "str_3").await.unwrap();
+
+        // The resumed stream follows the parts produced after it was resumed
+        let producer = async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            tx.send(text_delta("0", "lo")).unwrap();
+            tx.send(TextStreamPart::Finish {
+                finish_reason: LanguageModelFinishReason::Stop,
+                total_usage: LanguageModelUsage::new(10, 5),
+            })
+            .unwrap();
+        };
+        let (text, ()) = tokio::join!(result.text(), producer);
+
+        assert_eq!(text.unwrap(), "Hello");
+        let stream = storage.get_stream("str_3").await.unwrap();
+        assert_eq!(stream.status, StreamStatus::Completed);
+        assert!(
+            storage
+                .list_messages(&session_id, None)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resume_follows_stream_of_another_process() {
+        let (storage, session_id) =
+            setup_stream(&[text_start("0"), text_delta("0", "Hel")], Duration::ZERO).await;
+        let result = resume_stream(Arc::clone(&storage), "str_1").await.unwrap();
+
+        // The stream isn't produced by this process, but its heartbeat is recent
+        let producer = {
+            let storage = Arc::clone(&storage);
+            async move {
+                tokio::time::sleep(POLL_INTERVAL * 2).await;
+                let chunks = [
+                    text_delta("0", "lo"),
+                    TextStreamPart::Finish {
+                        finish_reason: LanguageModelFinishReason::Stop,
+                        total_usage: LanguageModelUsage::new(10, 5),
+                    },
+                ]
+                .map(|part| serde_json::to_value(part).unwrap());
+                storage
+                    .append_stream_chunks("str_1", &chunks)
+                    .await
+                    .unwrap();
+                storage
+                    .update_stream_status("str_1", StreamStatus::Active, StreamStatus::Completed)
+                    .await
+                    .unwrap();
+            }
+        };
+        let (text, ()) = tokio::join!(result.text(), producer);
+
+        assert_eq!(text.unwrap(), "Hello");
+        let stream = storage.get_stream("str_1").await.unwrap();
+        assert_eq!(stream.status, StreamStatus::Completed);
+        assert!(
+            storage
+                .list_messages(&session_id, None)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -1,7 +1,10 @@
-use crate::error::AISDKError;
+use crate::error::{AISDKError, NoObjectGeneratedErrorBuilder};
 use crate::generate_text::{GeneratedFile, RequestMetadata, ResponseMetadata, StepResult};
 use crate::output::{Output, ReasoningOutput, TextOutput};
 use crate::stream_text::TextStreamPart;
+use crate::ui_message_stream::{
+    UIMessageChunk, UIMessageStreamOptions, to_sse_stream, to_ui_message_stream,
+};
 use futures_util::StreamExt;
 use futures_util::stream::Stream;
 use llm_kit_provider::language_model::call_warning::LanguageModelCallWarning;
@@ -10,6 +13,7 @@ use llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason;
 use llm_kit_provider::language_model::usage::LanguageModelUsage;
 use llm_kit_provider::shared::provider_metadata::SharedProviderMetadata;
 use llm_kit_provider_utils::tool::{ToolCall, ToolResult};
+use serde_json::Value;
 use std::pin::Pin;
 use std::sync::{Arc, OnceLock};
 use tokio::sync::Mutex;
@@ -163,6 +167,9 @@ pub struct StreamTextResult {
 
     /// Whether the stream has been consumed
     consumed: Arc<Mutex<bool>>,
+
+    /// Whether the text is parsed as JSON output
+    json_output: bool,
 }
 
 impl StreamTextResult {
@@ -187,9 +194,16 @@ impl StreamTextResult {
             state: Arc::new(OnceLock::new()),
             full_stream: Arc::new(Mutex::new(Some(full_stream))),
             consumed: Arc::new(Mutex::new(false)),
+            json_output: false,
         }
     }
 
+    /// Parses the text of the last step as JSON in [`output`](Self::output).
+    pub(crate) fn with_json_output(mut self) -> Self {
+        self.json_output = true;
+        self
+    }
+
     /// Consumes the stream and populates the internal state.
     ///
     /// This method is called automatically by all Promise-like accessors.
@@ -239,36 +253,38 @@ impl StreamTextResult {
     ) -> Result<StreamState, AISDKError> {
         let mut state = StreamState::default();
         let mut current_text = String::new();
-        let mut current_reasoning = String::new();
+        let mut active_reasoning = super::ActiveReasoning::default();
 
         while let Some(part) = stream.next().await {
             match part {
                 TextStreamPart::TextDelta { text, .. } => {
                     current_text.push_str(&text);
                 }
-                TextStreamPart::TextEnd { .. } => {
-                    if !current_text.is_empty() {
-                        state.text.push_str(&current_text);
-                        // Also add to content
-                        state
-                            .content
-                            .push(Output::Text(TextOutput::new(current_text.clone())));
-                        current_text.clear();
-                    }
+                TextStreamPart::TextEnd { .. } if !current_text.is_empty() => {
+                    state.text.push_str(&current_text);
+                    // Also add to content
+                    state
+                        .content
+                        .push(Output::Text(TextOutput::new(current_text.clone())));
+                    current_text.clear();
+                }
+                TextStreamPart::ReasoningStart {
+                    id,
+                    provider_metadata,
+                } => {
+                    active_reasoning.start(id, provider_metadata);
                 }
-                TextStreamPart::ReasoningDelta { text, .. } => {
-                    current_reasoning.push_str(&text);
+                TextStreamPart::ReasoningDelta { id, text, .. } => {
+                    active_reasoning.delta(&id, &text);
                 }
-                TextStreamPart::ReasoningEnd { .. } => {
-                    if !current_reasoning.is_empty() {
-                        state
-                            .reasoning
-                            .push(ReasoningOutput::new(current_reasoning.clone()));
+                TextStreamPart::ReasoningEnd {
+                    id,
+                    provider_metadata,
+                } => {
+                    if let Some(reasoning) = active_reasoning.end(&id, provider_metadata) {
+                        state.reasoning.push(reasoning.clone());
                         // Also add to content
-                        state.content.push(Output::Reasoning(ReasoningOutput::new(
-                            current_reasoning.clone(),
-                        )));
-                        current_reasoning.clear();
+                        state.content.push(Output::Reasoning(reasoning));
                     }
                 }
                 TextStreamPart::Source { source } => {
@@ -338,13 +354,9 @@ impl StreamTextResult {
         }
 
         // Flush any remaining reasoning
-        if !current_reasoning.is_empty() {
-            state
-                .reasoning
-                .push(ReasoningOutput::new(current_reasoning.clone()));
-            state
-                .content
-                .push(Output::Reasoning(ReasoningOutput::new(current_reasoning)));
+        for reasoning in active_reasoning.finish() {
+            state.reasoning.push(reasoning.clone());
+            state.content.push(Output::Reasoning(reasoning));
         }
 
         // Finalize reasoning text
@@ -439,6 +451,30 @@ impl StreamTextResult {
         Ok(self.state.get().unwrap().tool_results.clone())
     }
 
+    /// Gets the structured output parsed from the text of the last step.
+    ///
+    /// Returns `None` unless a JSON response format was requested.
+    ///
+    /// Automatically consumes the stream.
+    pub async fn output(&self) -> Result<Option<Value>, AISDKError> {
+        self.ensure_consumed().await?;
+        if !self.json_output {
+            return Ok(None);
+        }
+
+        let state = self.state.get().unwrap();
+        serde_json::from_str(&state.text)
+            .map(Some)
+            .map_err(|error| {
+                NoObjectGeneratedErrorBuilder::new()
+                    .message(format!("Could not parse the response: {}", error))
+                    .text(state.text.clone())
+                    .usage(state.usage)
+                    .finish_reason(state.finish_reason.clone())
+                    .build()
+            })
+    }
+
     /// Gets the reason why the generation finished. Taken from the last step.
     ///
     /// Automatically consumes the stream.
@@ -545,6 +581,34 @@ impl StreamTextResult {
         })
     }
 
+    /// Gets the stream as UI message stream chunks.
+    ///
+    /// See [`ui_message_stream`](crate::ui_message_stream) for the protocol.
+    ///
+    /// Note: This consumes the full stream, so it cannot be called after other
+    /// consuming operations.
+    pub fn ui_message_stream(
+        &self,
+        options: UIMessageStreamOptions,
+    ) -> AsyncIterableStream<UIMessageChunk> {
+        to_ui_message_stream(self.full_stream(), options)
+    }
+
+    /// Gets the stream as UI message stream server-sent events.
+    ///
+    /// Each item is a complete event (`data: {...}\n\n`), ready to be written to an
+    /// HTTP response with the
+    /// [`UI_MESSAGE_STREAM_HEADERS`](crate::ui_message_stream::UI_MESSAGE_STREAM_HEADERS).
+    ///
+    /// Note: This consumes the full stream, so it cannot be called after other
+    /// consuming operations.
+    pub fn ui_message_sse_stream(
+        &self,
+        options: UIMessageStreamOptions,
+    ) -> AsyncIterableStream<String> {
+        to_sse_stream(self.ui_message_stream(options))
+    }
+
     /// Gets a stream of partial outputs parsed as JSON values.
     ///
     /// This streams partial JSON objects as they are being constructed from the text output.
@@ -655,6 +719,7 @@ impl Clone for StreamTextResult {
             state: Arc::clone(&self.state),
             full_stream: Arc::clone(&self.full_stream),
             consumed: Arc::clone(&self.consumed),
+            json_output: self.json_output,
         }
     }
 }
@@ -694,6 +759,48 @@ mod tests {
         assert_eq!(text, "Hello world");
     }
 
+    #[tokio::test]
+    async fn test_stream_text_result_output() {
+        let text_parts = |text: &str| {
+            vec![
+                TextStreamPart::TextStart {
+                    id: "text1".to_string(),
+                    provider_metadata: None,
+                },
+                TextStreamPart::TextDelta {
+                    id: "text1".to_string(),
+                    provider_metadata: None,
+                    text: text.to_string(),
+                },
+                TextStreamPart::TextEnd {
+                    id: "text1".to_string(),
+                    provider_metadata: None,
+                },
+            ]
+        };
+
+        let stream: AsyncIterableStream<TextStreamPart> =
+            Box::pin(stream::iter(text_parts(r#"{"city": "Paris"}"#)));
+        let result = StreamTextResult::new(stream).with_json_output();
+        assert_eq!(
+            result.output().await.unwrap(),
+            Some(serde_json::json!({ "city": "Paris" }))
+        );
+
+        let stream: AsyncIterableStream<TextStreamPart> =
+            Box::pin(stream::iter(text_parts("not json")));
+        let result = StreamTextResult::new(stream).with_json_output();
+        assert!(matches!(
+            result.output().await,
+            Err(AISDKError::NoObjectGenerated { .. })
+        ));
+
+        let stream: AsyncIterableStream<TextStreamPart> =
+            Box::pin(stream::iter(text_parts("Paris")));
+        let result = StreamTextResult::new(stream);
+        assert_eq!(result.output().await.unwrap(), None);
+    }
+
     #[tokio::test]
     async fn test_stream_text_result_reasoning() {
         let parts = vec![
@@ -0,0 +1,32 @@
+/// Settings that control which data is recorded in telemetry spans.
+pub mod settings;
+
+#[cfg(not(feature = "telemetry"))]
+mod noop_span;
+#[cfg(feature = "telemetry")]
+mod span;
+
+pub use settings::TelemetrySettings;
+
+#[cfg(not(feature = "telemetry"))]
+pub(crate) use noop_span::TelemetrySpan;
+#[cfg(feature = "telemetry")]
+pub(crate) use span::TelemetrySpan;
+
+/// Returns the finish reason as defined by the GenAI semantic conventions.
+#[cfg(feature = "telemetry")]
+fn finish_reason_name(
+    finish_reason: &llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason,
+) -> &'static str {
+    use llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason;
+
+    match finish_reason {
+        LanguageModelFinishReason::Stop => "stop",
+        LanguageModelFinishReason::Length => "length",
+        LanguageModelFinishReason::ContentFilter => "content_filter",
+        LanguageModelFinishReason::ToolCalls => "tool_calls",
+        LanguageModelFinishReason::Error => "error",
+        LanguageModelFinishReason::Other => "other",
+        LanguageModelFinishReason::Unknown => "unknown",
+    }
+}
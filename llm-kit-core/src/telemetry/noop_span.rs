@@ -0,0 +1,68 @@
+use super::TelemetrySettings;
+use crate::error::AISDKError;
+use llm_kit_provider::language_model::call_options::LanguageModelCallOptions;
+use llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason;
+use llm_kit_provider::language_model::usage::LanguageModelUsage;
+use llm_kit_provider::language_model::{LanguageModel, LanguageModelGenerateResponse};
+use llm_kit_provider_utils::tool::{ToolCall, ToolOutput};
+use serde::Serialize;
+use std::future::Future;
+
+/// No-op stand-in for the tracing span used when the `telemetry` feature is disabled.
+///
+/// It mirrors the API of the real span so call sites don't need `cfg` attributes.
+#[derive(Clone)]
+pub(crate) struct TelemetrySpan;
+
+impl TelemetrySpan {
+    pub(crate) fn generate_text(
+        _model: &dyn LanguageModel,
+        _telemetry: &TelemetrySettings,
+    ) -> Self {
+        Self
+    }
+
+    pub(crate) fn stream_text(_model: &dyn LanguageModel, _telemetry: &TelemetrySettings) -> Self {
+        Self
+    }
+
+    pub(crate) fn step(&self, _step_number: usize) -> Self {
+        Self
+    }
+
+    pub(crate) fn model_call(
+        &self,
+        _operation: &'static str,
+        _model: &dyn LanguageModel,
+        _options: &LanguageModelCallOptions,
+    ) -> Self {
+        Self
+    }
+
+    pub(crate) fn tool_call(&self, _tool_call: &ToolCall) -> Self {
+        Self
+    }
+
+    pub(crate) fn instrument<F: Future>(&self, future: F) -> F {
+        future
+    }
+
+    pub(crate) fn record_response(&self, _response: &LanguageModelGenerateResponse) {}
+
+    pub(crate) fn record_finish(
+        &self,
+        _finish_reason: &LanguageModelFinishReason,
+        _usage: &LanguageModelUsage,
+    ) {
+    }
+
+    pub(crate) fn record_output<T: Serialize + ?Sized>(&self, _output: &T) {}
+
+    pub(crate) fn record_output_text(&self, _text: &str) {}
+
+    pub(crate) fn record_tool_calls(&self, _tool_calls: &[ToolCall]) {}
+
+    pub(crate) fn record_tool_output(&self, _output: Option<&ToolOutput>) {}
+
+    pub(crate) fn record_error(&self, _error: &AISDKError) {}
+}
@@ -0,0 +1,115 @@
+/// Telemetry settings for a generation call.
+///
+/// Spans are only emitted when the `telemetry` feature is enabled. They follow the
+/// OpenTelemetry GenAI semantic conventions and can be exported with any `tracing`
+/// subscriber, for example `tracing-opentelemetry`.
+///
+/// Telemetry is opt-in: no spans are emitted unless the settings are enabled with
+/// [`with_enabled`](Self::with_enabled). Enabled spans record prompts, generated
+/// outputs, tool arguments and tool results. Use
+/// [`with_record_inputs`](Self::with_record_inputs) and
+/// [`with_record_outputs`](Self::with_record_outputs) to redact them.
+///
+/// # Example
+///
+/// ```
+/// use llm_kit_core::telemetry::TelemetrySettings;
+///
+/// let telemetry = TelemetrySettings::new()
+///     .with_enabled(true)
+///     .with_function_id("summarize-ticket")
+///     .with_record_inputs(false)
+///     .with_record_outputs(false);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TelemetrySettings {
+    /// Whether spans are emitted for the call.
+    ///
+    /// Default: false
+    pub is_enabled: bool,
+
+    /// Whether inputs such as prompt messages and tool arguments are recorded.
+    ///
+    /// Default: true
+    pub record_inputs: bool,
+
+    /// Whether outputs such as generated content and tool results are recorded.
+    ///
+    /// Default: true
+    pub record_outputs: bool,
+
+    /// Identifier of the calling function, recorded as `llm_kit.function_id`.
+    /// Useful to group the telemetry data of a call site.
+    pub function_id: Option<String>,
+}
+
+impl TelemetrySettings {
+    /// Creates new telemetry settings with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether spans are emitted.
+    pub fn with_enabled(mut self, is_enabled: bool) -> Self {
+        self.is_enabled = is_enabled;
+        self
+    }
+
+    /// Sets whether inputs are recorded.
+    pub fn with_record_inputs(mut self, record_inputs: bool) -> Self {
+        self.record_inputs = record_inputs;
+        self
+    }
+
+    /// Sets whether outputs are recorded.
+    pub fn with_record_outputs(mut self, record_outputs: bool) -> Self {
+        self.record_outputs = record_outputs;
+        self
+    }
+
+    /// Sets the identifier of the calling function.
+    pub fn with_function_id(mut self, function_id: impl Into<String>) -> Self {
+        self.function_id = Some(function_id.into());
+        self
+    }
+}
+
+impl Default for TelemetrySettings {
+    fn default() -> Self {
+        Self {
+            is_enabled: false,
+            record_inputs: true,
+            record_outputs: true,
+            function_id: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_settings_are_disabled() {
+        let settings = TelemetrySettings::default();
+
+        assert!(!settings.is_enabled);
+        assert!(settings.record_inputs);
+        assert!(settings.record_outputs);
+        assert_eq!(settings.function_id, None);
+    }
+
+    #[test]
+    fn test_builder() {
+        let settings = TelemetrySettings::new()
+            .with_enabled(true)
+            .with_record_inputs(false)
+            .with_record_outputs(false)
+            .with_function_id("my-function");
+
+        assert!(settings.is_enabled);
+        assert!(!settings.record_inputs);
+        assert!(!settings.record_outputs);
+        assert_eq!(settings.function_id.as_deref(), Some("my-function"));
+    }
+}
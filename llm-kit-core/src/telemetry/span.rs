@@ -0,0 +1,608 @@
+use super::{TelemetrySettings, finish_reason_name};
+use crate::error::AISDKError;
+use llm_kit_provider::language_model::call_options::LanguageModelCallOptions;
+use llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason;
+use llm_kit_provider::language_model::usage::LanguageModelUsage;
+use llm_kit_provider::language_model::{LanguageModel, LanguageModelGenerateResponse};
+use llm_kit_provider_utils::tool::{ToolCall, ToolOutput};
+use serde::Serialize;
+use std::future::Future;
+use tracing::field::Empty;
+use tracing::instrument::{Instrument, Instrumented};
+use tracing::{Span, info_span};
+
+/// Creates the root span of a generation call.
+macro_rules! root_span {
+    ($name:literal, $model:expr) => {
+        info_span!(
+            $name,
+            "llm_kit.operation" = $name,
+            "llm_kit.function_id" = Empty,
+            "gen_ai.provider.name" = $model.provider(),
+            "gen_ai.request.model" = $model.model_id(),
+            "gen_ai.usage.input_tokens" = Empty,
+            "gen_ai.usage.output_tokens" = Empty,
+            "gen_ai.response.finish_reasons" = Empty,
+            "gen_ai.output.messages" = Empty,
+            "error.type" = Empty,
+            "otel.status_code" = Empty,
+            "otel.status_message" = Empty,
+        )
+    };
+}
+
+/// A tracing span for one part of a generation call.
+///
+/// The span carries the redaction settings so that child spans created from it
+/// record inputs and outputs consistently.
+#[derive(Clone)]
+pub(crate) struct TelemetrySpan {
+    span: Span,
+    record_inputs: bool,
+    record_outputs: bool,
+}
+
+impl TelemetrySpan {
+    /// Creates the root span of a `generate_text` call.
+    pub(crate) fn generate_text(model: &dyn LanguageModel, telemetry: &TelemetrySettings) -> Self {
+        if !telemetry.is_enabled {
+            return Self::disabled();
+        }
+        Self::root(root_span!("generate_text", model), telemetry)
+    }
+
+    /// Creates the root span of a `stream_text` call.
+    pub(crate) fn stream_text(model: &dyn LanguageModel, telemetry: &TelemetrySettings) -> Self {
+        if !telemetry.is_enabled {
+            return Self::disabled();
+        }
+        Self::root(root_span!("stream_text", model), telemetry)
+    }
+
+    /// Creates the span of a single step of the multi-step loop.
+    pub(crate) fn step(&self, step_number: usize) -> Self {
+        if self.span.is_disabled() {
+            return self.clone();
+        }
+
+        self.child(info_span!(
+            parent: &self.span,
+            "step",
+            "llm_kit.step.number" = step_number as u64,
+            "llm_kit.step.tool_calls" = Empty,
+            "gen_ai.usage.input_tokens" = Empty,
+            "gen_ai.usage.output_tokens" = Empty,
+            "gen_ai.response.finish_reasons" = Empty,
+        ))
+    }
+
+    /// Creates the span of a `do_generate` or `do_stream` call on the model.
+    pub(crate) fn model_call(
+        &self,
+        operation: &'static str,
+        model: &dyn LanguageModel,
+        options: &LanguageModelCallOptions,
+    ) -> Self {
+        if self.span.is_disabled() {
+            return self.clone();
+        }
+
+        let span = info_span!(
+            parent: &self.span,
+            "chat",
+            "otel.name" = %format_args!("chat {}", model.model_id()),
+            "gen_ai.operation.name" = "chat",
+            "llm_kit.operation" = operation,
+            "gen_ai.provider.name" = model.provider(),
+            "gen_ai.request.model" = model.model_id(),
+            "gen_ai.request.max_tokens" = Empty,
+            "gen_ai.request.temperature" = Empty,
+            "gen_ai.request.top_p" = Empty,
+            "gen_ai.request.top_k" = Empty,
+            "gen_ai.request.presence_penalty" = Empty,
+            "gen_ai.request.frequency_penalty" = Empty,
+            "gen_ai.request.stop_sequences" = Empty,
+            "gen_ai.request.seed" = Empty,
+            "gen_ai.input.messages" = Empty,
+            "gen_ai.response.id" = Empty,
+            "gen_ai.response.model" = Empty,
+            "gen_ai.usage.input_tokens" = Empty,
+            "gen_ai.usage.output_tokens" = Empty,
+            "gen_ai.response.finish_reasons" = Empty,
+            "gen_ai.output.messages" = Empty,
+            "error.type" = Empty,
+            "otel.status_code" = Empty,
+            "otel.status_message" = Empty,
+        );
+
+        if let Some(max_tokens) = options.max_output_tokens {
+            span.record("gen_ai.request.max_tokens", max_tokens);
+        }
+        if let Some(temperature) = options.temperature {
+            span.record("gen_ai.request.temperature", temperature);
+        }
+        if let Some(top_p) = options.top_p {
+            span.record("gen_ai.request.top_p", top_p);
+        }
+        if let Some(top_k) = options.top_k {
+            span.record("gen_ai.request.top_k", top_k);
+        }
+        if let Some(presence_penalty) = options.presence_penalty {
+            span.record("gen_ai.request.presence_penalty", presence_penalty);
+        }
+        if let Some(frequency_penalty) = options.frequency_penalty {
+            span.record("gen_ai.request.frequency_penalty", frequency_penalty);
+        }
+        if let Some(stop_sequences) = &options.stop_sequences {
+            span.record(
+                "gen_ai.request.stop_sequences",
+                stop_sequences.join(",").as_str(),
+            );
+        }
+        if let Some(seed) = options.seed {
+            span.record("gen_ai.request.seed", seed);
+        }
+
+        let span = self.child(span);
+        if span.record_inputs {
+            span.record_json("gen_ai.input.messages", &options.prompt);
+        }
+        span
+    }
+
+    /// Creates the span of a single tool execution.
+    pub(crate) fn tool_call(&self, tool_call: &ToolCall) -> Self {
+        if self.span.is_disabled() {
+            return self.clone();
+        }
+
+        let span = self.child(info_span!(
+            parent: &self.span,
+            "execute_tool",
+            "otel.name" = %format_args!("execute_tool {}", tool_call.tool_name),
+            "gen_ai.operation.name" = "execute_tool",
+            "gen_ai.tool.type" = "function",
+            "gen_ai.tool.name" = tool_call.tool_name.as_str(),
+            "gen_ai.tool.call.id" = tool_call.tool_call_id.as_str(),
+            "gen_ai.tool.call.arguments" = Empty,
+            "gen_ai.tool.call.result" = Empty,
+            "error.type" = Empty,
+            "otel.status_code" = Empty,
+            "otel.status_message" = Empty,
+        ));
+        if span.record_inputs {
+            span.record_json("gen_ai.tool.call.arguments", &tool_call.input);
+        }
+        span
+    }
+
+    /// Instruments a future with this span.
+    pub(crate) fn instrument<F: Future>(&self, future: F) -> Instrumented<F> {
+        future.instrument(self.span.clone())
+    }
+
+    /// Records the response metadata, usage, finish reason and content of a `do_generate` call.
+    pub(crate) fn record_response(&self, response: &LanguageModelGenerateResponse) {
+        if let Some(metadata) = &response.response {
+            if let Some(id) = &metadata.id {
+                self.span.record("gen_ai.response.id", id.as_str());
+            }
+            if let Some(model_id) = &metadata.model_id {
+                self.span.record("gen_ai.response.model", model_id.as_str());
+            }
+        }
+        self.record_finish(&response.finish_reason, &response.usage);
+        self.record_output(&response.content);
+    }
+
+    /// Records the finish reason and token usage.
+    pub(crate) fn record_finish(
+        &self,
+        finish_reason: &LanguageModelFinishReason,
+        usage: &LanguageModelUsage,
+    ) {
+        self.span
+            .record("gen_ai.usage.input_tokens", usage.input_tokens);
+        self.span
+            .record("gen_ai.usage.output_tokens", usage.output_tokens);
+        self.span.record(
+            "gen_ai.response.finish_reasons",
+            finish_reason_name(finish_reason),
+        );
+    }
+
+    /// Records the generated content as JSON.
+    pub(crate) fn record_output<T: Serialize + ?Sized>(&self, output: &T) {
+        if self.record_outputs {
+            self.record_json("gen_ai.output.messages", output);
+        }
+    }
+
+    /// Records the generated text.
+    pub(crate) fn record_output_text(&self, text: &str) {
+        if self.record_outputs {
+            self.span.record("gen_ai.output.messages", text);
+        }
+    }
+
+    /// Records the names of the tools called in a step.
+    pub(crate) fn record_tool_calls(&self, tool_calls: &[ToolCall]) {
+        if tool_calls.is_empty() {
+            return;
+        }
+        let names: Vec<&str> = tool_calls
+            .iter()
+            .map(|tool_call| tool_call.tool_name.as_str())
+            .collect();
+        self.span
+            .record("llm_kit.step.tool_calls", names.join(",").as_str());
+    }
+
+    /// Records the output of a tool execution.
+    pub(crate) fn record_tool_output(&self, output: Option<&ToolOutput>) {
+        match output {
+            Some(ToolOutput::Result(result)) if self.record_outputs => {
+                self.record_json("gen_ai.tool.call.result", &result.output);
+            }
+            Some(ToolOutput::Error(error)) => {
+                self.span.record("error.type", error.error_type.as_str());
+                self.span.record("otel.status_code", "ERROR");
+                if self.record_outputs {
+                    self.record_json("gen_ai.tool.call.result", &error.error);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Records an error and marks the span as failed.
+    pub(crate) fn record_error(&self, error: &AISDKError) {
+        self.span.record("error.type", error_type(error));
+        self.span.record("otel.status_code", "ERROR");
+        self.span
+            .record("otel.status_message", error.to_string().as_str());
+    }
+
+    fn disabled() -> Self {
+        Self {
+            span: Span::none(),
+            record_inputs: false,
+            record_outputs: false,
+        }
+    }
+
+    fn root(span: Span, telemetry: &TelemetrySettings) -> Self {
+        if let Some(function_id) = &telemetry.function_id {
+            span.record("llm_kit.function_id", function_id.as_str());
+        }
+        Self {
+            span,
+            record_inputs: telemetry.record_inputs,
+            record_outputs: telemetry.record_outputs,
+        }
+    }
+
+    fn child(&self, span: Span) -> Self {
+        Self {
+            span,
+            record_inputs: self.record_inputs,
+            record_outputs: self.record_outputs,
+        }
+    }
+
+    fn record_json<T: Serialize + ?Sized>(&self, field: &str, value: &T) {
+        if self.span.is_disabled() {
+            return;
+        }
+        if let Ok(json) = serde_json::to_string(value) {
+            self.span.record(field, json.as_str());
+        }
+    }
+}
+
+/// Returns a low-cardinality name for the error, recorded as `error.type`.
+fn error_type(error: &AISDKError) -> &'static str {
+    match error {
+        AISDKError::InvalidArgument { .. } => "InvalidArgument",
+        AISDKError::InvalidPrompt { .. } => "InvalidPrompt",
+        AISDKError::InvalidStreamPart { .. } => "InvalidStreamPart",
+        AISDKError::ModelError { .. } => "ModelError",
+        AISDKError::RetryableError { .. } => "RetryableError",
+        AISDKError::InvalidToolInput { .. } => "InvalidToolInput",
+        AISDKError::NoSuchTool { .. } => "NoSuchTool",
+        AISDKError::NoImageGenerated { .. } => "NoImageGenerated",
+        AISDKError::NoObjectGenerated { .. } => "NoObjectGenerated",
+        AISDKError::NoOutputGenerated { .. } => "NoOutputGenerated",
+        AISDKError::NoSpeechGenerated { .. } => "NoSpeechGenerated",
+        AISDKError::NoTranscriptGenerated { .. } => "NoTranscriptGenerated",
+        AISDKError::UnsupportedModelVersion { .. } => "UnsupportedModelVersion",
+        AISDKError::Download { .. } => "Download",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::GenerateText;
+    use crate::prompt::Prompt;
+    use crate::telemetry::TelemetrySettings;
+    use crate::tool::ToolSet;
+    use async_trait::async_trait;
+    use llm_kit_provider::language_model::call_options::LanguageModelCallOptions;
+    use llm_kit_provider::language_model::content::LanguageModelContent;
+    use llm_kit_provider::language_model::content::text::LanguageModelText;
+    use llm_kit_provider::language_model::content::tool_call::LanguageModelToolCall;
+    use llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason;
+    use llm_kit_provider::language_model::response_metadata::LanguageModelResponseMetadata;
+    use llm_kit_provider::language_model::usage::LanguageModelUsage;
+    use llm_kit_provider::language_model::{
+        LanguageModel, LanguageModelGenerateResponse, LanguageModelStreamResponse,
+    };
+    use llm_kit_provider_utils::tool::{Tool, ToolExecutionOutput};
+    use regex::Regex;
+    use serde_json::{Value, json};
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Subscriber, subscriber};
+    use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+    use tracing_subscriber::registry::LookupSpan;
+
+    /// A span with the fields recorded on it.
+    #[derive(Debug, Clone)]
+    struct CapturedSpan {
+        name: &'static str,
+        fields: HashMap<String, String>,
+    }
+
+    impl CapturedSpan {
+        fn field(&self, name: &str) -> Option<&str> {
+            self.fields.get(name).map(String::as_str)
+        }
+    }
+
+    /// Position of a span in the captured spans, stored in the span extensions.
+    struct SpanIndex(usize);
+
+    /// Layer that captures all spans and their recorded fields.
+    #[derive(Clone, Default)]
+    struct CaptureLayer {
+        spans: Arc<Mutex<Vec<CapturedSpan>>>,
+    }
+
+    impl CaptureLayer {
+        fn span(&self, name: &str) -> CapturedSpan {
+            let spans = self.spans.lock().unwrap();
+            let mut matching = spans.iter().filter(|span| span.name == name);
+            let span = matching.next().expect("span was captured").clone();
+            assert!(matching.next().is_none(), "span {} is unique", name);
+            span
+        }
+    }
+
+    struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+
+        fn record_u64(&mut self, field: &Field, value: u64) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+
+    impl<S> Layer<S> for CaptureLayer
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+            let mut fields = HashMap::new();
+            attrs.record(&mut FieldVisitor(&mut fields));
+
+            let mut spans = self.spans.lock().unwrap();
+            spans.push(CapturedSpan {
+                name: attrs.metadata().name(),
+                fields,
+            });
+            let span = ctx.span(id).expect("span exists");
+            span.extensions_mut().insert(SpanIndex(spans.len() - 1));
+        }
+
+        fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+            let span = ctx.span(id).expect("span exists");
+            let extensions = span.extensions();
+            let SpanIndex(index) = extensions.get::<SpanIndex>().expect("span is captured");
+            values.record(&mut FieldVisitor(
+                &mut self.spans.lock().unwrap()[*index].fields,
+            ));
+        }
+    }
+
+    /// Model that answers with a text and a call of the `get_weather` tool.
+    struct WeatherMockLanguageModel;
+
+    #[async_trait]
+    impl LanguageModel for WeatherMockLanguageModel {
+        fn provider(&self) -> &str {
+            "test-provider"
+        }
+
+        fn model_id(&self) -> &str {
+            "test-model"
+        }
+
+        async fn supported_urls(&self) -> HashMap<String, Vec<Regex>> {
+            HashMap::new()
+        }
+
+        async fn do_generate(
+            &self,
+            _options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelGenerateResponse, Box<dyn std::error::Error>> {
+            Ok(LanguageModelGenerateResponse {
+                content: vec![
+                    LanguageModelContent::Text(LanguageModelText::new("Let me check.")),
+                    LanguageModelContent::ToolCall(LanguageModelToolCall::new(
+                        "call_1",
+                        "get_weather",
+                        r#"{"city": "Paris"}"#,
+                    )),
+                ],
+                finish_reason: LanguageModelFinishReason::ToolCalls,
+                usage: LanguageModelUsage::new(10, 20),
+                provider_metadata: None,
+                request: None,
+                response: Some(LanguageModelResponseMetadata {
+                    id: Some("resp_1".to_string()),
+                    timestamp: None,
+                    model_id: Some("test-model-2025".to_string()),
+                }),
+                warnings: Vec::new(),
+            })
+        }
+
+        async fn do_stream(
+            &self,
+            _options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelStreamResponse, Box<dyn std::error::Error>> {
+            unimplemented!("Mock implementation")
+        }
+    }
+
+    /// Runs a `generate_text` call with one step and one tool call and captures its spans.
+    async fn capture_generate_text(telemetry: TelemetrySettings) -> CaptureLayer {
+        let layer = CaptureLayer::default();
+        let _guard = subscriber::set_default(tracing_subscriber::registry().with(layer.clone()));
+
+        let mut tools = ToolSet::new();
+        tools.insert(
+            "get_weather".to_string(),
+            Tool::function(json!({ "type": "object" })).with_execute(Arc::new(
+                |_input: Value, _options| {
+                    ToolExecutionOutput::Single(Box::pin(async {
+                        Ok(json!({ "temperature": 21 }))
+                    }))
+                },
+            )),
+        );
+
+        let result = GenerateText::new(
+            Arc::new(WeatherMockLanguageModel),
+            Prompt::text("What is the weather in Paris?"),
+        )
+        .tools(tools)
+        .telemetry(telemetry.with_function_id("weather"))
+        .execute()
+        .await
+        .unwrap();
+        assert_eq!(result.steps.len(), 1);
+
+        layer
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_records_gen_ai_attributes() {
+        let layer = capture_generate_text(TelemetrySettings::new().with_enabled(true)).await;
+
+        let root = layer.span("generate_text");
+        assert_eq!(root.field("llm_kit.function_id"), Some("weather"));
+        assert_eq!(root.field("gen_ai.provider.name"), Some("test-provider"));
+        assert_eq!(root.field("gen_ai.request.model"), Some("test-model"));
+        assert_eq!(root.field("gen_ai.usage.input_tokens"), Some("10"));
+        assert_eq!(root.field("gen_ai.usage.output_tokens"), Some("20"));
+        assert_eq!(
+            root.field("gen_ai.response.finish_reasons"),
+            Some("tool_calls")
+        );
+        assert_eq!(root.field("otel.status_code"), None);
+
+        let step = layer.span("step");
+        assert_eq!(step.field("llm_kit.step.number"), Some("0"));
+        assert_eq!(step.field("llm_kit.step.tool_calls"), Some("get_weather"));
+
+        let chat = layer.span("chat");
+        assert_eq!(chat.field("gen_ai.operation.name"), Some("chat"));
+        assert_eq!(chat.field("gen_ai.provider.name"), Some("test-provider"));
+        assert_eq!(chat.field("gen_ai.request.model"), Some("test-model"));
+        assert_eq!(chat.field("gen_ai.response.id"), Some("resp_1"));
+        assert_eq!(chat.field("gen_ai.response.model"), Some("test-model-2025"));
+        assert_eq!(chat.field("gen_ai.usage.input_tokens"), Some("10"));
+        assert_eq!(chat.field("gen_ai.usage.output_tokens"), Some("20"));
+        assert_eq!(
+            chat.field("gen_ai.response.finish_reasons"),
+            Some("tool_calls")
+        );
+
+        let tool = layer.span("execute_tool");
+        assert_eq!(tool.field("gen_ai.tool.name"), Some("get_weather"));
+        assert_eq!(tool.field("gen_ai.tool.call.id"), Some("call_1"));
+        assert_eq!(tool.field("otel.status_code"), None);
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_records_inputs_and_outputs() {
+        let layer = capture_generate_text(TelemetrySettings::new().with_enabled(true)).await;
+
+        let root = layer.span("generate_text");
+        assert_eq!(root.field("gen_ai.output.messages"), Some("Let me check."));
+
+        let chat = layer.span("chat");
+        assert!(
+            chat.field("gen_ai.input.messages")
+                .unwrap()
+                .contains("What is the weather in Paris?")
+        );
+        let output = chat.field("gen_ai.output.messages").unwrap();
+        assert!(output.contains("Let me check."));
+        assert!(output.contains("get_weather"));
+
+        let tool = layer.span("execute_tool");
+        assert_eq!(
+            serde_json::from_str::<Value>(tool.field("gen_ai.tool.call.arguments").unwrap())
+                .unwrap(),
+            json!({ "city": "Paris" })
+        );
+        assert_eq!(
+            tool.field("gen_ai.tool.call.result"),
+            Some(r#"{"temperature":21}"#)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_redacts_inputs_and_outputs() {
+        let layer = capture_generate_text(
+            TelemetrySettings::new()
+                .with_enabled(true)
+                .with_record_inputs(false)
+                .with_record_outputs(false),
+        )
+        .await;
+
+        let root = layer.span("generate_text");
+        assert_eq!(root.field("gen_ai.output.messages"), None);
+        // Attributes without prompt or output data are still recorded
+        assert_eq!(root.field("gen_ai.usage.input_tokens"), Some("10"));
+
+        let chat = layer.span("chat");
+        assert_eq!(chat.field("gen_ai.input.messages"), None);
+        assert_eq!(chat.field("gen_ai.output.messages"), None);
+
+        let tool = layer.span("execute_tool");
+        assert_eq!(tool.field("gen_ai.tool.name"), Some("get_weather"));
+        assert_eq!(tool.field("gen_ai.tool.call.arguments"), None);
+        assert_eq!(tool.field("gen_ai.tool.call.result"), None);
+    }
+
+    #[tokio::test]
+    async fn test_default_telemetry_emits_no_spans() {
+        let layer = capture_generate_text(TelemetrySettings::default()).await;
+
+        assert!(layer.spans.lock().unwrap().is_empty());
+    }
+}
@@ -16,11 +16,13 @@ pub mod tool_set;
 pub mod type_safe;
 
 pub use collect_tool_approvals::{
-    CollectedToolApproval, CollectedToolApprovals, collect_tool_approvals,
+    CollectedToolApproval, CollectedToolApprovals, collect_tool_approvals, pending_tool_approvals,
 };
 pub use execute_tool_call::execute_tool_call;
 pub use is_approval_needed::is_approval_needed;
-pub use parse_tool_call::{parse_provider_executed_dynamic_tool_call, parse_tool_call};
+pub use parse_tool_call::{
+    parse_provider_executed_dynamic_tool_call, parse_tool_call, parse_tool_call_with_repair,
+};
 pub use prepare_tools::prepare_tools_and_tool_choice;
 pub use repair_function::{ToolCallRepairFunction, ToolCallRepairOptions, no_repair};
 pub use tool_set::ToolSet;
@@ -1,6 +1,8 @@
 use llm_kit_provider_utils::message::{AssistantContent, Message, ToolContentPart};
-use llm_kit_provider_utils::tool::{ToolApprovalRequest, ToolApprovalResponse, ToolCall};
-use std::collections::HashMap;
+use llm_kit_provider_utils::tool::{
+    ToolApprovalRequest, ToolApprovalRequestOutput, ToolApprovalResponse, ToolCall,
+};
+use std::collections::{HashMap, HashSet};
 
 /// A collected tool approval containing the approval request, response, and associated tool call.
 ///
@@ -233,6 +235,114 @@ pub fn collect_tool_approvals(messages: &[Message]) -> CollectedToolApprovals {
     }
 }
 
+/// Finds the tool approval requests in the message history that are still waiting for a response.
+///
+/// An approval request is pending when no tool message contains a response to it and no
+/// tool result exists for its tool call. Requests whose tool call cannot be found are skipped.
+///
+/// # Arguments
+///
+/// * `messages` - The message history to search
+///
+/// # Returns
+///
+/// The pending approval requests with their tool calls, in the order they were requested.
+///
+/// # Example
+///
+/// ```rust
+/// use llm_kit_core::tool::pending_tool_approvals;
+/// use llm_kit_provider_utils::message::{Message, AssistantMessage, AssistantContentPart};
+/// use llm_kit_provider_utils::message::content_parts::ToolCallPart;
+/// use llm_kit_provider_utils::tool::ToolApprovalRequest;
+/// use serde_json::json;
+///
+/// let messages = vec![Message::Assistant(AssistantMessage::with_parts(vec![
+///     AssistantContentPart::ToolCall(ToolCallPart::new(
+///         "call_123",
+///         "delete_file",
+///         json!({"path": "/important/file.txt"}),
+///     )),
+///     AssistantContentPart::ToolApprovalRequest(
+///         ToolApprovalRequest::new("approval_456", "call_123")
+///     ),
+/// ]))];
+///
+/// let pending = pending_tool_approvals(&messages);
+///
+/// assert_eq!(pending.len(), 1);
+/// assert_eq!(pending[0].approval_id, "approval_456");
+/// assert_eq!(pending[0].tool_call.tool_name, "delete_file");
+/// ```
+pub fn pending_tool_approvals(messages: &[Message]) -> Vec<ToolApprovalRequestOutput> {
+    use llm_kit_provider_utils::message::assistant::AssistantContentPart;
+
+    let mut tool_calls_by_id: HashMap<String, ToolCall> = HashMap::new();
+    let mut approval_requests: Vec<ToolApprovalRequest> = Vec::new();
+    let mut answered_approval_ids: HashSet<String> = HashSet::new();
+    let mut tool_result_ids: HashSet<String> = HashSet::new();
+
+    for message in messages {
+        match message {
+            Message::Assistant(assistant_msg) => {
+                if let AssistantContent::Parts(parts) = &assistant_msg.content {
+                    for part in parts {
+                        match part {
+                            AssistantContentPart::ToolCall(tool_call) => {
+                                let call = ToolCall::new(
+                                    tool_call.tool_call_id.clone(),
+                                    tool_call.tool_name.clone(),
+                                    tool_call.input.clone(),
+                                )
+                                .with_provider_executed(
+                                    tool_call.provider_executed.unwrap_or(false),
+                                );
+
+                                tool_calls_by_id.insert(tool_call.tool_call_id.clone(), call);
+                            }
+                            AssistantContentPart::ToolApprovalRequest(approval_req) => {
+                                approval_requests.push(approval_req.clone());
+                            }
+                            AssistantContentPart::ToolResult(tool_result) => {
+                                tool_result_ids.insert(tool_result.tool_call_id.clone());
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            Message::Tool(tool_msg) => {
+                for part in &tool_msg.content {
+                    match part {
+                        ToolContentPart::ToolResult(tool_result) => {
+                            tool_result_ids.insert(tool_result.tool_call_id.clone());
+                        }
+                        ToolContentPart::ApprovalResponse(approval_response) => {
+                            answered_approval_ids.insert(approval_response.approval_id.clone());
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    approval_requests
+        .into_iter()
+        .filter(|request| {
+            !answered_approval_ids.contains(&request.approval_id)
+                && !tool_result_ids.contains(&request.tool_call_id)
+        })
+        .filter_map(|request| {
+            tool_calls_by_id
+                .get(&request.tool_call_id)
+                .map(|tool_call| {
+                    ToolApprovalRequestOutput::new(request.approval_id, tool_call.clone())
+                })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -452,4 +562,53 @@ mod tests {
         assert_eq!(collected.approval_response, approval_response);
         assert_eq!(collected.tool_call, tool_call);
     }
+
+    #[test]
+    fn test_pending_tool_approvals() {
+        let messages = vec![
+            Message::Assistant(AssistantMessage::with_parts(vec![
+                AssistantContentPart::ToolCall(ToolCallPart::new(
+                    "call_1",
+                    "delete_file",
+                    json!({"path": "a.txt"}),
+                )),
+                AssistantContentPart::ToolApprovalRequest(ToolApprovalRequest::new(
+                    "approval_1",
+                    "call_1",
+                )),
+                AssistantContentPart::ToolCall(ToolCallPart::new(
+                    "call_2",
+                    "delete_file",
+                    json!({"path": "b.txt"}),
+                )),
+                AssistantContentPart::ToolApprovalRequest(ToolApprovalRequest::new(
+                    "approval_2",
+                    "call_2",
+                )),
+                AssistantContentPart::ToolCall(ToolCallPart::new(
+                    "call_3",
+                    "delete_file",
+                    json!({"path": "c.txt"}),
+                )),
+                AssistantContentPart::ToolApprovalRequest(ToolApprovalRequest::new(
+                    "approval_3",
+                    "call_3",
+                )),
+            ])),
+            Message::Tool(ToolMessage::new(vec![
+                ToolContentPart::ApprovalResponse(ToolApprovalResponse::granted("approval_1")),
+                ToolContentPart::ToolResult(ToolResultPart::new(
+                    "call_3",
+                    "delete_file",
+                    ToolResultOutput::json(json!({"deleted": true})),
+                )),
+            ])),
+        ];
+
+        let pending = pending_tool_approvals(&messages);
+
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].approval_id, "approval_2");
+        assert_eq!(pending[0].tool_call.tool_call_id, "call_2");
+    }
 }
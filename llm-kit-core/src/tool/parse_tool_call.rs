@@ -22,8 +22,10 @@
 //!
 //! When validation fails, detailed error messages indicate which constraints were violated.
 
+use super::repair_function::{ToolCallRepairFunction, ToolCallRepairOptions};
 use crate::error::AISDKError;
 use llm_kit_provider::language_model::content::tool_call::LanguageModelToolCall;
+use llm_kit_provider_utils::message::Message;
 use llm_kit_provider_utils::tool::{Tool, ToolCall};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -208,6 +210,59 @@ pub fn parse_tool_call(
     Ok(call)
 }
 
+/// Parses a tool call, attempting to repair it when parsing fails.
+///
+/// If [`parse_tool_call`] fails with [`AISDKError::NoSuchTool`] or
+/// [`AISDKError::InvalidToolInput`] and a repair function is provided, the repair
+/// function is called with the failed tool call and the error. A repaired tool call
+/// is parsed again; if the repair function returns `None`, the original error is returned.
+///
+/// # Arguments
+///
+/// * `tool_call` - The tool call from the provider to parse
+/// * `tools` - The set of available tools
+/// * `repair_tool_call` - Optional repair function
+/// * `system` - The system prompt of the current step
+/// * `messages` - The messages of the current step
+///
+/// # Errors
+///
+/// Returns the original parsing error when the tool call cannot be repaired, or the
+/// error from parsing the repaired tool call.
+pub async fn parse_tool_call_with_repair(
+    tool_call: &LanguageModelToolCall,
+    tools: &HashMap<String, Tool>,
+    repair_tool_call: Option<&ToolCallRepairFunction>,
+    system: Option<&str>,
+    messages: &[Message],
+) -> Result<ToolCall, AISDKError> {
+    let error = match parse_tool_call(tool_call, tools) {
+        Ok(call) => return Ok(call),
+        Err(error) => error,
+    };
+
+    let repair_tool_call = match (repair_tool_call, &error) {
+        (
+            Some(repair_tool_call),
+            AISDKError::NoSuchTool { .. } | AISDKError::InvalidToolInput { .. },
+        ) => repair_tool_call,
+        _ => return Err(error),
+    };
+
+    let options = ToolCallRepairOptions::new(
+        system.map(|s| s.to_string()),
+        messages.to_vec(),
+        tool_call.clone(),
+        tools.clone(),
+        error.clone(),
+    );
+
+    match repair_tool_call(options).await {
+        Some(repaired) => parse_tool_call(&repaired, tools),
+        None => Err(error),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -429,4 +484,77 @@ mod tests {
         assert_eq!(parsed.tool_name, "external_tool");
         assert_eq!(parsed.provider_executed, Some(true));
     }
+
+    fn weather_tools() -> HashMap<String, Tool> {
+        let schema = json!({
+            "type": "object",
+            "properties": { "city": { "type": "string" } },
+            "required": ["city"]
+        });
+        let mut tools = HashMap::new();
+        tools.insert("get_weather".to_string(), Tool::function(schema));
+        tools
+    }
+
+    #[tokio::test]
+    async fn test_parse_tool_call_with_repair_fixes_tool_name() {
+        let tools = weather_tools();
+        let tool_call = LanguageModelToolCall::new("call_123", "getWeather", r#"{"city": "SF"}"#);
+
+        let repair: ToolCallRepairFunction = Box::new(|options: ToolCallRepairOptions| {
+            Box::pin(async move {
+                assert!(matches!(options.error, AISDKError::NoSuchTool { .. }));
+                assert_eq!(options.system.as_deref(), Some("system"));
+                Some(LanguageModelToolCall::new(
+                    options.tool_call.tool_call_id,
+                    "get_weather",
+                    options.tool_call.input,
+                ))
+            })
+        });
+
+        let parsed =
+            parse_tool_call_with_repair(&tool_call, &tools, Some(&repair), Some("system"), &[])
+                .await
+                .unwrap();
+        assert_eq!(parsed.tool_call_id, "call_123");
+        assert_eq!(parsed.tool_name, "get_weather");
+        assert_eq!(parsed.input, json!({"city": "SF"}));
+    }
+
+    #[tokio::test]
+    async fn test_parse_tool_call_with_repair_fixes_input() {
+        let tools = weather_tools();
+        let tool_call = LanguageModelToolCall::new("call_123", "get_weather", r#"{"town": "SF"}"#);
+
+        let repair: ToolCallRepairFunction = Box::new(|options: ToolCallRepairOptions| {
+            Box::pin(async move {
+                assert!(matches!(options.error, AISDKError::InvalidToolInput { .. }));
+                Some(LanguageModelToolCall::new(
+                    options.tool_call.tool_call_id,
+                    options.tool_call.tool_name,
+                    r#"{"city": "SF"}"#,
+                ))
+            })
+        });
+
+        let parsed = parse_tool_call_with_repair(&tool_call, &tools, Some(&repair), None, &[])
+            .await
+            .unwrap();
+        assert_eq!(parsed.input, json!({"city": "SF"}));
+    }
+
+    #[tokio::test]
+    async fn test_parse_tool_call_with_repair_returns_original_error() {
+        let tools = weather_tools();
+        let tool_call = LanguageModelToolCall::new("call_123", "unknown", "{}");
+        let repair = crate::tool::no_repair();
+
+        let result =
+            parse_tool_call_with_repair(&tool_call, &tools, Some(&repair), None, &[]).await;
+        assert!(matches!(result, Err(AISDKError::NoSuchTool { .. })));
+
+        let result = parse_tool_call_with_repair(&tool_call, &tools, None, None, &[]).await;
+        assert!(matches!(result, Err(AISDKError::NoSuchTool { .. })));
+    }
 }
@@ -4,7 +4,7 @@ pub mod result;
 pub use result::TranscriptionResult;
 
 use crate::error::AISDKError;
-use crate::generate_text::prepare_retries;
+use crate::generate_text::{RetryPolicy, prepare_retries};
 use llm_kit_provider::shared::headers::SharedHeaders;
 use llm_kit_provider::shared::provider_options::SharedProviderOptions;
 use llm_kit_provider::transcription_model::TranscriptionModel;
@@ -44,6 +44,7 @@ pub struct Transcribe {
     audio: AudioInput,
     provider_options: Option<SharedProviderOptions>,
     max_retries: Option<u32>,
+    retry_policy: Option<Arc<dyn RetryPolicy>>,
     abort_signal: Option<CancellationToken>,
     headers: Option<SharedHeaders>,
 }
@@ -61,6 +62,7 @@ impl Transcribe {
             audio,
             provider_options: None,
             max_retries: None,
+            retry_policy: None,
             abort_signal: None,
             headers: None,
         }
@@ -86,6 +88,16 @@ impl Transcribe {
         self
     }
 
+    /// Set the policy that decides whether and when failed calls are retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `retry_policy` - The retry policy. Default: [`DefaultRetryPolicy`](crate::generate_text::DefaultRetryPolicy).
+    pub fn retry_policy(mut self, retry_policy: Arc<dyn RetryPolicy>) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
     /// Set an abort signal that can be used to cancel the call.
     ///
     /// # Arguments
@@ -127,7 +139,11 @@ impl Transcribe {
             add_user_agent_suffix(self.headers, format!("ai/{}", VERSION));
 
         // Prepare retry configuration
-        let retry_config = prepare_retries(self.max_retries, self.abort_signal.clone())?;
+        let retry_config = prepare_retries(
+            self.max_retries,
+            self.abort_signal.clone(),
+            self.retry_policy.clone(),
+        )?;
 
         // Convert audio input to bytes
         let audio_bytes = match self.audio {
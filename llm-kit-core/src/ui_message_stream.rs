@@ -0,0 +1,485 @@
+//! Conversion of text streams into the UI message stream protocol.
+//!
+//! The UI message stream is the server-sent events (SSE) format the AI SDK UI
+//! hooks consume: every event is a `data:` line with a JSON [`UIMessageChunk`],
+//! and the stream ends with `data: [DONE]`.
+//!
+//! [`StreamTextResult::ui_message_stream`] converts a result into chunks and
+//! [`StreamTextResult::ui_message_sse_stream`] into SSE events. The `axum` and
+//! `hyper` features add adapters that return a streaming HTTP response with the
+//! protocol headers, for both the UI message stream and a plain-text stream.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use llm_kit_core::StreamText;
+//! use llm_kit_core::prompt::Prompt;
+//! use llm_kit_core::ui_message_stream::UIMessageStreamOptions;
+//! use futures::StreamExt;
+//! # use llm_kit_provider::LanguageModel;
+//! # use std::sync::Arc;
+//! # async fn example(model: Arc<dyn LanguageModel>) -> Result<(), Box<dyn std::error::Error>> {
+//!
+//! let result = StreamText::new(model, Prompt::text("Hello")).execute().await?;
+//!
+//! let mut events =
+//!     result.ui_message_sse_stream(UIMessageStreamOptions::new().with_send_sources(true));
+//! while let Some(event) = events.next().await {
+//!     print!("{}", event); // e.g. `data: {"type":"text-delta","id":"0","delta":"Hi"}`
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+/// Chunk types of the UI message stream protocol.
+pub mod chunk;
+
+/// Streaming HTTP responses for axum (requires axum feature).
+#[cfg(feature = "axum")]
+pub mod axum;
+/// Streaming HTTP responses for hyper (requires hyper feature).
+#[cfg(feature = "hyper")]
+pub mod hyper;
+
+pub use chunk::UIMessageChunk;
+
+use crate::stream_text::{AsyncIterableStream, TextStreamPart};
+use futures_util::StreamExt;
+use llm_kit_provider::language_model::content::source::LanguageModelSource;
+use serde_json::Value;
+use std::sync::Arc;
+
+/// Headers of a UI message stream response.
+pub const UI_MESSAGE_STREAM_HEADERS: [(&str, &str); 5] = [
+    ("content-type", "text/event-stream"),
+    ("cache-control", "no-cache"),
+    ("connection", "keep-alive"),
+    ("x-vercel-ai-ui-message-stream", "v1"),
+    ("x-accel-buffering", "no"),
+];
+
+/// Headers of a plain-text stream response.
+pub const TEXT_STREAM_HEADERS: [(&str, &str); 1] = [("content-type", "text/plain; charset=utf-8")];
+
+/// A type alias for functions that turn an error into the text sent to the client.
+pub type UIErrorHandler = Arc<dyn Fn(&Value) -> String + Send + Sync>;
+
+/// Options for converting a text stream into a UI message stream.
+///
+/// # Example
+///
+/// ```
+/// use llm_kit_core::ui_message_stream::UIMessageStreamOptions;
+/// use std::sync::Arc;
+///
+/// let options = UIMessageStreamOptions::new()
+///     .with_message_id("msg_123")
+///     .with_send_reasoning(false)
+///     .with_on_error(Arc::new(|error| error.to_string()));
+/// ```
+#[derive(Clone)]
+pub struct UIMessageStreamOptions {
+    /// ID of the assistant message, sent with the start chunk.
+    pub message_id: Option<String>,
+
+    /// Whether to send reasoning chunks (default: true).
+    pub send_reasoning: bool,
+
+    /// Whether to send source chunks (default: false).
+    pub send_sources: bool,
+
+    /// Turns errors into the text sent to the client.
+    ///
+    /// By default errors are masked as "An error occurred.", so no internal details
+    /// reach the client.
+    pub on_error: Option<UIErrorHandler>,
+}
+
+impl Default for UIMessageStreamOptions {
+    fn default() -> Self {
+        Self {
+            message_id: None,
+            send_reasoning: true,
+            send_sources: false,
+            on_error: None,
+        }
+    }
+}
+
+impl UIMessageStreamOptions {
+    /// Creates new options with the defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the ID of the assistant message.
+    pub fn with_message_id(mut self, message_id: impl Into<String>) -> Self {
+        self.message_id = Some(message_id.into());
+        self
+    }
+
+    /// Sets whether to send reasoning chunks.
+    pub fn with_send_reasoning(mut self, send_reasoning: bool) -> Self {
+        self.send_reasoning = send_reasoning;
+        self
+    }
+
+    /// Sets whether to send source chunks.
+    pub fn with_send_sources(mut self, send_sources: bool) -> Self {
+        self.send_sources = send_sources;
+        self
+    }
+
+    /// Sets the function that turns errors into the text sent to the client.
+    pub fn with_on_error(mut self, on_error: UIErrorHandler) -> Self {
+        self.on_error = Some(on_error);
+        self
+    }
+
+    /// Returns the text sent to the client for an error.
+    fn error_text(&self, error: &Value) -> String {
+        match &self.on_error {
+            Some(on_error) => on_error(error),
+            None => "An error occurred.".to_string(),
+        }
+    }
+}
+
+/// Converts a stream of text stream parts into UI message chunks.
+///
+/// Parts without a counterpart in the protocol, such as the end of a tool input and
+/// raw provider values, are dropped.
+pub fn to_ui_message_stream(
+    parts: AsyncIterableStream<TextStreamPart>,
+    options: UIMessageStreamOptions,
+) -> AsyncIterableStream<UIMessageChunk> {
+    Box::pin(parts.filter_map(move |part| {
+        let chunk = to_ui_message_chunk(part, &options);
+        async move { chunk }
+    }))
+}
+
+/// Converts UI message chunks into server-sent events, ending with `data: [DONE]`.
+pub fn to_sse_stream(chunks: AsyncIterableStream<UIMessageChunk>) -> AsyncIterableStream<String> {
+    Box::pin(async_stream::stream! {
+        let mut chunks = chunks;
+        while let Some(chunk) = chunks.next().await {
+            match serde_json::to_string(&chunk) {
+                Ok(json) => yield format!("data: {}\n\n", json),
+                Err(e) => log::warn!("Failed to serialize UI message chunk: {}", e),
+            }
+        }
+        yield "data: [DONE]\n\n".to_string();
+    })
+}
+
+/// Converts a text stream part into its UI message chunk, if it has one.
+fn to_ui_message_chunk(
+    part: TextStreamPart,
+    options: &UIMessageStreamOptions,
+) -> Option<UIMessageChunk> {
+    let chunk = match part {
+        TextStreamPart::Start => UIMessageChunk::Start {
+            message_id: options.message_id.clone(),
+            message_metadata: None,
+        },
+        TextStreamPart::Finish { .. } => UIMessageChunk::Finish {
+            message_metadata: None,
+        },
+        TextStreamPart::StartStep { .. } => UIMessageChunk::StartStep,
+        TextStreamPart::FinishStep { .. } => UIMessageChunk::FinishStep,
+        TextStreamPart::TextStart {
+            id,
+            provider_metadata,
+        } => UIMessageChunk::TextStart {
+            id,
+            provider_metadata,
+        },
+        TextStreamPart::TextDelta {
+            id,
+            provider_metadata,
+            text,
+        } => UIMessageChunk::TextDelta {
+            id,
+            delta: text,
+            provider_metadata,
+        },
+        TextStreamPart::TextEnd {
+            id,
+            provider_metadata,
+        } => UIMessageChunk::TextEnd {
+            id,
+            provider_metadata,
+        },
+        TextStreamPart::ReasoningStart {
+            id,
+            provider_metadata,
+        } if options.send_reasoning => UIMessageChunk::ReasoningStart {
+            id,
+            provider_metadata,
+        },
+        TextStreamPart::ReasoningDelta {
+            id,
+            provider_metadata,
+            text,
+        } if options.send_reasoning => UIMessageChunk::ReasoningDelta {
+            id,
+            delta: text,
+            provider_metadata,
+        },
+        TextStreamPart::ReasoningEnd {
+            id,
+            provider_metadata,
+        } if options.send_reasoning => UIMessageChunk::ReasoningEnd {
+            id,
+            provider_metadata,
+        },
+        TextStreamPart::ToolInputStart {
+            id,
+            tool_name,
+            provider_executed,
+            dynamic,
+            title,
+            ..
+        } => UIMessageChunk::ToolInputStart {
+            tool_call_id: id,
+            tool_name,
+            provider_executed,
+            dynamic,
+            title,
+        },
+        TextStreamPart::ToolInputDelta { id, delta, .. } => UIMessageChunk::ToolInputDelta {
+            tool_call_id: id,
+            input_text_delta: delta,
+        },
+        TextStreamPart::ToolCall { tool_call } => match tool_call.invalid {
+            Some(true) => UIMessageChunk::ToolInputError {
+                error_text: options.error_text(tool_call.error.as_ref().unwrap_or(&Value::Null)),
+                tool_call_id: tool_call.tool_call_id,
+                tool_name: tool_call.tool_name,
+                input: tool_call.input,
+                provider_executed: tool_call.provider_executed,
+                provider_metadata: tool_call.provider_metadata,
+            },
+            _ => UIMessageChunk::ToolInputAvailable {
+                tool_call_id: tool_call.tool_call_id,
+                tool_name: tool_call.tool_name,
+                input: tool_call.input,
+                provider_executed: tool_call.provider_executed,
+                provider_metadata: tool_call.provider_metadata,
+            },
+        },
+        TextStreamPart::ToolApprovalRequest { approval_request } => {
+            UIMessageChunk::ToolApprovalRequest {
+                approval_id: approval_request.approval_id,
+                tool_call_id: approval_request.tool_call.tool_call_id,
+            }
+        }
+        TextStreamPart::ToolResult { tool_result } => UIMessageChunk::ToolOutputAvailable {
+            tool_call_id: tool_result.tool_call_id,
+            output: tool_result.output,
+            provider_executed: tool_result.provider_executed,
+            preliminary: tool_result.preliminary,
+        },
+        TextStreamPart::ToolError { tool_error } => UIMessageChunk::ToolOutputError {
+            error_text: options.error_text(&tool_error.error),
+            tool_call_id: tool_error.tool_call_id,
+            provider_executed: tool_error.provider_executed,
+        },
+        TextStreamPart::ToolOutputDenied { tool_call_id, .. } => {
+            UIMessageChunk::ToolOutputDenied { tool_call_id }
+        }
+        TextStreamPart::Source { source } if options.send_sources => match source.source {
+            LanguageModelSource::Url {
+                id,
+                url,
+                title,
+                provider_metadata,
+            } => UIMessageChunk::SourceUrl {
+                source_id: id,
+                url,
+                title,
+                provider_metadata,
+            },
+            LanguageModelSource::Document {
+                id,
+                media_type,
+                title,
+                filename,
+                provider_metadata,
+            } => UIMessageChunk::SourceDocument {
+                source_id: id,
+                media_type,
+                title,
+                filename,
+                provider_metadata,
+            },
+        },
+        TextStreamPart::File { file } => UIMessageChunk::File {
+            url: format!("data:{};base64,{}", file.media_type, file.base64),
+            media_type: file.media_type,
+        },
+        TextStreamPart::Abort => UIMessageChunk::Abort,
+        TextStreamPart::Error { error } => UIMessageChunk::Error {
+            error_text: options.error_text(&error),
+        },
+        _ => return None,
+    };
+    Some(chunk)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::SourceOutput;
+    use crate::stream_text::StreamGeneratedFile;
+    use llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason;
+    use llm_kit_provider::language_model::usage::LanguageModelUsage;
+    use llm_kit_provider_utils::tool::{ToolCall, ToolError};
+    use serde_json::json;
+
+    fn source() -> TextStreamPart {
+        TextStreamPart::Source {
+            source: SourceOutput::new(LanguageModelSource::Url {
+                id: "src_1".to_string(),
+                url: "https://example.com".to_string(),
+                title: None,
+                provider_metadata: None,
+            }),
+        }
+    }
+
+    fn reasoning() -> TextStreamPart {
+        TextStreamPart::ReasoningDelta {
+            id: "r".to_string(),
+            provider_metadata: None,
+            text: "Thinking".to_string(),
+        }
+    }
+
+    async fn convert(
+        parts: Vec<TextStreamPart>,
+        options: UIMessageStreamOptions,
+    ) -> Vec<UIMessageChunk> {
+        to_ui_message_stream(Box::pin(futures::stream::iter(parts)), options)
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_converts_text_and_tool_parts() {
+        let chunks = convert(
+            vec![
+                TextStreamPart::Start,
+                TextStreamPart::TextDelta {
+                    id: "0".to_string(),
+                    provider_metadata: None,
+                    text: "Hi".to_string(),
+                },
+                TextStreamPart::ToolInputEnd {
+                    id: "call_1".to_string(),
+                    provider_metadata: None,
+                },
+                TextStreamPart::ToolCall {
+                    tool_call: ToolCall::new(
+                        "call_1".to_string(),
+                        "get_weather".to_string(),
+                        json!({ "city": "Paris" }),
+                    ),
+                },
+                TextStreamPart::ToolError {
+                    tool_error: ToolError::new(
+                        "call_2".to_string(),
+                        "get_weather".to_string(),
+                        json!({}),
+                        json!("connection refused"),
+                    ),
+                },
+                TextStreamPart::File {
+                    file: StreamGeneratedFile {
+                        base64: "aGk=".to_string(),
+                        media_type: "text/plain".to_string(),
+                        name: None,
+                    },
+                },
+                TextStreamPart::Finish {
+                    finish_reason: LanguageModelFinishReason::Stop,
+                    total_usage: LanguageModelUsage::new(10, 5),
+                },
+            ],
+            UIMessageStreamOptions::new().with_message_id("msg_1"),
+        )
+        .await;
+
+        assert_eq!(
+            serde_json::to_value(&chunks).unwrap(),
+            json!([
+                { "type": "start", "messageId": "msg_1" },
+                { "type": "text-delta", "id": "0", "delta": "Hi" },
+                {
+                    "type": "tool-input-available",
+                    "toolCallId": "call_1",
+                    "toolName": "get_weather",
+                    "input": { "city": "Paris" }
+                },
+                {
+                    "type": "tool-output-error",
+                    "toolCallId": "call_2",
+                    "errorText": "An error occurred."
+                },
+                { "type": "file", "url": "data:text/plain;base64,aGk=", "mediaType": "text/plain" },
+                { "type": "finish" }
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_options_filter_reasoning_and_sources() {
+        let defaults = convert(vec![reasoning(), source()], UIMessageStreamOptions::new()).await;
+        assert_eq!(defaults.len(), 1);
+        assert!(matches!(defaults[0], UIMessageChunk::ReasoningDelta { .. }));
+
+        let options = UIMessageStreamOptions::new()
+            .with_send_reasoning(false)
+            .with_send_sources(true);
+        let chunks = convert(vec![reasoning(), source()], options).await;
+        assert_eq!(chunks.len(), 1);
+        assert!(matches!(chunks[0], UIMessageChunk::SourceUrl { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_on_error_formats_errors() {
+        let options = UIMessageStreamOptions::new().with_on_error(Arc::new(|error| {
+            error["message"].as_str().unwrap_or_default().to_string()
+        }));
+        let chunks = convert(
+            vec![TextStreamPart::Error {
+                error: json!({ "message": "rate limited" }),
+            }],
+            options,
+        )
+        .await;
+
+        assert_eq!(
+            chunks,
+            vec![UIMessageChunk::Error {
+                error_text: "rate limited".to_string()
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sse_stream_ends_with_done() {
+        let chunks: AsyncIterableStream<UIMessageChunk> =
+            Box::pin(futures::stream::iter(vec![UIMessageChunk::StartStep]));
+        let events: Vec<String> = to_sse_stream(chunks).collect().await;
+
+        assert_eq!(
+            events,
+            vec![
+                "data: {\"type\":\"start-step\"}\n\n".to_string(),
+                "data: [DONE]\n\n".to_string()
+            ]
+        );
+    }
+}
@@ -0,0 +1,364 @@
+use llm_kit_provider::shared::provider_metadata::SharedProviderMetadata;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A chunk of the UI message stream protocol.
+///
+/// The UI message stream is the wire format the AI SDK UI hooks (e.g. `useChat`)
+/// consume. Every chunk is a JSON object with a `type` field; chunks that belong
+/// together share an ID (`id` for text and reasoning, `toolCallId` for tools).
+///
+/// # Example
+///
+/// ```
+/// use llm_kit_core::ui_message_stream::UIMessageChunk;
+///
+/// let chunk = UIMessageChunk::TextDelta {
+///     id: "0".to_string(),
+///     delta: "Hello".to_string(),
+///     provider_metadata: None,
+/// };
+///
+/// assert_eq!(
+///     serde_json::to_string(&chunk).unwrap(),
+///     r#"{"type":"text-delta","id":"0","delta":"Hello"}"#
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum UIMessageChunk {
+    /// Start of the assistant message.
+    #[serde(rename_all = "camelCase")]
+    Start {
+        /// ID of the assistant message.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        message_id: Option<String>,
+
+        /// Metadata of the assistant message.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        message_metadata: Option<Value>,
+    },
+
+    /// End of the assistant message.
+    #[serde(rename_all = "camelCase")]
+    Finish {
+        /// Metadata of the assistant message.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        message_metadata: Option<Value>,
+    },
+
+    /// Start of a generation step.
+    StartStep,
+
+    /// End of a generation step.
+    FinishStep,
+
+    /// Start of a text segment.
+    #[serde(rename_all = "camelCase")]
+    TextStart {
+        /// ID of the text segment.
+        id: String,
+
+        /// Provider-specific metadata.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        provider_metadata: Option<SharedProviderMetadata>,
+    },
+
+    /// A text delta.
+    #[serde(rename_all = "camelCase")]
+    TextDelta {
+        /// ID of the text segment.
+        id: String,
+
+        /// The text content.
+        delta: String,
+
+        /// Provider-specific metadata.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        provider_metadata: Option<SharedProviderMetadata>,
+    },
+
+    /// End of a text segment.
+    #[serde(rename_all = "camelCase")]
+    TextEnd {
+        /// ID of the text segment.
+        id: String,
+
+        /// Provider-specific metadata.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        provider_metadata: Option<SharedProviderMetadata>,
+    },
+
+    /// Start of a reasoning segment.
+    #[serde(rename_all = "camelCase")]
+    ReasoningStart {
+        /// ID of the reasoning segment.
+        id: String,
+
+        /// Provider-specific metadata.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        provider_metadata: Option<SharedProviderMetadata>,
+    },
+
+    /// A reasoning delta.
+    #[serde(rename_all = "camelCase")]
+    ReasoningDelta {
+        /// ID of the reasoning segment.
+        id: String,
+
+        /// The reasoning text content.
+        delta: String,
+
+        /// Provider-specific metadata.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        provider_metadata: Option<SharedProviderMetadata>,
+    },
+
+    /// End of a reasoning segment.
+    #[serde(rename_all = "camelCase")]
+    ReasoningEnd {
+        /// ID of the reasoning segment.
+        id: String,
+
+        /// Provider-specific metadata.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        provider_metadata: Option<SharedProviderMetadata>,
+    },
+
+    /// Start of a streamed tool input.
+    #[serde(rename_all = "camelCase")]
+    ToolInputStart {
+        /// ID of the tool call.
+        tool_call_id: String,
+
+        /// Name of the tool.
+        tool_name: String,
+
+        /// Whether the provider executed this tool.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        provider_executed: Option<bool>,
+
+        /// Whether this is a dynamic tool.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        dynamic: Option<bool>,
+
+        /// Optional title for the tool.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+    },
+
+    /// A delta of a streamed tool input.
+    #[serde(rename_all = "camelCase")]
+    ToolInputDelta {
+        /// ID of the tool call.
+        tool_call_id: String,
+
+        /// The incremental input text.
+        input_text_delta: String,
+    },
+
+    /// The complete, parsed input of a tool call.
+    #[serde(rename_all = "camelCase")]
+    ToolInputAvailable {
+        /// ID of the tool call.
+        tool_call_id: String,
+
+        /// Name of the tool.
+        tool_name: String,
+
+        /// The tool input.
+        input: Value,
+
+        /// Whether the provider executed this tool.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        provider_executed: Option<bool>,
+
+        /// Provider-specific metadata.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        provider_metadata: Option<SharedProviderMetadata>,
+    },
+
+    /// A tool call whose input could not be parsed or validated.
+    #[serde(rename_all = "camelCase")]
+    ToolInputError {
+        /// ID of the tool call.
+        tool_call_id: String,
+
+        /// Name of the tool.
+        tool_name: String,
+
+        /// The invalid tool input.
+        input: Value,
+
+        /// Description of the error.
+        error_text: String,
+
+        /// Whether the provider executed this tool.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        provider_executed: Option<bool>,
+
+        /// Provider-specific metadata.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        provider_metadata: Option<SharedProviderMetadata>,
+    },
+
+    /// A request to approve a tool call before it is executed.
+    #[serde(rename_all = "camelCase")]
+    ToolApprovalRequest {
+        /// ID of the approval request.
+        approval_id: String,
+
+        /// ID of the tool call.
+        tool_call_id: String,
+    },
+
+    /// The output of a tool call.
+    #[serde(rename_all = "camelCase")]
+    ToolOutputAvailable {
+        /// ID of the tool call.
+        tool_call_id: String,
+
+        /// The tool output.
+        output: Value,
+
+        /// Whether the provider executed this tool.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        provider_executed: Option<bool>,
+
+        /// Whether this is a preliminary output that a later one replaces.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        preliminary: Option<bool>,
+    },
+
+    /// A tool call that failed during execution.
+    #[serde(rename_all = "camelCase")]
+    ToolOutputError {
+        /// ID of the tool call.
+        tool_call_id: String,
+
+        /// Description of the error.
+        error_text: String,
+
+        /// Whether the provider executed this tool.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        provider_executed: Option<bool>,
+    },
+
+    /// A tool call whose execution was denied.
+    #[serde(rename_all = "camelCase")]
+    ToolOutputDenied {
+        /// ID of the tool call.
+        tool_call_id: String,
+    },
+
+    /// A URL source.
+    #[serde(rename_all = "camelCase")]
+    SourceUrl {
+        /// ID of the source.
+        source_id: String,
+
+        /// URL of the source.
+        url: String,
+
+        /// Optional title of the source.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+
+        /// Provider-specific metadata.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        provider_metadata: Option<SharedProviderMetadata>,
+    },
+
+    /// A document source.
+    #[serde(rename_all = "camelCase")]
+    SourceDocument {
+        /// ID of the source.
+        source_id: String,
+
+        /// IANA media type of the document.
+        media_type: String,
+
+        /// Title of the document.
+        title: String,
+
+        /// Optional filename of the document.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        filename: Option<String>,
+
+        /// Provider-specific metadata.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        provider_metadata: Option<SharedProviderMetadata>,
+    },
+
+    /// A generated file.
+    #[serde(rename_all = "camelCase")]
+    File {
+        /// URL of the file; generated files are sent as data URLs.
+        url: String,
+
+        /// IANA media type of the file.
+        media_type: String,
+    },
+
+    /// Metadata of the assistant message, sent while it is generated.
+    #[serde(rename_all = "camelCase")]
+    MessageMetadata {
+        /// Metadata of the assistant message.
+        message_metadata: Value,
+    },
+
+    /// An error that ended the stream.
+    #[serde(rename_all = "camelCase")]
+    Error {
+        /// Description of the error.
+        error_text: String,
+    },
+
+    /// The stream was aborted.
+    Abort,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_chunk_serialization() {
+        let chunk = UIMessageChunk::ToolInputAvailable {
+            tool_call_id: "call_1".to_string(),
+            tool_name: "get_weather".to_string(),
+            input: json!({ "city": "Paris" }),
+            provider_executed: None,
+            provider_metadata: None,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&chunk).unwrap(),
+            json!({
+                "type": "tool-input-available",
+                "toolCallId": "call_1",
+                "toolName": "get_weather",
+                "input": { "city": "Paris" }
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(UIMessageChunk::StartStep).unwrap(),
+            json!({ "type": "start-step" })
+        );
+    }
+
+    #[test]
+    fn test_chunk_deserialization() {
+        let chunk: UIMessageChunk =
+            serde_json::from_value(json!({ "type": "tool-output-denied", "toolCallId": "call_1" }))
+                .unwrap();
+
+        assert_eq!(
+            chunk,
+            UIMessageChunk::ToolOutputDenied {
+                tool_call_id: "call_1".to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use super::{TEXT_STREAM_HEADERS, UI_MESSAGE_STREAM_HEADERS, UIMessageStreamOptions};
+use crate::stream_text::{AsyncIterableStream, StreamTextResult};
+use ::hyper::Response;
+use ::hyper::body::Frame;
+use ::hyper::header::{HeaderName, HeaderValue};
+use bytes::Bytes;
+use futures_util::StreamExt;
+use http_body_util::combinators::UnsyncBoxBody;
+use http_body_util::{BodyExt, StreamBody};
+use std::convert::Infallible;
+
+/// Body of the streaming responses.
+pub type StreamingBody = UnsyncBoxBody<Bytes, Infallible>;
+
+/// Creates a streaming response with the UI message stream of a result.
+///
+/// # Example
+///
+/// ```ignore
+/// use llm_kit_core::ui_message_stream::{UIMessageStreamOptions, hyper::ui_message_stream_response};
+///
+/// let result = StreamText::new(model, prompt).execute().await?;
+/// let response = ui_message_stream_response(&result, UIMessageStreamOptions::new());
+/// ```
+pub fn ui_message_stream_response(
+    result: &StreamTextResult,
+    options: UIMessageStreamOptions,
+) -> Response<StreamingBody> {
+    streaming_response(
+        result.ui_message_sse_stream(options),
+        &UI_MESSAGE_STREAM_HEADERS,
+    )
+}
+
+/// Creates a streaming response with the plain text deltas of a result.
+pub fn text_stream_response(result: &StreamTextResult) -> Response<StreamingBody> {
+    streaming_response(result.text_stream(), &TEXT_STREAM_HEADERS)
+}
+
+/// Creates a response that streams the given body with the given headers.
+fn streaming_response(
+    body: AsyncIterableStream<String>,
+    headers: &[(&'static str, &'static str)],
+) -> Response<StreamingBody> {
+    let frames = body.map(|data| Ok::<_, Infallible>(Frame::data(Bytes::from(data))));
+    let mut response = Response::new(StreamBody::new(frames).boxed_unsync());
+    for (name, value) in headers {
+        response.headers_mut().insert(
+            HeaderName::from_static(name),
+            HeaderValue::from_static(value),
+        );
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stream_text::TextStreamPart;
+
+    fn result() -> StreamTextResult {
+        StreamTextResult::new(Box::pin(futures::stream::iter(vec![
+            TextStreamPart::TextDelta {
+                id: "0".to_string(),
+                provider_metadata: None,
+                text: "Hi".to_string(),
+            },
+        ])))
+    }
+
+    #[tokio::test]
+    async fn test_ui_message_stream_response() {
+        let response = ui_message_stream_response(&result(), UIMessageStreamOptions::new());
+
+        assert_eq!(response.headers()["content-type"], "text/event-stream");
+        assert_eq!(response.headers()["cache-control"], "no-cache");
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(
+            body,
+            "data: {\"type\":\"text-delta\",\"id\":\"0\",\"delta\":\"Hi\"}\n\ndata: [DONE]\n\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_text_stream_response() {
+        let response = text_stream_response(&result());
+
+        assert_eq!(
+            response.headers()["content-type"],
+            "text/plain; charset=utf-8"
+        );
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(body, "Hi");
+    }
+}
@@ -37,10 +37,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Process the stream
     while let Some(part) = result.stream.next().await {
         match part {
-            LanguageModelStreamPart::StreamStart(start) => {
-                if !start.warnings.is_empty() {
-                    println!("Warnings: {:?}", start.warnings);
-                }
+            LanguageModelStreamPart::StreamStart(start) if !start.warnings.is_empty() => {
+                println!("Warnings: {:?}", start.warnings);
             }
             LanguageModelStreamPart::TextDelta(delta) => {
                 print!("{}", delta.delta);
@@ -33,6 +33,7 @@ impl DeepSeekChatLanguageModel {
             include_usage: true, // DeepSeek always includes usage
             supports_structured_outputs: false,
             supported_urls: None,
+            http_client: config.http_client,
         };
 
         let inner = OpenAICompatibleChatLanguageModel::new(
@@ -88,6 +89,7 @@ mod tests {
             provider: "deepseek.chat".to_string(),
             base_url: "https://api.deepseek.com/v1".to_string(),
             headers: Box::new(HashMap::new),
+            http_client: reqwest::Client::new(),
         }
     }
 
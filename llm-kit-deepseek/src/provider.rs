@@ -13,12 +13,18 @@ use crate::settings::DeepSeekProviderSettings;
 /// Provides methods to create language models for DeepSeek's chat and reasoning models.
 pub struct DeepSeekProvider {
     settings: DeepSeekProviderSettings,
+    http_client: reqwest::Client,
 }
 
 impl DeepSeekProvider {
     /// Creates a new DeepSeek provider.
     pub fn new(settings: DeepSeekProviderSettings) -> Self {
-        Self { settings }
+        let http_client = settings.http_client.clone().unwrap_or_default();
+
+        Self {
+            settings,
+            http_client,
+        }
     }
 
     /// Returns the provider name.
@@ -75,6 +81,7 @@ impl DeepSeekProvider {
 
                 headers
             }),
+            http_client: self.http_client.clone(),
         }
     }
 
@@ -89,6 +96,7 @@ pub struct DeepSeekChatConfig {
     pub provider: String,
     pub base_url: String,
     pub headers: Box<dyn Fn() -> HashMap<String, String> + Send + Sync>,
+    pub http_client: reqwest::Client,
 }
 
 // Implement the Provider trait for compatibility with the provider interface
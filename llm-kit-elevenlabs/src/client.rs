@@ -36,6 +36,7 @@ pub struct ElevenLabsClient {
     base_url: Option<String>,
     api_key: Option<String>,
     headers: HashMap<String, String>,
+    http_client: Option<reqwest::Client>,
 }
 
 impl ElevenLabsClient {
@@ -53,6 +54,7 @@ impl ElevenLabsClient {
             base_url: None,
             api_key: None,
             headers: HashMap::new(),
+            http_client: None,
         }
     }
 
@@ -125,6 +127,30 @@ impl ElevenLabsClient {
         self
     }
 
+    /// Set the HTTP client used for all API requests of the provider.
+    ///
+    /// Use this to share a connection pool or to configure proxies, timeouts,
+    /// TLS roots or a user agent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use llm_kit_elevenlabs::ElevenLabsClient;
+    /// use std::time::Duration;
+    ///
+    /// let http_client = reqwest::Client::builder()
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let client = ElevenLabsClient::new()
+    ///     .http_client(http_client);
+    /// ```
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
     /// Build the ElevenLabs provider.
     ///
     /// # Examples
@@ -151,6 +177,10 @@ impl ElevenLabsClient {
             settings = settings.with_headers(self.headers);
         }
 
+        if let Some(http_client) = self.http_client {
+            settings = settings.with_http_client(http_client);
+        }
+
         ElevenLabsProvider::new(settings)
     }
 }
@@ -11,6 +11,9 @@ pub struct ElevenLabsConfig {
 
     /// Headers to include in all requests
     pub headers: HashMap<String, String>,
+
+    /// HTTP client used to send requests
+    pub http_client: reqwest::Client,
 }
 
 impl ElevenLabsConfig {
@@ -24,9 +27,16 @@ impl ElevenLabsConfig {
             provider: provider.into(),
             base_url: base_url.into(),
             headers,
+            http_client: reqwest::Client::new(),
         }
     }
 
+    /// Set the HTTP client used to send requests.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = http_client;
+        self
+    }
+
     /// Build the full URL for a given path.
     pub fn url(&self, path: &str) -> String {
         format!("{}{}", self.base_url, path)
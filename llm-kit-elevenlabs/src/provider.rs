@@ -13,12 +13,18 @@ use std::sync::Arc;
 #[derive(Clone)]
 pub struct ElevenLabsProvider {
     settings: ElevenLabsProviderSettings,
+    http_client: reqwest::Client,
 }
 
 impl ElevenLabsProvider {
     /// Create a new ElevenLabs provider with settings.
     pub fn new(settings: ElevenLabsProviderSettings) -> Self {
-        Self { settings }
+        let http_client = settings.http_client.clone().unwrap_or_default();
+
+        Self {
+            settings,
+            http_client,
+        }
     }
 
     /// Get headers for API requests.
@@ -38,11 +44,10 @@ impl ElevenLabsProvider {
     /// Create a speech model configuration.
     fn create_speech_config(&self) -> Result<ElevenLabsConfig, String> {
         let headers = self.get_headers()?;
-        Ok(ElevenLabsConfig::new(
-            "elevenlabs.speech",
-            self.settings.get_base_url(),
-            headers,
-        ))
+        Ok(
+            ElevenLabsConfig::new("elevenlabs.speech", self.settings.get_base_url(), headers)
+                .with_http_client(self.http_client.clone()),
+        )
     }
 
     /// Create a transcription model configuration.
@@ -52,7 +57,8 @@ impl ElevenLabsProvider {
             "elevenlabs.transcription",
             self.settings.get_base_url(),
             headers,
-        ))
+        )
+        .with_http_client(self.http_client.clone()))
     }
 }
 
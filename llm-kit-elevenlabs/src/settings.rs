@@ -13,6 +13,10 @@ pub struct ElevenLabsProviderSettings {
     /// Base URL for the ElevenLabs API.
     /// Defaults to <https://api.elevenlabs.io>.
     pub base_url: Option<String>,
+
+    /// Optional HTTP client shared by all models of the provider. Use it to configure
+    /// proxies, timeouts, TLS roots or a user agent. Defaults to a new `reqwest::Client`.
+    pub http_client: Option<reqwest::Client>,
 }
 
 impl ElevenLabsProviderSettings {
@@ -22,6 +26,7 @@ impl ElevenLabsProviderSettings {
             api_key: None,
             headers: None,
             base_url: None,
+            http_client: None,
         }
     }
 
@@ -50,6 +55,12 @@ impl ElevenLabsProviderSettings {
         self
     }
 
+    /// Set the HTTP client used for API requests.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
     /// Get the base URL (defaults to ElevenLabs API URL).
     pub fn get_base_url(&self) -> String {
         self.base_url
@@ -6,6 +6,7 @@ use super::provider_options::ElevenLabsSpeechProviderOptions;
 use crate::config::ElevenLabsConfig;
 use crate::error::parse_elevenlabs_error;
 use async_trait::async_trait;
+use llm_kit_provider::error::ProviderError;
 use llm_kit_provider::speech_model::{
     AudioData, SpeechModel, SpeechModelRequestMetadata, SpeechModelResponse,
     SpeechModelResponseMetadata, call_options::SpeechModelCallOptions,
@@ -232,7 +233,7 @@ impl SpeechModel for ElevenLabsSpeechModel {
         }
 
         // Make the API request
-        let client = reqwest::Client::new();
+        let client = &self.config.http_client;
         let mut request_builder = client.post(&url).json(&request_body);
 
         // Add headers
@@ -253,7 +254,17 @@ impl SpeechModel for ElevenLabsSpeechModel {
             let error_body = response.text().await?;
             let error_message = parse_elevenlabs_error(&error_body)
                 .unwrap_or_else(|| format!("HTTP {}: {}", status, error_body));
-            return Err(error_message.into());
+            return Err(Box::new(ProviderError::api_call_error_with_details(
+                error_message,
+                url,
+                serde_json::to_string(&request_body).unwrap_or_default(),
+                Some(status.as_u16()),
+                Some(response_headers),
+                Some(error_body),
+                None, // Auto-determine retryability based on status code
+                None,
+                None,
+            )));
         }
 
         // Get audio data as bytes
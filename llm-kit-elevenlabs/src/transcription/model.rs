@@ -4,6 +4,7 @@ use super::provider_options::ElevenLabsTranscriptionProviderOptions;
 use crate::config::ElevenLabsConfig;
 use crate::error::parse_elevenlabs_error;
 use async_trait::async_trait;
+use llm_kit_provider::error::ProviderError;
 use llm_kit_provider::transcription_model::{
     TranscriptSegment, TranscriptionModel, TranscriptionModelResponse,
     TranscriptionModelResponseMetadata, call_options::TranscriptionModelCallOptions,
@@ -157,7 +158,7 @@ impl TranscriptionModel for ElevenLabsTranscriptionModel {
         }
 
         // Make the API request
-        let client = reqwest::Client::new();
+        let client = &self.config.http_client;
         let mut request_builder = client.post(&url).multipart(form);
 
         // Add headers
@@ -178,7 +179,17 @@ impl TranscriptionModel for ElevenLabsTranscriptionModel {
             let error_body = response.text().await?;
             let error_message = parse_elevenlabs_error(&error_body)
                 .unwrap_or_else(|| format!("HTTP {}: {}", status, error_body));
-            return Err(error_message.into());
+            return Err(Box::new(ProviderError::api_call_error_with_details(
+                error_message,
+                url,
+                String::new(), // Multipart form data is not recorded
+                Some(status.as_u16()),
+                Some(response_headers),
+                Some(error_body),
+                None, // Auto-determine retryability based on status code
+                None,
+                None,
+            )));
         }
 
         // Parse response
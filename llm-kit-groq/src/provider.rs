@@ -15,12 +15,18 @@ use llm_kit_openai_compatible::OpenAICompatibleChatConfig;
 /// Provides methods to create language models for Groq's chat models.
 pub struct GroqProvider {
     settings: GroqProviderSettings,
+    http_client: reqwest::Client,
 }
 
 impl GroqProvider {
     /// Creates a new Groq provider.
     pub fn new(settings: GroqProviderSettings) -> Self {
-        Self { settings }
+        let http_client = settings.http_client.clone().unwrap_or_default();
+
+        Self {
+            settings,
+            http_client,
+        }
     }
 
     /// Returns the provider name.
@@ -134,6 +140,7 @@ impl GroqProvider {
                 );
                 map
             }),
+            http_client: self.http_client.clone(),
         }
     }
 
@@ -161,6 +168,7 @@ impl GroqProvider {
 
                 headers
             }),
+            http_client: self.http_client.clone(),
         }
     }
 
@@ -188,6 +196,7 @@ impl GroqProvider {
 
                 headers
             }),
+            http_client: self.http_client.clone(),
         }
     }
 
@@ -13,6 +13,10 @@ pub struct GroqProviderSettings {
     /// Optional custom headers to include in requests. These will be added to request headers
     /// after any headers potentially added by use of the `api_key` option.
     pub headers: Option<HashMap<String, String>>,
+
+    /// Optional HTTP client shared by all models of the provider. Use it to configure
+    /// proxies, timeouts, TLS roots or a user agent. Defaults to a new `reqwest::Client`.
+    pub http_client: Option<reqwest::Client>,
 }
 
 impl Default for GroqProviderSettings {
@@ -21,6 +25,7 @@ impl Default for GroqProviderSettings {
             base_url: "https://api.groq.com/openai/v1".to_string(),
             api_key: None,
             headers: None,
+            http_client: None,
         }
     }
 }
@@ -64,6 +69,12 @@ impl GroqProviderSettings {
         self.headers = Some(headers);
         self
     }
+
+    /// Sets the HTTP client used for API requests.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
 }
 
 #[cfg(test)]
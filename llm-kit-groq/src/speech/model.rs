@@ -1,5 +1,6 @@
 use super::options::GroqSpeechOptions;
 use async_trait::async_trait;
+use llm_kit_provider::error::ProviderError;
 use llm_kit_provider::speech_model::{
     AudioData, SpeechModel, SpeechModelRequestMetadata, SpeechModelResponse,
     SpeechModelResponseMetadata, call_options::SpeechModelCallOptions,
@@ -16,6 +17,7 @@ pub struct GroqSpeechConfig {
     pub provider: String,
     pub base_url: String,
     pub headers: Arc<dyn Fn() -> HashMap<String, String> + Send + Sync>,
+    pub http_client: reqwest::Client,
 }
 
 /// Groq speech model implementation.
@@ -155,7 +157,7 @@ impl SpeechModel for GroqSpeechModel {
         }
 
         // Make the API request
-        let client = reqwest::Client::new();
+        let client = &self.config.http_client;
         let mut request_builder = client.post(&url).json(&request_body);
 
         // Add headers
@@ -174,7 +176,17 @@ impl SpeechModel for GroqSpeechModel {
         // Check for errors
         if !status.is_success() {
             let error_body = response.text().await?;
-            return Err(format!("Groq TTS API error ({}): {}", status, error_body).into());
+            return Err(Box::new(ProviderError::api_call_error_with_details(
+                format!("Groq TTS API error ({}): {}", status, error_body),
+                url,
+                serde_json::to_string(&request_body).unwrap_or_default(),
+                Some(status.as_u16()),
+                Some(response_headers),
+                Some(error_body),
+                None, // Auto-determine retryability based on status code
+                None,
+                None,
+            )));
         }
 
         // Get audio data as bytes
@@ -218,6 +230,7 @@ mod tests {
                 headers.insert("Authorization".to_string(), "Bearer test-key".to_string());
                 headers
             }),
+            http_client: reqwest::Client::new(),
         };
 
         let model = GroqSpeechModel::new("playai-tts".to_string(), config);
@@ -231,6 +244,7 @@ mod tests {
             provider: "groq.speech".to_string(),
             base_url: "https://api.groq.com/openai/v1".to_string(),
             headers: Arc::new(HashMap::new),
+            http_client: reqwest::Client::new(),
         };
 
         let model = GroqSpeechModel::new("playai-tts".to_string(), config);
@@ -254,6 +268,7 @@ mod tests {
             provider: "groq.speech".to_string(),
             base_url: "https://api.groq.com/openai/v1".to_string(),
             headers: Arc::new(HashMap::new),
+            http_client: reqwest::Client::new(),
         };
 
         let model = GroqSpeechModel::new("playai-tts".to_string(), config);
@@ -271,6 +286,7 @@ mod tests {
             provider: "groq.speech".to_string(),
             base_url: "https://api.groq.com/openai/v1".to_string(),
             headers: Arc::new(HashMap::new),
+            http_client: reqwest::Client::new(),
         };
 
         let model = GroqSpeechModel::new("playai-tts".to_string(), config);
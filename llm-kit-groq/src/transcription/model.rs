@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use llm_kit_provider::error::ProviderError;
 use llm_kit_provider::transcription_model::call_options::TranscriptionModelCallOptions;
 use llm_kit_provider::transcription_model::call_warning::TranscriptionModelCallWarning;
 use llm_kit_provider::transcription_model::{
@@ -17,6 +18,7 @@ pub struct GroqTranscriptionConfig {
     pub provider: String,
     pub base_url: String,
     pub headers: Box<dyn Fn() -> HashMap<String, String> + Send + Sync>,
+    pub http_client: reqwest::Client,
 }
 
 /// Groq transcription model implementation.
@@ -123,7 +125,7 @@ impl TranscriptionModel for GroqTranscriptionModel {
         let url = format!("{}/audio/transcriptions", self.config.base_url);
         let headers = (self.config.headers)();
 
-        let client = reqwest::Client::new();
+        let client = &self.config.http_client;
         let mut request = client.post(&url).multipart(form);
 
         // Add headers
@@ -165,7 +167,8 @@ async fn process_response(
     warnings: Vec<TranscriptionModelCallWarning>,
 ) -> Result<TranscriptionModelResponse, Box<dyn std::error::Error>> {
     let status = response.status();
-    let headers_map = response
+    let url = response.url().to_string();
+    let headers_map: HashMap<String, String> = response
         .headers()
         .iter()
         .map(|(k, v)| (k.to_string(), v.to_str().unwrap_or("").to_string()))
@@ -173,7 +176,17 @@ async fn process_response(
 
     if !status.is_success() {
         let error_text = response.text().await?;
-        return Err(format!("Groq API error ({}): {}", status, error_text).into());
+        return Err(Box::new(ProviderError::api_call_error_with_details(
+            format!("Groq API error ({}): {}", status, error_text),
+            url,
+            String::new(), // Multipart form data is not recorded
+            Some(status.as_u16()),
+            Some(headers_map),
+            Some(error_text),
+            None, // Auto-determine retryability based on status code
+            None,
+            None,
+        )));
     }
 
     let body_text = response.text().await?;
@@ -20,6 +20,9 @@ pub struct HuggingFaceClientConfig {
 
     /// Function to generate headers for API requests.
     pub headers: HeadersGeneratorFn,
+
+    /// HTTP client used to send requests to the API.
+    pub http_client: reqwest::Client,
 }
 
 impl HuggingFaceClientConfig {
@@ -33,8 +36,15 @@ impl HuggingFaceClientConfig {
             provider: provider.into(),
             url,
             headers,
+            http_client: reqwest::Client::new(),
         }
     }
+
+    /// Sets the HTTP client used to send requests to the API.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = http_client;
+        self
+    }
 }
 
 /// Builder for creating a Hugging Face client.
@@ -85,6 +95,7 @@ pub struct HuggingFaceClient {
     base_url: Option<String>,
     api_key: Option<String>,
     headers: HashMap<String, String>,
+    http_client: Option<reqwest::Client>,
 }
 
 impl HuggingFaceClient {
@@ -180,6 +191,30 @@ impl HuggingFaceClient {
         self
     }
 
+    /// Sets the HTTP client used for all API requests of the provider.
+    ///
+    /// Use this to share a connection pool or to configure proxies, timeouts,
+    /// TLS roots or a user agent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use llm_kit_huggingface::HuggingFaceClient;
+    /// use std::time::Duration;
+    ///
+    /// let http_client = reqwest::Client::builder()
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let client = HuggingFaceClient::new()
+    ///     .http_client(http_client);
+    /// ```
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
     /// Builds the `HuggingFaceProvider` with the configured settings.
     ///
     /// # Returns
@@ -214,6 +249,10 @@ impl HuggingFaceClient {
             settings = settings.with_header(key, value);
         }
 
+        if let Some(http_client) = self.http_client {
+            settings = settings.with_http_client(http_client);
+        }
+
         HuggingFaceProvider::new(settings)
     }
 }
@@ -11,12 +11,18 @@ use crate::settings::HuggingFaceProviderSettings;
 /// Hugging Face provider implementation.
 pub struct HuggingFaceProvider {
     settings: HuggingFaceProviderSettings,
+    http_client: reqwest::Client,
 }
 
 impl HuggingFaceProvider {
     /// Creates a new Hugging Face provider.
     pub fn new(settings: HuggingFaceProviderSettings) -> Self {
-        Self { settings }
+        let http_client = settings.http_client.clone().unwrap_or_default();
+
+        Self {
+            settings,
+            http_client,
+        }
     }
 
     /// Creates a Hugging Face Responses API language model.
@@ -62,6 +68,7 @@ impl HuggingFaceProvider {
                 headers
             }),
         )
+        .with_http_client(self.http_client.clone())
     }
 }
 
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use futures_util::{Stream, StreamExt};
+use llm_kit_provider::error::ProviderError;
 use llm_kit_provider::language_model::{
     LanguageModel, LanguageModelGenerateResponse, LanguageModelRequestMetadata,
     LanguageModelStreamResponse, call_options::LanguageModelCallOptions,
@@ -7,6 +8,7 @@ use llm_kit_provider::language_model::{
     response_metadata::LanguageModelResponseMetadata, stream_part::LanguageModelStreamPart,
     usage::LanguageModelUsage,
 };
+use llm_kit_provider_utils::event_source::parse_json_event_stream;
 use regex::Regex;
 use reqwest;
 use serde::Deserialize;
@@ -170,50 +172,26 @@ impl HuggingFaceResponsesLanguageModel {
         byte_stream: impl Stream<Item = Result<bytes::Bytes, reqwest::Error>> + Send + 'static,
         warnings: Vec<LanguageModelCallWarning>,
     ) -> impl Stream<Item = LanguageModelStreamPart> + Unpin + Send {
-        let mut buffer = String::new();
         let mut state = StreamState::default();
 
         Box::pin(async_stream::stream! {
             // Emit stream start with warnings
             yield LanguageModelStreamPart::stream_start(warnings);
 
-            let mut stream = Box::pin(byte_stream);
+            let mut events = Box::pin(parse_json_event_stream::<HuggingFaceStreamChunk, _, _, _>(byte_stream));
 
-            while let Some(result) = stream.next().await {
+            while let Some(result) = events.next().await {
                 match result {
-                    Ok(bytes) => {
-                        // Append to buffer
-                        if let Ok(text) = std::str::from_utf8(&bytes) {
-                            buffer.push_str(text);
-
-                            // Process complete lines
-                            while let Some(pos) = buffer.find('\n') {
-                                let line = buffer[..pos].trim().to_string();
-                                buffer.drain(..=pos);
-
-                                // Skip empty lines
-                                if line.is_empty() {
-                                    continue;
-                                }
-
-                                // Parse SSE format
-                                if let Some(data) = line.strip_prefix("data: ") {
-                                    // Check for [DONE] marker
-                                    if data == "[DONE]" {
-                                        break;
-                                    }
-
-                                    // Parse JSON chunk
-                                    if let Ok(chunk) = serde_json::from_str::<HuggingFaceStreamChunk>(data) {
-                                        // Process the chunk and emit stream parts
-                                        for part in Self::process_chunk(&mut state, chunk) {
-                                            yield part;
-                                        }
-                                    }
-                                }
-                            }
+                    Ok(Ok(chunk)) => {
+                        // Process the chunk and emit stream parts
+                        for part in Self::process_chunk(&mut state, chunk) {
+                            yield part;
                         }
                     }
+                    Ok(Err(error)) => {
+                        // Skip chunks that don't match the schema, e.g. of new event types
+                        log::warn!("Skipping unrecognized stream chunk: {}", error);
+                    }
                     Err(e) => {
                         yield LanguageModelStreamPart::error(json!({ "message": e.to_string() }));
                         break;
@@ -356,6 +334,42 @@ impl HuggingFaceResponsesLanguageModel {
     }
 }
 
+/// Converts a failed response into an API call error.
+async fn failed_response_error(
+    response: reqwest::Response,
+    url: &str,
+    body: &Value,
+) -> Box<dyn std::error::Error> {
+    let status = response.status();
+    let response_headers: HashMap<String, String> = response
+        .headers()
+        .iter()
+        .filter_map(|(k, v)| v.to_str().ok().map(|v| (k.to_string(), v.to_string())))
+        .collect();
+    let error_body = match response.text().await {
+        Ok(error_body) => error_body,
+        Err(error) => return Box::new(error),
+    };
+
+    // Try to parse as HuggingFace error
+    let message = match serde_json::from_str::<HuggingFaceErrorData>(&error_body) {
+        Ok(hf_error) => format!("API error: {}", hf_error.message()),
+        Err(_) => format!("API request failed with status {}: {}", status, error_body),
+    };
+
+    Box::new(ProviderError::api_call_error_with_details(
+        message,
+        url,
+        body.to_string(),
+        Some(status.as_u16()),
+        Some(response_headers),
+        Some(error_body),
+        None, // Auto-determine retryability based on status code
+        None,
+        None,
+    ))
+}
+
 /// Helper struct to track streaming state across chunks.
 #[derive(Default)]
 struct StreamState {
@@ -504,6 +518,10 @@ impl LanguageModel for HuggingFaceResponsesLanguageModel {
         map
     }
 
+    fn supports_structured_outputs(&self) -> bool {
+        true
+    }
+
     async fn do_generate(
         &self,
         options: LanguageModelCallOptions,
@@ -521,7 +539,7 @@ impl LanguageModel for HuggingFaceResponsesLanguageModel {
         }
 
         // Make API request
-        let client = reqwest::Client::new();
+        let client = &self.config.http_client;
         let mut request = client.post(&url).header("Content-Type", "application/json");
 
         for (key, value) in headers {
@@ -529,19 +547,8 @@ impl LanguageModel for HuggingFaceResponsesLanguageModel {
         }
 
         let response = request.body(serde_json::to_string(&body)?).send().await?;
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_body = response.text().await?;
-
-            // Try to parse as HuggingFace error
-            if let Ok(hf_error) = serde_json::from_str::<HuggingFaceErrorData>(&error_body) {
-                return Err(format!("API error: {}", hf_error.message()).into());
-            }
-
-            return Err(
-                format!("API request failed with status {}: {}", status, error_body).into(),
-            );
+        if !response.status().is_success() {
+            return Err(failed_response_error(response, &url, &body).await);
         }
 
         let response_body = response.text().await?;
@@ -684,7 +691,7 @@ impl LanguageModel for HuggingFaceResponsesLanguageModel {
         }
 
         // Make streaming API request
-        let client = reqwest::Client::new();
+        let client = &self.config.http_client;
         let mut request = client.post(&url).header("Content-Type", "application/json");
 
         for (key, value) in headers {
@@ -692,19 +699,8 @@ impl LanguageModel for HuggingFaceResponsesLanguageModel {
         }
 
         let response = request.body(serde_json::to_string(&body)?).send().await?;
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_body = response.text().await?;
-
-            // Try to parse as HuggingFace error
-            if let Ok(hf_error) = serde_json::from_str::<HuggingFaceErrorData>(&error_body) {
-                return Err(format!("API error: {}", hf_error.message()).into());
-            }
-
-            return Err(
-                format!("API request failed with status {}: {}", status, error_body).into(),
-            );
+        if !response.status().is_success() {
+            return Err(failed_response_error(response, &url, &body).await);
         }
 
         // Create the stream processor
@@ -13,6 +13,10 @@ pub struct HuggingFaceProviderSettings {
 
     /// Custom headers to include in all requests.
     pub headers: Option<HashMap<String, String>>,
+
+    /// Optional HTTP client shared by all models of the provider. Use it to configure
+    /// proxies, timeouts, TLS roots or a user agent. Defaults to a new `reqwest::Client`.
+    pub http_client: Option<reqwest::Client>,
 }
 
 impl HuggingFaceProviderSettings {
@@ -22,6 +26,7 @@ impl HuggingFaceProviderSettings {
             api_key: None,
             base_url: "https://router.huggingface.co/v1".to_string(),
             headers: None,
+            http_client: None,
         }
     }
 
@@ -47,6 +52,12 @@ impl HuggingFaceProviderSettings {
         self
     }
 
+    /// Sets the HTTP client used for API requests.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
     /// Loads API key from environment variable if not already set.
     pub fn load_api_key_from_env(mut self) -> Self {
         if self.api_key.is_none() {
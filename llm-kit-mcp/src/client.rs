@@ -0,0 +1,487 @@
+use crate::error::McpError;
+use crate::transport::McpTransport;
+use crate::types::{
+    CallToolResult, GetPromptResult, Implementation, InitializeResult, JsonRpcError,
+    JsonRpcMessage, JsonRpcNotification, JsonRpcRequest, JsonRpcResponse, LATEST_PROTOCOL_VERSION,
+    McpPrompt, McpResource, McpResourceTemplate, McpTool, ReadResourceResult, RequestId,
+    ServerCapabilities,
+};
+use llm_kit_core::tool::ToolSet;
+use llm_kit_provider_utils::tool::{Tool, ToolExecuteOptions, ToolExecutionOutput};
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc, oneshot};
+use tokio_util::sync::CancellationToken;
+
+/// Number of notifications kept for subscribers that fall behind.
+const NOTIFICATION_CAPACITY: usize = 64;
+
+/// Options for connecting an [`McpClient`].
+#[derive(Debug, Clone)]
+pub struct McpClientOptions {
+    /// The name and version the client reports to the server.
+    pub client_info: Implementation,
+
+    /// How long to wait for the response to a request. `None` waits forever.
+    pub request_timeout: Option<Duration>,
+}
+
+impl Default for McpClientOptions {
+    fn default() -> Self {
+        Self {
+            client_info: Implementation::new("llm-kit", env!("CARGO_PKG_VERSION")),
+            request_timeout: Some(Duration::from_secs(60)),
+        }
+    }
+}
+
+impl McpClientOptions {
+    /// Creates the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name and version the client reports to the server.
+    pub fn with_client_info(mut self, client_info: Implementation) -> Self {
+        self.client_info = client_info;
+        self
+    }
+
+    /// Sets how long to wait for the response to a request.
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Waits for responses without a time limit.
+    pub fn without_request_timeout(mut self) -> Self {
+        self.request_timeout = None;
+        self
+    }
+}
+
+/// A client for a Model Context Protocol server.
+///
+/// The client performs the initialization handshake when it connects, answers
+/// pings from the server, and delivers server notifications to subscribers of
+/// [`notifications`](McpClient::notifications). Clones share the connection.
+///
+/// # Example
+///
+/// ```no_run
+/// use llm_kit_mcp::{McpClient, StdioTransport};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = McpClient::connect(
+///     StdioTransport::new("npx").with_args(["-y", "@modelcontextprotocol/server-everything"]),
+/// )
+/// .await?;
+///
+/// for tool in client.list_tools().await? {
+///     println!("{}: {:?}", tool.name, tool.description);
+/// }
+///
+/// client.close().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct McpClient {
+    inner: Arc<ClientInner>,
+}
+
+/// State shared by the clones of a client and its message dispatcher.
+struct ClientInner {
+    transport: Arc<dyn McpTransport>,
+    next_id: AtomicI64,
+    pending: Mutex<HashMap<RequestId, oneshot::Sender<Result<Value, McpError>>>>,
+    notifications: broadcast::Sender<JsonRpcNotification>,
+    server: OnceLock<InitializeResult>,
+    request_timeout: Option<Duration>,
+}
+
+impl McpClient {
+    /// Connects to a server with the default options.
+    pub async fn connect(transport: impl McpTransport + 'static) -> Result<Self, McpError> {
+        Self::connect_with_options(transport, McpClientOptions::default()).await
+    }
+
+    /// Connects to a server and performs the initialization handshake.
+    pub async fn connect_with_options(
+        transport: impl McpTransport + 'static,
+        options: McpClientOptions,
+    ) -> Result<Self, McpError> {
+        let (incoming_tx, incoming_rx) = mpsc::unbounded_channel();
+        let (notifications, _) = broadcast::channel(NOTIFICATION_CAPACITY);
+
+        let client = Self {
+            inner: Arc::new(ClientInner {
+                transport: Arc::new(transport),
+                next_id: AtomicI64::new(0),
+                pending: Mutex::new(HashMap::new()),
+                notifications,
+                server: OnceLock::new(),
+                request_timeout: options.request_timeout,
+            }),
+        };
+
+        tokio::spawn(dispatch(Arc::downgrade(&client.inner), incoming_rx));
+        client.inner.transport.start(incoming_tx).await?;
+
+        let result: InitializeResult = client
+            .request(
+                "initialize",
+                Some(json!({
+                    "protocolVersion": LATEST_PROTOCOL_VERSION,
+                    "capabilities": {},
+                    "clientInfo": options.client_info,
+                })),
+                None,
+            )
+            .await?;
+        client
+            .inner
+            .transport
+            .set_protocol_version(&result.protocol_version);
+        let _ = client.inner.server.set(result);
+
+        client.notify("notifications/initialized", None).await?;
+
+        Ok(client)
+    }
+
+    /// The name and version of the server.
+    pub fn server_info(&self) -> &Implementation {
+        &self.initialize_result().server_info
+    }
+
+    /// The capabilities the server announced.
+    pub fn server_capabilities(&self) -> &ServerCapabilities {
+        &self.initialize_result().capabilities
+    }
+
+    /// The protocol version the server chose.
+    pub fn protocol_version(&self) -> &str {
+        &self.initialize_result().protocol_version
+    }
+
+    /// Instructions from the server on how to use it, e.g. for a system prompt.
+    pub fn instructions(&self) -> Option<&str> {
+        self.initialize_result().instructions.as_deref()
+    }
+
+    fn initialize_result(&self) -> &InitializeResult {
+        self.inner
+            .server
+            .get()
+            .expect("client is initialized when connected")
+    }
+
+    /// Subscribes to the notifications the server sends, such as
+    /// `notifications/tools/list_changed` or `notifications/message`.
+    ///
+    /// Only notifications sent after subscribing are received.
+    pub fn notifications(&self) -> broadcast::Receiver<JsonRpcNotification> {
+        self.inner.notifications.subscribe()
+    }
+
+    /// Checks that the server is responsive.
+    pub async fn ping(&self) -> Result<(), McpError> {
+        self.request::<Value>("ping", None, None).await.map(|_| ())
+    }
+
+    /// Lists all tools of the server.
+    pub async fn list_tools(&self) -> Result<Vec<McpTool>, McpError> {
+        self.require(self.server_capabilities().tools.is_some(), "tools")?;
+        self.list_all("tools/list", "tools").await
+    }
+
+    /// Calls a tool of the server.
+    ///
+    /// A tool that fails returns a result with `is_error` set, not an error.
+    pub async fn call_tool(
+        &self,
+        name: &str,
+        arguments: Value,
+    ) -> Result<CallToolResult, McpError> {
+        self.call_tool_with_abort_signal(name, arguments, None)
+            .await
+    }
+
+    /// Calls a tool of the server and cancels the call when the signal is triggered.
+    pub async fn call_tool_with_abort_signal(
+        &self,
+        name: &str,
+        arguments: Value,
+        abort_signal: Option<CancellationToken>,
+    ) -> Result<CallToolResult, McpError> {
+        self.request(
+            "tools/call",
+            Some(json!({ "name": name, "arguments": arguments })),
+            abort_signal,
+        )
+        .await
+    }
+
+    /// Converts the tools of the server into a [`ToolSet`].
+    ///
+    /// Each tool becomes a dynamic [`Tool`] whose `execute` calls the tool on the
+    /// server. The output of a tool is its [`CallToolResult`] as JSON; results with
+    /// `is_error` set are returned as tool errors.
+    pub async fn tools(&self) -> Result<ToolSet, McpError> {
+        let mut tool_set = ToolSet::new();
+
+        for mcp_tool in self.list_tools().await? {
+            let client = self.clone();
+            let name = mcp_tool.name.clone();
+
+            let mut tool = Tool::dynamic(mcp_tool.input_schema).with_execute(Arc::new(
+                move |input: Value, options: ToolExecuteOptions| {
+                    let client = client.clone();
+                    let name = name.clone();
+                    ToolExecutionOutput::Single(Box::pin(async move {
+                        let result = client
+                            .call_tool_with_abort_signal(&name, input, options.abort_signal)
+                            .await
+                            .map_err(|error| Value::String(error.to_string()))?;
+                        let is_error = result.is_error;
+                        let output = serde_json::to_value(result)
+                            .map_err(|error| Value::String(error.to_string()))?;
+
+                        if is_error { Err(output) } else { Ok(output) }
+                    }))
+                },
+            ));
+            if let Some(description) = mcp_tool.description.or(mcp_tool.title) {
+                tool = tool.with_description(description);
+            }
+            if let Some(output_schema) = mcp_tool.output_schema {
+                tool = tool.with_output_schema(output_schema);
+            }
+
+            tool_set.insert(mcp_tool.name, tool);
+        }
+
+        Ok(tool_set)
+    }
+
+    /// Lists all resources of the server.
+    pub async fn list_resources(&self) -> Result<Vec<McpResource>, McpError> {
+        self.require(self.server_capabilities().resources.is_some(), "resources")?;
+        self.list_all("resources/list", "resources").await
+    }
+
+    /// Lists all resource templates of the server.
+    pub async fn list_resource_templates(&self) -> Result<Vec<McpResourceTemplate>, McpError> {
+        self.require(self.server_capabilities().resources.is_some(), "resources")?;
+        self.list_all("resources/templates/list", "resourceTemplates")
+            .await
+    }
+
+    /// Reads a resource of the server.
+    pub async fn read_resource(&self, uri: &str) -> Result<ReadResourceResult, McpError> {
+        self.request("resources/read", Some(json!({ "uri": uri })), None)
+            .await
+    }
+
+    /// Lists all prompt templates of the server.
+    pub async fn list_prompts(&self) -> Result<Vec<McpPrompt>, McpError> {
+        self.require(self.server_capabilities().prompts.is_some(), "prompts")?;
+        self.list_all("prompts/list", "prompts").await
+    }
+
+    /// Gets a prompt from a prompt template of the server.
+    pub async fn get_prompt(
+        &self,
+        name: &str,
+        arguments: HashMap<String, String>,
+    ) -> Result<GetPromptResult, McpError> {
+        self.request(
+            "prompts/get",
+            Some(json!({ "name": name, "arguments": arguments })),
+            None,
+        )
+        .await
+    }
+
+    /// Closes the connection. Requests still waiting for a response fail.
+    pub async fn close(&self) -> Result<(), McpError> {
+        self.inner.transport.close().await?;
+        fail_pending(&self.inner);
+        Ok(())
+    }
+
+    fn require(&self, supported: bool, capability: &str) -> Result<(), McpError> {
+        if supported {
+            Ok(())
+        } else {
+            Err(McpError::Unsupported(capability.to_string()))
+        }
+    }
+
+    /// Sends a notification to the server.
+    async fn notify(&self, method: &str, params: Option<Value>) -> Result<(), McpError> {
+        self.inner
+            .transport
+            .send(JsonRpcMessage::Notification(JsonRpcNotification::new(
+                method, params,
+            )))
+            .await
+    }
+
+    /// Sends a request and waits for its result.
+    ///
+    /// When the request times out or the abort signal is triggered, the server is
+    /// notified that the request is cancelled.
+    async fn request<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Option<Value>,
+        abort_signal: Option<CancellationToken>,
+    ) -> Result<T, McpError> {
+        let id = RequestId::Number(self.inner.next_id.fetch_add(1, Ordering::Relaxed));
+        let (response_tx, response_rx) = oneshot::channel();
+        self.inner
+            .pending
+            .lock()
+            .unwrap()
+            .insert(id.clone(), response_tx);
+
+        let request = JsonRpcRequest::new(id.clone(), method, params);
+        if let Err(error) = self
+            .inner
+            .transport
+            .send(JsonRpcMessage::Request(request))
+            .await
+        {
+            self.inner.pending.lock().unwrap().remove(&id);
+            return Err(error);
+        }
+
+        let cancelled = async {
+            match &abort_signal {
+                Some(signal) => signal.cancelled().await,
+                None => std::future::pending().await,
+            }
+        };
+        let timeout = async {
+            match self.inner.request_timeout {
+                Some(timeout) => tokio::time::sleep(timeout).await,
+                None => std::future::pending().await,
+            }
+        };
+
+        let error = tokio::select! {
+            response = response_rx => {
+                let result = response.unwrap_or(Err(McpError::Closed))?;
+                return Ok(serde_json::from_value(result)?);
+            }
+            _ = cancelled => McpError::Cancelled(method.to_string()),
+            _ = timeout => McpError::Timeout(method.to_string()),
+        };
+
+        self.inner.pending.lock().unwrap().remove(&id);
+        let _ = self
+            .notify(
+                "notifications/cancelled",
+                Some(json!({ "requestId": id, "reason": error.to_string() })),
+            )
+            .await;
+        Err(error)
+    }
+
+    /// Requests all pages of a paginated list.
+    async fn list_all<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        key: &str,
+    ) -> Result<Vec<T>, McpError> {
+        let mut items = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let params = cursor.map(|cursor| json!({ "cursor": cursor }));
+            let mut page: Value = self.request(method, params, None).await?;
+
+            if let Some(page_items) = page.get_mut(key) {
+                items.extend(serde_json::from_value::<Vec<T>>(page_items.take())?);
+            }
+
+            cursor = page
+                .get("nextCursor")
+                .and_then(Value::as_str)
+                .map(String::from);
+            if cursor.is_none() {
+                return Ok(items);
+            }
+        }
+    }
+}
+
+/// Fails the requests still waiting for a response.
+fn fail_pending(inner: &ClientInner) {
+    for (_, response_tx) in inner.pending.lock().unwrap().drain() {
+        let _ = response_tx.send(Err(McpError::Closed));
+    }
+}
+
+/// Routes the messages received from the server until the connection closes.
+async fn dispatch(inner: Weak<ClientInner>, mut incoming: mpsc::UnboundedReceiver<JsonRpcMessage>) {
+    while let Some(message) = incoming.recv().await {
+        let Some(inner) = inner.upgrade() else {
+            return;
+        };
+
+        match message {
+            JsonRpcMessage::Response(response) => {
+                let response_tx = inner.pending.lock().unwrap().remove(&response.id);
+                let Some(response_tx) = response_tx else {
+                    log::warn!("Ignoring MCP response to unknown request {:?}", response.id);
+                    continue;
+                };
+
+                let result = match response.error {
+                    Some(error) => Err(McpError::Server {
+                        code: error.code,
+                        message: error.message,
+                        data: error.data,
+                    }),
+                    None => Ok(response.result.unwrap_or(Value::Null)),
+                };
+                let _ = response_tx.send(result);
+            }
+            JsonRpcMessage::Notification(notification) => {
+                // Sending fails only when nobody is subscribed
+                let _ = inner.notifications.send(notification);
+            }
+            JsonRpcMessage::Request(request) => {
+                let response = match request.method.as_str() {
+                    "ping" => JsonRpcResponse::success(request.id, json!({})),
+                    method => JsonRpcResponse::error(
+                        request.id,
+                        JsonRpcError {
+                            code: JsonRpcError::METHOD_NOT_FOUND,
+                            message: format!("Method not found: {}", method),
+                            data: None,
+                        },
+                    ),
+                };
+                if let Err(error) = inner
+                    .transport
+                    .send(JsonRpcMessage::Response(response))
+                    .await
+                {
+                    log::warn!("Failed to answer MCP server request: {}", error);
+                }
+            }
+        }
+    }
+
+    if let Some(inner) = inner.upgrade() {
+        fail_pending(&inner);
+    }
+}
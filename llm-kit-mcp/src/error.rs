@@ -0,0 +1,53 @@
+use serde_json::Value;
+use thiserror::Error;
+
+/// Errors that can occur when talking to an MCP server.
+#[derive(Error, Debug)]
+pub enum McpError {
+    /// The server answered a request with a JSON-RPC error.
+    #[error("MCP server error {code}: {message}")]
+    Server {
+        /// JSON-RPC error code.
+        code: i64,
+        /// Error message from the server.
+        message: String,
+        /// Additional error data from the server.
+        data: Option<Value>,
+    },
+
+    /// The transport failed to send or receive a message.
+    #[error("MCP transport error: {0}")]
+    Transport(String),
+
+    /// A message could not be serialized or deserialized.
+    #[error("MCP serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    /// The server does not support the requested capability.
+    #[error("MCP server does not support {0}")]
+    Unsupported(String),
+
+    /// The server did not answer a request in time.
+    #[error("MCP request '{0}' timed out")]
+    Timeout(String),
+
+    /// The request was cancelled before the server answered.
+    #[error("MCP request '{0}' was cancelled")]
+    Cancelled(String),
+
+    /// The connection to the server is closed.
+    #[error("MCP connection closed")]
+    Closed,
+}
+
+impl From<std::io::Error> for McpError {
+    fn from(error: std::io::Error) -> Self {
+        McpError::Transport(error.to_string())
+    }
+}
+
+impl From<reqwest::Error> for McpError {
+    fn from(error: reqwest::Error) -> Self {
+        McpError::Transport(error.to_string())
+    }
+}
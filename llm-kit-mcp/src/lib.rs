@@ -0,0 +1,61 @@
+//! # LLM Kit MCP
+//!
+//! Model Context Protocol (MCP) client for LLM Kit.
+//!
+//! This crate connects to MCP servers and exposes their tools as a [`ToolSet`]
+//! that can be passed to `GenerateText`, `StreamText` or an agent. Calling one of
+//! these tools forwards the call to the server.
+//!
+//! ## Features
+//!
+//! - **Transports**: [`StdioTransport`] runs a local server as a child process,
+//!   [`StreamableHttpTransport`] talks to a remote server over Streamable HTTP
+//! - **Tools**: [`McpClient::tools`] converts the server tools into dynamic tools
+//! - **Resources and Prompts**: List and read resources, list and get prompts
+//! - **Notifications**: Subscribe to server notifications with [`McpClient::notifications`]
+//! - **Cancellation**: Aborted tool calls and timed out requests are cancelled on the server
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use llm_kit_core::GenerateText;
+//! use llm_kit_core::prompt::Prompt;
+//! use llm_kit_mcp::{McpClient, StdioTransport};
+//! # use std::sync::Arc;
+//! # use llm_kit_provider::LanguageModel;
+//!
+//! # async fn example(model: Arc<dyn LanguageModel>) -> Result<(), Box<dyn std::error::Error>> {
+//! // Start the server and perform the initialization handshake
+//! let client = McpClient::connect(
+//!     StdioTransport::new("npx").with_args(["-y", "@modelcontextprotocol/server-everything"]),
+//! )
+//! .await?;
+//!
+//! // Let the model call the server tools
+//! let result = GenerateText::new(model, Prompt::text("Add 2 and 3"))
+//!     .tools(client.tools().await?)
+//!     .execute()
+//!     .await?;
+//!
+//! client.close().await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`ToolSet`]: llm_kit_core::tool::ToolSet
+
+mod client;
+mod error;
+/// Transports that connect the client to a server.
+pub mod transport;
+/// JSON-RPC and MCP message types.
+pub mod types;
+
+pub use client::{McpClient, McpClientOptions};
+pub use error::McpError;
+pub use transport::{McpTransport, StdioTransport, StreamableHttpTransport};
+pub use types::{
+    CallToolResult, GetPromptResult, Implementation, McpContent, McpPrompt, McpPromptArgument,
+    McpPromptMessage, McpResource, McpResourceTemplate, McpTool, ReadResourceResult,
+    ResourceContents, ServerCapabilities,
+};
@@ -0,0 +1,38 @@
+/// Streamable HTTP transport for remote MCP servers.
+pub mod http;
+/// Stdio transport for MCP servers run as child processes.
+pub mod stdio;
+
+pub use http::StreamableHttpTransport;
+pub use stdio::StdioTransport;
+
+use crate::error::McpError;
+use crate::types::JsonRpcMessage;
+use async_trait::async_trait;
+use tokio::sync::mpsc;
+
+/// The channel a transport delivers the messages received from the server to.
+pub type MessageSender = mpsc::UnboundedSender<JsonRpcMessage>;
+
+/// A connection to an MCP server that exchanges JSON-RPC messages.
+///
+/// The [`McpClient`](crate::McpClient) starts the transport once and then sends
+/// requests, notifications and responses through it. Messages from the server are
+/// delivered to the channel passed to [`start`](McpTransport::start); dropping all
+/// senders of that channel tells the client that the connection is closed.
+#[async_trait]
+pub trait McpTransport: Send + Sync {
+    /// Opens the connection. Messages received from the server are sent to `incoming`.
+    async fn start(&self, incoming: MessageSender) -> Result<(), McpError>;
+
+    /// Sends a message to the server.
+    async fn send(&self, message: JsonRpcMessage) -> Result<(), McpError>;
+
+    /// Closes the connection.
+    async fn close(&self) -> Result<(), McpError>;
+
+    /// Sets the protocol version negotiated during initialization.
+    ///
+    /// Transports that send the version with each message (such as HTTP) override this.
+    fn set_protocol_version(&self, _version: &str) {}
+}
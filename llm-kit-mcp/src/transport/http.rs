@@ -0,0 +1,392 @@
+use super::{McpTransport, MessageSender};
+use crate::error::McpError;
+use crate::types::JsonRpcMessage;
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use llm_kit_provider_utils::event_source::event_source_stream;
+use reqwest::header::{ACCEPT, CONTENT_TYPE, HeaderMap};
+use reqwest::{Response, StatusCode};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tokio::task::JoinHandle;
+
+/// Header that carries the session ID assigned by the server.
+const SESSION_ID_HEADER: &str = "mcp-session-id";
+
+/// Header that carries the negotiated protocol version.
+const PROTOCOL_VERSION_HEADER: &str = "mcp-protocol-version";
+
+/// A transport for MCP servers that implement the Streamable HTTP transport.
+///
+/// Every message is sent as a POST request to the server URL. The server answers
+/// requests with a JSON body or with a server-sent event stream that can carry
+/// notifications before the response. After initialization, the transport also
+/// opens a GET event stream for notifications that are not tied to a request, if
+/// the server offers one.
+///
+/// # Example
+///
+/// ```no_run
+/// use llm_kit_mcp::StreamableHttpTransport;
+///
+/// let transport = StreamableHttpTransport::new("https://example.com/mcp")
+///     .with_header("Authorization", "Bearer my-token");
+/// ```
+pub struct StreamableHttpTransport {
+    url: String,
+    headers: HashMap<String, String>,
+    client: reqwest::Client,
+    session_id: Mutex<Option<String>>,
+    protocol_version: Mutex<Option<String>>,
+    incoming: Mutex<Option<MessageSender>>,
+    tasks: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl StreamableHttpTransport {
+    /// Creates a transport for the MCP endpoint at the given URL.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            headers: HashMap::new(),
+            client: reqwest::Client::new(),
+            session_id: Mutex::new(None),
+            protocol_version: Mutex::new(None),
+            incoming: Mutex::new(None),
+            tasks: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Adds a header that is sent with every request, e.g. for authorization.
+    pub fn with_header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(key.into(), value.into());
+        self
+    }
+
+    /// Sets the HTTP client used for the requests.
+    pub fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// The session ID assigned by the server, if any.
+    pub fn session_id(&self) -> Option<String> {
+        self.session_id.lock().unwrap().clone()
+    }
+
+    /// Builds a request with the custom, session and protocol version headers.
+    fn http_request(&self, method: reqwest::Method) -> reqwest::RequestBuilder {
+        let mut request = self.client.request(method, &self.url);
+        for (key, value) in &self.headers {
+            request = request.header(key, value);
+        }
+        if let Some(session_id) = self.session_id() {
+            request = request.header(SESSION_ID_HEADER, session_id);
+        }
+        if let Some(version) = self.protocol_version.lock().unwrap().clone() {
+            request = request.header(PROTOCOL_VERSION_HEADER, version);
+        }
+        request
+    }
+
+    fn incoming(&self) -> Result<MessageSender, McpError> {
+        self.incoming
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or(McpError::Closed)
+    }
+
+    /// Forwards the messages of an event stream response in a background task.
+    fn spawn_event_stream(&self, response: Response, incoming: MessageSender) {
+        let task = tokio::spawn(async move {
+            let mut events = Box::pin(event_source_stream(response.bytes_stream()));
+            while let Some(event) = events.next().await {
+                let event = match event {
+                    Ok(event) => event,
+                    Err(error) => {
+                        log::warn!("MCP event stream failed: {}", error);
+                        break;
+                    }
+                };
+                if event.event.as_deref().is_some_and(|name| name != "message") {
+                    continue;
+                }
+                match serde_json::from_str::<JsonRpcMessage>(&event.data) {
+                    Ok(message) => {
+                        if incoming.send(message).is_err() {
+                            break;
+                        }
+                    }
+                    Err(error) => log::warn!("Ignoring invalid message from MCP server: {}", error),
+                }
+            }
+        });
+
+        let mut tasks = self.tasks.lock().unwrap();
+        tasks.retain(|task| !task.is_finished());
+        tasks.push(task);
+    }
+
+    /// Opens the GET event stream for notifications from the server.
+    async fn open_notification_stream(&self) -> Result<(), McpError> {
+        let response = self
+            .http_request(reqwest::Method::GET)
+            .header(ACCEPT, "text/event-stream")
+            .send()
+            .await?;
+
+        // Servers without a notification stream answer 405
+        if response.status() == StatusCode::METHOD_NOT_ALLOWED {
+            return Ok(());
+        }
+        if !response.status().is_success() {
+            return Err(McpError::Transport(format!(
+                "failed to open notification stream: HTTP {}",
+                response.status()
+            )));
+        }
+
+        self.spawn_event_stream(response, self.incoming()?);
+        Ok(())
+    }
+}
+
+/// Whether the response headers declare the given content type.
+fn has_content_type(headers: &HeaderMap, content_type: &str) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with(content_type))
+}
+
+#[async_trait]
+impl McpTransport for StreamableHttpTransport {
+    async fn start(&self, incoming: MessageSender) -> Result<(), McpError> {
+        *self.incoming.lock().unwrap() = Some(incoming);
+        Ok(())
+    }
+
+    async fn send(&self, message: JsonRpcMessage) -> Result<(), McpError> {
+        let incoming = self.incoming()?;
+        let response = self
+            .http_request(reqwest::Method::POST)
+            .header(ACCEPT, "application/json, text/event-stream")
+            .json(&message)
+            .send()
+            .await?;
+
+        if let Some(session_id) = response
+            .headers()
+            .get(SESSION_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+        {
+            *self.session_id.lock().unwrap() = Some(session_id.to_string());
+        }
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(McpError::Transport(format!("HTTP {}: {}", status, body)));
+        }
+
+        // Only requests get a response; notifications and responses are accepted with 202
+        if let JsonRpcMessage::Request(_) = message {
+            if has_content_type(response.headers(), "text/event-stream") {
+                self.spawn_event_stream(response, incoming);
+            } else if has_content_type(response.headers(), "application/json") {
+                let body = response.bytes().await?;
+                let messages = match serde_json::from_slice::<Vec<JsonRpcMessage>>(&body) {
+                    Ok(messages) => messages,
+                    Err(_) => vec![serde_json::from_slice::<JsonRpcMessage>(&body)?],
+                };
+                for message in messages {
+                    let _ = incoming.send(message);
+                }
+            }
+        }
+
+        // The server can send notifications once the client is initialized
+        if let JsonRpcMessage::Notification(notification) = &message
+            && notification.method == "notifications/initialized"
+            && let Err(error) = self.open_notification_stream().await
+        {
+            log::warn!("{}", error);
+        }
+
+        Ok(())
+    }
+
+    async fn close(&self) -> Result<(), McpError> {
+        for task in self.tasks.lock().unwrap().drain(..) {
+            task.abort();
+        }
+        self.incoming.lock().unwrap().take();
+
+        // Ask the server to end the session
+        if self.session_id().is_some() {
+            let _ = self.http_request(reqwest::Method::DELETE).send().await;
+            self.session_id.lock().unwrap().take();
+        }
+        Ok(())
+    }
+
+    fn set_protocol_version(&self, version: &str) {
+        *self.protocol_version.lock().unwrap() = Some(version.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::McpClient;
+    use serde_json::{Value, json};
+    use std::sync::Arc;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+
+    /// A request received by the stub server: method, session ID and body.
+    type Received = (String, Option<String>, Option<Value>);
+
+    /// Serves a minimal Streamable HTTP MCP endpoint and records the requests.
+    async fn start_stub_server() -> (String, Arc<Mutex<Vec<Received>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/mcp", listener.local_addr().unwrap());
+        let received = Arc::new(Mutex::new(Vec::new()));
+
+        let requests = received.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let requests = requests.clone();
+                tokio::spawn(handle_connection(stream, requests));
+            }
+        });
+
+        (url, received)
+    }
+
+    async fn handle_connection(mut stream: TcpStream, received: Arc<Mutex<Vec<Received>>>) {
+        let mut buffer = Vec::new();
+        let header_end = loop {
+            let mut chunk = [0; 1024];
+            let read = stream.read(&mut chunk).await.unwrap();
+            if read == 0 {
+                return;
+            }
+            buffer.extend_from_slice(&chunk[..read]);
+            if let Some(position) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+                break position + 4;
+            }
+        };
+
+        let head = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+        let method = head.split(' ').next().unwrap().to_string();
+        let header = |name: &str| {
+            head.lines().find_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                key.eq_ignore_ascii_case(name)
+                    .then(|| value.trim().to_string())
+            })
+        };
+        let content_length: usize = header("content-length")
+            .map(|length| length.parse().unwrap())
+            .unwrap_or(0);
+        while buffer.len() < header_end + content_length {
+            let mut chunk = [0; 1024];
+            let read = stream.read(&mut chunk).await.unwrap();
+            buffer.extend_from_slice(&chunk[..read]);
+        }
+        let body: Option<Value> = serde_json::from_slice(&buffer[header_end..]).ok();
+        let session_id = header(SESSION_ID_HEADER);
+        received
+            .lock()
+            .unwrap()
+            .push((method.clone(), session_id.clone(), body.clone()));
+
+        let body = body.unwrap_or(Value::Null);
+        let response = match (method.as_str(), body["method"].as_str()) {
+            // Initialize is answered over an event stream and starts the session
+            ("POST", Some("initialize")) => {
+                let result = json!({
+                    "jsonrpc": "2.0",
+                    "id": body["id"],
+                    "result": {
+                        "protocolVersion": body["params"]["protocolVersion"],
+                        "capabilities": { "tools": {} },
+                        "serverInfo": { "name": "http-stub", "version": "1.0.0" }
+                    }
+                });
+                let events = format!("event: message\ndata: {}\n\n", result);
+                format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\n{}: session-1\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    SESSION_ID_HEADER,
+                    events.len(),
+                    events
+                )
+            }
+            _ if session_id.as_deref() != Some("session-1") => {
+                "HTTP/1.1 400 Bad Request\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                    .to_string()
+            }
+            ("POST", Some("tools/list")) => {
+                let result = json!({
+                    "jsonrpc": "2.0",
+                    "id": body["id"],
+                    "result": { "tools": [{ "name": "echo", "inputSchema": { "type": "object" } }] }
+                })
+                .to_string();
+                format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    result.len(),
+                    result
+                )
+            }
+            ("POST", _) => {
+                "HTTP/1.1 202 Accepted\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                    .to_string()
+            }
+            ("GET", _) => {
+                "HTTP/1.1 405 Method Not Allowed\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                    .to_string()
+            }
+            _ => "HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n".to_string(),
+        };
+
+        stream.write_all(response.as_bytes()).await.unwrap();
+        stream.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_streamable_http_session() {
+        let (url, received) = start_stub_server().await;
+
+        let client = McpClient::connect(StreamableHttpTransport::new(url))
+            .await
+            .unwrap();
+        assert_eq!(client.server_info().name, "http-stub");
+
+        let tools = client.list_tools().await.unwrap();
+        assert_eq!(tools[0].name, "echo");
+
+        client.close().await.unwrap();
+
+        let received = received.lock().unwrap();
+        let requests: Vec<(&str, Option<&str>)> = received
+            .iter()
+            .map(|(method, session_id, _)| (method.as_str(), session_id.as_deref()))
+            .collect();
+        assert_eq!(
+            requests,
+            [
+                ("POST", None),
+                ("POST", Some("session-1")),
+                ("GET", Some("session-1")),
+                ("POST", Some("session-1")),
+                ("DELETE", Some("session-1")),
+            ]
+        );
+        assert_eq!(
+            received[1].2.as_ref().unwrap()["method"],
+            "notifications/initialized"
+        );
+    }
+}
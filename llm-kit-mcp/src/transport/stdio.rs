@@ -0,0 +1,148 @@
+use super::{McpTransport, MessageSender};
+use crate::error::McpError;
+use crate::types::JsonRpcMessage;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{Child, ChildStdin, Command};
+use tokio::sync::Mutex;
+
+/// How long a server may take to exit after its stdin is closed before it is killed.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// A transport that runs an MCP server as a child process.
+///
+/// Messages are exchanged as newline-delimited JSON over the stdin and stdout of
+/// the process. The stderr of the server is passed through to the stderr of the
+/// current process. The process is killed when the transport is dropped.
+///
+/// # Example
+///
+/// ```no_run
+/// use llm_kit_mcp::StdioTransport;
+///
+/// let transport = StdioTransport::new("npx")
+///     .with_args(["-y", "@modelcontextprotocol/server-filesystem", "."])
+///     .with_env("LOG_LEVEL", "warn");
+/// ```
+pub struct StdioTransport {
+    command: String,
+    args: Vec<String>,
+    env: HashMap<String, String>,
+    cwd: Option<PathBuf>,
+    connection: Mutex<Option<StdioConnection>>,
+}
+
+/// The running server process.
+struct StdioConnection {
+    child: Child,
+    stdin: ChildStdin,
+}
+
+impl StdioTransport {
+    /// Creates a transport that runs the given command.
+    pub fn new(command: impl Into<String>) -> Self {
+        Self {
+            command: command.into(),
+            args: Vec::new(),
+            env: HashMap::new(),
+            cwd: None,
+            connection: Mutex::new(None),
+        }
+    }
+
+    /// Adds arguments to the command.
+    pub fn with_args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets an environment variable for the server process.
+    pub fn with_env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.insert(key.into(), value.into());
+        self
+    }
+
+    /// Sets the working directory of the server process.
+    pub fn with_cwd(mut self, cwd: impl Into<PathBuf>) -> Self {
+        self.cwd = Some(cwd.into());
+        self
+    }
+}
+
+#[async_trait]
+impl McpTransport for StdioTransport {
+    async fn start(&self, incoming: MessageSender) -> Result<(), McpError> {
+        let mut command = Command::new(&self.command);
+        command
+            .args(&self.args)
+            .envs(&self.env)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .kill_on_drop(true);
+        if let Some(cwd) = &self.cwd {
+            command.current_dir(cwd);
+        }
+
+        let mut child = command.spawn().map_err(|error| {
+            McpError::Transport(format!("failed to start '{}': {}", self.command, error))
+        })?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = child.stdout.take().expect("stdout is piped");
+
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(stdout).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match serde_json::from_str::<JsonRpcMessage>(&line) {
+                    Ok(message) => {
+                        if incoming.send(message).is_err() {
+                            break;
+                        }
+                    }
+                    Err(error) => log::warn!("Ignoring invalid message from MCP server: {}", error),
+                }
+            }
+        });
+
+        *self.connection.lock().await = Some(StdioConnection { child, stdin });
+        Ok(())
+    }
+
+    async fn send(&self, message: JsonRpcMessage) -> Result<(), McpError> {
+        let mut line = serde_json::to_string(&message)?;
+        line.push('\n');
+
+        let mut connection = self.connection.lock().await;
+        let connection = connection.as_mut().ok_or(McpError::Closed)?;
+        connection.stdin.write_all(line.as_bytes()).await?;
+        connection.stdin.flush().await?;
+        Ok(())
+    }
+
+    async fn close(&self) -> Result<(), McpError> {
+        let Some(StdioConnection { mut child, stdin }) = self.connection.lock().await.take() else {
+            return Ok(());
+        };
+
+        // Closing stdin asks the server to exit
+        drop(stdin);
+        if tokio::time::timeout(SHUTDOWN_TIMEOUT, child.wait())
+            .await
+            .is_err()
+        {
+            child.kill().await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,526 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The JSON-RPC version used by MCP.
+pub const JSONRPC_VERSION: &str = "2.0";
+
+/// The MCP protocol version requested by the client.
+pub const LATEST_PROTOCOL_VERSION: &str = "2025-06-18";
+
+/// The ID of a JSON-RPC request.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RequestId {
+    /// A numeric ID.
+    Number(i64),
+    /// A string ID.
+    String(String),
+}
+
+/// A JSON-RPC request that expects a response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonRpcRequest {
+    /// Always "2.0".
+    pub jsonrpc: String,
+
+    /// The ID that the response refers to.
+    pub id: RequestId,
+
+    /// The method to invoke.
+    pub method: String,
+
+    /// The parameters of the method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<Value>,
+}
+
+impl JsonRpcRequest {
+    /// Creates a new request.
+    pub fn new(id: RequestId, method: impl Into<String>, params: Option<Value>) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id,
+            method: method.into(),
+            params,
+        }
+    }
+}
+
+/// A JSON-RPC notification, which has no response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonRpcNotification {
+    /// Always "2.0".
+    pub jsonrpc: String,
+
+    /// The notification method, e.g. `notifications/tools/list_changed`.
+    pub method: String,
+
+    /// The parameters of the notification.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<Value>,
+}
+
+impl JsonRpcNotification {
+    /// Creates a new notification.
+    pub fn new(method: impl Into<String>, params: Option<Value>) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            method: method.into(),
+            params,
+        }
+    }
+}
+
+/// The error of a failed JSON-RPC request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonRpcError {
+    /// The error code.
+    pub code: i64,
+
+    /// A short description of the error.
+    pub message: String,
+
+    /// Additional information about the error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl JsonRpcError {
+    /// Error code for methods that do not exist.
+    pub const METHOD_NOT_FOUND: i64 = -32601;
+}
+
+/// A JSON-RPC response to a request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonRpcResponse {
+    /// Always "2.0".
+    pub jsonrpc: String,
+
+    /// The ID of the request this response belongs to.
+    pub id: RequestId,
+
+    /// The result of a successful request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+
+    /// The error of a failed request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<JsonRpcError>,
+}
+
+impl JsonRpcResponse {
+    /// Creates a successful response.
+    pub fn success(id: RequestId, result: Value) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id,
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    /// Creates an error response.
+    pub fn error(id: RequestId, error: JsonRpcError) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id,
+            result: None,
+            error: Some(error),
+        }
+    }
+}
+
+/// Any JSON-RPC message exchanged with an MCP server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum JsonRpcMessage {
+    /// A request, from the client or from the server.
+    Request(JsonRpcRequest),
+    /// A notification, from the client or from the server.
+    Notification(JsonRpcNotification),
+    /// A response to an earlier request.
+    Response(JsonRpcResponse),
+}
+
+/// The name and version of an MCP client or server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Implementation {
+    /// Name of the implementation.
+    pub name: String,
+
+    /// Version of the implementation.
+    pub version: String,
+
+    /// Human-readable title of the implementation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+impl Implementation {
+    /// Creates a new implementation description.
+    pub fn new(name: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            title: None,
+        }
+    }
+}
+
+/// The capabilities an MCP server announced during initialization.
+///
+/// Each capability is present when the server supports it. Its value holds
+/// the options of the capability, such as `{"listChanged": true}`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ServerCapabilities {
+    /// Tools the client can call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Value>,
+
+    /// Resources the client can read.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Value>,
+
+    /// Prompt templates the client can get.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompts: Option<Value>,
+
+    /// Log messages the server sends as notifications.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logging: Option<Value>,
+
+    /// Argument completions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completions: Option<Value>,
+
+    /// Non-standard capabilities.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub experimental: Option<Value>,
+}
+
+/// The result of the initialization handshake.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitializeResult {
+    /// The protocol version the server chose.
+    pub protocol_version: String,
+
+    /// The capabilities of the server.
+    #[serde(default)]
+    pub capabilities: ServerCapabilities,
+
+    /// The name and version of the server.
+    pub server_info: Implementation,
+
+    /// Instructions on how to use the server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+}
+
+/// A tool offered by an MCP server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpTool {
+    /// Name of the tool.
+    pub name: String,
+
+    /// Human-readable title of the tool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Description of what the tool does.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// JSON Schema of the tool input.
+    pub input_schema: Value,
+
+    /// JSON Schema of the structured tool output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_schema: Option<Value>,
+
+    /// Hints about the behavior of the tool, e.g. `readOnlyHint`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<Value>,
+}
+
+/// A content block returned by a tool or contained in a prompt message.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum McpContent {
+    /// Text content.
+    Text {
+        /// The text.
+        text: String,
+    },
+
+    /// Base64-encoded image data.
+    #[serde(rename_all = "camelCase")]
+    Image {
+        /// The base64-encoded data.
+        data: String,
+        /// IANA media type of the image.
+        mime_type: String,
+    },
+
+    /// Base64-encoded audio data.
+    #[serde(rename_all = "camelCase")]
+    Audio {
+        /// The base64-encoded data.
+        data: String,
+        /// IANA media type of the audio.
+        mime_type: String,
+    },
+
+    /// A link to a resource the client can read.
+    #[serde(rename_all = "camelCase")]
+    ResourceLink {
+        /// URI of the resource.
+        uri: String,
+        /// Name of the resource.
+        name: String,
+        /// Description of the resource.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// IANA media type of the resource.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        mime_type: Option<String>,
+    },
+
+    /// The contents of an embedded resource.
+    Resource {
+        /// The resource contents.
+        resource: ResourceContents,
+    },
+}
+
+/// The result of a tool call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallToolResult {
+    /// The unstructured output of the tool.
+    #[serde(default)]
+    pub content: Vec<McpContent>,
+
+    /// The structured output of the tool, matching its output schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub structured_content: Option<Value>,
+
+    /// Whether the tool call failed.
+    #[serde(default)]
+    pub is_error: bool,
+}
+
+/// A resource offered by an MCP server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpResource {
+    /// URI of the resource.
+    pub uri: String,
+
+    /// Name of the resource.
+    pub name: String,
+
+    /// Human-readable title of the resource.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Description of the resource.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// IANA media type of the resource.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+
+    /// Size of the resource in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+}
+
+/// A template for resources offered by an MCP server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpResourceTemplate {
+    /// RFC 6570 URI template of the resources.
+    pub uri_template: String,
+
+    /// Name of the template.
+    pub name: String,
+
+    /// Human-readable title of the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Description of the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// IANA media type of the resources.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+}
+
+/// The contents of a resource.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ResourceContents {
+    /// Text contents.
+    #[serde(rename_all = "camelCase")]
+    Text {
+        /// URI of the resource.
+        uri: String,
+        /// IANA media type of the resource.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        mime_type: Option<String>,
+        /// The text of the resource.
+        text: String,
+    },
+
+    /// Binary contents.
+    #[serde(rename_all = "camelCase")]
+    Blob {
+        /// URI of the resource.
+        uri: String,
+        /// IANA media type of the resource.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        mime_type: Option<String>,
+        /// The base64-encoded data of the resource.
+        blob: String,
+    },
+}
+
+/// The result of reading a resource.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReadResourceResult {
+    /// The contents of the resource. A resource can have several contents,
+    /// e.g. the files of a directory.
+    pub contents: Vec<ResourceContents>,
+}
+
+/// A prompt template offered by an MCP server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct McpPrompt {
+    /// Name of the prompt.
+    pub name: String,
+
+    /// Human-readable title of the prompt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Description of the prompt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Arguments the prompt template accepts.
+    #[serde(default)]
+    pub arguments: Vec<McpPromptArgument>,
+}
+
+/// An argument of a prompt template.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct McpPromptArgument {
+    /// Name of the argument.
+    pub name: String,
+
+    /// Description of the argument.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Whether the argument must be provided.
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// A message of a prompt returned by an MCP server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct McpPromptMessage {
+    /// The role of the message, `user` or `assistant`.
+    pub role: String,
+
+    /// The content of the message.
+    pub content: McpContent,
+}
+
+/// The result of getting a prompt.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GetPromptResult {
+    /// Description of the prompt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The messages of the prompt.
+    pub messages: Vec<McpPromptMessage>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_json_rpc_message_deserialization() {
+        let request: JsonRpcMessage =
+            serde_json::from_value(json!({"jsonrpc": "2.0", "id": 1, "method": "ping"})).unwrap();
+        assert_eq!(
+            request,
+            JsonRpcMessage::Request(JsonRpcRequest::new(RequestId::Number(1), "ping", None))
+        );
+
+        let notification: JsonRpcMessage = serde_json::from_value(
+            json!({"jsonrpc": "2.0", "method": "notifications/tools/list_changed"}),
+        )
+        .unwrap();
+        assert!(matches!(notification, JsonRpcMessage::Notification(_)));
+
+        let response: JsonRpcMessage = serde_json::from_value(json!({
+            "jsonrpc": "2.0",
+            "id": "a",
+            "error": {"code": -32601, "message": "Method not found"}
+        }))
+        .unwrap();
+        match response {
+            JsonRpcMessage::Response(response) => {
+                assert_eq!(response.id, RequestId::String("a".to_string()));
+                assert_eq!(response.error.unwrap().code, JsonRpcError::METHOD_NOT_FOUND);
+            }
+            other => panic!("Expected response, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_call_tool_result_deserialization() {
+        let result: CallToolResult = serde_json::from_value(json!({
+            "content": [
+                {"type": "text", "text": "Sunny"},
+                {"type": "image", "data": "aGk=", "mimeType": "image/png"},
+                {"type": "resource", "resource": {"uri": "file:///a.txt", "text": "A"}}
+            ],
+            "structuredContent": {"forecast": "sunny"}
+        }))
+        .unwrap();
+
+        assert!(!result.is_error);
+        assert_eq!(
+            result.content[1],
+            McpContent::Image {
+                data: "aGk=".to_string(),
+                mime_type: "image/png".to_string()
+            }
+        );
+        assert_eq!(
+            result.content[2],
+            McpContent::Resource {
+                resource: ResourceContents::Text {
+                    uri: "file:///a.txt".to_string(),
+                    mime_type: None,
+                    text: "A".to_string()
+                }
+            }
+        );
+        assert_eq!(
+            result.structured_content,
+            Some(json!({"forecast": "sunny"}))
+        );
+    }
+}
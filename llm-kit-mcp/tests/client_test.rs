@@ -0,0 +1,220 @@
+use llm_kit_mcp::*;
+use llm_kit_provider_utils::tool::{Tool, ToolExecuteOptions, ToolExecutionOutput, ToolType};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+fn stub_server() -> StdioTransport {
+    StdioTransport::new(env!("CARGO_BIN_EXE_mcp-stub-server"))
+}
+
+async fn connect() -> McpClient {
+    McpClient::connect(stub_server())
+        .await
+        .expect("connects to the stub server")
+}
+
+async fn execute(tool: &Tool, input: Value, options: ToolExecuteOptions) -> Result<Value, Value> {
+    let execute = tool.execute.as_ref().expect("tool has an execute function");
+    match execute(input, options) {
+        ToolExecutionOutput::Single(future) => future.await,
+        ToolExecutionOutput::Streaming(_) => panic!("expected a single output"),
+    }
+}
+
+#[tokio::test]
+async fn test_connect_performs_handshake() {
+    let client = connect().await;
+
+    assert_eq!(client.server_info().name, "stub-server");
+    assert_eq!(client.protocol_version(), types::LATEST_PROTOCOL_VERSION);
+    assert_eq!(
+        client.instructions(),
+        Some("Use the echo tool to test the connection.")
+    );
+    assert!(client.server_capabilities().tools.is_some());
+    assert!(client.server_capabilities().completions.is_none());
+
+    client.ping().await.unwrap();
+    client.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_list_tools_follows_pagination() {
+    let client = connect().await;
+
+    let tools = client.list_tools().await.unwrap();
+    let names: Vec<&str> = tools.iter().map(|tool| tool.name.as_str()).collect();
+
+    assert_eq!(names, ["echo", "add", "fail", "slow", "cancelled_requests"]);
+    assert_eq!(tools[1].title.as_deref(), Some("Add numbers"));
+    assert!(tools[1].output_schema.is_some());
+}
+
+#[tokio::test]
+async fn test_call_tool() {
+    let client = connect().await;
+
+    let result = client
+        .call_tool("add", json!({ "a": 2, "b": 3 }))
+        .await
+        .unwrap();
+    assert!(!result.is_error);
+    assert_eq!(result.structured_content, Some(json!({ "sum": 5 })));
+    assert_eq!(
+        result.content,
+        vec![McpContent::Text {
+            text: "5".to_string()
+        }]
+    );
+
+    let error = client.call_tool("unknown", json!({})).await.unwrap_err();
+    assert!(matches!(error, McpError::Server { code: -32602, .. }));
+}
+
+#[tokio::test]
+async fn test_tools_converts_to_tool_set() {
+    let client = connect().await;
+
+    let tools = client.tools().await.unwrap();
+    assert_eq!(tools.len(), 5);
+
+    let add = &tools["add"];
+    assert_eq!(add.tool_type, ToolType::Dynamic);
+    assert_eq!(add.description.as_deref(), Some("Add numbers"));
+    assert_eq!(add.input_schema["properties"]["a"]["type"], "integer");
+    assert!(add.output_schema.is_some());
+
+    let output = execute(
+        add,
+        json!({ "a": 20, "b": 22 }),
+        ToolExecuteOptions::new("call-1", vec![]),
+    )
+    .await
+    .unwrap();
+    assert_eq!(output["structuredContent"], json!({ "sum": 42 }));
+    assert_eq!(output["content"][0]["text"], "42");
+
+    let error = execute(
+        &tools["fail"],
+        json!({}),
+        ToolExecuteOptions::new("call-2", vec![]),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(error["isError"], true);
+    assert_eq!(error["content"][0]["text"], "Something went wrong");
+}
+
+#[tokio::test]
+async fn test_notifications_are_delivered() {
+    let client = connect().await;
+    let mut notifications = client.notifications();
+
+    let result = client
+        .call_tool("echo", json!({ "text": "hello" }))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content,
+        vec![McpContent::Text {
+            text: "hello".to_string()
+        }]
+    );
+
+    let notification = notifications.recv().await.unwrap();
+    assert_eq!(notification.method, "notifications/message");
+    assert_eq!(notification.params.unwrap()["data"], "echo called");
+}
+
+#[tokio::test]
+async fn test_resources() {
+    let client = connect().await;
+
+    let resources = client.list_resources().await.unwrap();
+    assert_eq!(resources.len(), 1);
+    assert_eq!(resources[0].uri, "file:///readme.md");
+    assert_eq!(resources[0].mime_type.as_deref(), Some("text/markdown"));
+
+    let templates = client.list_resource_templates().await.unwrap();
+    assert_eq!(templates[0].uri_template, "file:///{path}");
+
+    let result = client.read_resource("file:///readme.md").await.unwrap();
+    assert_eq!(
+        result.contents,
+        vec![ResourceContents::Text {
+            uri: "file:///readme.md".to_string(),
+            mime_type: Some("text/markdown".to_string()),
+            text: "# Readme".to_string(),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_prompts() {
+    let client = connect().await;
+
+    let prompts = client.list_prompts().await.unwrap();
+    assert_eq!(prompts[0].name, "greet");
+    assert!(prompts[0].arguments[0].required);
+
+    let arguments = HashMap::from([("name".to_string(), "Ada".to_string())]);
+    let result = client.get_prompt("greet", arguments).await.unwrap();
+    assert_eq!(result.messages[0].role, "user");
+    assert_eq!(
+        result.messages[0].content,
+        McpContent::Text {
+            text: "Say hello to Ada".to_string()
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_request_timeout_cancels_request() {
+    let client = McpClient::connect_with_options(
+        stub_server(),
+        McpClientOptions::new().with_request_timeout(Duration::from_millis(200)),
+    )
+    .await
+    .unwrap();
+
+    let error = client.call_tool("slow", json!({})).await.unwrap_err();
+    assert!(matches!(error, McpError::Timeout(_)));
+
+    // The server was told that the request is cancelled
+    let result = client
+        .call_tool("cancelled_requests", json!({}))
+        .await
+        .unwrap();
+    let cancelled = &result.structured_content.unwrap()["requestIds"];
+    assert_eq!(cancelled.as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_abort_signal_cancels_tool_call() {
+    let client = connect().await;
+    let tools = client.tools().await.unwrap();
+
+    let abort_signal = CancellationToken::new();
+    let mut options = ToolExecuteOptions::new("call-1", vec![]);
+    options.abort_signal = Some(abort_signal.clone());
+
+    let slow = execute(&tools["slow"], json!({}), options);
+    let cancel = async {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        abort_signal.cancel();
+    };
+    let (result, _) = tokio::join!(slow, cancel);
+
+    assert!(result.unwrap_err().as_str().unwrap().contains("cancelled"));
+}
+
+#[tokio::test]
+async fn test_close_fails_later_requests() {
+    let client = connect().await;
+    client.close().await.unwrap();
+
+    let error = client.ping().await.unwrap_err();
+    assert!(matches!(error, McpError::Closed));
+}
@@ -0,0 +1,179 @@
+//! A minimal MCP server over stdio for the integration tests.
+//!
+//! It answers the requests the client sends with canned results. The `slow` tool
+//! never answers, so that timeouts and cancellation can be tested; the
+//! `cancelled_requests` tool reports the IDs of the cancelled requests it received.
+
+use serde_json::{Value, json};
+use std::io::{BufRead, Write};
+
+fn main() {
+    let stdin = std::io::stdin();
+    let mut stdout = std::io::stdout();
+    let mut cancelled: Vec<Value> = Vec::new();
+
+    for line in stdin.lock().lines() {
+        let Ok(line) = line else { break };
+        if line.trim().is_empty() {
+            continue;
+        }
+        let message: Value = serde_json::from_str(&line).expect("valid JSON-RPC message");
+        let method = message["method"].as_str().unwrap_or_default();
+        let params = &message["params"];
+
+        let Some(id) = message.get("id").cloned() else {
+            if method == "notifications/cancelled" {
+                cancelled.push(params["requestId"].clone());
+            }
+            continue;
+        };
+
+        let result = match method {
+            "initialize" => Ok(json!({
+                "protocolVersion": params["protocolVersion"],
+                "capabilities": {
+                    "tools": { "listChanged": true },
+                    "resources": {},
+                    "prompts": {},
+                    "logging": {}
+                },
+                "serverInfo": { "name": "stub-server", "version": "1.0.0" },
+                "instructions": "Use the echo tool to test the connection."
+            })),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(list_tools(params["cursor"].as_str())),
+            "tools/call" => {
+                let arguments = &params["arguments"];
+                match params["name"].as_str().unwrap_or_default() {
+                    "echo" => {
+                        send(
+                            &mut stdout,
+                            json!({
+                                "jsonrpc": "2.0",
+                                "method": "notifications/message",
+                                "params": { "level": "info", "data": "echo called" }
+                            }),
+                        );
+                        Ok(json!({
+                            "content": [{ "type": "text", "text": arguments["text"] }]
+                        }))
+                    }
+                    "add" => {
+                        let sum = arguments["a"].as_i64().unwrap_or(0)
+                            + arguments["b"].as_i64().unwrap_or(0);
+                        Ok(json!({
+                            "content": [{ "type": "text", "text": sum.to_string() }],
+                            "structuredContent": { "sum": sum }
+                        }))
+                    }
+                    "fail" => Ok(json!({
+                        "content": [{ "type": "text", "text": "Something went wrong" }],
+                        "isError": true
+                    })),
+                    "slow" => continue,
+                    "cancelled_requests" => Ok(json!({
+                        "content": [],
+                        "structuredContent": { "requestIds": cancelled }
+                    })),
+                    name => {
+                        Err(json!({ "code": -32602, "message": format!("Unknown tool: {}", name) }))
+                    }
+                }
+            }
+            "resources/list" => Ok(json!({
+                "resources": [{
+                    "uri": "file:///readme.md",
+                    "name": "readme.md",
+                    "mimeType": "text/markdown"
+                }]
+            })),
+            "resources/templates/list" => Ok(json!({
+                "resourceTemplates": [{
+                    "uriTemplate": "file:///{path}",
+                    "name": "file"
+                }]
+            })),
+            "resources/read" => Ok(json!({
+                "contents": [{
+                    "uri": params["uri"],
+                    "mimeType": "text/markdown",
+                    "text": "# Readme"
+                }]
+            })),
+            "prompts/list" => Ok(json!({
+                "prompts": [{
+                    "name": "greet",
+                    "description": "Greets a person",
+                    "arguments": [{ "name": "name", "required": true }]
+                }]
+            })),
+            "prompts/get" => Ok(json!({
+                "description": "Greets a person",
+                "messages": [{
+                    "role": "user",
+                    "content": {
+                        "type": "text",
+                        "text": format!("Say hello to {}", params["arguments"]["name"].as_str().unwrap_or_default())
+                    }
+                }]
+            })),
+            _ => Err(json!({ "code": -32601, "message": format!("Method not found: {}", method) })),
+        };
+
+        let response = match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(error) => json!({ "jsonrpc": "2.0", "id": id, "error": error }),
+        };
+        send(&mut stdout, response);
+    }
+}
+
+/// Lists the tools in two pages to exercise pagination.
+fn list_tools(cursor: Option<&str>) -> Value {
+    let schema = |properties: Value| json!({ "type": "object", "properties": properties });
+
+    match cursor {
+        None => json!({
+            "tools": [{
+                "name": "echo",
+                "description": "Echoes the text",
+                "inputSchema": schema(json!({ "text": { "type": "string" } }))
+            }],
+            "nextCursor": "2"
+        }),
+        Some(_) => json!({
+            "tools": [
+                {
+                    "name": "add",
+                    "title": "Add numbers",
+                    "inputSchema": schema(json!({
+                        "a": { "type": "integer" },
+                        "b": { "type": "integer" }
+                    })),
+                    "outputSchema": schema(json!({ "sum": { "type": "integer" } }))
+                },
+                {
+                    "name": "fail",
+                    "description": "Always fails",
+                    "inputSchema": schema(json!({}))
+                },
+                {
+                    "name": "slow",
+                    "description": "Never answers",
+                    "inputSchema": schema(json!({}))
+                },
+                {
+                    "name": "cancelled_requests",
+                    "description": "Lists the cancelled request IDs",
+                    "inputSchema": schema(json!({}))
+                }
+            ]
+        }),
+    }
+}
+
+fn send(stdout: &mut std::io::Stdout, message: Value) {
+    let mut stdout = stdout.lock();
+    writeln!(stdout, "{}", message).expect("stdout is writable");
+    stdout.flush().expect("stdout is writable");
+}
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use futures_util::{Stream, StreamExt};
+use llm_kit_provider::error::ProviderError;
 use llm_kit_provider::language_model::stream_part::LanguageModelStreamPart;
 use llm_kit_provider::language_model::{
     LanguageModel, LanguageModelGenerateResponse, LanguageModelStreamResponse,
@@ -8,6 +9,7 @@ use llm_kit_provider::language_model::{
     content::text::LanguageModelText, content::tool_call::LanguageModelToolCall,
     usage::LanguageModelUsage,
 };
+use llm_kit_provider_utils::event_source::parse_json_event_stream;
 use regex::Regex;
 use reqwest;
 use serde::Deserialize;
@@ -45,6 +47,9 @@ pub struct OpenAICompatibleChatConfig {
 
     /// Function to get supported URLs for the model
     pub supported_urls: Option<SupportedUrlsFn>,
+
+    /// HTTP client used to send requests to the API
+    pub http_client: reqwest::Client,
 }
 
 impl Default for OpenAICompatibleChatConfig {
@@ -56,6 +61,7 @@ impl Default for OpenAICompatibleChatConfig {
             include_usage: false,
             supports_structured_outputs: false,
             supported_urls: None,
+            http_client: reqwest::Client::new(),
         }
     }
 }
@@ -98,7 +104,6 @@ impl OpenAICompatibleChatLanguageModel {
         byte_stream: impl Stream<Item = Result<bytes::Bytes, reqwest::Error>> + Send + 'static,
         warnings: Vec<LanguageModelCallWarning>,
     ) -> impl Stream<Item = LanguageModelStreamPart> + Unpin + Send {
-        let mut buffer = String::new();
         let mut state = StreamState {
             text_id: None,
             reasoning_id: None,
@@ -110,43 +115,20 @@ impl OpenAICompatibleChatLanguageModel {
             // This is outside the event loop and only emitted once per stream instance
             yield LanguageModelStreamPart::stream_start(warnings);
 
-            let mut stream = Box::pin(byte_stream);
+            let mut events = Box::pin(parse_json_event_stream::<OpenAIStreamChunk, _, _, _>(byte_stream));
 
-            while let Some(result) = stream.next().await {
+            while let Some(result) = events.next().await {
                 match result {
-                    Ok(bytes) => {
-                        // Append to buffer
-                        if let Ok(text) = std::str::from_utf8(&bytes) {
-                            buffer.push_str(text);
-
-                            // Process complete lines
-                            while let Some(pos) = buffer.find('\n') {
-                                let line = buffer[..pos].trim().to_string();
-                                buffer.drain(..=pos);
-
-                                // Skip empty lines
-                                if line.is_empty() {
-                                    continue;
-                                }
-
-                                // Parse SSE format
-                                if let Some(data) = line.strip_prefix("data: ") {
-                                    // Check for [DONE] marker
-                                    if data == "[DONE]" {
-                                        break;
-                                    }
-
-                                    // Parse JSON chunk
-                                    if let Ok(chunk) = serde_json::from_str::<OpenAIStreamChunk>(data) {
-                                        // Process the chunk and emit stream parts
-                                        for part in Self::process_chunk(&mut state, chunk) {
-                                            yield part;
-                                        }
-                                    }
-                                }
-                            }
+                    Ok(Ok(chunk)) => {
+                        // Process the chunk and emit stream parts
+                        for part in Self::process_chunk(&mut state, chunk) {
+                            yield part;
                         }
                     }
+                    Ok(Err(error)) => {
+                        // Skip chunks that don't match the schema, e.g. of new event types
+                        log::warn!("Skipping unrecognized stream chunk: {}", error);
+                    }
                     Err(e) => {
                         yield LanguageModelStreamPart::error(json!({ "message": e.to_string() }));
                         break;
@@ -568,6 +550,10 @@ impl LanguageModel for OpenAICompatibleChatLanguageModel {
         }
     }
 
+    fn supports_structured_outputs(&self) -> bool {
+        self.config.supports_structured_outputs
+    }
+
     async fn do_generate(
         &self,
         options: LanguageModelCallOptions,
@@ -593,7 +579,7 @@ impl LanguageModel for OpenAICompatibleChatLanguageModel {
         }
 
         // Make API request
-        let client = reqwest::Client::new();
+        let client = &self.config.http_client;
         let mut request = client.post(&url).header("Content-Type", "application/json");
 
         for (key, value) in headers {
@@ -616,29 +602,26 @@ impl LanguageModel for OpenAICompatibleChatLanguageModel {
 
         if !status.is_success() {
             let error_body = response.text().await?;
-
-            // Check for Retry-After header to include in error message
-            let retry_info = if let Some(retry_after) = response_headers.get("retry-after") {
-                if let Ok(retry_str) = retry_after.to_str() {
-                    // Try to parse as seconds (integer)
-                    if let Ok(seconds) = retry_str.parse::<u64>() {
-                        format!(" (Retry after {} seconds)", seconds)
-                    } else {
-                        // Could be HTTP-date format, but for now just include the raw value
-                        format!(" (Retry-After: {})", retry_str)
-                    }
-                } else {
-                    String::new()
-                }
-            } else {
-                String::new()
-            };
-
-            return Err(format!(
-                "API request failed with status {}: {}{}",
-                status, error_body, retry_info
-            )
-            .into());
+            let error_headers: HashMap<String, String> = response_headers
+                .iter()
+                .filter_map(|(k, v)| {
+                    v.to_str()
+                        .ok()
+                        .map(|s| (k.as_str().to_string(), s.to_string()))
+                })
+                .collect();
+
+            return Err(Box::new(ProviderError::api_call_error_with_details(
+                format!("API request failed with status {}: {}", status, error_body),
+                url,
+                body_string,
+                Some(status.as_u16()),
+                Some(error_headers),
+                Some(error_body),
+                None, // Auto-determine retryability based on status code
+                None,
+                None,
+            )));
         }
 
         let response_body = response.text().await?;
@@ -785,7 +768,7 @@ impl LanguageModel for OpenAICompatibleChatLanguageModel {
         }
 
         // Make streaming API request
-        let client = reqwest::Client::new();
+        let client = &self.config.http_client;
         let mut request = client.post(&url).header("Content-Type", "application/json");
 
         for (key, value) in headers {
@@ -808,29 +791,26 @@ impl LanguageModel for OpenAICompatibleChatLanguageModel {
 
         if !status.is_success() {
             let error_body = response.text().await?;
-
-            // Check for Retry-After header to include in error message
-            let retry_info = if let Some(retry_after) = response_headers.get("retry-after") {
-                if let Ok(retry_str) = retry_after.to_str() {
-                    // Try to parse as seconds (integer)
-                    if let Ok(seconds) = retry_str.parse::<u64>() {
-                        format!(" (Retry after {} seconds)", seconds)
-                    } else {
-                        // Could be HTTP-date format, but for now just include the raw value
-                        format!(" (Retry-After: {})", retry_str)
-                    }
-                } else {
-                    String::new()
-                }
-            } else {
-                String::new()
-            };
-
-            return Err(format!(
-                "API request failed with status {}: {}{}",
-                status, error_body, retry_info
-            )
-            .into());
+            let error_headers: HashMap<String, String> = response_headers
+                .iter()
+                .filter_map(|(k, v)| {
+                    v.to_str()
+                        .ok()
+                        .map(|s| (k.as_str().to_string(), s.to_string()))
+                })
+                .collect();
+
+            return Err(Box::new(ProviderError::api_call_error_with_details(
+                format!("API request failed with status {}: {}", status, error_body),
+                url,
+                body_string,
+                Some(status.as_u16()),
+                Some(error_headers),
+                Some(error_body),
+                None, // Auto-determine retryability based on status code
+                None,
+                None,
+            )));
         }
 
         // Build headers map from HTTP response headers
@@ -62,6 +62,7 @@ pub struct OpenAICompatibleClient {
     query_params: HashMap<String, String>,
     include_usage: bool,
     supports_structured_outputs: bool,
+    http_client: Option<reqwest::Client>,
 }
 
 impl OpenAICompatibleClient {
@@ -188,6 +189,19 @@ impl OpenAICompatibleClient {
         self
     }
 
+    /// Sets the HTTP client used for all API requests of the provider.
+    ///
+    /// Use this to share a connection pool or to configure proxies, timeouts,
+    /// TLS roots or a user agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `http_client` - The `reqwest` client to use
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
     /// Builds the `OpenAICompatibleProvider` with the configured settings.
     ///
     /// # Returns
@@ -235,6 +249,10 @@ impl OpenAICompatibleClient {
         settings = settings.with_include_usage(self.include_usage);
         settings = settings.with_supports_structured_outputs(self.supports_structured_outputs);
 
+        if let Some(http_client) = self.http_client {
+            settings = settings.with_http_client(http_client);
+        }
+
         OpenAICompatibleProvider::new(settings)
     }
 }
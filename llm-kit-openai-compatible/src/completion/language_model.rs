@@ -1,11 +1,13 @@
 use async_trait::async_trait;
 use futures_util::{Stream, StreamExt};
+use llm_kit_provider::error::ProviderError;
 use llm_kit_provider::language_model::stream_part::LanguageModelStreamPart;
 use llm_kit_provider::language_model::{
     LanguageModel, LanguageModelGenerateResponse, LanguageModelStreamResponse,
     call_options::LanguageModelCallOptions, call_warning::LanguageModelCallWarning,
     content::LanguageModelContent, content::text::LanguageModelText, usage::LanguageModelUsage,
 };
+use llm_kit_provider_utils::event_source::parse_json_event_stream;
 use regex::Regex;
 use reqwest;
 use serde::Deserialize;
@@ -34,6 +36,9 @@ pub struct OpenAICompatibleCompletionConfig {
 
     /// Whether to include usage information in streaming responses
     pub include_usage: bool,
+
+    /// HTTP client used to send requests to the API
+    pub http_client: reqwest::Client,
 }
 
 impl Default for OpenAICompatibleCompletionConfig {
@@ -43,6 +48,7 @@ impl Default for OpenAICompatibleCompletionConfig {
             headers: Box::new(HashMap::new),
             url: Box::new(|_model_id, path| format!("https://api.openai.com/v1{}", path)),
             include_usage: false,
+            http_client: reqwest::Client::new(),
         }
     }
 }
@@ -241,7 +247,7 @@ impl LanguageModel for OpenAICompatibleCompletionLanguageModel {
         }
 
         // Make API request
-        let client = reqwest::Client::new();
+        let client = &self.config.http_client;
         let mut request = client.post(&url).header("Content-Type", "application/json");
 
         for (key, value) in headers {
@@ -264,29 +270,26 @@ impl LanguageModel for OpenAICompatibleCompletionLanguageModel {
 
         if !status.is_success() {
             let error_body = response.text().await?;
-
-            // Check for Retry-After header to include in error message
-            let retry_info = if let Some(retry_after) = response_headers.get("retry-after") {
-                if let Ok(retry_str) = retry_after.to_str() {
-                    // Try to parse as seconds (integer)
-                    if let Ok(seconds) = retry_str.parse::<u64>() {
-                        format!(" (Retry after {} seconds)", seconds)
-                    } else {
-                        // Could be HTTP-date format, but for now just include the raw value
-                        format!(" (Retry-After: {})", retry_str)
-                    }
-                } else {
-                    String::new()
-                }
-            } else {
-                String::new()
-            };
-
-            return Err(format!(
-                "API request failed with status {}: {}{}",
-                status, error_body, retry_info
-            )
-            .into());
+            let error_headers: HashMap<String, String> = response_headers
+                .iter()
+                .filter_map(|(k, v)| {
+                    v.to_str()
+                        .ok()
+                        .map(|s| (k.as_str().to_string(), s.to_string()))
+                })
+                .collect();
+
+            return Err(Box::new(ProviderError::api_call_error_with_details(
+                format!("API request failed with status {}: {}", status, error_body),
+                url,
+                body_string,
+                Some(status.as_u16()),
+                Some(error_headers),
+                Some(error_body),
+                None, // Auto-determine retryability based on status code
+                None,
+                None,
+            )));
         }
 
         let response_body = response.text().await?;
@@ -392,7 +395,7 @@ impl LanguageModel for OpenAICompatibleCompletionLanguageModel {
         }
 
         // Make streaming API request
-        let client = reqwest::Client::new();
+        let client = &self.config.http_client;
         let mut request = client.post(&url).header("Content-Type", "application/json");
 
         for (key, value) in headers {
@@ -415,29 +418,26 @@ impl LanguageModel for OpenAICompatibleCompletionLanguageModel {
 
         if !status.is_success() {
             let error_body = response.text().await?;
-
-            // Check for Retry-After header to include in error message
-            let retry_info = if let Some(retry_after) = response_headers.get("retry-after") {
-                if let Ok(retry_str) = retry_after.to_str() {
-                    // Try to parse as seconds (integer)
-                    if let Ok(seconds) = retry_str.parse::<u64>() {
-                        format!(" (Retry after {} seconds)", seconds)
-                    } else {
-                        // Could be HTTP-date format, but for now just include the raw value
-                        format!(" (Retry-After: {})", retry_str)
-                    }
-                } else {
-                    String::new()
-                }
-            } else {
-                String::new()
-            };
-
-            return Err(format!(
-                "API request failed with status {}: {}{}",
-                status, error_body, retry_info
-            )
-            .into());
+            let error_headers: HashMap<String, String> = response_headers
+                .iter()
+                .filter_map(|(k, v)| {
+                    v.to_str()
+                        .ok()
+                        .map(|s| (k.as_str().to_string(), s.to_string()))
+                })
+                .collect();
+
+            return Err(Box::new(ProviderError::api_call_error_with_details(
+                format!("API request failed with status {}: {}", status, error_body),
+                url,
+                body_string,
+                Some(status.as_u16()),
+                Some(error_headers),
+                Some(error_body),
+                None, // Auto-determine retryability based on status code
+                None,
+                None,
+            )));
         }
 
         // Build headers map from HTTP response headers
@@ -472,79 +472,55 @@ impl OpenAICompatibleCompletionLanguageModel {
         byte_stream: impl Stream<Item = Result<bytes::Bytes, reqwest::Error>> + Send + 'static,
         warnings: Vec<LanguageModelCallWarning>,
     ) -> impl Stream<Item = LanguageModelStreamPart> + Unpin + Send {
-        let mut buffer = String::new();
         let mut is_first_chunk = true;
 
         Box::pin(async_stream::stream! {
             // Emit stream start with warnings
             yield LanguageModelStreamPart::stream_start(warnings);
 
-            let mut stream = Box::pin(byte_stream);
+            let mut events = Box::pin(parse_json_event_stream::<OpenAICompletionChunk, _, _, _>(byte_stream));
             let mut finish_reason = llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason::Unknown;
             let mut usage = LanguageModelUsage::default();
 
-            while let Some(result) = stream.next().await {
+            while let Some(result) = events.next().await {
                 match result {
-                    Ok(bytes) => {
-                        // Append to buffer
-                        if let Ok(text) = std::str::from_utf8(&bytes) {
-                            buffer.push_str(text);
-
-                            // Process complete lines
-                            while let Some(pos) = buffer.find('\n') {
-                                let line = buffer[..pos].trim().to_string();
-                                buffer.drain(..=pos);
-
-                                // Skip empty lines
-                                if line.is_empty() {
-                                    continue;
-                                }
-
-                                // Parse SSE format
-                                if let Some(data) = line.strip_prefix("data: ") {
-                                    // Check for [DONE] marker
-                                    if data == "[DONE]" {
-                                        break;
-                                    }
-
-                                    // Parse JSON chunk
-                                    if let Ok(chunk) = serde_json::from_str::<OpenAICompletionChunk>(data) {
-                                        if is_first_chunk {
-                                            is_first_chunk = false;
-
-                                            // Emit response metadata
-                                            yield LanguageModelStreamPart::ResponseMetadata(
-                                                get_response_metadata(chunk.id.clone(), chunk.model.clone(), chunk.created)
-                                            );
-
-                                            // Emit text start
-                                            yield LanguageModelStreamPart::text_start("0");
-                                        }
-
-                                        // Update usage if present
-                                        if let Some(api_usage) = &chunk.usage {
-                                            usage.input_tokens = api_usage.prompt_tokens.unwrap_or(0);
-                                            usage.output_tokens = api_usage.completion_tokens.unwrap_or(0);
-                                            usage.total_tokens = api_usage.total_tokens.unwrap_or(0);
-                                        }
-
-                                        // Process choice
-                                        if let Some(choice) = chunk.choices.first() {
-                                            // Update finish reason if present
-                                            if let Some(reason) = &choice.finish_reason {
-                                                finish_reason = map_openai_compatible_finish_reason(Some(reason));
-                                            }
-
-                                            // Emit text delta
-                                            if !choice.text.is_empty() {
-                                                yield LanguageModelStreamPart::text_delta("0", &choice.text);
-                                            }
-                                        }
-                                    }
-                                }
+                    Ok(Ok(chunk)) => {
+                        if is_first_chunk {
+                            is_first_chunk = false;
+
+                            // Emit response metadata
+                            yield LanguageModelStreamPart::ResponseMetadata(
+                                get_response_metadata(chunk.id.clone(), chunk.model.clone(), chunk.created)
+                            );
+
+                            // Emit text start
+                            yield LanguageModelStreamPart::text_start("0");
+                        }
+
+                        // Update usage if present
+                        if let Some(api_usage) = &chunk.usage {
+                            usage.input_tokens = api_usage.prompt_tokens.unwrap_or(0);
+                            usage.output_tokens = api_usage.completion_tokens.unwrap_or(0);
+                            usage.total_tokens = api_usage.total_tokens.unwrap_or(0);
+                        }
+
+                        // Process choice
+                        if let Some(choice) = chunk.choices.first() {
+                            // Update finish reason if present
+                            if let Some(reason) = &choice.finish_reason {
+                                finish_reason = map_openai_compatible_finish_reason(Some(reason));
+                            }
+
+                            // Emit text delta
+                            if !choice.text.is_empty() {
+                                yield LanguageModelStreamPart::text_delta("0", &choice.text);
                             }
                         }
                     }
+                    Ok(Err(error)) => {
+                        // Skip chunks that don't match the schema, e.g. of new event types
+                        log::warn!("Skipping unrecognized stream chunk: {}", error);
+                    }
                     Err(e) => {
                         yield LanguageModelStreamPart::error(json!({ "message": e.to_string() }));
                         break;
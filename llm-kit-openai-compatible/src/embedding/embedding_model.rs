@@ -31,6 +31,9 @@ pub struct OpenAICompatibleEmbeddingConfig {
 
     /// Override the parallelism of embedding calls
     pub supports_parallel_calls: Option<bool>,
+
+    /// HTTP client used to send requests to the API
+    pub http_client: reqwest::Client,
 }
 
 impl Default for OpenAICompatibleEmbeddingConfig {
@@ -41,6 +44,7 @@ impl Default for OpenAICompatibleEmbeddingConfig {
             url: Box::new(|_model_id, path| format!("https://api.openai.com/v1{}", path)),
             max_embeddings_per_call: None,
             supports_parallel_calls: None,
+            http_client: reqwest::Client::new(),
         }
     }
 }
@@ -157,7 +161,7 @@ impl EmbeddingModel<String> for OpenAICompatibleEmbeddingModel {
         let url = (self.config.url)(&self.model_id, "/embeddings");
 
         // Create HTTP client
-        let client = reqwest::Client::new();
+        let client = &self.config.http_client;
 
         // Build request
         let mut request = client.post(&url).json(&body);
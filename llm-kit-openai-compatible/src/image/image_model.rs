@@ -26,6 +26,9 @@ pub struct OpenAICompatibleImageModelConfig {
 
     /// Function to generate the URL for API requests
     pub url: UrlGeneratorFn,
+
+    /// HTTP client used to send requests to the API
+    pub http_client: reqwest::Client,
 }
 
 impl Default for OpenAICompatibleImageModelConfig {
@@ -34,6 +37,7 @@ impl Default for OpenAICompatibleImageModelConfig {
             provider: "openai-compatible".to_string(),
             headers: Box::new(HashMap::new),
             url: Box::new(|_model_id, path| format!("https://api.openai.com/v1{}", path)),
+            http_client: reqwest::Client::new(),
         }
     }
 }
@@ -145,7 +149,7 @@ impl ImageModel for OpenAICompatibleImageModel {
         let url = (self.config.url)(&self.model_id, "/images/generations");
 
         // Create HTTP client
-        let client = reqwest::Client::new();
+        let client = &self.config.http_client;
 
         // Build request
         let mut request = client.post(&url).json(&body);
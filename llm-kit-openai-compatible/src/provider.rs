@@ -19,12 +19,18 @@ use crate::settings::OpenAICompatibleProviderSettings;
 /// Provides methods to create different types of language models.
 pub struct OpenAICompatibleProvider {
     settings: OpenAICompatibleProviderSettings,
+    http_client: reqwest::Client,
 }
 
 impl OpenAICompatibleProvider {
     /// Creates a new OpenAI-compatible provider.
     pub fn new(settings: OpenAICompatibleProviderSettings) -> Self {
-        Self { settings }
+        let http_client = settings.http_client.clone().unwrap_or_default();
+
+        Self {
+            settings,
+            http_client,
+        }
     }
 
     /// Helper function to safely build URLs with query parameters.
@@ -143,6 +149,7 @@ impl OpenAICompatibleProvider {
             include_usage: self.settings.include_usage,
             supports_structured_outputs: self.settings.supports_structured_outputs,
             supported_urls: None,
+            http_client: self.http_client.clone(),
         }
     }
 
@@ -186,6 +193,7 @@ impl OpenAICompatibleProvider {
                 Self::build_url_with_params(&base_url, path, &query_params)
             }),
             include_usage: self.settings.include_usage,
+            http_client: self.http_client.clone(),
         }
     }
 
@@ -230,6 +238,7 @@ impl OpenAICompatibleProvider {
             }),
             max_embeddings_per_call: None,
             supports_parallel_calls: None,
+            http_client: self.http_client.clone(),
         }
     }
 
@@ -272,6 +281,7 @@ impl OpenAICompatibleProvider {
             url: Box::new(move |_model_id: &str, path: &str| {
                 Self::build_url_with_params(&base_url, path, &query_params)
             }),
+            http_client: self.http_client.clone(),
         }
     }
 
@@ -31,6 +31,10 @@ pub struct OpenAICompatibleProviderSettings {
 
     /// Whether the provider supports structured outputs in chat models.
     pub supports_structured_outputs: bool,
+
+    /// Optional HTTP client shared by all models of the provider. Use it to configure
+    /// proxies, timeouts, TLS roots or a user agent. Defaults to a new `reqwest::Client`.
+    pub http_client: Option<reqwest::Client>,
 }
 
 impl Default for OpenAICompatibleProviderSettings {
@@ -45,6 +49,7 @@ impl Default for OpenAICompatibleProviderSettings {
             query_params: None,
             include_usage: false,
             supports_structured_outputs: false,
+            http_client: None,
         }
     }
 }
@@ -62,6 +67,7 @@ impl OpenAICompatibleProviderSettings {
             query_params: None,
             include_usage: false,
             supports_structured_outputs: false,
+            http_client: None,
         }
     }
 
@@ -122,4 +128,10 @@ impl OpenAICompatibleProviderSettings {
         self.supports_structured_outputs = supports;
         self
     }
+
+    /// Sets the HTTP client used for API requests.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
 }
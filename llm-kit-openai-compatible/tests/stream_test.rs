@@ -0,0 +1,50 @@
+use futures_util::StreamExt;
+use llm_kit_openai_compatible::{OpenAICompatibleProvider, OpenAICompatibleProviderSettings};
+use llm_kit_provider::language_model::call_options::LanguageModelCallOptions;
+use llm_kit_provider::language_model::prompt::LanguageModelMessage;
+use llm_kit_provider::language_model::stream_part::LanguageModelStreamPart;
+use llm_kit_provider_utils::testing::{MockResponse, MockServer, test_http_client};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_chat_stream_skips_unknown_chunks() {
+    let server = MockServer::start().await;
+    server.enqueue(MockResponse::event_stream(
+        [
+            json!({ "id": "chat_1", "model": "test-model", "choices": [{ "index": 0, "delta": { "role": "assistant", "content": "Hello" } }] }),
+            json!({ "object": "keepalive" }),
+            json!({ "id": "chat_1", "choices": [{ "index": 0, "delta": { "content": " world" }, "finish_reason": "stop" }] }),
+        ],
+        true,
+    ));
+    let provider = OpenAICompatibleProvider::new(
+        OpenAICompatibleProviderSettings::new(server.url(), "test")
+            .with_api_key("test-key")
+            .with_http_client(test_http_client()),
+    );
+
+    let response = provider
+        .chat_model("test-model")
+        .do_stream(LanguageModelCallOptions::new(vec![
+            LanguageModelMessage::user_text("Hi"),
+        ]))
+        .await
+        .unwrap();
+    let parts: Vec<LanguageModelStreamPart> = response.stream.collect().await;
+
+    assert!(
+        !parts
+            .iter()
+            .any(|part| matches!(part, LanguageModelStreamPart::Error(_))),
+        "unexpected error in {:?}",
+        parts
+    );
+    let text: String = parts
+        .iter()
+        .filter_map(|part| match part {
+            LanguageModelStreamPart::TextDelta(delta) => Some(delta.delta.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(text, "Hello world");
+}
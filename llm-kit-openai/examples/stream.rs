@@ -55,10 +55,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         use llm_kit_provider::language_model::stream_part::LanguageModelStreamPart;
 
         match part {
-            LanguageModelStreamPart::StreamStart(start) => {
-                if !start.warnings.is_empty() {
-                    println!("Warnings: {:?}", start.warnings);
-                }
+            LanguageModelStreamPart::StreamStart(start) if !start.warnings.is_empty() => {
+                println!("Warnings: {:?}", start.warnings);
             }
             LanguageModelStreamPart::TextDelta(delta) => {
                 print!("{}", delta.delta);
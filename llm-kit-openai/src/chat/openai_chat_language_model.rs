@@ -10,6 +10,7 @@ use super::openai_chat_options::{
 };
 use super::openai_chat_prepare_tools::prepare_chat_tools;
 use super::openai_chat_prompt::{SystemMessageMode, convert_to_openai_chat_messages};
+use crate::error::parse_openai_error;
 use async_trait::async_trait;
 use futures_util::Stream;
 use futures_util::StreamExt;
@@ -26,6 +27,7 @@ use llm_kit_provider::language_model::{
     LanguageModelStreamResponse, StreamResponseMetadata,
 };
 use llm_kit_provider::shared::provider_metadata::SharedProviderMetadata;
+use llm_kit_provider_utils::event_source::parse_json_event_stream;
 use regex::Regex;
 use serde_json::{Value as JsonValue, json};
 use std::collections::HashMap;
@@ -41,6 +43,8 @@ pub struct OpenAIChatConfig {
     pub base_url: String,
     /// Function to get headers
     pub headers: Arc<dyn Fn() -> HashMap<String, String> + Send + Sync>,
+    /// HTTP client used to send requests
+    pub http_client: reqwest::Client,
 }
 
 impl OpenAIChatConfig {
@@ -54,8 +58,15 @@ impl OpenAIChatConfig {
             provider,
             base_url,
             headers,
+            http_client: reqwest::Client::new(),
         }
     }
+
+    /// Set the HTTP client used to send requests
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = http_client;
+        self
+    }
 }
 
 /// OpenAI Chat Language Model
@@ -299,23 +310,19 @@ impl OpenAIChatLanguageModel {
         if let Some(ref service_tier) = openai_options.service_tier {
             use super::openai_chat_options::ServiceTier;
             match service_tier {
-                ServiceTier::Flex => {
-                    if !supports_flex_processing(&self.model_id) {
-                        warnings.push(LanguageModelCallWarning::unsupported_setting_with_details(
-                            "serviceTier",
-                            "flex processing is only available for o3, o4-mini, and gpt-5 models",
-                        ));
-                        args.as_object_mut().unwrap().remove("service_tier");
-                    }
+                ServiceTier::Flex if !supports_flex_processing(&self.model_id) => {
+                    warnings.push(LanguageModelCallWarning::unsupported_setting_with_details(
+                        "serviceTier",
+                        "flex processing is only available for o3, o4-mini, and gpt-5 models",
+                    ));
+                    args.as_object_mut().unwrap().remove("service_tier");
                 }
-                ServiceTier::Priority => {
-                    if !supports_priority_processing(&self.model_id) {
-                        warnings.push(LanguageModelCallWarning::unsupported_setting_with_details(
-                            "serviceTier",
-                            "priority processing is only available for supported models and requires Enterprise access"
-                        ));
-                        args.as_object_mut().unwrap().remove("service_tier");
-                    }
+                ServiceTier::Priority if !supports_priority_processing(&self.model_id) => {
+                    warnings.push(LanguageModelCallWarning::unsupported_setting_with_details(
+                        "serviceTier",
+                        "priority processing is only available for supported models and requires Enterprise access"
+                    ));
+                    args.as_object_mut().unwrap().remove("service_tier");
                 }
                 _ => {}
             }
@@ -329,7 +336,6 @@ impl OpenAIChatLanguageModel {
         byte_stream: impl Stream<Item = Result<bytes::Bytes, reqwest::Error>> + Send + 'static,
         warnings: Vec<LanguageModelCallWarning>,
     ) -> impl Stream<Item = LanguageModelStreamPart> + Unpin + Send {
-        let mut buffer = String::new();
         let mut state = StreamState {
             text_id: None,
             reasoning_id: None,
@@ -340,43 +346,20 @@ impl OpenAIChatLanguageModel {
             // Emit stream start with warnings
             yield LanguageModelStreamPart::stream_start(warnings);
 
-            let mut stream = Box::pin(byte_stream);
+            let mut events = Box::pin(parse_json_event_stream::<OpenAIChatChunk, _, _, _>(byte_stream));
 
-            while let Some(result) = stream.next().await {
+            while let Some(result) = events.next().await {
                 match result {
-                    Ok(bytes) => {
-                        // Append to buffer
-                        if let Ok(text) = std::str::from_utf8(&bytes) {
-                            buffer.push_str(text);
-
-                            // Process complete lines
-                            while let Some(pos) = buffer.find('\n') {
-                                let line = buffer[..pos].trim().to_string();
-                                buffer.drain(..=pos);
-
-                                // Skip empty lines
-                                if line.is_empty() {
-                                    continue;
-                                }
-
-                                // Parse SSE format
-                                if let Some(data) = line.strip_prefix("data: ") {
-                                    // Check for [DONE] marker
-                                    if data == "[DONE]" {
-                                        break;
-                                    }
-
-                                    // Parse JSON chunk
-                                    if let Ok(chunk) = serde_json::from_str::<OpenAIChatChunk>(data) {
-                                        // Process the chunk and emit stream parts
-                                        for part in Self::process_chunk(&mut state, chunk) {
-                                            yield part;
-                                        }
-                                    }
-                                }
-                            }
+                    Ok(Ok(chunk)) => {
+                        // Process the chunk and emit stream parts
+                        for part in Self::process_chunk(&mut state, chunk) {
+                            yield part;
                         }
                     }
+                    Ok(Err(error)) => {
+                        // Skip chunks that don't match the schema, e.g. of new event types
+                        log::warn!("Skipping unrecognized stream chunk: {}", error);
+                    }
                     Err(e) => {
                         yield LanguageModelStreamPart::error(json!({ "message": e.to_string() }));
                         break;
@@ -579,6 +562,10 @@ impl LanguageModel for OpenAIChatLanguageModel {
         urls
     }
 
+    fn supports_structured_outputs(&self) -> bool {
+        true
+    }
+
     async fn do_generate(
         &self,
         options: LanguageModelCallOptions,
@@ -586,7 +573,7 @@ impl LanguageModel for OpenAIChatLanguageModel {
         let (body, warnings) = self.build_request_args(&options).await?;
 
         // Make HTTP request
-        let client = reqwest::Client::new();
+        let client = &self.config.http_client;
         let headers = (self.config.headers)();
         let url = format!("{}/chat/completions", self.config.base_url);
 
@@ -596,8 +583,25 @@ impl LanguageModel for OpenAIChatLanguageModel {
         }
 
         let response = request.send().await?;
-        let _response_headers = response.headers().clone();
-        let response_body: OpenAIChatResponse = response.json().await?;
+        let status = response.status();
+        let response_headers: HashMap<String, String> = response
+            .headers()
+            .iter()
+            .map(|(k, v)| (k.as_str().to_string(), v.to_str().unwrap_or("").to_string()))
+            .collect();
+        let response_text = response.text().await?;
+
+        if !status.is_success() {
+            return Err(Box::new(parse_openai_error(
+                &url,
+                &body,
+                status.as_u16(),
+                response_headers,
+                &response_text,
+            )));
+        }
+
+        let response_body: OpenAIChatResponse = serde_json::from_str(&response_text)?;
 
         // Process response
         let choice = &response_body.choices[0];
@@ -713,7 +717,7 @@ impl LanguageModel for OpenAIChatLanguageModel {
         body["stream_options"] = json!({ "include_usage": true });
 
         // Make HTTP request
-        let client = reqwest::Client::new();
+        let client = &self.config.http_client;
         let headers = (self.config.headers)();
         let url = format!("{}/chat/completions", self.config.base_url);
 
@@ -723,7 +727,23 @@ impl LanguageModel for OpenAIChatLanguageModel {
         }
 
         let response = request.send().await?;
-        let response_headers = response.headers().clone();
+        let status = response.status();
+        let response_headers: HashMap<String, String> = response
+            .headers()
+            .iter()
+            .map(|(k, v)| (k.as_str().to_string(), v.to_str().unwrap_or("").to_string()))
+            .collect();
+
+        if !status.is_success() {
+            let response_text = response.text().await?;
+            return Err(Box::new(parse_openai_error(
+                &url,
+                &body,
+                status.as_u16(),
+                response_headers,
+                &response_text,
+            )));
+        }
 
         // Create the stream processor
         let byte_stream = response.bytes_stream();
@@ -733,14 +753,7 @@ impl LanguageModel for OpenAIChatLanguageModel {
             stream: Box::new(stream),
             request: Some(LanguageModelRequestMetadata { body: Some(body) }),
             response: Some(StreamResponseMetadata {
-                headers: Some(
-                    response_headers
-                        .iter()
-                        .map(|(k, v)| {
-                            (k.as_str().to_string(), v.to_str().unwrap_or("").to_string())
-                        })
-                        .collect(),
-                ),
+                headers: Some(response_headers),
             }),
         })
     }
@@ -68,6 +68,7 @@ pub struct OpenAIClient {
     project: Option<String>,
     headers: HashMap<String, String>,
     name: Option<String>,
+    http_client: Option<reqwest::Client>,
 }
 
 impl OpenAIClient {
@@ -222,6 +223,30 @@ impl OpenAIClient {
         self
     }
 
+    /// Sets the HTTP client used for all API requests of the provider.
+    ///
+    /// Use this to share a connection pool or to configure proxies, timeouts,
+    /// TLS roots or a user agent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use llm_kit_openai::OpenAIClient;
+    /// use std::time::Duration;
+    ///
+    /// let http_client = reqwest::Client::builder()
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let client = OpenAIClient::new()
+    ///     .http_client(http_client);
+    /// ```
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
     /// Builds the `OpenAIProvider` with the configured settings.
     ///
     /// # Returns
@@ -268,6 +293,10 @@ impl OpenAIClient {
             settings = settings.with_headers(self.headers);
         }
 
+        if let Some(http_client) = self.http_client {
+            settings = settings.with_http_client(http_client);
+        }
+
         OpenAIProvider::new(settings)
     }
 }
@@ -0,0 +1,9 @@
+//! OpenAI Embeddings API Implementation
+//!
+//! This module contains the embedding model for OpenAI's `/embeddings` endpoint.
+
+pub mod openai_embedding_model;
+pub mod openai_embedding_options;
+
+pub use openai_embedding_model::OpenAIEmbeddingModel;
+pub use openai_embedding_options::{OpenAIEmbeddingModelId, OpenAIEmbeddingProviderOptions};
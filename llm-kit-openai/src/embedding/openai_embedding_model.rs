@@ -0,0 +1,296 @@
+//! OpenAI Embedding Model Implementation
+//!
+//! Implements the `EmbeddingModel` trait for OpenAI's `/embeddings` endpoint.
+
+use crate::embedding::{OpenAIEmbeddingModelId, OpenAIEmbeddingProviderOptions};
+use crate::error::parse_openai_error;
+use async_trait::async_trait;
+use llm_kit_provider::embedding_model::call_options::EmbeddingModelCallOptions;
+use llm_kit_provider::embedding_model::embedding::EmbeddingModelEmbedding;
+use llm_kit_provider::embedding_model::{
+    EmbeddingModel, EmbeddingModelResponse, EmbeddingModelResponseMetadata, EmbeddingModelUsage,
+};
+use llm_kit_provider::error::ProviderError;
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Maximum number of values OpenAI accepts in a single embedding request.
+const MAX_EMBEDDINGS_PER_CALL: usize = 2048;
+
+/// Configuration for the OpenAI embedding model
+#[derive(Clone)]
+pub struct OpenAIEmbeddingConfig {
+    /// Provider name
+    pub provider: String,
+    /// Base URL for API calls
+    pub base_url: String,
+    /// Function to get headers
+    pub headers: Arc<dyn Fn() -> HashMap<String, String> + Send + Sync>,
+    /// HTTP client used to send requests
+    pub http_client: reqwest::Client,
+}
+
+impl OpenAIEmbeddingConfig {
+    /// Create a new config
+    pub fn new(
+        provider: String,
+        base_url: String,
+        headers: Arc<dyn Fn() -> HashMap<String, String> + Send + Sync>,
+    ) -> Self {
+        Self {
+            provider,
+            base_url,
+            headers,
+            http_client: reqwest::Client::new(),
+        }
+    }
+
+    /// Set the HTTP client used to send requests
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = http_client;
+        self
+    }
+}
+
+/// OpenAI Embedding Model
+///
+/// Embeds text with models such as `text-embedding-3-small`, `text-embedding-3-large`
+/// and `text-embedding-ada-002`.
+pub struct OpenAIEmbeddingModel {
+    model_id: OpenAIEmbeddingModelId,
+    config: OpenAIEmbeddingConfig,
+}
+
+impl OpenAIEmbeddingModel {
+    /// Create a new OpenAI embedding model
+    pub fn new(model_id: OpenAIEmbeddingModelId, config: OpenAIEmbeddingConfig) -> Self {
+        Self { model_id, config }
+    }
+
+    /// Build the request body for the given values and options.
+    fn build_request_body(
+        &self,
+        values: &[String],
+        options: &EmbeddingModelCallOptions<String>,
+    ) -> Result<JsonValue, Box<dyn std::error::Error>> {
+        let openai_options: OpenAIEmbeddingProviderOptions = match options
+            .provider_options
+            .as_ref()
+            .and_then(|o| o.get("openai"))
+        {
+            Some(openai_opts) => serde_json::from_value(serde_json::to_value(openai_opts)?)?,
+            None => OpenAIEmbeddingProviderOptions::default(),
+        };
+
+        let mut body = json!({
+            "model": self.model_id,
+            "input": values,
+            "encoding_format": "float",
+        });
+
+        if let Some(dimensions) = openai_options.dimensions {
+            body["dimensions"] = json!(dimensions);
+        }
+        if let Some(user) = openai_options.user {
+            body["user"] = json!(user);
+        }
+
+        Ok(body)
+    }
+}
+
+#[async_trait]
+impl EmbeddingModel<String> for OpenAIEmbeddingModel {
+    fn provider(&self) -> &str {
+        &self.config.provider
+    }
+
+    fn model_id(&self) -> &str {
+        &self.model_id
+    }
+
+    async fn max_embeddings_per_call(&self) -> Option<usize> {
+        Some(MAX_EMBEDDINGS_PER_CALL)
+    }
+
+    async fn supports_parallel_calls(&self) -> bool {
+        true
+    }
+
+    async fn do_embed(
+        &self,
+        options: EmbeddingModelCallOptions<String>,
+    ) -> Result<EmbeddingModelResponse, Box<dyn std::error::Error>> {
+        if options.values.len() > MAX_EMBEDDINGS_PER_CALL {
+            return Err(Box::new(ProviderError::too_many_embedding_values_for_call(
+                self.config.provider.clone(),
+                self.model_id.clone(),
+                MAX_EMBEDDINGS_PER_CALL,
+                options.values.len(),
+            )));
+        }
+
+        let body = self.build_request_body(&options.values, &options)?;
+
+        // Make HTTP request
+        let client = &self.config.http_client;
+        let url = format!("{}/embeddings", self.config.base_url);
+        let mut headers = (self.config.headers)();
+        if let Some(ref option_headers) = options.headers {
+            headers.extend(option_headers.clone());
+        }
+
+        let mut request = client.post(&url).json(&body);
+        for (key, value) in headers {
+            request = request.header(key, value);
+        }
+
+        let response = if let Some(signal) = &options.abort_signal {
+            tokio::select! {
+                result = request.send() => result?,
+                _ = signal.cancelled() => {
+                    return Err("Operation cancelled".into());
+                }
+            }
+        } else {
+            request.send().await?
+        };
+
+        let status = response.status();
+        let response_headers: HashMap<String, String> = response
+            .headers()
+            .iter()
+            .map(|(k, v)| (k.as_str().to_string(), v.to_str().unwrap_or("").to_string()))
+            .collect();
+        let response_text = response.text().await?;
+
+        if !status.is_success() {
+            return Err(Box::new(parse_openai_error(
+                &url,
+                &body,
+                status.as_u16(),
+                response_headers,
+                &response_text,
+            )));
+        }
+
+        let response_body: JsonValue = serde_json::from_str(&response_text)?;
+        let response_data: OpenAIEmbeddingResponse = serde_json::from_value(response_body.clone())?;
+
+        let embeddings: Vec<EmbeddingModelEmbedding> = response_data
+            .data
+            .into_iter()
+            .map(|item| item.embedding)
+            .collect();
+
+        let mut embedding_response = EmbeddingModelResponse::new(embeddings);
+        if let Some(usage) = response_data.usage {
+            embedding_response =
+                embedding_response.with_usage(EmbeddingModelUsage::new(usage.prompt_tokens));
+        }
+
+        Ok(embedding_response.with_response_metadata(
+            EmbeddingModelResponseMetadata::new()
+                .with_headers(response_headers)
+                .with_body(response_body),
+        ))
+    }
+}
+
+/// OpenAI embedding API response
+#[derive(Debug, Deserialize)]
+struct OpenAIEmbeddingResponse {
+    data: Vec<OpenAIEmbeddingData>,
+    #[serde(default)]
+    usage: Option<OpenAIEmbeddingUsage>,
+}
+
+/// A single embedding in the response
+#[derive(Debug, Deserialize)]
+struct OpenAIEmbeddingData {
+    embedding: Vec<f64>,
+}
+
+/// Token usage of an embedding request
+#[derive(Debug, Deserialize)]
+struct OpenAIEmbeddingUsage {
+    prompt_tokens: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn create_model() -> OpenAIEmbeddingModel {
+        OpenAIEmbeddingModel::new(
+            "text-embedding-3-small".to_string(),
+            OpenAIEmbeddingConfig::new(
+                "openai.embedding".to_string(),
+                "https://api.openai.com/v1".to_string(),
+                Arc::new(HashMap::new),
+            ),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_model_properties() {
+        let model = create_model();
+
+        assert_eq!(model.model_id(), "text-embedding-3-small");
+        assert_eq!(model.provider(), "openai.embedding");
+        assert_eq!(model.max_embeddings_per_call().await, Some(2048));
+        assert!(model.supports_parallel_calls().await);
+    }
+
+    #[test]
+    fn test_build_request_body_with_dimensions() {
+        let model = create_model();
+        let values = vec!["hello".to_string()];
+
+        let mut openai_options = HashMap::new();
+        openai_options.insert("dimensions".to_string(), json!(256));
+        openai_options.insert("user".to_string(), json!("user-1"));
+        let mut provider_options = HashMap::new();
+        provider_options.insert("openai".to_string(), openai_options);
+
+        let options =
+            EmbeddingModelCallOptions::new(values.clone()).with_provider_options(provider_options);
+        let body = model.build_request_body(&values, &options).unwrap();
+
+        assert_eq!(body["model"], "text-embedding-3-small");
+        assert_eq!(body["input"], json!(["hello"]));
+        assert_eq!(body["encoding_format"], "float");
+        assert_eq!(body["dimensions"], 256);
+        assert_eq!(body["user"], "user-1");
+    }
+
+    #[tokio::test]
+    async fn test_too_many_values() {
+        let model = create_model();
+        let values = vec!["x".to_string(); MAX_EMBEDDINGS_PER_CALL + 1];
+
+        let result = model.do_embed(EmbeddingModelCallOptions::new(values)).await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_response_deserialization() {
+        let response: OpenAIEmbeddingResponse = serde_json::from_value(json!({
+            "object": "list",
+            "data": [
+                { "object": "embedding", "index": 0, "embedding": [0.1, 0.2] },
+                { "object": "embedding", "index": 1, "embedding": [0.3, 0.4] }
+            ],
+            "model": "text-embedding-3-small",
+            "usage": { "prompt_tokens": 8, "total_tokens": 8 }
+        }))
+        .unwrap();
+
+        assert_eq!(response.data.len(), 2);
+        assert_eq!(response.data[1].embedding, vec![0.3, 0.4]);
+        assert_eq!(response.usage.unwrap().prompt_tokens, 8);
+    }
+}
@@ -0,0 +1,68 @@
+//! OpenAI Embedding Options
+//!
+//! Provider options for the OpenAI embedding model.
+
+use serde::{Deserialize, Serialize};
+
+/// OpenAI embedding model identifier (e.g. `text-embedding-3-small`).
+pub type OpenAIEmbeddingModelId = String;
+
+/// Provider options for OpenAI embedding models.
+///
+/// Passed through `provider_options["openai"]`.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenAIEmbeddingProviderOptions {
+    /// The number of dimensions the resulting output embeddings should have.
+    /// Only supported in `text-embedding-3` and later models.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<u32>,
+
+    /// A unique identifier representing your end-user, which can help OpenAI to
+    /// monitor and detect abuse.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+impl OpenAIEmbeddingProviderOptions {
+    /// Creates new empty options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of dimensions.
+    pub fn with_dimensions(mut self, dimensions: u32) -> Self {
+        self.dimensions = Some(dimensions);
+        self
+    }
+
+    /// Sets the end-user identifier.
+    pub fn with_user(mut self, user: impl Into<String>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder() {
+        let options = OpenAIEmbeddingProviderOptions::new()
+            .with_dimensions(256)
+            .with_user("user-1");
+
+        assert_eq!(options.dimensions, Some(256));
+        assert_eq!(options.user, Some("user-1".to_string()));
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let options: OpenAIEmbeddingProviderOptions =
+            serde_json::from_str(r#"{"dimensions": 512}"#).unwrap();
+
+        assert_eq!(options.dimensions, Some(512));
+        assert!(options.user.is_none());
+    }
+}
@@ -0,0 +1,120 @@
+//! OpenAI API error handling.
+//!
+//! Parses error responses from the OpenAI API into `ProviderError::APICallError`.
+
+use llm_kit_provider::error::{APICallErrorBuilder, ProviderError};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// OpenAI API error response structure.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OpenAIErrorData {
+    /// The error object.
+    pub error: OpenAIErrorDetails,
+}
+
+/// OpenAI API error details.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OpenAIErrorDetails {
+    /// The error message.
+    pub message: String,
+
+    /// The error type (e.g. `invalid_request_error`).
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub error_type: Option<String>,
+
+    /// The parameter that caused the error.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub param: Option<Value>,
+
+    /// The error code (string or number).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<Value>,
+}
+
+/// Build a `ProviderError` from a failed OpenAI API response.
+///
+/// The message is taken from the OpenAI error body when it can be parsed, and the
+/// status code, headers and raw body are preserved so that callers can decide
+/// whether to retry.
+///
+/// # Arguments
+///
+/// * `url` - The URL that was called
+/// * `request_body` - The JSON request body that was sent
+/// * `status_code` - The HTTP status code
+/// * `response_headers` - The response headers
+/// * `body` - The response body as a string
+pub fn parse_openai_error(
+    url: &str,
+    request_body: &Value,
+    status_code: u16,
+    response_headers: HashMap<String, String>,
+    body: &str,
+) -> ProviderError {
+    let message = match serde_json::from_str::<OpenAIErrorData>(body) {
+        Ok(error_data) => error_data.error.message,
+        Err(_) => format!("API request failed with status {}: {}", status_code, body),
+    };
+
+    APICallErrorBuilder::new(message, url, request_body.to_string())
+        .status_code(status_code)
+        .response_headers(response_headers)
+        .response_body(body)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_error_deserialization() {
+        let json = r#"{
+            "error": {
+                "message": "Invalid API key",
+                "type": "invalid_request_error",
+                "param": null,
+                "code": "invalid_api_key"
+            }
+        }"#;
+
+        let error: OpenAIErrorData = serde_json::from_str(json).unwrap();
+        assert_eq!(error.error.message, "Invalid API key");
+        assert_eq!(
+            error.error.error_type.as_deref(),
+            Some("invalid_request_error")
+        );
+    }
+
+    #[test]
+    fn test_parse_openai_error() {
+        let error = parse_openai_error(
+            "https://api.openai.com/v1/responses",
+            &json!({ "model": "gpt-4o" }),
+            429,
+            HashMap::new(),
+            r#"{"error": {"message": "Rate limit reached", "type": "requests"}}"#,
+        );
+
+        assert!(error.is_retryable());
+        assert_eq!(error.status_code(), Some(429));
+        assert!(error.to_string().contains("Rate limit reached"));
+    }
+
+    #[test]
+    fn test_parse_non_json_error() {
+        let error = parse_openai_error(
+            "https://api.openai.com/v1/responses",
+            &json!({}),
+            400,
+            HashMap::new(),
+            "Bad Request",
+        );
+
+        assert!(!error.is_retryable());
+        assert!(error.to_string().contains("Bad Request"));
+    }
+}
@@ -0,0 +1,11 @@
+//! OpenAI Images API Implementation
+//!
+//! This module contains the image model for OpenAI's `/images/generations` endpoint.
+
+pub mod openai_image_model;
+pub mod openai_image_options;
+
+pub use openai_image_model::OpenAIImageModel;
+pub use openai_image_options::{
+    ImageQuality, ImageStyle, OpenAIImageModelId, OpenAIImageProviderOptions,
+};
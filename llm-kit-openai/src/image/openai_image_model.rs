@@ -0,0 +1,348 @@
+//! OpenAI Image Model Implementation
+//!
+//! Implements the `ImageModel` trait for OpenAI's `/images/generations` endpoint.
+
+use crate::error::parse_openai_error;
+use crate::image::openai_image_options::{
+    has_default_response_format, openai_image_model_max_images_per_call,
+};
+use crate::image::{OpenAIImageModelId, OpenAIImageProviderOptions};
+use async_trait::async_trait;
+use llm_kit_provider::image_model::call_options::ImageModelCallOptions;
+use llm_kit_provider::image_model::call_warning::ImageModelCallWarning;
+use llm_kit_provider::image_model::{
+    ImageData, ImageModel, ImageModelProviderMetadata, ImageModelResponse,
+    ImageModelResponseMetadata,
+};
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+/// Configuration for the OpenAI image model
+#[derive(Clone)]
+pub struct OpenAIImageConfig {
+    /// Provider name
+    pub provider: String,
+    /// Base URL for API calls
+    pub base_url: String,
+    /// Function to get headers
+    pub headers: Arc<dyn Fn() -> HashMap<String, String> + Send + Sync>,
+    /// HTTP client used to send requests
+    pub http_client: reqwest::Client,
+}
+
+impl OpenAIImageConfig {
+    /// Create a new config
+    pub fn new(
+        provider: String,
+        base_url: String,
+        headers: Arc<dyn Fn() -> HashMap<String, String> + Send + Sync>,
+    ) -> Self {
+        Self {
+            provider,
+            base_url,
+            headers,
+            http_client: reqwest::Client::new(),
+        }
+    }
+
+    /// Set the HTTP client used to send requests
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = http_client;
+        self
+    }
+}
+
+/// OpenAI Image Model
+///
+/// Generates images with `dall-e-2`, `dall-e-3` and `gpt-image-1`.
+pub struct OpenAIImageModel {
+    model_id: OpenAIImageModelId,
+    config: OpenAIImageConfig,
+}
+
+impl OpenAIImageModel {
+    /// Create a new OpenAI image model
+    pub fn new(model_id: OpenAIImageModelId, config: OpenAIImageConfig) -> Self {
+        Self { model_id, config }
+    }
+
+    /// Build the request body and collect warnings for unsupported settings.
+    fn build_request_body(
+        &self,
+        options: &ImageModelCallOptions,
+    ) -> Result<(JsonValue, Vec<ImageModelCallWarning>), Box<dyn std::error::Error>> {
+        let mut warnings = Vec::new();
+
+        let openai_options: OpenAIImageProviderOptions = match options
+            .provider_options
+            .as_ref()
+            .and_then(|o| o.get("openai"))
+        {
+            Some(openai_opts) => serde_json::from_value(serde_json::to_value(openai_opts)?)?,
+            None => OpenAIImageProviderOptions::default(),
+        };
+
+        if options.aspect_ratio.is_some() {
+            warnings.push(ImageModelCallWarning::unsupported_setting_with_details(
+                "aspectRatio",
+                "This model does not support aspect ratio. Use `size` instead.",
+            ));
+        }
+        if options.seed.is_some() {
+            warnings.push(ImageModelCallWarning::unsupported_setting("seed"));
+        }
+
+        let mut body = json!({
+            "model": self.model_id,
+            "prompt": options.prompt,
+            "n": options.n,
+        });
+
+        if let Some(ref size) = options.size {
+            body["size"] = json!(size.to_string());
+        }
+        if !has_default_response_format(&self.model_id) {
+            body["response_format"] = json!("b64_json");
+        }
+
+        // Typed provider options are serialized in the API's snake_case format
+        if let Some(quality) = openai_options.quality {
+            body["quality"] = json!(quality);
+        }
+        if let Some(style) = openai_options.style {
+            body["style"] = json!(style);
+        }
+        if let Some(background) = openai_options.background {
+            body["background"] = json!(background);
+        }
+        if let Some(output_format) = openai_options.output_format {
+            body["output_format"] = json!(output_format);
+        }
+        if let Some(output_compression) = openai_options.output_compression {
+            body["output_compression"] = json!(output_compression);
+        }
+        if let Some(moderation) = openai_options.moderation {
+            body["moderation"] = json!(moderation);
+        }
+        if let Some(user) = openai_options.user {
+            body["user"] = json!(user);
+        }
+
+        Ok((body, warnings))
+    }
+}
+
+#[async_trait]
+impl ImageModel for OpenAIImageModel {
+    fn specification_version(&self) -> &str {
+        "v3"
+    }
+
+    fn provider(&self) -> &str {
+        &self.config.provider
+    }
+
+    fn model_id(&self) -> &str {
+        &self.model_id
+    }
+
+    async fn max_images_per_call(&self, model_id: &str) -> Option<usize> {
+        Some(openai_image_model_max_images_per_call(model_id))
+    }
+
+    async fn do_generate(
+        &self,
+        options: ImageModelCallOptions,
+    ) -> Result<ImageModelResponse, Box<dyn std::error::Error>> {
+        let timestamp = SystemTime::now();
+        let (body, warnings) = self.build_request_body(&options)?;
+
+        // Make HTTP request
+        let client = &self.config.http_client;
+        let url = format!("{}/images/generations", self.config.base_url);
+        let mut headers = (self.config.headers)();
+        if let Some(ref option_headers) = options.headers {
+            headers.extend(option_headers.clone());
+        }
+
+        let mut request = client.post(&url).json(&body);
+        for (key, value) in headers {
+            request = request.header(key, value);
+        }
+
+        let response = if let Some(signal) = &options.abort_signal {
+            tokio::select! {
+                result = request.send() => result?,
+                _ = signal.cancelled() => {
+                    return Err("Operation cancelled".into());
+                }
+            }
+        } else {
+            request.send().await?
+        };
+
+        let status = response.status();
+        let response_headers: HashMap<String, String> = response
+            .headers()
+            .iter()
+            .map(|(k, v)| (k.as_str().to_string(), v.to_str().unwrap_or("").to_string()))
+            .collect();
+        let response_text = response.text().await?;
+
+        if !status.is_success() {
+            return Err(Box::new(parse_openai_error(
+                &url,
+                &body,
+                status.as_u16(),
+                response_headers,
+                &response_text,
+            )));
+        }
+
+        let response_body: OpenAIImageResponse = serde_json::from_str(&response_text)?;
+
+        let images = response_body
+            .data
+            .iter()
+            .map(|item| ImageData::Base64(item.b64_json.clone()))
+            .collect();
+
+        let response_metadata =
+            ImageModelResponseMetadata::with_timestamp(self.model_id.clone(), timestamp)
+                .with_headers(response_headers);
+
+        Ok(ImageModelResponse::new(images, response_metadata)
+            .with_warnings(warnings)
+            .with_provider_metadata(image_provider_metadata(&response_body)))
+    }
+}
+
+/// Build the `openai` provider metadata with the revised prompt of each image.
+fn image_provider_metadata(response: &OpenAIImageResponse) -> ImageModelProviderMetadata {
+    let images: Vec<JsonValue> = response
+        .data
+        .iter()
+        .map(|item| match &item.revised_prompt {
+            Some(revised_prompt) => json!({ "revisedPrompt": revised_prompt }),
+            None => JsonValue::Null,
+        })
+        .collect();
+
+    let mut openai = HashMap::new();
+    openai.insert("images".to_string(), JsonValue::Array(images));
+
+    let mut metadata = HashMap::new();
+    metadata.insert("openai".to_string(), openai);
+    metadata
+}
+
+/// OpenAI image API response
+#[derive(Debug, Deserialize)]
+struct OpenAIImageResponse {
+    data: Vec<OpenAIImageData>,
+}
+
+/// A single generated image
+#[derive(Debug, Deserialize)]
+struct OpenAIImageData {
+    b64_json: String,
+    #[serde(default)]
+    revised_prompt: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use llm_kit_provider::image_model::call_options::{AspectRatio, ImageSize};
+
+    fn create_model(model_id: &str) -> OpenAIImageModel {
+        OpenAIImageModel::new(
+            model_id.to_string(),
+            OpenAIImageConfig::new(
+                "openai.image".to_string(),
+                "https://api.openai.com/v1".to_string(),
+                Arc::new(HashMap::new),
+            ),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_model_properties() {
+        let model = create_model("dall-e-3");
+
+        assert_eq!(model.model_id(), "dall-e-3");
+        assert_eq!(model.provider(), "openai.image");
+        assert_eq!(model.max_images_per_call("dall-e-3").await, Some(1));
+    }
+
+    #[test]
+    fn test_build_request_body_with_options() {
+        let model = create_model("dall-e-3");
+
+        let mut openai_options = HashMap::new();
+        openai_options.insert("quality".to_string(), json!("hd"));
+        openai_options.insert("style".to_string(), json!("vivid"));
+        let mut provider_options = HashMap::new();
+        provider_options.insert("openai".to_string(), openai_options);
+
+        let options = ImageModelCallOptions::new("A cat", 1)
+            .with_size(ImageSize::new(1024, 1792))
+            .with_provider_options(provider_options);
+        let (body, warnings) = model.build_request_body(&options).unwrap();
+
+        assert_eq!(body["model"], "dall-e-3");
+        assert_eq!(body["size"], "1024x1792");
+        assert_eq!(body["response_format"], "b64_json");
+        assert_eq!(body["quality"], "hd");
+        assert_eq!(body["style"], "vivid");
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_gpt_image_omits_response_format() {
+        let model = create_model("gpt-image-1");
+
+        let mut openai_options = HashMap::new();
+        openai_options.insert("outputFormat".to_string(), json!("webp"));
+        openai_options.insert("outputCompression".to_string(), json!(80));
+        let mut provider_options = HashMap::new();
+        provider_options.insert("openai".to_string(), openai_options);
+
+        let options =
+            ImageModelCallOptions::new("A cat", 2).with_provider_options(provider_options);
+        let (body, _) = model.build_request_body(&options).unwrap();
+
+        assert!(body.get("response_format").is_none());
+        assert_eq!(body["output_format"], "webp");
+        assert_eq!(body["output_compression"], 80);
+    }
+
+    #[test]
+    fn test_unsupported_settings_warnings() {
+        let model = create_model("dall-e-2");
+        let options = ImageModelCallOptions::new("A cat", 1)
+            .with_aspect_ratio(AspectRatio::ratio_16_9())
+            .with_seed(42);
+
+        let (_, warnings) = model.build_request_body(&options).unwrap();
+        assert_eq!(warnings.len(), 2);
+    }
+
+    #[test]
+    fn test_provider_metadata_revised_prompt() {
+        let response: OpenAIImageResponse = serde_json::from_value(json!({
+            "created": 1,
+            "data": [{ "b64_json": "abc", "revised_prompt": "A fluffy cat" }]
+        }))
+        .unwrap();
+
+        let metadata = image_provider_metadata(&response);
+        assert_eq!(
+            metadata["openai"]["images"],
+            json!([{ "revisedPrompt": "A fluffy cat" }])
+        );
+    }
+}
@@ -0,0 +1,163 @@
+//! OpenAI Image Options
+//!
+//! Provider options and model capabilities for the OpenAI image model.
+
+use serde::{Deserialize, Serialize};
+
+/// OpenAI image model identifier (e.g. `dall-e-3`, `gpt-image-1`).
+pub type OpenAIImageModelId = String;
+
+/// Quality of the generated images.
+///
+/// `standard` and `hd` apply to `dall-e-3`; `low`, `medium` and `high` apply to
+/// `gpt-image-1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageQuality {
+    /// Let the model pick the quality
+    Auto,
+    /// Standard quality (`dall-e-3`)
+    Standard,
+    /// High definition (`dall-e-3`)
+    Hd,
+    /// Low quality (`gpt-image-1`)
+    Low,
+    /// Medium quality (`gpt-image-1`)
+    Medium,
+    /// High quality (`gpt-image-1`)
+    High,
+}
+
+/// Style of the generated images (`dall-e-3` only).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageStyle {
+    /// Hyper-real and dramatic images
+    Vivid,
+    /// More natural, less hyper-real images
+    Natural,
+}
+
+/// Provider options for OpenAI image models.
+///
+/// Passed through `provider_options["openai"]`.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenAIImageProviderOptions {
+    /// Quality of the generated images.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quality: Option<ImageQuality>,
+
+    /// Style of the generated images (`dall-e-3` only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ImageStyle>,
+
+    /// Background transparency: `transparent`, `opaque` or `auto` (`gpt-image-1` only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<String>,
+
+    /// Output format: `png`, `jpeg` or `webp` (`gpt-image-1` only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_format: Option<String>,
+
+    /// Compression level (0-100) for `jpeg` and `webp` outputs (`gpt-image-1` only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_compression: Option<u8>,
+
+    /// Content moderation level: `low` or `auto` (`gpt-image-1` only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moderation: Option<String>,
+
+    /// A unique identifier representing your end-user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+impl OpenAIImageProviderOptions {
+    /// Creates new empty options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the image quality.
+    pub fn with_quality(mut self, quality: ImageQuality) -> Self {
+        self.quality = Some(quality);
+        self
+    }
+
+    /// Sets the image style.
+    pub fn with_style(mut self, style: ImageStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Sets the background transparency.
+    pub fn with_background(mut self, background: impl Into<String>) -> Self {
+        self.background = Some(background.into());
+        self
+    }
+
+    /// Sets the output format.
+    pub fn with_output_format(mut self, output_format: impl Into<String>) -> Self {
+        self.output_format = Some(output_format.into());
+        self
+    }
+
+    /// Sets the output compression level.
+    pub fn with_output_compression(mut self, output_compression: u8) -> Self {
+        self.output_compression = Some(output_compression);
+        self
+    }
+
+    /// Sets the moderation level.
+    pub fn with_moderation(mut self, moderation: impl Into<String>) -> Self {
+        self.moderation = Some(moderation.into());
+        self
+    }
+
+    /// Sets the end-user identifier.
+    pub fn with_user(mut self, user: impl Into<String>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+}
+
+/// Maximum number of images per call for a model.
+pub fn openai_image_model_max_images_per_call(model_id: &str) -> usize {
+    match model_id {
+        "dall-e-3" => 1,
+        _ => 10,
+    }
+}
+
+/// Whether the model always returns base64 data and rejects `response_format`.
+pub fn has_default_response_format(model_id: &str) -> bool {
+    model_id.starts_with("gpt-image")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_serialize_options() {
+        let options = OpenAIImageProviderOptions::new()
+            .with_quality(ImageQuality::Hd)
+            .with_style(ImageStyle::Natural)
+            .with_output_format("webp");
+
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            json!({ "quality": "hd", "style": "natural", "outputFormat": "webp" })
+        );
+    }
+
+    #[test]
+    fn test_model_capabilities() {
+        assert_eq!(openai_image_model_max_images_per_call("dall-e-3"), 1);
+        assert_eq!(openai_image_model_max_images_per_call("dall-e-2"), 10);
+        assert!(has_default_response_format("gpt-image-1"));
+        assert!(!has_default_response_format("dall-e-3"));
+    }
+}
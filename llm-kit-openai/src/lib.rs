@@ -2,16 +2,18 @@
 //!
 //! OpenAI provider implementation for the LLM Kit.
 //!
-//! This crate provides integration with OpenAI's chat completion API,
+//! This crate provides integration with OpenAI's chat completion and Responses APIs,
 //! following the LLM Kit provider pattern for Rust.
 //!
 //! ## Features
 //!
 //! - Chat completions with streaming support
+//! - Responses API with reasoning summaries, `previous_response_id` and built-in tools
 //! - Tool calling (function calling)
 //! - Multi-modal inputs (text, images, audio, PDFs)
 //! - Reasoning models support (o1, o3, etc.)
 //! - Provider-specific options (logprobs, reasoning effort, service tiers, etc.)
+//! - Embeddings, image generation, speech synthesis and transcription
 //! - Type-safe configuration
 //!
 //! ## Quick Start
@@ -97,6 +99,9 @@
 //!
 //! - **Provider** (`OpenAIProvider`): Creates model instances
 //! - **Language Model** (`OpenAIChatLanguageModel`): Implements text generation and streaming
+//! - **Responses Model** (`OpenAIResponsesLanguageModel`): Implements the `/responses` API
+//! - **Embedding, Image, Speech and Transcription Models**: Implement the `/embeddings`,
+//!   `/images/generations`, `/audio/speech` and `/audio/transcriptions` APIs
 //! - **Message Conversion**: Converts SDK messages to OpenAI format
 //! - **Tool Preparation**: Converts SDK tools to OpenAI function format
 //! - **API Types**: Request and response types for the OpenAI API
@@ -116,6 +121,45 @@
 //! };
 //! ```
 //!
+//! ## Responses API
+//!
+//! `OpenAIProvider::responses` creates a model backed by the `/responses` endpoint.
+//! Options are read from `provider_options["openai"]` (see
+//! [`responses::OpenAIResponsesProviderOptions`]) and built-in tools are available in
+//! [`openai_tools`]:
+//!
+//! ```no_run
+//! use llm_kit_openai::{OpenAIClient, openai_tools};
+//!
+//! let provider = OpenAIClient::new().api_key("your-api-key").build();
+//! let model = provider.responses("gpt-5");
+//! let web_search = openai_tools::web_search().search_context_size("medium").build();
+//! ```
+//!
+//! Reasoning items are returned with their OpenAI item id (and, when `store` is `false`,
+//! their encrypted content) in the provider metadata, so multi-step generations send them
+//! back to the API automatically.
+//!
+//! ## Embeddings, Images, Speech and Transcription
+//!
+//! ```no_run
+//! use llm_kit_openai::OpenAIClient;
+//!
+//! let provider = OpenAIClient::new().api_key("your-api-key").build();
+//!
+//! let embedding_model = provider.text_embedding("text-embedding-3-small");
+//! let image_model = provider.image("dall-e-3");
+//! let speech_model = provider.speech("gpt-4o-mini-tts");
+//! let transcription_model = provider.transcription("whisper-1");
+//! ```
+//!
+//! Each model reads typed options from `provider_options["openai"]`:
+//! [`embedding::OpenAIEmbeddingProviderOptions`] (`dimensions`, `user`),
+//! [`image::OpenAIImageProviderOptions`] (`quality`, `style`, ...),
+//! [`speech::OpenAISpeechProviderOptions`] (`instructions`, `speed`) and
+//! [`transcription::OpenAITranscriptionProviderOptions`] (`language`,
+//! `timestampGranularities`, ...). `whisper-1` transcriptions include verbose segments.
+//!
 //! ## Supported Models
 //!
 //! All OpenAI chat models are supported, including:
@@ -140,13 +184,34 @@
 pub mod chat;
 /// Client builder for creating OpenAI providers
 pub mod client;
+/// Embeddings API implementation
+pub mod embedding;
+/// Error handling for the OpenAI API
+pub mod error;
+/// Images API implementation
+pub mod image;
+/// OpenAI built-in tools for the Responses API
+pub mod openai_tools;
 /// OpenAI provider implementation
 pub mod provider;
+/// Responses API implementation
+pub mod responses;
 /// Settings and configuration for OpenAI providers
 pub mod settings;
+/// Speech (text-to-speech) API implementation
+pub mod speech;
+/// Transcription (speech-to-text) API implementation
+pub mod transcription;
 
 // Re-export main types for convenience
 pub use chat::{OpenAIChatLanguageModel, OpenAIChatLanguageModelOptions, OpenAIChatModelId};
 pub use client::OpenAIClient;
+pub use embedding::{OpenAIEmbeddingModel, OpenAIEmbeddingProviderOptions};
+pub use image::{OpenAIImageModel, OpenAIImageProviderOptions};
 pub use provider::OpenAIProvider;
+pub use responses::{
+    OpenAIResponsesLanguageModel, OpenAIResponsesModelId, OpenAIResponsesProviderOptions,
+};
 pub use settings::OpenAIProviderSettings;
+pub use speech::{OpenAISpeechModel, OpenAISpeechProviderOptions};
+pub use transcription::{OpenAITranscriptionModel, OpenAITranscriptionProviderOptions};
@@ -0,0 +1,256 @@
+//! OpenAI built-in tools for the Responses API.
+//!
+//! These tools are executed by OpenAI and are only supported by the Responses
+//! language model (`OpenAIProvider::responses`). Pass them in a `ToolSet` like any other tool.
+//!
+//! # Example
+//!
+//! ```
+//! use llm_kit_openai::openai_tools;
+//!
+//! let search = openai_tools::web_search()
+//!     .search_context_size("high")
+//!     .user_location(Some("Berlin"), None::<&str>, Some("DE"), None::<&str>)
+//!     .build();
+//! let files = openai_tools::file_search(vec!["vs_123".to_string()])
+//!     .max_num_results(5)
+//!     .build();
+//! let code = openai_tools::code_interpreter(None);
+//! let image = openai_tools::image_generation(None);
+//! ```
+
+use llm_kit_provider_utils::tool::{ProviderDefinedToolFactory, ProviderDefinedToolOptions, Tool};
+use serde_json::json;
+
+/// Builder for the web search tool (`openai.web_search` / `openai.web_search_preview`).
+#[derive(Clone, Debug)]
+pub struct WebSearchBuilder {
+    id: &'static str,
+    name: &'static str,
+    search_context_size: Option<String>,
+    user_location: Option<serde_json::Value>,
+    allowed_domains: Option<Vec<String>>,
+}
+
+impl WebSearchBuilder {
+    fn new(id: &'static str, name: &'static str) -> Self {
+        Self {
+            id,
+            name,
+            search_context_size: None,
+            user_location: None,
+            allowed_domains: None,
+        }
+    }
+
+    /// Sets how much context is retrieved from the web (`low`, `medium` or `high`).
+    pub fn search_context_size(mut self, size: impl Into<String>) -> Self {
+        self.search_context_size = Some(size.into());
+        self
+    }
+
+    /// Sets the approximate user location for geographically relevant results.
+    pub fn user_location(
+        mut self,
+        city: Option<impl Into<String>>,
+        region: Option<impl Into<String>>,
+        country: Option<impl Into<String>>,
+        timezone: Option<impl Into<String>>,
+    ) -> Self {
+        self.user_location = Some(json!({
+            "type": "approximate",
+            "city": city.map(Into::into),
+            "region": region.map(Into::into),
+            "country": country.map(Into::into),
+            "timezone": timezone.map(Into::into),
+        }));
+        self
+    }
+
+    /// Restricts the search to the given domains (`web_search` only).
+    pub fn allowed_domains(mut self, domains: Vec<String>) -> Self {
+        self.allowed_domains = Some(domains);
+        self
+    }
+
+    /// Builds the configured tool.
+    pub fn build(self) -> Tool {
+        let factory = ProviderDefinedToolFactory::new(self.id, self.name, json!({}));
+
+        let mut opts = ProviderDefinedToolOptions::new();
+        if let Some(size) = self.search_context_size {
+            opts = opts.with_arg("searchContextSize", json!(size));
+        }
+        if let Some(location) = self.user_location {
+            opts = opts.with_arg("userLocation", location);
+        }
+        if let Some(domains) = self.allowed_domains {
+            opts = opts.with_arg("allowedDomains", json!(domains));
+        }
+
+        factory.create(opts)
+    }
+}
+
+/// Creates a web search tool (`openai.web_search`).
+pub fn web_search() -> WebSearchBuilder {
+    WebSearchBuilder::new("openai.web_search", "web_search")
+}
+
+/// Creates a web search preview tool (`openai.web_search_preview`).
+pub fn web_search_preview() -> WebSearchBuilder {
+    WebSearchBuilder::new("openai.web_search_preview", "web_search_preview")
+}
+
+/// Builder for the file search tool (`openai.file_search`).
+#[derive(Clone, Debug)]
+pub struct FileSearchBuilder {
+    vector_store_ids: Vec<String>,
+    max_num_results: Option<u32>,
+    ranker: Option<String>,
+    score_threshold: Option<f64>,
+    filters: Option<serde_json::Value>,
+}
+
+impl FileSearchBuilder {
+    /// Sets the maximum number of results to return.
+    pub fn max_num_results(mut self, max_num_results: u32) -> Self {
+        self.max_num_results = Some(max_num_results);
+        self
+    }
+
+    /// Sets the ranking options for the search.
+    pub fn ranking(mut self, ranker: impl Into<String>, score_threshold: Option<f64>) -> Self {
+        self.ranker = Some(ranker.into());
+        self.score_threshold = score_threshold;
+        self
+    }
+
+    /// Sets attribute filters for the search (OpenAI filter object).
+    pub fn filters(mut self, filters: serde_json::Value) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    /// Builds the configured tool.
+    pub fn build(self) -> Tool {
+        let factory =
+            ProviderDefinedToolFactory::new("openai.file_search", "file_search", json!({}));
+
+        let mut opts = ProviderDefinedToolOptions::new()
+            .with_arg("vectorStoreIds", json!(self.vector_store_ids));
+        if let Some(max) = self.max_num_results {
+            opts = opts.with_arg("maxNumResults", json!(max));
+        }
+        if let Some(ranker) = self.ranker {
+            opts = opts.with_arg(
+                "ranking",
+                json!({ "ranker": ranker, "scoreThreshold": self.score_threshold }),
+            );
+        }
+        if let Some(filters) = self.filters {
+            opts = opts.with_arg("filters", filters);
+        }
+
+        factory.create(opts)
+    }
+}
+
+/// Creates a file search tool (`openai.file_search`) over the given vector stores.
+pub fn file_search(vector_store_ids: Vec<String>) -> FileSearchBuilder {
+    FileSearchBuilder {
+        vector_store_ids,
+        max_num_results: None,
+        ranker: None,
+        score_threshold: None,
+        filters: None,
+    }
+}
+
+/// Creates a code interpreter tool (`openai.code_interpreter`).
+///
+/// Use the `container` arg to pass a container id (string) or `{"fileIds": [...]}`;
+/// an automatic container is used otherwise.
+pub fn code_interpreter(options: Option<ProviderDefinedToolOptions>) -> Tool {
+    ProviderDefinedToolFactory::new(
+        "openai.code_interpreter",
+        "code_interpreter",
+        json!({
+            "type": "object",
+            "properties": {
+                "code": { "type": ["string", "null"] },
+                "containerId": { "type": "string" }
+            }
+        }),
+    )
+    .create(options.unwrap_or_default())
+}
+
+/// Creates an image generation tool (`openai.image_generation`).
+///
+/// Args such as `quality`, `size`, `outputFormat` or `background` can be set
+/// through `ProviderDefinedToolOptions::with_arg`.
+pub fn image_generation(options: Option<ProviderDefinedToolOptions>) -> Tool {
+    ProviderDefinedToolFactory::new("openai.image_generation", "image_generation", json!({}))
+        .create(options.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use llm_kit_provider_utils::tool::ToolType;
+
+    #[test]
+    fn test_web_search_args() {
+        let tool = web_search()
+            .search_context_size("low")
+            .allowed_domains(vec!["docs.rs".to_string()])
+            .build();
+
+        match tool.tool_type {
+            ToolType::ProviderDefined { id, name, args } => {
+                assert_eq!(id, "openai.web_search");
+                assert_eq!(name, "web_search");
+                assert_eq!(args["searchContextSize"], "low");
+                assert_eq!(args["allowedDomains"], json!(["docs.rs"]));
+            }
+            _ => panic!("expected provider-defined tool"),
+        }
+    }
+
+    #[test]
+    fn test_file_search_args() {
+        let tool = file_search(vec!["vs_1".to_string()])
+            .max_num_results(3)
+            .build();
+
+        match tool.tool_type {
+            ToolType::ProviderDefined { id, args, .. } => {
+                assert_eq!(id, "openai.file_search");
+                assert_eq!(args["vectorStoreIds"], json!(["vs_1"]));
+                assert_eq!(args["maxNumResults"], 3);
+            }
+            _ => panic!("expected provider-defined tool"),
+        }
+    }
+
+    #[test]
+    fn test_code_interpreter_and_image_generation() {
+        let tool = code_interpreter(None);
+        assert!(matches!(
+            tool.tool_type,
+            ToolType::ProviderDefined { ref id, .. } if id == "openai.code_interpreter"
+        ));
+
+        let tool = image_generation(Some(
+            ProviderDefinedToolOptions::new().with_arg("quality", json!("low")),
+        ));
+        match tool.tool_type {
+            ToolType::ProviderDefined { id, args, .. } => {
+                assert_eq!(id, "openai.image_generation");
+                assert_eq!(args["quality"], "low");
+            }
+            _ => panic!("expected provider-defined tool"),
+        }
+    }
+}
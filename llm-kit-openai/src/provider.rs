@@ -4,7 +4,17 @@
 
 use crate::chat::openai_chat_language_model::OpenAIChatConfig;
 use crate::chat::{OpenAIChatLanguageModel, OpenAIChatModelId};
+use crate::embedding::openai_embedding_model::OpenAIEmbeddingConfig;
+use crate::embedding::{OpenAIEmbeddingModel, OpenAIEmbeddingModelId};
+use crate::image::openai_image_model::OpenAIImageConfig;
+use crate::image::{OpenAIImageModel, OpenAIImageModelId};
+use crate::responses::openai_responses_language_model::OpenAIResponsesConfig;
+use crate::responses::{OpenAIResponsesLanguageModel, OpenAIResponsesModelId};
 use crate::settings::OpenAIProviderSettings;
+use crate::speech::openai_speech_model::OpenAISpeechConfig;
+use crate::speech::{OpenAISpeechModel, OpenAISpeechModelId};
+use crate::transcription::openai_transcription_model::OpenAITranscriptionConfig;
+use crate::transcription::{OpenAITranscriptionModel, OpenAITranscriptionModelId};
 use llm_kit_provider::embedding_model::EmbeddingModel;
 use llm_kit_provider::error::ProviderError;
 use llm_kit_provider::image_model::ImageModel;
@@ -22,6 +32,7 @@ pub struct OpenAIProvider {
     base_url: String,
     provider_name: String,
     headers: Arc<dyn Fn() -> HashMap<String, String> + Send + Sync>,
+    http_client: reqwest::Client,
 }
 
 impl OpenAIProvider {
@@ -75,6 +86,7 @@ impl OpenAIProvider {
         let organization = settings.organization;
         let project = settings.project;
         let custom_headers = settings.headers.unwrap_or_default();
+        let http_client = settings.http_client.unwrap_or_default();
 
         // Create headers closure
         let headers_fn = Arc::new(move || {
@@ -104,6 +116,7 @@ impl OpenAIProvider {
             base_url,
             provider_name,
             headers: headers_fn,
+            http_client,
         }
     }
 
@@ -112,11 +125,40 @@ impl OpenAIProvider {
         self.create_chat_model(model_id.into())
     }
 
+    /// Create a Responses API model with the given model ID.
+    ///
+    /// The Responses API supports reasoning summaries, `previous_response_id`
+    /// continuation, encrypted reasoning items and OpenAI built-in tools
+    /// (see [`crate::openai_tools`]).
+    pub fn responses(&self, model_id: impl Into<String>) -> OpenAIResponsesLanguageModel {
+        self.create_responses_model(model_id.into())
+    }
+
     /// Create a language model with the given model ID.
     pub fn language_model(&self, model_id: impl Into<String>) -> OpenAIChatLanguageModel {
         self.create_chat_model(model_id.into())
     }
 
+    /// Create a text embedding model with the given model ID.
+    pub fn text_embedding(&self, model_id: impl Into<String>) -> OpenAIEmbeddingModel {
+        self.create_embedding_model(model_id.into())
+    }
+
+    /// Create an image model with the given model ID.
+    pub fn image(&self, model_id: impl Into<String>) -> OpenAIImageModel {
+        self.create_image_model(model_id.into())
+    }
+
+    /// Create a speech (text-to-speech) model with the given model ID.
+    pub fn speech(&self, model_id: impl Into<String>) -> OpenAISpeechModel {
+        self.create_speech_model(model_id.into())
+    }
+
+    /// Create a transcription (speech-to-text) model with the given model ID.
+    pub fn transcription(&self, model_id: impl Into<String>) -> OpenAITranscriptionModel {
+        self.create_transcription_model(model_id.into())
+    }
+
     /// Get the base URL for this provider (for testing).
     #[doc(hidden)]
     pub fn base_url(&self) -> &str {
@@ -134,10 +176,72 @@ impl OpenAIProvider {
             format!("{}.chat", self.provider_name),
             self.base_url.clone(),
             self.headers.clone(),
-        );
+        )
+        .with_http_client(self.http_client.clone());
 
         OpenAIChatLanguageModel::new(model_id, config)
     }
+
+    fn create_responses_model(
+        &self,
+        model_id: OpenAIResponsesModelId,
+    ) -> OpenAIResponsesLanguageModel {
+        let config = OpenAIResponsesConfig::new(
+            format!("{}.responses", self.provider_name),
+            self.base_url.clone(),
+            self.headers.clone(),
+        )
+        .with_http_client(self.http_client.clone());
+
+        OpenAIResponsesLanguageModel::new(model_id, config)
+    }
+
+    fn create_embedding_model(&self, model_id: OpenAIEmbeddingModelId) -> OpenAIEmbeddingModel {
+        let config = OpenAIEmbeddingConfig::new(
+            format!("{}.embedding", self.provider_name),
+            self.base_url.clone(),
+            self.headers.clone(),
+        )
+        .with_http_client(self.http_client.clone());
+
+        OpenAIEmbeddingModel::new(model_id, config)
+    }
+
+    fn create_image_model(&self, model_id: OpenAIImageModelId) -> OpenAIImageModel {
+        let config = OpenAIImageConfig::new(
+            format!("{}.image", self.provider_name),
+            self.base_url.clone(),
+            self.headers.clone(),
+        )
+        .with_http_client(self.http_client.clone());
+
+        OpenAIImageModel::new(model_id, config)
+    }
+
+    fn create_speech_model(&self, model_id: OpenAISpeechModelId) -> OpenAISpeechModel {
+        let config = OpenAISpeechConfig::new(
+            format!("{}.speech", self.provider_name),
+            self.base_url.clone(),
+            self.headers.clone(),
+        )
+        .with_http_client(self.http_client.clone());
+
+        OpenAISpeechModel::new(model_id, config)
+    }
+
+    fn create_transcription_model(
+        &self,
+        model_id: OpenAITranscriptionModelId,
+    ) -> OpenAITranscriptionModel {
+        let config = OpenAITranscriptionConfig::new(
+            format!("{}.transcription", self.provider_name),
+            self.base_url.clone(),
+            self.headers.clone(),
+        )
+        .with_http_client(self.http_client.clone());
+
+        OpenAITranscriptionModel::new(model_id, config)
+    }
 }
 
 impl Provider for OpenAIProvider {
@@ -153,22 +257,24 @@ impl Provider for OpenAIProvider {
         &self,
         model_id: &str,
     ) -> Result<Arc<dyn EmbeddingModel<String>>, ProviderError> {
-        Err(ProviderError::no_such_model(model_id, "textEmbeddingModel"))
+        Ok(Arc::new(self.create_embedding_model(model_id.to_string())))
     }
 
     fn image_model(&self, model_id: &str) -> Result<Arc<dyn ImageModel>, ProviderError> {
-        Err(ProviderError::no_such_model(model_id, "imageModel"))
+        Ok(Arc::new(self.create_image_model(model_id.to_string())))
     }
 
     fn transcription_model(
         &self,
         model_id: &str,
     ) -> Result<Arc<dyn TranscriptionModel>, ProviderError> {
-        Err(ProviderError::no_such_model(model_id, "transcriptionModel"))
+        Ok(Arc::new(
+            self.create_transcription_model(model_id.to_string()),
+        ))
     }
 
     fn speech_model(&self, model_id: &str) -> Result<Arc<dyn SpeechModel>, ProviderError> {
-        Err(ProviderError::no_such_model(model_id, "speechModel"))
+        Ok(Arc::new(self.create_speech_model(model_id.to_string())))
     }
 
     fn reranking_model(&self, model_id: &str) -> Result<Arc<dyn RerankingModel>, ProviderError> {
@@ -234,6 +340,43 @@ mod tests {
         assert_eq!(model.provider(), "openai.chat");
     }
 
+    #[test]
+    fn test_create_responses_model() {
+        let provider = OpenAIProvider::new(OpenAIProviderSettings::new().with_api_key("test-key"));
+
+        let model = provider.responses("gpt-5");
+        assert_eq!(model.model_id(), "gpt-5");
+        assert_eq!(model.provider(), "openai.responses");
+    }
+
+    #[test]
+    fn test_create_embedding_image_speech_transcription_models() {
+        let provider = OpenAIProvider::new(OpenAIProviderSettings::new().with_api_key("test-key"));
+
+        let embedding = provider.text_embedding("text-embedding-3-small");
+        assert_eq!(embedding.provider(), "openai.embedding");
+
+        let image = provider.image("dall-e-3");
+        assert_eq!(image.provider(), "openai.image");
+
+        let speech = provider.speech("tts-1");
+        assert_eq!(speech.provider(), "openai.speech");
+
+        let transcription = provider.transcription("whisper-1");
+        assert_eq!(transcription.provider(), "openai.transcription");
+    }
+
+    #[test]
+    fn test_provider_trait_models() {
+        let provider = OpenAIProvider::new(OpenAIProviderSettings::new().with_api_key("test-key"));
+
+        assert!(Provider::text_embedding_model(&provider, "text-embedding-3-small").is_ok());
+        assert!(Provider::image_model(&provider, "gpt-image-1").is_ok());
+        assert!(Provider::speech_model(&provider, "gpt-4o-mini-tts").is_ok());
+        assert!(Provider::transcription_model(&provider, "whisper-1").is_ok());
+        assert!(Provider::reranking_model(&provider, "rerank").is_err());
+    }
+
     #[test]
     fn test_provider_trait() {
         let provider = OpenAIProvider::new(OpenAIProviderSettings::new().with_api_key("test-key"));
@@ -0,0 +1,15 @@
+//! OpenAI Responses API Implementation
+//!
+//! This module contains all the components for the OpenAI Responses API (`/responses`).
+
+pub mod map_openai_responses_finish_reason;
+pub mod openai_responses_api;
+pub mod openai_responses_language_model;
+pub mod openai_responses_options;
+pub mod openai_responses_prepare_tools;
+pub mod openai_responses_prompt;
+
+pub use openai_responses_language_model::OpenAIResponsesLanguageModel;
+pub use openai_responses_options::{
+    OpenAIResponsesModelId, OpenAIResponsesProviderOptions, ReasoningSummary,
+};
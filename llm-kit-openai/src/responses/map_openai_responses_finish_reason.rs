@@ -0,0 +1,72 @@
+//! OpenAI Responses Finish Reason Mapping
+//!
+//! Maps the `incomplete_details.reason` of a response to SDK finish reason types.
+
+use llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason;
+
+/// Map an OpenAI Responses API finish reason to SDK finish reason.
+///
+/// # Arguments
+///
+/// * `finish_reason` - The `incomplete_details.reason` from the response, if any
+/// * `has_function_call` - Whether the response contains client-side function calls
+///
+/// # Returns
+///
+/// The corresponding SDK finish reason
+pub fn map_openai_responses_finish_reason(
+    finish_reason: Option<&str>,
+    has_function_call: bool,
+) -> LanguageModelFinishReason {
+    match finish_reason {
+        None => {
+            if has_function_call {
+                LanguageModelFinishReason::ToolCalls
+            } else {
+                LanguageModelFinishReason::Stop
+            }
+        }
+        Some("max_output_tokens") => LanguageModelFinishReason::Length,
+        Some("content_filter") => LanguageModelFinishReason::ContentFilter,
+        Some(_) => {
+            if has_function_call {
+                LanguageModelFinishReason::ToolCalls
+            } else {
+                LanguageModelFinishReason::Unknown
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_completed() {
+        assert_eq!(
+            map_openai_responses_finish_reason(None, false),
+            LanguageModelFinishReason::Stop
+        );
+        assert_eq!(
+            map_openai_responses_finish_reason(None, true),
+            LanguageModelFinishReason::ToolCalls
+        );
+    }
+
+    #[test]
+    fn test_map_incomplete() {
+        assert_eq!(
+            map_openai_responses_finish_reason(Some("max_output_tokens"), true),
+            LanguageModelFinishReason::Length
+        );
+        assert_eq!(
+            map_openai_responses_finish_reason(Some("content_filter"), false),
+            LanguageModelFinishReason::ContentFilter
+        );
+        assert_eq!(
+            map_openai_responses_finish_reason(Some("other"), false),
+            LanguageModelFinishReason::Unknown
+        );
+    }
+}
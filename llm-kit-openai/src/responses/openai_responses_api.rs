@@ -0,0 +1,394 @@
+//! OpenAI Responses API Response Types
+//!
+//! This module defines the response and streaming event structures for OpenAI's `/responses` endpoint.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+/// Response object returned by the OpenAI Responses API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenAIResponsesResponse {
+    /// Unique identifier of the response.
+    pub id: String,
+    /// Unix timestamp (in seconds) of when the response was created.
+    #[serde(default)]
+    pub created_at: Option<i64>,
+    /// The model used for the response.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Output items generated by the model.
+    #[serde(default)]
+    pub output: Vec<OpenAIResponsesOutputItem>,
+    /// Token usage for the response.
+    #[serde(default)]
+    pub usage: Option<OpenAIResponsesUsage>,
+    /// Details about why the response is incomplete.
+    #[serde(default)]
+    pub incomplete_details: Option<OpenAIResponsesIncompleteDetails>,
+    /// The service tier that processed the request.
+    #[serde(default)]
+    pub service_tier: Option<String>,
+    /// Error details when the response failed.
+    #[serde(default)]
+    pub error: Option<OpenAIResponsesError>,
+}
+
+/// Token usage of a response.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OpenAIResponsesUsage {
+    /// Number of input tokens.
+    pub input_tokens: u64,
+    /// Number of output tokens.
+    pub output_tokens: u64,
+    /// Total number of tokens.
+    #[serde(default)]
+    pub total_tokens: Option<u64>,
+    /// Breakdown of the input tokens.
+    #[serde(default)]
+    pub input_tokens_details: Option<InputTokensDetails>,
+    /// Breakdown of the output tokens.
+    #[serde(default)]
+    pub output_tokens_details: Option<OutputTokensDetails>,
+}
+
+/// Breakdown of input tokens.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InputTokensDetails {
+    /// Number of tokens served from the prompt cache.
+    #[serde(default)]
+    pub cached_tokens: Option<u64>,
+}
+
+/// Breakdown of output tokens.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OutputTokensDetails {
+    /// Number of reasoning tokens.
+    #[serde(default)]
+    pub reasoning_tokens: Option<u64>,
+}
+
+/// Reason a response is incomplete.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenAIResponsesIncompleteDetails {
+    /// The reason (e.g. `max_output_tokens`, `content_filter`).
+    pub reason: Option<String>,
+}
+
+/// Error object of a failed response or an `error` stream event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenAIResponsesError {
+    /// Error code.
+    #[serde(default)]
+    pub code: Option<String>,
+    /// Human-readable error message.
+    pub message: String,
+}
+
+/// An output item of a response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OpenAIResponsesOutputItem {
+    /// An assistant message.
+    Message {
+        /// Item ID.
+        id: String,
+        /// Message content parts.
+        #[serde(default)]
+        content: Vec<OpenAIResponsesOutputContent>,
+    },
+    /// A reasoning item.
+    Reasoning {
+        /// Item ID.
+        id: String,
+        /// Encrypted reasoning content (when requested via `include`).
+        #[serde(default)]
+        encrypted_content: Option<String>,
+        /// Reasoning summary parts.
+        #[serde(default)]
+        summary: Vec<ReasoningSummaryPart>,
+    },
+    /// A call to a client-side function tool.
+    FunctionCall {
+        /// Item ID.
+        id: String,
+        /// Call ID used to match the function output.
+        call_id: String,
+        /// Function name.
+        name: String,
+        /// JSON-encoded arguments.
+        #[serde(default)]
+        arguments: String,
+    },
+    /// A provider-executed web search.
+    WebSearchCall {
+        /// Item ID.
+        id: String,
+        /// Status of the call.
+        #[serde(default)]
+        status: Option<String>,
+        /// The search action that was performed.
+        #[serde(default)]
+        action: Option<JsonValue>,
+    },
+    /// A provider-executed file search.
+    FileSearchCall {
+        /// Item ID.
+        id: String,
+        /// Queries used for the search.
+        #[serde(default)]
+        queries: Vec<String>,
+        /// Search results (when requested via `include`).
+        #[serde(default)]
+        results: Option<JsonValue>,
+    },
+    /// A provider-executed code interpreter call.
+    CodeInterpreterCall {
+        /// Item ID.
+        id: String,
+        /// The executed code.
+        #[serde(default)]
+        code: Option<String>,
+        /// The container the code ran in.
+        #[serde(default)]
+        container_id: Option<String>,
+        /// Outputs of the execution.
+        #[serde(default)]
+        outputs: Option<JsonValue>,
+    },
+    /// A provider-executed image generation call.
+    ImageGenerationCall {
+        /// Item ID.
+        id: String,
+        /// Base64-encoded generated image.
+        #[serde(default)]
+        result: Option<String>,
+    },
+    /// Any other output item type.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Summary part of a reasoning item.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReasoningSummaryPart {
+    /// The summary text.
+    pub text: String,
+}
+
+/// Content part of an output message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OpenAIResponsesOutputContent {
+    /// Generated text.
+    OutputText {
+        /// The text.
+        text: String,
+        /// Annotations (citations) on the text.
+        #[serde(default)]
+        annotations: Vec<OpenAIResponsesAnnotation>,
+    },
+    /// A refusal from the model.
+    Refusal {
+        /// The refusal message.
+        refusal: String,
+    },
+    /// Any other content type.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Annotation on output text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OpenAIResponsesAnnotation {
+    /// A citation of a web page.
+    UrlCitation {
+        /// The cited URL.
+        url: String,
+        /// The title of the cited page.
+        #[serde(default)]
+        title: Option<String>,
+    },
+    /// A citation of an uploaded file.
+    FileCitation {
+        /// The cited file ID.
+        file_id: String,
+        /// The filename of the cited file.
+        #[serde(default)]
+        filename: Option<String>,
+    },
+    /// Any other annotation type.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A server-sent event of a streaming response.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type")]
+pub enum OpenAIResponsesChunk {
+    /// The response was created.
+    #[serde(rename = "response.created")]
+    ResponseCreated {
+        /// The (partial) response.
+        response: OpenAIResponsesResponse,
+    },
+    /// A new output item was added.
+    #[serde(rename = "response.output_item.added")]
+    OutputItemAdded {
+        /// Index of the item in the output array.
+        output_index: usize,
+        /// The (partial) item.
+        item: OpenAIResponsesOutputItem,
+    },
+    /// An output item is complete.
+    #[serde(rename = "response.output_item.done")]
+    OutputItemDone {
+        /// Index of the item in the output array.
+        output_index: usize,
+        /// The completed item.
+        item: OpenAIResponsesOutputItem,
+    },
+    /// A text delta.
+    #[serde(rename = "response.output_text.delta")]
+    OutputTextDelta {
+        /// The message item ID.
+        item_id: String,
+        /// The text delta.
+        delta: String,
+    },
+    /// An annotation was added to the output text.
+    #[serde(rename = "response.output_text.annotation.added")]
+    OutputTextAnnotationAdded {
+        /// The message item ID.
+        item_id: String,
+        /// The annotation.
+        annotation: OpenAIResponsesAnnotation,
+    },
+    /// A function call arguments delta.
+    #[serde(rename = "response.function_call_arguments.delta")]
+    FunctionCallArgumentsDelta {
+        /// The function call item ID.
+        item_id: String,
+        /// Index of the item in the output array.
+        output_index: usize,
+        /// The arguments delta.
+        delta: String,
+    },
+    /// A new reasoning summary part was added.
+    #[serde(rename = "response.reasoning_summary_part.added")]
+    ReasoningSummaryPartAdded {
+        /// The reasoning item ID.
+        item_id: String,
+        /// Index of the summary part.
+        summary_index: usize,
+    },
+    /// A reasoning summary text delta.
+    #[serde(rename = "response.reasoning_summary_text.delta")]
+    ReasoningSummaryTextDelta {
+        /// The reasoning item ID.
+        item_id: String,
+        /// Index of the summary part.
+        summary_index: usize,
+        /// The text delta.
+        delta: String,
+    },
+    /// The response finished (completed or incomplete).
+    #[serde(rename = "response.completed", alias = "response.incomplete")]
+    ResponseFinished {
+        /// The final response.
+        response: OpenAIResponsesResponse,
+    },
+    /// The response failed.
+    #[serde(rename = "response.failed")]
+    ResponseFailed {
+        /// The failed response.
+        response: OpenAIResponsesResponse,
+    },
+    /// An error occurred.
+    #[serde(rename = "error")]
+    Error {
+        /// Error code.
+        #[serde(default)]
+        code: Option<String>,
+        /// Error message.
+        message: String,
+    },
+    /// Any other event type.
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_deserialize_response() {
+        let response: OpenAIResponsesResponse = serde_json::from_value(json!({
+            "id": "resp_1",
+            "created_at": 1741257730,
+            "model": "o4-mini",
+            "output": [
+                {
+                    "type": "reasoning",
+                    "id": "rs_1",
+                    "encrypted_content": "enc",
+                    "summary": [{ "type": "summary_text", "text": "thinking" }]
+                },
+                {
+                    "type": "message",
+                    "id": "msg_1",
+                    "role": "assistant",
+                    "content": [{ "type": "output_text", "text": "hi", "annotations": [] }]
+                },
+                { "type": "mcp_list_tools", "id": "x" }
+            ],
+            "usage": { "input_tokens": 3, "output_tokens": 5 }
+        }))
+        .unwrap();
+
+        assert_eq!(response.output.len(), 3);
+        assert!(matches!(
+            &response.output[0],
+            OpenAIResponsesOutputItem::Reasoning { encrypted_content: Some(c), .. } if c == "enc"
+        ));
+        assert!(matches!(
+            response.output[2],
+            OpenAIResponsesOutputItem::Unknown
+        ));
+    }
+
+    #[test]
+    fn test_deserialize_chunks() {
+        let chunk: OpenAIResponsesChunk = serde_json::from_value(json!({
+            "type": "response.output_text.delta",
+            "item_id": "msg_1",
+            "output_index": 0,
+            "content_index": 0,
+            "delta": "Hel"
+        }))
+        .unwrap();
+        assert!(matches!(
+            chunk,
+            OpenAIResponsesChunk::OutputTextDelta { ref delta, .. } if delta == "Hel"
+        ));
+
+        let chunk: OpenAIResponsesChunk = serde_json::from_value(json!({
+            "type": "response.incomplete",
+            "response": { "id": "resp_1", "incomplete_details": { "reason": "max_output_tokens" } }
+        }))
+        .unwrap();
+        assert!(matches!(
+            chunk,
+            OpenAIResponsesChunk::ResponseFinished { .. }
+        ));
+
+        let chunk: OpenAIResponsesChunk = serde_json::from_value(json!({
+            "type": "response.in_progress",
+            "response": { "id": "resp_1" }
+        }))
+        .unwrap();
+        assert!(matches!(chunk, OpenAIResponsesChunk::Unknown));
+    }
+}
@@ -0,0 +1,1207 @@
+//! OpenAI Responses Language Model Implementation
+//!
+//! This module implements the `LanguageModel` trait for OpenAI's Responses API (`/responses`).
+
+use super::map_openai_responses_finish_reason::map_openai_responses_finish_reason;
+use super::openai_responses_api::{
+    OpenAIResponsesAnnotation, OpenAIResponsesChunk, OpenAIResponsesOutputContent,
+    OpenAIResponsesOutputItem, OpenAIResponsesResponse, OpenAIResponsesUsage,
+};
+use super::openai_responses_options::{
+    OpenAIResponsesModelId, OpenAIResponsesProviderOptions, is_responses_reasoning_model,
+};
+use super::openai_responses_prepare_tools::prepare_responses_tools;
+use super::openai_responses_prompt::convert_to_openai_responses_input;
+use crate::chat::openai_chat_prompt::SystemMessageMode;
+use crate::error::parse_openai_error;
+use async_trait::async_trait;
+use futures_util::Stream;
+use futures_util::StreamExt;
+use llm_kit_provider::language_model::call_options::{
+    LanguageModelCallOptions, LanguageModelResponseFormat,
+};
+use llm_kit_provider::language_model::call_warning::LanguageModelCallWarning;
+use llm_kit_provider::language_model::content::LanguageModelContent;
+use llm_kit_provider::language_model::content::reasoning::LanguageModelReasoning;
+use llm_kit_provider::language_model::content::source::LanguageModelSource;
+use llm_kit_provider::language_model::content::text::LanguageModelText;
+use llm_kit_provider::language_model::content::tool_call::LanguageModelToolCall;
+use llm_kit_provider::language_model::content::tool_result::LanguageModelToolResult;
+use llm_kit_provider::language_model::response_metadata::LanguageModelResponseMetadata;
+use llm_kit_provider::language_model::stream_part::LanguageModelStreamPart;
+use llm_kit_provider::language_model::stream_part::finish::LanguageModelStreamFinish;
+use llm_kit_provider::language_model::stream_part::reasoning_end::LanguageModelStreamReasoningEnd;
+use llm_kit_provider::language_model::stream_part::reasoning_start::LanguageModelStreamReasoningStart;
+use llm_kit_provider::language_model::stream_part::text_start::LanguageModelStreamTextStart;
+use llm_kit_provider::language_model::tool::LanguageModelTool;
+use llm_kit_provider::language_model::usage::LanguageModelUsage;
+use llm_kit_provider::language_model::{
+    LanguageModel, LanguageModelGenerateResponse, LanguageModelRequestMetadata,
+    LanguageModelStreamResponse, StreamResponseMetadata,
+};
+use llm_kit_provider::shared::provider_metadata::SharedProviderMetadata;
+use llm_kit_provider_utils::event_source::parse_json_event_stream;
+use regex::Regex;
+use serde_json::{Value as JsonValue, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Configuration for OpenAI Responses language model
+#[derive(Clone)]
+pub struct OpenAIResponsesConfig {
+    /// Provider name
+    pub provider: String,
+    /// Base URL for API calls
+    pub base_url: String,
+    /// Function to get headers
+    pub headers: Arc<dyn Fn() -> HashMap<String, String> + Send + Sync>,
+    /// HTTP client used to send requests
+    pub http_client: reqwest::Client,
+}
+
+impl OpenAIResponsesConfig {
+    /// Create a new config
+    pub fn new(
+        provider: String,
+        base_url: String,
+        headers: Arc<dyn Fn() -> HashMap<String, String> + Send + Sync>,
+    ) -> Self {
+        Self {
+            provider,
+            base_url,
+            headers,
+            http_client: reqwest::Client::new(),
+        }
+    }
+
+    /// Set the HTTP client used to send requests
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = http_client;
+        self
+    }
+}
+
+/// OpenAI Responses Language Model
+///
+/// Supports reasoning summaries, `previous_response_id` continuation, encrypted
+/// reasoning items (for `store: false`) and OpenAI built-in tools
+/// (web search, file search, code interpreter, image generation).
+pub struct OpenAIResponsesLanguageModel {
+    model_id: OpenAIResponsesModelId,
+    config: OpenAIResponsesConfig,
+}
+
+/// Helper struct to track streaming state across chunks
+#[derive(Default)]
+struct StreamState {
+    has_function_call: bool,
+    tool_calls: HashMap<usize, ToolCallState>,
+    reasoning: HashMap<String, ReasoningState>,
+    built_in_tool_names: HashMap<String, String>,
+}
+
+struct ToolCallState {
+    call_id: String,
+    arguments: String,
+}
+
+struct ReasoningState {
+    encrypted_content: Option<String>,
+    summary_parts: Vec<usize>,
+}
+
+impl OpenAIResponsesLanguageModel {
+    /// Create a new OpenAI Responses language model
+    pub fn new(model_id: OpenAIResponsesModelId, config: OpenAIResponsesConfig) -> Self {
+        Self { model_id, config }
+    }
+
+    fn url(&self) -> String {
+        format!("{}/responses", self.config.base_url)
+    }
+
+    /// Build request arguments from call options
+    fn build_request_args(
+        &self,
+        options: &LanguageModelCallOptions,
+    ) -> Result<(JsonValue, Vec<LanguageModelCallWarning>), Box<dyn std::error::Error>> {
+        let mut warnings = Vec::new();
+        let is_reasoning_model = is_responses_reasoning_model(&self.model_id);
+
+        // Parse provider options
+        let openai_options: OpenAIResponsesProviderOptions = match options
+            .provider_options
+            .as_ref()
+            .and_then(|o| o.get("openai"))
+        {
+            Some(openai_opts) => serde_json::from_value(serde_json::to_value(openai_opts)?)?,
+            None => OpenAIResponsesProviderOptions::default(),
+        };
+
+        let strict_json_schema = openai_options.strict_json_schema.unwrap_or(false);
+        let store = openai_options.store.unwrap_or(true);
+
+        // Check unsupported settings
+        if options.top_k.is_some() {
+            warnings.push(LanguageModelCallWarning::unsupported_setting("topK"));
+        }
+        if options.seed.is_some() {
+            warnings.push(LanguageModelCallWarning::unsupported_setting("seed"));
+        }
+        if options.presence_penalty.is_some() {
+            warnings.push(LanguageModelCallWarning::unsupported_setting(
+                "presencePenalty",
+            ));
+        }
+        if options.frequency_penalty.is_some() {
+            warnings.push(LanguageModelCallWarning::unsupported_setting(
+                "frequencyPenalty",
+            ));
+        }
+        if options.stop_sequences.is_some() {
+            warnings.push(LanguageModelCallWarning::unsupported_setting(
+                "stopSequences",
+            ));
+        }
+
+        // Convert prompt
+        let system_message_mode = if is_reasoning_model {
+            SystemMessageMode::Developer
+        } else {
+            SystemMessageMode::System
+        };
+        let (input, input_warnings) =
+            convert_to_openai_responses_input(&options.prompt, system_message_mode, store);
+        warnings.extend(input_warnings);
+
+        // Prepare tools
+        let (tools, tool_choice, tool_warnings) = prepare_responses_tools(
+            options.tools.as_deref(),
+            options.tool_choice.as_ref(),
+            strict_json_schema,
+        );
+        warnings.extend(tool_warnings);
+
+        let mut args = json!({
+            "model": self.model_id,
+            "input": input,
+        });
+
+        if let Some(max_tokens) = options.max_output_tokens {
+            args["max_output_tokens"] = json!(max_tokens);
+        }
+        if let Some(temp) = options.temperature {
+            args["temperature"] = json!(temp);
+        }
+        if let Some(top_p) = options.top_p {
+            args["top_p"] = json!(top_p);
+        }
+
+        // Structured outputs and verbosity live under `text`
+        let mut text = serde_json::Map::new();
+        if let Some(LanguageModelResponseFormat::Json {
+            schema,
+            name,
+            description,
+        }) = &options.response_format
+        {
+            let format = match schema {
+                Some(schema_val) => json!({
+                    "type": "json_schema",
+                    "strict": strict_json_schema,
+                    "name": name.as_deref().unwrap_or("response"),
+                    "description": description,
+                    "schema": schema_val,
+                }),
+                None => json!({ "type": "json_object" }),
+            };
+            text.insert("format".to_string(), format);
+        }
+        if let Some(ref verbosity) = openai_options.text_verbosity {
+            text.insert("verbosity".to_string(), json!(verbosity));
+        }
+        if !text.is_empty() {
+            args["text"] = JsonValue::Object(text);
+        }
+
+        // Add tools
+        if let Some(tools) = tools {
+            args["tools"] = json!(tools);
+        }
+        if let Some(tool_choice) = tool_choice {
+            args["tool_choice"] = tool_choice;
+        }
+
+        // Add OpenAI-specific options
+        if let Some(ref previous_response_id) = openai_options.previous_response_id {
+            args["previous_response_id"] = json!(previous_response_id);
+        }
+        if let Some(ref instructions) = openai_options.instructions {
+            args["instructions"] = json!(instructions);
+        }
+        if let Some(ref metadata) = openai_options.metadata {
+            args["metadata"] = json!(metadata);
+        }
+        if let Some(parallel) = openai_options.parallel_tool_calls {
+            args["parallel_tool_calls"] = json!(parallel);
+        }
+        if let Some(max_tool_calls) = openai_options.max_tool_calls {
+            args["max_tool_calls"] = json!(max_tool_calls);
+        }
+        if let Some(store) = openai_options.store {
+            args["store"] = json!(store);
+        }
+        if let Some(ref user) = openai_options.user {
+            args["user"] = json!(user);
+        }
+        if let Some(ref service_tier) = openai_options.service_tier {
+            args["service_tier"] = json!(service_tier);
+        }
+        if let Some(ref prompt_cache_key) = openai_options.prompt_cache_key {
+            args["prompt_cache_key"] = json!(prompt_cache_key);
+        }
+        if let Some(ref safety_id) = openai_options.safety_identifier {
+            args["safety_identifier"] = json!(safety_id);
+        }
+
+        let mut include = openai_options.include.clone().unwrap_or_default();
+
+        if is_reasoning_model {
+            let mut reasoning = serde_json::Map::new();
+            if let Some(ref effort) = openai_options.reasoning_effort {
+                reasoning.insert("effort".to_string(), json!(effort));
+            }
+            if let Some(ref summary) = openai_options.reasoning_summary {
+                reasoning.insert("summary".to_string(), json!(summary));
+            }
+            if !reasoning.is_empty() {
+                args["reasoning"] = JsonValue::Object(reasoning);
+            }
+
+            // Without server-side storage, reasoning can only be carried across
+            // turns through its encrypted content.
+            if !store && !include.iter().any(|i| i == "reasoning.encrypted_content") {
+                include.push("reasoning.encrypted_content".to_string());
+            }
+
+            // Remove unsupported settings
+            if args.get("temperature").is_some() {
+                args.as_object_mut().unwrap().remove("temperature");
+                warnings.push(LanguageModelCallWarning::unsupported_setting_with_details(
+                    "temperature",
+                    "temperature is not supported for reasoning models",
+                ));
+            }
+            if args.get("top_p").is_some() {
+                args.as_object_mut().unwrap().remove("top_p");
+                warnings.push(LanguageModelCallWarning::unsupported_setting_with_details(
+                    "topP",
+                    "topP is not supported for reasoning models",
+                ));
+            }
+        } else {
+            if openai_options.reasoning_effort.is_some() {
+                warnings.push(LanguageModelCallWarning::unsupported_setting_with_details(
+                    "reasoningEffort",
+                    "reasoningEffort is not supported for non-reasoning models",
+                ));
+            }
+            if openai_options.reasoning_summary.is_some() {
+                warnings.push(LanguageModelCallWarning::unsupported_setting_with_details(
+                    "reasoningSummary",
+                    "reasoningSummary is not supported for non-reasoning models",
+                ));
+            }
+        }
+
+        if !include.is_empty() {
+            args["include"] = json!(include);
+        }
+
+        Ok((args, warnings))
+    }
+
+    /// Convert the output items of a response into SDK content.
+    ///
+    /// Returns the content and whether the response contains client-side function calls.
+    fn convert_output(
+        output: &[OpenAIResponsesOutputItem],
+        built_in_tool_names: &HashMap<String, String>,
+    ) -> (Vec<LanguageModelContent>, bool) {
+        let mut content = Vec::new();
+        let mut has_function_call = false;
+
+        for item in output {
+            match item {
+                OpenAIResponsesOutputItem::Message { id, content: parts } => {
+                    for part in parts {
+                        if let OpenAIResponsesOutputContent::OutputText { text, annotations } = part
+                        {
+                            content.push(LanguageModelContent::Text(
+                                LanguageModelText::with_metadata(
+                                    text.clone(),
+                                    openai_metadata(vec![("itemId", json!(id))]),
+                                ),
+                            ));
+                            for annotation in annotations {
+                                if let Some(source) = annotation_to_source(annotation) {
+                                    content.push(LanguageModelContent::Source(source));
+                                }
+                            }
+                        }
+                    }
+                }
+                OpenAIResponsesOutputItem::Reasoning {
+                    id,
+                    encrypted_content,
+                    summary,
+                } => {
+                    let metadata = openai_metadata(vec![
+                        ("itemId", json!(id)),
+                        ("reasoningEncryptedContent", json!(encrypted_content)),
+                    ]);
+
+                    // Always emit at least one reasoning part so the item (and its
+                    // encrypted content) can be sent back in follow-up requests.
+                    if summary.is_empty() {
+                        content.push(LanguageModelContent::Reasoning(
+                            LanguageModelReasoning::with_metadata("", metadata),
+                        ));
+                    } else {
+                        for part in summary {
+                            content.push(LanguageModelContent::Reasoning(
+                                LanguageModelReasoning::with_metadata(
+                                    part.text.clone(),
+                                    metadata.clone(),
+                                ),
+                            ));
+                        }
+                    }
+                }
+                OpenAIResponsesOutputItem::FunctionCall {
+                    id,
+                    call_id,
+                    name,
+                    arguments,
+                } => {
+                    has_function_call = true;
+                    content.push(LanguageModelContent::ToolCall(
+                        LanguageModelToolCall::with_options(
+                            call_id.clone(),
+                            name.clone(),
+                            arguments.clone(),
+                            None,
+                            Some(openai_metadata(vec![("itemId", json!(id))])),
+                        ),
+                    ));
+                }
+                _ => content.extend(built_in_tool_content(item, built_in_tool_names)),
+            }
+        }
+
+        (content, has_function_call)
+    }
+
+    /// Process SSE byte stream into LanguageModelStreamPart events
+    fn process_stream(
+        byte_stream: impl Stream<Item = Result<bytes::Bytes, reqwest::Error>> + Send + 'static,
+        warnings: Vec<LanguageModelCallWarning>,
+        built_in_tool_names: HashMap<String, String>,
+    ) -> impl Stream<Item = LanguageModelStreamPart> + Unpin + Send {
+        let mut state = StreamState {
+            built_in_tool_names,
+            ..Default::default()
+        };
+
+        Box::pin(async_stream::stream! {
+            // Emit stream start with warnings
+            yield LanguageModelStreamPart::stream_start(warnings);
+
+            let mut events = Box::pin(parse_json_event_stream::<OpenAIResponsesChunk, _, _, _>(byte_stream));
+
+            while let Some(result) = events.next().await {
+                match result {
+                    Ok(Ok(chunk)) => {
+                        for part in Self::process_chunk(&mut state, chunk) {
+                            yield part;
+                        }
+                    }
+                    Ok(Err(error)) => {
+                        // Skip chunks that don't match the schema, e.g. of new event types
+                        log::warn!("Skipping unrecognized stream chunk: {}", error);
+                    }
+                    Err(e) => {
+                        yield LanguageModelStreamPart::error(json!({ "message": e.to_string() }));
+                        break;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Process a single streaming event and emit StreamPart events
+    fn process_chunk(
+        state: &mut StreamState,
+        chunk: OpenAIResponsesChunk,
+    ) -> Vec<LanguageModelStreamPart> {
+        let mut parts = Vec::new();
+
+        match chunk {
+            OpenAIResponsesChunk::ResponseCreated { response } => {
+                parts.push(LanguageModelStreamPart::ResponseMetadata(
+                    LanguageModelResponseMetadata {
+                        id: Some(response.id),
+                        timestamp: response.created_at,
+                        model_id: response.model,
+                    },
+                ));
+            }
+
+            OpenAIResponsesChunk::OutputItemAdded { output_index, item } => match item {
+                OpenAIResponsesOutputItem::Message { id, .. } => {
+                    parts.push(LanguageModelStreamPart::TextStart(
+                        LanguageModelStreamTextStart::with_metadata(
+                            id.clone(),
+                            Some(openai_metadata(vec![("itemId", json!(id))])),
+                        ),
+                    ));
+                }
+                OpenAIResponsesOutputItem::FunctionCall { call_id, name, .. } => {
+                    parts.push(LanguageModelStreamPart::tool_input_start(&call_id, &name));
+                    state.tool_calls.insert(
+                        output_index,
+                        ToolCallState {
+                            call_id,
+                            arguments: String::new(),
+                        },
+                    );
+                }
+                OpenAIResponsesOutputItem::Reasoning {
+                    id,
+                    encrypted_content,
+                    ..
+                } => {
+                    parts.push(LanguageModelStreamPart::ReasoningStart(
+                        LanguageModelStreamReasoningStart::with_metadata(
+                            format!("{}:0", id),
+                            Some(openai_metadata(vec![
+                                ("itemId", json!(id)),
+                                ("reasoningEncryptedContent", json!(encrypted_content)),
+                            ])),
+                        ),
+                    ));
+                    state.reasoning.insert(
+                        id,
+                        ReasoningState {
+                            encrypted_content,
+                            summary_parts: vec![0],
+                        },
+                    );
+                }
+                _ => {}
+            },
+
+            OpenAIResponsesChunk::OutputTextDelta { item_id, delta } => {
+                parts.push(LanguageModelStreamPart::text_delta(item_id, delta));
+            }
+
+            OpenAIResponsesChunk::OutputTextAnnotationAdded { annotation, .. } => {
+                if let Some(source) = annotation_to_source(&annotation) {
+                    parts.push(LanguageModelStreamPart::Source(source));
+                }
+            }
+
+            OpenAIResponsesChunk::FunctionCallArgumentsDelta {
+                output_index,
+                delta,
+                ..
+            } => {
+                if let Some(tool_state) = state.tool_calls.get_mut(&output_index) {
+                    tool_state.arguments.push_str(&delta);
+                    parts.push(LanguageModelStreamPart::tool_input_delta(
+                        &tool_state.call_id,
+                        delta,
+                    ));
+                }
+            }
+
+            OpenAIResponsesChunk::ReasoningSummaryPartAdded {
+                item_id,
+                summary_index,
+            } => {
+                // The first summary part is started together with the reasoning item.
+                if summary_index > 0
+                    && let Some(reasoning) = state.reasoning.get_mut(&item_id)
+                {
+                    reasoning.summary_parts.push(summary_index);
+                    parts.push(LanguageModelStreamPart::ReasoningStart(
+                        LanguageModelStreamReasoningStart::with_metadata(
+                            format!("{}:{}", item_id, summary_index),
+                            Some(openai_metadata(vec![
+                                ("itemId", json!(item_id)),
+                                (
+                                    "reasoningEncryptedContent",
+                                    json!(reasoning.encrypted_content),
+                                ),
+                            ])),
+                        ),
+                    ));
+                }
+            }
+
+            OpenAIResponsesChunk::ReasoningSummaryTextDelta {
+                item_id,
+                summary_index,
+                delta,
+            } => {
+                parts.push(LanguageModelStreamPart::reasoning_delta(
+                    format!("{}:{}", item_id, summary_index),
+                    delta,
+                ));
+            }
+
+            OpenAIResponsesChunk::OutputItemDone { output_index, item } => match item {
+                OpenAIResponsesOutputItem::Message { id, .. } => {
+                    parts.push(LanguageModelStreamPart::text_end(id));
+                }
+                OpenAIResponsesOutputItem::FunctionCall {
+                    id,
+                    call_id,
+                    name,
+                    arguments,
+                } => {
+                    state.has_function_call = true;
+                    let arguments = match state.tool_calls.remove(&output_index) {
+                        Some(tool_state) if arguments.is_empty() => tool_state.arguments,
+                        _ => arguments,
+                    };
+                    parts.push(LanguageModelStreamPart::tool_input_end(&call_id));
+                    parts.push(LanguageModelStreamPart::ToolCall(
+                        LanguageModelToolCall::with_options(
+                            call_id,
+                            name,
+                            arguments,
+                            None,
+                            Some(openai_metadata(vec![("itemId", json!(id))])),
+                        ),
+                    ));
+                }
+                OpenAIResponsesOutputItem::Reasoning {
+                    id,
+                    encrypted_content,
+                    ..
+                } => {
+                    if let Some(reasoning) = state.reasoning.remove(&id) {
+                        let metadata = openai_metadata(vec![
+                            ("itemId", json!(id)),
+                            ("reasoningEncryptedContent", json!(encrypted_content)),
+                        ]);
+                        for summary_index in reasoning.summary_parts {
+                            parts.push(LanguageModelStreamPart::ReasoningEnd(
+                                LanguageModelStreamReasoningEnd::with_metadata(
+                                    format!("{}:{}", id, summary_index),
+                                    Some(metadata.clone()),
+                                ),
+                            ));
+                        }
+                    }
+                }
+                other => {
+                    for content in built_in_tool_content(&other, &state.built_in_tool_names) {
+                        match content {
+                            LanguageModelContent::ToolCall(call) => {
+                                parts.push(LanguageModelStreamPart::ToolCall(call));
+                            }
+                            LanguageModelContent::ToolResult(result) => {
+                                parts.push(LanguageModelStreamPart::ToolResult(result));
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            },
+
+            OpenAIResponsesChunk::ResponseFinished { response } => {
+                let finish_reason = map_openai_responses_finish_reason(
+                    response
+                        .incomplete_details
+                        .as_ref()
+                        .and_then(|d| d.reason.as_deref()),
+                    state.has_function_call,
+                );
+                parts.push(LanguageModelStreamPart::Finish(
+                    LanguageModelStreamFinish::with_metadata(
+                        convert_usage(response.usage.as_ref()),
+                        finish_reason,
+                        Some(response_provider_metadata(&response)),
+                    ),
+                ));
+            }
+
+            OpenAIResponsesChunk::ResponseFailed { response } => {
+                if let Some(error) = &response.error {
+                    parts.push(LanguageModelStreamPart::error(json!({
+                        "code": error.code,
+                        "message": error.message,
+                    })));
+                }
+                parts.push(LanguageModelStreamPart::Finish(
+                    LanguageModelStreamFinish::with_metadata(
+                        convert_usage(response.usage.as_ref()),
+                        llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason::Error,
+                        Some(response_provider_metadata(&response)),
+                    ),
+                ));
+            }
+
+            OpenAIResponsesChunk::Error { code, message } => {
+                parts.push(LanguageModelStreamPart::error(json!({
+                    "code": code,
+                    "message": message,
+                })));
+            }
+
+            OpenAIResponsesChunk::Unknown => {}
+        }
+
+        parts
+    }
+}
+
+/// Build `{"openai": {...}}` provider metadata from key/value pairs.
+fn openai_metadata(entries: Vec<(&str, JsonValue)>) -> SharedProviderMetadata {
+    let openai = entries
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect();
+    let mut metadata = SharedProviderMetadata::new();
+    metadata.insert("openai".to_string(), openai);
+    metadata
+}
+
+fn response_provider_metadata(response: &OpenAIResponsesResponse) -> SharedProviderMetadata {
+    let mut entries = vec![("responseId", json!(response.id))];
+    if let Some(ref service_tier) = response.service_tier {
+        entries.push(("serviceTier", json!(service_tier)));
+    }
+    openai_metadata(entries)
+}
+
+fn convert_usage(usage: Option<&OpenAIResponsesUsage>) -> LanguageModelUsage {
+    match usage {
+        Some(usage) => LanguageModelUsage {
+            input_tokens: usage.input_tokens,
+            output_tokens: usage.output_tokens,
+            total_tokens: usage
+                .total_tokens
+                .unwrap_or(usage.input_tokens + usage.output_tokens),
+            reasoning_tokens: usage
+                .output_tokens_details
+                .as_ref()
+                .and_then(|d| d.reasoning_tokens)
+                .unwrap_or(0),
+            cached_input_tokens: usage
+                .input_tokens_details
+                .as_ref()
+                .and_then(|d| d.cached_tokens)
+                .unwrap_or(0),
+        },
+        None => LanguageModelUsage::default(),
+    }
+}
+
+fn annotation_to_source(annotation: &OpenAIResponsesAnnotation) -> Option<LanguageModelSource> {
+    match annotation {
+        OpenAIResponsesAnnotation::UrlCitation { url, title } => Some(LanguageModelSource::Url {
+            id: uuid::Uuid::new_v4().to_string(),
+            url: url.clone(),
+            title: title.clone(),
+            provider_metadata: None,
+        }),
+        OpenAIResponsesAnnotation::FileCitation { file_id, filename } => {
+            Some(LanguageModelSource::Document {
+                id: uuid::Uuid::new_v4().to_string(),
+                media_type: "text/plain".to_string(),
+                title: filename.clone().unwrap_or_else(|| file_id.clone()),
+                filename: filename.clone(),
+                provider_metadata: Some(openai_metadata(vec![("fileId", json!(file_id))])),
+            })
+        }
+        OpenAIResponsesAnnotation::Unknown => None,
+    }
+}
+
+/// Collect the tool names the caller registered for OpenAI built-in tools,
+/// keyed by the Responses API tool type (e.g. `web_search`).
+fn built_in_tool_names(tools: Option<&[LanguageModelTool]>) -> HashMap<String, String> {
+    tools
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|tool| match tool {
+            LanguageModelTool::ProviderDefined(tool) => tool
+                .id
+                .strip_prefix("openai.")
+                .map(|tool_type| (tool_type.to_string(), tool.name.clone())),
+            LanguageModelTool::Function(_) => None,
+        })
+        .collect()
+}
+
+fn built_in_tool_name(names: &HashMap<String, String>, tool_type: &str) -> String {
+    names.get(tool_type).cloned().unwrap_or_else(|| {
+        if tool_type == "web_search" {
+            names
+                .get("web_search_preview")
+                .cloned()
+                .unwrap_or_else(|| tool_type.to_string())
+        } else {
+            tool_type.to_string()
+        }
+    })
+}
+
+/// Convert a provider-executed built-in tool item into a tool call and its result.
+fn built_in_tool_content(
+    item: &OpenAIResponsesOutputItem,
+    names: &HashMap<String, String>,
+) -> Vec<LanguageModelContent> {
+    let (id, tool_type, input, result) = match item {
+        OpenAIResponsesOutputItem::WebSearchCall { id, status, action } => (
+            id,
+            "web_search",
+            json!({}),
+            json!({ "status": status, "action": action }),
+        ),
+        OpenAIResponsesOutputItem::FileSearchCall {
+            id,
+            queries,
+            results,
+        } => (
+            id,
+            "file_search",
+            json!({}),
+            json!({ "queries": queries, "results": results }),
+        ),
+        OpenAIResponsesOutputItem::CodeInterpreterCall {
+            id,
+            code,
+            container_id,
+            outputs,
+        } => (
+            id,
+            "code_interpreter",
+            json!({ "code": code, "containerId": container_id }),
+            json!({ "outputs": outputs }),
+        ),
+        OpenAIResponsesOutputItem::ImageGenerationCall { id, result } => (
+            id,
+            "image_generation",
+            json!({}),
+            json!({ "result": result }),
+        ),
+        _ => return Vec::new(),
+    };
+
+    let tool_name = built_in_tool_name(names, tool_type);
+    vec![
+        LanguageModelContent::ToolCall(LanguageModelToolCall::with_options(
+            id.clone(),
+            tool_name.clone(),
+            input.to_string(),
+            Some(true),
+            None,
+        )),
+        LanguageModelContent::ToolResult(LanguageModelToolResult::with_options(
+            id.clone(),
+            tool_name,
+            result,
+            None,
+            Some(true),
+            None,
+        )),
+    ]
+}
+
+fn header_map_to_hash_map(headers: &reqwest::header::HeaderMap) -> HashMap<String, String> {
+    headers
+        .iter()
+        .map(|(k, v)| (k.as_str().to_string(), v.to_str().unwrap_or("").to_string()))
+        .collect()
+}
+
+#[async_trait]
+impl LanguageModel for OpenAIResponsesLanguageModel {
+    fn specification_version(&self) -> &str {
+        "v3"
+    }
+
+    fn provider(&self) -> &str {
+        &self.config.provider
+    }
+
+    fn model_id(&self) -> &str {
+        &self.model_id
+    }
+
+    async fn supported_urls(&self) -> HashMap<String, Vec<Regex>> {
+        let mut urls = HashMap::new();
+        urls.insert(
+            "image/*".to_string(),
+            vec![Regex::new(r"^https?://.*$").unwrap()],
+        );
+        urls.insert(
+            "application/pdf".to_string(),
+            vec![Regex::new(r"^https?://.*$").unwrap()],
+        );
+        urls
+    }
+
+    fn supports_structured_outputs(&self) -> bool {
+        true
+    }
+
+    async fn do_generate(
+        &self,
+        options: LanguageModelCallOptions,
+    ) -> Result<LanguageModelGenerateResponse, Box<dyn std::error::Error>> {
+        let (body, warnings) = self.build_request_args(&options)?;
+
+        // Make HTTP request
+        let client = &self.config.http_client;
+        let url = self.url();
+        let mut headers = (self.config.headers)();
+        if let Some(ref option_headers) = options.headers {
+            headers.extend(option_headers.clone());
+        }
+
+        let mut request = client.post(&url).json(&body);
+        for (key, value) in headers {
+            request = request.header(key, value);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        let response_headers = header_map_to_hash_map(response.headers());
+        let response_text = response.text().await?;
+
+        if !status.is_success() {
+            return Err(Box::new(parse_openai_error(
+                &url,
+                &body,
+                status.as_u16(),
+                response_headers,
+                &response_text,
+            )));
+        }
+
+        let response_body: OpenAIResponsesResponse = serde_json::from_str(&response_text)?;
+
+        if let Some(error) = &response_body.error {
+            return Err(format!("OpenAI response failed: {}", error.message).into());
+        }
+
+        let names = built_in_tool_names(options.tools.as_deref());
+        let (content, has_function_call) = Self::convert_output(&response_body.output, &names);
+
+        let finish_reason = map_openai_responses_finish_reason(
+            response_body
+                .incomplete_details
+                .as_ref()
+                .and_then(|d| d.reason.as_deref()),
+            has_function_call,
+        );
+
+        Ok(LanguageModelGenerateResponse {
+            content,
+            finish_reason,
+            usage: convert_usage(response_body.usage.as_ref()),
+            provider_metadata: Some(response_provider_metadata(&response_body)),
+            request: Some(LanguageModelRequestMetadata { body: Some(body) }),
+            response: Some(LanguageModelResponseMetadata {
+                id: Some(response_body.id),
+                timestamp: response_body.created_at,
+                model_id: response_body.model,
+            }),
+            warnings,
+        })
+    }
+
+    async fn do_stream(
+        &self,
+        options: LanguageModelCallOptions,
+    ) -> Result<LanguageModelStreamResponse, Box<dyn std::error::Error>> {
+        let (mut body, warnings) = self.build_request_args(&options)?;
+        body["stream"] = json!(true);
+
+        // Make HTTP request
+        let client = &self.config.http_client;
+        let url = self.url();
+        let mut headers = (self.config.headers)();
+        if let Some(ref option_headers) = options.headers {
+            headers.extend(option_headers.clone());
+        }
+
+        let mut request = client.post(&url).json(&body);
+        for (key, value) in headers {
+            request = request.header(key, value);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        let response_headers = header_map_to_hash_map(response.headers());
+
+        if !status.is_success() {
+            let response_text = response.text().await?;
+            return Err(Box::new(parse_openai_error(
+                &url,
+                &body,
+                status.as_u16(),
+                response_headers,
+                &response_text,
+            )));
+        }
+
+        let names = built_in_tool_names(options.tools.as_deref());
+        let stream = Self::process_stream(response.bytes_stream(), warnings, names);
+
+        Ok(LanguageModelStreamResponse {
+            stream: Box::new(stream),
+            request: Some(LanguageModelRequestMetadata { body: Some(body) }),
+            response: Some(StreamResponseMetadata {
+                headers: Some(response_headers),
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::responses::openai_responses_api::ReasoningSummaryPart;
+    use llm_kit_provider::language_model::prompt::message::parts::LanguageModelTextPart;
+    use llm_kit_provider::language_model::prompt::message::{
+        LanguageModelMessage, LanguageModelUserMessage, LanguageModelUserMessagePart,
+    };
+
+    fn create_model(model_id: &str) -> OpenAIResponsesLanguageModel {
+        OpenAIResponsesLanguageModel::new(
+            model_id.to_string(),
+            OpenAIResponsesConfig::new(
+                "openai.responses".to_string(),
+                "https://api.openai.com/v1".to_string(),
+                Arc::new(HashMap::new),
+            ),
+        )
+    }
+
+    fn user_prompt(text: &str) -> Vec<LanguageModelMessage> {
+        vec![LanguageModelMessage::User(LanguageModelUserMessage::new(
+            vec![LanguageModelUserMessagePart::Text(
+                LanguageModelTextPart::new(text),
+            )],
+        ))]
+    }
+
+    fn openai_options(value: JsonValue) -> HashMap<String, HashMap<String, JsonValue>> {
+        let mut options = HashMap::new();
+        options.insert(
+            "openai".to_string(),
+            serde_json::from_value::<HashMap<String, JsonValue>>(value).unwrap(),
+        );
+        options
+    }
+
+    #[test]
+    fn test_build_request_args_basic() {
+        let model = create_model("gpt-4o");
+        let options = LanguageModelCallOptions::new(user_prompt("Hello"))
+            .with_temperature(0.5)
+            .with_max_output_tokens(100)
+            .with_provider_options(openai_options(json!({
+                "previousResponseId": "resp_prev",
+                "instructions": "Be brief."
+            })));
+
+        let (args, warnings) = model.build_request_args(&options).unwrap();
+
+        assert!(warnings.is_empty());
+        assert_eq!(args["model"], "gpt-4o");
+        assert_eq!(args["temperature"], 0.5);
+        assert_eq!(args["max_output_tokens"], 100);
+        assert_eq!(args["previous_response_id"], "resp_prev");
+        assert_eq!(args["instructions"], "Be brief.");
+        assert_eq!(
+            args["input"],
+            json!([{ "role": "user", "content": [{ "type": "input_text", "text": "Hello" }] }])
+        );
+    }
+
+    #[test]
+    fn test_build_request_args_reasoning_model() {
+        let model = create_model("o4-mini");
+        let options = LanguageModelCallOptions::new(user_prompt("Hello"))
+            .with_temperature(0.5)
+            .with_provider_options(openai_options(json!({
+                "reasoningEffort": "low",
+                "reasoningSummary": "auto",
+                "store": false
+            })));
+
+        let (args, warnings) = model.build_request_args(&options).unwrap();
+
+        assert!(args.get("temperature").is_none());
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            args["reasoning"],
+            json!({ "effort": "low", "summary": "auto" })
+        );
+        assert_eq!(args["store"], false);
+        assert_eq!(args["include"], json!(["reasoning.encrypted_content"]));
+    }
+
+    #[test]
+    fn test_build_request_args_json_schema() {
+        let model = create_model("gpt-4o");
+        let options = LanguageModelCallOptions::new(user_prompt("Hello")).with_response_format(
+            LanguageModelResponseFormat::Json {
+                schema: Some(json!({ "type": "object" })),
+                name: Some("answer".to_string()),
+                description: None,
+            },
+        );
+
+        let (args, _) = model.build_request_args(&options).unwrap();
+
+        assert_eq!(args["text"]["format"]["type"], "json_schema");
+        assert_eq!(args["text"]["format"]["name"], "answer");
+        assert_eq!(
+            args["text"]["format"]["schema"],
+            json!({ "type": "object" })
+        );
+    }
+
+    #[test]
+    fn test_convert_output() {
+        let output = vec![
+            OpenAIResponsesOutputItem::Reasoning {
+                id: "rs_1".to_string(),
+                encrypted_content: Some("enc".to_string()),
+                summary: vec![ReasoningSummaryPart {
+                    text: "Thinking".to_string(),
+                }],
+            },
+            OpenAIResponsesOutputItem::Message {
+                id: "msg_1".to_string(),
+                content: vec![OpenAIResponsesOutputContent::OutputText {
+                    text: "Hi".to_string(),
+                    annotations: vec![OpenAIResponsesAnnotation::UrlCitation {
+                        url: "https://example.com".to_string(),
+                        title: Some("Example".to_string()),
+                    }],
+                }],
+            },
+            OpenAIResponsesOutputItem::WebSearchCall {
+                id: "ws_1".to_string(),
+                status: Some("completed".to_string()),
+                action: None,
+            },
+            OpenAIResponsesOutputItem::FunctionCall {
+                id: "fc_1".to_string(),
+                call_id: "call_1".to_string(),
+                name: "get_weather".to_string(),
+                arguments: "{}".to_string(),
+            },
+        ];
+
+        let (content, has_function_call) =
+            OpenAIResponsesLanguageModel::convert_output(&output, &HashMap::new());
+
+        assert!(has_function_call);
+        assert_eq!(content.len(), 6);
+
+        match &content[0] {
+            LanguageModelContent::Reasoning(reasoning) => {
+                let metadata = reasoning.provider_metadata.as_ref().unwrap();
+                assert_eq!(metadata["openai"]["itemId"], "rs_1");
+                assert_eq!(metadata["openai"]["reasoningEncryptedContent"], "enc");
+            }
+            other => panic!("unexpected content: {:?}", other),
+        }
+        assert!(matches!(&content[1], LanguageModelContent::Text(t) if t.text == "Hi"));
+        assert!(matches!(&content[2], LanguageModelContent::Source(_)));
+        assert!(matches!(
+            &content[3],
+            LanguageModelContent::ToolCall(call) if call.provider_executed == Some(true)
+                && call.tool_name == "web_search"
+        ));
+        assert!(matches!(&content[4], LanguageModelContent::ToolResult(_)));
+        assert!(matches!(
+            &content[5],
+            LanguageModelContent::ToolCall(call) if call.tool_call_id == "call_1"
+        ));
+    }
+
+    #[test]
+    fn test_process_stream_events() {
+        let mut state = StreamState::default();
+        let events = vec![
+            json!({ "type": "response.created", "response": { "id": "resp_1", "created_at": 1, "model": "o4-mini" } }),
+            json!({ "type": "response.output_item.added", "output_index": 0, "item": { "type": "reasoning", "id": "rs_1", "summary": [] } }),
+            json!({ "type": "response.reasoning_summary_text.delta", "item_id": "rs_1", "summary_index": 0, "delta": "Hmm" }),
+            json!({ "type": "response.output_item.done", "output_index": 0, "item": { "type": "reasoning", "id": "rs_1", "encrypted_content": "enc", "summary": [] } }),
+            json!({ "type": "response.output_item.added", "output_index": 1, "item": { "type": "function_call", "id": "fc_1", "call_id": "call_1", "name": "get_weather", "arguments": "" } }),
+            json!({ "type": "response.function_call_arguments.delta", "item_id": "fc_1", "output_index": 1, "delta": "{\"city\":" }),
+            json!({ "type": "response.function_call_arguments.delta", "item_id": "fc_1", "output_index": 1, "delta": "\"Paris\"}" }),
+            json!({ "type": "response.output_item.done", "output_index": 1, "item": { "type": "function_call", "id": "fc_1", "call_id": "call_1", "name": "get_weather", "arguments": "" } }),
+            json!({ "type": "response.completed", "response": { "id": "resp_1", "usage": { "input_tokens": 5, "output_tokens": 7 } } }),
+        ];
+
+        let parts: Vec<LanguageModelStreamPart> = events
+            .into_iter()
+            .flat_map(|event| {
+                let chunk: OpenAIResponsesChunk = serde_json::from_value(event).unwrap();
+                OpenAIResponsesLanguageModel::process_chunk(&mut state, chunk)
+            })
+            .collect();
+
+        assert!(matches!(
+            parts[0],
+            LanguageModelStreamPart::ResponseMetadata(_)
+        ));
+        assert!(
+            matches!(&parts[1], LanguageModelStreamPart::ReasoningStart(s) if s.id == "rs_1:0")
+        );
+        assert!(
+            matches!(&parts[2], LanguageModelStreamPart::ReasoningDelta(d) if d.delta == "Hmm")
+        );
+        match &parts[3] {
+            LanguageModelStreamPart::ReasoningEnd(end) => {
+                let metadata = end.provider_metadata.as_ref().unwrap();
+                assert_eq!(metadata["openai"]["reasoningEncryptedContent"], "enc");
+            }
+            other => panic!("unexpected part: {:?}", other),
+        }
+        assert!(matches!(
+            &parts[4],
+            LanguageModelStreamPart::ToolInputStart(_)
+        ));
+        match &parts[8] {
+            LanguageModelStreamPart::ToolCall(call) => {
+                assert_eq!(call.input, "{\"city\":\"Paris\"}");
+            }
+            other => panic!("unexpected part: {:?}", other),
+        }
+        match &parts[9] {
+            LanguageModelStreamPart::Finish(finish) => {
+                assert_eq!(
+                    finish.finish_reason,
+                    llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason::ToolCalls
+                );
+                assert_eq!(finish.usage.total_tokens, 12);
+                let metadata = finish.provider_metadata.as_ref().unwrap();
+                assert_eq!(metadata["openai"]["responseId"], "resp_1");
+            }
+            other => panic!("unexpected part: {:?}", other),
+        }
+    }
+}
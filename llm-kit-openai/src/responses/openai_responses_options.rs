@@ -0,0 +1,149 @@
+//! OpenAI Responses Model Configuration and Options
+//!
+//! This module defines the model IDs and provider-specific options for the OpenAI Responses API.
+
+use crate::chat::openai_chat_options::{ReasoningEffort, ServiceTier, TextVerbosity};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// OpenAI Responses model identifier.
+///
+/// Based on <https://platform.openai.com/docs/api-reference/responses>
+pub type OpenAIResponsesModelId = String;
+
+/// Provider-specific options for OpenAI Responses models.
+///
+/// These options are read from `provider_options["openai"]`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenAIResponsesProviderOptions {
+    /// The ID of a previous response to continue the conversation from.
+    ///
+    /// When set, the API reuses the stored conversation state and only the
+    /// new input items need to be sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_response_id: Option<String>,
+
+    /// Reasoning effort for reasoning models.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reasoning_effort: Option<ReasoningEffort>,
+
+    /// Whether and how detailed the model should summarize its reasoning.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reasoning_summary: Option<ReasoningSummary>,
+
+    /// Additional output data to include in the response
+    /// (e.g. `reasoning.encrypted_content`, `file_search_call.results`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<Vec<String>>,
+
+    /// Whether to store the generated response for later retrieval.
+    /// Defaults to `true` on the API side.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<bool>,
+
+    /// Instructions for the model, sent as a top-level `instructions` field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+
+    /// Metadata to associate with the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+
+    /// Whether to enable parallel function calling during tool use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parallel_tool_calls: Option<bool>,
+
+    /// The maximum number of calls to built-in tools in a single response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tool_calls: Option<u32>,
+
+    /// A unique identifier representing your end-user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+
+    /// Service tier for the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_tier: Option<ServiceTier>,
+
+    /// Whether to use strict JSON schema validation for tools and structured outputs.
+    /// Defaults to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict_json_schema: Option<bool>,
+
+    /// Controls the verbosity of the model's responses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_verbosity: Option<TextVerbosity>,
+
+    /// A cache key for prompt caching.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt_cache_key: Option<String>,
+
+    /// A stable identifier used to help detect users violating OpenAI's usage policies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub safety_identifier: Option<String>,
+}
+
+/// Reasoning summary levels for reasoning models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReasoningSummary {
+    /// Let the model pick the most detailed summarizer available.
+    Auto,
+    /// A short summary.
+    Concise,
+    /// A detailed summary.
+    Detailed,
+}
+
+/// Check if a model is a reasoning model on the Responses API.
+///
+/// In addition to the o-series, the GPT-5 family (except `gpt-5-chat`) reasons by default.
+pub fn is_responses_reasoning_model(model_id: &str) -> bool {
+    model_id.starts_with("o1")
+        || model_id.starts_with("o3")
+        || model_id.starts_with("o4-mini")
+        || model_id.starts_with("codex-mini")
+        || model_id.starts_with("computer-use-preview")
+        || (model_id.starts_with("gpt-5") && !model_id.starts_with("gpt-5-chat"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_is_responses_reasoning_model() {
+        assert!(is_responses_reasoning_model("o3-mini"));
+        assert!(is_responses_reasoning_model("o4-mini"));
+        assert!(is_responses_reasoning_model("gpt-5"));
+        assert!(is_responses_reasoning_model("gpt-5-mini"));
+        assert!(!is_responses_reasoning_model("gpt-5-chat-latest"));
+        assert!(!is_responses_reasoning_model("gpt-4o"));
+    }
+
+    #[test]
+    fn test_deserialize_camel_case_options() {
+        let options: OpenAIResponsesProviderOptions = serde_json::from_value(json!({
+            "previousResponseId": "resp_123",
+            "reasoningEffort": "high",
+            "reasoningSummary": "detailed",
+            "store": false,
+            "include": ["reasoning.encrypted_content"]
+        }))
+        .unwrap();
+
+        assert_eq!(options.previous_response_id.as_deref(), Some("resp_123"));
+        assert!(matches!(
+            options.reasoning_effort,
+            Some(ReasoningEffort::High)
+        ));
+        assert_eq!(options.reasoning_summary, Some(ReasoningSummary::Detailed));
+        assert_eq!(options.store, Some(false));
+        assert_eq!(
+            options.include,
+            Some(vec!["reasoning.encrypted_content".to_string()])
+        );
+    }
+}
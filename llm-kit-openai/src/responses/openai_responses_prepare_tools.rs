@@ -0,0 +1,285 @@
+//! OpenAI Responses Tool Preparation
+//!
+//! Converts SDK tools (function tools and OpenAI built-in tools) to the Responses API format.
+
+use llm_kit_provider::language_model::call_warning::LanguageModelCallWarning;
+use llm_kit_provider::language_model::tool::LanguageModelTool;
+use llm_kit_provider::language_model::tool_choice::LanguageModelToolChoice;
+use serde_json::{Map, Value as JsonValue, json};
+use std::collections::HashMap;
+
+/// Names of the built-in tools that can be forced through `tool_choice`.
+const BUILT_IN_TOOL_TYPES: &[&str] = &[
+    "web_search",
+    "web_search_preview",
+    "file_search",
+    "code_interpreter",
+    "image_generation",
+];
+
+/// Prepare tools for an OpenAI Responses API call.
+///
+/// Function tools are sent as `{"type": "function", ...}` entries. Provider-defined
+/// tools with an `openai.*` id are mapped to the matching built-in tool.
+///
+/// # Arguments
+///
+/// * `tools` - Optional list of tools
+/// * `tool_choice` - Optional tool choice strategy
+/// * `strict_json_schema` - Whether strict JSON schema validation is enabled
+///
+/// # Returns
+///
+/// Tuple of (Responses tools, Responses tool choice, warnings)
+pub fn prepare_responses_tools(
+    tools: Option<&[LanguageModelTool]>,
+    tool_choice: Option<&LanguageModelToolChoice>,
+    strict_json_schema: bool,
+) -> (
+    Option<Vec<JsonValue>>,
+    Option<JsonValue>,
+    Vec<LanguageModelCallWarning>,
+) {
+    let mut tool_warnings = Vec::new();
+
+    // When the tools array is empty, change it to None to prevent errors
+    let tools = match tools {
+        Some(t) if !t.is_empty() => t,
+        _ => return (None, None, tool_warnings),
+    };
+
+    let mut openai_tools = Vec::new();
+
+    for tool in tools {
+        match tool {
+            LanguageModelTool::Function(function_tool) => {
+                openai_tools.push(json!({
+                    "type": "function",
+                    "name": function_tool.name,
+                    "description": function_tool.description,
+                    "parameters": function_tool.input_schema,
+                    "strict": strict_json_schema,
+                }));
+            }
+            LanguageModelTool::ProviderDefined(provider_tool) => {
+                match provider_defined_tool(&provider_tool.id, &provider_tool.args) {
+                    Some(openai_tool) => openai_tools.push(openai_tool),
+                    None => tool_warnings.push(LanguageModelCallWarning::unsupported_tool(
+                        LanguageModelTool::ProviderDefined(provider_tool.clone()),
+                    )),
+                }
+            }
+        }
+    }
+
+    let openai_tool_choice = match tool_choice {
+        None => None,
+        Some(LanguageModelToolChoice::Auto) => Some(json!("auto")),
+        Some(LanguageModelToolChoice::None) => Some(json!("none")),
+        Some(LanguageModelToolChoice::Required) => Some(json!("required")),
+        Some(LanguageModelToolChoice::Tool { name }) => {
+            if BUILT_IN_TOOL_TYPES.contains(&name.as_str()) {
+                Some(json!({ "type": name }))
+            } else {
+                Some(json!({ "type": "function", "name": name }))
+            }
+        }
+    };
+
+    (Some(openai_tools), openai_tool_choice, tool_warnings)
+}
+
+/// Map a provider-defined tool to its built-in Responses API representation.
+fn provider_defined_tool(id: &str, args: &HashMap<String, JsonValue>) -> Option<JsonValue> {
+    let mut tool = Map::new();
+
+    match id {
+        "openai.web_search" | "openai.web_search_preview" => {
+            let tool_type = id.trim_start_matches("openai.");
+            tool.insert("type".to_string(), json!(tool_type));
+            copy_arg(args, "searchContextSize", "search_context_size", &mut tool);
+            if let Some(location) = args.get("userLocation") {
+                let mut location = location.clone();
+                if let Some(obj) = location.as_object_mut() {
+                    obj.entry("type").or_insert_with(|| json!("approximate"));
+                }
+                tool.insert("user_location".to_string(), location);
+            }
+            if let Some(domains) = args.get("allowedDomains") {
+                tool.insert("filters".to_string(), json!({ "allowed_domains": domains }));
+            }
+        }
+        "openai.file_search" => {
+            tool.insert("type".to_string(), json!("file_search"));
+            copy_arg(args, "vectorStoreIds", "vector_store_ids", &mut tool);
+            copy_arg(args, "maxNumResults", "max_num_results", &mut tool);
+            copy_arg(args, "filters", "filters", &mut tool);
+            if let Some(ranking) = args.get("ranking") {
+                let mut ranking_options = Map::new();
+                if let Some(ranker) = ranking.get("ranker") {
+                    ranking_options.insert("ranker".to_string(), ranker.clone());
+                }
+                if let Some(threshold) = ranking.get("scoreThreshold") {
+                    ranking_options.insert("score_threshold".to_string(), threshold.clone());
+                }
+                tool.insert(
+                    "ranking_options".to_string(),
+                    JsonValue::Object(ranking_options),
+                );
+            }
+        }
+        "openai.code_interpreter" => {
+            tool.insert("type".to_string(), json!("code_interpreter"));
+            let container = match args.get("container") {
+                Some(JsonValue::String(container_id)) => json!(container_id),
+                Some(JsonValue::Object(container)) => json!({
+                    "type": "auto",
+                    "file_ids": container.get("fileIds").cloned().unwrap_or(json!([])),
+                }),
+                _ => json!({ "type": "auto" }),
+            };
+            tool.insert("container".to_string(), container);
+        }
+        "openai.image_generation" => {
+            tool.insert("type".to_string(), json!("image_generation"));
+            copy_arg(args, "background", "background", &mut tool);
+            copy_arg(args, "inputFidelity", "input_fidelity", &mut tool);
+            copy_arg(args, "model", "model", &mut tool);
+            copy_arg(args, "moderation", "moderation", &mut tool);
+            copy_arg(args, "outputCompression", "output_compression", &mut tool);
+            copy_arg(args, "outputFormat", "output_format", &mut tool);
+            copy_arg(args, "partialImages", "partial_images", &mut tool);
+            copy_arg(args, "quality", "quality", &mut tool);
+            copy_arg(args, "size", "size", &mut tool);
+        }
+        _ => return None,
+    }
+
+    Some(JsonValue::Object(tool))
+}
+
+fn copy_arg(
+    args: &HashMap<String, JsonValue>,
+    from: &str,
+    to: &str,
+    tool: &mut Map<String, JsonValue>,
+) {
+    if let Some(value) = args.get(from)
+        && !value.is_null()
+    {
+        tool.insert(to.to_string(), value.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use llm_kit_provider::language_model::tool::function_tool::LanguageModelFunctionTool;
+    use llm_kit_provider::language_model::tool::provider_defined_tool::LanguageModelProviderDefinedTool;
+
+    #[test]
+    fn test_no_tools() {
+        let (tools, choice, warnings) = prepare_responses_tools(None, None, false);
+        assert!(tools.is_none());
+        assert!(choice.is_none());
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_function_tool() {
+        let tools = vec![LanguageModelTool::Function(
+            LanguageModelFunctionTool::new("get_weather", json!({"type": "object"}))
+                .with_description("Get the weather"),
+        )];
+
+        let (tools, choice, warnings) = prepare_responses_tools(
+            Some(&tools),
+            Some(&LanguageModelToolChoice::Tool {
+                name: "get_weather".to_string(),
+            }),
+            true,
+        );
+
+        let tools = tools.unwrap();
+        assert_eq!(tools[0]["type"], "function");
+        assert_eq!(tools[0]["name"], "get_weather");
+        assert_eq!(tools[0]["strict"], true);
+        assert_eq!(
+            choice,
+            Some(json!({ "type": "function", "name": "get_weather" }))
+        );
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_built_in_tools() {
+        let mut search_args = HashMap::new();
+        search_args.insert("searchContextSize".to_string(), json!("high"));
+        search_args.insert("userLocation".to_string(), json!({ "city": "Berlin" }));
+
+        let mut file_args = HashMap::new();
+        file_args.insert("vectorStoreIds".to_string(), json!(["vs_1"]));
+        file_args.insert("maxNumResults".to_string(), json!(5));
+
+        let tools = vec![
+            LanguageModelTool::ProviderDefined(LanguageModelProviderDefinedTool::new(
+                "openai.web_search",
+                "web_search",
+                search_args,
+            )),
+            LanguageModelTool::ProviderDefined(LanguageModelProviderDefinedTool::new(
+                "openai.file_search",
+                "file_search",
+                file_args,
+            )),
+            LanguageModelTool::ProviderDefined(LanguageModelProviderDefinedTool::new(
+                "openai.code_interpreter",
+                "code_interpreter",
+                HashMap::new(),
+            )),
+        ];
+
+        let (tools, choice, warnings) = prepare_responses_tools(
+            Some(&tools),
+            Some(&LanguageModelToolChoice::Tool {
+                name: "web_search".to_string(),
+            }),
+            false,
+        );
+
+        let tools = tools.unwrap();
+        assert_eq!(
+            tools[0],
+            json!({
+                "type": "web_search",
+                "search_context_size": "high",
+                "user_location": { "type": "approximate", "city": "Berlin" }
+            })
+        );
+        assert_eq!(
+            tools[1],
+            json!({ "type": "file_search", "vector_store_ids": ["vs_1"], "max_num_results": 5 })
+        );
+        assert_eq!(
+            tools[2],
+            json!({ "type": "code_interpreter", "container": { "type": "auto" } })
+        );
+        assert_eq!(choice, Some(json!({ "type": "web_search" })));
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_unsupported_provider_tool() {
+        let tools = vec![LanguageModelTool::ProviderDefined(
+            LanguageModelProviderDefinedTool::new(
+                "anthropic.bash_20250124",
+                "bash",
+                HashMap::new(),
+            ),
+        )];
+
+        let (tools, _, warnings) = prepare_responses_tools(Some(&tools), None, false);
+        assert!(tools.unwrap().is_empty());
+        assert_eq!(warnings.len(), 1);
+    }
+}
@@ -0,0 +1,489 @@
+//! OpenAI Responses Prompt Conversion
+//!
+//! Converts SDK prompt messages to OpenAI Responses API input items.
+
+use crate::chat::openai_chat_prompt::SystemMessageMode;
+use base64::Engine;
+use llm_kit_provider::language_model::call_warning::LanguageModelCallWarning;
+use llm_kit_provider::language_model::prompt::LanguageModelPrompt;
+use llm_kit_provider::language_model::prompt::message::parts::LanguageModelFilePart;
+use llm_kit_provider::language_model::prompt::message::{
+    LanguageModelAssistantMessage, LanguageModelAssistantMessagePart, LanguageModelDataContent,
+    LanguageModelMessage, LanguageModelToolMessage, LanguageModelToolResultContentItem,
+    LanguageModelToolResultOutput, LanguageModelUserMessage, LanguageModelUserMessagePart,
+};
+use llm_kit_provider::shared::provider_options::SharedProviderOptions;
+use serde_json::{Value as JsonValue, json};
+use std::collections::HashMap;
+
+/// OpenAI Responses input is an array of input items.
+pub type OpenAIResponsesInput = Vec<JsonValue>;
+
+/// Convert SDK prompt to OpenAI Responses input items.
+///
+/// Reasoning parts are sent back as `reasoning` items (grouped by their OpenAI item id,
+/// including the encrypted content) when `store` is false, and as `item_reference`
+/// entries when the response was stored by OpenAI.
+///
+/// # Arguments
+///
+/// * `prompt` - The SDK prompt to convert
+/// * `system_message_mode` - How to handle system messages
+/// * `store` - Whether responses are stored by OpenAI
+///
+/// # Returns
+///
+/// The converted input items and any warnings
+pub fn convert_to_openai_responses_input(
+    prompt: &LanguageModelPrompt,
+    system_message_mode: SystemMessageMode,
+    store: bool,
+) -> (OpenAIResponsesInput, Vec<LanguageModelCallWarning>) {
+    let mut input = Vec::new();
+    let mut warnings = Vec::new();
+
+    for message in prompt {
+        match message {
+            LanguageModelMessage::System(system_msg) => match system_message_mode {
+                SystemMessageMode::System => {
+                    input.push(json!({ "role": "system", "content": system_msg.content }));
+                }
+                SystemMessageMode::Developer => {
+                    input.push(json!({ "role": "developer", "content": system_msg.content }));
+                }
+                SystemMessageMode::Remove => {
+                    warnings.push(LanguageModelCallWarning::other(
+                        "system messages are removed for this model",
+                    ));
+                }
+            },
+            LanguageModelMessage::User(user_msg) => {
+                convert_user_message(user_msg, &mut input, &mut warnings);
+            }
+            LanguageModelMessage::Assistant(assistant_msg) => {
+                convert_assistant_message(assistant_msg, store, &mut input, &mut warnings);
+            }
+            LanguageModelMessage::Tool(tool_msg) => {
+                convert_tool_message(tool_msg, &mut input);
+            }
+        }
+    }
+
+    (input, warnings)
+}
+
+/// Read the OpenAI output item id that was attached to a part as provider metadata.
+fn openai_item_id(provider_options: &Option<SharedProviderOptions>) -> Option<String> {
+    openai_option(provider_options, "itemId")
+}
+
+fn openai_option(provider_options: &Option<SharedProviderOptions>, key: &str) -> Option<String> {
+    provider_options
+        .as_ref()
+        .and_then(|options| options.get("openai"))
+        .and_then(|openai| openai.get(key))
+        .and_then(|value| value.as_str())
+        .map(|value| value.to_string())
+}
+
+fn convert_user_message(
+    msg: &LanguageModelUserMessage,
+    input: &mut OpenAIResponsesInput,
+    warnings: &mut Vec<LanguageModelCallWarning>,
+) {
+    let mut content_parts = Vec::new();
+
+    for (index, part) in msg.content.iter().enumerate() {
+        match part {
+            LanguageModelUserMessagePart::Text(text_part) => {
+                content_parts.push(json!({ "type": "input_text", "text": text_part.text }));
+            }
+            LanguageModelUserMessagePart::File(file_part) => {
+                if let Some(content_part) = convert_file_part(file_part, index, warnings) {
+                    content_parts.push(content_part);
+                }
+            }
+        }
+    }
+
+    input.push(json!({ "role": "user", "content": content_parts }));
+}
+
+fn convert_file_part(
+    file_part: &LanguageModelFilePart,
+    index: usize,
+    warnings: &mut Vec<LanguageModelCallWarning>,
+) -> Option<JsonValue> {
+    let media_type = file_part.media_type.as_str();
+
+    if media_type.starts_with("image/") {
+        let media_type = if media_type == "image/*" {
+            "image/jpeg"
+        } else {
+            media_type
+        };
+
+        let part = match &file_part.data {
+            LanguageModelDataContent::Url(url) => {
+                json!({ "type": "input_image", "image_url": url.to_string() })
+            }
+            LanguageModelDataContent::Base64(data) if data.starts_with("file-") => {
+                json!({ "type": "input_image", "file_id": data })
+            }
+            LanguageModelDataContent::Base64(data) => json!({
+                "type": "input_image",
+                "image_url": format!("data:{};base64,{}", media_type, data)
+            }),
+            LanguageModelDataContent::Bytes(bytes) => json!({
+                "type": "input_image",
+                "image_url": format!(
+                    "data:{};base64,{}",
+                    media_type,
+                    base64::engine::general_purpose::STANDARD.encode(bytes)
+                )
+            }),
+        };
+        Some(part)
+    } else if media_type == "application/pdf" {
+        let filename = file_part
+            .filename
+            .clone()
+            .unwrap_or_else(|| format!("part-{}.pdf", index));
+
+        let part = match &file_part.data {
+            LanguageModelDataContent::Url(url) => {
+                json!({ "type": "input_file", "file_url": url.to_string() })
+            }
+            LanguageModelDataContent::Base64(data) if data.starts_with("file-") => {
+                json!({ "type": "input_file", "file_id": data })
+            }
+            LanguageModelDataContent::Base64(data) => json!({
+                "type": "input_file",
+                "filename": filename,
+                "file_data": format!("data:application/pdf;base64,{}", data)
+            }),
+            LanguageModelDataContent::Bytes(bytes) => json!({
+                "type": "input_file",
+                "filename": filename,
+                "file_data": format!(
+                    "data:application/pdf;base64,{}",
+                    base64::engine::general_purpose::STANDARD.encode(bytes)
+                )
+            }),
+        };
+        Some(part)
+    } else {
+        warnings.push(LanguageModelCallWarning::other(format!(
+            "file part media type {} is not supported",
+            media_type
+        )));
+        None
+    }
+}
+
+fn convert_assistant_message(
+    msg: &LanguageModelAssistantMessage,
+    store: bool,
+    input: &mut OpenAIResponsesInput,
+    warnings: &mut Vec<LanguageModelCallWarning>,
+) {
+    // Index into `input` of the reasoning item for each reasoning item id,
+    // so that multiple summary parts are merged back into a single item.
+    let mut reasoning_items: HashMap<String, usize> = HashMap::new();
+
+    for part in &msg.content {
+        match part {
+            LanguageModelAssistantMessagePart::Text(text_part) => {
+                let mut item = json!({
+                    "role": "assistant",
+                    "content": [{ "type": "output_text", "text": text_part.text }]
+                });
+                if let Some(item_id) = openai_item_id(&text_part.provider_options) {
+                    item["id"] = json!(item_id);
+                }
+                input.push(item);
+            }
+            LanguageModelAssistantMessagePart::ToolCall(tool_call) => {
+                // Provider-executed calls are replayed through their results.
+                if tool_call.provider_executed == Some(true) {
+                    continue;
+                }
+
+                let arguments = match &tool_call.input {
+                    JsonValue::String(arguments) => arguments.clone(),
+                    other => other.to_string(),
+                };
+                let mut item = json!({
+                    "type": "function_call",
+                    "call_id": tool_call.tool_call_id,
+                    "name": tool_call.tool_name,
+                    "arguments": arguments,
+                });
+                if let Some(item_id) = openai_item_id(&tool_call.provider_options) {
+                    item["id"] = json!(item_id);
+                }
+                input.push(item);
+            }
+            LanguageModelAssistantMessagePart::ToolResult(tool_result) => {
+                if store {
+                    input.push(json!({ "type": "item_reference", "id": tool_result.tool_call_id }));
+                } else {
+                    warnings.push(LanguageModelCallWarning::other(format!(
+                        "Results for OpenAI tool {} are not sent to the API when store is false",
+                        tool_result.tool_name
+                    )));
+                }
+            }
+            LanguageModelAssistantMessagePart::Reasoning(reasoning) => {
+                let Some(item_id) = openai_item_id(&reasoning.provider_options) else {
+                    warnings.push(LanguageModelCallWarning::other(
+                        "Non-OpenAI reasoning parts are not supported. Skipping reasoning part.",
+                    ));
+                    continue;
+                };
+
+                if let Some(&index) = reasoning_items.get(&item_id) {
+                    if !store
+                        && !reasoning.text.is_empty()
+                        && let Some(summary) = input[index]["summary"].as_array_mut()
+                    {
+                        summary.push(json!({ "type": "summary_text", "text": reasoning.text }));
+                    }
+                    continue;
+                }
+
+                if store {
+                    input.push(json!({ "type": "item_reference", "id": item_id }));
+                } else {
+                    let summary = if reasoning.text.is_empty() {
+                        vec![]
+                    } else {
+                        vec![json!({ "type": "summary_text", "text": reasoning.text })]
+                    };
+                    let mut item = json!({
+                        "type": "reasoning",
+                        "id": item_id,
+                        "summary": summary,
+                    });
+                    if let Some(encrypted) =
+                        openai_option(&reasoning.provider_options, "reasoningEncryptedContent")
+                    {
+                        item["encrypted_content"] = json!(encrypted);
+                    }
+                    input.push(item);
+                }
+                reasoning_items.insert(item_id, input.len() - 1);
+            }
+            LanguageModelAssistantMessagePart::File(_) => {
+                warnings.push(LanguageModelCallWarning::other(
+                    "file parts in assistant messages are not supported",
+                ));
+            }
+        }
+    }
+}
+
+fn convert_tool_message(msg: &LanguageModelToolMessage, input: &mut OpenAIResponsesInput) {
+    for tool_result in &msg.content {
+        let output = match &tool_result.output {
+            LanguageModelToolResultOutput::Text { value }
+            | LanguageModelToolResultOutput::ErrorText { value } => json!(value),
+            LanguageModelToolResultOutput::Json { value }
+            | LanguageModelToolResultOutput::ErrorJson { value } => json!(value.to_string()),
+            LanguageModelToolResultOutput::Content { value } => JsonValue::Array(
+                value
+                    .iter()
+                    .map(|item| match item {
+                        LanguageModelToolResultContentItem::Text { text } => {
+                            json!({ "type": "input_text", "text": text })
+                        }
+                        LanguageModelToolResultContentItem::Media { data, media_type } => {
+                            if media_type.starts_with("image/") {
+                                json!({
+                                    "type": "input_image",
+                                    "image_url": format!("data:{};base64,{}", media_type, data)
+                                })
+                            } else {
+                                json!({
+                                    "type": "input_file",
+                                    "filename": "data",
+                                    "file_data": format!("data:{};base64,{}", media_type, data)
+                                })
+                            }
+                        }
+                    })
+                    .collect(),
+            ),
+        };
+
+        input.push(json!({
+            "type": "function_call_output",
+            "call_id": tool_result.tool_call_id,
+            "output": output,
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use llm_kit_provider::language_model::prompt::message::parts::{
+        LanguageModelReasoningPart, LanguageModelTextPart, LanguageModelToolCallPart,
+    };
+    use llm_kit_provider::language_model::prompt::message::{
+        LanguageModelSystemMessage, LanguageModelToolResultPart,
+    };
+
+    fn openai_options(entries: &[(&str, &str)]) -> SharedProviderOptions {
+        let mut openai = HashMap::new();
+        for (key, value) in entries {
+            openai.insert(key.to_string(), json!(value));
+        }
+        let mut options = HashMap::new();
+        options.insert("openai".to_string(), openai);
+        options
+    }
+
+    #[test]
+    fn test_system_and_user_messages() {
+        let prompt = vec![
+            LanguageModelMessage::System(LanguageModelSystemMessage::new("Be brief.")),
+            LanguageModelMessage::User(LanguageModelUserMessage::new(vec![
+                LanguageModelUserMessagePart::Text(LanguageModelTextPart::new("Hello")),
+                LanguageModelUserMessagePart::File(LanguageModelFilePart::new(
+                    LanguageModelDataContent::Base64("AAAA".to_string()),
+                    "image/png",
+                )),
+            ])),
+        ];
+
+        let (input, warnings) =
+            convert_to_openai_responses_input(&prompt, SystemMessageMode::Developer, true);
+
+        assert!(warnings.is_empty());
+        assert_eq!(
+            input[0],
+            json!({ "role": "developer", "content": "Be brief." })
+        );
+        assert_eq!(
+            input[1],
+            json!({
+                "role": "user",
+                "content": [
+                    { "type": "input_text", "text": "Hello" },
+                    { "type": "input_image", "image_url": "data:image/png;base64,AAAA" }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_reasoning_without_store_sends_encrypted_content() {
+        let options = openai_options(&[
+            ("itemId", "rs_1"),
+            ("reasoningEncryptedContent", "encrypted"),
+        ]);
+        let prompt = vec![LanguageModelMessage::Assistant(
+            LanguageModelAssistantMessage::new(vec![
+                LanguageModelAssistantMessagePart::Reasoning(
+                    LanguageModelReasoningPart::with_options("first", Some(options.clone())),
+                ),
+                LanguageModelAssistantMessagePart::Reasoning(
+                    LanguageModelReasoningPart::with_options("second", Some(options)),
+                ),
+                LanguageModelAssistantMessagePart::Text(LanguageModelTextPart::with_options(
+                    "Answer",
+                    Some(openai_options(&[("itemId", "msg_1")])),
+                )),
+            ]),
+        )];
+
+        let (input, warnings) =
+            convert_to_openai_responses_input(&prompt, SystemMessageMode::System, false);
+
+        assert!(warnings.is_empty());
+        assert_eq!(
+            input,
+            vec![
+                json!({
+                    "type": "reasoning",
+                    "id": "rs_1",
+                    "encrypted_content": "encrypted",
+                    "summary": [
+                        { "type": "summary_text", "text": "first" },
+                        { "type": "summary_text", "text": "second" }
+                    ]
+                }),
+                json!({
+                    "role": "assistant",
+                    "id": "msg_1",
+                    "content": [{ "type": "output_text", "text": "Answer" }]
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reasoning_with_store_uses_item_reference() {
+        let options = openai_options(&[("itemId", "rs_1")]);
+        let prompt = vec![LanguageModelMessage::Assistant(
+            LanguageModelAssistantMessage::new(vec![
+                LanguageModelAssistantMessagePart::Reasoning(
+                    LanguageModelReasoningPart::with_options("first", Some(options.clone())),
+                ),
+                LanguageModelAssistantMessagePart::Reasoning(
+                    LanguageModelReasoningPart::with_options("second", Some(options)),
+                ),
+            ]),
+        )];
+
+        let (input, _) =
+            convert_to_openai_responses_input(&prompt, SystemMessageMode::System, true);
+
+        assert_eq!(
+            input,
+            vec![json!({ "type": "item_reference", "id": "rs_1" })]
+        );
+    }
+
+    #[test]
+    fn test_function_call_round_trip() {
+        let prompt = vec![
+            LanguageModelMessage::Assistant(LanguageModelAssistantMessage::new(vec![
+                LanguageModelAssistantMessagePart::ToolCall(LanguageModelToolCallPart::new(
+                    "call_1",
+                    "get_weather",
+                    json!({ "city": "Paris" }),
+                )),
+            ])),
+            LanguageModelMessage::Tool(LanguageModelToolMessage::new(vec![
+                LanguageModelToolResultPart::new(
+                    "call_1",
+                    "get_weather",
+                    LanguageModelToolResultOutput::json(json!({ "temp": 20 })),
+                ),
+            ])),
+        ];
+
+        let (input, _) =
+            convert_to_openai_responses_input(&prompt, SystemMessageMode::System, true);
+
+        assert_eq!(
+            input[0],
+            json!({
+                "type": "function_call",
+                "call_id": "call_1",
+                "name": "get_weather",
+                "arguments": "{\"city\":\"Paris\"}"
+            })
+        );
+        assert_eq!(
+            input[1],
+            json!({
+                "type": "function_call_output",
+                "call_id": "call_1",
+                "output": "{\"temp\":20}"
+            })
+        );
+    }
+}
@@ -12,6 +12,7 @@ use std::collections::HashMap;
 /// * `project` - OpenAI project ID.
 /// * `headers` - Custom headers to include in the requests.
 /// * `name` - Provider name. Overrides the `openai` default name for 3rd party providers.
+/// * `http_client` - HTTP client shared by all models of the provider.
 ///
 /// # Example
 ///
@@ -30,6 +31,7 @@ use std::collections::HashMap;
 ///         h
 ///     }),
 ///     name: Some("my-openai".to_string()),
+///     http_client: None,
 /// };
 /// ```
 #[derive(Debug, Clone, Default)]
@@ -53,6 +55,10 @@ pub struct OpenAIProviderSettings {
 
     /// Provider name. Overrides the `openai` default name for 3rd party providers.
     pub name: Option<String>,
+
+    /// Optional HTTP client shared by all models of the provider. Use it to configure
+    /// proxies, timeouts, TLS roots or a user agent. Defaults to a new `reqwest::Client`.
+    pub http_client: Option<reqwest::Client>,
 }
 
 impl OpenAIProviderSettings {
@@ -108,6 +114,12 @@ impl OpenAIProviderSettings {
         self.name = Some(name.into());
         self
     }
+
+    /// Sets the HTTP client used for API requests.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
 }
 
 #[cfg(test)]
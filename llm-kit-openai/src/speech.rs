@@ -0,0 +1,9 @@
+//! OpenAI Speech API Implementation
+//!
+//! This module contains the text-to-speech model for OpenAI's `/audio/speech` endpoint.
+
+pub mod openai_speech_model;
+pub mod openai_speech_options;
+
+pub use openai_speech_model::OpenAISpeechModel;
+pub use openai_speech_options::{OpenAISpeechModelId, OpenAISpeechProviderOptions};
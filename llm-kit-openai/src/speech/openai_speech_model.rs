@@ -0,0 +1,281 @@
+//! OpenAI Speech Model Implementation
+//!
+//! Implements the `SpeechModel` trait for OpenAI's `/audio/speech` endpoint.
+
+use crate::error::parse_openai_error;
+use crate::speech::openai_speech_options::SUPPORTED_OUTPUT_FORMATS;
+use crate::speech::{OpenAISpeechModelId, OpenAISpeechProviderOptions};
+use async_trait::async_trait;
+use llm_kit_provider::speech_model::call_options::SpeechModelCallOptions;
+use llm_kit_provider::speech_model::call_warning::SpeechModelCallWarning;
+use llm_kit_provider::speech_model::{
+    AudioData, SpeechModel, SpeechModelRequestMetadata, SpeechModelResponse,
+    SpeechModelResponseMetadata,
+};
+use serde_json::{Value as JsonValue, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+/// Configuration for the OpenAI speech model
+#[derive(Clone)]
+pub struct OpenAISpeechConfig {
+    /// Provider name
+    pub provider: String,
+    /// Base URL for API calls
+    pub base_url: String,
+    /// Function to get headers
+    pub headers: Arc<dyn Fn() -> HashMap<String, String> + Send + Sync>,
+    /// HTTP client used to send requests
+    pub http_client: reqwest::Client,
+}
+
+impl OpenAISpeechConfig {
+    /// Create a new config
+    pub fn new(
+        provider: String,
+        base_url: String,
+        headers: Arc<dyn Fn() -> HashMap<String, String> + Send + Sync>,
+    ) -> Self {
+        Self {
+            provider,
+            base_url,
+            headers,
+            http_client: reqwest::Client::new(),
+        }
+    }
+
+    /// Set the HTTP client used to send requests
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = http_client;
+        self
+    }
+}
+
+/// OpenAI Speech Model
+///
+/// Generates audio from text with `tts-1`, `tts-1-hd` and `gpt-4o-mini-tts`.
+pub struct OpenAISpeechModel {
+    model_id: OpenAISpeechModelId,
+    config: OpenAISpeechConfig,
+}
+
+impl OpenAISpeechModel {
+    /// Create a new OpenAI speech model
+    pub fn new(model_id: OpenAISpeechModelId, config: OpenAISpeechConfig) -> Self {
+        Self { model_id, config }
+    }
+
+    /// Build the request body and collect warnings for unsupported settings.
+    fn build_request_body(
+        &self,
+        options: &SpeechModelCallOptions,
+    ) -> Result<(JsonValue, Vec<SpeechModelCallWarning>), Box<dyn std::error::Error>> {
+        let mut warnings = Vec::new();
+
+        let openai_options: OpenAISpeechProviderOptions = match options
+            .provider_options
+            .as_ref()
+            .and_then(|o| o.get("openai"))
+        {
+            Some(openai_opts) => serde_json::from_value(serde_json::to_value(openai_opts)?)?,
+            None => OpenAISpeechProviderOptions::default(),
+        };
+
+        let mut body = json!({
+            "model": self.model_id,
+            "input": options.text,
+            "voice": options.voice.as_deref().unwrap_or("alloy"),
+            "response_format": "mp3",
+        });
+
+        if let Some(ref output_format) = options.output_format {
+            if SUPPORTED_OUTPUT_FORMATS.contains(&output_format.as_str()) {
+                body["response_format"] = json!(output_format);
+            } else {
+                warnings.push(SpeechModelCallWarning::unsupported_setting_with_details(
+                    "outputFormat",
+                    format!(
+                        "Unsupported output format: {}. Using mp3 instead.",
+                        output_format
+                    ),
+                ));
+            }
+        }
+
+        if let Some(speed) = openai_options.speed.or(options.speed) {
+            body["speed"] = json!(speed);
+        }
+        if let Some(instructions) = openai_options
+            .instructions
+            .or_else(|| options.instructions.clone())
+        {
+            body["instructions"] = json!(instructions);
+        }
+
+        if options.language.is_some() {
+            warnings.push(SpeechModelCallWarning::unsupported_setting_with_details(
+                "language",
+                "OpenAI speech models do not support language selection. The language is inferred from the input text.",
+            ));
+        }
+
+        Ok((body, warnings))
+    }
+}
+
+#[async_trait]
+impl SpeechModel for OpenAISpeechModel {
+    fn specification_version(&self) -> &str {
+        "v3"
+    }
+
+    fn provider(&self) -> &str {
+        &self.config.provider
+    }
+
+    fn model_id(&self) -> &str {
+        &self.model_id
+    }
+
+    async fn do_generate(
+        &self,
+        options: SpeechModelCallOptions,
+    ) -> Result<SpeechModelResponse, Box<dyn std::error::Error>> {
+        let timestamp = SystemTime::now();
+        let (body, warnings) = self.build_request_body(&options)?;
+
+        // Make HTTP request
+        let client = &self.config.http_client;
+        let url = format!("{}/audio/speech", self.config.base_url);
+        let mut headers = (self.config.headers)();
+        if let Some(ref option_headers) = options.headers {
+            headers.extend(option_headers.clone());
+        }
+
+        let mut request = client.post(&url).json(&body);
+        for (key, value) in headers {
+            request = request.header(key, value);
+        }
+
+        let response = if let Some(signal) = &options.abort_signal {
+            tokio::select! {
+                result = request.send() => result?,
+                _ = signal.cancelled() => {
+                    return Err("Operation cancelled".into());
+                }
+            }
+        } else {
+            request.send().await?
+        };
+
+        let status = response.status();
+        let response_headers: HashMap<String, String> = response
+            .headers()
+            .iter()
+            .map(|(k, v)| (k.as_str().to_string(), v.to_str().unwrap_or("").to_string()))
+            .collect();
+
+        if !status.is_success() {
+            let response_text = response.text().await?;
+            return Err(Box::new(parse_openai_error(
+                &url,
+                &body,
+                status.as_u16(),
+                response_headers,
+                &response_text,
+            )));
+        }
+
+        let audio_bytes = response.bytes().await?;
+
+        let response_metadata =
+            SpeechModelResponseMetadata::with_timestamp(self.model_id.clone(), timestamp)
+                .with_headers(response_headers);
+
+        Ok(
+            SpeechModelResponse::new(AudioData::Binary(audio_bytes.to_vec()), response_metadata)
+                .with_warnings(warnings)
+                .with_request_metadata(SpeechModelRequestMetadata::new().with_body(body)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_model() -> OpenAISpeechModel {
+        OpenAISpeechModel::new(
+            "gpt-4o-mini-tts".to_string(),
+            OpenAISpeechConfig::new(
+                "openai.speech".to_string(),
+                "https://api.openai.com/v1".to_string(),
+                Arc::new(HashMap::new),
+            ),
+        )
+    }
+
+    #[test]
+    fn test_model_properties() {
+        let model = create_model();
+
+        assert_eq!(model.model_id(), "gpt-4o-mini-tts");
+        assert_eq!(model.provider(), "openai.speech");
+    }
+
+    #[test]
+    fn test_build_request_body_defaults() {
+        let model = create_model();
+        let options = SpeechModelCallOptions::new("Hello");
+
+        let (body, warnings) = model.build_request_body(&options).unwrap();
+
+        assert_eq!(
+            body,
+            json!({
+                "model": "gpt-4o-mini-tts",
+                "input": "Hello",
+                "voice": "alloy",
+                "response_format": "mp3"
+            })
+        );
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_build_request_body_with_options() {
+        let model = create_model();
+
+        let mut openai_options = HashMap::new();
+        openai_options.insert("instructions".to_string(), json!("Whisper"));
+        let mut provider_options = HashMap::new();
+        provider_options.insert("openai".to_string(), openai_options);
+
+        let options = SpeechModelCallOptions::new("Hello")
+            .with_voice("nova")
+            .with_output_format("wav")
+            .with_speed(1.5)
+            .with_provider_options(provider_options);
+
+        let (body, _) = model.build_request_body(&options).unwrap();
+
+        assert_eq!(body["voice"], "nova");
+        assert_eq!(body["response_format"], "wav");
+        assert_eq!(body["speed"], 1.5);
+        assert_eq!(body["instructions"], "Whisper");
+    }
+
+    #[test]
+    fn test_unsupported_settings_warnings() {
+        let model = create_model();
+        let options = SpeechModelCallOptions::new("Hello")
+            .with_output_format("ogg")
+            .with_language("de");
+
+        let (body, warnings) = model.build_request_body(&options).unwrap();
+
+        assert_eq!(body["response_format"], "mp3");
+        assert_eq!(warnings.len(), 2);
+    }
+}
@@ -0,0 +1,62 @@
+//! OpenAI Speech Options
+//!
+//! Provider options for the OpenAI speech model.
+
+use serde::{Deserialize, Serialize};
+
+/// OpenAI speech model identifier (e.g. `tts-1`, `tts-1-hd`, `gpt-4o-mini-tts`).
+pub type OpenAISpeechModelId = String;
+
+/// Audio formats supported by the OpenAI speech endpoint.
+pub const SUPPORTED_OUTPUT_FORMATS: &[&str] = &["mp3", "opus", "aac", "flac", "wav", "pcm"];
+
+/// Provider options for OpenAI speech models.
+///
+/// Passed through `provider_options["openai"]`. Values set here take precedence
+/// over the matching call settings.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenAISpeechProviderOptions {
+    /// Instructions for the voice, e.g. tone or accent.
+    /// Not supported by `tts-1` and `tts-1-hd`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+
+    /// Speed of the generated audio, from 0.25 to 4.0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed: Option<f64>,
+}
+
+impl OpenAISpeechProviderOptions {
+    /// Creates new empty options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the voice instructions.
+    pub fn with_instructions(mut self, instructions: impl Into<String>) -> Self {
+        self.instructions = Some(instructions.into());
+        self
+    }
+
+    /// Sets the speed of the generated audio.
+    pub fn with_speed(mut self, speed: f64) -> Self {
+        self.speed = Some(speed);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder() {
+        let options = OpenAISpeechProviderOptions::new()
+            .with_instructions("Speak cheerfully")
+            .with_speed(1.25);
+
+        assert_eq!(options.instructions, Some("Speak cheerfully".to_string()));
+        assert_eq!(options.speed, Some(1.25));
+    }
+}
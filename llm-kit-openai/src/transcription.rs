@@ -0,0 +1,11 @@
+//! OpenAI Transcription API Implementation
+//!
+//! This module contains the speech-to-text model for OpenAI's `/audio/transcriptions` endpoint.
+
+pub mod openai_transcription_model;
+pub mod openai_transcription_options;
+
+pub use openai_transcription_model::OpenAITranscriptionModel;
+pub use openai_transcription_options::{
+    OpenAITranscriptionModelId, OpenAITranscriptionProviderOptions, TimestampGranularity,
+};
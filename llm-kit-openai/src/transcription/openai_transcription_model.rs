@@ -0,0 +1,474 @@
+//! OpenAI Transcription Model Implementation
+//!
+//! Implements the `TranscriptionModel` trait for OpenAI's `/audio/transcriptions` endpoint.
+
+use crate::error::parse_openai_error;
+use crate::transcription::openai_transcription_options::is_json_only_transcription_model;
+use crate::transcription::{
+    OpenAITranscriptionModelId, OpenAITranscriptionProviderOptions, TimestampGranularity,
+};
+use async_trait::async_trait;
+use base64::Engine;
+use llm_kit_provider::transcription_model::call_options::{
+    TranscriptionAudioData, TranscriptionModelCallOptions,
+};
+use llm_kit_provider::transcription_model::{
+    TranscriptSegment, TranscriptionModel, TranscriptionModelRequestMetadata,
+    TranscriptionModelResponse, TranscriptionModelResponseMetadata,
+};
+use reqwest::multipart::{Form, Part};
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+/// Configuration for the OpenAI transcription model
+#[derive(Clone)]
+pub struct OpenAITranscriptionConfig {
+    /// Provider name
+    pub provider: String,
+    /// Base URL for API calls
+    pub base_url: String,
+    /// Function to get headers
+    pub headers: Arc<dyn Fn() -> HashMap<String, String> + Send + Sync>,
+    /// HTTP client used to send requests
+    pub http_client: reqwest::Client,
+}
+
+impl OpenAITranscriptionConfig {
+    /// Create a new config
+    pub fn new(
+        provider: String,
+        base_url: String,
+        headers: Arc<dyn Fn() -> HashMap<String, String> + Send + Sync>,
+    ) -> Self {
+        Self {
+            provider,
+            base_url,
+            headers,
+            http_client: reqwest::Client::new(),
+        }
+    }
+
+    /// Set the HTTP client used to send requests
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = http_client;
+        self
+    }
+}
+
+/// OpenAI Transcription Model
+///
+/// Transcribes audio with `whisper-1`, `gpt-4o-transcribe` and `gpt-4o-mini-transcribe`.
+/// `whisper-1` returns verbose transcriptions with segments, language and duration.
+pub struct OpenAITranscriptionModel {
+    model_id: OpenAITranscriptionModelId,
+    config: OpenAITranscriptionConfig,
+}
+
+impl OpenAITranscriptionModel {
+    /// Create a new OpenAI transcription model
+    pub fn new(model_id: OpenAITranscriptionModelId, config: OpenAITranscriptionConfig) -> Self {
+        Self { model_id, config }
+    }
+
+    /// Build the text fields of the multipart form (everything except the audio file).
+    fn build_form_fields(
+        &self,
+        options: &TranscriptionModelCallOptions,
+    ) -> Result<Vec<(String, String)>, Box<dyn std::error::Error>> {
+        let openai_options: OpenAITranscriptionProviderOptions = match options
+            .provider_options
+            .as_ref()
+            .and_then(|o| o.get("openai"))
+        {
+            Some(openai_opts) => serde_json::from_value(serde_json::to_value(openai_opts)?)?,
+            None => OpenAITranscriptionProviderOptions::default(),
+        };
+
+        let response_format = if is_json_only_transcription_model(&self.model_id) {
+            "json"
+        } else {
+            "verbose_json"
+        };
+
+        let mut fields = vec![
+            ("model".to_string(), self.model_id.clone()),
+            ("response_format".to_string(), response_format.to_string()),
+        ];
+
+        if let Some(language) = openai_options.language {
+            fields.push(("language".to_string(), language));
+        }
+        if let Some(prompt) = openai_options.prompt {
+            fields.push(("prompt".to_string(), prompt));
+        }
+        if let Some(temperature) = openai_options.temperature {
+            fields.push(("temperature".to_string(), temperature.to_string()));
+        }
+        for include in openai_options.include.unwrap_or_default() {
+            fields.push(("include[]".to_string(), include));
+        }
+        if response_format == "verbose_json" {
+            let granularities = openai_options
+                .timestamp_granularities
+                .unwrap_or_else(|| vec![TimestampGranularity::Segment]);
+            for granularity in granularities {
+                fields.push((
+                    "timestamp_granularities[]".to_string(),
+                    granularity.as_str().to_string(),
+                ));
+            }
+        }
+
+        Ok(fields)
+    }
+}
+
+#[async_trait]
+impl TranscriptionModel for OpenAITranscriptionModel {
+    fn specification_version(&self) -> &str {
+        "v3"
+    }
+
+    fn provider(&self) -> &str {
+        &self.config.provider
+    }
+
+    fn model_id(&self) -> &str {
+        &self.model_id
+    }
+
+    async fn do_generate(
+        &self,
+        options: TranscriptionModelCallOptions,
+    ) -> Result<TranscriptionModelResponse, Box<dyn std::error::Error>> {
+        let timestamp = SystemTime::now();
+        let fields = self.build_form_fields(&options)?;
+
+        let audio_bytes = match &options.audio {
+            TranscriptionAudioData::Base64(data) => {
+                base64::engine::general_purpose::STANDARD.decode(data.as_bytes())?
+            }
+            TranscriptionAudioData::Binary(data) => data.clone(),
+        };
+        let file_part = Part::bytes(audio_bytes)
+            .file_name(format!(
+                "audio.{}",
+                media_type_to_extension(&options.media_type)
+            ))
+            .mime_str(&options.media_type)?;
+
+        let mut form = Form::new().part("file", file_part);
+        for (key, value) in &fields {
+            form = form.text(key.clone(), value.clone());
+        }
+
+        // Make HTTP request
+        let client = &self.config.http_client;
+        let url = format!("{}/audio/transcriptions", self.config.base_url);
+        let mut headers = (self.config.headers)();
+        if let Some(ref option_headers) = options.headers {
+            headers.extend(option_headers.clone());
+        }
+
+        let mut request = client.post(&url).multipart(form);
+        for (key, value) in headers {
+            request = request.header(key, value);
+        }
+
+        let response = if let Some(signal) = &options.abort_signal {
+            tokio::select! {
+                result = request.send() => result?,
+                _ = signal.cancelled() => {
+                    return Err("Operation cancelled".into());
+                }
+            }
+        } else {
+            request.send().await?
+        };
+
+        let status = response.status();
+        let response_headers: HashMap<String, String> = response
+            .headers()
+            .iter()
+            .map(|(k, v)| (k.as_str().to_string(), v.to_str().unwrap_or("").to_string()))
+            .collect();
+        let response_text = response.text().await?;
+
+        // Describe the form fields as JSON for the request metadata and errors
+        let request_body: JsonValue = fields
+            .iter()
+            .map(|(key, value)| (key.clone(), json!(value)))
+            .collect::<serde_json::Map<_, _>>()
+            .into();
+
+        if !status.is_success() {
+            return Err(Box::new(parse_openai_error(
+                &url,
+                &request_body,
+                status.as_u16(),
+                response_headers,
+                &response_text,
+            )));
+        }
+
+        let response_body: JsonValue = serde_json::from_str(&response_text)?;
+        let transcription: OpenAITranscriptionResponse =
+            serde_json::from_value(response_body.clone())?;
+
+        let response_metadata =
+            TranscriptionModelResponseMetadata::with_timestamp(self.model_id.clone(), timestamp)
+                .with_headers(response_headers)
+                .with_body(response_body);
+
+        let mut result = TranscriptionModelResponse::new(transcription.text, response_metadata)
+            .with_segments(convert_segments(
+                transcription.segments,
+                transcription.words,
+            ))
+            .with_request_metadata(
+                TranscriptionModelRequestMetadata::new().with_body(request_body.to_string()),
+            );
+
+        if let Some(language) = transcription.language {
+            result = result.with_language(to_iso_639_1(&language));
+        }
+        if let Some(duration) = transcription.duration {
+            result = result.with_duration(duration);
+        }
+
+        Ok(result)
+    }
+}
+
+/// Convert verbose segments (or words, when only word timestamps were requested).
+fn convert_segments(
+    segments: Option<Vec<OpenAITranscriptionSegment>>,
+    words: Option<Vec<OpenAITranscriptionWord>>,
+) -> Vec<TranscriptSegment> {
+    match (segments, words) {
+        (Some(segments), _) if !segments.is_empty() => segments
+            .into_iter()
+            .map(|segment| TranscriptSegment::new(segment.text, segment.start, segment.end))
+            .collect(),
+        (_, Some(words)) => words
+            .into_iter()
+            .map(|word| TranscriptSegment::new(word.word, word.start, word.end))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Verbose transcriptions report the language by name; map it to ISO-639-1.
+fn to_iso_639_1(language: &str) -> String {
+    let code = match language.to_lowercase().as_str() {
+        "afrikaans" => "af",
+        "arabic" => "ar",
+        "armenian" => "hy",
+        "azerbaijani" => "az",
+        "belarusian" => "be",
+        "bosnian" => "bs",
+        "bulgarian" => "bg",
+        "catalan" => "ca",
+        "chinese" => "zh",
+        "croatian" => "hr",
+        "czech" => "cs",
+        "danish" => "da",
+        "dutch" => "nl",
+        "english" => "en",
+        "estonian" => "et",
+        "finnish" => "fi",
+        "french" => "fr",
+        "galician" => "gl",
+        "german" => "de",
+        "greek" => "el",
+        "hebrew" => "he",
+        "hindi" => "hi",
+        "hungarian" => "hu",
+        "icelandic" => "is",
+        "indonesian" => "id",
+        "italian" => "it",
+        "japanese" => "ja",
+        "kannada" => "kn",
+        "kazakh" => "kk",
+        "korean" => "ko",
+        "latvian" => "lv",
+        "lithuanian" => "lt",
+        "macedonian" => "mk",
+        "malay" => "ms",
+        "marathi" => "mr",
+        "maori" => "mi",
+        "nepali" => "ne",
+        "norwegian" => "no",
+        "persian" => "fa",
+        "polish" => "pl",
+        "portuguese" => "pt",
+        "romanian" => "ro",
+        "russian" => "ru",
+        "serbian" => "sr",
+        "slovak" => "sk",
+        "slovenian" => "sl",
+        "spanish" => "es",
+        "swahili" => "sw",
+        "swedish" => "sv",
+        "tagalog" => "tl",
+        "tamil" => "ta",
+        "thai" => "th",
+        "turkish" => "tr",
+        "ukrainian" => "uk",
+        "urdu" => "ur",
+        "vietnamese" => "vi",
+        "welsh" => "cy",
+        _ => return language.to_string(),
+    };
+    code.to_string()
+}
+
+/// Convert IANA media type to file extension.
+fn media_type_to_extension(media_type: &str) -> &str {
+    match media_type {
+        "audio/mpeg" | "audio/mp3" => "mp3",
+        "audio/wav" | "audio/wave" | "audio/x-wav" => "wav",
+        "audio/ogg" => "ogg",
+        "audio/flac" => "flac",
+        "audio/webm" => "webm",
+        "audio/mp4" | "audio/m4a" | "audio/x-m4a" => "m4a",
+        _ => "bin",
+    }
+}
+
+/// OpenAI transcription API response (`json` or `verbose_json`)
+#[derive(Debug, Deserialize)]
+struct OpenAITranscriptionResponse {
+    text: String,
+    #[serde(default)]
+    language: Option<String>,
+    #[serde(default)]
+    duration: Option<f64>,
+    #[serde(default)]
+    segments: Option<Vec<OpenAITranscriptionSegment>>,
+    #[serde(default)]
+    words: Option<Vec<OpenAITranscriptionWord>>,
+}
+
+/// A segment of a verbose transcription
+#[derive(Debug, Deserialize)]
+struct OpenAITranscriptionSegment {
+    text: String,
+    start: f64,
+    end: f64,
+}
+
+/// A word of a verbose transcription
+#[derive(Debug, Deserialize)]
+struct OpenAITranscriptionWord {
+    word: String,
+    start: f64,
+    end: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_model(model_id: &str) -> OpenAITranscriptionModel {
+        OpenAITranscriptionModel::new(
+            model_id.to_string(),
+            OpenAITranscriptionConfig::new(
+                "openai.transcription".to_string(),
+                "https://api.openai.com/v1".to_string(),
+                Arc::new(HashMap::new),
+            ),
+        )
+    }
+
+    #[test]
+    fn test_model_properties() {
+        let model = create_model("whisper-1");
+
+        assert_eq!(model.model_id(), "whisper-1");
+        assert_eq!(model.provider(), "openai.transcription");
+    }
+
+    #[test]
+    fn test_form_fields_whisper_defaults_to_verbose_segments() {
+        let model = create_model("whisper-1");
+        let options = TranscriptionModelCallOptions::mp3(vec![1, 2, 3]);
+
+        let fields = model.build_form_fields(&options).unwrap();
+
+        assert!(fields.contains(&("response_format".to_string(), "verbose_json".to_string())));
+        assert!(fields.contains(&(
+            "timestamp_granularities[]".to_string(),
+            "segment".to_string()
+        )));
+    }
+
+    #[test]
+    fn test_form_fields_with_provider_options() {
+        let model = create_model("gpt-4o-transcribe");
+
+        let mut openai_options = HashMap::new();
+        openai_options.insert("language".to_string(), json!("de"));
+        openai_options.insert("prompt".to_string(), json!("Names: Ada"));
+        openai_options.insert("temperature".to_string(), json!(0.2));
+        openai_options.insert("timestampGranularities".to_string(), json!(["word"]));
+        let mut provider_options = HashMap::new();
+        provider_options.insert("openai".to_string(), openai_options);
+
+        let options = TranscriptionModelCallOptions::mp3(vec![1, 2, 3])
+            .with_provider_options(provider_options);
+        let fields = model.build_form_fields(&options).unwrap();
+
+        assert!(fields.contains(&("response_format".to_string(), "json".to_string())));
+        assert!(fields.contains(&("language".to_string(), "de".to_string())));
+        assert!(fields.contains(&("prompt".to_string(), "Names: Ada".to_string())));
+        assert!(fields.contains(&("temperature".to_string(), "0.2".to_string())));
+        // gpt-4o transcription models don't support timestamps
+        assert!(!fields.iter().any(|(k, _)| k == "timestamp_granularities[]"));
+    }
+
+    #[test]
+    fn test_verbose_response_segments() {
+        let response: OpenAITranscriptionResponse = serde_json::from_value(json!({
+            "task": "transcribe",
+            "language": "english",
+            "duration": 2.5,
+            "text": "Hello world",
+            "segments": [
+                { "id": 0, "seek": 0, "start": 0.0, "end": 1.2, "text": "Hello" },
+                { "id": 1, "seek": 0, "start": 1.2, "end": 2.5, "text": " world" }
+            ]
+        }))
+        .unwrap();
+
+        let segments = convert_segments(response.segments, response.words);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[1].text, " world");
+        assert_eq!(segments[1].end_second, 2.5);
+        assert_eq!(to_iso_639_1(&response.language.unwrap()), "en");
+    }
+
+    #[test]
+    fn test_word_only_response() {
+        let response: OpenAITranscriptionResponse = serde_json::from_value(json!({
+            "text": "Hi there",
+            "words": [
+                { "word": "Hi", "start": 0.0, "end": 0.3 },
+                { "word": "there", "start": 0.3, "end": 0.7 }
+            ]
+        }))
+        .unwrap();
+
+        let segments = convert_segments(response.segments, response.words);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].text, "Hi");
+    }
+
+    #[test]
+    fn test_unknown_language_passthrough() {
+        assert_eq!(to_iso_639_1("klingon"), "klingon");
+    }
+}
@@ -0,0 +1,129 @@
+//! OpenAI Transcription Options
+//!
+//! Provider options for the OpenAI transcription model.
+
+use serde::{Deserialize, Serialize};
+
+/// OpenAI transcription model identifier (e.g. `whisper-1`, `gpt-4o-transcribe`).
+pub type OpenAITranscriptionModelId = String;
+
+/// Granularity of the timestamps returned in verbose transcriptions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimestampGranularity {
+    /// Word-level timestamps
+    Word,
+    /// Segment-level timestamps
+    Segment,
+}
+
+impl TimestampGranularity {
+    /// The value sent to the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimestampGranularity::Word => "word",
+            TimestampGranularity::Segment => "segment",
+        }
+    }
+}
+
+/// Provider options for OpenAI transcription models.
+///
+/// Passed through `provider_options["openai"]`.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenAITranscriptionProviderOptions {
+    /// Additional information to include in the response, e.g. `logprobs`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<Vec<String>>,
+
+    /// The language of the input audio in ISO-639-1 format (e.g. `en`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+
+    /// Text to guide the model's style or continue a previous audio segment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt: Option<String>,
+
+    /// Sampling temperature between 0 and 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f64>,
+
+    /// Timestamp granularities to populate. Defaults to `segment`.
+    /// Only used by models that return verbose transcriptions (`whisper-1`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp_granularities: Option<Vec<TimestampGranularity>>,
+}
+
+impl OpenAITranscriptionProviderOptions {
+    /// Creates new empty options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the additional response information to include.
+    pub fn with_include(mut self, include: Vec<String>) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sets the language of the input audio.
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Sets the prompt.
+    pub fn with_prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.prompt = Some(prompt.into());
+        self
+    }
+
+    /// Sets the sampling temperature.
+    pub fn with_temperature(mut self, temperature: f64) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    /// Sets the timestamp granularities.
+    pub fn with_timestamp_granularities(
+        mut self,
+        granularities: Vec<TimestampGranularity>,
+    ) -> Self {
+        self.timestamp_granularities = Some(granularities);
+        self
+    }
+}
+
+/// Whether the model only supports `json` and `text` responses (no verbose segments).
+pub fn is_json_only_transcription_model(model_id: &str) -> bool {
+    model_id.starts_with("gpt-4o-transcribe") || model_id.starts_with("gpt-4o-mini-transcribe")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_deserialize_options() {
+        let options: OpenAITranscriptionProviderOptions = serde_json::from_value(json!({
+            "language": "en",
+            "timestampGranularities": ["word"]
+        }))
+        .unwrap();
+
+        assert_eq!(options.language, Some("en".to_string()));
+        assert_eq!(
+            options.timestamp_granularities,
+            Some(vec![TimestampGranularity::Word])
+        );
+    }
+
+    #[test]
+    fn test_json_only_models() {
+        assert!(is_json_only_transcription_model("gpt-4o-transcribe"));
+        assert!(is_json_only_transcription_model("gpt-4o-mini-transcribe"));
+        assert!(!is_json_only_transcription_model("whisper-1"));
+    }
+}
@@ -0,0 +1,57 @@
+use llm_kit_openai::{OpenAIProvider, OpenAIProviderSettings};
+use llm_kit_provider::LanguageModel;
+use llm_kit_provider::error::ProviderError;
+use llm_kit_provider::language_model::call_options::LanguageModelCallOptions;
+use llm_kit_provider::language_model::prompt::LanguageModelMessage;
+use llm_kit_provider_utils::testing::{MockResponse, MockServer, test_http_client};
+use serde_json::json;
+
+fn provider(server: &MockServer) -> OpenAIProvider {
+    OpenAIProvider::new(
+        OpenAIProviderSettings::new()
+            .with_base_url(server.url())
+            .with_api_key("test-key")
+            .with_http_client(test_http_client()),
+    )
+}
+
+fn error_response(status: u16, message: &str) -> MockResponse {
+    MockResponse::json(json!({ "error": { "message": message } })).with_status(status)
+}
+
+fn call_options() -> LanguageModelCallOptions {
+    LanguageModelCallOptions::new(vec![LanguageModelMessage::user_text("Hi")])
+}
+
+#[tokio::test]
+async fn test_chat_generate_errors_are_api_call_errors() {
+    let server = MockServer::start().await;
+    server.enqueue(error_response(401, "Invalid API key"));
+    server.enqueue(error_response(429, "Rate limit reached"));
+    let model = provider(&server).chat("gpt-4o");
+
+    for (status, retryable) in [(401, false), (429, true)] {
+        let error = model.do_generate(call_options()).await.unwrap_err();
+        let error = error.downcast_ref::<ProviderError>().unwrap();
+
+        assert_eq!(error.status_code(), Some(status));
+        assert_eq!(error.is_retryable(), retryable);
+    }
+}
+
+#[tokio::test]
+async fn test_chat_stream_errors_are_api_call_errors() {
+    let server = MockServer::start().await;
+    server.enqueue(error_response(429, "Rate limit reached"));
+    let model = provider(&server).chat("gpt-4o");
+
+    let error = match model.do_stream(call_options()).await {
+        Ok(_) => panic!("expected an error"),
+        Err(error) => error,
+    };
+    let error = error.downcast_ref::<ProviderError>().unwrap();
+
+    assert_eq!(error.status_code(), Some(429));
+    assert!(error.is_retryable());
+    assert!(error.to_string().contains("Rate limit reached"));
+}
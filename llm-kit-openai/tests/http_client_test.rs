@@ -0,0 +1,35 @@
+use llm_kit_openai::{OpenAIProvider, OpenAIProviderSettings};
+use llm_kit_provider_utils::testing::{
+    AnyModel, MockServer, assert_models_use_http_client, provider_models, test_http_client,
+};
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_http_client_is_used_by_every_model() {
+    let server = MockServer::start().await;
+    let provider = OpenAIProvider::new(
+        OpenAIProviderSettings::new()
+            .with_base_url(server.url())
+            .with_api_key("test-key")
+            .with_http_client(test_http_client()),
+    );
+
+    let mut models = provider_models(&provider, "test-model");
+    models.push(AnyModel::Language(Arc::new(
+        provider.responses("test-model"),
+    )));
+    let kinds: Vec<&str> = models.iter().map(|model| model.kind()).collect();
+    assert_eq!(
+        kinds,
+        [
+            "language",
+            "embedding",
+            "image",
+            "speech",
+            "transcription",
+            "language"
+        ]
+    );
+
+    assert_models_use_http_client(&server, &models).await;
+}
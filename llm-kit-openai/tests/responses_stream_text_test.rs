@@ -0,0 +1,124 @@
+use llm_kit_core::prompt::Prompt;
+use llm_kit_core::tool::ToolSet;
+use llm_kit_core::{StreamText, step_count_is};
+use llm_kit_openai::{OpenAIProvider, OpenAIProviderSettings};
+use llm_kit_provider::shared::provider_options::SharedProviderOptions;
+use llm_kit_provider_utils::testing::{MockResponse, MockServer, test_http_client};
+use llm_kit_provider_utils::tool::{Tool, ToolExecutionOutput};
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A first step with a reasoning item that has no summary text, followed by a tool call.
+fn reasoning_and_tool_call_step() -> MockResponse {
+    MockResponse::event_stream(
+        [
+            json!({ "type": "response.created", "response": { "id": "resp_1", "created_at": 1, "model": "o4-mini" } }),
+            json!({ "type": "response.output_item.added", "output_index": 0, "item": { "type": "reasoning", "id": "rs_1", "summary": [] } }),
+            json!({ "type": "response.output_item.done", "output_index": 0, "item": { "type": "reasoning", "id": "rs_1", "encrypted_content": "enc_1", "summary": [] } }),
+            json!({ "type": "response.output_item.added", "output_index": 1, "item": { "type": "function_call", "id": "fc_1", "call_id": "call_1", "name": "get_weather", "arguments": "" } }),
+            json!({ "type": "response.function_call_arguments.delta", "item_id": "fc_1", "output_index": 1, "delta": "{\"city\":\"Paris\"}" }),
+            json!({ "type": "response.output_item.done", "output_index": 1, "item": { "type": "function_call", "id": "fc_1", "call_id": "call_1", "name": "get_weather", "arguments": "{\"city\":\"Paris\"}" } }),
+            json!({ "type": "response.completed", "response": { "id": "resp_1", "usage": { "input_tokens": 5, "output_tokens": 7 } } }),
+        ],
+        false,
+    )
+}
+
+fn text_step() -> MockResponse {
+    MockResponse::event_stream(
+        [
+            json!({ "type": "response.created", "response": { "id": "resp_2", "created_at": 2, "model": "o4-mini" } }),
+            json!({ "type": "response.output_item.added", "output_index": 0, "item": { "type": "message", "id": "msg_1", "content": [] } }),
+            json!({ "type": "response.output_text.delta", "item_id": "msg_1", "delta": "It is sunny in Paris." }),
+            json!({ "type": "response.output_item.done", "output_index": 0, "item": { "type": "message", "id": "msg_1", "content": [] } }),
+            json!({ "type": "response.completed", "response": { "id": "resp_2", "usage": { "input_tokens": 20, "output_tokens": 6 } } }),
+        ],
+        false,
+    )
+}
+
+fn weather_tools() -> ToolSet {
+    let weather_tool = Tool::function(json!({
+        "type": "object",
+        "properties": { "city": { "type": "string" } },
+        "required": ["city"]
+    }))
+    .with_execute(Arc::new(|_input, _options| {
+        ToolExecutionOutput::Single(Box::pin(async { Ok(json!("Sunny")) }))
+    }));
+
+    let mut tools = ToolSet::new();
+    tools.insert("get_weather".to_string(), weather_tool);
+    tools
+}
+
+#[tokio::test]
+async fn test_stream_text_round_trips_reasoning_between_steps() {
+    let server = MockServer::start().await;
+    server.enqueue(reasoning_and_tool_call_step());
+    server.enqueue(text_step());
+
+    let provider = OpenAIProvider::new(
+        OpenAIProviderSettings::new()
+            .with_base_url(server.url())
+            .with_api_key("test-key")
+            .with_http_client(test_http_client()),
+    );
+
+    // Without stored responses, reasoning is sent back with its encrypted content
+    let mut provider_options = SharedProviderOptions::new();
+    provider_options.insert(
+        "openai".to_string(),
+        HashMap::from([("store".to_string(), json!(false))]),
+    );
+
+    let result = StreamText::new(
+        Arc::new(provider.responses("o4-mini")),
+        Prompt::text("What is the weather in Paris?"),
+    )
+    .tools(weather_tools())
+    .provider_options(provider_options)
+    .stop_when(vec![Box::new(step_count_is(2))])
+    .execute()
+    .await
+    .unwrap();
+
+    assert_eq!(result.text().await.unwrap(), "It is sunny in Paris.");
+
+    // The reasoning of the first step is kept although it has no summary text
+    let reasoning = result.reasoning().await.unwrap();
+    assert_eq!(reasoning.len(), 1);
+    let reasoning = &reasoning[0];
+    assert_eq!(reasoning.text, "");
+    let metadata = reasoning.provider_metadata.as_ref().unwrap();
+    assert_eq!(metadata["openai"]["itemId"], json!("rs_1"));
+    assert_eq!(
+        metadata["openai"]["reasoningEncryptedContent"],
+        json!("enc_1")
+    );
+
+    // The second request sends the reasoning item back before the function call
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    let input = requests[1].json().unwrap()["input"].clone();
+    let input = input.as_array().unwrap();
+    let reasoning_index = input
+        .iter()
+        .position(|item| item["type"] == "reasoning")
+        .expect("reasoning item is sent back");
+    assert_eq!(
+        input[reasoning_index],
+        json!({ "type": "reasoning", "id": "rs_1", "encrypted_content": "enc_1", "summary": [] })
+    );
+    let function_call_index = input
+        .iter()
+        .position(|item| item["type"] == "function_call")
+        .unwrap();
+    assert!(reasoning_index < function_call_index);
+    assert!(
+        input
+            .iter()
+            .any(|item| item["type"] == "function_call_output" && item["call_id"] == "call_1")
+    );
+}
@@ -0,0 +1,368 @@
+//! Server-sent events parsing utilities
+//!
+//! This module implements an incremental parser for the `text/event-stream` format
+//! as specified by the [HTML Living Standard](https://html.spec.whatwg.org/multipage/server-sent-events.html#event-stream-interpretation),
+//! and stream adapters that turn HTTP response byte streams into events and typed
+//! JSON chunks.
+
+use futures_util::stream::{Stream, StreamExt};
+use llm_kit_provider::error::ProviderError;
+use serde::de::DeserializeOwned;
+
+/// The data sent by OpenAI-style APIs to mark the end of a stream.
+pub const DONE_MARKER: &str = "[DONE]";
+
+/// The result of parsing a JSON event.
+///
+/// A malformed event yields `Err(ProviderError::JSONParse { .. })` without ending
+/// the stream, so callers can report the error and keep processing later events.
+pub type ParseResult<T> = Result<T, ProviderError>;
+
+/// A single dispatched server-sent event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventSourceMessage {
+    /// The event type from the `event:` field, or `None` for the default `message` type.
+    pub event: Option<String>,
+
+    /// The event data. Multiple `data:` lines are joined with `\n`.
+    pub data: String,
+
+    /// The last event ID set by an `id:` field, if any.
+    pub id: Option<String>,
+}
+
+/// An incremental parser for the `text/event-stream` format.
+///
+/// Bytes can be fed in chunks of any size. Lines may end in `\r\n`, `\n` or `\r`,
+/// and chunk boundaries may fall anywhere, including inside a line terminator or
+/// a multi-byte UTF-8 character. An event that is not terminated by a blank line
+/// before the stream ends is discarded, as the specification requires.
+///
+/// # Example
+///
+/// ```
+/// use llm_kit_provider_utils::event_source::EventSourceParser;
+///
+/// let mut parser = EventSourceParser::new();
+///
+/// assert!(parser.feed(b"event: delta\ndata: {\"text\":").is_empty());
+///
+/// let events = parser.feed(b"\"Hi\"}\n\n");
+/// assert_eq!(events.len(), 1);
+/// assert_eq!(events[0].event.as_deref(), Some("delta"));
+/// assert_eq!(events[0].data, r#"{"text":"Hi"}"#);
+/// ```
+#[derive(Debug, Default)]
+pub struct EventSourceParser {
+    /// Bytes of the current, incomplete line.
+    line: Vec<u8>,
+
+    /// Whether the previous chunk ended with `\r`, so a leading `\n` must be skipped.
+    pending_cr: bool,
+
+    /// Whether the first line has been processed (used to strip a byte order mark).
+    started: bool,
+
+    /// The event type buffer.
+    event: Option<String>,
+
+    /// The data buffer.
+    data: String,
+
+    /// The last event ID buffer.
+    last_event_id: Option<String>,
+
+    /// The reconnection time in milliseconds from the last valid `retry:` field.
+    retry: Option<u64>,
+}
+
+impl EventSourceParser {
+    /// Create a new parser.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The reconnection time in milliseconds sent by the server, if any.
+    pub fn retry(&self) -> Option<u64> {
+        self.retry
+    }
+
+    /// Feed a chunk of bytes and return the events it completes.
+    pub fn feed(&mut self, chunk: &[u8]) -> Vec<EventSourceMessage> {
+        let mut events = Vec::new();
+        let mut bytes = chunk;
+
+        if self.pending_cr {
+            self.pending_cr = false;
+            if let Some(rest) = bytes.strip_prefix(b"\n") {
+                bytes = rest;
+            }
+        }
+
+        while let Some(pos) = bytes.iter().position(|&b| b == b'\n' || b == b'\r') {
+            self.line.extend_from_slice(&bytes[..pos]);
+
+            if bytes[pos] == b'\r' {
+                match bytes.get(pos + 1) {
+                    Some(b'\n') => bytes = &bytes[pos + 2..],
+                    Some(_) => bytes = &bytes[pos + 1..],
+                    None => {
+                        self.pending_cr = true;
+                        bytes = &[];
+                    }
+                }
+            } else {
+                bytes = &bytes[pos + 1..];
+            }
+
+            let line = std::mem::take(&mut self.line);
+            if let Some(event) = self.process_line(&line) {
+                events.push(event);
+            }
+        }
+
+        self.line.extend_from_slice(bytes);
+        events
+    }
+
+    /// Process a complete line, returning an event if the line dispatches one.
+    fn process_line(&mut self, line: &[u8]) -> Option<EventSourceMessage> {
+        let line = String::from_utf8_lossy(line);
+        let mut line = line.as_ref();
+
+        if !self.started {
+            self.started = true;
+            line = line.strip_prefix('\u{FEFF}').unwrap_or(line);
+        }
+
+        if line.is_empty() {
+            return self.dispatch();
+        }
+
+        if line.starts_with(':') {
+            return None;
+        }
+
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+
+        match field {
+            "event" => self.event = Some(value.to_string()),
+            "data" => {
+                self.data.push_str(value);
+                self.data.push('\n');
+            }
+            "id" if !value.contains('\0') => self.last_event_id = Some(value.to_string()),
+            "retry" if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) => {
+                self.retry = value.parse().ok();
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    /// Dispatch the buffered event and reset the event type and data buffers.
+    fn dispatch(&mut self) -> Option<EventSourceMessage> {
+        let event = self.event.take();
+
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let mut data = std::mem::take(&mut self.data);
+        data.pop();
+
+        Some(EventSourceMessage {
+            event: event.filter(|event| !event.is_empty()),
+            data,
+            id: self.last_event_id.clone(),
+        })
+    }
+}
+
+/// Parse a byte stream into server-sent events.
+///
+/// Errors from the byte stream are passed through unchanged.
+///
+/// # Arguments
+///
+/// * `byte_stream` - Raw bytes, typically from an HTTP response body
+///
+/// # Returns
+///
+/// A stream of `EventSourceMessage` values.
+pub fn event_source_stream<S, B, E>(
+    byte_stream: S,
+) -> impl Stream<Item = Result<EventSourceMessage, E>> + Send
+where
+    S: Stream<Item = Result<B, E>> + Send + 'static,
+    B: AsRef<[u8]> + Send,
+    E: Send,
+{
+    async_stream::stream! {
+        let mut parser = EventSourceParser::new();
+        let mut byte_stream = Box::pin(byte_stream);
+
+        while let Some(result) = byte_stream.next().await {
+            match result {
+                Ok(bytes) => {
+                    for event in parser.feed(bytes.as_ref()) {
+                        yield Ok(event);
+                    }
+                }
+                Err(error) => yield Err(error),
+            }
+        }
+    }
+}
+
+/// Parse a byte stream of server-sent events into typed JSON chunks.
+///
+/// The data of each event is parsed as JSON into `T`. Events whose data is the
+/// `[DONE]` marker are skipped. An event that fails to parse yields
+/// `Ok(Err(ProviderError::JSONParse { .. }))` and the stream continues with the
+/// next event; errors from the byte stream are yielded as `Err`.
+///
+/// # Example
+///
+/// ```
+/// use futures_util::{StreamExt, stream};
+/// use llm_kit_provider_utils::event_source::parse_json_event_stream;
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, Deserialize)]
+/// struct Chunk {
+///     text: String,
+/// }
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let bytes = stream::iter(vec![Ok::<_, std::io::Error>(
+///     "data: {\"text\":\"Hi\"}\n\ndata: oops\n\ndata: [DONE]\n\n",
+/// )]);
+///
+/// let chunks: Vec<_> = parse_json_event_stream::<Chunk, _, _, _>(bytes)
+///     .collect()
+///     .await;
+///
+/// assert_eq!(chunks.len(), 2);
+/// assert_eq!(chunks[0].as_ref().unwrap().as_ref().unwrap().text, "Hi");
+/// assert!(chunks[1].as_ref().unwrap().is_err());
+/// # }
+/// ```
+pub fn parse_json_event_stream<T, S, B, E>(
+    byte_stream: S,
+) -> impl Stream<Item = Result<ParseResult<T>, E>> + Send
+where
+    T: DeserializeOwned,
+    S: Stream<Item = Result<B, E>> + Send + 'static,
+    B: AsRef<[u8]> + Send,
+    E: Send,
+{
+    event_source_stream(byte_stream).filter_map(|result| async move {
+        match result {
+            Ok(event) if event.data == DONE_MARKER => None,
+            Ok(event) => Some(Ok(parse_json_event(&event.data))),
+            Err(error) => Some(Err(error)),
+        }
+    })
+}
+
+/// Parse the data of a single event as JSON.
+#[allow(clippy::result_large_err)]
+fn parse_json_event<T: DeserializeOwned>(data: &str) -> ParseResult<T> {
+    serde_json::from_str(data).map_err(|error| ProviderError::json_parse_error(data, error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::stream;
+    use serde::Deserialize;
+
+    fn feed_all(chunks: &[&[u8]]) -> Vec<EventSourceMessage> {
+        let mut parser = EventSourceParser::new();
+        chunks.iter().flat_map(|chunk| parser.feed(chunk)).collect()
+    }
+
+    #[test]
+    fn test_parses_fields_and_multiline_data() {
+        let events = feed_all(&[
+            b": keep-alive\nevent: update\nid: 42\ndata: first\ndata:second\n\ndata\n\n",
+        ]);
+
+        assert_eq!(
+            events,
+            vec![
+                EventSourceMessage {
+                    event: Some("update".to_string()),
+                    data: "first\nsecond".to_string(),
+                    id: Some("42".to_string()),
+                },
+                EventSourceMessage {
+                    event: None,
+                    data: String::new(),
+                    id: Some("42".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_handles_line_endings_and_chunk_boundaries() {
+        let events = feed_all(&[b"data: a\r", b"\n\r", b"\ndata: b\r\rdata: c\n", b"\n"]);
+
+        let data: Vec<_> = events.iter().map(|event| event.data.as_str()).collect();
+        assert_eq!(data, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_handles_utf8_split_across_chunks() {
+        let bytes = "\u{FEFF}data: héllo 👋\n\n".as_bytes();
+        let chunks: Vec<&[u8]> = bytes.chunks(1).collect();
+
+        let events = feed_all(&chunks);
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].data, "héllo 👋");
+    }
+
+    #[test]
+    fn test_ignores_empty_events_and_discards_unterminated_event() {
+        let mut parser = EventSourceParser::new();
+
+        assert!(parser.feed(b"event: ping\n\nretry: 3000\n\n").is_empty());
+        assert_eq!(parser.retry(), Some(3000));
+        assert!(parser.feed(b"data: incomplete\n").is_empty());
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Chunk {
+        value: u32,
+    }
+
+    #[tokio::test]
+    async fn test_parse_json_event_stream_recovers_from_invalid_json() {
+        let byte_stream = stream::iter(vec![
+            Ok(b"data: {\"value\":1}\n\ndata: {\"val".to_vec()),
+            Ok(b"ue\":\n\ndata: {\"value\":2}\n\ndata: [DONE]\n\n".to_vec()),
+            Err("connection reset"),
+        ]);
+
+        let results: Vec<_> = parse_json_event_stream::<Chunk, _, _, _>(byte_stream)
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap().as_ref().unwrap().value, 1);
+        match results[1].as_ref().unwrap() {
+            Err(ProviderError::JSONParse { text, .. }) => assert_eq!(text, "{\"value\":"),
+            other => panic!("Expected JSONParse error, got {other:?}"),
+        }
+        assert_eq!(results[2].as_ref().unwrap().as_ref().unwrap().value, 2);
+        assert_eq!(results[3].as_ref().unwrap_err(), &"connection reset");
+    }
+}
@@ -142,6 +142,7 @@
 //!
 //! - [`message`]: Message types and content parts for conversations
 //! - [`tool`]: Tool definitions, execution, and approval workflows
+//! - [`event_source`]: Server-sent events parsing for streaming responses
 //!
 //! ## Re-exports
 //!
@@ -201,6 +202,23 @@ pub mod tool;
 /// - Provider-specific option extraction
 pub mod parse_provider_options;
 
+/// Server-sent events parsing utilities.
+///
+/// This module provides a spec-compliant incremental parser for `text/event-stream`
+/// responses, including:
+/// - Incremental parsing across arbitrary chunk boundaries
+/// - Byte stream adapters that yield events
+/// - Typed JSON event streams with recoverable parse errors
+pub mod event_source;
+
+/// Mock HTTP server for provider tests (requires the `testing` feature).
+///
+/// This module provides a local server that records requests and serves canned
+/// responses, together with helpers that check that every model of a provider
+/// sends its requests through an injected HTTP client.
+#[cfg(feature = "testing")]
+pub mod testing;
+
 // Re-export commonly used types for convenience
 pub use message::content_parts::{
     FileId, FilePart, FileSource, ImagePart, ImageSource, ReasoningPart, TextPart, ToolCallPart,
@@ -217,6 +235,6 @@ pub use tool::{
     NeedsApproval, OnPreliminaryToolResult, ProviderDefinedToolFactory,
     ProviderDefinedToolFactoryWithOutput, ProviderDefinedToolOptions, Tool, ToolApprovalRequest,
     ToolApprovalRequestOutput, ToolApprovalResponse, ToolCall, ToolError, ToolExecuteFunction,
-    ToolExecuteOptions, ToolExecutionOutput, ToolNeedsApprovalFunction, ToolOutput, ToolResult,
-    ToolType,
+    ToolExecuteOptions, ToolExecutionOutput, ToolNeedsApprovalFunction, ToolOutput,
+    ToolOutputDenied, ToolResult, ToolType,
 };
@@ -0,0 +1,489 @@
+//! A local mock HTTP server for provider tests.
+//!
+//! Every provider accepts a `reqwest::Client` through `with_http_client` on its
+//! settings and a base URL through `with_base_url`. Pointing both at a
+//! [`MockServer`] lets a test run a model without network access, answer it with
+//! canned responses and inspect the requests it sent:
+//!
+//! ```no_run
+//! use llm_kit_provider_utils::testing::{MockResponse, MockServer, test_http_client};
+//! use serde_json::json;
+//!
+//! # async fn example() {
+//! let server = MockServer::start().await;
+//! server.enqueue(MockResponse::json(json!({ "data": [] })));
+//!
+//! // Create the provider with `.with_base_url(server.url())` and
+//! // `.with_http_client(test_http_client())`, then call one of its models
+//!
+//! let request = &server.requests()[0];
+//! assert_eq!(request.method, "POST");
+//! assert_eq!(request.header("x-llm-kit-test-client"), Some("injected"));
+//! # }
+//! ```
+
+use llm_kit_provider::Provider;
+use llm_kit_provider::embedding_model::EmbeddingModel;
+use llm_kit_provider::embedding_model::call_options::EmbeddingModelCallOptions;
+use llm_kit_provider::image_model::ImageModel;
+use llm_kit_provider::image_model::call_options::ImageModelCallOptions;
+use llm_kit_provider::language_model::LanguageModel;
+use llm_kit_provider::language_model::call_options::LanguageModelCallOptions;
+use llm_kit_provider::language_model::prompt::LanguageModelMessage;
+use llm_kit_provider::reranking_model::RerankingModel;
+use llm_kit_provider::reranking_model::call_options::RerankingModelCallOptions;
+use llm_kit_provider::speech_model::SpeechModel;
+use llm_kit_provider::speech_model::call_options::SpeechModelCallOptions;
+use llm_kit_provider::transcription_model::TranscriptionModel;
+use llm_kit_provider::transcription_model::call_options::TranscriptionModelCallOptions;
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Header that [`test_http_client`] adds to every request.
+pub const TEST_CLIENT_HEADER: &str = "x-llm-kit-test-client";
+
+/// How long [`assert_models_use_http_client`] waits for a single model call.
+const MODEL_CALL_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A canned response of the [`MockServer`].
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    /// HTTP status code.
+    pub status: u16,
+    /// Response headers.
+    pub headers: Vec<(String, String)>,
+    /// Response body.
+    pub body: Vec<u8>,
+}
+
+impl MockResponse {
+    /// Creates a `200 OK` response with a JSON body.
+    pub fn json(body: Value) -> Self {
+        Self {
+            status: 200,
+            headers: vec![("content-type".to_string(), "application/json".to_string())],
+            body: body.to_string().into_bytes(),
+        }
+    }
+
+    /// Creates a `200 OK` server-sent event response with one `data` event per
+    /// value, followed by `data: [DONE]` when `done` is set.
+    pub fn event_stream(events: impl IntoIterator<Item = Value>, done: bool) -> Self {
+        let mut body = String::new();
+        for event in events {
+            body.push_str(&format!("data: {}\n\n", event));
+        }
+        if done {
+            body.push_str("data: [DONE]\n\n");
+        }
+        Self {
+            status: 200,
+            headers: vec![("content-type".to_string(), "text/event-stream".to_string())],
+            body: body.into_bytes(),
+        }
+    }
+
+    /// Sets the status code.
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Adds a response header.
+    pub fn with_header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((key.into(), value.into()));
+        self
+    }
+}
+
+/// A request received by the [`MockServer`].
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    /// HTTP method, e.g. `POST`.
+    pub method: String,
+    /// Request path including the query string.
+    pub path: String,
+    /// Request headers with lowercase names.
+    pub headers: HashMap<String, String>,
+    /// Request body.
+    pub body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    /// Returns a request header by its (case-insensitive) name.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    /// Parses the body as JSON.
+    pub fn json(&self) -> Option<Value> {
+        serde_json::from_slice(&self.body).ok()
+    }
+}
+
+/// A local HTTP/1.1 server that records requests and answers them with queued responses.
+///
+/// Responses are served in the order they were queued. When the queue is empty,
+/// requests are answered with `200 OK` and an empty JSON object.
+pub struct MockServer {
+    url: String,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+    responses: Arc<Mutex<VecDeque<MockResponse>>>,
+}
+
+impl MockServer {
+    /// Starts a server on a random local port.
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("failed to bind mock server");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let responses = Arc::new(Mutex::new(VecDeque::new()));
+
+        let server_requests = Arc::clone(&requests);
+        let server_responses = Arc::clone(&responses);
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(handle_connection(
+                    stream,
+                    Arc::clone(&server_requests),
+                    Arc::clone(&server_responses),
+                ));
+            }
+        });
+
+        Self {
+            url,
+            requests,
+            responses,
+        }
+    }
+
+    /// The base URL of the server, e.g. `http://127.0.0.1:12345`.
+    pub fn url(&self) -> String {
+        self.url.clone()
+    }
+
+    /// Queues a response for the next request.
+    pub fn enqueue(&self, response: MockResponse) {
+        self.responses.lock().unwrap().push_back(response);
+    }
+
+    /// The requests received so far.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+    responses: Arc<Mutex<VecDeque<MockResponse>>>,
+) {
+    let Some(request) = read_request(&mut stream).await else {
+        return;
+    };
+    requests.lock().unwrap().push(request);
+
+    let response = responses
+        .lock()
+        .unwrap()
+        .pop_front()
+        .unwrap_or_else(|| MockResponse::json(Value::Object(Default::default())));
+
+    let mut head = format!("HTTP/1.1 {} Mock\r\n", response.status);
+    for (key, value) in &response.headers {
+        head.push_str(&format!("{}: {}\r\n", key, value));
+    }
+    head.push_str(&format!(
+        "content-length: {}\r\nconnection: close\r\n\r\n",
+        response.body.len()
+    ));
+
+    let _ = stream.write_all(head.as_bytes()).await;
+    let _ = stream.write_all(&response.body).await;
+    let _ = stream.shutdown().await;
+}
+
+async fn read_request(stream: &mut TcpStream) -> Option<RecordedRequest> {
+    let mut buffer = Vec::new();
+    let header_end = loop {
+        if let Some(position) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+            break position + 4;
+        }
+        read_more(stream, &mut buffer).await?;
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+    let mut lines = head.lines();
+    let mut request_line = lines.next()?.split(' ');
+    let method = request_line.next()?.to_string();
+    let path = request_line.next()?.to_string();
+    let headers: HashMap<String, String> = lines
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            Some((key.trim().to_ascii_lowercase(), value.trim().to_string()))
+        })
+        .collect();
+
+    let mut body = buffer.split_off(header_end);
+    if headers
+        .get("transfer-encoding")
+        .is_some_and(|encoding| encoding.eq_ignore_ascii_case("chunked"))
+    {
+        while !body.ends_with(b"0\r\n\r\n") {
+            read_more(stream, &mut body).await?;
+        }
+        body = decode_chunked(&body);
+    } else {
+        let content_length: usize = headers
+            .get("content-length")
+            .and_then(|length| length.parse().ok())
+            .unwrap_or(0);
+        while body.len() < content_length {
+            read_more(stream, &mut body).await?;
+        }
+    }
+
+    Some(RecordedRequest {
+        method,
+        path,
+        headers,
+        body,
+    })
+}
+
+async fn read_more(stream: &mut TcpStream, buffer: &mut Vec<u8>) -> Option<()> {
+    let mut chunk = [0; 8192];
+    let read = stream.read(&mut chunk).await.ok()?;
+    if read == 0 {
+        return None;
+    }
+    buffer.extend_from_slice(&chunk[..read]);
+    Some(())
+}
+
+fn decode_chunked(mut data: &[u8]) -> Vec<u8> {
+    let mut body = Vec::new();
+    while let Some(line_end) = data.windows(2).position(|window| window == b"\r\n") {
+        let size = std::str::from_utf8(&data[..line_end])
+            .ok()
+            .and_then(|size| usize::from_str_radix(size.trim(), 16).ok())
+            .unwrap_or(0);
+        if size == 0 {
+            break;
+        }
+        let start = line_end + 2;
+        body.extend_from_slice(&data[start..start + size]);
+        data = &data[start + size + 2..];
+    }
+    body
+}
+
+/// Creates an HTTP client that marks its requests with the [`TEST_CLIENT_HEADER`] header.
+///
+/// Pass it to a provider's `with_http_client` to check that the provider's models
+/// send their requests through the injected client.
+pub fn test_http_client() -> reqwest::Client {
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert(
+        TEST_CLIENT_HEADER,
+        reqwest::header::HeaderValue::from_static("injected"),
+    );
+    reqwest::Client::builder()
+        .default_headers(headers)
+        .build()
+        .expect("failed to build test HTTP client")
+}
+
+/// A model of any kind, for [`assert_models_use_http_client`].
+#[derive(Clone)]
+pub enum AnyModel {
+    /// A language model.
+    Language(Arc<dyn LanguageModel>),
+    /// A text embedding model.
+    Embedding(Arc<dyn EmbeddingModel<String>>),
+    /// An image model.
+    Image(Arc<dyn ImageModel>),
+    /// A speech model.
+    Speech(Arc<dyn SpeechModel>),
+    /// A transcription model.
+    Transcription(Arc<dyn TranscriptionModel>),
+    /// A reranking model.
+    Reranking(Arc<dyn RerankingModel>),
+}
+
+impl AnyModel {
+    /// Returns a name for the kind of the model.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            AnyModel::Language(_) => "language",
+            AnyModel::Embedding(_) => "embedding",
+            AnyModel::Image(_) => "image",
+            AnyModel::Speech(_) => "speech",
+            AnyModel::Transcription(_) => "transcription",
+            AnyModel::Reranking(_) => "reranking",
+        }
+    }
+
+    /// Makes a minimal call to the model. The result is not checked, because the
+    /// mock responses are usually not valid responses of the provider API.
+    async fn call(&self) {
+        let call = async {
+            match self {
+                AnyModel::Language(model) => {
+                    let prompt = vec![LanguageModelMessage::user_text("Hello")];
+                    let _ = model
+                        .do_generate(LanguageModelCallOptions::new(prompt))
+                        .await;
+                }
+                AnyModel::Embedding(model) => {
+                    let options = EmbeddingModelCallOptions::new(vec!["Hello".to_string()]);
+                    let _ = model.do_embed(options).await;
+                }
+                AnyModel::Image(model) => {
+                    let _ = model
+                        .do_generate(ImageModelCallOptions::new("A cat", 1))
+                        .await;
+                }
+                AnyModel::Speech(model) => {
+                    let _ = model
+                        .do_generate(SpeechModelCallOptions::new("Hello"))
+                        .await;
+                }
+                AnyModel::Transcription(model) => {
+                    let options = TranscriptionModelCallOptions::new(vec![0u8; 16], "audio/wav");
+                    let _ = model.do_generate(options).await;
+                }
+                AnyModel::Reranking(model) => {
+                    let options =
+                        RerankingModelCallOptions::new(vec!["Hello".to_string()], "Greeting");
+                    let _ = model.do_rerank(options).await;
+                }
+            }
+        };
+        // Models that poll for results keep going on unexpected responses
+        let _ = tokio::time::timeout(MODEL_CALL_TIMEOUT, call).await;
+    }
+}
+
+/// Returns every model the provider supports through the [`Provider`] trait.
+pub fn provider_models(provider: &dyn Provider, model_id: &str) -> Vec<AnyModel> {
+    [
+        provider
+            .language_model(model_id)
+            .ok()
+            .map(AnyModel::Language),
+        provider
+            .text_embedding_model(model_id)
+            .ok()
+            .map(AnyModel::Embedding),
+        provider.image_model(model_id).ok().map(AnyModel::Image),
+        provider.speech_model(model_id).ok().map(AnyModel::Speech),
+        provider
+            .transcription_model(model_id)
+            .ok()
+            .map(AnyModel::Transcription),
+        provider
+            .reranking_model(model_id)
+            .ok()
+            .map(AnyModel::Reranking),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Calls each model and asserts that it sent at least one request to the server and
+/// that all its requests were sent through the client from [`test_http_client`].
+///
+/// # Panics
+///
+/// Panics when a model sent no request or sent a request without the
+/// [`TEST_CLIENT_HEADER`] header.
+pub async fn assert_models_use_http_client(server: &MockServer, models: &[AnyModel]) {
+    for model in models {
+        let before = server.requests().len();
+        model.call().await;
+        let requests = server.requests();
+
+        assert!(
+            requests.len() > before,
+            "{} model sent no request to the mock server",
+            model.kind()
+        );
+        for request in &requests[before..] {
+            assert_eq!(
+                request.header(TEST_CLIENT_HEADER),
+                Some("injected"),
+                "{} model sent {} {} without the injected HTTP client",
+                model.kind(),
+                request.method,
+                request.path
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_mock_server_records_requests_and_serves_queued_responses() {
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(json!({ "id": 1 })).with_status(201));
+
+        let client = test_http_client();
+        let response = client
+            .post(format!("{}/v1/chat", server.url()))
+            .json(&json!({ "model": "test" }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 201);
+        assert_eq!(response.json::<Value>().await.unwrap(), json!({ "id": 1 }));
+
+        // The queue is empty, so the default response is served
+        let response = client.get(server.url()).send().await.unwrap();
+        assert_eq!(response.text().await.unwrap(), "{}");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/v1/chat");
+        assert_eq!(requests[0].header(TEST_CLIENT_HEADER), Some("injected"));
+        assert_eq!(requests[0].json(), Some(json!({ "model": "test" })));
+        assert_eq!(requests[1].method, "GET");
+    }
+
+    #[tokio::test]
+    async fn test_event_stream_response() {
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::event_stream([json!({ "delta": "Hi" })], true));
+
+        let body = reqwest::get(server.url())
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        assert_eq!(body, "data: {\"delta\":\"Hi\"}\n\ndata: [DONE]\n\n");
+    }
+
+    #[test]
+    fn test_decode_chunked() {
+        assert_eq!(
+            decode_chunked(b"4\r\nWiki\r\n5\r\npedia\r\n0\r\n\r\n"),
+            b"Wikipedia"
+        );
+    }
+}
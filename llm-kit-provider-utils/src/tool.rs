@@ -16,6 +16,8 @@ pub mod tool_call;
 pub mod tool_error;
 /// Tool output type (either result or error).
 pub mod tool_output;
+/// Output type for tool calls whose execution was denied.
+pub mod tool_output_denied;
 /// Tool result type for successful tool executions.
 pub mod tool_result;
 
@@ -39,6 +41,7 @@ use std::collections::HashMap;
 pub use tool_call::ToolCall;
 pub use tool_error::ToolError;
 pub use tool_output::ToolOutput;
+pub use tool_output_denied::ToolOutputDenied;
 pub use tool_result::ToolResult;
 
 /// A tool that can be called by a language model.
@@ -181,6 +184,12 @@ pub struct Tool {
     /// The type of tool (function, dynamic, or provider-defined).
     pub tool_type: ToolType,
 
+    /// Whether the tool must run on its own.
+    ///
+    /// Tool calls of a step run concurrently by default. Calls to a sequential tool
+    /// wait for the preceding calls to finish and run before the following ones start.
+    pub sequential: bool,
+
     /// Async function that executes the tool with the given input.
     ///
     /// Returns either a single value or a stream of values. Wrapped in `Arc`
@@ -218,6 +227,7 @@ impl Tool {
             output_schema: None,
             needs_approval: NeedsApproval::No,
             tool_type: ToolType::Function,
+            sequential: false,
             execute: None,
             on_input_start: None,
             on_input_delta: None,
@@ -235,6 +245,7 @@ impl Tool {
             output_schema: None,
             needs_approval: NeedsApproval::No,
             tool_type: ToolType::Dynamic,
+            sequential: false,
             execute: None,
             on_input_start: None,
             on_input_delta: None,
@@ -261,6 +272,7 @@ impl Tool {
                 name: name.into(),
                 args,
             },
+            sequential: false,
             execute: None,
             on_input_start: None,
             on_input_delta: None,
@@ -303,6 +315,12 @@ impl Tool {
         self
     }
 
+    /// Sets whether the tool must run on its own instead of concurrently with other tool calls.
+    pub fn with_sequential(mut self, sequential: bool) -> Self {
+        self.sequential = sequential;
+        self
+    }
+
     /// Sets the execute function.
     pub fn with_execute(mut self, func: ToolExecuteFunction<Value, Value>) -> Self {
         self.execute = Some(func);
@@ -506,6 +524,14 @@ mod tests {
         assert!(matches!(tool.needs_approval, NeedsApproval::Yes));
     }
 
+    #[test]
+    fn test_tool_with_sequential() {
+        let schema = json!({"type": "object"});
+
+        assert!(!Tool::function(schema.clone()).sequential);
+        assert!(Tool::function(schema).with_sequential(true).sequential);
+    }
+
     #[test]
     fn test_tool_with_provider_options() {
         let schema = json!({"type": "object"});
@@ -269,6 +269,7 @@ impl ProviderDefinedToolFactory {
                 name: self.name.clone(),
                 args: options.args,
             },
+            sequential: false,
             execute: options.execute,
             on_input_start: options.on_input_start,
             on_input_delta: options.on_input_delta,
@@ -445,6 +446,7 @@ impl ProviderDefinedToolFactoryWithOutput {
                 name: self.name.clone(),
                 args: options.args,
             },
+            sequential: false,
             execute: options.execute,
             on_input_start: options.on_input_start,
             on_input_delta: options.on_input_delta,
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A tool call whose execution was denied by the user.
+///
+/// This is reported instead of a tool result when a tool approval request
+/// is answered with a denial.
+///
+/// # Example
+///
+/// ```
+/// use llm_kit_provider_utils::tool::ToolOutputDenied;
+/// use serde_json::json;
+///
+/// let denied = ToolOutputDenied::new("call_123", "delete_file", json!({"path": "a.txt"}))
+///     .with_reason("Not allowed");
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolOutputDenied {
+    /// Type discriminator (always "tool-output-denied").
+    #[serde(rename = "type")]
+    pub output_type: String,
+
+    /// The ID of the tool call that was denied.
+    pub tool_call_id: String,
+
+    /// The name of the tool that was called.
+    pub tool_name: String,
+
+    /// The input that was provided to the tool.
+    pub input: Value,
+
+    /// Optional reason for the denial.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+
+    /// Whether the provider executed this tool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_executed: Option<bool>,
+}
+
+impl ToolOutputDenied {
+    /// Creates a new denied tool output.
+    ///
+    /// # Arguments
+    ///
+    /// * `tool_call_id` - The ID of the tool call
+    /// * `tool_name` - The name of the tool
+    /// * `input` - The input that was provided to the tool
+    pub fn new(
+        tool_call_id: impl Into<String>,
+        tool_name: impl Into<String>,
+        input: Value,
+    ) -> Self {
+        Self {
+            output_type: "tool-output-denied".to_string(),
+            tool_call_id: tool_call_id.into(),
+            tool_name: tool_name.into(),
+            input,
+            reason: None,
+            provider_executed: None,
+        }
+    }
+
+    /// Sets the reason for the denial.
+    pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+
+    /// Sets whether the provider executed this tool.
+    pub fn with_provider_executed(mut self, executed: bool) -> Self {
+        self.provider_executed = Some(executed);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_tool_output_denied_serialization() {
+        let denied = ToolOutputDenied::new("call_123", "delete_file", json!({"path": "a.txt"}))
+            .with_reason("Not allowed");
+
+        assert_eq!(
+            serde_json::to_value(&denied).unwrap(),
+            json!({
+                "type": "tool-output-denied",
+                "toolCallId": "call_123",
+                "toolName": "delete_file",
+                "input": {"path": "a.txt"},
+                "reason": "Not allowed"
+            })
+        );
+    }
+}
@@ -22,6 +22,8 @@ pub mod content;
 mod data_content;
 /// Finish reason types for language model responses.
 pub mod finish_reason;
+/// Middleware for intercepting language model calls.
+pub mod middleware;
 /// Prompt types for language model requests.
 pub mod prompt;
 /// Response metadata types.
@@ -122,6 +124,17 @@ pub trait LanguageModel: Send + Sync {
     /// a list of regex patterns that match valid URLs for that type.
     async fn supported_urls(&self) -> HashMap<String, Vec<Regex>>;
 
+    /// Whether the model supports structured outputs.
+    ///
+    /// When `true`, the model honours a JSON schema passed through
+    /// [`LanguageModelResponseFormat::Json`](call_options::LanguageModelResponseFormat::Json).
+    /// Object generation falls back to tool calling for models that return `false`.
+    ///
+    /// Defaults to `false`.
+    fn supports_structured_outputs(&self) -> bool {
+        false
+    }
+
     /// Generates text synchronously.
     ///
     /// This method performs a complete text generation request and returns
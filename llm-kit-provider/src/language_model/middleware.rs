@@ -0,0 +1,108 @@
+/// Middleware that applies default call settings.
+pub mod default_settings;
+/// Wrapping of language models with middleware.
+pub mod wrap_language_model;
+
+pub use default_settings::DefaultSettingsMiddleware;
+pub use wrap_language_model::wrap_language_model;
+
+use crate::language_model::call_options::LanguageModelCallOptions;
+use crate::language_model::{
+    LanguageModel, LanguageModelGenerateResponse, LanguageModelStreamResponse,
+};
+use async_trait::async_trait;
+
+/// The type of call that is being made to a wrapped language model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LanguageModelCallType {
+    /// A call to [`LanguageModel::do_generate`].
+    Generate,
+    /// A call to [`LanguageModel::do_stream`].
+    Stream,
+}
+
+/// Middleware for intercepting calls to a language model.
+///
+/// Middleware can transform the call options before they reach the model and wrap the
+/// generate and stream calls to add behaviour such as default settings, logging, caching
+/// or guardrails. All hooks have pass-through default implementations, so implementors
+/// only need to override the hooks they use.
+///
+/// The `model` passed to the hooks is the next model in the middleware chain. Calling
+/// [`LanguageModel::do_generate`] or [`LanguageModel::do_stream`] on it continues the chain.
+///
+/// # Examples
+///
+/// ```
+/// use llm_kit_provider::LanguageModel;
+/// use llm_kit_provider::language_model::call_options::LanguageModelCallOptions;
+/// use llm_kit_provider::language_model::middleware::{
+///     LanguageModelCallType, LanguageModelMiddleware,
+/// };
+/// use async_trait::async_trait;
+///
+/// struct LowTemperatureMiddleware;
+///
+/// #[async_trait]
+/// impl LanguageModelMiddleware for LowTemperatureMiddleware {
+///     async fn transform_params(
+///         &self,
+///         _call_type: LanguageModelCallType,
+///         params: LanguageModelCallOptions,
+///         _model: &dyn LanguageModel,
+///     ) -> Result<LanguageModelCallOptions, Box<dyn std::error::Error>> {
+///         Ok(params.with_temperature(0.0))
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait LanguageModelMiddleware: Send + Sync {
+    /// Overrides the provider name of the wrapped model.
+    ///
+    /// Defaults to `None`, which keeps the provider name of the wrapped model.
+    fn override_provider(&self, _model: &dyn LanguageModel) -> Option<String> {
+        None
+    }
+
+    /// Overrides the model ID of the wrapped model.
+    ///
+    /// Defaults to `None`, which keeps the model ID of the wrapped model.
+    fn override_model_id(&self, _model: &dyn LanguageModel) -> Option<String> {
+        None
+    }
+
+    /// Transforms the call options before they are passed to the wrapped model.
+    ///
+    /// Called for both generate and stream calls, before [`wrap_generate`](Self::wrap_generate)
+    /// and [`wrap_stream`](Self::wrap_stream).
+    async fn transform_params(
+        &self,
+        _call_type: LanguageModelCallType,
+        params: LanguageModelCallOptions,
+        _model: &dyn LanguageModel,
+    ) -> Result<LanguageModelCallOptions, Box<dyn std::error::Error>> {
+        Ok(params)
+    }
+
+    /// Wraps a generate call to the model.
+    ///
+    /// Defaults to calling [`LanguageModel::do_generate`] on the wrapped model.
+    async fn wrap_generate(
+        &self,
+        model: &dyn LanguageModel,
+        params: LanguageModelCallOptions,
+    ) -> Result<LanguageModelGenerateResponse, Box<dyn std::error::Error>> {
+        model.do_generate(params).await
+    }
+
+    /// Wraps a stream call to the model.
+    ///
+    /// Defaults to calling [`LanguageModel::do_stream`] on the wrapped model.
+    async fn wrap_stream(
+        &self,
+        model: &dyn LanguageModel,
+        params: LanguageModelCallOptions,
+    ) -> Result<LanguageModelStreamResponse, Box<dyn std::error::Error>> {
+        model.do_stream(params).await
+    }
+}
@@ -0,0 +1,201 @@
+use crate::language_model::LanguageModel;
+use crate::language_model::call_options::LanguageModelCallOptions;
+use crate::language_model::middleware::{LanguageModelCallType, LanguageModelMiddleware};
+use crate::shared::provider_options::SharedProviderOptions;
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+/// Middleware that applies default settings to every call.
+///
+/// Settings that are set on the call take precedence over the defaults. Headers and
+/// provider options are merged, with the values of the call overriding the defaults.
+///
+/// # Examples
+///
+/// ```
+/// use llm_kit_provider::language_model::middleware::DefaultSettingsMiddleware;
+///
+/// let middleware = DefaultSettingsMiddleware::new()
+///     .with_temperature(0.3)
+///     .with_max_output_tokens(1024);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DefaultSettingsMiddleware {
+    max_output_tokens: Option<u32>,
+    temperature: Option<f64>,
+    stop_sequences: Option<Vec<String>>,
+    top_p: Option<f64>,
+    top_k: Option<u32>,
+    presence_penalty: Option<f64>,
+    frequency_penalty: Option<f64>,
+    seed: Option<u32>,
+    headers: Option<HashMap<String, String>>,
+    provider_options: Option<SharedProviderOptions>,
+}
+
+impl DefaultSettingsMiddleware {
+    /// Creates a new middleware without any default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the default maximum number of output tokens.
+    pub fn with_max_output_tokens(mut self, tokens: u32) -> Self {
+        self.max_output_tokens = Some(tokens);
+        self
+    }
+
+    /// Sets the default temperature.
+    pub fn with_temperature(mut self, temperature: f64) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    /// Sets the default stop sequences.
+    pub fn with_stop_sequences(mut self, sequences: Vec<String>) -> Self {
+        self.stop_sequences = Some(sequences);
+        self
+    }
+
+    /// Sets the default top-p value.
+    pub fn with_top_p(mut self, top_p: f64) -> Self {
+        self.top_p = Some(top_p);
+        self
+    }
+
+    /// Sets the default top-k value.
+    pub fn with_top_k(mut self, top_k: u32) -> Self {
+        self.top_k = Some(top_k);
+        self
+    }
+
+    /// Sets the default presence penalty.
+    pub fn with_presence_penalty(mut self, penalty: f64) -> Self {
+        self.presence_penalty = Some(penalty);
+        self
+    }
+
+    /// Sets the default frequency penalty.
+    pub fn with_frequency_penalty(mut self, penalty: f64) -> Self {
+        self.frequency_penalty = Some(penalty);
+        self
+    }
+
+    /// Sets the default seed.
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets the default headers.
+    pub fn with_headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.headers = Some(headers);
+        self
+    }
+
+    /// Sets the default provider options.
+    pub fn with_provider_options(mut self, options: SharedProviderOptions) -> Self {
+        self.provider_options = Some(options);
+        self
+    }
+
+    /// Applies the default settings to the call options.
+    pub fn apply(&self, mut params: LanguageModelCallOptions) -> LanguageModelCallOptions {
+        params.max_output_tokens = params.max_output_tokens.or(self.max_output_tokens);
+        params.temperature = params.temperature.or(self.temperature);
+        params.stop_sequences = params
+            .stop_sequences
+            .or_else(|| self.stop_sequences.clone());
+        params.top_p = params.top_p.or(self.top_p);
+        params.top_k = params.top_k.or(self.top_k);
+        params.presence_penalty = params.presence_penalty.or(self.presence_penalty);
+        params.frequency_penalty = params.frequency_penalty.or(self.frequency_penalty);
+        params.seed = params.seed.or(self.seed);
+
+        if let Some(defaults) = &self.headers {
+            let mut headers = defaults.clone();
+            headers.extend(params.headers.take().unwrap_or_default());
+            params.headers = Some(headers);
+        }
+
+        if let Some(defaults) = &self.provider_options {
+            let mut provider_options = defaults.clone();
+            for (provider, options) in params.provider_options.take().unwrap_or_default() {
+                provider_options
+                    .entry(provider)
+                    .or_default()
+                    .extend(options);
+            }
+            params.provider_options = Some(provider_options);
+        }
+
+        params
+    }
+}
+
+#[async_trait]
+impl LanguageModelMiddleware for DefaultSettingsMiddleware {
+    async fn transform_params(
+        &self,
+        _call_type: LanguageModelCallType,
+        params: LanguageModelCallOptions,
+        _model: &dyn LanguageModel,
+    ) -> Result<LanguageModelCallOptions, Box<dyn std::error::Error>> {
+        Ok(self.apply(params))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_apply_fills_missing_settings() {
+        let middleware = DefaultSettingsMiddleware::new()
+            .with_temperature(0.3)
+            .with_max_output_tokens(100);
+
+        let params = middleware.apply(LanguageModelCallOptions::new(vec![]).with_temperature(0.9));
+
+        assert_eq!(params.temperature, Some(0.9));
+        assert_eq!(params.max_output_tokens, Some(100));
+        assert_eq!(params.top_p, None);
+    }
+
+    #[test]
+    fn test_apply_merges_headers_and_provider_options() {
+        let middleware = DefaultSettingsMiddleware::new()
+            .with_headers(HashMap::from([
+                ("x-default".to_string(), "1".to_string()),
+                ("x-shared".to_string(), "default".to_string()),
+            ]))
+            .with_provider_options(HashMap::from([(
+                "openai".to_string(),
+                HashMap::from([
+                    ("user".to_string(), json!("default")),
+                    ("store".to_string(), json!(false)),
+                ]),
+            )]));
+
+        let params = middleware.apply(
+            LanguageModelCallOptions::new(vec![])
+                .with_headers(HashMap::from([(
+                    "x-shared".to_string(),
+                    "call".to_string(),
+                )]))
+                .with_provider_options(HashMap::from([(
+                    "openai".to_string(),
+                    HashMap::from([("user".to_string(), json!("call"))]),
+                )])),
+        );
+
+        let headers = params.headers.unwrap();
+        assert_eq!(headers["x-default"], "1");
+        assert_eq!(headers["x-shared"], "call");
+
+        let openai = &params.provider_options.unwrap()["openai"];
+        assert_eq!(openai["user"], json!("call"));
+        assert_eq!(openai["store"], json!(false));
+    }
+}
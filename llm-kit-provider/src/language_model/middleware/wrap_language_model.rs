@@ -0,0 +1,238 @@
+use crate::language_model::call_options::LanguageModelCallOptions;
+use crate::language_model::middleware::{LanguageModelCallType, LanguageModelMiddleware};
+use crate::language_model::{
+    LanguageModel, LanguageModelGenerateResponse, LanguageModelStreamResponse,
+};
+use async_trait::async_trait;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Wraps a language model with a list of middleware.
+///
+/// The middleware are applied in order: the first middleware is the outermost one and
+/// sees the call options first, the last middleware is closest to the model.
+///
+/// # Examples
+///
+/// ```no_run
+/// use llm_kit_provider::LanguageModel;
+/// use llm_kit_provider::language_model::middleware::{
+///     DefaultSettingsMiddleware, LanguageModelMiddleware, wrap_language_model,
+/// };
+/// use std::sync::Arc;
+/// # fn example(model: Arc<dyn LanguageModel>) {
+///
+/// let defaults: Arc<dyn LanguageModelMiddleware> =
+///     Arc::new(DefaultSettingsMiddleware::new().with_temperature(0.2));
+/// let model = wrap_language_model(model, vec![defaults]);
+/// # }
+/// ```
+pub fn wrap_language_model(
+    model: Arc<dyn LanguageModel>,
+    middlewares: Vec<Arc<dyn LanguageModelMiddleware>>,
+) -> Arc<dyn LanguageModel> {
+    middlewares
+        .into_iter()
+        .rev()
+        .fold(model, |wrapped, middleware| {
+            Arc::new(WrappedLanguageModel::new(wrapped, middleware))
+        })
+}
+
+/// A language model whose calls pass through a single middleware.
+struct WrappedLanguageModel {
+    model: Arc<dyn LanguageModel>,
+    middleware: Arc<dyn LanguageModelMiddleware>,
+    provider: String,
+    model_id: String,
+}
+
+impl WrappedLanguageModel {
+    fn new(model: Arc<dyn LanguageModel>, middleware: Arc<dyn LanguageModelMiddleware>) -> Self {
+        let provider = middleware
+            .override_provider(model.as_ref())
+            .unwrap_or_else(|| model.provider().to_string());
+        let model_id = middleware
+            .override_model_id(model.as_ref())
+            .unwrap_or_else(|| model.model_id().to_string());
+
+        Self {
+            model,
+            middleware,
+            provider,
+            model_id,
+        }
+    }
+}
+
+#[async_trait]
+impl LanguageModel for WrappedLanguageModel {
+    fn specification_version(&self) -> &str {
+        self.model.specification_version()
+    }
+
+    fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    fn model_id(&self) -> &str {
+        &self.model_id
+    }
+
+    async fn supported_urls(&self) -> HashMap<String, Vec<Regex>> {
+        self.model.supported_urls().await
+    }
+
+    fn supports_structured_outputs(&self) -> bool {
+        self.model.supports_structured_outputs()
+    }
+
+    async fn do_generate(
+        &self,
+        options: LanguageModelCallOptions,
+    ) -> Result<LanguageModelGenerateResponse, Box<dyn std::error::Error>> {
+        let params = self
+            .middleware
+            .transform_params(
+                LanguageModelCallType::Generate,
+                options,
+                self.model.as_ref(),
+            )
+            .await?;
+
+        self.middleware
+            .wrap_generate(self.model.as_ref(), params)
+            .await
+    }
+
+    async fn do_stream(
+        &self,
+        options: LanguageModelCallOptions,
+    ) -> Result<LanguageModelStreamResponse, Box<dyn std::error::Error>> {
+        let params = self
+            .middleware
+            .transform_params(LanguageModelCallType::Stream, options, self.model.as_ref())
+            .await?;
+
+        self.middleware
+            .wrap_stream(self.model.as_ref(), params)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language_model::finish_reason::LanguageModelFinishReason;
+    use crate::language_model::usage::LanguageModelUsage;
+    use futures::executor::block_on;
+    use std::sync::Mutex;
+
+    struct RecordingLanguageModel {
+        temperatures: Mutex<Vec<Option<f64>>>,
+    }
+
+    #[async_trait]
+    impl LanguageModel for RecordingLanguageModel {
+        fn provider(&self) -> &str {
+            "test-provider"
+        }
+
+        fn model_id(&self) -> &str {
+            "test-model"
+        }
+
+        async fn supported_urls(&self) -> HashMap<String, Vec<Regex>> {
+            HashMap::new()
+        }
+
+        async fn do_generate(
+            &self,
+            options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelGenerateResponse, Box<dyn std::error::Error>> {
+            self.temperatures.lock().unwrap().push(options.temperature);
+
+            Ok(LanguageModelGenerateResponse {
+                content: Vec::new(),
+                finish_reason: LanguageModelFinishReason::Stop,
+                usage: LanguageModelUsage::new(1, 1),
+                provider_metadata: None,
+                request: None,
+                response: None,
+                warnings: Vec::new(),
+            })
+        }
+
+        async fn do_stream(
+            &self,
+            _options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelStreamResponse, Box<dyn std::error::Error>> {
+            unimplemented!("Mock implementation")
+        }
+    }
+
+    /// Multiplies the temperature and records the order in which it was called.
+    struct ScaleTemperatureMiddleware {
+        factor: f64,
+        name: &'static str,
+        calls: Arc<Mutex<Vec<&'static str>>>,
+    }
+
+    #[async_trait]
+    impl LanguageModelMiddleware for ScaleTemperatureMiddleware {
+        fn override_model_id(&self, model: &dyn LanguageModel) -> Option<String> {
+            Some(format!("{}+{}", model.model_id(), self.name))
+        }
+
+        async fn transform_params(
+            &self,
+            _call_type: LanguageModelCallType,
+            params: LanguageModelCallOptions,
+            _model: &dyn LanguageModel,
+        ) -> Result<LanguageModelCallOptions, Box<dyn std::error::Error>> {
+            self.calls.lock().unwrap().push(self.name);
+            let temperature = params.temperature.unwrap_or(1.0) * self.factor;
+            Ok(params.with_temperature(temperature))
+        }
+    }
+
+    #[test]
+    fn test_wrap_language_model_applies_middleware_in_order() {
+        let model = Arc::new(RecordingLanguageModel {
+            temperatures: Mutex::new(Vec::new()),
+        });
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let middlewares: Vec<Arc<dyn LanguageModelMiddleware>> = vec![
+            Arc::new(ScaleTemperatureMiddleware {
+                factor: 0.5,
+                name: "outer",
+                calls: Arc::clone(&calls),
+            }),
+            Arc::new(ScaleTemperatureMiddleware {
+                factor: 0.2,
+                name: "inner",
+                calls: Arc::clone(&calls),
+            }),
+        ];
+
+        let wrapped = wrap_language_model(model.clone(), middlewares);
+        block_on(wrapped.do_generate(LanguageModelCallOptions::new(vec![]))).unwrap();
+
+        assert_eq!(*calls.lock().unwrap(), vec!["outer", "inner"]);
+        assert_eq!(*model.temperatures.lock().unwrap(), vec![Some(0.1)]);
+        assert_eq!(wrapped.provider(), "test-provider");
+        assert_eq!(wrapped.model_id(), "test-model+inner+outer");
+    }
+
+    #[test]
+    fn test_wrap_language_model_without_middleware() {
+        let model: Arc<dyn LanguageModel> = Arc::new(RecordingLanguageModel {
+            temperatures: Mutex::new(Vec::new()),
+        });
+
+        let wrapped = wrap_language_model(Arc::clone(&model), Vec::new());
+
+        assert!(Arc::ptr_eq(&wrapped, &model));
+    }
+}
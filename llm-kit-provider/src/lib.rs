@@ -176,6 +176,7 @@ pub use embedding_model::{
 };
 pub use error::ProviderError;
 pub use image_model::{ImageData, ImageModel, ImageModelResponse, ImageModelResponseMetadata};
+pub use language_model::middleware::LanguageModelMiddleware;
 pub use language_model::{
     LanguageModel, LanguageModelGenerateResponse, LanguageModelRequestMetadata,
     LanguageModelStreamResponse,
@@ -0,0 +1,229 @@
+use async_trait::async_trait;
+use llm_kit_storage::blob::{content_hash, is_content_hash};
+use llm_kit_storage::{BlobRef, BlobStore, StorageError};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::sync::Mutex;
+
+/// Filesystem-based blob store
+///
+/// Payloads are stored as raw files named by their content hash, and every owner
+/// of a payload as an empty marker file next to it:
+///
+/// ```text
+/// base_path/
+/// ├── {hash[..2]}/{hash}
+/// └── {hash[..2]}/{hash}.owners/{owner}
+/// ```
+///
+/// Owners must be non-empty and consist of ASCII letters, digits, `_`, `-` and `.`.
+/// Changes to the owners are serialized within the process, so a payload is never
+/// deleted while another owner is added.
+pub struct FilesystemBlobStore {
+    base_path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FilesystemBlobStore {
+    /// Create a new blob store in the given directory
+    ///
+    /// The directory is created when the first payload is stored.
+    pub fn new(base_path: impl Into<PathBuf>) -> Self {
+        Self {
+            base_path: base_path.into(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Build path for a payload file
+    fn blob_path(&self, hash: &str) -> Result<PathBuf, StorageError> {
+        if !is_content_hash(hash) {
+            return Err(StorageError::InvalidData(format!(
+                "Invalid blob hash: {}",
+                hash
+            )));
+        }
+        Ok(self.base_path.join(&hash[..2]).join(hash))
+    }
+
+    /// Build path for the owner marker directory of a payload
+    fn owners_dir(&self, hash: &str) -> Result<PathBuf, StorageError> {
+        Ok(self.blob_path(hash)?.with_extension("owners"))
+    }
+
+    /// Write an owner marker file
+    async fn write_owner(&self, hash: &str, owner: &str) -> Result<(), StorageError> {
+        let owners_dir = self.owners_dir(hash)?;
+        fs::create_dir_all(&owners_dir)
+            .await
+            .map_err(|e| StorageError::IoError(e.to_string()))?;
+        fs::write(owners_dir.join(owner), [])
+            .await
+            .map_err(|e| StorageError::IoError(e.to_string()))
+    }
+}
+
+/// Check that an owner can be used as a file name
+fn validate_owner(owner: &str) -> Result<(), StorageError> {
+    let valid = owner
+        .bytes()
+        .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'-' | b'.'))
+        && !matches!(owner, "" | "." | "..");
+    if !valid {
+        return Err(StorageError::InvalidData(format!(
+            "Invalid blob owner: {}",
+            owner
+        )));
+    }
+    Ok(())
+}
+
+/// Check whether a directory has no entries
+async fn is_empty_dir(path: &Path) -> Result<bool, StorageError> {
+    match fs::read_dir(path).await {
+        Ok(mut entries) => Ok(entries
+            .next_entry()
+            .await
+            .map_err(|e| StorageError::IoError(e.to_string()))?
+            .is_none()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(true),
+        Err(e) => Err(StorageError::IoError(e.to_string())),
+    }
+}
+
+#[async_trait]
+impl BlobStore for FilesystemBlobStore {
+    async fn put(&self, data: &[u8], owner: &str) -> Result<BlobRef, StorageError> {
+        validate_owner(owner)?;
+        let hash = content_hash(data);
+        let path = self.blob_path(&hash)?;
+        let _guard = self.lock.lock().await;
+
+        if !path.exists() {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .await
+                    .map_err(|e| StorageError::IoError(e.to_string()))?;
+            }
+
+            // Atomic write using temp file + rename
+            let temp_path = path.with_extension("tmp");
+            fs::write(&temp_path, data)
+                .await
+                .map_err(|e| StorageError::IoError(e.to_string()))?;
+            fs::rename(&temp_path, &path)
+                .await
+                .map_err(|e| StorageError::IoError(e.to_string()))?;
+        }
+        self.write_owner(&hash, owner).await?;
+
+        Ok(BlobRef {
+            hash,
+            size: data.len() as u64,
+        })
+    }
+
+    async fn add_owner(&self, hash: &str, owner: &str) -> Result<(), StorageError> {
+        validate_owner(owner)?;
+        let path = self.blob_path(hash)?;
+        let _guard = self.lock.lock().await;
+
+        if !path.exists() {
+            return Err(StorageError::NotFound(format!("Blob not found: {}", hash)));
+        }
+        self.write_owner(hash, owner).await
+    }
+
+    async fn get(&self, hash: &str) -> Result<Vec<u8>, StorageError> {
+        let path = self.blob_path(hash)?;
+        fs::read(&path).await.map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                StorageError::NotFound(format!("Blob not found: {}", hash))
+            } else {
+                StorageError::IoError(e.to_string())
+            }
+        })
+    }
+
+    async fn release(&self, hash: &str, owner: &str) -> Result<bool, StorageError> {
+        validate_owner(owner)?;
+        let path = self.blob_path(hash)?;
+        let owners_dir = self.owners_dir(hash)?;
+        let _guard = self.lock.lock().await;
+
+        match fs::remove_file(owners_dir.join(owner)).await {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(StorageError::IoError(e.to_string())),
+        }
+        if !is_empty_dir(&owners_dir).await? || !path.exists() {
+            return Ok(false);
+        }
+
+        fs::remove_file(&path)
+            .await
+            .map_err(|e| StorageError::IoError(e.to_string()))?;
+        if owners_dir.exists() {
+            fs::remove_dir(&owners_dir)
+                .await
+                .map_err(|e| StorageError::IoError(e.to_string()))?;
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_put_deduplicates_payloads() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = FilesystemBlobStore::new(temp_dir.path());
+
+        let first = store.put(b"screenshot", "msg_1.prt_1").await.unwrap();
+        let second = store.put(b"screenshot", "msg_2.prt_1").await.unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(first.size, 10);
+        assert_eq!(store.get(&first.hash).await.unwrap(), b"screenshot");
+    }
+
+    #[tokio::test]
+    async fn test_release_deletes_payload_after_last_owner() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = FilesystemBlobStore::new(temp_dir.path());
+        let blob = store.put(b"screenshot", "msg_1.prt_1").await.unwrap();
+        store.add_owner(&blob.hash, "msg_2.prt_1").await.unwrap();
+
+        assert!(!store.release(&blob.hash, "msg_1.prt_1").await.unwrap());
+        assert!(store.get(&blob.hash).await.is_ok());
+
+        assert!(store.release(&blob.hash, "msg_2.prt_1").await.unwrap());
+        assert!(matches!(
+            store.get(&blob.hash).await,
+            Err(StorageError::NotFound(_))
+        ));
+        assert!(!store.release(&blob.hash, "msg_2.prt_1").await.unwrap());
+        assert!(matches!(
+            store.add_owner(&blob.hash, "msg_3.prt_1").await,
+            Err(StorageError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_invalid_hashes_and_owners() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = FilesystemBlobStore::new(temp_dir.path());
+
+        assert!(matches!(
+            store.get("../session").await,
+            Err(StorageError::InvalidData(_))
+        ));
+        assert!(matches!(
+            store.put(b"data", "../owner").await,
+            Err(StorageError::InvalidData(_))
+        ));
+    }
+}
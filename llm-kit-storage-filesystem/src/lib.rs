@@ -12,6 +12,7 @@
 //! - **Sortable IDs**: Timestamp-based IDs with lexicographic ordering
 //! - **Atomic Operations**: Write-rename pattern prevents corruption
 //! - **Type Safety**: Strongly-typed message parts with serde serialization
+//! - **Blob Offloading**: Optional content-addressed storage for image and file data
 //!
 //! ## Directory Structure
 //!
@@ -19,7 +20,29 @@
 //! base_path/
 //! ├── session/{sessionID}.json
 //! ├── message/{sessionID}/{messageID}.json
-//! └── part/{messageID}/{partID}.json
+//! ├── part/{messageID}/{partID}.json
+//! ├── stream/{streamID}.json
+//! └── stream/{streamID}.jsonl
+//! ```
+//!
+//! Stream chunks are appended to the `.jsonl` file, one JSON value per line.
+//!
+//! ## Blob Offloading
+//!
+//! By default, image and file data is stored inline in the part files. With a
+//! [`BlobStore`](llm_kit_storage::BlobStore), such as [`FilesystemBlobStore`], the
+//! data is stored once per content hash and the part files only hold a reference.
+//! Payloads are deleted when the last part referencing them is deleted.
+//!
+//! ```rust,no_run
+//! use llm_kit_storage_filesystem::{FilesystemBlobStore, FilesystemStorage};
+//! use std::sync::Arc;
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let storage = FilesystemStorage::new("./ai-storage")?
+//!     .with_blob_store(Arc::new(FilesystemBlobStore::new("./ai-storage/blob")));
+//! # Ok(())
+//! # }
 //! ```
 //!
 //! ## Usage
@@ -57,19 +80,27 @@
 //!
 //! Total length: 30 characters (4 prefix + 12 time + 14 random)
 
-mod id_generator;
+mod blob;
+
+pub use blob::FilesystemBlobStore;
 
 use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
 use llm_kit_storage::{
-    AssistantMessage, MessageMetadata, MessagePart, MessageRole, Session, Storage, StorageError,
-    UserMessage,
+    AssistantMessage, BlobStore, IdGenerator, MessageMetadata, MessagePart, MessageRole, Session,
+    Storage, StorageError, StoredStream, StreamStatus, UserMessage,
 };
-use std::path::PathBuf;
+use serde_json::{Value, json};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tokio::fs;
+use tokio::io::AsyncWriteExt;
 
 /// Filesystem-based storage provider
 pub struct FilesystemStorage {
     base_path: PathBuf,
+    blob_store: Option<Arc<dyn BlobStore>>,
 }
 
 impl FilesystemStorage {
@@ -85,15 +116,25 @@ impl FilesystemStorage {
     pub fn new(base_path: impl Into<PathBuf>) -> Result<Self, StorageError> {
         Ok(Self {
             base_path: base_path.into(),
+            blob_store: None,
         })
     }
 
+    /// Store image and file data in a blob store instead of the part files
+    ///
+    /// Parts written before the blob store was set keep their inline data.
+    pub fn with_blob_store(mut self, blob_store: Arc<dyn BlobStore>) -> Self {
+        self.blob_store = Some(blob_store);
+        self
+    }
+
     /// Initialize storage directories (must be called before use)
     ///
     /// Creates the base directory structure:
     /// - `base_path/session/`
     /// - `base_path/message/`
     /// - `base_path/part/`
+    /// - `base_path/stream/`
     ///
     /// # Errors
     ///
@@ -111,6 +152,9 @@ impl FilesystemStorage {
         fs::create_dir_all(self.base_path.join("part"))
             .await
             .map_err(|e| StorageError::IoError(e.to_string()))?;
+        fs::create_dir_all(self.base_path.join("stream"))
+            .await
+            .map_err(|e| StorageError::IoError(e.to_string()))?;
         Ok(())
     }
 
@@ -129,6 +173,20 @@ impl FilesystemStorage {
             .join(format!("{}.json", message_id))
     }
 
+    /// Build path for stream file
+    fn stream_path(&self, stream_id: &str) -> PathBuf {
+        self.base_path
+            .join("stream")
+            .join(format!("{}.json", stream_id))
+    }
+
+    /// Build path for stream chunks file
+    fn stream_chunks_path(&self, stream_id: &str) -> PathBuf {
+        self.base_path
+            .join("stream")
+            .join(format!("{}.jsonl", stream_id))
+    }
+
     /// Build path for part file
     fn part_path(&self, message_id: &str, part_id: &str) -> PathBuf {
         self.base_path
@@ -182,6 +240,186 @@ impl FilesystemStorage {
         serde_json::from_slice(&data).map_err(|e| StorageError::SerializationError(e.to_string()))
     }
 
+    /// Write a part, moving its image or file data to the blob store if configured
+    async fn write_part(&self, message_id: &str, part: &MessagePart) -> Result<(), StorageError> {
+        let part_id = Self::extract_part_id(part);
+        let part_path = self.part_path(message_id, part_id);
+        let Some(blob_store) = &self.blob_store else {
+            return self.write_json(&part_path, part).await;
+        };
+
+        // Release the data of a part that is overwritten
+        if part_path.exists() {
+            self.release_blob(&part_path, message_id).await?;
+        }
+
+        let mut json = serde_json::to_value(part)
+            .map_err(|e| StorageError::SerializationError(e.to_string()))?;
+        if let Some(data) = part_data_mut(&mut json) {
+            let inline = match data["format"].as_str() {
+                Some("binary") => serde_json::from_value::<Vec<u8>>(data["data"].take())
+                    .ok()
+                    .map(|bytes| (bytes, "binary")),
+                // Only offload base64 that is restored unchanged
+                Some("base64") => data["data"].as_str().and_then(|encoded| {
+                    STANDARD
+                        .decode(encoded)
+                        .ok()
+                        .filter(|bytes| STANDARD.encode(bytes) == encoded)
+                        .map(|bytes| (bytes, "base64"))
+                }),
+                _ => None,
+            };
+
+            if let Some((bytes, encoding)) = inline {
+                let blob = blob_store
+                    .put(&bytes, &blob_owner(message_id, part_id))
+                    .await?;
+                *data = json!({
+                    "format": "blob",
+                    "hash": blob.hash,
+                    "size": blob.size,
+                    "encoding": encoding,
+                });
+            }
+        }
+
+        self.write_json(&part_path, &json).await
+    }
+
+    /// Read a part, restoring image or file data from the blob store
+    async fn read_part(&self, path: &PathBuf) -> Result<MessagePart, StorageError> {
+        let mut json: Value = self.read_json(path).await?;
+
+        if let Some(data) = part_data_mut(&mut json)
+            && data["format"] == "blob"
+        {
+            let blob_store = self.blob_store.as_ref().ok_or_else(|| {
+                StorageError::InvalidData(format!(
+                    "Part references a blob but no blob store is configured: {}",
+                    path.display()
+                ))
+            })?;
+            let hash = data["hash"].as_str().unwrap_or_default();
+            let bytes = blob_store.get(hash).await?;
+
+            *data = if data["encoding"] == "base64" {
+                json!({ "format": "base64", "data": STANDARD.encode(bytes) })
+            } else {
+                json!({ "format": "binary", "data": bytes })
+            };
+        }
+
+        serde_json::from_value(json).map_err(|e| StorageError::SerializationError(e.to_string()))
+    }
+
+    /// Release the blob referenced by a part file, if any
+    async fn release_blob(&self, part_path: &Path, message_id: &str) -> Result<(), StorageError> {
+        let Some(blob_store) = &self.blob_store else {
+            return Ok(());
+        };
+
+        let mut json: Value = self.read_json(&part_path.to_path_buf()).await?;
+        if let Some(hash) = blob_hash(&mut json)
+            && let Some(part_id) = part_path.file_stem().and_then(|s| s.to_str())
+        {
+            blob_store
+                .release(&hash, &blob_owner(message_id, part_id))
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Delete all parts of a message, releasing the blobs they reference
+    async fn delete_parts(&self, message_id: &str) -> Result<(), StorageError> {
+        let parts_dir = self.base_path.join("part").join(message_id);
+        if !parts_dir.exists() {
+            return Ok(());
+        }
+
+        if self.blob_store.is_some() {
+            let mut entries = fs::read_dir(&parts_dir)
+                .await
+                .map_err(|e| StorageError::IoError(e.to_string()))?;
+
+            while let Some(entry) = entries
+                .next_entry()
+                .await
+                .map_err(|e| StorageError::IoError(e.to_string()))?
+            {
+                if entry.path().extension().and_then(|s| s.to_str()) == Some("json") {
+                    self.release_blob(&entry.path(), message_id).await?;
+                }
+            }
+        }
+
+        fs::remove_dir_all(&parts_dir)
+            .await
+            .map_err(|e| StorageError::IoError(e.to_string()))
+    }
+
+    /// Read the stored JSON of a message
+    async fn read_message_json(
+        &self,
+        session_id: &str,
+        message_id: &str,
+    ) -> Result<serde_json::Value, StorageError> {
+        let message_path = self.message_path(session_id, message_id);
+        self.read_json(&message_path).await.map_err(|e| match e {
+            StorageError::NotFound(_) => {
+                StorageError::NotFound(format!("Message not found: {}", message_id))
+            }
+            other => other,
+        })
+    }
+
+    /// Copy all part files of a message to another message
+    async fn copy_parts(
+        &self,
+        from_message_id: &str,
+        to_message_id: &str,
+    ) -> Result<(), StorageError> {
+        let from_dir = self.base_path.join("part").join(from_message_id);
+        if !from_dir.exists() {
+            return Ok(());
+        }
+
+        let to_dir = self.base_path.join("part").join(to_message_id);
+        fs::create_dir_all(&to_dir)
+            .await
+            .map_err(|e| StorageError::IoError(e.to_string()))?;
+
+        let mut entries = fs::read_dir(&from_dir)
+            .await
+            .map_err(|e| StorageError::IoError(e.to_string()))?;
+
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| StorageError::IoError(e.to_string()))?
+        {
+            if entry.path().extension().and_then(|s| s.to_str()) == Some("json") {
+                // The copy is a new owner of the blob the part references
+                if let Some(blob_store) = &self.blob_store {
+                    let mut json: Value = self.read_json(&entry.path()).await?;
+                    if let Some(hash) = blob_hash(&mut json)
+                        && let Some(part_id) = entry.path().file_stem().and_then(|s| s.to_str())
+                    {
+                        blob_store
+                            .add_owner(&hash, &blob_owner(to_message_id, part_id))
+                            .await?;
+                    }
+                }
+
+                fs::copy(entry.path(), to_dir.join(entry.file_name()))
+                    .await
+                    .map_err(|e| StorageError::IoError(e.to_string()))?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Extract part ID from MessagePart enum
     fn extract_part_id(part: &MessagePart) -> &str {
         match part {
@@ -192,24 +430,48 @@ impl FilesystemStorage {
             MessagePart::ToolCall(p) => &p.id,
             MessagePart::ToolResult(p) => &p.id,
             MessagePart::Source(p) => &p.id,
+            MessagePart::ToolApprovalRequest(p) => &p.id,
+            MessagePart::ToolApprovalResponse(p) => &p.id,
         }
     }
 }
 
+/// Get the data of an image or file part in its JSON form
+fn part_data_mut(part: &mut Value) -> Option<&mut Value> {
+    match part.get("type")?.as_str()? {
+        "image" | "file" => part.get_mut("data"),
+        _ => None,
+    }
+}
+
+/// Get the hash of the blob a part references in its JSON form
+fn blob_hash(part: &mut Value) -> Option<String> {
+    let data = part_data_mut(part)?;
+    if data["format"] != "blob" {
+        return None;
+    }
+    data["hash"].as_str().map(String::from)
+}
+
+/// Blob owner name of a part
+fn blob_owner(message_id: &str, part_id: &str) -> String {
+    format!("{}.{}", message_id, part_id)
+}
+
 #[async_trait]
 impl Storage for FilesystemStorage {
     // ID Generation
 
     fn generate_session_id(&self) -> String {
-        id_generator::IdGenerator::generate_session_id()
+        IdGenerator::generate_session_id()
     }
 
     fn generate_message_id(&self) -> String {
-        id_generator::IdGenerator::generate_message_id()
+        IdGenerator::generate_message_id()
     }
 
     fn generate_part_id(&self) -> String {
-        id_generator::IdGenerator::generate_part_id()
+        IdGenerator::generate_part_id()
     }
 
     // Session Operations
@@ -248,7 +510,7 @@ impl Storage for FilesystemStorage {
         }
 
         // Sort by updated_at (most recent first)
-        sessions.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+        sessions.sort_by_key(|s| std::cmp::Reverse(s.updated_at));
 
         // Apply limit
         if let Some(limit) = limit {
@@ -273,13 +535,8 @@ impl Storage for FilesystemStorage {
             let message_ids = self.list_messages(session_id, None).await?;
 
             for message_id in message_ids {
-                // Delete parts
-                let parts_dir = self.base_path.join("part").join(&message_id);
-                if parts_dir.exists() {
-                    fs::remove_dir_all(&parts_dir)
-                        .await
-                        .map_err(|e| StorageError::IoError(e.to_string()))?;
-                }
+                // Delete parts, releasing their blobs
+                self.delete_parts(&message_id).await?;
             }
 
             // Delete messages directory
@@ -288,6 +545,36 @@ impl Storage for FilesystemStorage {
                 .map_err(|e| StorageError::IoError(e.to_string()))?;
         }
 
+        // Delete streams
+        let streams_dir = self.base_path.join("stream");
+        if streams_dir.exists() {
+            let mut entries = fs::read_dir(&streams_dir)
+                .await
+                .map_err(|e| StorageError::IoError(e.to_string()))?;
+
+            while let Some(entry) = entries
+                .next_entry()
+                .await
+                .map_err(|e| StorageError::IoError(e.to_string()))?
+            {
+                if entry.path().extension().and_then(|s| s.to_str()) != Some("json") {
+                    continue;
+                }
+                let stream: StoredStream = self.read_json(&entry.path()).await?;
+                if stream.session_id == session_id {
+                    let chunks_path = self.stream_chunks_path(&stream.id);
+                    if chunks_path.exists() {
+                        fs::remove_file(&chunks_path)
+                            .await
+                            .map_err(|e| StorageError::IoError(e.to_string()))?;
+                    }
+                    fs::remove_file(entry.path())
+                        .await
+                        .map_err(|e| StorageError::IoError(e.to_string()))?;
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -304,9 +591,7 @@ impl Storage for FilesystemStorage {
 
         // Store all parts
         for part in parts {
-            let part_id = Self::extract_part_id(part);
-            let part_path = self.part_path(&message.id, part_id);
-            self.write_json(&part_path, part).await?;
+            self.write_part(&message.id, part).await?;
         }
 
         Ok(())
@@ -323,9 +608,7 @@ impl Storage for FilesystemStorage {
 
         // Store all parts
         for part in parts {
-            let part_id = Self::extract_part_id(part);
-            let part_path = self.part_path(&message.id, part_id);
-            self.write_json(&part_path, part).await?;
+            self.write_part(&message.id, part).await?;
         }
 
         Ok(())
@@ -383,7 +666,7 @@ impl Storage for FilesystemStorage {
             part_files.sort();
 
             for part_file in part_files {
-                let part: MessagePart = self.read_json(&part_file).await?;
+                let part: MessagePart = self.read_part(&part_file).await?;
                 parts.push(part);
             }
         }
@@ -452,7 +735,7 @@ impl Storage for FilesystemStorage {
             part_files.sort();
 
             for part_file in part_files {
-                let part: MessagePart = self.read_json(&part_file).await?;
+                let part: MessagePart = self.read_part(&part_file).await?;
                 parts.push(part);
             }
         }
@@ -498,12 +781,219 @@ impl Storage for FilesystemStorage {
 
         Ok(message_ids)
     }
+
+    // Editing Operations
+
+    async fn delete_message(&self, session_id: &str, message_id: &str) -> Result<(), StorageError> {
+        let message_path = self.message_path(session_id, message_id);
+        if !message_path.exists() {
+            return Err(StorageError::NotFound(format!(
+                "Message not found: {}",
+                message_id
+            )));
+        }
+
+        fs::remove_file(&message_path)
+            .await
+            .map_err(|e| StorageError::IoError(e.to_string()))?;
+
+        // Delete parts
+        self.delete_parts(message_id).await
+    }
+
+    async fn update_message_parts(
+        &self,
+        session_id: &str,
+        message_id: &str,
+        parts: &[MessagePart],
+    ) -> Result<(), StorageError> {
+        let mut message = self.read_message_json(session_id, message_id).await?;
+        message["part_ids"] = parts
+            .iter()
+            .map(|part| serde_json::Value::from(Self::extract_part_id(part)))
+            .collect();
+
+        // Replace the parts
+        self.delete_parts(message_id).await?;
+        for part in parts {
+            self.write_part(message_id, part).await?;
+        }
+
+        let message_path = self.message_path(session_id, message_id);
+        self.write_json(&message_path, &message).await
+    }
+
+    // Branching Operations
+
+    async fn fork_session(
+        &self,
+        session_id: &str,
+        at_message_id: &str,
+    ) -> Result<Session, StorageError> {
+        let parent = self.get_session(session_id).await?;
+        let message_ids = self.list_messages(session_id, None).await?;
+        let end = message_ids
+            .iter()
+            .position(|id| id == at_message_id)
+            .ok_or_else(|| {
+                StorageError::NotFound(format!("Message not found: {}", at_message_id))
+            })?;
+
+        let mut session = Session::new(self.generate_session_id());
+        session.title = parent.title;
+        session.metadata = parent
+            .metadata
+            .with_parent(session_id.to_string(), at_message_id.to_string());
+
+        // Copy messages with new IDs, keeping their order
+        for message_id in &message_ids[..=end] {
+            let mut message = self.read_message_json(session_id, message_id).await?;
+            let new_message_id = self.generate_message_id();
+            message["id"] = new_message_id.clone().into();
+            message["session_id"] = session.id.clone().into();
+
+            let message_path = self.message_path(&session.id, &new_message_id);
+            self.write_json(&message_path, &message).await?;
+            self.copy_parts(message_id, &new_message_id).await?;
+        }
+
+        // Store the session last, so an interrupted fork isn't listed
+        self.store_session(&session).await?;
+
+        Ok(session)
+    }
+
+    async fn list_child_sessions(&self, session_id: &str) -> Result<Vec<Session>, StorageError> {
+        let sessions = self.list_sessions(None).await?;
+
+        Ok(sessions
+            .into_iter()
+            .filter(|session| session.metadata.parent_session_id.as_deref() == Some(session_id))
+            .collect())
+    }
+
+    // Stream Operations
+
+    async fn store_stream(&self, stream: &StoredStream) -> Result<(), StorageError> {
+        let path = self.stream_path(&stream.id);
+        self.write_json(&path, stream).await
+    }
+
+    async fn get_stream(&self, stream_id: &str) -> Result<StoredStream, StorageError> {
+        let path = self.stream_path(stream_id);
+        self.read_json(&path).await.map_err(|e| match e {
+            StorageError::NotFound(_) => {
+                StorageError::NotFound(format!("Stream not found: {}", stream_id))
+            }
+            other => other,
+        })
+    }
+
+    async fn update_stream_status(
+        &self,
+        stream_id: &str,
+        expected: StreamStatus,
+        status: StreamStatus,
+    ) -> Result<bool, StorageError> {
+        if !self.stream_path(stream_id).exists() {
+            return Err(StorageError::NotFound(format!(
+                "Stream not found: {}",
+                stream_id
+            )));
+        }
+
+        // Lock the chunks file, which is only appended to, as the stream file is
+        // replaced on write. The lock is released when the file is dropped.
+        let chunks = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.stream_chunks_path(stream_id))
+            .await
+            .map_err(|e| StorageError::IoError(e.to_string()))?
+            .into_std()
+            .await;
+        let _lock = tokio::task::spawn_blocking(move || chunks.lock().map(|()| chunks))
+            .await
+            .map_err(|e| StorageError::ProviderError(e.to_string()))?
+            .map_err(|e| StorageError::IoError(e.to_string()))?;
+
+        let stream = match self.get_stream(stream_id).await? {
+            stream if stream.status == expected => stream,
+            _ => return Ok(false),
+        };
+        self.write_json(&self.stream_path(stream_id), &stream.with_status(status))
+            .await?;
+        Ok(true)
+    }
+
+    async fn append_stream_chunks(
+        &self,
+        stream_id: &str,
+        chunks: &[serde_json::Value],
+    ) -> Result<(), StorageError> {
+        if !self.stream_path(stream_id).exists() {
+            return Err(StorageError::NotFound(format!(
+                "Stream not found: {}",
+                stream_id
+            )));
+        }
+
+        // One chunk per line, written at once so a reader never sees a partial batch
+        let mut lines = Vec::new();
+        for chunk in chunks {
+            serde_json::to_writer(&mut lines, chunk)
+                .map_err(|e| StorageError::SerializationError(e.to_string()))?;
+            lines.push(b'\n');
+        }
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.stream_chunks_path(stream_id))
+            .await
+            .map_err(|e| StorageError::IoError(e.to_string()))?;
+        file.write_all(&lines)
+            .await
+            .map_err(|e| StorageError::IoError(e.to_string()))?;
+        file.flush()
+            .await
+            .map_err(|e| StorageError::IoError(e.to_string()))
+    }
+
+    async fn get_stream_chunks(
+        &self,
+        stream_id: &str,
+        offset: usize,
+    ) -> Result<Vec<serde_json::Value>, StorageError> {
+        if !self.stream_path(stream_id).exists() {
+            return Err(StorageError::NotFound(format!(
+                "Stream not found: {}",
+                stream_id
+            )));
+        }
+
+        let data = match fs::read(self.stream_chunks_path(stream_id)).await {
+            Ok(data) => data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(StorageError::IoError(e.to_string())),
+        };
+
+        // Stop at a trailing line that is still being written
+        Ok(data
+            .split(|byte| *byte == b'\n')
+            .filter(|line| !line.is_empty())
+            .skip(offset)
+            .map_while(|line| serde_json::from_slice(line).ok())
+            .collect())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use llm_kit_storage::{MessageMetadata, Session, TextPart, UsageStats};
+    use llm_kit_storage::{
+        FileData, FilePart, ImageData, ImagePart, MessageMetadata, Session, TextPart, UsageStats,
+    };
     use tempfile::TempDir;
 
     async fn setup_storage() -> (FilesystemStorage, TempDir) {
@@ -563,6 +1053,7 @@ mod tests {
                 usage: Some(UsageStats::new(10, 20)),
                 finish_reason: Some("stop".to_string()),
                 custom: None,
+                ..Default::default()
             });
 
         storage
@@ -697,4 +1188,147 @@ mod tests {
         let message_ids = storage.list_messages(&session_id, Some(3)).await.unwrap();
         assert_eq!(message_ids.len(), 3);
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_storage_conformance() {
+        let (storage, _dir) = setup_storage().await;
+        llm_kit_storage::storage_conformance::run_all(std::sync::Arc::new(storage)).await;
+    }
+
+    async fn setup_blob_storage() -> (FilesystemStorage, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = FilesystemStorage::new(temp_dir.path())
+            .unwrap()
+            .with_blob_store(Arc::new(FilesystemBlobStore::new(
+                temp_dir.path().join("blob"),
+            )));
+        storage.initialize().await.unwrap();
+        (storage, temp_dir)
+    }
+
+    /// Store a session with one user message holding an image and a base64 file
+    async fn store_attachments(storage: &FilesystemStorage, image: &[u8]) -> (String, String) {
+        let session_id = storage.generate_session_id();
+        storage
+            .store_session(&Session::new(session_id.clone()))
+            .await
+            .unwrap();
+
+        let message_id = storage.generate_message_id();
+        let parts = vec![
+            MessagePart::Image(ImagePart::new(
+                storage.generate_part_id(),
+                "image/png".to_string(),
+                ImageData::Binary {
+                    data: image.to_vec(),
+                },
+            )),
+            MessagePart::File(FilePart::new(
+                storage.generate_part_id(),
+                "text/plain".to_string(),
+                FileData::Base64 {
+                    data: STANDARD.encode(b"notes"),
+                },
+            )),
+        ];
+        let message = UserMessage::new(
+            message_id.clone(),
+            session_id.clone(),
+            parts.iter().map(|part| part.id().to_string()).collect(),
+        );
+        storage.store_user_message(&message, &parts).await.unwrap();
+
+        (session_id, message_id)
+    }
+
+    /// Count the payload files of a blob store directory
+    fn count_blobs(path: &Path) -> usize {
+        std::fs::read_dir(path)
+            .map(|dirs| {
+                dirs.flat_map(|dir| std::fs::read_dir(dir.unwrap().path()).unwrap())
+                    .filter(|entry| entry.as_ref().unwrap().path().extension().is_none())
+                    .count()
+            })
+            .unwrap_or(0)
+    }
+
+    #[tokio::test]
+    async fn test_blob_store_offloads_part_data() {
+        let (storage, dir) = setup_blob_storage().await;
+        let image = vec![137u8; 4096];
+        let (session_id, message_id) = store_attachments(&storage, &image).await;
+
+        let (_, parts) = storage.get_message(&session_id, &message_id).await.unwrap();
+        let MessagePart::Image(image_part) = &parts[0] else {
+            panic!("Expected image part");
+        };
+        assert_eq!(image_part.data, ImageData::Binary { data: image });
+        let MessagePart::File(file_part) = &parts[1] else {
+            panic!("Expected file part");
+        };
+        assert_eq!(
+            file_part.data,
+            FileData::Base64 {
+                data: STANDARD.encode(b"notes")
+            }
+        );
+
+        // The part file only holds a reference
+        let part_json =
+            std::fs::read_to_string(storage.part_path(&message_id, &image_part.id)).unwrap();
+        assert!(part_json.contains(r#""format": "blob""#));
+        assert!(part_json.len() < 1024);
+        assert_eq!(count_blobs(&dir.path().join("blob")), 2);
+    }
+
+    #[tokio::test]
+    async fn test_blob_store_deduplicates_and_collects_on_delete() {
+        let (storage, dir) = setup_blob_storage().await;
+        let blob_dir = dir.path().join("blob");
+        let (first_session, _) = store_attachments(&storage, b"screenshot").await;
+        let (second_session, _) = store_attachments(&storage, b"screenshot").await;
+        assert_eq!(count_blobs(&blob_dir), 2);
+
+        storage.delete_session(&first_session).await.unwrap();
+        assert_eq!(count_blobs(&blob_dir), 2);
+
+        storage.delete_session(&second_session).await.unwrap();
+        assert_eq!(count_blobs(&blob_dir), 0);
+    }
+
+    #[tokio::test]
+    async fn test_forked_session_keeps_blobs() {
+        let (storage, dir) = setup_blob_storage().await;
+        let (session_id, message_id) = store_attachments(&storage, b"screenshot").await;
+
+        let fork = storage
+            .fork_session(&session_id, &message_id)
+            .await
+            .unwrap();
+        storage.delete_session(&session_id).await.unwrap();
+
+        let message_ids = storage.list_messages(&fork.id, None).await.unwrap();
+        let (_, parts) = storage
+            .get_message(&fork.id, &message_ids[0])
+            .await
+            .unwrap();
+        let MessagePart::Image(image_part) = &parts[0] else {
+            panic!("Expected image part");
+        };
+        assert_eq!(
+            image_part.data,
+            ImageData::Binary {
+                data: b"screenshot".to_vec()
+            }
+        );
+
+        storage.delete_session(&fork.id).await.unwrap();
+        assert_eq!(count_blobs(&dir.path().join("blob")), 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_storage_conformance_with_blob_store() {
+        let (storage, _dir) = setup_blob_storage().await;
+        llm_kit_storage::storage_conformance::run_all(std::sync::Arc::new(storage)).await;
+    }
 }
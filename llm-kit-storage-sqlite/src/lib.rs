@@ -0,0 +1,1082 @@
+//! # LLM Kit Storage - SQLite Provider
+//!
+//! SQLite-based storage implementation for LLM Kit Storage.
+//!
+//! This crate provides a storage provider that implements the `Storage` trait from
+//! `llm-kit-storage`. It stores sessions, messages, and parts in a single SQLite file.
+//!
+//! ## Features
+//!
+//! - **Single File**: The whole conversation history lives in one database file
+//! - **Transactional Writes**: A message and its parts are stored atomically, and
+//!   `delete_session` cascades to messages, parts and streams inside a transaction
+//! - **Schema Migrations**: The schema is versioned and upgraded by [`SqliteStorage::initialize`]
+//! - **Sortable IDs**: The same timestamp-based IDs as the filesystem provider
+//!
+//! ## Schema
+//!
+//! ```text
+//! sessions (id, updated_at, data)
+//! messages (id, session_id, role, data)
+//! parts    (id, message_id → messages.id ON DELETE CASCADE, data)
+//! streams  (id, session_id, data)
+//! stream_chunks (stream_id → streams.id ON DELETE CASCADE, seq, data)
+//! ```
+//!
+//! The `data` columns hold the JSON serialization of the stored types.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use llm_kit_storage::Storage;
+//! use llm_kit_storage_sqlite::SqliteStorage;
+//! use std::sync::Arc;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! // Open the database and apply pending migrations
+//! let storage = Arc::new(SqliteStorage::new("./ai-storage.db")?);
+//! storage.initialize().await?;
+//!
+//! // Generate a new session
+//! let session_id = storage.generate_session_id();
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## ID Format
+//!
+//! The SQLite provider generates sortable IDs:
+//!
+//! - **Session IDs**: `ses_{inverted_time}{random}` (descending order)
+//! - **Message IDs**: `msg_{time}{random}` (ascending order)
+//! - **Part IDs**: `prt_{time}{random}` (ascending order)
+//!
+//! Total length: 30 characters (4 prefix + 12 time + 14 random)
+
+mod migrations;
+
+use async_trait::async_trait;
+use llm_kit_storage::{
+    AssistantMessage, IdGenerator, MessageMetadata, MessagePart, MessageRole, Session, Storage,
+    StorageError, StoredStream, StreamStatus, UserMessage,
+};
+use rusqlite::{Connection, OptionalExtension, TransactionBehavior, params};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+/// Insert a session or update the stored one
+const UPSERT_SESSION: &str = "INSERT INTO sessions (id, updated_at, data) VALUES (?1, ?2, ?3)
+     ON CONFLICT (id) DO UPDATE SET updated_at = excluded.updated_at, data = excluded.data";
+
+/// SQLite-based storage provider
+pub struct SqliteStorage {
+    connection: Arc<Mutex<Connection>>,
+}
+
+impl SqliteStorage {
+    /// Open a SQLite storage instance backed by a database file
+    ///
+    /// The file is created if it doesn't exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the database file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be opened
+    pub fn new(path: impl AsRef<Path>) -> Result<Self, StorageError> {
+        Self::open(Connection::open(path).map_err(database_error)?)
+    }
+
+    /// Open a SQLite storage instance backed by an in-memory database
+    ///
+    /// The data is lost when the storage is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be opened
+    pub fn in_memory() -> Result<Self, StorageError> {
+        Self::open(Connection::open_in_memory().map_err(database_error)?)
+    }
+
+    /// Apply pending schema migrations (must be called before use)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a migration fails
+    pub async fn initialize(&self) -> Result<(), StorageError> {
+        self.call(migrations::migrate).await
+    }
+
+    /// Returns the schema version of the database
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the version cannot be read
+    pub async fn schema_version(&self) -> Result<usize, StorageError> {
+        self.call(|connection| migrations::current_version(connection))
+            .await
+    }
+
+    fn open(connection: Connection) -> Result<Self, StorageError> {
+        // Required for parts to be deleted together with their message
+        connection
+            .pragma_update(None, "foreign_keys", true)
+            .map_err(database_error)?;
+
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+        })
+    }
+
+    /// Run a database operation on the blocking thread pool
+    async fn call<T, F>(&self, operation: F) -> Result<T, StorageError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection) -> rusqlite::Result<T> + Send + 'static,
+    {
+        let connection = Arc::clone(&self.connection);
+
+        tokio::task::spawn_blocking(move || {
+            let mut connection = connection
+                .lock()
+                .map_err(|e| StorageError::ProviderError(e.to_string()))?;
+            operation(&mut connection).map_err(database_error)
+        })
+        .await
+        .map_err(|e| StorageError::ProviderError(e.to_string()))?
+    }
+
+    /// Store a message and its parts in one transaction
+    ///
+    /// A previously stored message with the same ID is replaced, including its parts.
+    async fn store_message(
+        &self,
+        message_id: String,
+        session_id: String,
+        role: MessageRole,
+        message: String,
+        parts: Vec<(String, String)>,
+    ) -> Result<(), StorageError> {
+        self.call(move |connection| {
+            let transaction = connection.transaction()?;
+
+            transaction.execute("DELETE FROM messages WHERE id = ?1", params![message_id])?;
+            transaction.execute(
+                "INSERT INTO messages (id, session_id, role, data) VALUES (?1, ?2, ?3, ?4)",
+                params![message_id, session_id, role_name(role), message],
+            )?;
+            for (part_id, part) in &parts {
+                transaction.execute(
+                    "INSERT INTO parts (id, message_id, data) VALUES (?1, ?2, ?3)",
+                    params![part_id, message_id, part],
+                )?;
+            }
+
+            transaction.commit()
+        })
+        .await
+    }
+
+    /// Serialize message parts together with their IDs
+    fn serialize_parts(parts: &[MessagePart]) -> Result<Vec<(String, String)>, StorageError> {
+        parts
+            .iter()
+            .map(|part| Ok((Self::extract_part_id(part).to_string(), to_json(part)?)))
+            .collect()
+    }
+
+    /// Extract part ID from MessagePart enum
+    fn extract_part_id(part: &MessagePart) -> &str {
+        match part {
+            MessagePart::Text(p) => &p.id,
+            MessagePart::Image(p) => &p.id,
+            MessagePart::File(p) => &p.id,
+            MessagePart::Reasoning(p) => &p.id,
+            MessagePart::ToolCall(p) => &p.id,
+            MessagePart::ToolResult(p) => &p.id,
+            MessagePart::Source(p) => &p.id,
+            MessagePart::ToolApprovalRequest(p) => &p.id,
+            MessagePart::ToolApprovalResponse(p) => &p.id,
+        }
+    }
+}
+
+/// Convert a database error into a storage error
+fn database_error(error: rusqlite::Error) -> StorageError {
+    StorageError::ProviderError(error.to_string())
+}
+
+/// Serialize a value to JSON
+fn to_json<T: serde::Serialize>(value: &T) -> Result<String, StorageError> {
+    serde_json::to_string(value).map_err(|e| StorageError::SerializationError(e.to_string()))
+}
+
+/// Deserialize a value from JSON
+fn from_json<T: serde::de::DeserializeOwned>(data: &str) -> Result<T, StorageError> {
+    serde_json::from_str(data).map_err(|e| StorageError::SerializationError(e.to_string()))
+}
+
+/// Name of a role as stored in the `role` column
+fn role_name(role: MessageRole) -> &'static str {
+    match role {
+        MessageRole::User => "user",
+        MessageRole::Assistant => "assistant",
+        MessageRole::System => "system",
+    }
+}
+
+/// Parse a role stored in the `role` column
+fn parse_role(role: &str) -> Result<MessageRole, StorageError> {
+    match role {
+        "user" => Ok(MessageRole::User),
+        "assistant" => Ok(MessageRole::Assistant),
+        "system" => Ok(MessageRole::System),
+        _ => Err(StorageError::SerializationError(format!(
+            "Invalid role: {}",
+            role
+        ))),
+    }
+}
+
+/// Convert an optional limit to a SQL `LIMIT` value (-1 means no limit)
+fn sql_limit(limit: Option<usize>) -> i64 {
+    limit.map_or(-1, |limit| i64::try_from(limit).unwrap_or(i64::MAX))
+}
+
+#[async_trait]
+impl Storage for SqliteStorage {
+    // ID Generation
+
+    fn generate_session_id(&self) -> String {
+        IdGenerator::generate_session_id()
+    }
+
+    fn generate_message_id(&self) -> String {
+        IdGenerator::generate_message_id()
+    }
+
+    fn generate_part_id(&self) -> String {
+        IdGenerator::generate_part_id()
+    }
+
+    // Session Operations
+
+    async fn store_session(&self, session: &Session) -> Result<(), StorageError> {
+        let id = session.id.clone();
+        let updated_at = session.updated_at.timestamp_micros();
+        let data = to_json(session)?;
+
+        self.call(move |connection| {
+            connection.execute(UPSERT_SESSION, params![id, updated_at, data])?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn get_session(&self, session_id: &str) -> Result<Session, StorageError> {
+        let id = session_id.to_string();
+        let data = self
+            .call(move |connection| {
+                connection
+                    .query_row(
+                        "SELECT data FROM sessions WHERE id = ?1",
+                        params![id],
+                        |row| row.get::<_, String>(0),
+                    )
+                    .optional()
+            })
+            .await?
+            .ok_or_else(|| StorageError::NotFound(format!("Session not found: {}", session_id)))?;
+
+        from_json(&data)
+    }
+
+    async fn list_sessions(&self, limit: Option<usize>) -> Result<Vec<Session>, StorageError> {
+        let limit = sql_limit(limit);
+        let rows = self
+            .call(move |connection| {
+                // Most recent first; session IDs are descending, so newer sessions win ties
+                let mut statement = connection.prepare(
+                    "SELECT data FROM sessions ORDER BY updated_at DESC, id ASC LIMIT ?1",
+                )?;
+                statement
+                    .query_map(params![limit], |row| row.get::<_, String>(0))?
+                    .collect::<rusqlite::Result<Vec<_>>>()
+            })
+            .await?;
+
+        rows.iter().map(|data| from_json(data)).collect()
+    }
+
+    async fn delete_session(&self, session_id: &str) -> Result<(), StorageError> {
+        let id = session_id.to_string();
+
+        self.call(move |connection| {
+            let transaction = connection.transaction()?;
+
+            // Parts are deleted through the foreign key cascade on messages
+            transaction.execute("DELETE FROM messages WHERE session_id = ?1", params![id])?;
+            transaction.execute("DELETE FROM sessions WHERE id = ?1", params![id])?;
+            // Chunks are deleted through the foreign key cascade on streams
+            transaction.execute("DELETE FROM streams WHERE session_id = ?1", params![id])?;
+
+            transaction.commit()
+        })
+        .await
+    }
+
+    // Message Operations
+
+    async fn store_user_message(
+        &self,
+        message: &UserMessage,
+        parts: &[MessagePart],
+    ) -> Result<(), StorageError> {
+        self.store_message(
+            message.id.clone(),
+            message.session_id.clone(),
+            MessageRole::User,
+            to_json(message)?,
+            Self::serialize_parts(parts)?,
+        )
+        .await
+    }
+
+    async fn store_assistant_message(
+        &self,
+        message: &AssistantMessage,
+        parts: &[MessagePart],
+    ) -> Result<(), StorageError> {
+        self.store_message(
+            message.id.clone(),
+            message.session_id.clone(),
+            MessageRole::Assistant,
+            to_json(message)?,
+            Self::serialize_parts(parts)?,
+        )
+        .await
+    }
+
+    async fn get_message(
+        &self,
+        session_id: &str,
+        message_id: &str,
+    ) -> Result<(MessageRole, Vec<MessagePart>), StorageError> {
+        let (role, parts, _) = self
+            .get_message_with_metadata(session_id, message_id)
+            .await?;
+        Ok((role, parts))
+    }
+
+    async fn get_message_with_metadata(
+        &self,
+        session_id: &str,
+        message_id: &str,
+    ) -> Result<(MessageRole, Vec<MessagePart>, Option<MessageMetadata>), StorageError> {
+        let session = session_id.to_string();
+        let id = message_id.to_string();
+
+        let (role, data, parts) = self
+            .call(move |connection| {
+                let message = connection
+                    .query_row(
+                        "SELECT role, data FROM messages WHERE id = ?1 AND session_id = ?2",
+                        params![id, session],
+                        |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)),
+                    )
+                    .optional()?;
+
+                let Some((role, data)) = message else {
+                    return Ok(None);
+                };
+
+                // Part IDs are chronological, so ordering by ID keeps the original order
+                let mut statement = connection
+                    .prepare("SELECT data FROM parts WHERE message_id = ?1 ORDER BY id")?;
+                let parts = statement
+                    .query_map(params![id], |row| row.get::<_, String>(0))?
+                    .collect::<rusqlite::Result<Vec<_>>>()?;
+
+                Ok(Some((role, data, parts)))
+            })
+            .await?
+            .ok_or_else(|| StorageError::NotFound(format!("Message not found: {}", message_id)))?;
+
+        let role = parse_role(&role)?;
+
+        // Extract metadata for assistant messages
+        let metadata = if role == MessageRole::Assistant {
+            serde_json::from_str::<AssistantMessage>(&data)
+                .ok()
+                .map(|msg| msg.metadata)
+        } else {
+            None
+        };
+
+        let parts = parts
+            .iter()
+            .map(|part| from_json(part))
+            .collect::<Result<Vec<MessagePart>, _>>()?;
+
+        Ok((role, parts, metadata))
+    }
+
+    async fn list_messages(
+        &self,
+        session_id: &str,
+        limit: Option<usize>,
+    ) -> Result<Vec<String>, StorageError> {
+        let session = session_id.to_string();
+        let limit = sql_limit(limit);
+
+        // Sort by ID (chronological due to timestamp-based IDs)
+        self.call(move |connection| {
+            let mut statement = connection
+                .prepare("SELECT id FROM messages WHERE session_id = ?1 ORDER BY id LIMIT ?2")?;
+            statement
+                .query_map(params![session, limit], |row| row.get::<_, String>(0))?
+                .collect()
+        })
+        .await
+    }
+
+    // Editing Operations
+
+    async fn delete_message(&self, session_id: &str, message_id: &str) -> Result<(), StorageError> {
+        let session = session_id.to_string();
+        let id = message_id.to_string();
+
+        // Parts are deleted through the foreign key cascade on messages
+        let deleted = self
+            .call(move |connection| {
+                connection.execute(
+                    "DELETE FROM messages WHERE id = ?1 AND session_id = ?2",
+                    params![id, session],
+                )
+            })
+            .await?;
+
+        if deleted == 0 {
+            return Err(StorageError::NotFound(format!(
+                "Message not found: {}",
+                message_id
+            )));
+        }
+        Ok(())
+    }
+
+    async fn update_message_parts(
+        &self,
+        session_id: &str,
+        message_id: &str,
+        parts: &[MessagePart],
+    ) -> Result<(), StorageError> {
+        let session = session_id.to_string();
+        let id = message_id.to_string();
+        let part_ids = to_json(&parts.iter().map(Self::extract_part_id).collect::<Vec<_>>())?;
+        let parts = Self::serialize_parts(parts)?;
+
+        let updated = self
+            .call(move |connection| {
+                let transaction = connection.transaction()?;
+
+                let updated = transaction.execute(
+                    "UPDATE messages SET data = json_set(data, '$.part_ids', json(?3))
+                     WHERE id = ?1 AND session_id = ?2",
+                    params![id, session, part_ids],
+                )?;
+                if updated == 0 {
+                    return Ok(false);
+                }
+
+                transaction.execute("DELETE FROM parts WHERE message_id = ?1", params![id])?;
+                for (part_id, part) in &parts {
+                    transaction.execute(
+                        "INSERT INTO parts (id, message_id, data) VALUES (?1, ?2, ?3)",
+                        params![part_id, id, part],
+                    )?;
+                }
+
+                transaction.commit()?;
+                Ok(true)
+            })
+            .await?;
+
+        if !updated {
+            return Err(StorageError::NotFound(format!(
+                "Message not found: {}",
+                message_id
+            )));
+        }
+        Ok(())
+    }
+
+    // Branching Operations
+
+    async fn fork_session(
+        &self,
+        session_id: &str,
+        at_message_id: &str,
+    ) -> Result<Session, StorageError> {
+        let parent = self.get_session(session_id).await?;
+
+        let mut session = Session::new(self.generate_session_id());
+        session.title = parent.title;
+        session.metadata = parent
+            .metadata
+            .with_parent(session_id.to_string(), at_message_id.to_string());
+
+        let parent_id = session_id.to_string();
+        let at_id = at_message_id.to_string();
+        let id = session.id.clone();
+        let updated_at = session.updated_at.timestamp_micros();
+        let data = to_json(&session)?;
+
+        let forked = self
+            .call(move |connection| {
+                let transaction = connection.transaction()?;
+
+                let message_ids = {
+                    let mut statement = transaction.prepare(
+                        "SELECT id FROM messages WHERE session_id = ?1 AND id <= ?2 ORDER BY id",
+                    )?;
+                    statement
+                        .query_map(params![parent_id, at_id], |row| row.get::<_, String>(0))?
+                        .collect::<rusqlite::Result<Vec<_>>>()?
+                };
+                if message_ids.last() != Some(&at_id) {
+                    return Ok(false);
+                }
+
+                // Copy messages with new IDs, keeping their order
+                for message_id in &message_ids {
+                    let new_message_id = IdGenerator::generate_message_id();
+                    transaction.execute(
+                        "INSERT INTO messages (id, session_id, role, data)
+                         SELECT ?1, ?2, role, json_set(data, '$.id', ?1, '$.session_id', ?2)
+                         FROM messages WHERE id = ?3",
+                        params![new_message_id, id, message_id],
+                    )?;
+                    transaction.execute(
+                        "INSERT INTO parts (id, message_id, data)
+                         SELECT id, ?1, data FROM parts WHERE message_id = ?2",
+                        params![new_message_id, message_id],
+                    )?;
+                }
+
+                transaction.execute(UPSERT_SESSION, params![id, updated_at, data])?;
+                transaction.commit()?;
+                Ok(true)
+            })
+            .await?;
+
+        if !forked {
+            return Err(StorageError::NotFound(format!(
+                "Message not found: {}",
+                at_message_id
+            )));
+        }
+        Ok(session)
+    }
+
+    async fn list_child_sessions(&self, session_id: &str) -> Result<Vec<Session>, StorageError> {
+        let parent_id = session_id.to_string();
+        let rows = self
+            .call(move |connection| {
+                let mut statement = connection.prepare(
+                    "SELECT data FROM sessions
+                     WHERE json_extract(data, '$.metadata.parent_session_id') = ?1
+                     ORDER BY updated_at DESC, id ASC",
+                )?;
+                statement
+                    .query_map(params![parent_id], |row| row.get::<_, String>(0))?
+                    .collect::<rusqlite::Result<Vec<_>>>()
+            })
+            .await?;
+
+        rows.iter().map(|data| from_json(data)).collect()
+    }
+
+    // Stream Operations
+
+    async fn store_stream(&self, stream: &StoredStream) -> Result<(), StorageError> {
+        let id = stream.id.clone();
+        let session_id = stream.session_id.clone();
+        let data = to_json(stream)?;
+
+        // An upsert rather than a replace, which would cascade to the chunks
+        self.call(move |connection| {
+            connection.execute(
+                "INSERT INTO streams (id, session_id, data) VALUES (?1, ?2, ?3)
+                 ON CONFLICT (id) DO UPDATE
+                 SET session_id = excluded.session_id, data = excluded.data",
+                params![id, session_id, data],
+            )
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn get_stream(&self, stream_id: &str) -> Result<StoredStream, StorageError> {
+        let id = stream_id.to_string();
+        let data = self
+            .call(move |connection| {
+                connection
+                    .query_row(
+                        "SELECT data FROM streams WHERE id = ?1",
+                        params![id],
+                        |row| row.get::<_, String>(0),
+                    )
+                    .optional()
+            })
+            .await?
+            .ok_or_else(|| StorageError::NotFound(format!("Stream not found: {}", stream_id)))?;
+
+        from_json(&data)
+    }
+
+    async fn update_stream_status(
+        &self,
+        stream_id: &str,
+        expected: StreamStatus,
+        status: StreamStatus,
+    ) -> Result<bool, StorageError> {
+        let id = stream_id.to_string();
+
+        // An immediate transaction, so other connections can't update in between
+        self.call(move |connection| {
+            let transaction =
+                connection.transaction_with_behavior(TransactionBehavior::Immediate)?;
+
+            let Some(data) = transaction
+                .query_row(
+                    "SELECT data FROM streams WHERE id = ?1",
+                    params![id],
+                    |row| row.get::<_, String>(0),
+                )
+                .optional()?
+            else {
+                return Ok(Err(StorageError::NotFound(format!(
+                    "Stream not found: {}",
+                    id
+                ))));
+            };
+            let stream = match from_json::<StoredStream>(&data) {
+                Ok(stream) if stream.status == expected => stream,
+                Ok(_) => return Ok(Ok(false)),
+                Err(e) => return Ok(Err(e)),
+            };
+            let data = match to_json(&stream.with_status(status)) {
+                Ok(data) => data,
+                Err(e) => return Ok(Err(e)),
+            };
+
+            transaction.execute(
+                "UPDATE streams SET data = ?2 WHERE id = ?1",
+                params![id, data],
+            )?;
+            transaction.commit()?;
+            Ok(Ok(true))
+        })
+        .await?
+    }
+
+    async fn append_stream_chunks(
+        &self,
+        stream_id: &str,
+        chunks: &[serde_json::Value],
+    ) -> Result<(), StorageError> {
+        let id = stream_id.to_string();
+        let chunks = chunks.iter().map(to_json).collect::<Result<Vec<_>, _>>()?;
+
+        let appended = self
+            .call(move |connection| {
+                let transaction = connection.transaction()?;
+
+                let exists = transaction
+                    .query_row("SELECT 1 FROM streams WHERE id = ?1", params![id], |_| {
+                        Ok(())
+                    })
+                    .optional()?
+                    .is_some();
+                if !exists {
+                    return Ok(false);
+                }
+
+                let next_seq: i64 = transaction.query_row(
+                    "SELECT COALESCE(MAX(seq) + 1, 0) FROM stream_chunks WHERE stream_id = ?1",
+                    params![id],
+                    |row| row.get(0),
+                )?;
+                for (seq, chunk) in (next_seq..).zip(&chunks) {
+                    transaction.execute(
+                        "INSERT INTO stream_chunks (stream_id, seq, data) VALUES (?1, ?2, ?3)",
+                        params![id, seq, chunk],
+                    )?;
+                }
+
+                transaction.commit()?;
+                Ok(true)
+            })
+            .await?;
+
+        if !appended {
+            return Err(StorageError::NotFound(format!(
+                "Stream not found: {}",
+                stream_id
+            )));
+        }
+        Ok(())
+    }
+
+    async fn get_stream_chunks(
+        &self,
+        stream_id: &str,
+        offset: usize,
+    ) -> Result<Vec<serde_json::Value>, StorageError> {
+        let id = stream_id.to_string();
+        let offset = i64::try_from(offset).unwrap_or(i64::MAX);
+
+        let rows = self
+            .call(move |connection| {
+                let exists = connection
+                    .query_row("SELECT 1 FROM streams WHERE id = ?1", params![id], |_| {
+                        Ok(())
+                    })
+                    .optional()?
+                    .is_some();
+                if !exists {
+                    return Ok(None);
+                }
+
+                let mut statement = connection.prepare(
+                    "SELECT data FROM stream_chunks WHERE stream_id = ?1
+                     ORDER BY seq LIMIT -1 OFFSET ?2",
+                )?;
+                statement
+                    .query_map(params![id, offset], |row| row.get::<_, String>(0))?
+                    .collect::<rusqlite::Result<Vec<_>>>()
+                    .map(Some)
+            })
+            .await?
+            .ok_or_else(|| StorageError::NotFound(format!("Stream not found: {}", stream_id)))?;
+
+        rows.iter().map(|data| from_json(data)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use llm_kit_storage::{MessageMetadata, Session, TextPart, UsageStats};
+    use tempfile::TempDir;
+
+    async fn setup_storage() -> SqliteStorage {
+        let storage = SqliteStorage::in_memory().unwrap();
+        storage.initialize().await.unwrap();
+        storage
+    }
+
+    async fn count_parts(storage: &SqliteStorage) -> i64 {
+        storage
+            .call(|connection| {
+                connection.query_row("SELECT COUNT(*) FROM parts", [], |row| row.get(0))
+            })
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_store_and_get_session() {
+        let storage = setup_storage().await;
+
+        let session_id = storage.generate_session_id();
+        let session = Session::new(session_id.clone()).with_title("Test Session".to_string());
+
+        storage.store_session(&session).await.unwrap();
+        let retrieved = storage.get_session(&session_id).await.unwrap();
+
+        assert_eq!(session.id, retrieved.id);
+        assert_eq!(session.title, retrieved.title);
+    }
+
+    #[tokio::test]
+    async fn test_store_session_updates_existing() {
+        let storage = setup_storage().await;
+
+        let session_id = storage.generate_session_id();
+        let session = Session::new(session_id.clone()).with_title("Draft".to_string());
+        storage.store_session(&session).await.unwrap();
+
+        let session = session.with_title("Final".to_string());
+        storage.store_session(&session).await.unwrap();
+
+        let retrieved = storage.get_session(&session_id).await.unwrap();
+        assert_eq!(retrieved.title, Some("Final".to_string()));
+        assert_eq!(storage.list_sessions(None).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_store_and_get_user_message() {
+        let storage = setup_storage().await;
+
+        let session_id = storage.generate_session_id();
+        let message_id = storage.generate_message_id();
+        let part_id = storage.generate_part_id();
+
+        let text_part = TextPart::new(part_id.clone(), "Hello".to_string());
+        let parts = vec![MessagePart::Text(text_part)];
+        let message = UserMessage::new(message_id.clone(), session_id.clone(), vec![part_id]);
+
+        storage.store_user_message(&message, &parts).await.unwrap();
+        let (role, retrieved_parts) = storage.get_message(&session_id, &message_id).await.unwrap();
+
+        assert_eq!(role, MessageRole::User);
+        assert_eq!(parts, retrieved_parts);
+    }
+
+    #[tokio::test]
+    async fn test_store_and_get_assistant_message() {
+        let storage = setup_storage().await;
+
+        let session_id = storage.generate_session_id();
+        let message_id = storage.generate_message_id();
+        let part_id = storage.generate_part_id();
+
+        let text_part = TextPart::new(part_id.clone(), "Hello from AI".to_string());
+        let parts = vec![MessagePart::Text(text_part)];
+        let metadata = MessageMetadata {
+            model_id: Some("gpt-4".to_string()),
+            provider: Some("openai".to_string()),
+            usage: Some(UsageStats::new(10, 20)),
+            finish_reason: Some("stop".to_string()),
+            custom: None,
+            ..Default::default()
+        };
+        let message = AssistantMessage::new(message_id.clone(), session_id.clone(), vec![part_id])
+            .with_metadata(metadata.clone());
+
+        storage
+            .store_assistant_message(&message, &parts)
+            .await
+            .unwrap();
+        let (role, retrieved_parts, retrieved_metadata) = storage
+            .get_message_with_metadata(&session_id, &message_id)
+            .await
+            .unwrap();
+
+        assert_eq!(role, MessageRole::Assistant);
+        assert_eq!(parts, retrieved_parts);
+        assert_eq!(retrieved_metadata, Some(metadata));
+    }
+
+    #[tokio::test]
+    async fn test_parts_keep_their_order() {
+        let storage = setup_storage().await;
+
+        let session_id = storage.generate_session_id();
+        let message_id = storage.generate_message_id();
+        let parts: Vec<MessagePart> = (0..5)
+            .map(|i| {
+                MessagePart::Text(TextPart::new(
+                    storage.generate_part_id(),
+                    format!("Part {}", i),
+                ))
+            })
+            .collect();
+        let part_ids = parts
+            .iter()
+            .map(|part| SqliteStorage::extract_part_id(part).to_string())
+            .collect();
+        let message = UserMessage::new(message_id.clone(), session_id.clone(), part_ids);
+
+        storage.store_user_message(&message, &parts).await.unwrap();
+        let (_, retrieved_parts) = storage.get_message(&session_id, &message_id).await.unwrap();
+
+        assert_eq!(parts, retrieved_parts);
+    }
+
+    #[tokio::test]
+    async fn test_store_message_is_atomic() {
+        let storage = setup_storage().await;
+
+        let session_id = storage.generate_session_id();
+        let message_id = storage.generate_message_id();
+        let part_id = storage.generate_part_id();
+
+        // Two parts with the same ID violate the primary key of the parts table
+        let parts = vec![
+            MessagePart::Text(TextPart::new(part_id.clone(), "First".to_string())),
+            MessagePart::Text(TextPart::new(part_id.clone(), "Second".to_string())),
+        ];
+        let message = UserMessage::new(message_id.clone(), session_id.clone(), vec![part_id]);
+
+        assert!(storage.store_user_message(&message, &parts).await.is_err());
+        assert!(matches!(
+            storage.get_message(&session_id, &message_id).await,
+            Err(StorageError::NotFound(_))
+        ));
+        assert_eq!(count_parts(&storage).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_list_sessions_sorted() {
+        let storage = setup_storage().await;
+
+        // Create multiple sessions
+        let session1_id = storage.generate_session_id();
+        let session1 = Session::new(session1_id).with_title("Session 1".to_string());
+        storage.store_session(&session1).await.unwrap();
+
+        // Sleep to ensure different timestamps
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+
+        let session2_id = storage.generate_session_id();
+        let session2 = Session::new(session2_id).with_title("Session 2".to_string());
+        storage.store_session(&session2).await.unwrap();
+
+        let sessions = storage.list_sessions(None).await.unwrap();
+        assert_eq!(sessions.len(), 2);
+        // Most recent first (session2)
+        assert_eq!(sessions[0].title, Some("Session 2".to_string()));
+        assert_eq!(sessions[1].title, Some("Session 1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_list_messages_sorted() {
+        let storage = setup_storage().await;
+
+        let session_id = storage.generate_session_id();
+
+        // Create multiple messages
+        for i in 0..3 {
+            let message_id = storage.generate_message_id();
+            let part_id = storage.generate_part_id();
+            let text_part = TextPart::new(part_id.clone(), format!("Message {}", i));
+            let parts = vec![MessagePart::Text(text_part)];
+            let message = UserMessage::new(message_id, session_id.clone(), vec![part_id]);
+            storage.store_user_message(&message, &parts).await.unwrap();
+        }
+
+        let message_ids = storage.list_messages(&session_id, None).await.unwrap();
+        assert_eq!(message_ids.len(), 3);
+        // Should be in chronological order (ascending)
+        assert!(message_ids[0] < message_ids[1]);
+        assert!(message_ids[1] < message_ids[2]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_session_cascades() {
+        let storage = setup_storage().await;
+
+        let session_id = storage.generate_session_id();
+        let session = Session::new(session_id.clone());
+        storage.store_session(&session).await.unwrap();
+
+        // Add a message
+        let message_id = storage.generate_message_id();
+        let part_id = storage.generate_part_id();
+        let text_part = TextPart::new(part_id.clone(), "Test".to_string());
+        let parts = vec![MessagePart::Text(text_part)];
+        let message = UserMessage::new(message_id.clone(), session_id.clone(), vec![part_id]);
+        storage.store_user_message(&message, &parts).await.unwrap();
+
+        // Delete session
+        storage.delete_session(&session_id).await.unwrap();
+
+        // Verify session is gone
+        assert!(storage.get_session(&session_id).await.is_err());
+
+        // Verify messages and parts are gone
+        let messages = storage.list_messages(&session_id, None).await.unwrap();
+        assert_eq!(messages.len(), 0);
+        assert_eq!(count_parts(&storage).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_get_nonexistent_session() {
+        let storage = setup_storage().await;
+        let result = storage.get_session("nonexistent").await;
+        assert!(matches!(result, Err(StorageError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_nonexistent_message() {
+        let storage = setup_storage().await;
+        let result = storage.get_message("session-123", "message-456").await;
+        assert!(matches!(result, Err(StorageError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_list_sessions_with_limit() {
+        let storage = setup_storage().await;
+
+        // Create 5 sessions
+        for i in 0..5 {
+            let session_id = storage.generate_session_id();
+            let session = Session::new(session_id).with_title(format!("Session {}", i));
+            storage.store_session(&session).await.unwrap();
+            tokio::time::sleep(tokio::time::Duration::from_millis(5)).await;
+        }
+
+        let sessions = storage.list_sessions(Some(3)).await.unwrap();
+        assert_eq!(sessions.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_list_messages_with_limit() {
+        let storage = setup_storage().await;
+
+        let session_id = storage.generate_session_id();
+
+        // Create 5 messages
+        for i in 0..5 {
+            let message_id = storage.generate_message_id();
+            let part_id = storage.generate_part_id();
+            let text_part = TextPart::new(part_id.clone(), format!("Message {}", i));
+            let parts = vec![MessagePart::Text(text_part)];
+            let message = UserMessage::new(message_id, session_id.clone(), vec![part_id]);
+            storage.store_user_message(&message, &parts).await.unwrap();
+        }
+
+        let message_ids = storage.list_messages(&session_id, Some(3)).await.unwrap();
+        assert_eq!(message_ids.len(), 3);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_storage_conformance() {
+        let dir = TempDir::new().unwrap();
+        let storage = SqliteStorage::new(dir.path().join("storage.db")).unwrap();
+        storage.initialize().await.unwrap();
+        llm_kit_storage::storage_conformance::run_all(Arc::new(storage)).await;
+    }
+
+    #[tokio::test]
+    async fn test_data_persists_across_connections() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("storage.db");
+
+        let session_id = {
+            let storage = SqliteStorage::new(&path).unwrap();
+            storage.initialize().await.unwrap();
+
+            let session_id = storage.generate_session_id();
+            storage
+                .store_session(&Session::new(session_id.clone()))
+                .await
+                .unwrap();
+            session_id
+        };
+
+        let storage = SqliteStorage::new(&path).unwrap();
+        storage.initialize().await.unwrap();
+
+        assert_eq!(
+            storage.schema_version().await.unwrap(),
+            migrations::latest_version()
+        );
+        assert!(storage.get_session(&session_id).await.is_ok());
+    }
+}
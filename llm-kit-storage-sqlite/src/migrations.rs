@@ -0,0 +1,107 @@
+use rusqlite::Connection;
+
+/// Schema migrations, applied in order.
+///
+/// The schema version is tracked with SQLite's `user_version` pragma: a database at
+/// version `n` has the first `n` migrations applied. Append new migrations to the end
+/// of this list and never edit a migration that has been released.
+const MIGRATIONS: &[&str] = &[
+    // 1: sessions, messages and parts
+    r#"
+    CREATE TABLE sessions (
+        id TEXT PRIMARY KEY NOT NULL,
+        updated_at INTEGER NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE INDEX sessions_updated_at ON sessions (updated_at DESC);
+
+    CREATE TABLE messages (
+        id TEXT PRIMARY KEY NOT NULL,
+        session_id TEXT NOT NULL,
+        role TEXT NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE INDEX messages_session_id ON messages (session_id, id);
+
+    CREATE TABLE parts (
+        id TEXT NOT NULL,
+        message_id TEXT NOT NULL REFERENCES messages (id) ON DELETE CASCADE,
+        data TEXT NOT NULL,
+        PRIMARY KEY (message_id, id)
+    );
+    "#,
+    // 2: lookup of forked sessions by parent
+    r#"
+    CREATE INDEX sessions_parent_session_id
+        ON sessions (json_extract(data, '$.metadata.parent_session_id'));
+    "#,
+    // 3: resumable streams and their chunks
+    r#"
+    CREATE TABLE streams (
+        id TEXT PRIMARY KEY NOT NULL,
+        session_id TEXT NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE INDEX streams_session_id ON streams (session_id);
+
+    CREATE TABLE stream_chunks (
+        stream_id TEXT NOT NULL REFERENCES streams (id) ON DELETE CASCADE,
+        seq INTEGER NOT NULL,
+        data TEXT NOT NULL,
+        PRIMARY KEY (stream_id, seq)
+    );
+    "#,
+];
+
+/// Returns the schema version of a fully migrated database.
+#[cfg(test)]
+pub(crate) fn latest_version() -> usize {
+    MIGRATIONS.len()
+}
+
+/// Returns the current schema version of the database.
+pub(crate) fn current_version(connection: &Connection) -> rusqlite::Result<usize> {
+    connection.query_row("PRAGMA user_version", [], |row| row.get(0))
+}
+
+/// Applies all pending migrations.
+///
+/// Each migration runs in its own transaction together with the version bump, so an
+/// interrupted migration leaves the database at the previous version.
+pub(crate) fn migrate(connection: &mut Connection) -> rusqlite::Result<()> {
+    let version = current_version(connection)?;
+
+    for (index, migration) in MIGRATIONS.iter().enumerate().skip(version) {
+        let transaction = connection.transaction()?;
+        transaction.execute_batch(migration)?;
+        transaction.pragma_update(None, "user_version", index + 1)?;
+        transaction.commit()?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate_fresh_database() {
+        let mut connection = Connection::open_in_memory().unwrap();
+        assert_eq!(current_version(&connection).unwrap(), 0);
+
+        migrate(&mut connection).unwrap();
+
+        assert_eq!(current_version(&connection).unwrap(), latest_version());
+    }
+
+    #[test]
+    fn test_migrate_is_idempotent() {
+        let mut connection = Connection::open_in_memory().unwrap();
+
+        migrate(&mut connection).unwrap();
+        migrate(&mut connection).unwrap();
+
+        assert_eq!(current_version(&connection).unwrap(), latest_version());
+    }
+}
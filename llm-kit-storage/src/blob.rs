@@ -0,0 +1,86 @@
+use crate::StorageError;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Reference to a payload in a [`BlobStore`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BlobRef {
+    /// Content hash of the payload (see [`content_hash`])
+    pub hash: String,
+
+    /// Size of the payload in bytes
+    pub size: u64,
+}
+
+/// Content-addressed store for large binary payloads, such as image and file data.
+///
+/// Payloads are keyed by their [`content_hash`], so identical payloads are stored
+/// once. Every payload keeps a set of owners, usually the parts referencing it; a
+/// payload is deleted when its last owner releases it.
+///
+/// Storage implementations use a blob store to keep binary data out of their part
+/// records, which then hold only a [`BlobRef`].
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    /// Store a payload and add `owner` to its owners.
+    ///
+    /// Storing a payload that already exists only adds the owner.
+    async fn put(&self, data: &[u8], owner: &str) -> Result<BlobRef, StorageError>;
+
+    /// Add `owner` to the owners of an existing payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::NotFound` if the payload doesn't exist.
+    async fn add_owner(&self, hash: &str, owner: &str) -> Result<(), StorageError>;
+
+    /// Read a payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::NotFound` if the payload doesn't exist.
+    async fn get(&self, hash: &str) -> Result<Vec<u8>, StorageError>;
+
+    /// Remove `owner` from the owners of a payload, deleting the payload if it has
+    /// no owners left.
+    ///
+    /// Releasing a payload or owner that doesn't exist is not an error.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the payload was deleted.
+    async fn release(&self, hash: &str, owner: &str) -> Result<bool, StorageError>;
+}
+
+/// Content hash of a payload: the lowercase hex SHA-256 digest.
+pub fn content_hash(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Check that a string is a hash produced by [`content_hash`].
+pub fn is_content_hash(hash: &str) -> bool {
+    hash.len() == 64
+        && hash
+            .bytes()
+            .all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(&byte))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_hash() {
+        assert_eq!(
+            content_hash(b"hello"),
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+        assert!(is_content_hash(&content_hash(b"")));
+        assert!(!is_content_hash("../../etc/passwd"));
+        assert!(!is_content_hash(&content_hash(b"hello").to_uppercase()));
+    }
+}
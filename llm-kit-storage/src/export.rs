@@ -0,0 +1,319 @@
+/// Conversion of sessions to and from OpenAI-style chat messages.
+pub mod openai;
+
+pub use openai::{export_openai_jsonl, import_openai_jsonl};
+
+use crate::{
+    AssistantMessage, MessageMetadata, MessagePart, MessageRole, Session, Storage, StorageError,
+    UserMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Value of [`SessionExport::format`] identifying a session export document.
+pub const SESSION_EXPORT_FORMAT: &str = "llm-kit-session";
+
+/// Current version of the session export document.
+pub const SESSION_EXPORT_VERSION: u32 = 1;
+
+/// A whole session as a self-describing, portable document.
+///
+/// Contains the session, its messages in chronological order and all their parts,
+/// including inline image and file data. Created by [`export_session`] and restored
+/// into any [`Storage`] by [`import_session`].
+///
+/// # Example
+///
+/// ```no_run
+/// use llm_kit_storage::export::{SessionExport, export_session, import_session};
+/// # use llm_kit_storage::Storage;
+/// # async fn example(source: &dyn Storage, target: &dyn Storage) -> Result<(), Box<dyn std::error::Error>> {
+///
+/// let json = export_session(source, "ses_123").await?.to_json()?;
+///
+/// let export = SessionExport::from_json(&json)?;
+/// let session = import_session(target, &export).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SessionExport {
+    /// Document format, always [`SESSION_EXPORT_FORMAT`]
+    pub format: String,
+
+    /// Document version
+    pub version: u32,
+
+    /// When the session was exported
+    pub exported_at: DateTime<Utc>,
+
+    /// The exported session
+    pub session: Session,
+
+    /// Messages in chronological order
+    pub messages: Vec<ExportedMessage>,
+}
+
+/// A message of a [`SessionExport`] with all its parts.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExportedMessage {
+    /// Message identifier in the exporting storage
+    pub id: String,
+
+    /// Role of the message sender
+    pub role: MessageRole,
+
+    /// Message metadata (for assistant messages)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<MessageMetadata>,
+
+    /// Message parts in chronological order
+    pub parts: Vec<MessagePart>,
+}
+
+impl SessionExport {
+    /// Create an export document for a session and its messages.
+    pub fn new(session: Session, messages: Vec<ExportedMessage>) -> Self {
+        Self {
+            format: SESSION_EXPORT_FORMAT.to_string(),
+            version: SESSION_EXPORT_VERSION,
+            exported_at: Utc::now(),
+            session,
+            messages,
+        }
+    }
+
+    /// Serialize the document to pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, StorageError> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| StorageError::SerializationError(e.to_string()))
+    }
+
+    /// Parse a document created by [`SessionExport::to_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::InvalidData` if the JSON is not a session export or was
+    /// written by a newer version.
+    pub fn from_json(json: &str) -> Result<Self, StorageError> {
+        let export: Self = serde_json::from_str(json)
+            .map_err(|e| StorageError::SerializationError(e.to_string()))?;
+
+        if export.format != SESSION_EXPORT_FORMAT {
+            return Err(StorageError::InvalidData(format!(
+                "Not a session export: format is {:?}",
+                export.format
+            )));
+        }
+        if export.version > SESSION_EXPORT_VERSION {
+            return Err(StorageError::InvalidData(format!(
+                "Unsupported session export version: {}",
+                export.version
+            )));
+        }
+        Ok(export)
+    }
+}
+
+/// Export a session with all its messages and parts.
+///
+/// # Errors
+///
+/// Returns `StorageError::NotFound` if the session doesn't exist.
+pub async fn export_session(
+    storage: &dyn Storage,
+    session_id: &str,
+) -> Result<SessionExport, StorageError> {
+    let session = storage.get_session(session_id).await?;
+
+    let mut messages = Vec::new();
+    for message_id in storage.list_messages(session_id, None).await? {
+        let (role, parts, metadata) = storage
+            .get_message_with_metadata(session_id, &message_id)
+            .await?;
+        messages.push(ExportedMessage {
+            id: message_id,
+            role,
+            metadata,
+            parts,
+        });
+    }
+
+    Ok(SessionExport::new(session, messages))
+}
+
+/// Import an exported session as a new session.
+///
+/// The session, its messages and their parts get fresh IDs from the storage's
+/// `generate_*_id` methods, so a session can be imported next to its original or
+/// several times. Everything else, including timestamps and metadata, is kept, except
+/// the link to a parent session, whose ID is not valid in the target storage.
+/// [`MessageRole::System`] messages are skipped, as the [`Storage`] trait only stores
+/// user and assistant messages.
+///
+/// # Returns
+///
+/// The new session.
+pub async fn import_session(
+    storage: &dyn Storage,
+    export: &SessionExport,
+) -> Result<Session, StorageError> {
+    let mut session = export.session.clone();
+    session.id = storage.generate_session_id();
+    session.metadata.parent_session_id = None;
+    session.metadata.forked_from_message_id = None;
+    storage.store_session(&session).await?;
+
+    for message in &export.messages {
+        let parts: Vec<MessagePart> = message
+            .parts
+            .iter()
+            .cloned()
+            .map(|mut part| {
+                part.set_id(storage.generate_part_id());
+                part
+            })
+            .collect();
+        let part_ids = parts.iter().map(|part| part.id().to_string()).collect();
+        let message_id = storage.generate_message_id();
+
+        match message.role {
+            MessageRole::User => {
+                let user_message = UserMessage::new(message_id, session.id.clone(), part_ids);
+                storage.store_user_message(&user_message, &parts).await?;
+            }
+            MessageRole::Assistant => {
+                let assistant_message =
+                    AssistantMessage::new(message_id, session.id.clone(), part_ids)
+                        .with_metadata(message.metadata.clone().unwrap_or_default());
+                storage
+                    .store_assistant_message(&assistant_message, &parts)
+                    .await?;
+            }
+            MessageRole::System => {}
+        }
+    }
+
+    Ok(session)
+}
+
+#[cfg(all(test, feature = "memory"))]
+mod tests {
+    use super::*;
+    use crate::{FileData, FilePart, ImageData, ImagePart, InMemoryStorage, TextPart, UsageStats};
+
+    async fn store_conversation(storage: &dyn Storage) -> Session {
+        let session = Session::new(storage.generate_session_id()).with_title("Trip".to_string());
+        storage.store_session(&session).await.unwrap();
+
+        let parts = vec![
+            MessagePart::Text(TextPart::new(
+                storage.generate_part_id(),
+                "What is in this picture?".to_string(),
+            )),
+            MessagePart::Image(ImagePart::new(
+                storage.generate_part_id(),
+                "image/png".to_string(),
+                ImageData::Binary {
+                    data: vec![137, 80, 78, 71],
+                },
+            )),
+            MessagePart::File(
+                FilePart::new(
+                    storage.generate_part_id(),
+                    "text/plain".to_string(),
+                    FileData::Binary {
+                        data: b"notes".to_vec(),
+                    },
+                )
+                .with_filename("notes.txt".to_string()),
+            ),
+        ];
+        let message = UserMessage::new(
+            storage.generate_message_id(),
+            session.id.clone(),
+            parts.iter().map(|part| part.id().to_string()).collect(),
+        );
+        storage.store_user_message(&message, &parts).await.unwrap();
+
+        let part = MessagePart::Text(TextPart::new(
+            storage.generate_part_id(),
+            "A beach.".to_string(),
+        ));
+        let message = AssistantMessage::new(
+            storage.generate_message_id(),
+            session.id.clone(),
+            vec![part.id().to_string()],
+        )
+        .with_metadata(MessageMetadata {
+            model_id: Some("model-1".to_string()),
+            usage: Some(UsageStats::new(10, 3)),
+            ..Default::default()
+        });
+        storage
+            .store_assistant_message(&message, &[part])
+            .await
+            .unwrap();
+
+        session
+    }
+
+    #[tokio::test]
+    async fn test_export_round_trip_through_json() {
+        let source = InMemoryStorage::new();
+        let session = store_conversation(&source).await;
+
+        let export = export_session(&source, &session.id).await.unwrap();
+        let parsed = SessionExport::from_json(&export.to_json().unwrap()).unwrap();
+
+        assert_eq!(parsed, export);
+        assert_eq!(parsed.session, session);
+        assert_eq!(parsed.messages.len(), 2);
+        assert_eq!(parsed.messages[0].parts.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_import_allocates_fresh_ids() {
+        let storage = InMemoryStorage::new();
+        let session = store_conversation(&storage).await;
+        let export = export_session(&storage, &session.id).await.unwrap();
+
+        let imported = import_session(&storage, &export).await.unwrap();
+
+        assert_ne!(imported.id, session.id);
+        assert_eq!(imported.title, session.title);
+
+        let reexported = export_session(&storage, &imported.id).await.unwrap();
+        for (original, copy) in export.messages.iter().zip(&reexported.messages) {
+            assert_ne!(copy.id, original.id);
+            assert_eq!(copy.role, original.role);
+            assert_eq!(copy.metadata, original.metadata);
+            for (original_part, copied_part) in original.parts.iter().zip(&copy.parts) {
+                assert_ne!(copied_part.id(), original_part.id());
+
+                let mut renamed = copied_part.clone();
+                renamed.set_id(original_part.id().to_string());
+                assert_eq!(&renamed, original_part);
+            }
+        }
+        assert_eq!(reexported.messages.len(), export.messages.len());
+    }
+
+    #[test]
+    fn test_from_json_rejects_other_documents() {
+        let mut export = SessionExport::new(Session::new("ses_1".to_string()), Vec::new());
+        export.format = "something-else".to_string();
+
+        assert!(matches!(
+            SessionExport::from_json(&serde_json::to_string(&export).unwrap()),
+            Err(StorageError::InvalidData(_))
+        ));
+
+        export.format = SESSION_EXPORT_FORMAT.to_string();
+        export.version = SESSION_EXPORT_VERSION + 1;
+        assert!(matches!(
+            SessionExport::from_json(&serde_json::to_string(&export).unwrap()),
+            Err(StorageError::InvalidData(_))
+        ));
+    }
+}
@@ -0,0 +1,600 @@
+use super::{ExportedMessage, SessionExport, export_session, import_session};
+use crate::{
+    FileData, FilePart, ImageData, ImagePart, MessageMetadata, MessagePart, MessageRole, Session,
+    Storage, StorageError, TextPart, ToolCallPart, ToolResultData, ToolResultPart,
+};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde_json::{Value, json};
+
+/// Custom metadata flag of assistant messages that only hold client-side tool results.
+const CLIENT_TOOL_MESSAGE: &str = "is_client_tool_message";
+
+/// Export sessions as JSONL of OpenAI-style chat messages.
+///
+/// Each session becomes one line of the form `{"messages": [...]}`, the format used
+/// for chat fine-tuning data. See [`to_openai_messages`] for how parts are converted.
+///
+/// # Errors
+///
+/// Returns `StorageError::NotFound` if a session doesn't exist.
+pub async fn export_openai_jsonl(
+    storage: &dyn Storage,
+    session_ids: &[String],
+) -> Result<String, StorageError> {
+    let mut jsonl = String::new();
+    for session_id in session_ids {
+        let export = export_session(storage, session_id).await?;
+        jsonl.push_str(&json!({ "messages": to_openai_messages(&export) }).to_string());
+        jsonl.push('\n');
+    }
+    Ok(jsonl)
+}
+
+/// Import JSONL of OpenAI-style chat messages, one new session per line.
+///
+/// Every line must be an object with a `messages` array, as written by
+/// [`export_openai_jsonl`]. All lines are parsed before anything is stored, and the
+/// sessions are imported with [`import_session`], so they get fresh IDs.
+///
+/// # Returns
+///
+/// The new sessions, in the order of the lines.
+///
+/// # Errors
+///
+/// Returns `StorageError::SerializationError` or `StorageError::InvalidData` with the
+/// line number if a line can't be parsed.
+pub async fn import_openai_jsonl(
+    storage: &dyn Storage,
+    jsonl: &str,
+) -> Result<Vec<Session>, StorageError> {
+    let mut exports = Vec::new();
+    for (index, line) in jsonl.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let value: Value = serde_json::from_str(line)
+            .map_err(|e| StorageError::SerializationError(format!("Line {}: {}", index + 1, e)))?;
+        let messages = value
+            .get("messages")
+            .and_then(Value::as_array)
+            .ok_or_else(|| {
+                StorageError::InvalidData(format!("Line {}: missing messages array", index + 1))
+            })?;
+        exports.push(from_openai_messages(messages).map_err(|e| match e {
+            StorageError::InvalidData(message) => {
+                StorageError::InvalidData(format!("Line {}: {}", index + 1, message))
+            }
+            other => other,
+        })?);
+    }
+
+    let mut sessions = Vec::with_capacity(exports.len());
+    for export in &exports {
+        sessions.push(import_session(storage, export).await?);
+    }
+    Ok(sessions)
+}
+
+/// Convert an exported session to OpenAI-style chat messages.
+///
+/// - Text, images and files become message content; binary data is inlined as
+///   `data:` URLs. Files given by URL are skipped, as chat messages can't reference them.
+/// - Tool calls become `tool_calls` of the assistant message, and each tool result
+///   becomes a following `tool` message.
+/// - Reasoning and source parts are skipped.
+pub fn to_openai_messages(export: &SessionExport) -> Vec<Value> {
+    let mut messages = Vec::new();
+
+    for message in &export.messages {
+        match message.role {
+            MessageRole::System => messages.push(json!({
+                "role": "system",
+                "content": text_content(&message.parts),
+            })),
+            MessageRole::User => messages.push(json!({
+                "role": "user",
+                "content": user_content(&message.parts),
+            })),
+            MessageRole::Assistant => {
+                let text = text_content(&message.parts);
+                let tool_calls: Vec<Value> = message
+                    .parts
+                    .iter()
+                    .filter_map(|part| match part {
+                        MessagePart::ToolCall(call) => Some(json!({
+                            "id": call.tool_call_id,
+                            "type": "function",
+                            "function": {
+                                "name": call.tool_name,
+                                "arguments": call.arguments.to_string(),
+                            },
+                        })),
+                        _ => None,
+                    })
+                    .collect();
+
+                if !text.is_empty() || !tool_calls.is_empty() {
+                    let mut assistant = json!({
+                        "role": "assistant",
+                        "content": if text.is_empty() { Value::Null } else { Value::String(text) },
+                    });
+                    if !tool_calls.is_empty() {
+                        assistant["tool_calls"] = Value::Array(tool_calls);
+                    }
+                    messages.push(assistant);
+                }
+
+                for part in &message.parts {
+                    if let MessagePart::ToolResult(result) = part {
+                        messages.push(json!({
+                            "role": "tool",
+                            "tool_call_id": result.tool_call_id,
+                            "content": tool_result_content(&result.result),
+                        }));
+                    }
+                }
+            }
+        }
+    }
+
+    messages
+}
+
+/// Convert OpenAI-style chat messages to a session export.
+///
+/// This is the inverse of [`to_openai_messages`]. Consecutive `tool` messages are
+/// stored as one assistant message holding the tool results, the way client-side tool
+/// results are stored by `llm-kit-core`. The session and all IDs are placeholders that
+/// [`import_session`] replaces.
+///
+/// # Errors
+///
+/// Returns `StorageError::InvalidData` if a message has no or an unsupported role.
+pub fn from_openai_messages(messages: &[Value]) -> Result<SessionExport, StorageError> {
+    let mut exported: Vec<ExportedMessage> = Vec::new();
+
+    for message in messages {
+        let role = message
+            .get("role")
+            .and_then(Value::as_str)
+            .ok_or_else(|| StorageError::InvalidData("Message without a role".to_string()))?;
+        let content = message.get("content").unwrap_or(&Value::Null);
+
+        match role {
+            "system" | "developer" => exported.push(ExportedMessage {
+                id: String::new(),
+                role: MessageRole::System,
+                metadata: None,
+                parts: content_parts(content),
+            }),
+            "user" => exported.push(ExportedMessage {
+                id: String::new(),
+                role: MessageRole::User,
+                metadata: None,
+                parts: content_parts(content),
+            }),
+            "assistant" => {
+                let mut parts = content_parts(content);
+                let tool_calls = message.get("tool_calls").and_then(Value::as_array);
+                for call in tool_calls.into_iter().flatten() {
+                    let function = &call["function"];
+                    let arguments = function["arguments"].as_str().unwrap_or("{}");
+                    parts.push(MessagePart::ToolCall(ToolCallPart::new(
+                        String::new(),
+                        call["id"].as_str().unwrap_or_default().to_string(),
+                        function["name"].as_str().unwrap_or_default().to_string(),
+                        serde_json::from_str(arguments)
+                            .unwrap_or_else(|_| Value::String(arguments.to_string())),
+                    )));
+                }
+
+                exported.push(ExportedMessage {
+                    id: String::new(),
+                    role: MessageRole::Assistant,
+                    metadata: Some(MessageMetadata::default()),
+                    parts,
+                });
+            }
+            "tool" => {
+                let tool_call_id = message["tool_call_id"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string();
+                let output = match content {
+                    Value::String(text) => text.clone(),
+                    other => text_content(&content_parts(other)),
+                };
+                let result = MessagePart::ToolResult(ToolResultPart::new_success(
+                    String::new(),
+                    tool_call_id.clone(),
+                    tool_name(&exported, &tool_call_id),
+                    Value::String(output),
+                ));
+
+                match exported.last_mut() {
+                    Some(last) if is_client_tool_message(last) => last.parts.push(result),
+                    _ => exported.push(ExportedMessage {
+                        id: String::new(),
+                        role: MessageRole::Assistant,
+                        metadata: Some(MessageMetadata {
+                            custom: Some(json!({ (CLIENT_TOOL_MESSAGE): true })),
+                            ..Default::default()
+                        }),
+                        parts: vec![result],
+                    }),
+                }
+            }
+            other => {
+                return Err(StorageError::InvalidData(format!(
+                    "Unsupported message role: {}",
+                    other
+                )));
+            }
+        }
+    }
+
+    Ok(SessionExport::new(Session::new(String::new()), exported))
+}
+
+/// Join the text parts of a message.
+fn text_content(parts: &[MessagePart]) -> String {
+    parts
+        .iter()
+        .filter_map(|part| match part {
+            MessagePart::Text(text) => Some(text.text.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Content of a user message: a string for a single text part, content parts otherwise.
+fn user_content(parts: &[MessagePart]) -> Value {
+    if let [MessagePart::Text(text)] = parts {
+        return Value::String(text.text.clone());
+    }
+
+    let content = parts
+        .iter()
+        .filter_map(|part| match part {
+            MessagePart::Text(text) => Some(json!({ "type": "text", "text": text.text })),
+            MessagePart::Image(image) => {
+                let url = match &image.data {
+                    ImageData::Url { url } => url.clone(),
+                    ImageData::Base64 { data } => data_url(&image.media_type, data),
+                    ImageData::Binary { data } => {
+                        data_url(&image.media_type, &STANDARD.encode(data))
+                    }
+                };
+                Some(json!({ "type": "image_url", "image_url": { "url": url } }))
+            }
+            MessagePart::File(file) => {
+                let file_data = match &file.data {
+                    FileData::Url { .. } => return None,
+                    FileData::Base64 { data } => data_url(&file.media_type, data),
+                    FileData::Binary { data } => data_url(&file.media_type, &STANDARD.encode(data)),
+                };
+                let mut value = json!({ "type": "file", "file": { "file_data": file_data } });
+                if let Some(filename) = &file.filename {
+                    value["file"]["filename"] = Value::String(filename.clone());
+                }
+                Some(value)
+            }
+            _ => None,
+        })
+        .collect();
+
+    Value::Array(content)
+}
+
+/// Content of a tool message for a tool result.
+fn tool_result_content(result: &ToolResultData) -> String {
+    match result {
+        ToolResultData::Success {
+            output: Value::String(text),
+        } => text.clone(),
+        ToolResultData::Success { output } => output.to_string(),
+        ToolResultData::Error { error } => error.clone(),
+    }
+}
+
+/// Parts of a message content that is a string or an array of content parts.
+///
+/// Unsupported content parts, such as audio, are skipped.
+fn content_parts(content: &Value) -> Vec<MessagePart> {
+    match content {
+        Value::String(text) => vec![MessagePart::Text(TextPart::new(
+            String::new(),
+            text.clone(),
+        ))],
+        Value::Array(items) => items.iter().filter_map(content_part).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn content_part(item: &Value) -> Option<MessagePart> {
+    match item["type"].as_str()? {
+        "text" => Some(MessagePart::Text(TextPart::new(
+            String::new(),
+            item["text"].as_str()?.to_string(),
+        ))),
+        "image_url" => {
+            let url = item["image_url"]["url"].as_str()?;
+            let (media_type, data) = match parse_data_url(url) {
+                Some((media_type, data)) => (
+                    media_type.to_string(),
+                    ImageData::Base64 {
+                        data: data.to_string(),
+                    },
+                ),
+                None => (
+                    "image/*".to_string(),
+                    ImageData::Url {
+                        url: url.to_string(),
+                    },
+                ),
+            };
+            Some(MessagePart::Image(ImagePart::new(
+                String::new(),
+                media_type,
+                data,
+            )))
+        }
+        "file" => {
+            let file = &item["file"];
+            let (media_type, data) = parse_data_url(file["file_data"].as_str()?)?;
+            let mut part = FilePart::new(
+                String::new(),
+                media_type.to_string(),
+                FileData::Base64 {
+                    data: data.to_string(),
+                },
+            );
+            if let Some(filename) = file["filename"].as_str() {
+                part = part.with_filename(filename.to_string());
+            }
+            Some(MessagePart::File(part))
+        }
+        _ => None,
+    }
+}
+
+fn data_url(media_type: &str, base64: &str) -> String {
+    format!("data:{};base64,{}", media_type, base64)
+}
+
+/// Split a base64 `data:` URL into media type and data.
+fn parse_data_url(url: &str) -> Option<(&str, &str)> {
+    url.strip_prefix("data:")?.split_once(";base64,")
+}
+
+/// Name of the tool of an earlier tool call, or an empty string if there is none.
+fn tool_name(messages: &[ExportedMessage], tool_call_id: &str) -> String {
+    messages
+        .iter()
+        .rev()
+        .flat_map(|message| &message.parts)
+        .find_map(|part| match part {
+            MessagePart::ToolCall(call) if call.tool_call_id == tool_call_id => {
+                Some(call.tool_name.clone())
+            }
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+fn is_client_tool_message(message: &ExportedMessage) -> bool {
+    message
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.custom.as_ref())
+        .and_then(|custom| custom.get(CLIENT_TOOL_MESSAGE))
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(role: MessageRole, parts: Vec<MessagePart>) -> ExportedMessage {
+        ExportedMessage {
+            id: String::new(),
+            role,
+            metadata: None,
+            parts,
+        }
+    }
+
+    fn text(text: &str) -> MessagePart {
+        MessagePart::Text(TextPart::new(String::new(), text.to_string()))
+    }
+
+    #[test]
+    fn test_to_openai_messages() {
+        let export = SessionExport::new(
+            Session::new("ses_1".to_string()),
+            vec![
+                message(
+                    MessageRole::User,
+                    vec![
+                        text("Look"),
+                        MessagePart::Image(ImagePart::new(
+                            String::new(),
+                            "image/png".to_string(),
+                            ImageData::Binary {
+                                data: b"png".to_vec(),
+                            },
+                        )),
+                    ],
+                ),
+                message(
+                    MessageRole::Assistant,
+                    vec![
+                        MessagePart::ToolCall(ToolCallPart::new(
+                            String::new(),
+                            "call-1".to_string(),
+                            "weather".to_string(),
+                            json!({ "city": "Paris" }),
+                        )),
+                        MessagePart::ToolResult(ToolResultPart::new_success(
+                            String::new(),
+                            "call-1".to_string(),
+                            "weather".to_string(),
+                            json!({ "temperature": 21 }),
+                        )),
+                    ],
+                ),
+                message(MessageRole::Assistant, vec![text("It is sunny.")]),
+            ],
+        );
+
+        assert_eq!(
+            to_openai_messages(&export),
+            vec![
+                json!({
+                    "role": "user",
+                    "content": [
+                        { "type": "text", "text": "Look" },
+                        { "type": "image_url", "image_url": { "url": "data:image/png;base64,cG5n" } },
+                    ],
+                }),
+                json!({
+                    "role": "assistant",
+                    "content": null,
+                    "tool_calls": [{
+                        "id": "call-1",
+                        "type": "function",
+                        "function": { "name": "weather", "arguments": r#"{"city":"Paris"}"# },
+                    }],
+                }),
+                json!({
+                    "role": "tool",
+                    "tool_call_id": "call-1",
+                    "content": r#"{"temperature":21}"#,
+                }),
+                json!({ "role": "assistant", "content": "It is sunny." }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_openai_messages() {
+        let messages = vec![
+            json!({ "role": "system", "content": "Be brief." }),
+            json!({
+                "role": "user",
+                "content": [
+                    { "type": "text", "text": "Summarize" },
+                    { "type": "file", "file": { "filename": "a.txt", "file_data": "data:text/plain;base64,aGk=" } },
+                ],
+            }),
+            json!({
+                "role": "assistant",
+                "content": null,
+                "tool_calls": [{
+                    "id": "call-1",
+                    "type": "function",
+                    "function": { "name": "read", "arguments": "{\"path\":\"a.txt\"}" },
+                }],
+            }),
+            json!({ "role": "tool", "tool_call_id": "call-1", "content": "hi" }),
+            json!({ "role": "assistant", "content": "It says hi." }),
+        ];
+
+        let export = from_openai_messages(&messages).unwrap();
+
+        let roles: Vec<MessageRole> = export.messages.iter().map(|m| m.role).collect();
+        assert_eq!(
+            roles,
+            vec![
+                MessageRole::System,
+                MessageRole::User,
+                MessageRole::Assistant,
+                MessageRole::Assistant,
+                MessageRole::Assistant,
+            ]
+        );
+        match &export.messages[1].parts[1] {
+            MessagePart::File(file) => {
+                assert_eq!(file.media_type, "text/plain");
+                assert_eq!(file.filename.as_deref(), Some("a.txt"));
+                assert_eq!(
+                    file.data,
+                    FileData::Base64 {
+                        data: "aGk=".to_string()
+                    }
+                );
+            }
+            other => panic!("expected a file part, got {:?}", other),
+        }
+        match &export.messages[2].parts[..] {
+            [MessagePart::ToolCall(call)] => assert_eq!(call.arguments, json!({ "path": "a.txt" })),
+            other => panic!("expected a tool call, got {:?}", other),
+        }
+        assert!(is_client_tool_message(&export.messages[3]));
+        match &export.messages[3].parts[..] {
+            [MessagePart::ToolResult(result)] => assert_eq!(result.tool_name, "read"),
+            other => panic!("expected a tool result, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_from_openai_messages_rejects_unknown_role() {
+        let result = from_openai_messages(&[json!({ "role": "robot", "content": "beep" })]);
+
+        assert!(matches!(result, Err(StorageError::InvalidData(_))));
+    }
+
+    #[cfg(feature = "memory")]
+    #[tokio::test]
+    async fn test_jsonl_round_trip() {
+        let storage = crate::InMemoryStorage::new();
+        let jsonl = concat!(
+            r#"{"messages":[{"role":"user","content":"Hi"},{"role":"assistant","content":"Hello!"}]}"#,
+            "\n\n",
+            r#"{"messages":[{"role":"user","content":"Bye"}]}"#,
+            "\n",
+        );
+
+        let sessions = import_openai_jsonl(&storage, jsonl).await.unwrap();
+        assert_eq!(sessions.len(), 2);
+
+        let ids: Vec<String> = sessions.into_iter().map(|session| session.id).collect();
+        let exported = export_openai_jsonl(&storage, &ids).await.unwrap();
+        let lines = |jsonl: &str| -> Vec<Value> {
+            jsonl
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(|line| serde_json::from_str(line).unwrap())
+                .collect()
+        };
+        assert_eq!(lines(&exported), lines(jsonl));
+    }
+
+    #[cfg(feature = "memory")]
+    #[tokio::test]
+    async fn test_import_reports_invalid_line() {
+        let storage = crate::InMemoryStorage::new();
+        let jsonl = concat!(
+            r#"{"messages":[{"role":"user","content":"Hi"}]}"#,
+            "\n",
+            r#"{"messages":[{"content":"no role"}]}"#,
+        );
+
+        let error = import_openai_jsonl(&storage, jsonl).await.unwrap_err();
+
+        assert!(
+            matches!(&error, StorageError::InvalidData(message) if message.starts_with("Line 2:")),
+            "unexpected error: {:?}",
+            error
+        );
+        assert!(
+            storage.list_sessions(None).await.unwrap().is_empty(),
+            "nothing should be imported from an invalid file"
+        );
+    }
+}
@@ -4,7 +4,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 /// Monotonic counter for IDs generated in the same millisecond
 static COUNTER: AtomicU64 = AtomicU64::new(0);
 
-/// ID Generator for filesystem storage with sortable, unique identifiers
+/// ID generator with sortable, unique identifiers shared by the storage providers.
 pub struct IdGenerator;
 
 impl IdGenerator {
@@ -6,17 +6,24 @@
 //! - **Session management**: Create, retrieve, and delete conversation sessions
 //! - **Message storage**: Store user and assistant messages with full metadata
 //! - **Part storage**: Hierarchical storage of message parts (text, tools, files, etc.)
+//! - **Export/import**: Whole sessions as portable JSON documents or OpenAI-style JSONL
+//! - **Blob storage**: Content-addressed storage for large image and file payloads
+//! - **Stream storage**: Chunks of generation streams, so interrupted streams can be resumed
 //!
 //! ## Architecture
 //!
 //! The storage system follows the provider pattern similar to `llm-kit-provider`:
 //!
-//! 1. **Trait Layer** (`llm-kit-storage`): Defines the [`Storage`] trait - NO implementations
+//! 1. **Trait Layer** (`llm-kit-storage`): Defines the [`Storage`] trait - no persistent implementations
 //! 2. **Implementation Layer**: Concrete implementations in separate crates:
 //!    - `llm-kit-storage-filesystem`: Filesystem-based storage
+//!    - `llm-kit-storage-sqlite`: SQLite storage
 //!    - `llm-kit-storage-mongodb`: MongoDB storage (future)
 //!    - `llm-kit-storage-postgresql`: PostgreSQL storage (future)
 //!
+//! The only implementation shipped with this crate is `InMemoryStorage` (behind the
+//! `memory` feature), for tests and short-lived sessions.
+//!
 //! ## Hierarchy
 //!
 //! ```text
@@ -71,23 +78,52 @@
 
 #![warn(missing_docs)]
 
+/// Content-addressed storage for binary payloads.
+pub mod blob;
 /// Error types for storage operations.
 pub mod error;
+/// Export and import of whole sessions in portable formats.
+pub mod export;
+/// Sortable ID generation for sessions, messages, and parts.
+pub mod id_generator;
+/// In-memory storage provider (requires the `memory` feature).
+#[cfg(feature = "memory")]
+pub mod memory;
 /// Message types (User, Assistant, System).
 pub mod message;
 /// Message part types (Text, Tool, File, etc.).
 pub mod part;
+/// Model prices for computing the cost of stored usage.
+pub mod pricing;
+/// Query types for searching sessions and messages.
+pub mod query;
 /// Session types and metadata.
 pub mod session;
 /// Storage trait definition.
 pub mod storage;
+/// Conformance test suite for storage implementations (requires the `testing` feature).
+#[cfg(feature = "testing")]
+pub mod storage_conformance;
+/// Stored generation streams for resuming interrupted streams.
+pub mod stream;
+/// Token usage aggregation over sessions.
+pub mod usage;
 
 // Re-export commonly used types
+pub use blob::{BlobRef, BlobStore};
 pub use error::StorageError;
+pub use id_generator::IdGenerator;
+#[cfg(feature = "memory")]
+pub use memory::InMemoryStorage;
 pub use message::{AssistantMessage, MessageMetadata, MessageRole, UsageStats, UserMessage};
 pub use part::{
     FileData, FilePart, ImageData, ImageDimensions, ImagePart, MessagePart, ReasoningPart,
-    SourcePart, TextPart, ToolCallPart, ToolResultData, ToolResultPart,
+    SourcePart, TextPart, ToolApprovalRequestPart, ToolApprovalResponsePart, ToolCallPart,
+    ToolResultData, ToolResultPart,
 };
+pub use pricing::{ModelPricing, PricingTable};
+pub use query::{MessageMatch, MessageQuery, Page, SessionQuery};
 pub use session::{Session, SessionMetadata};
 pub use storage::Storage;
+pub use stream::{StoredStream, StreamStatus};
+pub use usage::{ModelUsage, SessionUsage};
@@ -0,0 +1,719 @@
+use crate::{
+    AssistantMessage, IdGenerator, MessageMetadata, MessagePart, MessageRole, Session, Storage,
+    StorageError, StoredStream, StreamStatus, UserMessage,
+};
+use async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Bound;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// In-memory storage provider.
+///
+/// Keeps sessions, messages, and parts in process memory, so nothing survives a
+/// restart. It uses the same sortable IDs and ordering as the persistent providers,
+/// which makes it a drop-in replacement in tests and for short-lived chat sessions.
+///
+/// Two optional limits bound memory usage:
+///
+/// - **TTL** ([`with_ttl`](Self::with_ttl)): a session and its messages expire when
+///   they haven't been stored or read for the given duration
+/// - **Capacity** ([`with_max_sessions`](Self::with_max_sessions)): when more sessions
+///   are held, the least recently used ones are evicted
+///
+/// # Example
+///
+/// ```rust
+/// use llm_kit_storage::{InMemoryStorage, Storage};
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// let storage: Arc<dyn Storage> = Arc::new(
+///     InMemoryStorage::new()
+///         .with_ttl(Duration::from_secs(30 * 60))
+///         .with_max_sessions(1000),
+/// );
+/// ```
+pub struct InMemoryStorage {
+    state: Mutex<State>,
+    ttl: Option<Duration>,
+    max_sessions: Option<usize>,
+}
+
+/// Mutable state of an [`InMemoryStorage`].
+#[derive(Default)]
+struct State {
+    sessions: HashMap<String, SessionEntry>,
+    /// Monotonic counter that orders accesses for LRU eviction.
+    clock: u64,
+}
+
+/// A session together with its messages and streams.
+///
+/// Messages may be stored before the session itself, so the session is optional.
+struct SessionEntry {
+    session: Option<Session>,
+    /// Messages keyed by ID, which keeps them in chronological order.
+    messages: BTreeMap<String, StoredMessage>,
+    /// Streams keyed by ID.
+    streams: HashMap<String, StreamEntry>,
+    last_accessed_at: Instant,
+    last_accessed_tick: u64,
+}
+
+/// A stored message with its parts sorted by part ID.
+#[derive(Clone)]
+struct StoredMessage {
+    role: MessageRole,
+    metadata: Option<MessageMetadata>,
+    parts: Vec<MessagePart>,
+}
+
+/// A stored stream with its chunks.
+struct StreamEntry {
+    stream: StoredStream,
+    chunks: Vec<serde_json::Value>,
+}
+
+impl InMemoryStorage {
+    /// Create an empty in-memory storage without TTL or capacity limit
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(State::default()),
+            ttl: None,
+            max_sessions: None,
+        }
+    }
+
+    /// Expire sessions that haven't been stored or read for the given duration
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Keep at most the given number of sessions, evicting the least recently used
+    pub fn with_max_sessions(mut self, max_sessions: usize) -> Self {
+        self.max_sessions = Some(max_sessions);
+        self
+    }
+
+    /// Lock the state and drop expired sessions
+    fn lock(&self) -> Result<MutexGuard<'_, State>, StorageError> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|e| StorageError::ProviderError(e.to_string()))?;
+
+        if let Some(ttl) = self.ttl {
+            let now = Instant::now();
+            state
+                .sessions
+                .retain(|_, entry| now.duration_since(entry.last_accessed_at) < ttl);
+        }
+
+        Ok(state)
+    }
+
+    /// Store a message, creating the session entry if needed
+    fn store_message(
+        &self,
+        session_id: &str,
+        message_id: &str,
+        message: StoredMessage,
+    ) -> Result<(), StorageError> {
+        let mut state = self.lock()?;
+        let entry = self.touch_or_insert(&mut state, session_id);
+        entry.messages.insert(message_id.to_string(), message);
+        Ok(())
+    }
+
+    /// Mark a session as used, creating its entry (and evicting others) if needed
+    fn touch_or_insert<'a>(&self, state: &'a mut State, session_id: &str) -> &'a mut SessionEntry {
+        if !state.sessions.contains_key(session_id) {
+            self.evict_for_insert(state);
+        }
+
+        state.clock += 1;
+        let tick = state.clock;
+        let entry = state
+            .sessions
+            .entry(session_id.to_string())
+            .or_insert_with(|| SessionEntry {
+                session: None,
+                messages: BTreeMap::new(),
+                streams: HashMap::new(),
+                last_accessed_at: Instant::now(),
+                last_accessed_tick: tick,
+            });
+        entry.last_accessed_at = Instant::now();
+        entry.last_accessed_tick = tick;
+        entry
+    }
+
+    /// Mark an existing session as used
+    fn touch<'a>(state: &'a mut State, session_id: &str) -> Option<&'a mut SessionEntry> {
+        state.clock += 1;
+        let tick = state.clock;
+        let entry = state.sessions.get_mut(session_id)?;
+        entry.last_accessed_at = Instant::now();
+        entry.last_accessed_tick = tick;
+        Some(entry)
+    }
+
+    /// Mark the session of a stream as used and get the stream
+    fn touch_stream<'a>(state: &'a mut State, stream_id: &str) -> Option<&'a mut StreamEntry> {
+        let session_id = state
+            .sessions
+            .iter()
+            .find(|(_, entry)| entry.streams.contains_key(stream_id))
+            .map(|(id, _)| id.clone())?;
+        Self::touch(state, &session_id)?.streams.get_mut(stream_id)
+    }
+
+    /// Evict least recently used sessions until one more fits
+    fn evict_for_insert(&self, state: &mut State) {
+        let Some(max_sessions) = self.max_sessions else {
+            return;
+        };
+
+        while !state.sessions.is_empty() && state.sessions.len() >= max_sessions {
+            let oldest = state
+                .sessions
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_accessed_tick)
+                .map(|(id, _)| id.clone());
+
+            if let Some(id) = oldest {
+                state.sessions.remove(&id);
+            }
+        }
+    }
+
+    /// Sort parts by ID, matching the order of the persistent providers
+    fn sorted_parts(parts: &[MessagePart]) -> Vec<MessagePart> {
+        let mut parts = parts.to_vec();
+        parts.sort_by(|a, b| Self::extract_part_id(a).cmp(Self::extract_part_id(b)));
+        parts
+    }
+
+    /// Extract part ID from MessagePart enum
+    fn extract_part_id(part: &MessagePart) -> &str {
+        match part {
+            MessagePart::Text(p) => &p.id,
+            MessagePart::Image(p) => &p.id,
+            MessagePart::File(p) => &p.id,
+            MessagePart::Reasoning(p) => &p.id,
+            MessagePart::ToolCall(p) => &p.id,
+            MessagePart::ToolResult(p) => &p.id,
+            MessagePart::Source(p) => &p.id,
+            MessagePart::ToolApprovalRequest(p) => &p.id,
+            MessagePart::ToolApprovalResponse(p) => &p.id,
+        }
+    }
+}
+
+impl Default for InMemoryStorage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Storage for InMemoryStorage {
+    // ID Generation
+
+    fn generate_session_id(&self) -> String {
+        IdGenerator::generate_session_id()
+    }
+
+    fn generate_message_id(&self) -> String {
+        IdGenerator::generate_message_id()
+    }
+
+    fn generate_part_id(&self) -> String {
+        IdGenerator::generate_part_id()
+    }
+
+    // Session Operations
+
+    async fn store_session(&self, session: &Session) -> Result<(), StorageError> {
+        let mut state = self.lock()?;
+        let entry = self.touch_or_insert(&mut state, &session.id);
+        entry.session = Some(session.clone());
+        Ok(())
+    }
+
+    async fn get_session(&self, session_id: &str) -> Result<Session, StorageError> {
+        let mut state = self.lock()?;
+        Self::touch(&mut state, session_id)
+            .and_then(|entry| entry.session.clone())
+            .ok_or_else(|| StorageError::NotFound(format!("Session not found: {}", session_id)))
+    }
+
+    async fn list_sessions(&self, limit: Option<usize>) -> Result<Vec<Session>, StorageError> {
+        let state = self.lock()?;
+        let mut sessions: Vec<Session> = state
+            .sessions
+            .values()
+            .filter_map(|entry| entry.session.clone())
+            .collect();
+
+        // Sort by updated_at (most recent first)
+        sessions.sort_by(|a, b| b.updated_at.cmp(&a.updated_at).then(a.id.cmp(&b.id)));
+
+        if let Some(limit) = limit {
+            sessions.truncate(limit);
+        }
+
+        Ok(sessions)
+    }
+
+    async fn delete_session(&self, session_id: &str) -> Result<(), StorageError> {
+        let mut state = self.lock()?;
+        state.sessions.remove(session_id);
+        Ok(())
+    }
+
+    // Message Operations
+
+    async fn store_user_message(
+        &self,
+        message: &UserMessage,
+        parts: &[MessagePart],
+    ) -> Result<(), StorageError> {
+        self.store_message(
+            &message.session_id,
+            &message.id,
+            StoredMessage {
+                role: MessageRole::User,
+                metadata: None,
+                parts: Self::sorted_parts(parts),
+            },
+        )
+    }
+
+    async fn store_assistant_message(
+        &self,
+        message: &AssistantMessage,
+        parts: &[MessagePart],
+    ) -> Result<(), StorageError> {
+        self.store_message(
+            &message.session_id,
+            &message.id,
+            StoredMessage {
+                role: MessageRole::Assistant,
+                metadata: Some(message.metadata.clone()),
+                parts: Self::sorted_parts(parts),
+            },
+        )
+    }
+
+    async fn get_message(
+        &self,
+        session_id: &str,
+        message_id: &str,
+    ) -> Result<(MessageRole, Vec<MessagePart>), StorageError> {
+        let (role, parts, _) = self
+            .get_message_with_metadata(session_id, message_id)
+            .await?;
+        Ok((role, parts))
+    }
+
+    async fn get_message_with_metadata(
+        &self,
+        session_id: &str,
+        message_id: &str,
+    ) -> Result<(MessageRole, Vec<MessagePart>, Option<MessageMetadata>), StorageError> {
+        let mut state = self.lock()?;
+        Self::touch(&mut state, session_id)
+            .and_then(|entry| entry.messages.get(message_id))
+            .map(|message| {
+                (
+                    message.role,
+                    message.parts.clone(),
+                    message.metadata.clone(),
+                )
+            })
+            .ok_or_else(|| StorageError::NotFound(format!("Message not found: {}", message_id)))
+    }
+
+    async fn list_messages(
+        &self,
+        session_id: &str,
+        limit: Option<usize>,
+    ) -> Result<Vec<String>, StorageError> {
+        let mut state = self.lock()?;
+        let Some(entry) = Self::touch(&mut state, session_id) else {
+            return Ok(Vec::new());
+        };
+
+        // Keys are sorted by ID (chronological due to timestamp-based IDs)
+        Ok(entry
+            .messages
+            .keys()
+            .take(limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect())
+    }
+
+    // Editing Operations
+
+    async fn delete_message(&self, session_id: &str, message_id: &str) -> Result<(), StorageError> {
+        let mut state = self.lock()?;
+        Self::touch(&mut state, session_id)
+            .and_then(|entry| entry.messages.remove(message_id))
+            .map(|_| ())
+            .ok_or_else(|| StorageError::NotFound(format!("Message not found: {}", message_id)))
+    }
+
+    async fn update_message_parts(
+        &self,
+        session_id: &str,
+        message_id: &str,
+        parts: &[MessagePart],
+    ) -> Result<(), StorageError> {
+        let mut state = self.lock()?;
+        let message = Self::touch(&mut state, session_id)
+            .and_then(|entry| entry.messages.get_mut(message_id))
+            .ok_or_else(|| StorageError::NotFound(format!("Message not found: {}", message_id)))?;
+
+        message.parts = Self::sorted_parts(parts);
+        Ok(())
+    }
+
+    // Branching Operations
+
+    async fn fork_session(
+        &self,
+        session_id: &str,
+        at_message_id: &str,
+    ) -> Result<Session, StorageError> {
+        let mut state = self.lock()?;
+        let Some((parent_session, parent_messages)) = Self::touch(&mut state, session_id)
+            .and_then(|entry| Some((entry.session.clone()?, &entry.messages)))
+        else {
+            return Err(StorageError::NotFound(format!(
+                "Session not found: {}",
+                session_id
+            )));
+        };
+
+        if !parent_messages.contains_key(at_message_id) {
+            return Err(StorageError::NotFound(format!(
+                "Message not found: {}",
+                at_message_id
+            )));
+        }
+
+        // Copy messages with new IDs, keeping their order
+        let messages: BTreeMap<String, StoredMessage> = parent_messages
+            .range::<str, _>((Bound::Unbounded, Bound::Included(at_message_id)))
+            .map(|(_, message)| (IdGenerator::generate_message_id(), message.clone()))
+            .collect();
+
+        let mut session = Session::new(IdGenerator::generate_session_id());
+        session.title = parent_session.title;
+        session.metadata = parent_session
+            .metadata
+            .with_parent(session_id.to_string(), at_message_id.to_string());
+
+        let entry = self.touch_or_insert(&mut state, &session.id);
+        entry.session = Some(session.clone());
+        entry.messages = messages;
+
+        Ok(session)
+    }
+
+    async fn list_child_sessions(&self, session_id: &str) -> Result<Vec<Session>, StorageError> {
+        let state = self.lock()?;
+        let mut sessions: Vec<Session> = state
+            .sessions
+            .values()
+            .filter_map(|entry| entry.session.clone())
+            .filter(|session| session.metadata.parent_session_id.as_deref() == Some(session_id))
+            .collect();
+
+        // Sort by updated_at (most recent first)
+        sessions.sort_by(|a, b| b.updated_at.cmp(&a.updated_at).then(a.id.cmp(&b.id)));
+
+        Ok(sessions)
+    }
+
+    // Stream Operations
+
+    async fn store_stream(&self, stream: &StoredStream) -> Result<(), StorageError> {
+        let mut state = self.lock()?;
+        let chunks = Self::touch_stream(&mut state, &stream.id)
+            .map(|entry| std::mem::take(&mut entry.chunks))
+            .unwrap_or_default();
+        for entry in state.sessions.values_mut() {
+            entry.streams.remove(&stream.id);
+        }
+
+        let entry = self.touch_or_insert(&mut state, &stream.session_id);
+        entry.streams.insert(
+            stream.id.clone(),
+            StreamEntry {
+                stream: stream.clone(),
+                chunks,
+            },
+        );
+        Ok(())
+    }
+
+    async fn get_stream(&self, stream_id: &str) -> Result<StoredStream, StorageError> {
+        let mut state = self.lock()?;
+        Self::touch_stream(&mut state, stream_id)
+            .map(|entry| entry.stream.clone())
+            .ok_or_else(|| StorageError::NotFound(format!("Stream not found: {}", stream_id)))
+    }
+
+    async fn update_stream_status(
+        &self,
+        stream_id: &str,
+        expected: StreamStatus,
+        status: StreamStatus,
+    ) -> Result<bool, StorageError> {
+        let mut state = self.lock()?;
+        let entry = Self::touch_stream(&mut state, stream_id)
+            .ok_or_else(|| StorageError::NotFound(format!("Stream not found: {}", stream_id)))?;
+        if entry.stream.status != expected {
+            return Ok(false);
+        }
+        entry.stream = entry.stream.clone().with_status(status);
+        Ok(true)
+    }
+
+    async fn append_stream_chunks(
+        &self,
+        stream_id: &str,
+        chunks: &[serde_json::Value],
+    ) -> Result<(), StorageError> {
+        let mut state = self.lock()?;
+        let entry = Self::touch_stream(&mut state, stream_id)
+            .ok_or_else(|| StorageError::NotFound(format!("Stream not found: {}", stream_id)))?;
+        entry.chunks.extend_from_slice(chunks);
+        Ok(())
+    }
+
+    async fn get_stream_chunks(
+        &self,
+        stream_id: &str,
+        offset: usize,
+    ) -> Result<Vec<serde_json::Value>, StorageError> {
+        let mut state = self.lock()?;
+        let entry = Self::touch_stream(&mut state, stream_id)
+            .ok_or_else(|| StorageError::NotFound(format!("Stream not found: {}", stream_id)))?;
+        Ok(entry.chunks.iter().skip(offset).cloned().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TextPart, UsageStats};
+
+    fn text_message(
+        storage: &InMemoryStorage,
+        session_id: &str,
+    ) -> (UserMessage, Vec<MessagePart>) {
+        let part_id = storage.generate_part_id();
+        let parts = vec![MessagePart::Text(TextPart::new(
+            part_id.clone(),
+            "Hello".to_string(),
+        ))];
+        let message = UserMessage::new(
+            storage.generate_message_id(),
+            session_id.to_string(),
+            vec![part_id],
+        );
+        (message, parts)
+    }
+
+    #[tokio::test]
+    async fn test_store_and_get_session() {
+        let storage = InMemoryStorage::new();
+
+        let session_id = storage.generate_session_id();
+        let session = Session::new(session_id.clone()).with_title("Test Session".to_string());
+
+        storage.store_session(&session).await.unwrap();
+        let retrieved = storage.get_session(&session_id).await.unwrap();
+
+        assert_eq!(session.id, retrieved.id);
+        assert_eq!(session.title, retrieved.title);
+    }
+
+    #[tokio::test]
+    async fn test_store_and_get_assistant_message() {
+        let storage = InMemoryStorage::new();
+
+        let session_id = storage.generate_session_id();
+        let message_id = storage.generate_message_id();
+        let part_id = storage.generate_part_id();
+
+        let parts = vec![MessagePart::Text(TextPart::new(
+            part_id.clone(),
+            "Hello from AI".to_string(),
+        ))];
+        let metadata = MessageMetadata {
+            model_id: Some("gpt-4".to_string()),
+            provider: Some("openai".to_string()),
+            usage: Some(UsageStats::new(10, 20)),
+            finish_reason: Some("stop".to_string()),
+            custom: None,
+            ..Default::default()
+        };
+        let message = AssistantMessage::new(message_id.clone(), session_id.clone(), vec![part_id])
+            .with_metadata(metadata.clone());
+
+        storage
+            .store_assistant_message(&message, &parts)
+            .await
+            .unwrap();
+        let (role, retrieved_parts, retrieved_metadata) = storage
+            .get_message_with_metadata(&session_id, &message_id)
+            .await
+            .unwrap();
+
+        assert_eq!(role, MessageRole::Assistant);
+        assert_eq!(parts, retrieved_parts);
+        assert_eq!(retrieved_metadata, Some(metadata));
+    }
+
+    #[tokio::test]
+    async fn test_list_sessions_sorted_with_limit() {
+        let storage = InMemoryStorage::new();
+
+        for i in 0..3 {
+            let session =
+                Session::new(storage.generate_session_id()).with_title(format!("Session {}", i));
+            storage.store_session(&session).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        let sessions = storage.list_sessions(Some(2)).await.unwrap();
+        assert_eq!(sessions.len(), 2);
+        // Most recent first
+        assert_eq!(sessions[0].title, Some("Session 2".to_string()));
+        assert_eq!(sessions[1].title, Some("Session 1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_list_messages_sorted_with_limit() {
+        let storage = InMemoryStorage::new();
+        let session_id = storage.generate_session_id();
+
+        for _ in 0..5 {
+            let (message, parts) = text_message(&storage, &session_id);
+            storage.store_user_message(&message, &parts).await.unwrap();
+        }
+
+        let message_ids = storage.list_messages(&session_id, None).await.unwrap();
+        assert_eq!(message_ids.len(), 5);
+        assert!(message_ids.windows(2).all(|pair| pair[0] < pair[1]));
+
+        let limited = storage.list_messages(&session_id, Some(3)).await.unwrap();
+        assert_eq!(limited, message_ids[..3]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_session_removes_messages() {
+        let storage = InMemoryStorage::new();
+        let session_id = storage.generate_session_id();
+        storage
+            .store_session(&Session::new(session_id.clone()))
+            .await
+            .unwrap();
+        let (message, parts) = text_message(&storage, &session_id);
+        storage.store_user_message(&message, &parts).await.unwrap();
+
+        storage.delete_session(&session_id).await.unwrap();
+
+        assert!(matches!(
+            storage.get_session(&session_id).await,
+            Err(StorageError::NotFound(_))
+        ));
+        assert!(matches!(
+            storage.get_message(&session_id, &message.id).await,
+            Err(StorageError::NotFound(_))
+        ));
+        assert!(
+            storage
+                .list_messages(&session_id, None)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_message_from_other_session() {
+        let storage = InMemoryStorage::new();
+        let session_id = storage.generate_session_id();
+        let (message, parts) = text_message(&storage, &session_id);
+        storage.store_user_message(&message, &parts).await.unwrap();
+
+        let result = storage.get_message("other-session", &message.id).await;
+        assert!(matches!(result, Err(StorageError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_ttl_expires_idle_sessions() {
+        let storage = InMemoryStorage::new().with_ttl(Duration::from_millis(50));
+
+        let idle_id = storage.generate_session_id();
+        let active_id = storage.generate_session_id();
+        storage
+            .store_session(&Session::new(idle_id.clone()))
+            .await
+            .unwrap();
+        storage
+            .store_session(&Session::new(active_id.clone()))
+            .await
+            .unwrap();
+
+        // Reading the active session keeps it alive
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        storage.get_session(&active_id).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        assert!(storage.get_session(&active_id).await.is_ok());
+        assert!(matches!(
+            storage.get_session(&idle_id).await,
+            Err(StorageError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_max_sessions_evicts_least_recently_used() {
+        let storage = InMemoryStorage::new().with_max_sessions(2);
+
+        let first_id = storage.generate_session_id();
+        let second_id = storage.generate_session_id();
+        let third_id = storage.generate_session_id();
+        storage
+            .store_session(&Session::new(first_id.clone()))
+            .await
+            .unwrap();
+        storage
+            .store_session(&Session::new(second_id.clone()))
+            .await
+            .unwrap();
+
+        // Using the first session makes the second one the least recently used
+        storage.get_session(&first_id).await.unwrap();
+        let (message, parts) = text_message(&storage, &third_id);
+        storage.store_user_message(&message, &parts).await.unwrap();
+
+        assert!(storage.get_session(&first_id).await.is_ok());
+        assert!(storage.get_session(&second_id).await.is_err());
+        assert!(storage.get_message(&third_id, &message.id).await.is_ok());
+    }
+
+    #[cfg(feature = "testing")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_storage_conformance() {
+        crate::storage_conformance::run_all(std::sync::Arc::new(InMemoryStorage::new())).await;
+    }
+}
@@ -45,28 +45,76 @@ pub struct MessageMetadata {
     /// Custom metadata
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom: Option<serde_json::Value>,
+
+    /// ID of the stream that generated this message (for resumable streams)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stream_id: Option<String>,
+
+    /// Whether the message is partial because its stream was interrupted
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub incomplete: bool,
 }
 
 /// Token usage statistics for a message.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+///
+/// Reasoning tokens are part of the completion tokens, and cached input tokens are
+/// part of the prompt tokens.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct UsageStats {
     /// Number of tokens in the prompt
-    pub prompt_tokens: u32,
+    pub prompt_tokens: u64,
     /// Number of tokens in the completion
-    pub completion_tokens: u32,
+    pub completion_tokens: u64,
     /// Total number of tokens
-    pub total_tokens: u32,
+    pub total_tokens: u64,
+    /// Number of reasoning tokens
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub reasoning_tokens: u64,
+    /// Number of prompt tokens read from the provider's cache
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub cached_input_tokens: u64,
+}
+
+fn is_zero(n: &u64) -> bool {
+    *n == 0
+}
+
+fn is_false(b: &bool) -> bool {
+    !*b
 }
 
 impl UsageStats {
     /// Create new usage statistics.
-    pub fn new(prompt_tokens: u32, completion_tokens: u32) -> Self {
+    pub fn new(prompt_tokens: u64, completion_tokens: u64) -> Self {
         Self {
             prompt_tokens,
             completion_tokens,
             total_tokens: prompt_tokens + completion_tokens,
+            ..Default::default()
         }
     }
+
+    /// Set the number of reasoning tokens (builder pattern).
+    pub fn with_reasoning_tokens(mut self, reasoning_tokens: u64) -> Self {
+        self.reasoning_tokens = reasoning_tokens;
+        self
+    }
+
+    /// Set the number of cached input tokens (builder pattern).
+    pub fn with_cached_input_tokens(mut self, cached_input_tokens: u64) -> Self {
+        self.cached_input_tokens = cached_input_tokens;
+        self
+    }
+}
+
+impl std::ops::AddAssign<&UsageStats> for UsageStats {
+    fn add_assign(&mut self, other: &UsageStats) {
+        self.prompt_tokens += other.prompt_tokens;
+        self.completion_tokens += other.completion_tokens;
+        self.total_tokens += other.total_tokens;
+        self.reasoning_tokens += other.reasoning_tokens;
+        self.cached_input_tokens += other.cached_input_tokens;
+    }
 }
 
 #[cfg(test)]
@@ -95,6 +143,31 @@ mod tests {
         assert_eq!(usage.prompt_tokens, 100);
         assert_eq!(usage.completion_tokens, 50);
         assert_eq!(usage.total_tokens, 150);
+        assert_eq!(usage.reasoning_tokens, 0);
+        assert_eq!(usage.cached_input_tokens, 0);
+    }
+
+    #[test]
+    fn test_usage_stats_add_assign() {
+        let mut usage = UsageStats::new(100, 50).with_cached_input_tokens(80);
+        usage += &UsageStats::new(10, 20).with_reasoning_tokens(5);
+
+        assert_eq!(
+            usage,
+            UsageStats::new(110, 70)
+                .with_reasoning_tokens(5)
+                .with_cached_input_tokens(80)
+        );
+    }
+
+    #[test]
+    fn test_usage_stats_deserializes_without_detailed_counts() {
+        let usage: UsageStats = serde_json::from_str(
+            r#"{"prompt_tokens":100,"completion_tokens":50,"total_tokens":150}"#,
+        )
+        .unwrap();
+
+        assert_eq!(usage, UsageStats::new(100, 50));
     }
 
     #[test]
@@ -115,6 +188,7 @@ mod tests {
             usage: Some(UsageStats::new(100, 50)),
             finish_reason: Some("stop".to_string()),
             custom: None,
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&metadata).unwrap();
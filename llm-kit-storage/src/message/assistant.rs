@@ -80,6 +80,7 @@ mod tests {
             usage: Some(UsageStats::new(100, 50)),
             finish_reason: Some("stop".to_string()),
             custom: None,
+            ..Default::default()
         };
 
         let message = AssistantMessage::new(
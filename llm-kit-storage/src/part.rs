@@ -8,6 +8,10 @@ pub mod reasoning;
 pub mod source;
 /// Text content part.
 pub mod text;
+/// Tool approval request part.
+pub mod tool_approval_request;
+/// Tool approval response part.
+pub mod tool_approval_response;
 /// Tool call part.
 pub mod tool_call;
 /// Tool result part.
@@ -18,6 +22,8 @@ pub use image::{ImageData, ImageDimensions, ImagePart};
 pub use reasoning::ReasoningPart;
 pub use source::SourcePart;
 pub use text::TextPart;
+pub use tool_approval_request::ToolApprovalRequestPart;
+pub use tool_approval_response::ToolApprovalResponsePart;
 pub use tool_call::ToolCallPart;
 pub use tool_result::{ToolResultData, ToolResultPart};
 
@@ -58,6 +64,10 @@ pub enum MessagePart {
     ToolResult(ToolResultPart),
     /// Source reference
     Source(SourcePart),
+    /// Request to approve a tool call
+    ToolApprovalRequest(ToolApprovalRequestPart),
+    /// Decision on a tool approval request
+    ToolApprovalResponse(ToolApprovalResponsePart),
 }
 
 impl MessagePart {
@@ -71,6 +81,23 @@ impl MessagePart {
             MessagePart::ToolCall(p) => &p.id,
             MessagePart::ToolResult(p) => &p.id,
             MessagePart::Source(p) => &p.id,
+            MessagePart::ToolApprovalRequest(p) => &p.id,
+            MessagePart::ToolApprovalResponse(p) => &p.id,
+        }
+    }
+
+    /// Set the ID of this part regardless of variant.
+    pub fn set_id(&mut self, id: String) {
+        match self {
+            MessagePart::Text(p) => p.id = id,
+            MessagePart::Image(p) => p.id = id,
+            MessagePart::File(p) => p.id = id,
+            MessagePart::Reasoning(p) => p.id = id,
+            MessagePart::ToolCall(p) => p.id = id,
+            MessagePart::ToolResult(p) => p.id = id,
+            MessagePart::Source(p) => p.id = id,
+            MessagePart::ToolApprovalRequest(p) => p.id = id,
+            MessagePart::ToolApprovalResponse(p) => p.id = id,
         }
     }
 
@@ -84,6 +111,8 @@ impl MessagePart {
             MessagePart::ToolCall(p) => p.created_at,
             MessagePart::ToolResult(p) => p.created_at,
             MessagePart::Source(p) => p.created_at,
+            MessagePart::ToolApprovalRequest(p) => p.created_at,
+            MessagePart::ToolApprovalResponse(p) => p.created_at,
         }
     }
 }
@@ -98,6 +127,13 @@ mod tests {
         assert_eq!(part.id(), "test-id");
     }
 
+    #[test]
+    fn test_message_part_set_id() {
+        let mut part = MessagePart::Text(TextPart::new("test-id".to_string(), "hello".to_string()));
+        part.set_id("new-id".to_string());
+        assert_eq!(part.id(), "new-id");
+    }
+
     #[test]
     fn test_message_part_created_at() {
         let part = MessagePart::Text(TextPart::new("test-id".to_string(), "hello".to_string()));
@@ -138,4 +174,26 @@ mod tests {
         let json = serde_json::to_string(&part).unwrap();
         assert!(json.contains(r#""type":"tool-call""#));
     }
+
+    #[test]
+    fn test_message_part_serialization_tool_approval() {
+        let part = MessagePart::ToolApprovalRequest(ToolApprovalRequestPart::new(
+            "test-id".to_string(),
+            "approval-id".to_string(),
+            "call-id".to_string(),
+        ));
+        let json = serde_json::to_string(&part).unwrap();
+        assert!(json.contains(r#""type":"tool-approval-request""#));
+
+        let part = MessagePart::ToolApprovalResponse(ToolApprovalResponsePart::new(
+            "test-id".to_string(),
+            "approval-id".to_string(),
+            true,
+        ));
+        let json = serde_json::to_string(&part).unwrap();
+        assert!(json.contains(r#""type":"tool-approval-response""#));
+
+        let deserialized: MessagePart = serde_json::from_str(&json).unwrap();
+        assert_eq!(part, deserialized);
+    }
 }
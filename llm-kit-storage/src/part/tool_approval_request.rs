@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A tool approval request part within a message.
+///
+/// Records that a tool call of the same message needs the user's approval before it runs.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ToolApprovalRequestPart {
+    /// Unique identifier for this part
+    pub id: String,
+
+    /// Approval identifier (for matching with responses)
+    pub approval_id: String,
+
+    /// Tool call identifier of the call that needs approval
+    pub tool_call_id: String,
+
+    /// When this part was created
+    pub created_at: DateTime<Utc>,
+}
+
+impl ToolApprovalRequestPart {
+    /// Create a new tool approval request part with the given parameters.
+    pub fn new(id: String, approval_id: String, tool_call_id: String) -> Self {
+        Self {
+            id,
+            approval_id,
+            tool_call_id,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tool_approval_request_part_serialization() {
+        let part = ToolApprovalRequestPart::new(
+            "part-id".to_string(),
+            "approval-id".to_string(),
+            "call-id".to_string(),
+        );
+        let json = serde_json::to_string(&part).unwrap();
+        let deserialized: ToolApprovalRequestPart = serde_json::from_str(&json).unwrap();
+        assert_eq!(part, deserialized);
+    }
+}
@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A tool approval response part within a message.
+///
+/// Records the user's decision on a tool approval request.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ToolApprovalResponsePart {
+    /// Unique identifier for this part
+    pub id: String,
+
+    /// Approval identifier of the request this response answers
+    pub approval_id: String,
+
+    /// Whether the tool call was approved
+    pub approved: bool,
+
+    /// Optional reason for the decision
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+
+    /// When this part was created
+    pub created_at: DateTime<Utc>,
+}
+
+impl ToolApprovalResponsePart {
+    /// Create a new tool approval response part with the given parameters.
+    pub fn new(id: String, approval_id: String, approved: bool) -> Self {
+        Self {
+            id,
+            approval_id,
+            approved,
+            reason: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Set the reason for the decision.
+    pub fn with_reason(mut self, reason: String) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tool_approval_response_part_with_reason() {
+        let part =
+            ToolApprovalResponsePart::new("part-id".to_string(), "approval-id".to_string(), false)
+                .with_reason("Too dangerous".to_string());
+        assert!(!part.approved);
+        assert_eq!(part.reason.as_deref(), Some("Too dangerous"));
+
+        let json = serde_json::to_string(&part).unwrap();
+        let deserialized: ToolApprovalResponsePart = serde_json::from_str(&json).unwrap();
+        assert_eq!(part, deserialized);
+    }
+}
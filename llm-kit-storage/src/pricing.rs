@@ -0,0 +1,208 @@
+use crate::{MessageMetadata, UsageStats};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Prices of a model, in currency units per million tokens.
+///
+/// The currency is up to the caller; all prices of a [`PricingTable`] should use the same.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct ModelPricing {
+    /// Price per million prompt tokens
+    pub input_per_million: f64,
+
+    /// Price per million completion tokens, including reasoning tokens
+    pub output_per_million: f64,
+
+    /// Price per million cached prompt tokens; the input price if not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cached_input_per_million: Option<f64>,
+}
+
+impl ModelPricing {
+    /// Create pricing with the given input and output prices per million tokens.
+    pub fn new(input_per_million: f64, output_per_million: f64) -> Self {
+        Self {
+            input_per_million,
+            output_per_million,
+            cached_input_per_million: None,
+        }
+    }
+
+    /// Set the price per million cached prompt tokens (builder pattern).
+    pub fn with_cached_input_per_million(mut self, cached_input_per_million: f64) -> Self {
+        self.cached_input_per_million = Some(cached_input_per_million);
+        self
+    }
+
+    /// Cost of the given usage.
+    pub fn cost(&self, usage: &UsageStats) -> f64 {
+        let cached = usage.cached_input_tokens.min(usage.prompt_tokens);
+        let uncached = usage.prompt_tokens - cached;
+        let cached_price = self
+            .cached_input_per_million
+            .unwrap_or(self.input_per_million);
+
+        (uncached as f64 * self.input_per_million
+            + cached as f64 * cached_price
+            + usage.completion_tokens as f64 * self.output_per_million)
+            / 1_000_000.0
+    }
+}
+
+/// Model prices keyed by provider and model ID.
+///
+/// Providers are matched exactly or by their name before the first `.`, so a price
+/// for `openai` also applies to `openai.chat` and `openai.responses`. The table
+/// serializes as `{ provider: { model_id: pricing } }`, so it can be loaded from a
+/// configuration file.
+///
+/// # Example
+///
+/// ```
+/// use llm_kit_storage::{ModelPricing, PricingTable, UsageStats};
+///
+/// let pricing = PricingTable::new().with_price(
+///     "openai".to_string(),
+///     "gpt-4o".to_string(),
+///     ModelPricing::new(2.5, 10.0).with_cached_input_per_million(1.25),
+/// );
+///
+/// let cost = pricing
+///     .cost(Some("openai.chat"), "gpt-4o", &UsageStats::new(1_000, 500))
+///     .unwrap();
+/// assert!((cost - 0.0075).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct PricingTable {
+    prices: BTreeMap<String, BTreeMap<String, ModelPricing>>,
+}
+
+impl PricingTable {
+    /// Create an empty pricing table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or replace the price of a model (builder pattern).
+    pub fn with_price(mut self, provider: String, model_id: String, pricing: ModelPricing) -> Self {
+        self.prices
+            .entry(provider)
+            .or_default()
+            .insert(model_id, pricing);
+        self
+    }
+
+    /// Get the price of a model.
+    ///
+    /// Without a provider, the first provider with a price for the model is used.
+    pub fn get(&self, provider: Option<&str>, model_id: &str) -> Option<&ModelPricing> {
+        match provider {
+            Some(provider) => {
+                let prefix = provider.split('.').next().unwrap_or(provider);
+                [provider, prefix]
+                    .into_iter()
+                    .find_map(|key| self.prices.get(key)?.get(model_id))
+            }
+            None => self.prices.values().find_map(|models| models.get(model_id)),
+        }
+    }
+
+    /// Cost of the given usage of a model, or `None` if the model has no price.
+    pub fn cost(&self, provider: Option<&str>, model_id: &str, usage: &UsageStats) -> Option<f64> {
+        self.get(provider, model_id)
+            .map(|pricing| pricing.cost(usage))
+    }
+
+    /// Cost of a stored message, or `None` if it has no usage or its model has no price.
+    pub fn message_cost(&self, metadata: &MessageMetadata) -> Option<f64> {
+        self.cost(
+            metadata.provider.as_deref(),
+            metadata.model_id.as_deref()?,
+            metadata.usage.as_ref()?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_model_pricing_cost_with_cached_tokens() {
+        let pricing = ModelPricing::new(2.0, 8.0).with_cached_input_per_million(0.5);
+        let usage = UsageStats::new(1_000_000, 500_000).with_cached_input_tokens(400_000);
+
+        // 600k uncached * 2.0 + 400k cached * 0.5 + 500k output * 8.0
+        assert!((pricing.cost(&usage) - 5.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cached_tokens_default_to_input_price() {
+        let pricing = ModelPricing::new(2.0, 8.0);
+        let usage = UsageStats::new(1_000_000, 0).with_cached_input_tokens(400_000);
+
+        assert!((pricing.cost(&usage) - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_provider_matching() {
+        let table = PricingTable::new()
+            .with_price(
+                "openai".to_string(),
+                "gpt-4o".to_string(),
+                ModelPricing::new(2.5, 10.0),
+            )
+            .with_price(
+                "openai.responses".to_string(),
+                "gpt-4o".to_string(),
+                ModelPricing::new(1.0, 1.0),
+            );
+
+        assert_eq!(
+            table.get(Some("openai.chat"), "gpt-4o"),
+            Some(&ModelPricing::new(2.5, 10.0))
+        );
+        assert_eq!(
+            table.get(Some("openai.responses"), "gpt-4o"),
+            Some(&ModelPricing::new(1.0, 1.0))
+        );
+        assert!(table.get(None, "gpt-4o").is_some());
+        assert_eq!(table.get(Some("anthropic"), "gpt-4o"), None);
+        assert_eq!(table.get(Some("openai"), "gpt-5"), None);
+    }
+
+    #[test]
+    fn test_message_cost() {
+        let table = PricingTable::new().with_price(
+            "anthropic".to_string(),
+            "claude".to_string(),
+            ModelPricing::new(3.0, 15.0),
+        );
+        let metadata = MessageMetadata {
+            model_id: Some("claude".to_string()),
+            provider: Some("anthropic.messages".to_string()),
+            usage: Some(UsageStats::new(1_000_000, 100_000)),
+            ..Default::default()
+        };
+
+        assert!((table.message_cost(&metadata).unwrap() - 4.5).abs() < 1e-9);
+        assert_eq!(table.message_cost(&MessageMetadata::default()), None);
+    }
+
+    #[test]
+    fn test_pricing_table_serialization() {
+        let json = r#"{"openai":{"gpt-4o":{"input_per_million":2.5,"output_per_million":10.0}}}"#;
+
+        let table: PricingTable = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            table,
+            PricingTable::new().with_price(
+                "openai".to_string(),
+                "gpt-4o".to_string(),
+                ModelPricing::new(2.5, 10.0),
+            )
+        );
+    }
+}
@@ -0,0 +1,427 @@
+use crate::{MessageMetadata, MessagePart, MessageRole, Session, StorageError};
+use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+
+/// Filters for [`Storage::search_sessions`](crate::Storage::search_sessions).
+///
+/// All set filters must match; an empty query matches every session. Time ranges
+/// include their start and exclude their end.
+///
+/// # Example
+///
+/// ```
+/// use llm_kit_storage::SessionQuery;
+///
+/// let query = SessionQuery::new()
+///     .with_user_id("user-123".to_string())
+///     .with_tags(vec!["support".to_string()])
+///     .with_text("refund".to_string())
+///     .with_limit(20);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SessionQuery {
+    /// Only sessions of this user
+    pub user_id: Option<String>,
+
+    /// Only sessions that have all of these tags
+    pub tags: Vec<String>,
+
+    /// Only sessions created at or after this time
+    pub created_after: Option<DateTime<Utc>>,
+
+    /// Only sessions created before this time
+    pub created_before: Option<DateTime<Utc>>,
+
+    /// Only sessions updated at or after this time
+    pub updated_after: Option<DateTime<Utc>>,
+
+    /// Only sessions updated before this time
+    pub updated_before: Option<DateTime<Utc>>,
+
+    /// Only sessions with a message generated by this model
+    pub model_id: Option<String>,
+
+    /// Only sessions with a text part containing this string (case-insensitive)
+    pub text: Option<String>,
+
+    /// Maximum number of sessions per page
+    pub limit: Option<usize>,
+
+    /// Cursor of the page to return, from [`Page::next_cursor`]
+    pub cursor: Option<String>,
+}
+
+impl SessionQuery {
+    /// Create a query that matches every session.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match sessions of this user (builder pattern).
+    pub fn with_user_id(mut self, user_id: String) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// Only match sessions that have all of these tags (builder pattern).
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Only match sessions created at or after this time (builder pattern).
+    pub fn with_created_after(mut self, time: DateTime<Utc>) -> Self {
+        self.created_after = Some(time);
+        self
+    }
+
+    /// Only match sessions created before this time (builder pattern).
+    pub fn with_created_before(mut self, time: DateTime<Utc>) -> Self {
+        self.created_before = Some(time);
+        self
+    }
+
+    /// Only match sessions updated at or after this time (builder pattern).
+    pub fn with_updated_after(mut self, time: DateTime<Utc>) -> Self {
+        self.updated_after = Some(time);
+        self
+    }
+
+    /// Only match sessions updated before this time (builder pattern).
+    pub fn with_updated_before(mut self, time: DateTime<Utc>) -> Self {
+        self.updated_before = Some(time);
+        self
+    }
+
+    /// Only match sessions with a message generated by this model (builder pattern).
+    pub fn with_model_id(mut self, model_id: String) -> Self {
+        self.model_id = Some(model_id);
+        self
+    }
+
+    /// Only match sessions with a text part containing this string (builder pattern).
+    pub fn with_text(mut self, text: String) -> Self {
+        self.text = Some(text);
+        self
+    }
+
+    /// Set the maximum number of sessions per page (builder pattern).
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Continue after the page that returned this cursor (builder pattern).
+    pub fn with_cursor(mut self, cursor: String) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Whether the session's own fields match the query.
+    ///
+    /// The message filters (`model_id` and `text`) are not checked.
+    pub fn matches_session(&self, session: &Session) -> bool {
+        let metadata = &session.metadata;
+        let tags = metadata.tags.as_deref().unwrap_or_default();
+
+        self.user_id
+            .as_ref()
+            .is_none_or(|user_id| metadata.user_id.as_ref() == Some(user_id))
+            && self.tags.iter().all(|tag| tags.contains(tag))
+            && in_range(session.created_at, self.created_after, self.created_before)
+            && in_range(session.updated_at, self.updated_after, self.updated_before)
+    }
+
+    /// Whether the query filters sessions by the content of their messages.
+    pub fn has_message_filters(&self) -> bool {
+        self.model_id.is_some() || self.text.is_some()
+    }
+
+    /// Whether a message matches the message filters (`model_id` and `text`).
+    pub fn matches_message(
+        &self,
+        parts: &[MessagePart],
+        metadata: Option<&MessageMetadata>,
+    ) -> bool {
+        matches_model(self.model_id.as_deref(), metadata)
+            && matches_text(self.text.as_deref(), parts)
+    }
+}
+
+/// Filters for [`Storage::search_messages`](crate::Storage::search_messages).
+///
+/// All set filters must match; an empty query matches every message of every session.
+///
+/// # Example
+///
+/// ```
+/// use llm_kit_storage::{MessageQuery, MessageRole};
+///
+/// let query = MessageQuery::new()
+///     .with_session_id("ses_123".to_string())
+///     .with_role(MessageRole::Assistant)
+///     .with_text("invoice".to_string());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MessageQuery {
+    /// Only messages of this session; all sessions if not set
+    pub session_id: Option<String>,
+
+    /// Only messages with this role
+    pub role: Option<MessageRole>,
+
+    /// Only messages generated by this model
+    pub model_id: Option<String>,
+
+    /// Only messages with a text part containing this string (case-insensitive)
+    pub text: Option<String>,
+
+    /// Maximum number of messages per page
+    pub limit: Option<usize>,
+
+    /// Cursor of the page to return, from [`Page::next_cursor`]
+    pub cursor: Option<String>,
+}
+
+impl MessageQuery {
+    /// Create a query that matches every message.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match messages of this session (builder pattern).
+    pub fn with_session_id(mut self, session_id: String) -> Self {
+        self.session_id = Some(session_id);
+        self
+    }
+
+    /// Only match messages with this role (builder pattern).
+    pub fn with_role(mut self, role: MessageRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// Only match messages generated by this model (builder pattern).
+    pub fn with_model_id(mut self, model_id: String) -> Self {
+        self.model_id = Some(model_id);
+        self
+    }
+
+    /// Only match messages with a text part containing this string (builder pattern).
+    pub fn with_text(mut self, text: String) -> Self {
+        self.text = Some(text);
+        self
+    }
+
+    /// Set the maximum number of messages per page (builder pattern).
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Continue after the page that returned this cursor (builder pattern).
+    pub fn with_cursor(mut self, cursor: String) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Whether a message matches the role, model and text filters.
+    pub fn matches_message(
+        &self,
+        role: MessageRole,
+        parts: &[MessagePart],
+        metadata: Option<&MessageMetadata>,
+    ) -> bool {
+        self.role.is_none_or(|expected| expected == role)
+            && matches_model(self.model_id.as_deref(), metadata)
+            && matches_text(self.text.as_deref(), parts)
+    }
+}
+
+/// A message returned by [`Storage::search_messages`](crate::Storage::search_messages).
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageMatch {
+    /// Session containing the message
+    pub session_id: String,
+
+    /// Message identifier
+    pub message_id: String,
+
+    /// Role of the message sender
+    pub role: MessageRole,
+
+    /// Message parts in chronological order
+    pub parts: Vec<MessagePart>,
+
+    /// Message metadata (for assistant messages)
+    pub metadata: Option<MessageMetadata>,
+}
+
+/// One page of search results.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    /// The results of this page
+    pub items: Vec<T>,
+
+    /// Cursor for the next page, or `None` if this is the last page
+    pub next_cursor: Option<String>,
+}
+
+/// Position of a session in search results, which are sorted by most recent
+/// update first and then by ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SessionCursor {
+    updated_at: i64,
+    id: String,
+}
+
+impl SessionCursor {
+    /// The cursor pointing at the given session.
+    pub(crate) fn of(session: &Session) -> Self {
+        Self {
+            updated_at: session.updated_at.timestamp_micros(),
+            id: session.id.clone(),
+        }
+    }
+
+    /// Parse a cursor returned by [`SessionCursor::encode`].
+    pub(crate) fn parse(cursor: &str) -> Result<Self, StorageError> {
+        cursor
+            .split_once(':')
+            .and_then(|(updated_at, id)| {
+                Some(Self {
+                    updated_at: updated_at.parse().ok()?,
+                    id: id.to_string(),
+                })
+            })
+            .ok_or_else(|| StorageError::InvalidData(format!("Invalid cursor: {}", cursor)))
+    }
+
+    pub(crate) fn encode(&self) -> String {
+        format!("{}:{}", self.updated_at, self.id)
+    }
+
+    /// Compare two cursors in result order.
+    pub(crate) fn cmp_order(&self, other: &Self) -> Ordering {
+        other
+            .updated_at
+            .cmp(&self.updated_at)
+            .then_with(|| self.id.cmp(&other.id))
+    }
+}
+
+fn in_range(
+    time: DateTime<Utc>,
+    after: Option<DateTime<Utc>>,
+    before: Option<DateTime<Utc>>,
+) -> bool {
+    after.is_none_or(|after| time >= after) && before.is_none_or(|before| time < before)
+}
+
+fn matches_model(model_id: Option<&str>, metadata: Option<&MessageMetadata>) -> bool {
+    model_id.is_none_or(|model_id| {
+        metadata.and_then(|metadata| metadata.model_id.as_deref()) == Some(model_id)
+    })
+}
+
+fn matches_text(text: Option<&str>, parts: &[MessagePart]) -> bool {
+    text.is_none_or(|text| {
+        let needle = text.to_lowercase();
+        parts.iter().any(|part| match part {
+            MessagePart::Text(part) => part.text.to_lowercase().contains(&needle),
+            _ => false,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SessionMetadata, TextPart};
+    use chrono::Duration;
+
+    fn text(text: &str) -> Vec<MessagePart> {
+        vec![MessagePart::Text(TextPart::new(
+            "prt_1".to_string(),
+            text.to_string(),
+        ))]
+    }
+
+    #[test]
+    fn test_matches_session_metadata() {
+        let session = Session::new("ses_1".to_string()).with_metadata(
+            SessionMetadata::with_user_id("user-1".to_string())
+                .with_tags(vec!["a".to_string(), "b".to_string()]),
+        );
+
+        assert!(SessionQuery::new().matches_session(&session));
+        assert!(
+            SessionQuery::new()
+                .with_user_id("user-1".to_string())
+                .with_tags(vec!["b".to_string()])
+                .matches_session(&session)
+        );
+        assert!(
+            !SessionQuery::new()
+                .with_user_id("user-2".to_string())
+                .matches_session(&session)
+        );
+        assert!(
+            !SessionQuery::new()
+                .with_tags(vec!["a".to_string(), "c".to_string()])
+                .matches_session(&session)
+        );
+    }
+
+    #[test]
+    fn test_matches_session_time_range() {
+        let session = Session::new("ses_1".to_string());
+        let created = session.created_at;
+
+        assert!(
+            SessionQuery::new()
+                .with_created_after(created)
+                .with_created_before(created + Duration::seconds(1))
+                .matches_session(&session)
+        );
+        assert!(
+            !SessionQuery::new()
+                .with_created_before(created)
+                .matches_session(&session)
+        );
+        assert!(
+            !SessionQuery::new()
+                .with_updated_after(created + Duration::seconds(1))
+                .matches_session(&session)
+        );
+    }
+
+    #[test]
+    fn test_matches_message() {
+        let metadata = MessageMetadata {
+            model_id: Some("model-1".to_string()),
+            ..Default::default()
+        };
+        let parts = text("Where is my Invoice?");
+
+        let query = MessageQuery::new()
+            .with_role(MessageRole::Assistant)
+            .with_model_id("model-1".to_string())
+            .with_text("invoice".to_string());
+        assert!(query.matches_message(MessageRole::Assistant, &parts, Some(&metadata)));
+        assert!(!query.matches_message(MessageRole::User, &parts, Some(&metadata)));
+        assert!(!query.matches_message(MessageRole::Assistant, &parts, None));
+        assert!(!query.matches_message(MessageRole::Assistant, &text("Hello"), Some(&metadata)));
+    }
+
+    #[test]
+    fn test_session_cursor_round_trip() {
+        let cursor = SessionCursor::of(&Session::new("ses_1:2".to_string()));
+
+        assert_eq!(SessionCursor::parse(&cursor.encode()).unwrap(), cursor);
+        assert!(matches!(
+            SessionCursor::parse("not-a-cursor"),
+            Err(StorageError::InvalidData(_))
+        ));
+    }
+}
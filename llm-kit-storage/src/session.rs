@@ -64,6 +64,14 @@ pub struct SessionMetadata {
     /// Custom metadata
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom: Option<serde_json::Value>,
+
+    /// Session this session was forked from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_session_id: Option<String>,
+
+    /// Message of the parent session at which this session was forked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forked_from_message_id: Option<String>,
 }
 
 impl SessionMetadata {
@@ -71,8 +79,7 @@ impl SessionMetadata {
     pub fn with_user_id(user_id: String) -> Self {
         Self {
             user_id: Some(user_id),
-            tags: None,
-            custom: None,
+            ..Default::default()
         }
     }
 
@@ -87,6 +94,17 @@ impl SessionMetadata {
         self.custom = Some(custom);
         self
     }
+
+    /// Record the session and message this session was forked from (builder pattern).
+    pub fn with_parent(
+        mut self,
+        parent_session_id: String,
+        forked_from_message_id: String,
+    ) -> Self {
+        self.parent_session_id = Some(parent_session_id);
+        self.forked_from_message_id = Some(forked_from_message_id);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -156,4 +174,21 @@ mod tests {
         let deserialized: SessionMetadata = serde_json::from_str(&json).unwrap();
         assert_eq!(metadata, deserialized);
     }
+
+    #[test]
+    fn test_session_metadata_with_parent() {
+        let metadata = SessionMetadata::default()
+            .with_parent("ses_parent".to_string(), "msg_fork".to_string());
+        assert_eq!(metadata.parent_session_id, Some("ses_parent".to_string()));
+        assert_eq!(
+            metadata.forked_from_message_id,
+            Some("msg_fork".to_string())
+        );
+
+        // Metadata stored before branching existed has no parent
+        let deserialized: SessionMetadata =
+            serde_json::from_str(r#"{"user_id":"user-123"}"#).unwrap();
+        assert_eq!(deserialized.parent_session_id, None);
+        assert_eq!(deserialized.forked_from_message_id, None);
+    }
 }
@@ -1,5 +1,7 @@
+use crate::query::SessionCursor;
 use crate::{
-    AssistantMessage, MessageMetadata, MessagePart, MessageRole, Session, StorageError, UserMessage,
+    AssistantMessage, MessageMatch, MessageMetadata, MessagePart, MessageQuery, MessageRole, Page,
+    Session, SessionQuery, SessionUsage, StorageError, StoredStream, StreamStatus, UserMessage,
 };
 use async_trait::async_trait;
 
@@ -71,6 +73,15 @@ use async_trait::async_trait;
 /// #   async fn list_messages(&self, session_id: &str, limit: Option<usize>) -> Result<Vec<String>, StorageError> { todo!() }
 /// #   async fn list_sessions(&self, limit: Option<usize>) -> Result<Vec<Session>, StorageError> { todo!() }
 /// #   async fn delete_session(&self, session_id: &str) -> Result<(), StorageError> { todo!() }
+/// #   async fn delete_message(&self, session_id: &str, message_id: &str) -> Result<(), StorageError> { todo!() }
+/// #   async fn update_message_parts(&self, session_id: &str, message_id: &str, parts: &[MessagePart]) -> Result<(), StorageError> { todo!() }
+/// #   async fn fork_session(&self, session_id: &str, at_message_id: &str) -> Result<Session, StorageError> { todo!() }
+/// #   async fn list_child_sessions(&self, session_id: &str) -> Result<Vec<Session>, StorageError> { todo!() }
+/// #   async fn store_stream(&self, stream: &llm_kit_storage::StoredStream) -> Result<(), StorageError> { todo!() }
+/// #   async fn get_stream(&self, stream_id: &str) -> Result<llm_kit_storage::StoredStream, StorageError> { todo!() }
+/// #   async fn update_stream_status(&self, stream_id: &str, expected: llm_kit_storage::StreamStatus, status: llm_kit_storage::StreamStatus) -> Result<bool, StorageError> { todo!() }
+/// #   async fn append_stream_chunks(&self, stream_id: &str, chunks: &[serde_json::Value]) -> Result<(), StorageError> { todo!() }
+/// #   async fn get_stream_chunks(&self, stream_id: &str, offset: usize) -> Result<Vec<serde_json::Value>, StorageError> { todo!() }
 /// }
 /// ```
 #[async_trait]
@@ -243,8 +254,347 @@ pub trait Storage: Send + Sync {
     ///
     /// * `session_id` - The session identifier
     ///
+    /// Deleting a session that doesn't exist succeeds, so that deletes can be retried.
+    async fn delete_session(&self, session_id: &str) -> Result<(), StorageError>;
+
+    // Editing Operations
+
+    /// Delete a message and all its parts.
+    ///
+    /// Used to regenerate an answer or to truncate a conversation. The other
+    /// messages of the session are not affected.
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id` - The session containing the message
+    /// * `message_id` - The message identifier
+    ///
     /// # Errors
     ///
-    /// Returns `StorageError::NotFound` if the session doesn't exist.
-    async fn delete_session(&self, session_id: &str) -> Result<(), StorageError>;
+    /// Returns `StorageError::NotFound` if the message doesn't exist.
+    async fn delete_message(&self, session_id: &str, message_id: &str) -> Result<(), StorageError>;
+
+    /// Replace all parts of a message.
+    ///
+    /// Used to edit a prior turn. The previous parts are removed and the message's
+    /// part IDs are updated to the new parts; role and metadata are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id` - The session containing the message
+    /// * `message_id` - The message identifier
+    /// * `parts` - The new message parts
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::NotFound` if the message doesn't exist.
+    async fn update_message_parts(
+        &self,
+        session_id: &str,
+        message_id: &str,
+        parts: &[MessagePart],
+    ) -> Result<(), StorageError>;
+
+    // Branching Operations
+
+    /// Fork a session at a message.
+    ///
+    /// Creates a new session with a copy of the history up to and including
+    /// `at_message_id`. Copied messages get new IDs in the same order. The new
+    /// session keeps the title and metadata of the original and records it in
+    /// [`SessionMetadata::parent_session_id`](crate::SessionMetadata::parent_session_id)
+    /// and [`SessionMetadata::forked_from_message_id`](crate::SessionMetadata::forked_from_message_id).
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id` - The session to fork
+    /// * `at_message_id` - The last message to copy into the new session
+    ///
+    /// # Returns
+    ///
+    /// The new session.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::NotFound` if the session or the message doesn't exist.
+    async fn fork_session(
+        &self,
+        session_id: &str,
+        at_message_id: &str,
+    ) -> Result<Session, StorageError>;
+
+    /// List the sessions forked from a session.
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id` - The parent session identifier
+    ///
+    /// # Returns
+    ///
+    /// Direct child sessions sorted by most recent first (descending order by updated_at).
+    async fn list_child_sessions(&self, session_id: &str) -> Result<Vec<Session>, StorageError>;
+
+    // Stream Operations
+
+    /// Store a stream, replacing an existing stream with the same ID.
+    ///
+    /// Replacing a stream updates its status and keeps its chunks. Streams are
+    /// deleted with their session.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - The stream to store
+    async fn store_stream(&self, stream: &StoredStream) -> Result<(), StorageError>;
+
+    /// Retrieve a stream by ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::NotFound` if the stream doesn't exist.
+    async fn get_stream(&self, stream_id: &str) -> Result<StoredStream, StorageError>;
+
+    /// Set the status of a stream if it has the expected status, updating its timestamp.
+    ///
+    /// The check and the update are atomic, so when several callers move a stream
+    /// out of a status, only one of them succeeds. Updating an active stream to
+    /// [`StreamStatus::Active`] refreshes its timestamp as a heartbeat.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream_id` - The stream identifier
+    /// * `expected` - The status the stream must have
+    /// * `status` - The new status
+    ///
+    /// # Returns
+    ///
+    /// Whether the stream had the expected status and was updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::NotFound` if the stream doesn't exist.
+    async fn update_stream_status(
+        &self,
+        stream_id: &str,
+        expected: StreamStatus,
+        status: StreamStatus,
+    ) -> Result<bool, StorageError>;
+
+    /// Append chunks to a stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream_id` - The stream identifier
+    /// * `chunks` - Chunks to append, in order
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::NotFound` if the stream doesn't exist.
+    async fn append_stream_chunks(
+        &self,
+        stream_id: &str,
+        chunks: &[serde_json::Value],
+    ) -> Result<(), StorageError>;
+
+    /// Retrieve the chunks of a stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream_id` - The stream identifier
+    /// * `offset` - Number of chunks to skip
+    ///
+    /// # Returns
+    ///
+    /// The chunks from `offset` on, in the order they were appended.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::NotFound` if the stream doesn't exist.
+    async fn get_stream_chunks(
+        &self,
+        stream_id: &str,
+        offset: usize,
+    ) -> Result<Vec<serde_json::Value>, StorageError>;
+
+    // Usage Operations
+
+    /// Sum the token usage of a session.
+    ///
+    /// Adds up the usage stored in the metadata of the session's assistant messages,
+    /// one per generation step, in total and per model. Use [`SessionUsage::cost`]
+    /// with a [`PricingTable`](crate::PricingTable) to compute the cost.
+    ///
+    /// The default implementation reads every message of the session. Providers that
+    /// can aggregate in their query language should override it.
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id` - The session identifier
+    async fn session_usage(&self, session_id: &str) -> Result<SessionUsage, StorageError> {
+        let mut usage = SessionUsage::default();
+        for message_id in self.list_messages(session_id, None).await? {
+            match self
+                .get_message_with_metadata(session_id, &message_id)
+                .await
+            {
+                Ok((_, _, Some(metadata))) => usage.add(&metadata),
+                Ok(_) | Err(StorageError::NotFound(_)) => {}
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(usage)
+    }
+
+    // Search Operations
+
+    /// Search sessions by metadata, time range and message content.
+    ///
+    /// Sessions are returned most recently updated first. Pass the returned
+    /// [`Page::next_cursor`] as [`SessionQuery::cursor`] to get the next page.
+    ///
+    /// The default implementation scans all sessions and, for the `model_id` and
+    /// `text` filters, their messages. Providers with an index should override it.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The filters, page size and cursor
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::InvalidData` if the cursor is invalid.
+    async fn search_sessions(&self, query: &SessionQuery) -> Result<Page<Session>, StorageError> {
+        let cursor = query
+            .cursor
+            .as_deref()
+            .map(SessionCursor::parse)
+            .transpose()?;
+        let limit = query.limit.unwrap_or(usize::MAX);
+
+        let mut sessions: Vec<(SessionCursor, Session)> = self
+            .list_sessions(None)
+            .await?
+            .into_iter()
+            .map(|session| (SessionCursor::of(&session), session))
+            .filter(|(position, session)| {
+                cursor
+                    .as_ref()
+                    .is_none_or(|cursor| position.cmp_order(cursor).is_gt())
+                    && query.matches_session(session)
+            })
+            .collect();
+        sessions.sort_by(|(a, _), (b, _)| a.cmp_order(b));
+
+        let mut items = Vec::new();
+        for (_, session) in sessions {
+            if query.has_message_filters()
+                && !has_matching_message(self, &session.id, query).await?
+            {
+                continue;
+            }
+            if items.len() == limit {
+                // The next page continues after the last returned session
+                let next_cursor = items.last().map(|last| SessionCursor::of(last).encode());
+                return Ok(Page { items, next_cursor });
+            }
+            items.push(session);
+        }
+
+        Ok(Page {
+            items,
+            next_cursor: None,
+        })
+    }
+
+    /// Search messages by role, model and text content.
+    ///
+    /// Messages are returned in chronological order (by message ID). Pass the
+    /// returned [`Page::next_cursor`] as [`MessageQuery::cursor`] to get the next page.
+    ///
+    /// The default implementation scans the messages of the queried session, or of
+    /// all sessions. Providers with an index should override it.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The filters, page size and cursor
+    async fn search_messages(
+        &self,
+        query: &MessageQuery,
+    ) -> Result<Page<MessageMatch>, StorageError> {
+        let session_ids = match &query.session_id {
+            Some(session_id) => vec![session_id.clone()],
+            None => self
+                .list_sessions(None)
+                .await?
+                .into_iter()
+                .map(|session| session.id)
+                .collect(),
+        };
+        let limit = query.limit.unwrap_or(usize::MAX);
+
+        let mut candidates = Vec::new();
+        for session_id in session_ids {
+            for message_id in self.list_messages(&session_id, None).await? {
+                if query
+                    .cursor
+                    .as_ref()
+                    .is_none_or(|cursor| &message_id > cursor)
+                {
+                    candidates.push((message_id, session_id.clone()));
+                }
+            }
+        }
+        candidates.sort();
+
+        let mut items: Vec<MessageMatch> = Vec::new();
+        for (message_id, session_id) in candidates {
+            let (role, parts, metadata) = match self
+                .get_message_with_metadata(&session_id, &message_id)
+                .await
+            {
+                Ok(message) => message,
+                // Deleted since it was listed
+                Err(StorageError::NotFound(_)) => continue,
+                Err(error) => return Err(error),
+            };
+            if !query.matches_message(role, &parts, metadata.as_ref()) {
+                continue;
+            }
+            if items.len() == limit {
+                let next_cursor = items.last().map(|last| last.message_id.clone());
+                return Ok(Page { items, next_cursor });
+            }
+            items.push(MessageMatch {
+                session_id,
+                message_id,
+                role,
+                parts,
+                metadata,
+            });
+        }
+
+        Ok(Page {
+            items,
+            next_cursor: None,
+        })
+    }
+}
+
+/// Whether a session has a message matching the message filters of a query.
+async fn has_matching_message<S: Storage + ?Sized>(
+    storage: &S,
+    session_id: &str,
+    query: &SessionQuery,
+) -> Result<bool, StorageError> {
+    for message_id in storage.list_messages(session_id, None).await? {
+        match storage
+            .get_message_with_metadata(session_id, &message_id)
+            .await
+        {
+            Ok((_, parts, metadata)) if query.matches_message(&parts, metadata.as_ref()) => {
+                return Ok(true);
+            }
+            Ok(_) | Err(StorageError::NotFound(_)) => {}
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(false)
 }
@@ -0,0 +1,1019 @@
+//! Conformance tests for [`Storage`] implementations.
+//!
+//! Each function exercises one aspect of the [`Storage`] contract against the given
+//! storage and panics with a descriptive message when the implementation deviates
+//! from the behavior of the built-in providers. The checks only touch sessions they
+//! create themselves, so they can run against a storage that already holds data.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use llm_kit_storage::storage_conformance;
+//! use std::sync::Arc;
+//!
+//! #[tokio::test]
+//! async fn test_storage_conformance() {
+//!     let storage = Arc::new(MyStorage::new());
+//!     storage_conformance::run_all(storage).await;
+//! }
+//! ```
+
+use crate::{
+    AssistantMessage, FileData, FilePart, ImageData, ImagePart, MessageMetadata, MessagePart,
+    MessageQuery, MessageRole, ReasoningPart, Session, SessionMetadata, SessionQuery, SourcePart,
+    Storage, StorageError, StoredStream, StreamStatus, TextPart, ToolCallPart, ToolResultPart,
+    UsageStats, UserMessage,
+};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+/// Run every conformance check against the given storage.
+///
+/// # Panics
+///
+/// Panics if the storage doesn't conform.
+pub async fn run_all(storage: Arc<dyn Storage>) {
+    generated_ids_are_unique_and_ordered(Arc::clone(&storage)).await;
+    session_round_trip(Arc::clone(&storage)).await;
+    list_sessions_ordering(Arc::clone(&storage)).await;
+    list_messages_ordering(Arc::clone(&storage)).await;
+    message_part_variants_round_trip(Arc::clone(&storage)).await;
+    message_metadata_round_trip(Arc::clone(&storage)).await;
+    delete_session_cascades(Arc::clone(&storage)).await;
+    not_found_errors(Arc::clone(&storage)).await;
+    invalid_data_errors(Arc::clone(&storage)).await;
+    delete_message_removes_message(Arc::clone(&storage)).await;
+    update_message_parts_replaces_parts(Arc::clone(&storage)).await;
+    fork_session_copies_history(Arc::clone(&storage)).await;
+    search_sessions_filters_and_paginates(Arc::clone(&storage)).await;
+    search_messages_filters_and_paginates(Arc::clone(&storage)).await;
+    stream_chunks_round_trip(Arc::clone(&storage)).await;
+    update_stream_status_is_conditional(Arc::clone(&storage)).await;
+    concurrent_writes(storage).await;
+}
+
+/// Generated IDs are unique, message and part IDs ascend, and session IDs descend.
+///
+/// # Panics
+///
+/// Panics if the storage doesn't conform.
+pub async fn generated_ids_are_unique_and_ordered(storage: Arc<dyn Storage>) {
+    let sessions: Vec<String> = (0..3).map(|_| storage.generate_session_id()).collect();
+    let messages: Vec<String> = (0..3).map(|_| storage.generate_message_id()).collect();
+    let parts: Vec<String> = (0..3).map(|_| storage.generate_part_id()).collect();
+
+    assert!(
+        sessions.windows(2).all(|pair| pair[0] > pair[1]),
+        "session IDs should sort newest first: {:?}",
+        sessions
+    );
+    assert!(
+        messages.windows(2).all(|pair| pair[0] < pair[1]),
+        "message IDs should sort oldest first: {:?}",
+        messages
+    );
+    assert!(
+        parts.windows(2).all(|pair| pair[0] < pair[1]),
+        "part IDs should sort oldest first: {:?}",
+        parts
+    );
+}
+
+/// A stored session is returned unchanged, and storing it again updates it.
+///
+/// # Panics
+///
+/// Panics if the storage doesn't conform.
+pub async fn session_round_trip(storage: Arc<dyn Storage>) {
+    let session = Session::new(storage.generate_session_id())
+        .with_title("Conformance".to_string())
+        .with_metadata(
+            SessionMetadata::with_user_id("user-1".to_string())
+                .with_tags(vec!["a".to_string(), "b".to_string()])
+                .with_custom(serde_json::json!({ "plan": "pro" })),
+        );
+
+    storage.store_session(&session).await.unwrap();
+    let retrieved = storage.get_session(&session.id).await.unwrap();
+    assert_eq!(retrieved, session, "session should round-trip unchanged");
+
+    let mut updated = session.with_title("Renamed".to_string());
+    updated.updated_at += Duration::seconds(1);
+    storage.store_session(&updated).await.unwrap();
+    let retrieved = storage.get_session(&updated.id).await.unwrap();
+    assert_eq!(
+        retrieved, updated,
+        "storing a session again should update it"
+    );
+}
+
+/// Sessions are listed most recently updated first, and the limit keeps the most recent.
+///
+/// # Panics
+///
+/// Panics if the storage doesn't conform.
+pub async fn list_sessions_ordering(storage: Arc<dyn Storage>) {
+    let now = Utc::now();
+    let mut ids = Vec::new();
+
+    // Stored out of order; offsets are minutes in the past
+    for offset in [2, 0, 1] {
+        let mut session = Session::new(storage.generate_session_id());
+        session.updated_at = now - Duration::minutes(offset);
+        storage.store_session(&session).await.unwrap();
+        ids.push((offset, session.id));
+    }
+    ids.sort();
+    let expected: Vec<String> = ids.into_iter().map(|(_, id)| id).collect();
+
+    let all = storage.list_sessions(None).await.unwrap();
+    let listed: Vec<String> = all
+        .iter()
+        .map(|session| session.id.clone())
+        .filter(|id| expected.contains(id))
+        .collect();
+    assert_eq!(
+        listed, expected,
+        "sessions should be listed most recently updated first"
+    );
+    assert!(
+        all.windows(2)
+            .all(|pair| pair[0].updated_at >= pair[1].updated_at),
+        "sessions should be sorted by updated_at, descending"
+    );
+
+    let limited = storage.list_sessions(Some(2)).await.unwrap();
+    assert_eq!(limited.len(), 2, "limit should cap the number of sessions");
+    assert_eq!(
+        limited[..],
+        all[..2],
+        "limit should keep the most recently updated sessions"
+    );
+}
+
+/// Messages are listed in the order they were created, and the limit keeps the oldest.
+///
+/// # Panics
+///
+/// Panics if the storage doesn't conform.
+pub async fn list_messages_ordering(storage: Arc<dyn Storage>) {
+    let session_id = storage.generate_session_id();
+    let mut expected = Vec::new();
+
+    for i in 0..5 {
+        let message = store_text_message(&storage, &session_id, &format!("Message {}", i)).await;
+        expected.push(message.id);
+    }
+
+    let listed = storage.list_messages(&session_id, None).await.unwrap();
+    assert_eq!(listed, expected, "messages should be listed oldest first");
+
+    let limited = storage.list_messages(&session_id, Some(3)).await.unwrap();
+    assert_eq!(
+        limited,
+        expected[..3],
+        "limit should keep the oldest messages"
+    );
+}
+
+/// Every [`MessagePart`] variant round-trips unchanged and in order.
+///
+/// # Panics
+///
+/// Panics if the storage doesn't conform.
+pub async fn message_part_variants_round_trip(storage: Arc<dyn Storage>) {
+    let session_id = storage.generate_session_id();
+    let message_id = storage.generate_message_id();
+
+    let parts = vec![
+        MessagePart::Text(TextPart::new(
+            storage.generate_part_id(),
+            "Hello".to_string(),
+        )),
+        MessagePart::Image(
+            ImagePart::new(
+                storage.generate_part_id(),
+                "image/png".to_string(),
+                ImageData::Base64 {
+                    data: "aGVsbG8=".to_string(),
+                },
+            )
+            .with_dimensions(64, 32),
+        ),
+        MessagePart::Image(ImagePart::new(
+            storage.generate_part_id(),
+            "image/jpeg".to_string(),
+            ImageData::Url {
+                url: "https://example.com/cat.jpg".to_string(),
+            },
+        )),
+        MessagePart::File(
+            FilePart::new(
+                storage.generate_part_id(),
+                "application/pdf".to_string(),
+                FileData::Binary {
+                    data: vec![0, 1, 2, 255],
+                },
+            )
+            .with_filename("report.pdf".to_string()),
+        ),
+        MessagePart::Reasoning({
+            let mut reasoning =
+                ReasoningPart::new(storage.generate_part_id(), "Thinking".to_string());
+            reasoning.provider_metadata = Some(serde_json::json!({ "signature": "abc" }));
+            reasoning
+        }),
+        MessagePart::ToolCall(ToolCallPart::new(
+            storage.generate_part_id(),
+            "call-1".to_string(),
+            "search".to_string(),
+            serde_json::json!({ "query": "rust", "limit": 3 }),
+        )),
+        MessagePart::ToolResult(ToolResultPart::new_success(
+            storage.generate_part_id(),
+            "call-1".to_string(),
+            "search".to_string(),
+            serde_json::json!(["result"]),
+        )),
+        MessagePart::ToolResult(ToolResultPart::new_error(
+            storage.generate_part_id(),
+            "call-2".to_string(),
+            "fetch".to_string(),
+            "timeout".to_string(),
+        )),
+        MessagePart::Source(
+            SourcePart::new(
+                storage.generate_part_id(),
+                "https://example.com".to_string(),
+            )
+            .with_title("Example".to_string()),
+        ),
+    ];
+    let part_ids = parts.iter().map(|part| part.id().to_string()).collect();
+    let message = AssistantMessage::new(message_id.clone(), session_id.clone(), part_ids);
+
+    storage
+        .store_assistant_message(&message, &parts)
+        .await
+        .unwrap();
+    let (role, retrieved) = storage.get_message(&session_id, &message_id).await.unwrap();
+
+    assert_eq!(role, MessageRole::Assistant);
+    assert_eq!(
+        retrieved, parts,
+        "parts should round-trip unchanged and in order"
+    );
+}
+
+/// Assistant messages return their metadata; user messages return none.
+///
+/// # Panics
+///
+/// Panics if the storage doesn't conform.
+pub async fn message_metadata_round_trip(storage: Arc<dyn Storage>) {
+    let session_id = storage.generate_session_id();
+
+    let user_message = store_text_message(&storage, &session_id, "Hi").await;
+    let (role, parts, metadata) = storage
+        .get_message_with_metadata(&session_id, &user_message.id)
+        .await
+        .unwrap();
+    assert_eq!(role, MessageRole::User);
+    assert_eq!(parts.len(), 1);
+    assert_eq!(metadata, None, "user messages should have no metadata");
+
+    let metadata = MessageMetadata {
+        model_id: Some("model-1".to_string()),
+        provider: Some("provider-1".to_string()),
+        usage: Some(UsageStats::new(10, 20)),
+        finish_reason: Some("stop".to_string()),
+        custom: Some(serde_json::json!({ "latency_ms": 120 })),
+        stream_id: Some("str_1".to_string()),
+        incomplete: true,
+    };
+    let part = MessagePart::Text(TextPart::new(
+        storage.generate_part_id(),
+        "Hello".to_string(),
+    ));
+    let message = AssistantMessage::new(
+        storage.generate_message_id(),
+        session_id.clone(),
+        vec![part.id().to_string()],
+    )
+    .with_metadata(metadata.clone());
+
+    storage
+        .store_assistant_message(&message, &[part])
+        .await
+        .unwrap();
+    let (role, _, retrieved) = storage
+        .get_message_with_metadata(&session_id, &message.id)
+        .await
+        .unwrap();
+    assert_eq!(role, MessageRole::Assistant);
+    assert_eq!(
+        retrieved,
+        Some(metadata),
+        "assistant metadata should round-trip unchanged"
+    );
+}
+
+/// Deleting a session removes its messages, and deleting an unknown session succeeds.
+///
+/// # Panics
+///
+/// Panics if the storage doesn't conform.
+pub async fn delete_session_cascades(storage: Arc<dyn Storage>) {
+    let session = Session::new(storage.generate_session_id());
+    storage.store_session(&session).await.unwrap();
+    let message = store_text_message(&storage, &session.id, "Bye").await;
+
+    let other_session_id = storage.generate_session_id();
+    let other_message = store_text_message(&storage, &other_session_id, "Stay").await;
+
+    storage.delete_session(&session.id).await.unwrap();
+
+    assert!(matches!(
+        storage.get_session(&session.id).await,
+        Err(StorageError::NotFound(_))
+    ));
+    assert!(matches!(
+        storage.get_message(&session.id, &message.id).await,
+        Err(StorageError::NotFound(_))
+    ));
+    assert!(
+        storage
+            .list_messages(&session.id, None)
+            .await
+            .unwrap()
+            .is_empty(),
+        "messages of a deleted session should be removed"
+    );
+    assert!(
+        storage
+            .get_message(&other_session_id, &other_message.id)
+            .await
+            .is_ok(),
+        "deleting a session should not affect other sessions"
+    );
+
+    storage
+        .delete_session(&storage.generate_session_id())
+        .await
+        .expect("deleting an unknown session should succeed");
+}
+
+/// Lookups of missing data fail with [`StorageError::NotFound`]; listings return nothing.
+///
+/// # Panics
+///
+/// Panics if the storage doesn't conform.
+pub async fn not_found_errors(storage: Arc<dyn Storage>) {
+    let session_id = storage.generate_session_id();
+    let message_id = storage.generate_message_id();
+
+    let error = storage.get_session(&session_id).await.unwrap_err();
+    assert!(
+        matches!(error, StorageError::NotFound(_)),
+        "missing session should be NotFound, got {:?}",
+        error
+    );
+
+    let error = storage
+        .get_message(&session_id, &message_id)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(error, StorageError::NotFound(_)),
+        "missing message should be NotFound, got {:?}",
+        error
+    );
+
+    let error = storage
+        .get_message_with_metadata(&session_id, &message_id)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(error, StorageError::NotFound(_)),
+        "missing message should be NotFound, got {:?}",
+        error
+    );
+
+    // A message is only visible through the session it belongs to
+    let message = store_text_message(&storage, &session_id, "Private").await;
+    let error = storage
+        .get_message(&storage.generate_session_id(), &message.id)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(error, StorageError::NotFound(_)),
+        "message of another session should be NotFound, got {:?}",
+        error
+    );
+
+    assert!(
+        storage
+            .list_messages(&storage.generate_session_id(), None)
+            .await
+            .unwrap()
+            .is_empty(),
+        "listing messages of an unknown session should return nothing"
+    );
+}
+
+/// Malformed input fails with [`StorageError::InvalidData`].
+///
+/// # Panics
+///
+/// Panics if the storage doesn't conform.
+pub async fn invalid_data_errors(storage: Arc<dyn Storage>) {
+    let query = SessionQuery::new().with_cursor("not a cursor".to_string());
+    let error = storage.search_sessions(&query).await.unwrap_err();
+    assert!(
+        matches!(error, StorageError::InvalidData(_)),
+        "malformed session cursor should be InvalidData, got {:?}",
+        error
+    );
+}
+
+/// Stream chunks are returned in order from an offset, survive status updates, and
+/// are deleted with their session.
+///
+/// # Panics
+///
+/// Panics if the storage doesn't conform.
+pub async fn stream_chunks_round_trip(storage: Arc<dyn Storage>) {
+    let session = Session::new(storage.generate_session_id());
+    storage.store_session(&session).await.unwrap();
+    let stream_id = format!("str_{}", storage.generate_part_id());
+
+    let error = storage.get_stream(&stream_id).await.unwrap_err();
+    assert!(
+        matches!(error, StorageError::NotFound(_)),
+        "missing stream should be NotFound, got {:?}",
+        error
+    );
+    let error = storage
+        .append_stream_chunks(&stream_id, &[serde_json::json!({ "type": "start" })])
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(error, StorageError::NotFound(_)),
+        "appending to a missing stream should be NotFound, got {:?}",
+        error
+    );
+
+    let stream = StoredStream::new(stream_id.clone(), session.id.clone());
+    storage.store_stream(&stream).await.unwrap();
+    assert_eq!(storage.get_stream(&stream_id).await.unwrap(), stream);
+
+    let chunks: Vec<serde_json::Value> = (0..5)
+        .map(|index| serde_json::json!({ "type": "text-delta", "text": index.to_string() }))
+        .collect();
+    storage
+        .append_stream_chunks(&stream_id, &chunks[..2])
+        .await
+        .unwrap();
+    storage
+        .append_stream_chunks(&stream_id, &chunks[2..])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        storage.get_stream_chunks(&stream_id, 0).await.unwrap(),
+        chunks,
+        "chunks should be returned in append order"
+    );
+    assert_eq!(
+        storage.get_stream_chunks(&stream_id, 3).await.unwrap(),
+        chunks[3..],
+        "chunks should be returned from the offset"
+    );
+    assert!(
+        storage
+            .get_stream_chunks(&stream_id, 10)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    let completed = stream.with_status(StreamStatus::Completed);
+    storage.store_stream(&completed).await.unwrap();
+    assert_eq!(storage.get_stream(&stream_id).await.unwrap(), completed);
+    assert_eq!(
+        storage
+            .get_stream_chunks(&stream_id, 0)
+            .await
+            .unwrap()
+            .len(),
+        5,
+        "storing a stream again should keep its chunks"
+    );
+
+    storage.delete_session(&session.id).await.unwrap();
+    assert!(
+        matches!(
+            storage.get_stream(&stream_id).await,
+            Err(StorageError::NotFound(_))
+        ),
+        "streams of a deleted session should be removed"
+    );
+}
+
+/// A stream status is only updated from the expected status, by one of several
+/// concurrent callers, and the update refreshes the stream's timestamp.
+///
+/// # Panics
+///
+/// Panics if the storage doesn't conform.
+pub async fn update_stream_status_is_conditional(storage: Arc<dyn Storage>) {
+    const UPDATERS: usize = 8;
+
+    let session = Session::new(storage.generate_session_id());
+    storage.store_session(&session).await.unwrap();
+    let stream_id = format!("str_{}", storage.generate_part_id());
+
+    let error = storage
+        .update_stream_status(&stream_id, StreamStatus::Active, StreamStatus::Completed)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(error, StorageError::NotFound(_)),
+        "updating a missing stream should be NotFound, got {:?}",
+        error
+    );
+
+    let stream = StoredStream::new(stream_id.clone(), session.id.clone());
+    storage.store_stream(&stream).await.unwrap();
+
+    assert!(
+        storage
+            .update_stream_status(&stream_id, StreamStatus::Active, StreamStatus::Active)
+            .await
+            .unwrap()
+    );
+    let refreshed = storage.get_stream(&stream_id).await.unwrap();
+    assert_eq!(refreshed.status, StreamStatus::Active);
+    assert!(
+        refreshed.updated_at >= stream.updated_at,
+        "a heartbeat should refresh the timestamp"
+    );
+
+    let mut handles = Vec::with_capacity(UPDATERS);
+    for _ in 0..UPDATERS {
+        let storage = Arc::clone(&storage);
+        let stream_id = stream_id.clone();
+        handles.push(tokio::spawn(async move {
+            storage
+                .update_stream_status(&stream_id, StreamStatus::Active, StreamStatus::Interrupted)
+                .await
+                .unwrap()
+        }));
+    }
+    let mut updated = 0;
+    for handle in handles {
+        updated += usize::from(handle.await.expect("updater task panicked"));
+    }
+    assert_eq!(updated, 1, "only one concurrent update should succeed");
+    assert_eq!(
+        storage.get_stream(&stream_id).await.unwrap().status,
+        StreamStatus::Interrupted
+    );
+
+    assert!(
+        !storage
+            .update_stream_status(&stream_id, StreamStatus::Active, StreamStatus::Completed)
+            .await
+            .unwrap(),
+        "a stream without the expected status should be left alone"
+    );
+    assert_eq!(
+        storage.get_stream(&stream_id).await.unwrap().status,
+        StreamStatus::Interrupted
+    );
+
+    storage.delete_session(&session.id).await.unwrap();
+}
+
+/// Deleting a message removes it and its parts, and leaves the other messages.
+///
+/// # Panics
+///
+/// Panics if the storage doesn't conform.
+pub async fn delete_message_removes_message(storage: Arc<dyn Storage>) {
+    let session_id = storage.generate_session_id();
+    let first = store_text_message(&storage, &session_id, "Keep").await;
+    let second = store_text_message(&storage, &session_id, "Delete").await;
+
+    storage
+        .delete_message(&session_id, &second.id)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        storage.get_message(&session_id, &second.id).await,
+        Err(StorageError::NotFound(_))
+    ));
+    assert_eq!(
+        storage.list_messages(&session_id, None).await.unwrap(),
+        vec![first.id],
+        "only the deleted message should be removed"
+    );
+
+    let error = storage
+        .delete_message(&session_id, &second.id)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(error, StorageError::NotFound(_)),
+        "deleting a missing message should be NotFound, got {:?}",
+        error
+    );
+}
+
+/// Updating the parts of a message replaces them and keeps its role and metadata.
+///
+/// # Panics
+///
+/// Panics if the storage doesn't conform.
+pub async fn update_message_parts_replaces_parts(storage: Arc<dyn Storage>) {
+    let session_id = storage.generate_session_id();
+    let metadata = MessageMetadata {
+        model_id: Some("model-1".to_string()),
+        ..Default::default()
+    };
+    let part = MessagePart::Text(TextPart::new(
+        storage.generate_part_id(),
+        "Draft".to_string(),
+    ));
+    let message = AssistantMessage::new(
+        storage.generate_message_id(),
+        session_id.clone(),
+        vec![part.id().to_string()],
+    )
+    .with_metadata(metadata.clone());
+    storage
+        .store_assistant_message(&message, &[part])
+        .await
+        .unwrap();
+
+    let parts = vec![
+        MessagePart::Text(TextPart::new(
+            storage.generate_part_id(),
+            "Final".to_string(),
+        )),
+        MessagePart::Source(SourcePart::new(
+            storage.generate_part_id(),
+            "https://example.com".to_string(),
+        )),
+    ];
+    storage
+        .update_message_parts(&session_id, &message.id, &parts)
+        .await
+        .unwrap();
+
+    let (role, retrieved, retrieved_metadata) = storage
+        .get_message_with_metadata(&session_id, &message.id)
+        .await
+        .unwrap();
+    assert_eq!(role, MessageRole::Assistant);
+    assert_eq!(retrieved, parts, "parts should be replaced");
+    assert_eq!(
+        retrieved_metadata,
+        Some(metadata),
+        "updating parts should keep the metadata"
+    );
+
+    let error = storage
+        .update_message_parts(&session_id, &storage.generate_message_id(), &parts)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(error, StorageError::NotFound(_)),
+        "updating a missing message should be NotFound, got {:?}",
+        error
+    );
+}
+
+/// Forking copies the history up to the fork point into a new, linked session.
+///
+/// # Panics
+///
+/// Panics if the storage doesn't conform.
+pub async fn fork_session_copies_history(storage: Arc<dyn Storage>) {
+    let parent = Session::new(storage.generate_session_id()).with_title("Parent".to_string());
+    storage.store_session(&parent).await.unwrap();
+    let mut messages = Vec::new();
+    for i in 0..3 {
+        messages.push(store_text_message(&storage, &parent.id, &format!("Message {}", i)).await);
+    }
+
+    let fork = storage
+        .fork_session(&parent.id, &messages[1].id)
+        .await
+        .unwrap();
+
+    assert_ne!(fork.id, parent.id);
+    assert_eq!(fork.title, parent.title, "a fork should keep the title");
+    assert_eq!(
+        fork.metadata.parent_session_id.as_deref(),
+        Some(parent.id.as_str())
+    );
+    assert_eq!(
+        fork.metadata.forked_from_message_id.as_deref(),
+        Some(messages[1].id.as_str())
+    );
+    assert_eq!(storage.get_session(&fork.id).await.unwrap(), fork);
+
+    let copied = storage.list_messages(&fork.id, None).await.unwrap();
+    assert_eq!(
+        copied.len(),
+        2,
+        "messages up to and including the fork point should be copied"
+    );
+    for (copy_id, original) in copied.iter().zip(&messages) {
+        assert_ne!(copy_id, &original.id, "copied messages should get new IDs");
+        let (role, parts) = storage.get_message(&fork.id, copy_id).await.unwrap();
+        let (_, original_parts) = storage.get_message(&parent.id, &original.id).await.unwrap();
+        assert_eq!(role, MessageRole::User);
+        assert_eq!(parts, original_parts, "copied parts should be unchanged");
+    }
+    assert_eq!(
+        storage.list_messages(&parent.id, None).await.unwrap().len(),
+        3,
+        "forking should not change the parent session"
+    );
+
+    let children = storage.list_child_sessions(&parent.id).await.unwrap();
+    assert_eq!(children, vec![fork], "the fork should be listed as a child");
+
+    let error = storage
+        .fork_session(&parent.id, &storage.generate_message_id())
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(error, StorageError::NotFound(_)),
+        "forking at a missing message should be NotFound, got {:?}",
+        error
+    );
+    let error = storage
+        .fork_session(&storage.generate_session_id(), &messages[0].id)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(error, StorageError::NotFound(_)),
+        "forking a missing session should be NotFound, got {:?}",
+        error
+    );
+}
+
+/// Session search applies every filter and pages through the results with cursors.
+///
+/// # Panics
+///
+/// Panics if the storage doesn't conform.
+pub async fn search_sessions_filters_and_paginates(storage: Arc<dyn Storage>) {
+    // A unique user keeps the results independent of other data in the storage
+    let user_id = storage.generate_session_id();
+    let now = Utc::now();
+    let mut expected = Vec::new();
+
+    for (offset, tags, text) in [
+        (0, vec!["support"], "Where is my refund?"),
+        (1, vec!["support", "billing"], "Invoice question"),
+        (2, vec!["sales"], "Pricing"),
+    ] {
+        let mut session = Session::new(storage.generate_session_id()).with_metadata(
+            SessionMetadata::with_user_id(user_id.clone())
+                .with_tags(tags.into_iter().map(String::from).collect()),
+        );
+        session.updated_at = now - Duration::minutes(offset);
+        storage.store_session(&session).await.unwrap();
+        store_text_message(&storage, &session.id, text).await;
+        expected.push(session);
+    }
+    let ids = |sessions: &[Session]| -> Vec<String> {
+        sessions.iter().map(|session| session.id.clone()).collect()
+    };
+    let query = SessionQuery::new().with_user_id(user_id.clone());
+
+    let page = storage.search_sessions(&query).await.unwrap();
+    assert_eq!(
+        ids(&page.items),
+        ids(&expected),
+        "sessions should be returned most recently updated first"
+    );
+    assert_eq!(
+        page.next_cursor, None,
+        "a full result should have no cursor"
+    );
+
+    let tagged = storage
+        .search_sessions(&query.clone().with_tags(vec!["support".to_string()]))
+        .await
+        .unwrap();
+    assert_eq!(ids(&tagged.items), ids(&expected[..2]), "tag filter");
+
+    let updated = storage
+        .search_sessions(
+            &query
+                .clone()
+                .with_updated_before(now - Duration::seconds(30)),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        ids(&updated.items),
+        ids(&expected[1..]),
+        "updated_at filter"
+    );
+
+    let text = storage
+        .search_sessions(&query.clone().with_text("REFUND".to_string()))
+        .await
+        .unwrap();
+    assert_eq!(
+        ids(&text.items),
+        ids(&expected[..1]),
+        "text search should be case-insensitive"
+    );
+
+    let first = storage
+        .search_sessions(&query.clone().with_limit(2))
+        .await
+        .unwrap();
+    assert_eq!(ids(&first.items), ids(&expected[..2]));
+    let cursor = first
+        .next_cursor
+        .expect("a partial result should have a cursor");
+    let second = storage
+        .search_sessions(&query.clone().with_limit(2).with_cursor(cursor))
+        .await
+        .unwrap();
+    assert_eq!(
+        ids(&second.items),
+        ids(&expected[2..]),
+        "the cursor should continue after the previous page"
+    );
+    assert_eq!(second.next_cursor, None);
+}
+
+/// Message search applies every filter and pages through the results with cursors.
+///
+/// # Panics
+///
+/// Panics if the storage doesn't conform.
+pub async fn search_messages_filters_and_paginates(storage: Arc<dyn Storage>) {
+    let session = Session::new(storage.generate_session_id());
+    storage.store_session(&session).await.unwrap();
+    let session_id = session.id;
+    let question = store_text_message(&storage, &session_id, "Where is my invoice?").await;
+
+    let part = MessagePart::Text(TextPart::new(
+        storage.generate_part_id(),
+        "Your invoice is attached.".to_string(),
+    ));
+    let answer = AssistantMessage::new(
+        storage.generate_message_id(),
+        session_id.clone(),
+        vec![part.id().to_string()],
+    )
+    .with_metadata(MessageMetadata {
+        model_id: Some("model-1".to_string()),
+        ..Default::default()
+    });
+    storage
+        .store_assistant_message(&answer, &[part])
+        .await
+        .unwrap();
+    let unrelated = store_text_message(&storage, &session_id, "Thanks").await;
+
+    let ids = |query: MessageQuery| {
+        let storage = Arc::clone(&storage);
+        async move {
+            let page = storage.search_messages(&query).await.unwrap();
+            let ids: Vec<String> = page
+                .items
+                .into_iter()
+                .map(|message| message.message_id)
+                .collect();
+            (ids, page.next_cursor)
+        }
+    };
+    let query = MessageQuery::new().with_session_id(session_id.clone());
+
+    assert_eq!(
+        ids(query.clone()).await,
+        (
+            vec![question.id.clone(), answer.id.clone(), unrelated.id.clone()],
+            None
+        ),
+        "messages should be returned oldest first"
+    );
+    assert_eq!(
+        ids(query.clone().with_text("invoice".to_string())).await.0,
+        vec![question.id.clone(), answer.id.clone()],
+        "text filter"
+    );
+    assert_eq!(
+        ids(query.clone().with_role(MessageRole::Assistant)).await.0,
+        vec![answer.id.clone()],
+        "role filter"
+    );
+    assert_eq!(
+        ids(query.clone().with_model_id("model-1".to_string()))
+            .await
+            .0,
+        vec![answer.id.clone()],
+        "model filter"
+    );
+
+    let (first, cursor) = ids(query.clone().with_limit(2)).await;
+    assert_eq!(first, vec![question.id, answer.id]);
+    let cursor = cursor.expect("a partial result should have a cursor");
+    assert_eq!(
+        ids(query.with_limit(2).with_cursor(cursor)).await,
+        (vec![unrelated.id], None),
+        "the cursor should continue after the previous page"
+    );
+
+    let found = storage
+        .search_messages(&MessageQuery::new().with_text("your invoice is ATTACHED".to_string()))
+        .await
+        .unwrap();
+    assert!(
+        found
+            .items
+            .iter()
+            .any(|message| message.session_id == session_id
+                && message.role == MessageRole::Assistant),
+        "searching all sessions should find the message"
+    );
+}
+
+/// Concurrent writes to the same and to different sessions are all persisted.
+///
+/// # Panics
+///
+/// Panics if the storage doesn't conform.
+pub async fn concurrent_writes(storage: Arc<dyn Storage>) {
+    const WRITERS: usize = 16;
+
+    let shared_session_id = storage.generate_session_id();
+    let mut handles = Vec::with_capacity(WRITERS);
+
+    for i in 0..WRITERS {
+        let storage = Arc::clone(&storage);
+        let shared_session_id = shared_session_id.clone();
+
+        handles.push(tokio::spawn(async move {
+            let session = Session::new(storage.generate_session_id());
+            storage.store_session(&session).await.unwrap();
+            store_text_message(&storage, &session.id, "Own session").await;
+
+            store_text_message(&storage, &shared_session_id, &format!("Writer {}", i))
+                .await
+                .id
+        }));
+    }
+
+    let mut expected = Vec::with_capacity(WRITERS);
+    for handle in handles {
+        expected.push(handle.await.expect("writer task panicked"));
+    }
+    expected.sort();
+
+    let listed = storage
+        .list_messages(&shared_session_id, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        listed, expected,
+        "every concurrently stored message should be listed"
+    );
+
+    for message_id in &listed {
+        let (_, parts) = storage
+            .get_message(&shared_session_id, message_id)
+            .await
+            .unwrap();
+        assert_eq!(parts.len(), 1);
+    }
+}
+
+/// Store a user message with a single text part.
+async fn store_text_message(
+    storage: &Arc<dyn Storage>,
+    session_id: &str,
+    text: &str,
+) -> UserMessage {
+    let part = MessagePart::Text(TextPart::new(storage.generate_part_id(), text.to_string()));
+    let message = UserMessage::new(
+        storage.generate_message_id(),
+        session_id.to_string(),
+        vec![part.id().to_string()],
+    );
+
+    storage.store_user_message(&message, &[part]).await.unwrap();
+    message
+}
@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Status of a [`StoredStream`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum StreamStatus {
+    /// The stream is being produced
+    Active,
+    /// The stream finished and its messages are stored
+    Completed,
+    /// The stream ended before it finished, e.g. because the process crashed
+    Interrupted,
+}
+
+/// A generation stream whose chunks are stored, so clients can resume it.
+///
+/// The chunks themselves are stored separately with
+/// [`Storage::append_stream_chunks`](crate::Storage::append_stream_chunks), as JSON
+/// values in the format of the producer.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StoredStream {
+    /// Unique stream identifier (chosen by the producer)
+    pub id: String,
+
+    /// Session the stream's messages belong to
+    pub session_id: String,
+
+    /// Current status
+    pub status: StreamStatus,
+
+    /// When this stream was created
+    pub created_at: DateTime<Utc>,
+
+    /// Last update timestamp
+    pub updated_at: DateTime<Utc>,
+}
+
+impl StoredStream {
+    /// Create a new active stream with the given ID in the given session.
+    pub fn new(id: String, session_id: String) -> Self {
+        let now = Utc::now();
+        Self {
+            id,
+            session_id,
+            status: StreamStatus::Active,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Set the status of this stream, updating its timestamp (builder pattern).
+    pub fn with_status(mut self, status: StreamStatus) -> Self {
+        self.status = status;
+        self.updated_at = Utc::now();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stored_stream_serialization() {
+        let stream = StoredStream::new("str_1".to_string(), "ses_1".to_string())
+            .with_status(StreamStatus::Interrupted);
+
+        let json = serde_json::to_value(&stream).unwrap();
+        assert_eq!(json["status"], "interrupted");
+
+        let deserialized: StoredStream = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized, stream);
+    }
+}
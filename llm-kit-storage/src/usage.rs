@@ -0,0 +1,155 @@
+use crate::{MessageMetadata, PricingTable, UsageStats};
+
+/// Token usage of a session, summed over its assistant messages.
+///
+/// Each assistant message stores the usage of the step that generated it, so the
+/// usage of a session is the sum over its messages. Returned by
+/// [`Storage::session_usage`](crate::Storage::session_usage).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SessionUsage {
+    /// Usage of all steps
+    pub total: UsageStats,
+
+    /// Usage per provider and model, sorted by provider and model
+    pub by_model: Vec<ModelUsage>,
+
+    /// Number of steps (assistant messages with usage)
+    pub steps: usize,
+}
+
+/// Token usage of one model within a session.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ModelUsage {
+    /// Provider of the model, if recorded
+    pub provider: Option<String>,
+
+    /// Model ID, if recorded
+    pub model_id: Option<String>,
+
+    /// Usage of all steps of this model
+    pub usage: UsageStats,
+
+    /// Number of steps of this model
+    pub steps: usize,
+}
+
+impl SessionUsage {
+    /// Add the usage of a message; messages without usage are ignored.
+    pub fn add(&mut self, metadata: &MessageMetadata) {
+        let Some(usage) = &metadata.usage else {
+            return;
+        };
+
+        self.total += usage;
+        self.steps += 1;
+
+        let key = (metadata.provider.as_ref(), metadata.model_id.as_ref());
+        let index = match self
+            .by_model
+            .binary_search_by(|model| (model.provider.as_ref(), model.model_id.as_ref()).cmp(&key))
+        {
+            Ok(index) => index,
+            Err(index) => {
+                self.by_model.insert(
+                    index,
+                    ModelUsage {
+                        provider: metadata.provider.clone(),
+                        model_id: metadata.model_id.clone(),
+                        ..Default::default()
+                    },
+                );
+                index
+            }
+        };
+        self.by_model[index].usage += usage;
+        self.by_model[index].steps += 1;
+    }
+
+    /// Cost of the session.
+    ///
+    /// Returns `None` if any model with usage has no price in the table, so a partial
+    /// cost is never mistaken for the full one.
+    pub fn cost(&self, pricing: &PricingTable) -> Option<f64> {
+        self.by_model
+            .iter()
+            .map(|model| {
+                pricing.cost(
+                    model.provider.as_deref(),
+                    model.model_id.as_deref()?,
+                    &model.usage,
+                )
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ModelPricing;
+
+    fn step(provider: &str, model_id: &str, usage: UsageStats) -> MessageMetadata {
+        MessageMetadata {
+            provider: Some(provider.to_string()),
+            model_id: Some(model_id.to_string()),
+            usage: Some(usage),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_add_groups_by_model() {
+        let mut usage = SessionUsage::default();
+        usage.add(&step("openai", "gpt-4o", UsageStats::new(100, 10)));
+        usage.add(&step("anthropic", "claude", UsageStats::new(50, 5)));
+        usage.add(&step(
+            "openai",
+            "gpt-4o",
+            UsageStats::new(200, 20).with_reasoning_tokens(7),
+        ));
+        usage.add(&MessageMetadata::default());
+
+        assert_eq!(usage.steps, 3);
+        assert_eq!(
+            usage.total,
+            UsageStats::new(350, 35).with_reasoning_tokens(7)
+        );
+        let models: Vec<(&str, usize, u64)> = usage
+            .by_model
+            .iter()
+            .map(|model| {
+                (
+                    model.model_id.as_deref().unwrap(),
+                    model.steps,
+                    model.usage.prompt_tokens,
+                )
+            })
+            .collect();
+        assert_eq!(models, vec![("claude", 1, 50), ("gpt-4o", 2, 300)]);
+    }
+
+    #[test]
+    fn test_cost_requires_price_for_every_model() {
+        let mut usage = SessionUsage::default();
+        usage.add(&step("openai", "gpt-4o", UsageStats::new(1_000_000, 0)));
+        usage.add(&step(
+            "openai",
+            "gpt-4o-mini",
+            UsageStats::new(1_000_000, 0),
+        ));
+
+        let pricing = PricingTable::new().with_price(
+            "openai".to_string(),
+            "gpt-4o".to_string(),
+            ModelPricing::new(2.5, 10.0),
+        );
+        assert_eq!(usage.cost(&pricing), None);
+
+        let pricing = pricing.with_price(
+            "openai".to_string(),
+            "gpt-4o-mini".to_string(),
+            ModelPricing::new(0.15, 0.6),
+        );
+        assert!((usage.cost(&pricing).unwrap() - 2.65).abs() < 1e-9);
+    }
+}
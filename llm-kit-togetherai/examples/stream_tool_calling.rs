@@ -202,10 +202,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             LanguageModelStreamPart::ToolInputDelta(tool_delta) => {
                 current_tool_buffer.push_str(&tool_delta.delta);
             }
-            LanguageModelStreamPart::ToolInputEnd(_) => {
-                if !current_tool_buffer.is_empty() {
-                    println!("   Arguments: {}", current_tool_buffer);
-                }
+            LanguageModelStreamPart::ToolInputEnd(_) if !current_tool_buffer.is_empty() => {
+                println!("   Arguments: {}", current_tool_buffer);
             }
             LanguageModelStreamPart::Finish(finish) => {
                 println!(
@@ -30,6 +30,7 @@ pub struct TogetherAIClient {
     base_url: Option<String>,
     api_key: Option<String>,
     headers: HashMap<String, String>,
+    http_client: Option<reqwest::Client>,
 }
 
 impl TogetherAIClient {
@@ -39,6 +40,7 @@ impl TogetherAIClient {
             base_url: None,
             api_key: None,
             headers: HashMap::new(),
+            http_client: None,
         }
     }
 
@@ -90,6 +92,30 @@ impl TogetherAIClient {
         self
     }
 
+    /// Sets the HTTP client used for all API requests of the provider.
+    ///
+    /// Use this to share a connection pool or to configure proxies, timeouts,
+    /// TLS roots or a user agent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use llm_kit_togetherai::TogetherAIClient;
+    /// use std::time::Duration;
+    ///
+    /// let http_client = reqwest::Client::builder()
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let client = TogetherAIClient::new()
+    ///     .http_client(http_client);
+    /// ```
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
     /// Builds the Together AI provider.
     pub fn build(self) -> TogetherAIProvider {
         let mut settings = TogetherAIProviderSettings::new();
@@ -106,6 +132,10 @@ impl TogetherAIClient {
             settings = settings.with_headers(self.headers);
         }
 
+        if let Some(http_client) = self.http_client {
+            settings = settings.with_http_client(http_client);
+        }
+
         TogetherAIProvider::new(settings)
     }
 }
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use llm_kit_provider::error::ProviderError;
 use llm_kit_provider::image_model::{
     ImageData, ImageModel, ImageModelResponse, ImageModelResponseMetadata,
     call_options::ImageModelCallOptions,
@@ -18,6 +19,8 @@ pub struct TogetherAIImageModelConfig {
     pub base_url: String,
     /// Function to generate headers
     pub headers: Box<dyn Fn() -> HashMap<String, String> + Send + Sync>,
+    /// HTTP client used to send requests
+    pub http_client: reqwest::Client,
 }
 
 /// Together AI image generation model implementation.
@@ -135,7 +138,7 @@ impl ImageModel for TogetherAIImageModel {
         }
 
         // Make API request
-        let client = reqwest::Client::new();
+        let client = &self.config.http_client;
         let mut request = client.post(&url).header("Content-Type", "application/json");
 
         for (key, value) in headers {
@@ -145,34 +148,45 @@ impl ImageModel for TogetherAIImageModel {
         // Send request with optional cancellation support
         let response = if let Some(signal) = &options.abort_signal {
             tokio::select! {
-                result = request.body(body_string).send() => result?,
+                result = request.body(body_string.clone()).send() => result?,
                 _ = signal.cancelled() => {
                     return Err("Operation cancelled".into());
                 }
             }
         } else {
-            request.body(body_string).send().await?
+            request.body(body_string.clone()).send().await?
         };
 
         let status = response.status();
-        let response_headers = response.headers().clone();
+
+        // Build response headers map
+        let mut headers_map: SharedHeaders = HashMap::new();
+        for (key, value) in response.headers().iter() {
+            if let Ok(value_str) = value.to_str() {
+                headers_map.insert(key.as_str().to_string(), value_str.to_string());
+            }
+        }
 
         if !status.is_success() {
             let error_body = response.text().await?;
 
             // Try to parse as Together AI error format
-            if let Ok(error_response) = serde_json::from_str::<TogetherAIErrorResponse>(&error_body)
-            {
-                return Err(format!(
-                    "API request failed with status {}: {}",
-                    status, error_response.error.message
-                )
-                .into());
-            }
-
-            return Err(
-                format!("API request failed with status {}: {}", status, error_body).into(),
-            );
+            let message = match serde_json::from_str::<TogetherAIErrorResponse>(&error_body) {
+                Ok(error_response) => error_response.error.message,
+                Err(_) => error_body.clone(),
+            };
+
+            return Err(Box::new(ProviderError::api_call_error_with_details(
+                format!("API request failed with status {}: {}", status, message),
+                url,
+                body_string,
+                Some(status.as_u16()),
+                Some(headers_map),
+                Some(error_body),
+                None, // Auto-determine retryability based on status code
+                None,
+                None,
+            )));
         }
 
         let response_body = response.text().await?;
@@ -185,14 +199,6 @@ impl ImageModel for TogetherAIImageModel {
             .map(|data| ImageData::from_base64(data.b64_json))
             .collect();
 
-        // Build response headers map
-        let mut headers_map: SharedHeaders = HashMap::new();
-        for (key, value) in response_headers.iter() {
-            if let Ok(value_str) = value.to_str() {
-                headers_map.insert(key.as_str().to_string(), value_str.to_string());
-            }
-        }
-
         Ok(ImageModelResponse {
             images,
             response: ImageModelResponseMetadata {
@@ -216,6 +222,7 @@ mod tests {
             provider: "togetherai.image".to_string(),
             base_url: "https://api.together.xyz/v1".to_string(),
             headers: Box::new(HashMap::new),
+            http_client: reqwest::Client::new(),
         };
 
         let model =
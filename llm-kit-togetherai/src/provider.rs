@@ -36,6 +36,7 @@ use crate::settings::TogetherAIProviderSettings;
 /// ```
 pub struct TogetherAIProvider {
     settings: TogetherAIProviderSettings,
+    http_client: reqwest::Client,
 }
 
 impl TogetherAIProvider {
@@ -45,7 +46,12 @@ impl TogetherAIProvider {
     ///
     /// * `settings` - Configuration settings for the provider
     pub fn new(settings: TogetherAIProviderSettings) -> Self {
-        Self { settings }
+        let http_client = settings.http_client.clone().unwrap_or_default();
+
+        Self {
+            settings,
+            http_client,
+        }
     }
 
     /// Returns the provider name.
@@ -213,6 +219,7 @@ impl TogetherAIProvider {
             include_usage: true,
             supports_structured_outputs: false,
             supported_urls: None,
+            http_client: self.http_client.clone(),
         }
     }
 
@@ -241,6 +248,7 @@ impl TogetherAIProvider {
             }),
             url: Box::new(move |_model_id: &str, path: &str| format!("{}{}", base_url, path)),
             include_usage: true,
+            http_client: self.http_client.clone(),
         }
     }
 
@@ -270,6 +278,7 @@ impl TogetherAIProvider {
             url: Box::new(move |_model_id: &str, path: &str| format!("{}{}", base_url, path)),
             max_embeddings_per_call: None,
             supports_parallel_calls: None,
+            http_client: self.http_client.clone(),
         }
     }
 
@@ -297,6 +306,7 @@ impl TogetherAIProvider {
 
                 headers
             }),
+            http_client: self.http_client.clone(),
         }
     }
 
@@ -324,6 +334,7 @@ impl TogetherAIProvider {
 
                 headers
             }),
+            http_client: self.http_client.clone(),
         }
     }
 }
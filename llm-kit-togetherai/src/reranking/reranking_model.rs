@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use llm_kit_provider::error::ProviderError;
 use llm_kit_provider::reranking_model::{
     RankedDocument, RerankingModel, RerankingModelResponse, RerankingModelResponseMetadata,
     call_options::RerankingModelCallOptions,
@@ -17,6 +18,8 @@ pub struct TogetherAIRerankingModelConfig {
     pub base_url: String,
     /// Function to generate headers
     pub headers: Box<dyn Fn() -> HashMap<String, String> + Send + Sync>,
+    /// HTTP client used to send requests
+    pub http_client: reqwest::Client,
 }
 
 /// Together AI reranking model implementation.
@@ -168,7 +171,7 @@ impl RerankingModel for TogetherAIRerankingModel {
         }
 
         // Make API request
-        let client = reqwest::Client::new();
+        let client = &self.config.http_client;
         let mut request = client.post(&url).header("Content-Type", "application/json");
 
         for (key, value) in headers {
@@ -178,34 +181,45 @@ impl RerankingModel for TogetherAIRerankingModel {
         // Send request with optional cancellation support
         let response = if let Some(signal) = &options.abort_signal {
             tokio::select! {
-                result = request.body(body_string).send() => result?,
+                result = request.body(body_string.clone()).send() => result?,
                 _ = signal.cancelled() => {
                     return Err("Operation cancelled".into());
                 }
             }
         } else {
-            request.body(body_string).send().await?
+            request.body(body_string.clone()).send().await?
         };
 
         let status = response.status();
-        let response_headers = response.headers().clone();
+
+        // Build response headers map
+        let mut headers_map: SharedHeaders = HashMap::new();
+        for (key, value) in response.headers().iter() {
+            if let Ok(value_str) = value.to_str() {
+                headers_map.insert(key.as_str().to_string(), value_str.to_string());
+            }
+        }
 
         if !status.is_success() {
             let error_body = response.text().await?;
 
             // Try to parse as Together AI error format
-            if let Ok(error_response) = serde_json::from_str::<TogetherAIErrorResponse>(&error_body)
-            {
-                return Err(format!(
-                    "API request failed with status {}: {}",
-                    status, error_response.error.message
-                )
-                .into());
-            }
-
-            return Err(
-                format!("API request failed with status {}: {}", status, error_body).into(),
-            );
+            let message = match serde_json::from_str::<TogetherAIErrorResponse>(&error_body) {
+                Ok(error_response) => error_response.error.message,
+                Err(_) => error_body.clone(),
+            };
+
+            return Err(Box::new(ProviderError::api_call_error_with_details(
+                format!("API request failed with status {}: {}", status, message),
+                url,
+                body_string,
+                Some(status.as_u16()),
+                Some(headers_map),
+                Some(error_body),
+                None, // Auto-determine retryability based on status code
+                None,
+                None,
+            )));
         }
 
         let response_body = response.text().await?;
@@ -218,14 +232,6 @@ impl RerankingModel for TogetherAIRerankingModel {
             .map(|result| RankedDocument::new(result.index, result.relevance_score))
             .collect();
 
-        // Build response headers map
-        let mut headers_map: SharedHeaders = HashMap::new();
-        for (key, value) in response_headers.iter() {
-            if let Ok(value_str) = value.to_str() {
-                headers_map.insert(key.as_str().to_string(), value_str.to_string());
-            }
-        }
-
         Ok(RerankingModelResponse {
             ranking,
             response: Some(RerankingModelResponseMetadata {
@@ -251,6 +257,7 @@ mod tests {
             provider: "togetherai.reranking".to_string(),
             base_url: "https://api.together.xyz/v1".to_string(),
             headers: Box::new(HashMap::new),
+            http_client: reqwest::Client::new(),
         };
 
         let model = TogetherAIRerankingModel::new("Salesforce/Llama-Rank-v1".to_string(), config);
@@ -22,6 +22,10 @@ pub struct TogetherAIProviderSettings {
     /// These will be added to request headers after any headers potentially
     /// added by use of the `api_key` option.
     pub headers: Option<HashMap<String, String>>,
+
+    /// Optional HTTP client shared by all models of the provider. Use it to configure
+    /// proxies, timeouts, TLS roots or a user agent. Defaults to a new `reqwest::Client`.
+    pub http_client: Option<reqwest::Client>,
 }
 
 impl Default for TogetherAIProviderSettings {
@@ -30,6 +34,7 @@ impl Default for TogetherAIProviderSettings {
             base_url: "https://api.together.xyz/v1".to_string(),
             api_key: None,
             headers: None,
+            http_client: None,
         }
     }
 }
@@ -131,6 +136,21 @@ impl TogetherAIProviderSettings {
         self
     }
 
+    /// Sets the HTTP client used for API requests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use llm_kit_togetherai::TogetherAIProviderSettings;
+    ///
+    /// let settings = TogetherAIProviderSettings::new()
+    ///     .with_http_client(reqwest::Client::new());
+    /// ```
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
     /// Gets the API key, loading from environment if not set.
     ///
     /// Attempts to load from `TOGETHER_AI_API_KEY` environment variable if
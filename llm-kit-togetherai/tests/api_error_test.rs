@@ -0,0 +1,52 @@
+use llm_kit_provider::error::ProviderError;
+use llm_kit_provider::image_model::call_options::ImageModelCallOptions;
+use llm_kit_provider::reranking_model::call_options::RerankingModelCallOptions;
+use llm_kit_provider_utils::testing::{MockResponse, MockServer};
+use llm_kit_togetherai::{TogetherAIProvider, TogetherAIProviderSettings};
+use serde_json::json;
+
+fn provider(server: &MockServer) -> TogetherAIProvider {
+    TogetherAIProvider::new(
+        TogetherAIProviderSettings::new()
+            .with_base_url(server.url())
+            .with_api_key("test-key"),
+    )
+}
+
+fn error_response(status: u16, message: &str) -> MockResponse {
+    MockResponse::json(json!({ "error": { "message": message } })).with_status(status)
+}
+
+#[tokio::test]
+async fn test_reranking_errors_are_api_call_errors() {
+    let server = MockServer::start().await;
+    server.enqueue(error_response(401, "Invalid API key"));
+    server.enqueue(error_response(503, "Overloaded"));
+    let model = provider(&server).reranking_model("test-model");
+
+    for (status, retryable) in [(401, false), (503, true)] {
+        let options = RerankingModelCallOptions::new(vec!["Hello".to_string()], "Greeting");
+        let error = model.do_rerank(options).await.unwrap_err();
+        let error = error.downcast_ref::<ProviderError>().unwrap();
+
+        assert_eq!(error.status_code(), Some(status));
+        assert_eq!(error.is_retryable(), retryable);
+    }
+}
+
+#[tokio::test]
+async fn test_image_errors_are_api_call_errors() {
+    let server = MockServer::start().await;
+    server.enqueue(error_response(400, "Invalid prompt"));
+    let model = provider(&server).image_model("test-model");
+
+    let error = model
+        .do_generate(ImageModelCallOptions::new("A cat", 1))
+        .await
+        .unwrap_err();
+    let error = error.downcast_ref::<ProviderError>().unwrap();
+
+    assert_eq!(error.status_code(), Some(400));
+    assert!(!error.is_retryable());
+    assert!(error.to_string().contains("Invalid prompt"));
+}
@@ -0,0 +1,25 @@
+use llm_kit_provider_utils::testing::{
+    AnyModel, MockServer, assert_models_use_http_client, provider_models, test_http_client,
+};
+use llm_kit_togetherai::{TogetherAIProvider, TogetherAIProviderSettings};
+
+#[tokio::test]
+async fn test_http_client_is_used_by_every_model() {
+    let server = MockServer::start().await;
+    let provider = TogetherAIProvider::new(
+        TogetherAIProviderSettings::new()
+            .with_base_url(server.url())
+            .with_api_key("test-key")
+            .with_http_client(test_http_client()),
+    );
+
+    let mut models = provider_models(&provider, "test-model");
+    models.push(AnyModel::Language(provider.completion_model("test-model")));
+    let kinds: Vec<&str> = models.iter().map(|model| model.kind()).collect();
+    assert_eq!(
+        kinds,
+        ["language", "embedding", "image", "reranking", "language"]
+    );
+
+    assert_models_use_http_client(&server, &models).await;
+}
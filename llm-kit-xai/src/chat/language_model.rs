@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use futures_util::{Stream, StreamExt};
+use llm_kit_provider::error::ProviderError;
 use llm_kit_provider::language_model::call_options::LanguageModelCallOptions;
 use llm_kit_provider::language_model::call_warning::LanguageModelCallWarning;
 use llm_kit_provider::language_model::content::LanguageModelContent;
@@ -14,6 +15,7 @@ use llm_kit_provider::language_model::{
     LanguageModel, LanguageModelGenerateResponse, LanguageModelRequestMetadata,
     LanguageModelStreamResponse, StreamResponseMetadata,
 };
+use llm_kit_provider_utils::event_source::parse_json_event_stream;
 use regex::Regex;
 use reqwest::Client;
 use serde::Deserialize;
@@ -36,10 +38,12 @@ pub struct XaiChatLanguageModel {
 impl XaiChatLanguageModel {
     /// Creates a new xAI chat language model.
     pub fn new(model_id: String, config: XaiChatConfig) -> Self {
+        let client = config.http_client.clone();
+
         Self {
             model_id,
             config: Arc::new(config),
-            client: Client::new(),
+            client,
         }
     }
 
@@ -101,7 +105,6 @@ impl XaiChatLanguageModel {
         byte_stream: impl Stream<Item = Result<bytes::Bytes, reqwest::Error>> + Send + 'static,
         warnings: Vec<LanguageModelCallWarning>,
     ) -> impl Stream<Item = LanguageModelStreamPart> + Unpin + Send {
-        let mut buffer = String::new();
         let mut state = StreamState {
             text_id: None,
             reasoning_id: None,
@@ -112,43 +115,20 @@ impl XaiChatLanguageModel {
             // Emit stream start with warnings
             yield LanguageModelStreamPart::stream_start(warnings);
 
-            let mut stream = Box::pin(byte_stream);
+            let mut events = Box::pin(parse_json_event_stream::<XaiStreamChunk, _, _, _>(byte_stream));
 
-            while let Some(result) = stream.next().await {
+            while let Some(result) = events.next().await {
                 match result {
-                    Ok(bytes) => {
-                        // Append to buffer
-                        if let Ok(text) = std::str::from_utf8(&bytes) {
-                            buffer.push_str(text);
-
-                            // Process complete lines
-                            while let Some(pos) = buffer.find('\n') {
-                                let line = buffer[..pos].trim().to_string();
-                                buffer.drain(..=pos);
-
-                                // Skip empty lines
-                                if line.is_empty() {
-                                    continue;
-                                }
-
-                                // Parse SSE format
-                                if let Some(data) = line.strip_prefix("data: ") {
-                                    // Check for [DONE] marker
-                                    if data == "[DONE]" {
-                                        break;
-                                    }
-
-                                    // Parse JSON chunk
-                                    if let Ok(chunk) = serde_json::from_str::<XaiStreamChunk>(data) {
-                                        // Process the chunk and emit stream parts
-                                        for part in Self::process_chunk(&mut state, chunk) {
-                                            yield part;
-                                        }
-                                    }
-                                }
-                            }
+                    Ok(Ok(chunk)) => {
+                        // Process the chunk and emit stream parts
+                        for part in Self::process_chunk(&mut state, chunk) {
+                            yield part;
                         }
                     }
+                    Ok(Err(error)) => {
+                        // Skip chunks that don't match the schema, e.g. of new event types
+                        log::warn!("Skipping unrecognized stream chunk: {}", error);
+                    }
                     Err(e) => {
                         yield LanguageModelStreamPart::error(serde_json::json!({ "message": e.to_string() }));
                         break;
@@ -336,6 +316,10 @@ impl LanguageModel for XaiChatLanguageModel {
         urls
     }
 
+    fn supports_structured_outputs(&self) -> bool {
+        true
+    }
+
     async fn do_generate(
         &self,
         options: LanguageModelCallOptions,
@@ -456,10 +440,17 @@ impl LanguageModel for XaiChatLanguageModel {
                 let provider_error = error_data.to_provider_error(status.as_u16(), &url);
                 return Err(Box::new(provider_error));
             }
-            return Err(Box::new(std::io::Error::other(format!(
-                "xAI API error ({}): {}",
-                status, error_body
-            ))));
+            return Err(Box::new(ProviderError::api_call_error_with_details(
+                format!("xAI API error ({}): {}", status, error_body),
+                url,
+                body.to_string(),
+                Some(status.as_u16()),
+                None,
+                Some(error_body),
+                None, // Auto-determine retryability based on status code
+                None,
+                None,
+            )));
         }
 
         // Parse response
@@ -659,10 +650,17 @@ impl LanguageModel for XaiChatLanguageModel {
                 let provider_error = error_data.to_provider_error(status.as_u16(), &url);
                 return Err(Box::new(provider_error));
             }
-            return Err(Box::new(std::io::Error::other(format!(
-                "xAI API error ({}): {}",
-                status, error_body
-            ))));
+            return Err(Box::new(ProviderError::api_call_error_with_details(
+                format!("xAI API error ({}): {}", status, error_body),
+                url,
+                body.to_string(),
+                Some(status.as_u16()),
+                None,
+                Some(error_body),
+                None, // Auto-determine retryability based on status code
+                None,
+                None,
+            )));
         }
 
         // Build headers map from HTTP response headers
@@ -973,6 +971,7 @@ mod tests {
             provider: "xai".to_string(),
             base_url: "https://api.x.ai/v1".to_string(),
             headers: Box::new(HashMap::new),
+            http_client: Client::new(),
         };
         let model = XaiChatLanguageModel::new("grok-2-1212".to_string(), config);
 
@@ -993,6 +992,7 @@ mod tests {
             provider: "xai".to_string(),
             base_url: "https://api.x.ai/v1".to_string(),
             headers: Box::new(HashMap::new),
+            http_client: Client::new(),
         };
         let model = XaiChatLanguageModel::new("grok-2-1212".to_string(), config);
 
@@ -1026,6 +1026,7 @@ mod tests {
             provider: "xai".to_string(),
             base_url: "https://api.x.ai/v1".to_string(),
             headers: Box::new(HashMap::new),
+            http_client: Client::new(),
         };
         let model = XaiChatLanguageModel::new("grok-2-1212".to_string(), config);
 
@@ -1065,6 +1066,7 @@ mod tests {
             provider: "xai".to_string(),
             base_url: "https://api.x.ai/v1".to_string(),
             headers: Box::new(HashMap::new),
+            http_client: Client::new(),
         };
         let model = XaiChatLanguageModel::new("grok-2-1212".to_string(), config);
 
@@ -1089,6 +1091,7 @@ mod tests {
             provider: "xai".to_string(),
             base_url: "https://api.x.ai/v1".to_string(),
             headers: Box::new(HashMap::new),
+            http_client: Client::new(),
         };
         let model = XaiChatLanguageModel::new("grok-2-1212".to_string(), config);
 
@@ -1116,6 +1119,7 @@ mod tests {
             provider: "xai".to_string(),
             base_url: "https://api.x.ai/v1".to_string(),
             headers: Box::new(HashMap::new),
+            http_client: Client::new(),
         };
         let model = XaiChatLanguageModel::new("grok-2-1212".to_string(), config);
 
@@ -20,6 +20,7 @@ pub struct XaiClient {
     base_url: Option<String>,
     api_key: Option<String>,
     headers: Option<HashMap<String, String>>,
+    http_client: Option<reqwest::Client>,
 }
 
 impl XaiClient {
@@ -58,6 +59,30 @@ impl XaiClient {
         self
     }
 
+    /// Sets the HTTP client used for all API requests of the provider.
+    ///
+    /// Use this to share a connection pool or to configure proxies, timeouts,
+    /// TLS roots or a user agent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use llm_kit_xai::XaiClient;
+    /// use std::time::Duration;
+    ///
+    /// let http_client = reqwest::Client::builder()
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let client = XaiClient::new()
+    ///     .http_client(http_client);
+    /// ```
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
     /// Builds the xAI provider.
     pub fn build(self) -> XaiProvider {
         let mut settings = XaiProviderSettings::new();
@@ -76,6 +101,10 @@ impl XaiClient {
             settings = settings.with_headers(headers);
         }
 
+        if let Some(http_client) = self.http_client {
+            settings = settings.with_http_client(http_client);
+        }
+
         XaiProvider::new(settings)
     }
 }
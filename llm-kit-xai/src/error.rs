@@ -23,34 +23,27 @@ impl XaiErrorData {
         let message = &self.error.message;
         let request_body = "{}"; // We don't have the original request body here
 
-        match status_code {
-            400 => ProviderError::api_call_error(
-                format!("Bad request: {}", message),
-                url,
-                request_body,
-            ),
-            401 => ProviderError::api_call_error(
-                format!("Unauthorized: {}", message),
-                url,
-                request_body,
-            ),
-            404 => {
-                ProviderError::api_call_error(format!("Not found: {}", message), url, request_body)
-            }
-            429 => ProviderError::api_call_error(
-                format!("Rate limit exceeded: {}", message),
-                url,
-                request_body,
-            ),
-            500..=599 => ProviderError::api_call_error(
-                format!("Server error: {}", message),
-                url,
-                request_body,
-            ),
-            _ => {
-                ProviderError::api_call_error(format!("API error: {}", message), url, request_body)
-            }
-        }
+        let message = match status_code {
+            400 => format!("Bad request: {}", message),
+            401 => format!("Unauthorized: {}", message),
+            404 => format!("Not found: {}", message),
+            429 => format!("Rate limit exceeded: {}", message),
+            500..=599 => format!("Server error: {}", message),
+            _ => format!("API error: {}", message),
+        };
+
+        // The status code decides whether the error is retryable
+        ProviderError::api_call_error_with_details(
+            message,
+            url,
+            request_body,
+            Some(status_code),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
     }
 }
 
@@ -90,7 +83,12 @@ mod tests {
 
         let provider_error =
             error_data.to_provider_error(429, "https://api.x.ai/v1/chat/completions");
-        // Just verify it doesn't panic - the error type is ApiCallError
         assert!(provider_error.to_string().contains("Rate limit exceeded"));
+        assert_eq!(provider_error.status_code(), Some(429));
+        assert!(provider_error.is_retryable());
+
+        let provider_error =
+            error_data.to_provider_error(401, "https://api.x.ai/v1/chat/completions");
+        assert!(!provider_error.is_retryable());
     }
 }
@@ -14,12 +14,18 @@ use crate::settings::XaiProviderSettings;
 /// Provides methods to create different types of language models for xAI's Grok models.
 pub struct XaiProvider {
     settings: XaiProviderSettings,
+    http_client: reqwest::Client,
 }
 
 impl XaiProvider {
     /// Creates a new xAI provider.
     pub fn new(settings: XaiProviderSettings) -> Self {
-        Self { settings }
+        let http_client = settings.http_client.clone().unwrap_or_default();
+
+        Self {
+            settings,
+            http_client,
+        }
     }
 
     /// Returns the provider name.
@@ -96,6 +102,7 @@ impl XaiProvider {
 
                 headers
             }),
+            http_client: self.http_client.clone(),
         }
     }
 
@@ -123,6 +130,7 @@ impl XaiProvider {
                 headers
             }),
             url: Box::new(move |_model_id: &str, path: &str| format!("{}{}", base_url, path)),
+            http_client: self.http_client.clone(),
         }
     }
 
@@ -137,6 +145,7 @@ pub struct XaiChatConfig {
     pub provider: String,
     pub base_url: String,
     pub headers: Box<dyn Fn() -> HashMap<String, String> + Send + Sync>,
+    pub http_client: reqwest::Client,
 }
 
 // Implement the Provider trait for compatibility with the provider interface